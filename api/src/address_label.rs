@@ -0,0 +1,30 @@
+//! Local, user-supplied metadata about receiving addresses.
+//!
+//! neptune-core knows nothing about these. They are stored by neptune-proton
+//! and keyed by the address in bech32m form.
+
+use std::collections::HashMap;
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Labels, eg "donations" or "from exchange", keyed by bech32m address.
+pub type AddressLabels = HashMap<String, String>;
+
+/// Funds that arrived at a single address, as seen in the wallet history.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AddressUsage {
+    /// Number of UTXOs received, including change.
+    pub received_count: usize,
+
+    /// Sum of the UTXOs received.
+    pub total_received: NativeCurrencyAmount,
+
+    /// Some of the UTXOs arrived in a block that also paid other addresses of
+    /// this wallet, so `total_received` leaves them out.
+    pub total_is_partial: bool,
+}
+
+/// Usage of each address that has received funds, keyed by bech32m address.
+pub type AddressUsages = HashMap<String, AddressUsage>;
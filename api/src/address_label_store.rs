@@ -0,0 +1,24 @@
+//! Persists [AddressLabels] as a JSON file alongside the neptune-core wallet.
+
+use crate::address_label::AddressLabels;
+use crate::json_store::JsonStore;
+
+static STORE: JsonStore<AddressLabels> =
+    JsonStore::in_wallet_dir("neptune-proton-address-labels.json");
+
+/// Returns all stored labels.
+pub async fn load() -> anyhow::Result<AddressLabels> {
+    STORE.load().await
+}
+
+/// Applies `f` to the stored labels and saves the result.
+///
+/// Entries left empty by `f` are dropped.
+pub async fn update(f: impl FnOnce(&mut AddressLabels)) -> anyhow::Result<AddressLabels> {
+    STORE
+        .update(|labels| {
+            f(labels);
+            labels.retain(|_, label| !label.is_empty());
+        })
+        .await
+}
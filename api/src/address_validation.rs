@@ -0,0 +1,148 @@
+//! Explains why a string is not a receiving address for this network.
+//!
+//! `ReceivingAddress::from_bech32m` only says that decoding failed.  This
+//! checks the bech32m encoding itself, so the user can be told whether the
+//! address was cut short, mistyped, or meant for another network.
+
+use neptune_types::address::ReceivingAddress;
+use neptune_types::network::Network;
+use thiserror::Error;
+
+/// The bech32m alphabet.  Each character encodes 5 bits.
+const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The checksum constant that distinguishes bech32m from bech32.
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// The number of checksum characters at the end of the data part.
+const CHECKSUM_LEN: usize = 6;
+
+/// Human readable prefixes of the address types this wallet can pay, each
+/// followed by a character for the network, and the least number of data
+/// characters a complete address of that type has.  The minimums are well
+/// below the real lengths, so only addresses that were clearly cut short
+/// are reported as such.
+const ADDRESS_TYPES: [(&str, usize); 2] = [("nolga", 1000), ("nsym", 60)];
+
+/// Why a string is not a receiving address for this network.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AddressProblem {
+    #[error("no address was entered")]
+    Empty,
+
+    /// Abbreviated for display, or missing part of its end.
+    #[error("the address is incomplete")]
+    Truncated,
+
+    /// Not bech32m: no separator, mixed case or characters outside the
+    /// alphabet.
+    #[error("not an address")]
+    Malformed,
+
+    /// The checksum does not match, so a character was changed.
+    #[error("the address checksum does not match")]
+    Checksum,
+
+    /// A valid address of a type this wallet pays, for another network.
+    #[error("the address is for another network than {expected} ({prefix})")]
+    WrongNetwork { prefix: String, expected: String },
+
+    /// A valid bech32m string, but not of a type this wallet pays.
+    #[error("unsupported address type ({prefix})")]
+    UnsupportedKeyType { prefix: String },
+}
+
+/// Decodes `input` as an address for `network`, or explains why it is not
+/// one.
+pub fn validate_address(input: &str, network: Network) -> Result<ReceivingAddress, AddressProblem> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(AddressProblem::Empty);
+    }
+    if let Ok(address) = ReceivingAddress::from_bech32m(input, network) {
+        return Ok(address);
+    }
+    Err(diagnose(input, network))
+}
+
+/// Explains why `input`, which did not decode, is not an address.
+fn diagnose(input: &str, network: Network) -> AddressProblem {
+    // as shown by `to_display_bech32m_abbreviated`.
+    if input.contains("...") || input.contains('…') {
+        return AddressProblem::Truncated;
+    }
+
+    // both cases are valid, but not mixed.
+    let has_lower = input.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = input.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return AddressProblem::Malformed;
+    }
+    let input = input.to_ascii_lowercase();
+
+    let Some((hrp, data)) = input.rsplit_once('1') else {
+        return AddressProblem::Malformed;
+    };
+    if hrp.is_empty() || !hrp.chars().all(|c| c.is_ascii_graphic()) {
+        return AddressProblem::Malformed;
+    }
+    let Some(values) = data
+        .chars()
+        .map(|c| CHARSET.find(c).map(|v| v as u8))
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return AddressProblem::Malformed;
+    };
+
+    let min_len = ADDRESS_TYPES
+        .iter()
+        .find(|(prefix, _)| hrp.starts_with(prefix))
+        .map(|(_, min_len)| *min_len);
+    if values.len() < CHECKSUM_LEN || min_len.is_some_and(|min| values.len() < min) {
+        return AddressProblem::Truncated;
+    }
+    if !checksum_is_valid(hrp, &values) {
+        return AddressProblem::Checksum;
+    }
+
+    match min_len {
+        // a well formed address of a known type, that is not for this
+        // network.
+        Some(_) => AddressProblem::WrongNetwork {
+            prefix: hrp.to_string(),
+            expected: network.to_string(),
+        },
+        None => AddressProblem::UnsupportedKeyType {
+            prefix: hrp.to_string(),
+        },
+    }
+}
+
+/// Verifies the bech32m checksum, per BIP-350.
+fn checksum_is_valid(hrp: &str, values: &[u8]) -> bool {
+    let expanded = hrp
+        .bytes()
+        .map(|b| b >> 5)
+        .chain([0])
+        .chain(hrp.bytes().map(|b| b & 31));
+    polymod(expanded.chain(values.iter().copied())) == BECH32M_CONST
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    values.fold(1, |chk, value| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x01ff_ffff) << 5) ^ u32::from(value);
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(chk, |chk, (_, g)| chk ^ g)
+    })
+}
@@ -0,0 +1,68 @@
+//! Parses announcements typed into the developer tools and the Send
+//! wizard.
+
+use serde::Deserialize;
+use serde::Serialize;
+use twenty_first::prelude::BFieldElement;
+
+/// The most field elements an announcement may have.  Every element adds
+/// to the size of the transaction, and so to the fee it needs.
+pub const MAX_ANNOUNCEMENT_ELEMENTS: usize = 512;
+
+/// How the message of an announcement is entered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, strum::EnumIter)]
+pub enum AnnouncementEncoding {
+    /// Field elements in hex, separated by whitespace or commas, eg
+    /// `0x01 ff 2a`.  Gives exact control over the message.
+    #[default]
+    Hex,
+
+    /// Text, one field element per UTF-8 byte.
+    Utf8,
+}
+
+impl AnnouncementEncoding {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Hex => "Hex",
+            Self::Utf8 => "UTF-8",
+        }
+    }
+}
+
+/// Converts `input` to the field elements of an announcement's message.
+pub fn parse(input: &str, encoding: AnnouncementEncoding) -> Result<Vec<BFieldElement>, String> {
+    let message: Vec<BFieldElement> = match encoding {
+        AnnouncementEncoding::Hex => input
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let digits = word
+                    .strip_prefix("0x")
+                    .or_else(|| word.strip_prefix("0X"))
+                    .unwrap_or(word);
+                let value = u64::from_str_radix(digits, 16)
+                    .map_err(|_| format!("'{word}' is not a hex number."))?;
+                if value >= BFieldElement::P {
+                    return Err(format!("{word} is not a field element."));
+                }
+                Ok(BFieldElement::new(value))
+            })
+            .collect::<Result<_, _>>()?,
+        AnnouncementEncoding::Utf8 => input
+            .bytes()
+            .map(|b| BFieldElement::new(b.into()))
+            .collect(),
+    };
+
+    if message.is_empty() {
+        return Err("The announcement is empty.".to_string());
+    }
+    if message.len() > MAX_ANNOUNCEMENT_ELEMENTS {
+        return Err(format!(
+            "The announcement has {} field elements, more than the {MAX_ANNOUNCEMENT_ELEMENTS} allowed.",
+            message.len()
+        ));
+    }
+    Ok(message)
+}
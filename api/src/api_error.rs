@@ -0,0 +1,212 @@
+//! The error returned by every API endpoint.
+//!
+//! Server code works with [anyhow::Error] and converts at the endpoint, where
+//! the cause is classified so that the UI can react to, eg, a node that is
+//! down differently from a send that cannot be afforded.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, strum::EnumIs)]
+pub enum ApiError {
+    /// neptune-core could not be reached, or dropped the connection.
+    ConnectionLost(String),
+    /// neptune-core is reachable, but did not answer in time.  It may be
+    /// busy, or hung.
+    Timeout(String),
+    /// neptune-core, or this app's own access control, rejected the request.
+    AuthFailed(String),
+    /// neptune-core is reachable, but this app cannot read the RPC cookie
+    /// that authenticates it, eg for lack of file permissions.  Only calls
+    /// that need no authentication, such as the network, work.
+    CookieUnavailable(String),
+    /// The wallet cannot cover the amount and fee.
+    InsufficientBalance(String),
+    InvalidAddress(String),
+    /// A transaction was handed to neptune-core, but the connection failed
+    /// before it answered, so whether it was broadcast is unknown.  Sending
+    /// it again may pay twice.
+    BroadcastUnknown(String),
+    /// Anything else.  The message is meant for the user.
+    Internal(String),
+}
+
+impl ApiError {
+    pub fn message(&self) -> &str {
+        match self {
+            Self::ConnectionLost(msg)
+            | Self::Timeout(msg)
+            | Self::AuthFailed(msg)
+            | Self::CookieUnavailable(msg)
+            | Self::InsufficientBalance(msg)
+            | Self::InvalidAddress(msg)
+            | Self::BroadcastUnknown(msg)
+            | Self::Internal(msg) => msg,
+        }
+    }
+
+    /// True for failures to reach neptune-core, that may pass if the call
+    /// is made again.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::ConnectionLost(_) | Self::Timeout(_))
+    }
+
+    /// True if a send that failed with this error may be sent again as is.
+    /// Not if the send itself must change, nor if it may have gone out.
+    pub fn can_retry_send(&self) -> bool {
+        !matches!(
+            self,
+            Self::InsufficientBalance(_) | Self::InvalidAddress(_) | Self::BroadcastUnknown(_)
+        )
+    }
+
+    /// Prefixes the message with `context`, keeping the kind of error.
+    pub fn context(self, context: impl std::fmt::Display) -> Self {
+        let wrap = |msg: String| format!("{}: {}", context, msg);
+        match self {
+            Self::ConnectionLost(msg) => Self::ConnectionLost(wrap(msg)),
+            Self::Timeout(msg) => Self::Timeout(wrap(msg)),
+            Self::AuthFailed(msg) => Self::AuthFailed(wrap(msg)),
+            Self::CookieUnavailable(msg) => Self::CookieUnavailable(wrap(msg)),
+            Self::InsufficientBalance(msg) => Self::InsufficientBalance(wrap(msg)),
+            Self::InvalidAddress(msg) => Self::InvalidAddress(wrap(msg)),
+            Self::BroadcastUnknown(msg) => Self::BroadcastUnknown(wrap(msg)),
+            Self::Internal(msg) => Self::Internal(wrap(msg)),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+// ApiError deliberately does not implement std::error::Error, which would
+// conflict with this blanket conversion.  It lets `?` take any error in an
+// endpoint, as with anyhow.
+impl<E: Into<anyhow::Error>> From<E> for ApiError {
+    fn from(e: E) -> Self {
+        let e = e.into();
+        // every endpoint's errors pass here, so a token neptune-core
+        // rejected is dropped, and reloaded by the next call.
+        #[cfg(not(target_arch = "wasm32"))]
+        crate::rpc_connection::note_error(&e);
+        classify(&e)
+    }
+}
+
+/// True if `e` is a failure to reach neptune-core, that may pass if the call
+/// is made again.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn is_transient(e: &anyhow::Error) -> bool {
+    classify(e).is_transient()
+}
+
+/// Errors that server code raises, via anyhow, to be reported as a specific
+/// [ApiError].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum Failure {
+    #[error("{0}")]
+    ConnectionLost(String),
+    #[error("{0}")]
+    Timeout(String),
+    #[error("{0}")]
+    InsufficientBalance(String),
+    #[error("{0}")]
+    InvalidAddress(String),
+    #[error("{0}")]
+    AuthFailed(String),
+    #[error("{0}")]
+    CookieUnavailable(String),
+    #[error("{0}")]
+    BroadcastUnknown(String),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn classify(e: &anyhow::Error) -> ApiError {
+    use neptune_cash::application::rpc::server::error::RpcError;
+
+    let from_failure = |failure: &Failure, msg: String| match failure {
+        Failure::ConnectionLost(_) => ApiError::ConnectionLost(msg),
+        Failure::Timeout(_) => ApiError::Timeout(msg),
+        Failure::InsufficientBalance(_) => ApiError::InsufficientBalance(msg),
+        Failure::InvalidAddress(_) => ApiError::InvalidAddress(msg),
+        Failure::AuthFailed(_) => ApiError::AuthFailed(msg),
+        Failure::CookieUnavailable(_) => ApiError::CookieUnavailable(msg),
+        Failure::BroadcastUnknown(_) => ApiError::BroadcastUnknown(msg),
+    };
+
+    // the full chain, so that context added along the way is not lost.
+    let msg = format!("{:#}", e);
+    // a Failure given as context outweighs the cause, eg a broadcast cut
+    // short by a lost connection.
+    if let Some(failure) = e.downcast_ref::<Failure>() {
+        return from_failure(failure, msg);
+    }
+    for cause in e.chain() {
+        if let Some(failure) = cause.downcast_ref::<Failure>() {
+            return from_failure(failure, msg);
+        }
+        match cause.downcast_ref::<RpcError>() {
+            Some(RpcError::Auth(_)) => return ApiError::AuthFailed(msg),
+            // neptune-core reports a send it cannot fund only in prose.
+            Some(e) if e.to_string().to_lowercase().contains("insufficient") => {
+                return ApiError::InsufficientBalance(msg);
+            }
+            _ => {}
+        }
+        match cause.downcast_ref::<tarpc::client::RpcError>() {
+            Some(tarpc::client::RpcError::DeadlineExceeded) => return ApiError::Timeout(msg),
+            Some(_) => return ApiError::ConnectionLost(msg),
+            None => {}
+        }
+        if let Some(io) = cause.downcast_ref::<std::io::Error>() {
+            use std::io::ErrorKind;
+            if matches!(
+                io.kind(),
+                ErrorKind::ConnectionRefused
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::NotConnected
+                    | ErrorKind::TimedOut
+            ) {
+                return ApiError::ConnectionLost(msg);
+            }
+        }
+    }
+    ApiError::Internal(msg)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn classify(e: &anyhow::Error) -> ApiError {
+    ApiError::Internal(format!("{:#}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sends_that_may_have_gone_out_are_not_retried() {
+        let msg = || "failed".to_string();
+        assert!(!ApiError::BroadcastUnknown(msg()).can_retry_send());
+        assert!(!ApiError::InsufficientBalance(msg()).can_retry_send());
+        assert!(!ApiError::InvalidAddress(msg()).can_retry_send());
+
+        assert!(ApiError::ConnectionLost(msg()).can_retry_send());
+        assert!(ApiError::Timeout(msg()).can_retry_send());
+        assert!(ApiError::Internal(msg()).can_retry_send());
+    }
+
+    #[test]
+    fn context_keeps_an_unknown_broadcast() {
+        let e = ApiError::BroadcastUnknown("connection reset".to_string()).context("send");
+        assert_eq!(
+            e,
+            ApiError::BroadcastUnknown("send: connection reset".to_string())
+        );
+    }
+}
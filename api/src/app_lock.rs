@@ -0,0 +1,77 @@
+//! Hashing of the PIN or passphrase that unlocks the app.
+//!
+//! Only an Argon2 hash is stored, in the prefs file.  The lock protects the
+//! UI from someone with brief access to an unlocked device.  It does not
+//! encrypt the wallet, which neptune-core manages.
+
+use std::time::Duration;
+
+use argon2::password_hash::PasswordHash;
+use argon2::password_hash::PasswordHasher;
+use argon2::password_hash::PasswordVerifier;
+use argon2::password_hash::SaltString;
+use argon2::Argon2;
+use rand_core::OsRng;
+use tokio::sync::Mutex;
+
+/// The shortest PIN accepted.
+pub const MIN_PIN_LEN: usize = 4;
+
+/// Slows down guessing.  Doubles with each consecutive wrong guess beyond
+/// the first few, up to [MAX_FAILURE_DELAY].
+const FAILURE_DELAY: Duration = Duration::from_secs(1);
+
+/// Wrong guesses allowed before the delay starts to grow, for typos.
+const FREE_FAILURES: u32 = 3;
+
+const MAX_FAILURE_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// Consecutive wrong guesses, server-wide.  Held while a guess is checked
+/// and its delay served, so that guesses made in parallel wait their turn
+/// rather than sidestepping the delay.
+static FAILURES: Mutex<u32> = Mutex::const_new(0);
+
+/// Returns a PHC-format hash of `pin`, with a random salt.
+pub async fn hash(pin: String) -> anyhow::Result<String> {
+    if pin.chars().count() < MIN_PIN_LEN {
+        anyhow::bail!("PIN must be at least {} characters.", MIN_PIN_LEN);
+    }
+    tokio::task::spawn_blocking(move || {
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(pin.as_bytes(), &salt)
+            .map(|h| h.to_string())
+            .map_err(|e| anyhow::anyhow!("Could not hash PIN: {}", e))
+    })
+    .await?
+}
+
+/// True if `pin` matches `hash`.  Returns after a delay if it does not,
+/// which grows with consecutive wrong guesses.
+pub async fn verify(pin: String, hash: String) -> anyhow::Result<bool> {
+    let mut failures = FAILURES.lock().await;
+
+    let matches = tokio::task::spawn_blocking(move || {
+        let parsed =
+            PasswordHash::new(&hash).map_err(|e| anyhow::anyhow!("Invalid PIN hash: {}", e))?;
+        Ok::<_, anyhow::Error>(
+            Argon2::default()
+                .verify_password(pin.as_bytes(), &parsed)
+                .is_ok(),
+        )
+    })
+    .await??;
+
+    if matches {
+        *failures = 0;
+    } else {
+        *failures += 1;
+        tokio::time::sleep(failure_delay(*failures)).await;
+    }
+    Ok(matches)
+}
+
+fn failure_delay(failures: u32) -> Duration {
+    let doublings = failures.saturating_sub(FREE_FAILURES).min(16);
+    (FAILURE_DELAY * 2u32.pow(doublings)).min(MAX_FAILURE_DELAY)
+}
@@ -0,0 +1,78 @@
+//! The kinds of coin the wallet holds, and its balance of each.
+//!
+//! A Neptune UTXO can hold coins of any type, each identified by the hash of
+//! its type script.  neptune-core's wallet reports only native coins so far,
+//! so every UTXO is [Asset::Native] for now, but screens group and label by
+//! asset so that other coin types slot in once they are reported.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::ui_utxo::UiUtxo;
+use neptune_types::ui_utxo::UtxoStatusEvent;
+use num_traits::Zero;
+use serde::Deserialize;
+use serde::Serialize;
+use twenty_first::tip5::Digest;
+
+/// A coin type.  Native coins sort first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Asset {
+    Native,
+    /// Any other coin, by the hash of its type script.
+    Other(Digest),
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Asset::Native => write!(f, "NPT"),
+            Asset::Other(type_script_hash) => {
+                let hex = type_script_hash.to_hex();
+                write!(f, "Token {}", &hex[..8])
+            }
+        }
+    }
+}
+
+/// The wallet's unspent holdings of one asset.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssetBalance {
+    pub asset: Asset,
+    pub num_utxos: usize,
+    /// None for assets whose amounts the wallet cannot value.
+    pub amount: Option<NativeCurrencyAmount>,
+}
+
+/// The asset a UTXO holds.
+///
+/// [UiUtxo] carries only a native amount, so this is always
+/// [Asset::Native] until neptune-core reports coin types.
+pub fn asset_of(_utxo: &UiUtxo) -> Asset {
+    Asset::Native
+}
+
+/// Sums the unspent, confirmed UTXOs by asset.
+pub fn balances(utxos: &[UiUtxo]) -> Vec<AssetBalance> {
+    let mut by_asset: BTreeMap<Asset, AssetBalance> = BTreeMap::new();
+    for utxo in utxos.iter().filter(|u| {
+        matches!(u.spent, UtxoStatusEvent::None)
+            && matches!(u.received, UtxoStatusEvent::Confirmed { .. })
+    }) {
+        let asset = asset_of(utxo);
+        let balance = by_asset.entry(asset).or_insert_with(|| AssetBalance {
+            asset,
+            num_utxos: 0,
+            amount: match asset {
+                Asset::Native => Some(NativeCurrencyAmount::zero()),
+                Asset::Other(_) => None,
+            },
+        });
+        balance.num_utxos += 1;
+        if let Some(amount) = balance.amount.as_mut() {
+            *amount = *amount + utxo.amount;
+        }
+    }
+    by_asset.into_values().collect()
+}
@@ -0,0 +1,19 @@
+//! Findings of the startup checks that the user should act on.
+
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AttentionItem {
+    /// Saved Send drafts that were never sent.
+    SendDrafts { count: usize },
+
+    /// An own transaction in the mempool that is not synced to the tip, so it
+    /// cannot be mined as is.
+    StuckTransaction { txid: TransactionKernelId },
+
+    /// UTXOs the wallet expects, eg change or off-chain transfers, but has
+    /// not received.
+    UnclaimedUtxos { count: u64 },
+}
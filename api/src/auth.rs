@@ -0,0 +1,313 @@
+//! Authentication for web deployments.
+//!
+//! By default every request is trusted, as is appropriate for the desktop app
+//! or a web server bound to localhost.  Self-hosters who put neptune-proton
+//! behind a single-sign-on proxy can select another [AuthProvider] with the
+//! `AUTH_PROVIDER` env var, so that the identity established by the proxy is
+//! mapped to a [Role] which limits what the caller may do.
+//!
+//! OIDC is supported by way of the proxy: eg oauth2-proxy or an ingress
+//! performs the login flow and forwards the user and groups as headers.
+//!
+//! Every API request must identify its caller, which [protect] enforces for
+//! the whole server.  Endpoints that need more than the Viewer role check it
+//! themselves with [authorize].
+
+use std::sync::OnceLock;
+
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::Json;
+use axum::Router;
+use dioxus::fullstack::HeaderMap;
+
+use crate::api_error::ApiError;
+use crate::api_error::Failure;
+use crate::session_store;
+use crate::wallet_capabilities::WalletCapabilities;
+
+/// What an authenticated caller is permitted to do.
+///
+/// Roles are ordered, each including the permissions of those before it.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, strum::EnumString,
+)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum Role {
+    /// May view balances, history, peers, etc.
+    Viewer,
+
+    /// May also send transactions, manage UTXOs and use the app lock.
+    Spender,
+
+    /// May also export the wallet secret and manage peer standings.
+    Admin,
+}
+
+impl Role {
+    /// Limits the wallet's capabilities to those this role may use.
+    pub fn restrict(self, capabilities: WalletCapabilities) -> WalletCapabilities {
+        WalletCapabilities {
+            can_spend: capabilities.can_spend && self >= Role::Spender,
+            can_export_secret: capabilities.can_export_secret && self >= Role::Admin,
+            // the tools broadcast transactions.
+            dev_tools: capabilities.dev_tools && self >= Role::Spender,
+            can_use_app_lock: capabilities.can_use_app_lock && self >= Role::Spender,
+        }
+    }
+}
+
+/// A caller, as established by an [AuthProvider].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Identity {
+    pub user: String,
+    pub role: Role,
+}
+
+/// Establishes who made a request.
+pub trait AuthProvider: Send + Sync {
+    /// Returns the caller's identity, or an error if the request is not
+    /// authenticated.
+    fn authenticate(&self, headers: &HeaderMap) -> anyhow::Result<Identity>;
+
+    /// True if every request is let in, so that there is nothing to check
+    /// before an endpoint runs.
+    fn trusts_everyone(&self) -> bool {
+        false
+    }
+}
+
+/// Trusts every request with full permissions.  The default.
+pub struct NoAuth;
+
+impl AuthProvider for NoAuth {
+    fn authenticate(&self, _headers: &HeaderMap) -> anyhow::Result<Identity> {
+        Ok(Identity {
+            user: "local".to_string(),
+            role: Role::Admin,
+        })
+    }
+
+    fn trusts_everyone(&self) -> bool {
+        true
+    }
+}
+
+/// Trusts user and group headers set by an authenticating reverse proxy.
+///
+/// The proxy must strip these headers from incoming requests, and
+/// neptune-proton must not be reachable except through the proxy, or anyone
+/// could claim any identity.
+pub struct TrustedHeaders {
+    pub user_header: String,
+    pub groups_header: String,
+    pub admin_groups: Vec<String>,
+    pub spender_groups: Vec<String>,
+    pub default_role: Role,
+}
+
+impl TrustedHeaders {
+    fn from_env() -> anyhow::Result<Self> {
+        let var = |name: &str, default: &str| {
+            std::env::var(name).unwrap_or_else(|_| default.to_string())
+        };
+        let list = |name: &str| -> Vec<String> {
+            var(name, "")
+                .split(',')
+                .map(|g| g.trim().to_string())
+                .filter(|g| !g.is_empty())
+                .collect()
+        };
+
+        let default_role = var("AUTH_DEFAULT_ROLE", "viewer");
+        let default_role = default_role
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid AUTH_DEFAULT_ROLE: {}", default_role))?;
+
+        Ok(Self {
+            user_header: var("AUTH_USER_HEADER", "X-Forwarded-User"),
+            groups_header: var("AUTH_GROUPS_HEADER", "X-Forwarded-Groups"),
+            admin_groups: list("AUTH_ADMIN_GROUPS"),
+            spender_groups: list("AUTH_SPENDER_GROUPS"),
+            default_role,
+        })
+    }
+
+    fn role_for(&self, groups: &[&str]) -> Role {
+        let in_any = |wanted: &[String]| groups.iter().any(|g| wanted.iter().any(|w| w == g));
+
+        if in_any(&self.admin_groups) {
+            Role::Admin
+        } else if in_any(&self.spender_groups) {
+            Role::Spender
+        } else {
+            self.default_role
+        }
+    }
+}
+
+impl AuthProvider for TrustedHeaders {
+    fn authenticate(&self, headers: &HeaderMap) -> anyhow::Result<Identity> {
+        let user = headers
+            .get(self.user_header.as_str())
+            .and_then(|v| v.to_str().ok())
+            .map(str::trim)
+            .filter(|u| !u.is_empty())
+            .ok_or_else(|| Failure::AuthFailed("Not authenticated.".to_string()))?;
+
+        // groups may be sent as one comma-separated header or repeated headers.
+        let groups: Vec<&str> = headers
+            .get_all(self.groups_header.as_str())
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .map(str::trim)
+            .filter(|g| !g.is_empty())
+            .collect();
+
+        Ok(Identity {
+            user: user.to_string(),
+            role: self.role_for(&groups),
+        })
+    }
+}
+
+/// Returns the provider selected by the `AUTH_PROVIDER` env var.
+///
+/// Panics on an invalid configuration, as continuing would risk serving the
+/// wallet with less protection than the operator intended.
+fn provider() -> &'static dyn AuthProvider {
+    static PROVIDER: OnceLock<Box<dyn AuthProvider>> = OnceLock::new();
+
+    PROVIDER
+        .get_or_init(|| {
+            let name = std::env::var("AUTH_PROVIDER").unwrap_or_default();
+            match name.to_ascii_lowercase().as_str() {
+                "" | "none" => Box::new(NoAuth),
+                "trusted-header" => Box::new(
+                    TrustedHeaders::from_env()
+                        .unwrap_or_else(|e| panic!("AUTH_PROVIDER=trusted-header: {}", e)),
+                ),
+                other => panic!("unknown AUTH_PROVIDER: {}", other),
+            }
+        })
+        .as_ref()
+}
+
+/// Returns the caller's identity, and records the activity of its session.
+///
+/// Fails if the session was revoked.
+pub async fn identify(headers: &HeaderMap) -> anyhow::Result<Identity> {
+    let identity = provider().authenticate(headers)?;
+    session_store::touch(&identity.user, headers).await?;
+    Ok(identity)
+}
+
+/// Returns the caller's identity if its role is at least `required`.
+pub async fn authorize(headers: &HeaderMap, required: Role) -> anyhow::Result<Identity> {
+    let identity = identify(headers).await?;
+    permit(&identity, required)?;
+    Ok(identity)
+}
+
+/// Fails unless `identity`'s role is at least `required`.
+fn permit(identity: &Identity, required: Role) -> anyhow::Result<()> {
+    if identity.role < required {
+        anyhow::bail!(Failure::AuthFailed(format!(
+            "User '{}' ({}) is not permitted to do this; {} role required.",
+            identity.user,
+            identity.role,
+            required
+        )));
+    }
+    Ok(())
+}
+
+/// Refuses API requests whose caller cannot be identified, or whose session
+/// was revoked, before they reach an endpoint.
+async fn require_identity(request: Request, next: Next) -> Response {
+    if request.uri().path().starts_with("/api/") && !provider().trusts_everyone() {
+        if let Err(e) = identify(request.headers()).await {
+            dioxus_logger::tracing::info!(
+                path = %request.uri().path(),
+                error = %e,
+                "request refused"
+            );
+            let error = ApiError::AuthFailed(e.to_string());
+            return (StatusCode::UNAUTHORIZED, Json(error)).into_response();
+        }
+    }
+    next.run(request).await
+}
+
+/// Makes the server's `router` refuse API requests from callers that cannot
+/// be identified.
+pub fn protect(router: Router) -> Router {
+    router.layer(axum::middleware::from_fn(require_identity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trusted_headers() -> TrustedHeaders {
+        TrustedHeaders {
+            user_header: "X-Forwarded-User".to_string(),
+            groups_header: "X-Forwarded-Groups".to_string(),
+            admin_groups: vec!["admins".to_string()],
+            spender_groups: vec!["spenders".to_string()],
+            default_role: Role::Viewer,
+        }
+    }
+
+    fn headers(user: &str, groups: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Forwarded-User", user.parse().unwrap());
+        headers.insert("X-Forwarded-Groups", groups.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn viewer_is_refused_spender_endpoints() {
+        let viewer = trusted_headers()
+            .authenticate(&headers("alice", "staff"))
+            .unwrap();
+        assert_eq!(viewer.role, Role::Viewer);
+
+        assert!(permit(&viewer, Role::Viewer).is_ok());
+        assert!(permit(&viewer, Role::Spender).is_err());
+        assert!(permit(&viewer, Role::Admin).is_err());
+    }
+
+    #[test]
+    fn roles_include_those_below() {
+        let spender = trusted_headers()
+            .authenticate(&headers("bob", "staff, spenders"))
+            .unwrap();
+        assert_eq!(spender.role, Role::Spender);
+        assert!(permit(&spender, Role::Spender).is_ok());
+        assert!(permit(&spender, Role::Admin).is_err());
+
+        let admin = trusted_headers()
+            .authenticate(&headers("carol", "admins"))
+            .unwrap();
+        assert!(permit(&admin, Role::Spender).is_ok());
+        assert!(permit(&admin, Role::Admin).is_ok());
+    }
+
+    #[test]
+    fn refusal_is_an_auth_failure() {
+        let viewer = Identity {
+            user: "alice".to_string(),
+            role: Role::Viewer,
+        };
+        let e = permit(&viewer, Role::Spender).unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<Failure>(),
+            Some(Failure::AuthFailed(_))
+        ));
+    }
+}
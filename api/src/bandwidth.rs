@@ -0,0 +1,32 @@
+//! Server-side throttling for metered connections.
+//!
+//! While the `metered_connection` pref is set, background work that uses the
+//! network is slowed down and large exports are deferred until the
+//! connection is unmetered again.
+
+use std::time::Duration;
+
+use crate::prefs::user_prefs::UserPrefs;
+use crate::prefs_store;
+
+/// How much longer intervals are while metered.
+const METERED_SLOWDOWN: u32 = 10;
+
+/// True if the stored prefs declare the connection metered.  If they cannot
+/// be read, eg before neptune-core's data directory is known, the default
+/// from the environment applies.
+pub async fn is_metered() -> bool {
+    match prefs_store::load().await {
+        Ok(prefs) => prefs.metered_connection(),
+        Err(_) => UserPrefs::default().metered_connection(),
+    }
+}
+
+/// Stretches a polling or caching interval while metered.
+pub async fn scale(interval: Duration) -> Duration {
+    if is_metered().await {
+        interval * METERED_SLOWDOWN
+    } else {
+        interval
+    }
+}
@@ -0,0 +1,68 @@
+//! Forks and reorganisations of the chain near the tip, as seen by the node.
+//!
+//! neptune-core does not report reorgs, so the server notices them by
+//! watching the digests of the latest blocks change.
+
+use serde::Deserialize;
+use serde::Serialize;
+use twenty_first::tip5::Digest;
+
+/// How long a reorg that touched the wallet's history is warned about.
+pub const RECENT_REORG_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// The node's tip switched from one branch to another.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReorgEvent {
+    /// unix time in milliseconds that the reorg was noticed.
+    pub at_ms: u64,
+
+    /// The last block both branches share.
+    pub fork_height: u64,
+
+    pub old_tip: Digest,
+    pub old_height: u64,
+    pub new_tip: Digest,
+    pub new_height: u64,
+
+    /// True if the wallet's history had entries in the blocks the reorg
+    /// replaced, so balances and confirmations may have changed.
+    pub affects_wallet: bool,
+}
+
+impl ReorgEvent {
+    /// Blocks of the old branch that were replaced.
+    pub fn depth(&self) -> u64 {
+        self.old_height.saturating_sub(self.fork_height)
+    }
+}
+
+/// A height at which the node knows more than one block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkedHeight {
+    pub height: u64,
+    /// The block on the node's current chain.
+    pub canonical: Digest,
+    /// The blocks on other branches.
+    pub others: Vec<Digest>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainForks {
+    pub tip_height: u64,
+
+    /// Heights near the tip with competing blocks, highest first.
+    pub forks: Vec<ForkedHeight>,
+
+    /// Reorgs seen since the server started, newest first.
+    pub reorgs: Vec<ReorgEvent>,
+}
+
+impl ChainForks {
+    /// The newest reorg within [RECENT_REORG_MS] of `now_ms` that touched the
+    /// wallet's history, if any.
+    pub fn recent_wallet_reorg(&self, now_ms: u64) -> Option<&ReorgEvent> {
+        self.reorgs
+            .iter()
+            .find(|r| r.affects_wallet && now_ms.saturating_sub(r.at_ms) < RECENT_REORG_MS)
+    }
+}
@@ -0,0 +1,51 @@
+//! Fiat cost basis of received funds.
+//!
+//! When funds arrive, the NPT price in every fiat currency known to the price
+//! provider is captured and stored locally. This happens regardless of the
+//! user's display preference, so that statements remain accurate without
+//! relying on historical price lookups later.
+
+use neptune_types::block_height::BlockHeight;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::timestamp::Timestamp;
+use serde::Deserialize;
+use serde::Serialize;
+use twenty_first::tip5::Digest;
+
+use crate::fiat_amount::FiatAmount;
+use crate::fiat_currency::FiatCurrency;
+use crate::price_map::PriceMap;
+
+/// The fiat prices at the time funds were received in a block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CostBasisEntry {
+    pub block_digest: Digest,
+    pub block_height: BlockHeight,
+
+    /// Timestamp of the block.
+    pub timestamp: Timestamp,
+
+    /// Net amount received in the block.
+    pub amount: NativeCurrencyAmount,
+
+    /// Price of one NPT in each fiat currency, when the receipt was detected.
+    ///
+    /// Empty while pending: the price provider could not be reached then.
+    /// Filled in once it can be, with the prices of that time.
+    pub prices: PriceMap,
+
+    /// When the prices were captured, in unix milliseconds.
+    pub captured_at_ms: u64,
+}
+
+impl CostBasisEntry {
+    /// True if the prices are yet to be captured.
+    pub fn is_pending(&self) -> bool {
+        self.prices.is_empty()
+    }
+
+    /// The price of one NPT in `currency` at receive time, if captured.
+    pub fn price(&self, currency: FiatCurrency) -> Option<FiatAmount> {
+        self.prices.get(currency)
+    }
+}
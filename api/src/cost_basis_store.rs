@@ -0,0 +1,72 @@
+//! Persists [CostBasisEntry] records as a JSON file alongside the neptune-core
+//! wallet.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::cost_basis::CostBasisEntry;
+use crate::json_store::JsonStore;
+use crate::new_receipts::Receipt;
+use crate::price_caching;
+use crate::price_map::PriceMap;
+
+static STORE: JsonStore<Vec<CostBasisEntry>> =
+    JsonStore::in_wallet_dir("neptune-proton-cost-basis.json");
+
+/// Returns all stored entries, oldest first.
+pub async fn load() -> anyhow::Result<Vec<CostBasisEntry>> {
+    STORE.load().await
+}
+
+/// Stores the current fiat prices for each receipt not already stored.
+///
+/// If the prices cannot be fetched, the receipts are stored without them,
+/// as pending, and their prices filled in by a later call.
+pub async fn capture(receipts: &[Receipt]) -> anyhow::Result<()> {
+    let stored = STORE.load().await?;
+    let is_new = |receipt: &Receipt| {
+        !stored.iter().any(|e| {
+            e.block_digest == receipt.block_digest && e.block_height == receipt.block_height
+        })
+    };
+    if !receipts.iter().any(is_new) && !stored.iter().any(CostBasisEntry::is_pending) {
+        return Ok(());
+    }
+
+    let prices = match price_caching::get_cached_fiat_prices().await {
+        Ok(p) => p,
+        Err(e) => {
+            dioxus_logger::tracing::warn!(error = %e, "could not fetch fiat prices for cost basis");
+            PriceMap::default()
+        }
+    };
+    let captured_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    let mut entries = STORE.lock().await?;
+    if !prices.is_empty() {
+        for entry in entries.iter_mut().filter(|e| e.is_pending()) {
+            entry.prices = prices.clone();
+            entry.captured_at_ms = captured_at_ms;
+        }
+    }
+    for receipt in receipts {
+        let exists = entries.iter().any(|e| {
+            e.block_digest == receipt.block_digest && e.block_height == receipt.block_height
+        });
+        if !exists {
+            entries.push(CostBasisEntry {
+                block_digest: receipt.block_digest,
+                block_height: receipt.block_height,
+                timestamp: receipt.timestamp,
+                amount: receipt.amount,
+                prices: prices.clone(),
+                captured_at_ms,
+            });
+        }
+    }
+
+    entries.save().await
+}
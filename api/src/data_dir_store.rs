@@ -0,0 +1,44 @@
+//! Persists the neptune-core data directory that the user chose, for when the
+//! one neptune-core reports cannot be used as is, eg because this app runs in
+//! a container or sees the directory under another path.
+//!
+//! The other prefs live in that data directory, so the choice is kept in this
+//! app's own config directory instead.  The `NEPTUNE_DATA_DIR` env var
+//! overrides it.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::json_store::JsonStore;
+
+static STORE: JsonStore<StoredDataDir> = JsonStore::in_config_dir("data-directory.json");
+
+#[derive(Default, Serialize, Deserialize)]
+struct StoredDataDir {
+    #[serde(default)]
+    root: Option<PathBuf>,
+}
+
+/// Reads the `NEPTUNE_DATA_DIR` env var.
+pub fn root_from_env() -> Option<PathBuf> {
+    std::env::var_os("NEPTUNE_DATA_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The root data directory to use instead of the one neptune-core reports,
+/// if any.
+pub async fn root() -> anyhow::Result<Option<PathBuf>> {
+    if let Some(root) = root_from_env() {
+        return Ok(Some(root));
+    }
+    Ok(STORE.load().await?.root)
+}
+
+/// Stores the chosen root data directory, or forgets it if `root` is None.
+pub async fn set_root(root: Option<PathBuf>) -> anyhow::Result<()> {
+    STORE.update(|stored| stored.root = root).await?;
+    Ok(())
+}
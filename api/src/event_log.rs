@@ -0,0 +1,277 @@
+//! Append-only export of wallet events as JSON Lines.
+//!
+//! Intended for users who feed their infrastructure logs into a monitoring or
+//! SIEM system. Each event is written as a single JSON object per line to a
+//! file and/or sent as a syslog datagram.
+//!
+//! # Environment Variables
+//! - `EVENT_LOG_FILE`: path of a file to append events to.
+//! - `EVENT_LOG_SYSLOG`: `host:port` of a syslog (UDP) endpoint.
+//!
+//! If neither is set, events are discarded.
+//!
+//! While on a metered connection, syslog datagrams are held back and sent once
+//! the connection is unmetered. File writes are unaffected.
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::ToSocketAddrs;
+use std::net::UdpSocket;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+
+use crate::bandwidth;
+use crate::new_receipts::Receipt;
+
+/// The most syslog lines held back while metered. Older lines are dropped.
+const MAX_DEFERRED: usize = 10_000;
+
+/// The most events kept in memory for support bundles.
+const MAX_RECENT: usize = 500;
+
+static DEFERRED: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Recent events with their unix time in milliseconds, oldest first.  Kept
+/// whether or not export is configured.
+static RECENT: Mutex<VecDeque<(u128, WalletEvent)>> = Mutex::new(VecDeque::new());
+
+/// A wallet event suitable for export.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WalletEvent {
+    /// A transaction was created and handed to neptune-core for broadcast.
+    Send {
+        txid: String,
+        num_outputs: usize,
+        fee: String,
+    },
+    /// A transaction carrying an arbitrary announcement was broadcast from
+    /// the developer tools.
+    AnnouncementBroadcast {
+        txid: String,
+        num_elements: usize,
+        fee: String,
+    },
+    /// Funds arrived in a block.
+    Receive {
+        block_digest: String,
+        block_height: u64,
+        amount: String,
+    },
+    /// Peer standing was reset, for a single IP or for all peers.
+    PeerStandingCleared { ip: Option<IpAddr> },
+    /// A device's session was revoked in Settings.
+    SessionRevoked {
+        user: String,
+        device: String,
+        ip: Option<IpAddr>,
+        revoked_by: String,
+    },
+    /// A wallet was restored from its seed phrase, as neptune-core had none.
+    WalletImported { node_restarted: bool },
+    /// The node's tip moved more blocks away from public nodes than allowed.
+    TipDiverged {
+        local_height: u64,
+        reference_height: u64,
+    },
+    /// The node's tip moved to another branch, replacing `depth` blocks
+    /// above `fork_height`.
+    Reorg {
+        fork_height: u64,
+        depth: u64,
+        old_tip: String,
+        new_tip: String,
+        affects_wallet: bool,
+    },
+    /// A server function failed.
+    Error { context: String, message: String },
+}
+
+#[derive(Serialize)]
+struct EventLine<'a> {
+    /// unix time in milliseconds
+    ts: u128,
+    #[serde(flatten)]
+    event: &'a WalletEvent,
+}
+
+#[derive(Clone, Debug, Default)]
+struct EventLogConfig {
+    file: Option<PathBuf>,
+    syslog: Option<String>,
+}
+
+impl EventLogConfig {
+    fn from_env() -> Self {
+        Self {
+            file: std::env::var("EVENT_LOG_FILE")
+                .ok()
+                .filter(|s| !s.is_empty())
+                .map(PathBuf::from),
+            syslog: std::env::var("EVENT_LOG_SYSLOG")
+                .ok()
+                .filter(|s| !s.is_empty()),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.file.is_some() || self.syslog.is_some()
+    }
+}
+
+/// Records an event to all configured sinks.
+///
+/// Failures are logged and otherwise ignored; exporting events must never
+/// cause a wallet operation to fail.
+pub async fn record(event: WalletEvent) {
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    remember(ts, event.clone());
+
+    let config = EventLogConfig::from_env();
+    if !config.is_enabled() {
+        return;
+    }
+
+    let line = match serde_json::to_string(&EventLine { ts, event: &event }) {
+        Ok(l) => l,
+        Err(e) => {
+            dioxus_logger::tracing::warn!(error = %e, "event log: could not serialize event");
+            return;
+        }
+    };
+
+    let metered = bandwidth::is_metered().await;
+    let result = tokio::task::spawn_blocking(move || {
+        if let Some(path) = &config.file {
+            append_to_file(path, &line)?;
+        }
+        if let Some(endpoint) = &config.syslog {
+            if metered {
+                defer(line);
+            } else {
+                send_deferred(endpoint)?;
+                send_syslog(endpoint, &line)?;
+            }
+        }
+        Ok::<_, std::io::Error>(())
+    })
+    .await;
+    if let Ok(Err(e)) = result {
+        dioxus_logger::tracing::warn!(error = %e, "event log: write failed");
+    }
+}
+
+/// Sends any syslog lines held back while the connection was metered.
+pub async fn flush_deferred() {
+    let Some(endpoint) = EventLogConfig::from_env().syslog else {
+        return;
+    };
+    let result = tokio::task::spawn_blocking(move || send_deferred(&endpoint)).await;
+    if let Ok(Err(e)) = result {
+        dioxus_logger::tracing::warn!(error = %e, "event log: flushing deferred events failed");
+    }
+}
+
+/// Records an [`WalletEvent::Error`] for a failed server function.
+pub async fn record_error(context: &str, error: &anyhow::Error) {
+    record(WalletEvent::Error {
+        context: context.to_string(),
+        message: error.to_string(),
+    })
+    .await
+}
+
+/// Records a [`WalletEvent::Receive`] for each receipt.
+pub async fn record_receipts(receipts: &[Receipt]) {
+    for receipt in receipts {
+        record(WalletEvent::Receive {
+            block_digest: receipt.block_digest.to_hex(),
+            block_height: receipt.block_height.into(),
+            amount: receipt.amount.to_string(),
+        })
+        .await;
+    }
+}
+
+/// Returns the events recorded since the server started, up to the most
+/// recent [MAX_RECENT], oldest first.
+pub fn recent() -> Vec<(u128, WalletEvent)> {
+    RECENT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .cloned()
+        .collect()
+}
+
+fn remember(ts: u128, event: WalletEvent) {
+    let mut recent = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+    if recent.len() >= MAX_RECENT {
+        recent.pop_front();
+    }
+    recent.push_back((ts, event));
+}
+
+fn append_to_file(path: &PathBuf, line: &str) -> std::io::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(file, "{}", line)
+}
+
+fn send_syslog(endpoint: &str, line: &str) -> std::io::Result<()> {
+    // RFC 5424 header. facility=user(1), severity=info(6) => pri 14.
+    let message = format!("<14>1 - - neptune-proton - - - {}", line);
+    let target = endpoint.to_socket_addrs()?.next().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("could not resolve {}", endpoint),
+        )
+    })?;
+    // the socket must be of the target's address family.
+    let unspecified: IpAddr = if target.is_ipv4() {
+        Ipv4Addr::UNSPECIFIED.into()
+    } else {
+        Ipv6Addr::UNSPECIFIED.into()
+    };
+    let socket = UdpSocket::bind((unspecified, 0))?;
+    socket.send_to(message.as_bytes(), target)?;
+    Ok(())
+}
+
+fn defer(line: String) {
+    let mut deferred = DEFERRED.lock().unwrap_or_else(|e| e.into_inner());
+    if deferred.len() >= MAX_DEFERRED {
+        deferred.pop_front();
+    }
+    deferred.push_back(line);
+}
+
+/// Sends the held back syslog lines, oldest first.  On failure the unsent
+/// lines are held back again, ahead of any deferred meanwhile.
+fn send_deferred(endpoint: &str) -> std::io::Result<()> {
+    let mut lines = std::mem::take(&mut *DEFERRED.lock().unwrap_or_else(|e| e.into_inner()));
+    while let Some(line) = lines.pop_front() {
+        if let Err(e) = send_syslog(endpoint, &line) {
+            lines.push_front(line);
+            let mut deferred = DEFERRED.lock().unwrap_or_else(|e| e.into_inner());
+            lines.append(&mut deferred);
+            let excess = lines.len().saturating_sub(MAX_DEFERRED);
+            lines.drain(..excess);
+            *deferred = lines;
+            return Err(e);
+        }
+    }
+    Ok(())
+}
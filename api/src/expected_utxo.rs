@@ -0,0 +1,24 @@
+//! Payments the wallet expects but has not yet received in a block.
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// An incoming transaction waiting in the mempool.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedUtxo {
+    pub txid: TransactionKernelId,
+    pub amount: NativeCurrencyAmount,
+    /// False while the transaction's proof is behind the tip, ie it cannot be
+    /// mined until it is updated.
+    pub synced: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedUtxos {
+    pub in_mempool: Vec<ExpectedUtxo>,
+    /// UTXOs the wallet was notified of, eg off-chain, but has not received.
+    /// An estimate, as neptune-core reports only their number.
+    pub unclaimed: u64,
+}
@@ -8,6 +8,8 @@ use num_traits::CheckedAdd;
 use thiserror::Error;
 
 use crate::fiat_currency::FiatCurrency;
+use crate::fiat_currency::SymbolPlacement;
+use crate::number_format::NumberFormat;
 
 /// An error that can occur when parsing a string into a `FiatAmount`.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -158,6 +160,25 @@ impl FiatAmount {
     pub fn to_string_with_code(&self) -> String {
         format!("{} {}", self, self.currency.code())
     }
+
+    /// Formats the amount with its currency symbol, grouped and placed as is
+    /// customary for the locale (e.g., "$1,234.56" or "1.234,56 €").
+    ///
+    /// Separated from the number by a no-break space, if at all.
+    pub fn to_string_localized(&self, format: &NumberFormat) -> String {
+        let number = format.fiat(self);
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", number.as_str()),
+        };
+        let symbol = self.currency.symbol();
+
+        match self.currency.symbol_placement(format.language) {
+            SymbolPlacement::Before => format!("{}{}{}", sign, symbol, number),
+            SymbolPlacement::BeforeSpaced => format!("{}{}\u{a0}{}", sign, symbol, number),
+            SymbolPlacement::AfterSpaced => format!("{}{}\u{a0}{}", sign, number, symbol),
+        }
+    }
 }
 
 /// Implements the default `Display` trait to format the amount as a numeric string (e.g., "25.34").
@@ -3,6 +3,21 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::prefs::language::Language;
+
+/// Where a currency symbol goes relative to the number.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SymbolPlacement {
+    /// `$1,234.56`
+    Before,
+
+    /// `CHF 1,234.56`
+    BeforeSpaced,
+
+    /// `1.234,56 €`
+    AfterSpaced,
+}
+
 /// Represents a fiat currency, containing its code, symbol, and formatting rules.
 #[derive(
     Debug,
@@ -185,6 +200,28 @@ impl FiatCurrency {
         }
     }
 
+    /// Where the symbol is placed when displaying amounts in `language`.
+    ///
+    /// English puts symbols first, spaced only if they are letters, eg
+    /// "$1.00" but "CHF 1.00".  German and Spanish put them last, eg "1,00 €".
+    pub fn symbol_placement(&self, language: Language) -> SymbolPlacement {
+        match language {
+            Language::English => {
+                let is_alphabetic = self
+                    .symbol()
+                    .chars()
+                    .last()
+                    .is_some_and(|c| c.is_alphabetic());
+                if is_alphabetic {
+                    SymbolPlacement::BeforeSpaced
+                } else {
+                    SymbolPlacement::Before
+                }
+            }
+            Language::Spanish | Language::German => SymbolPlacement::AfterSpaced,
+        }
+    }
+
     pub fn format_amount(&self, amt: &str) -> String {
         format!("{} {}", amt, self.code())
     }
@@ -0,0 +1,88 @@
+//! Resolves IP addresses with a local MaxMind-format GeoIP database, eg
+//! GeoLite2-Country or GeoLite2-City, whose path is given by the
+//! `GEOIP_DATABASE` env var.  No lookups are made without it.
+
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use maxminddb::geoip2;
+use maxminddb::Reader;
+
+use crate::peer_geo::PeerLocation;
+
+struct Database {
+    file_name: String,
+    reader: Reader<Vec<u8>>,
+}
+
+/// Opens the database once.  A missing or unreadable file is logged and
+/// treated as no database.
+fn database() -> Option<&'static Database> {
+    static DATABASE: OnceLock<Option<Database>> = OnceLock::new();
+
+    DATABASE
+        .get_or_init(|| {
+            let path = std::env::var("GEOIP_DATABASE").ok()?;
+            let path = Path::new(&path);
+            match Reader::open_readfile(path) {
+                Ok(reader) => Some(Database {
+                    file_name: path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_default(),
+                    reader,
+                }),
+                Err(e) => {
+                    dioxus_logger::tracing::warn!(
+                        path = %path.display(),
+                        error = %e,
+                        "could not open GEOIP_DATABASE"
+                    );
+                    None
+                }
+            }
+        })
+        .as_ref()
+}
+
+/// File name of the database in use, if any.
+pub fn database_name() -> Option<String> {
+    database().map(|db| db.file_name.clone())
+}
+
+/// Locates the peer at `address`.  Fields the database lacks are None.
+pub fn locate(address: SocketAddr) -> PeerLocation {
+    let mut location = PeerLocation::unknown(address);
+    let Some(db) = database() else {
+        return location;
+    };
+
+    // City records hold a superset of Country records, so this reads both.
+    let Ok(city) = db.reader.lookup::<geoip2::City>(canonical_ip(address)) else {
+        return location;
+    };
+    if let Some(country) = city.country {
+        location.country_code = country.iso_code.map(str::to_string);
+        location.country_name = country
+            .names
+            .and_then(|names| names.get("en").map(|n| n.to_string()));
+    }
+    if let Some(loc) = city.location {
+        location.latitude = loc.latitude;
+        location.longitude = loc.longitude;
+    }
+    location
+}
+
+/// Maps IPv4-mapped IPv6 addresses to IPv4, as the database keys them so.
+fn canonical_ip(address: SocketAddr) -> IpAddr {
+    match address.ip() {
+        IpAddr::V6(v6) => v6
+            .to_ipv4_mapped()
+            .map(IpAddr::V4)
+            .unwrap_or(IpAddr::V6(v6)),
+        ip => ip,
+    }
+}
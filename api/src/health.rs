@@ -0,0 +1,69 @@
+//! A self-test of everything the wallet depends on, for the Health Check
+//! screen and the onboarding.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::onboarding::RpcConnectionTest;
+
+/// What is checked, in the order the checks run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, strum::EnumIter)]
+pub enum HealthCheckKind {
+    /// neptune-core answers on its RPC port.
+    RpcConnection,
+    /// The RPC cookie can be read, and neptune-core accepts the token made
+    /// from it.
+    AuthToken,
+    /// neptune-core's data directory holds the wallet, and this app can write
+    /// its own files beside it.
+    DataDirectory,
+    /// At least one price provider answers, if fiat values are shown.
+    PriceProviders,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HealthStatus {
+    Ok,
+    /// Works, but not fully, eg a price provider is failing over.
+    Warning,
+    Failed,
+    /// Not run, because an earlier check failed or it does not apply.
+    Skipped,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthCheck {
+    pub kind: HealthCheckKind,
+    pub status: HealthStatus,
+    /// What was found, or what went wrong.  Untranslated.
+    pub detail: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// unix time in milliseconds that the checks ran.
+    pub checked_at_ms: u64,
+    /// The outcome of connecting to neptune-core, in the detail the
+    /// onboarding needs.
+    pub connection: RpcConnectionTest,
+    /// One per [HealthCheckKind], in order.
+    pub checks: Vec<HealthCheck>,
+}
+
+impl HealthReport {
+    pub fn check(&self, kind: HealthCheckKind) -> Option<&HealthCheck> {
+        self.checks.iter().find(|c| c.kind == kind)
+    }
+
+    /// True if no check failed.  Warnings and skipped checks do not count.
+    pub fn is_healthy(&self) -> bool {
+        self.checks.iter().all(|c| c.status != HealthStatus::Failed)
+    }
+
+    /// True if the wallet can talk to neptune-core.
+    pub fn is_connected(&self) -> bool {
+        [HealthCheckKind::RpcConnection, HealthCheckKind::AuthToken]
+            .into_iter()
+            .all(|kind| self.check(kind).map(|c| c.status) == Some(HealthStatus::Ok))
+    }
+}
@@ -0,0 +1,229 @@
+//! Runs the checks of the Health Check screen.
+//!
+//! Each check reports its own outcome rather than failing the whole run, so
+//! that the report is useful precisely when things are broken.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+
+use crate::api_error::ApiError;
+use crate::health::HealthCheck;
+use crate::health::HealthCheckKind;
+use crate::health::HealthReport;
+use crate::health::HealthStatus;
+use crate::neptune_rpc;
+use crate::onboarding::RpcConnectionTest;
+use crate::prefs::display_preference::DisplayPreference;
+use crate::prefs_store;
+use crate::price_caching;
+use crate::rpc_connection;
+
+/// Written and removed again to test that a directory is writable.
+const PROBE_FILE_NAME: &str = ".neptune-proton-write-test";
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+fn outcome(kind: HealthCheckKind, status: HealthStatus, detail: impl Into<String>) -> HealthCheck {
+    HealthCheck {
+        kind,
+        status,
+        detail: detail.into(),
+    }
+}
+
+pub async fn run() -> HealthReport {
+    let connection = match test_connection().await {
+        Ok(connection) => connection,
+        Err(e) => RpcConnectionTest::Unreachable {
+            port: neptune_rpc::neptune_core_rpc_port(),
+            error: ApiError::from(e).to_string(),
+        },
+    };
+    let checks = vec![
+        check_rpc_connection(&connection),
+        check_auth_token(&connection).await,
+        check_data_directory(&connection).await,
+        check_price_providers().await,
+    ];
+    HealthReport {
+        checked_at_ms: now_ms(),
+        connection,
+        checks,
+    }
+}
+
+/// Connects to neptune-core afresh and checks that its cookie can be read.
+pub async fn test_connection() -> anyhow::Result<RpcConnectionTest> {
+    // unauthenticated, and not cached, unlike the network.
+    if let Err(e) = neptune_rpc::cookie_hint().await {
+        return Ok(RpcConnectionTest::Unreachable {
+            port: neptune_rpc::neptune_core_rpc_port(),
+            error: ApiError::from(e).to_string(),
+        });
+    }
+    let network = neptune_rpc::network().await?;
+
+    // reads the cookie again, in case the data directory just changed.
+    rpc_connection::invalidate_token();
+    Ok(match neptune_rpc::get_token().await {
+        Ok(_) => RpcConnectionTest::Connected { network },
+        Err(e) => RpcConnectionTest::CookieUnavailable {
+            network,
+            error: ApiError::from(e).to_string(),
+        },
+    })
+}
+
+fn check_rpc_connection(connection: &RpcConnectionTest) -> HealthCheck {
+    let kind = HealthCheckKind::RpcConnection;
+    match connection {
+        RpcConnectionTest::Unreachable { port, error } => outcome(
+            kind,
+            HealthStatus::Failed,
+            format!("Nothing answered on port {port}: {error}"),
+        ),
+        RpcConnectionTest::CookieUnavailable { network, .. }
+        | RpcConnectionTest::Connected { network } => outcome(
+            kind,
+            HealthStatus::Ok,
+            format!(
+                "neptune-core ({network}) answered on port {}.",
+                neptune_rpc::neptune_core_rpc_port()
+            ),
+        ),
+    }
+}
+
+/// Makes an authenticated call, as reading the cookie does not prove that
+/// neptune-core accepts it.
+async fn check_auth_token(connection: &RpcConnectionTest) -> HealthCheck {
+    let kind = HealthCheckKind::AuthToken;
+    match connection {
+        RpcConnectionTest::Unreachable { .. } => {
+            outcome(kind, HealthStatus::Skipped, "neptune-core is unreachable.")
+        }
+        RpcConnectionTest::CookieUnavailable { error, .. } => {
+            outcome(kind, HealthStatus::Failed, error.clone())
+        }
+        RpcConnectionTest::Connected { .. } => match tip_height().await {
+            Ok(height) => outcome(
+                kind,
+                HealthStatus::Ok,
+                format!("neptune-core accepted the token, at block {height}."),
+            ),
+            Err(e) => outcome(kind, HealthStatus::Failed, ApiError::from(e).to_string()),
+        },
+    }
+}
+
+async fn tip_height() -> anyhow::Result<u64> {
+    let client = &neptune_rpc::rpc_client().await?;
+    neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .block_height(neptune_rpc::call_context("block_height"), token)
+            .await??
+            .into())
+    })
+    .await
+}
+
+/// The wallet directory must be writable, since this app keeps its notes
+/// and annotations there.  This app's own config directory, which holds the
+/// prefs and the chosen data directory, only warns.
+async fn check_data_directory(connection: &RpcConnectionTest) -> HealthCheck {
+    let kind = HealthCheckKind::DataDirectory;
+    if matches!(connection, RpcConnectionTest::Unreachable { .. }) {
+        return outcome(
+            kind,
+            HealthStatus::Skipped,
+            "neptune-core must answer to tell where its data directory is.",
+        );
+    }
+    let wallet_dir = match neptune_rpc::data_directory().await {
+        Ok(data_dir) => data_dir.wallet_directory_path(),
+        Err(e) => return outcome(kind, HealthStatus::Failed, ApiError::from(e).to_string()),
+    };
+    let config_dir = directories::ProjectDirs::from("org", "neptune", "neptune-proton")
+        .map(|dirs| dirs.config_dir().to_path_buf());
+
+    let result = tokio::task::spawn_blocking(move || {
+        probe_writable(&wallet_dir, false)?;
+        let config_error = config_dir.and_then(|dir| probe_writable(&dir, true).err());
+        anyhow::Ok((wallet_dir, config_error))
+    })
+    .await;
+    match result {
+        Ok(Ok((wallet_dir, None))) => outcome(
+            kind,
+            HealthStatus::Ok,
+            format!("{} is readable and writable.", wallet_dir.display()),
+        ),
+        Ok(Ok((_, Some(config_error)))) => {
+            outcome(kind, HealthStatus::Warning, config_error.to_string())
+        }
+        Ok(Err(e)) => outcome(kind, HealthStatus::Failed, e.to_string()),
+        Err(e) => outcome(kind, HealthStatus::Failed, e.to_string()),
+    }
+}
+
+/// Checks that `dir` is a directory this process can list and write to,
+/// creating it first if `create` is set.
+fn probe_writable(dir: &Path, create: bool) -> anyhow::Result<()> {
+    if create {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Could not create {}", dir.display()))?;
+    }
+    if !dir.is_dir() {
+        anyhow::bail!("{} does not exist.", dir.display());
+    }
+    std::fs::read_dir(dir).with_context(|| format!("Could not read {}", dir.display()))?;
+    let probe: PathBuf = dir.join(PROBE_FILE_NAME);
+    std::fs::write(&probe, b"").with_context(|| format!("Could not write to {}", dir.display()))?;
+    std::fs::remove_file(&probe)
+        .with_context(|| format!("Could not remove {}", probe.display()))?;
+    Ok(())
+}
+
+/// Prices are optional, so a failure here only warns.
+async fn check_price_providers() -> HealthCheck {
+    let kind = HealthCheckKind::PriceProviders;
+    let prefs = prefs_store::load().await.unwrap_or_default();
+    if matches!(prefs.display_preference(), DisplayPreference::NptOnly) {
+        return outcome(kind, HealthStatus::Skipped, "Fiat values are turned off.");
+    }
+    if let Err(e) = price_caching::get_cached_fiat_prices().await {
+        return outcome(kind, HealthStatus::Warning, e.to_string());
+    }
+    let failing: Vec<String> = price_caching::provider_status()
+        .into_iter()
+        .filter(|status| status.consecutive_failures > 0)
+        .map(|status| {
+            format!(
+                "{:?}: {}",
+                status.kind,
+                status.last_error.unwrap_or_default()
+            )
+        })
+        .collect();
+    if failing.is_empty() {
+        outcome(kind, HealthStatus::Ok, "Prices are available.")
+    } else {
+        outcome(
+            kind,
+            HealthStatus::Warning,
+            format!(
+                "Prices are available, but some providers are failing. {}",
+                failing.join("; ")
+            ),
+        )
+    }
+}
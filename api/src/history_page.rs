@@ -0,0 +1,93 @@
+//! The wallet history a page at a time, with one entry per block, so that
+//! wallets with long histories need not load all of it to show some.
+
+use neptune_types::block_height::BlockHeight;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::timestamp::Timestamp;
+use num_traits::Zero;
+use serde::Deserialize;
+use serde::Serialize;
+use twenty_first::tip5::Digest;
+
+/// A block and the wallet's net change in it.
+pub type HistoryRow = (Digest, BlockHeight, Timestamp, NativeCurrencyAmount);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HistorySortColumn {
+    #[default]
+    Date,
+    /// Receipts before sends.
+    Type,
+    Amount,
+    Block,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistorySort {
+    pub column: HistorySortColumn,
+    pub descending: bool,
+}
+
+impl Default for HistorySort {
+    /// Newest first.
+    fn default() -> Self {
+        Self {
+            column: HistorySortColumn::Date,
+            descending: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryPage {
+    /// Each row with its position in the whole history, oldest first, which
+    /// identifies it across pages and sort orders.
+    pub rows: Vec<(usize, HistoryRow)>,
+    /// The number of rows in the whole history.
+    pub total: usize,
+}
+
+/// Sums the entries of each block, as listed by neptune-core's `history`,
+/// newest block first.
+pub fn by_block(history: &[HistoryRow]) -> Vec<HistoryRow> {
+    let mut rows: Vec<HistoryRow> = vec![];
+    for &(digest, height, timestamp, amount) in history.iter().rev() {
+        match rows.last_mut() {
+            Some(last) if last.0 == digest && last.1 == height && last.2 == timestamp => {
+                last.3 = last.3 + amount;
+            }
+            _ => rows.push((digest, height, timestamp, amount)),
+        }
+    }
+    rows
+}
+
+/// Sorts `rows`, as returned by [by_block], and returns the `limit` rows from
+/// `offset`.
+pub fn page(rows: Vec<HistoryRow>, offset: usize, limit: usize, sort: HistorySort) -> HistoryPage {
+    let total = rows.len();
+    let mut rows: Vec<(usize, HistoryRow)> = rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| (total - 1 - i, row))
+        .collect();
+
+    let is_send = |amount: &NativeCurrencyAmount| *amount <= NativeCurrencyAmount::zero();
+    rows.sort_by(|(_, a), (_, b)| {
+        let ordering = match sort.column {
+            HistorySortColumn::Date => a.2.cmp(&b.2),
+            HistorySortColumn::Type => is_send(&a.3).cmp(&is_send(&b.3)),
+            HistorySortColumn::Amount => a.3.cmp(&b.3),
+            HistorySortColumn::Block => a.1.cmp(&b.1),
+        };
+        if sort.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    HistoryPage {
+        rows: rows.into_iter().skip(offset).take(limit).collect(),
+        total,
+    }
+}
@@ -0,0 +1,196 @@
+//! A value persisted as a JSON file, shared by the `*_store` modules.
+//!
+//! A missing file reads as the value's default.  Writes go to a temporary
+//! file that is synced and then renamed over the old one, so that a crash
+//! mid-write leaves the previous contents intact rather than a truncated
+//! file.
+
+use std::io::Write;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tokio::sync::MutexGuard;
+
+use crate::neptune_rpc;
+
+/// Where a [JsonStore] keeps its file.
+enum Dir {
+    /// Alongside the neptune-core wallet, so that the data follows it.
+    Wallet,
+    /// This app's own config directory, for what is needed before
+    /// neptune-core's data directory is known.
+    Config,
+}
+
+pub struct JsonStore<T> {
+    file_name: &'static str,
+    dir: Dir,
+    pretty: bool,
+    /// serializes read-modify-write cycles.
+    lock: Mutex<()>,
+    value: PhantomData<fn() -> T>,
+}
+
+/// The stored value, read while holding the store's lock.  Changes are
+/// written by [Locked::save].
+pub struct Locked<'a, T> {
+    _guard: MutexGuard<'a, ()>,
+    path: PathBuf,
+    pretty: bool,
+    value: T,
+}
+
+impl<T> JsonStore<T> {
+    /// A store for `file_name` in neptune-core's wallet directory.
+    pub const fn in_wallet_dir(file_name: &'static str) -> Self {
+        Self::new(file_name, Dir::Wallet)
+    }
+
+    /// A store for `file_name` in this app's config directory.
+    pub const fn in_config_dir(file_name: &'static str) -> Self {
+        Self::new(file_name, Dir::Config)
+    }
+
+    const fn new(file_name: &'static str, dir: Dir) -> Self {
+        Self {
+            file_name,
+            dir,
+            pretty: true,
+            lock: Mutex::const_new(()),
+            value: PhantomData,
+        }
+    }
+
+    /// Writes without indentation, for large files not meant to be read by
+    /// people.
+    pub const fn compact(mut self) -> Self {
+        self.pretty = false;
+        self
+    }
+
+    async fn path(&self) -> anyhow::Result<PathBuf> {
+        let dir = match self.dir {
+            Dir::Wallet => neptune_rpc::data_directory().await?.wallet_directory_path(),
+            Dir::Config => directories::ProjectDirs::from("org", "neptune", "neptune-proton")
+                .context("Could not find this app's config directory")?
+                .config_dir()
+                .to_path_buf(),
+        };
+        Ok(dir.join(self.file_name))
+    }
+}
+
+impl<T> JsonStore<T>
+where
+    T: Serialize + DeserializeOwned + Default + Send + 'static,
+{
+    /// Takes the store's lock and reads the stored value, for changes that
+    /// depend on it.
+    pub async fn lock(&self) -> anyhow::Result<Locked<'_, T>> {
+        let guard = self.lock.lock().await;
+        let path = self.path().await?;
+        let value = read(path.clone()).await?;
+        Ok(Locked {
+            _guard: guard,
+            path,
+            pretty: self.pretty,
+            value,
+        })
+    }
+
+    /// Returns the stored value.
+    pub async fn load(&self) -> anyhow::Result<T> {
+        Ok(self.lock().await?.value)
+    }
+
+    /// Replaces the stored value.
+    pub async fn save(&self, value: &T) -> anyhow::Result<()> {
+        let _guard = self.lock.lock().await;
+        write(self.path().await?, value, self.pretty).await
+    }
+
+    /// Applies `f` to the stored value and saves the result.
+    pub async fn update(&self, f: impl FnOnce(&mut T)) -> anyhow::Result<T> {
+        let mut locked = self.lock().await?;
+        f(&mut locked);
+        locked.save().await?;
+        Ok(locked.value)
+    }
+}
+
+impl<T: Serialize> Locked<'_, T> {
+    /// Writes the value as changed so far.  The lock is kept.
+    pub async fn save(&self) -> anyhow::Result<()> {
+        write(self.path.clone(), &self.value, self.pretty).await
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Locked<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Locked<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+async fn read<T>(path: PathBuf) -> anyhow::Result<T>
+where
+    T: DeserializeOwned + Default + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        if !path.exists() {
+            return Ok(T::default());
+        }
+        let json = std::fs::read_to_string(&path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Could not parse {}", path.display()))
+    })
+    .await?
+}
+
+async fn write<T: Serialize>(path: PathBuf, value: &T, pretty: bool) -> anyhow::Result<()> {
+    let json = if pretty {
+        serde_json::to_string_pretty(value)?
+    } else {
+        serde_json::to_string(value)?
+    };
+    tokio::task::spawn_blocking(move || {
+        write_replacing(&path, json.as_bytes())
+            .with_context(|| format!("Could not write {}", path.display()))
+    })
+    .await?
+}
+
+/// Writes `contents` to a temporary file beside `path`, then renames it to
+/// `path`, which is replaced as a whole or not at all.
+fn write_replacing(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let mut file = std::fs::File::create(&temp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+    std::fs::rename(&temp_path, path)
+}
@@ -1,15 +1,137 @@
 //! This crate contains all shared fullstack server functions.
 
+pub mod address_label;
+#[cfg(not(target_arch = "wasm32"))]
+mod address_label_store;
+pub mod address_validation;
+pub mod announcement_input;
+pub mod api_error;
+#[cfg(not(target_arch = "wasm32"))]
+mod app_lock;
+pub mod asset;
+pub mod attention;
+#[cfg(not(target_arch = "wasm32"))]
+mod auth;
+#[cfg(not(target_arch = "wasm32"))]
+mod bandwidth;
+pub mod chain_reorg;
+pub mod cost_basis;
+#[cfg(not(target_arch = "wasm32"))]
+mod cost_basis_store;
+#[cfg(not(target_arch = "wasm32"))]
+mod data_dir_store;
+#[cfg(not(target_arch = "wasm32"))]
+mod event_log;
+pub mod expected_utxo;
 pub mod fiat_amount;
 pub mod fiat_currency;
+#[cfg(not(target_arch = "wasm32"))]
+mod geoip;
+pub mod health;
+#[cfg(not(target_arch = "wasm32"))]
+mod health_checks;
+pub mod history_page;
+#[cfg(not(target_arch = "wasm32"))]
+mod json_store;
+pub mod log_entry;
+pub mod logging;
+pub mod managed_node;
+#[cfg(not(target_arch = "wasm32"))]
+mod managed_node_store;
+pub mod mempool_page;
+#[cfg(not(target_arch = "wasm32"))]
+mod nc_convert;
+#[cfg(not(target_arch = "wasm32"))]
+mod new_receipts;
+pub mod node_stats;
+#[cfg(not(target_arch = "wasm32"))]
+mod node_stats_store;
+#[cfg(not(target_arch = "wasm32"))]
+mod node_supervisor;
+pub mod node_version;
+#[cfg(not(target_arch = "wasm32"))]
+mod node_version_check;
+pub mod notification;
+#[cfg(not(target_arch = "wasm32"))]
+mod notification_store;
+#[cfg(not(target_arch = "wasm32"))]
+mod notifier;
+pub mod number_format;
+pub mod onboarding;
+#[cfg(not(target_arch = "wasm32"))]
+mod onboarding_store;
+pub mod payment_request;
+pub mod peer_geo;
 pub mod prefs;
 #[cfg(not(target_arch = "wasm32"))]
+mod prefs_store;
+#[cfg(not(target_arch = "wasm32"))]
 mod price_caching;
+#[cfg(not(target_arch = "wasm32"))]
+mod price_history;
 pub mod price_map;
 pub mod price_providers;
+pub mod reachability;
+#[cfg(not(target_arch = "wasm32"))]
+mod receipt_store;
+pub mod recent_recipient;
+#[cfg(not(target_arch = "wasm32"))]
+mod recent_recipient_store;
+#[cfg(not(target_arch = "wasm32"))]
+mod reorg_monitor;
+#[cfg(not(target_arch = "wasm32"))]
+mod rpc_cache;
 #[cfg(not(target_arch = "wasm32"))]
 mod rpc_api;
-
+#[cfg(not(target_arch = "wasm32"))]
+mod rpc_connection;
+pub mod rpc_metrics;
+#[cfg(not(target_arch = "wasm32"))]
+mod rpc_metrics_store;
+pub mod search;
+pub mod send_draft;
+#[cfg(not(target_arch = "wasm32"))]
+mod send_draft_store;
+pub mod send_job;
+#[cfg(not(target_arch = "wasm32"))]
+mod send_job_store;
+#[cfg(not(target_arch = "wasm32"))]
+mod send_monitor;
+pub mod send_progress;
+#[cfg(not(target_arch = "wasm32"))]
+mod sent_txs;
+pub mod session;
+#[cfg(not(target_arch = "wasm32"))]
+mod session_store;
+pub mod signed_tx;
+pub mod spendable_balance;
+#[cfg(not(target_arch = "wasm32"))]
+mod startup_checks;
+#[cfg(not(target_arch = "wasm32"))]
+mod support_bundle;
+#[cfg(not(target_arch = "wasm32"))]
+mod sync_monitor;
+pub mod sync_progress;
+pub mod tip_check;
+#[cfg(not(target_arch = "wasm32"))]
+mod tip_monitor;
+pub mod tx_annotation;
+#[cfg(not(target_arch = "wasm32"))]
+mod tx_annotation_store;
+pub mod tx_note;
+#[cfg(not(target_arch = "wasm32"))]
+mod tx_note_store;
+pub mod utxo_annotation;
+#[cfg(not(target_arch = "wasm32"))]
+mod utxo_annotation_store;
+pub mod utxo_breakdown;
+pub mod utxo_snapshot;
+pub mod wallet_capabilities;
+pub mod wallet_encryption;
+pub mod wallet_import;
+pub mod wallet_metadata;
+
+use std::collections::HashMap;
 use std::net::IpAddr;
 use std::net::SocketAddr;
 
@@ -36,182 +158,1726 @@ use neptune_types::wallet_file::WalletFile;
 use neptune_types::wallet_file_context::WalletFileContext;
 use neptune_types::secret_key_material::SecretKeyMaterial;
 
+use address_label::AddressLabels;
+use address_label::AddressUsages;
+use asset::AssetBalance;
+use attention::AttentionItem;
+use chain_reorg::ChainForks;
+use cost_basis::CostBasisEntry;
+use expected_utxo::ExpectedUtxo;
+use expected_utxo::ExpectedUtxos;
+use fiat_currency::FiatCurrency;
+use health::HealthReport;
+use history_page::HistoryPage;
+use history_page::HistoryRow;
+use history_page::HistorySort;
+use log_entry::LogEntry;
+use managed_node::ManagedNodeConfig;
+use managed_node::ManagedNodeStatus;
+use managed_node::NodeLogLine;
+use mempool_page::MempoolPage;
+use mempool_page::MempoolSort;
+use node_stats::NodeMetric;
+use node_stats::StatsSample;
+use node_stats::StatsWindow;
+use node_version::NodeVersion;
+use notification::Notification;
+use onboarding::OnboardingReason;
+use onboarding::OnboardingStatus;
+use onboarding::RpcConnectionTest;
+use peer_geo::PeerGeoReport;
+use prefs::amount_precision::AmountPrecision;
+use prefs::camera_resolution::CameraResolution;
+use prefs::confirmation::Confirmation;
+use prefs::data_directory::DataDirectoryChoice;
+use prefs::language::Language;
+use prefs::log_level::LogLevel;
+use prefs::refresh_intervals::RefreshIntervals;
+use prefs::refresh_intervals::RefreshScreen;
+use prefs::startup_check::StartupCheck;
 use prefs::user_prefs::UserPrefs;
 use price_map::PriceMap;
+use price_providers::PriceProviderStatus;
+use reachability::ReachabilityReport;
+use recent_recipient::RecentRecipient;
+use rpc_metrics::RpcMetrics;
+use search::SearchResult;
+use send_draft::SendDraft;
+use send_job::SendJob;
+use send_progress::SendProgress;
+use session::SessionInfo;
+use spendable_balance::SpendableBalance;
+use sync_progress::SyncProgress;
+use tip_check::TipCheck;
+use tx_annotation::TxAnnotations;
+use tx_note::TxNotes;
+use twenty_first::prelude::BFieldElement;
 use twenty_first::tip5::Digest;
+use utxo_annotation::UtxoAnnotations;
+use utxo_snapshot::SnapshotExport;
+use utxo_snapshot::SnapshotFormat;
+use wallet_capabilities::WalletCapabilities;
+use wallet_encryption::WalletEncryptionStatus;
+use wallet_import::WalletImport;
+use wallet_metadata::MetadataImportSummary;
 
-pub type ApiError = anyhow::Error;
+#[cfg(not(target_arch = "wasm32"))]
+use auth::Role;
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(not(target_arch = "wasm32"))]
+use rpc_cache::RpcCache;
+#[cfg(not(target_arch = "wasm32"))]
+use tx_annotation::TxAnnotation;
+#[cfg(not(target_arch = "wasm32"))]
+use tx_note::TxNote;
+#[cfg(not(target_arch = "wasm32"))]
+use wallet_encryption::WalletFileState;
+#[cfg(not(target_arch = "wasm32"))]
+use wallet_metadata::WalletMetadata;
+
+pub use api_error::ApiError;
+#[cfg(not(target_arch = "wasm32"))]
+pub use auth::protect;
+
+/// Like [anyhow::bail], for endpoints, which return [ApiError].
+macro_rules! bail {
+    ($($arg:tt)*) => {
+        return Err(ApiError::from(anyhow::anyhow!($($arg)*)))
+    };
+}
 
 /// Retrieves the user's preferences.
 ///
-/// In the future this may read from a settings file.  For now it just
-/// returns the default settings, which read from env vars.
+/// Most settings are read from env vars.  Those changed from within the app
+/// are read from a settings file.
 #[post("/api/get_user_prefs")]
 pub async fn get_user_prefs() -> Result<UserPrefs, ApiError> {
-    Ok(UserPrefs::default())
+    Ok(prefs_store::load().await?)
+}
+
+/// Sets whether a confirmation dialog is skipped, returning the updated list
+/// of suppressed confirmations.
+#[post("/api/set_confirmation_suppressed", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_confirmation_suppressed(
+    confirmation: Confirmation,
+    suppressed: bool,
+) -> Result<Vec<Confirmation>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_confirmation_suppressed(confirmation, suppressed).await?)
+}
+
+/// Sets whether a check runs when the app starts, returning the updated list
+/// of disabled checks.
+#[post("/api/set_startup_check_enabled", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_startup_check_enabled(
+    check: StartupCheck,
+    enabled: bool,
+) -> Result<Vec<StartupCheck>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_startup_check_enabled(check, enabled).await?)
+}
+
+/// Sets how often a screen refreshes while shown, returning the updated
+/// intervals.
+#[post("/api/set_refresh_interval", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_refresh_interval(
+    screen: RefreshScreen,
+    secs: u32,
+) -> Result<RefreshIntervals, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_refresh_interval(screen, secs).await?)
+}
+
+/// Pauses or resumes all background refreshing in the UI.
+#[post("/api/set_refresh_enabled", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_refresh_enabled(enabled: bool) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_refresh_enabled(enabled).await?)
+}
+
+/// Masks or shows amounts throughout the UI.
+#[post("/api/set_hide_amounts", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_hide_amounts(hide: bool) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_hide_amounts(hide).await?)
+}
+
+/// Sets how many decimals of NPT amounts are shown, and how the rest are
+/// dropped.
+#[post("/api/set_amount_precision", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_amount_precision(precision: AmountPrecision) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_amount_precision(precision).await?)
+}
+
+/// Remembers the address type last generated on the Receive screen.
+#[post("/api/set_receive_key_type", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_receive_key_type(key_type: KeyType) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_receive_key_type(key_type).await?)
+}
+
+/// Remembers the camera and resolution the QR scanner uses.
+#[post("/api/set_scanner_camera", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_scanner_camera(
+    device: Option<String>,
+    resolution: CameraResolution,
+) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_scanner_camera(device, resolution).await?)
+}
+
+/// The neptune-core data directory in use, and whether the user chose it.
+#[post("/api/data_directory_choice")]
+pub async fn data_directory_choice() -> Result<DataDirectoryChoice, ApiError> {
+    Ok(current_data_directory_choice().await?)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn current_data_directory_choice() -> anyhow::Result<DataDirectoryChoice> {
+    let chosen = data_dir_store::root().await?;
+    let in_use = neptune_rpc::data_directory().await?;
+    Ok(DataDirectoryChoice {
+        chosen: chosen.map(|root| root.display().to_string()),
+        from_env: data_dir_store::root_from_env().is_some(),
+        in_use: in_use.to_string(),
+    })
+}
+
+/// Uses `root` as neptune-core's data directory, as passed to its
+/// `--data-dir`, or the one neptune-core reports if None.  `root` must hold a
+/// wallet and a readable cookie.
+#[post("/api/set_data_directory", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_data_directory(root: Option<String>) -> Result<DataDirectoryChoice, ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    if data_dir_store::root_from_env().is_some() {
+        bail!("The data directory is set by the NEPTUNE_DATA_DIR env var.");
+    }
+
+    let root = root
+        .map(|root| root.trim().to_string())
+        .filter(|root| !root.is_empty())
+        .map(std::path::PathBuf::from);
+    if let Some(root) = &root {
+        neptune_rpc::check_data_directory(root.clone()).await?;
+    }
+    data_dir_store::set_root(root).await?;
+
+    // the cookie may differ in the new directory.
+    rpc_connection::invalidate_token();
+    Ok(current_data_directory_choice().await?)
+}
+
+/// Whether to show the first-run onboarding, and why.  Works without
+/// neptune-core.
+#[post("/api/onboarding_status")]
+pub async fn onboarding_status() -> Result<OnboardingStatus, ApiError> {
+    if onboarding_store::complete().await? {
+        return Ok(OnboardingStatus {
+            complete: true,
+            reason: None,
+        });
+    }
+
+    let reason = match neptune_rpc::network().await {
+        Err(e) => Some(OnboardingReason::NodeUnreachable(
+            ApiError::from(e).to_string(),
+        )),
+        // a wallet that cannot be read is left to the connection checks.
+        Ok(_) => match neptune_rpc::wallet_is_new().await {
+            Ok(true) => Some(OnboardingReason::NewWallet),
+            _ => None,
+        },
+    };
+    Ok(OnboardingStatus {
+        complete: false,
+        reason,
+    })
+}
+
+/// Connects to neptune-core afresh and checks that its cookie can be read.
+#[post("/api/test_rpc_connection")]
+pub async fn test_rpc_connection() -> Result<RpcConnectionTest, ApiError> {
+    Ok(health_checks::test_connection().await?)
+}
+
+/// Checks the connection to neptune-core, its auth token, the data directory
+/// and the price providers, reporting each outcome.  Works without
+/// neptune-core, for the onboarding.
+#[post("/api/health")]
+pub async fn health() -> Result<HealthReport, ApiError> {
+    Ok(health_checks::run().await)
+}
+
+/// Records that the user finished or skipped the onboarding.
+#[post("/api/complete_onboarding", headers: dioxus::fullstack::HeaderMap)]
+pub async fn complete_onboarding() -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(onboarding_store::set_complete().await?)
+}
+
+/// Sets the fiat currency amounts are shown in, or NPT only if `fiat` is None,
+/// returning the updated prefs.
+#[post("/api/set_fiat_currency", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_fiat_currency(fiat: Option<FiatCurrency>) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_fiat_currency(fiat).await?)
+}
+
+/// Runs the enabled startup checks, returning anything that needs the user's
+/// attention.
+#[post("/api/startup_checks")]
+pub async fn startup_checks() -> Result<Vec<AttentionItem>, ApiError> {
+    Ok(startup_checks::run_enabled().await?)
+}
+
+/// Sets the UI language, returning the updated prefs, as the decimal
+/// separator may follow the language.
+#[post("/api/set_language", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_language(language: Language) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_language(language).await?)
+}
+
+/// Sets, changes or removes (if `new` is `None`) the PIN that unlocks the
+/// app.  `current` must match the existing PIN, if any.
+#[post("/api/set_lock_pin", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_lock_pin(
+    current: Option<String>,
+    new: Option<String>,
+) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_pin(current, new).await?)
+}
+
+/// Checks a PIN entered to unlock the app.  Guesses are checked one at a
+/// time, and slowed down more with each wrong one.
+#[post("/api/verify_lock_pin", headers: dioxus::fullstack::HeaderMap)]
+pub async fn verify_lock_pin(pin: String) -> Result<bool, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::verify_pin(pin).await?)
+}
+
+/// Sets the minutes of inactivity after which the app locks, 0 for never.
+#[post("/api/set_idle_lock_minutes", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_idle_lock_minutes(minutes: u32) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_idle_lock_minutes(minutes).await?)
+}
+
+/// Sets the minutes of inactivity after which the app blurs, 0 for never.
+#[post("/api/set_idle_blur_minutes", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_idle_blur_minutes(minutes: u32) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_idle_blur_minutes(minutes).await?)
+}
+
+/// Sets the send amount above which the user must re-enter the end of each
+/// recipient address, 0 for never.
+#[post("/api/set_address_check_threshold", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_address_check_threshold(
+    threshold: NativeCurrencyAmount,
+) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(prefs_store::set_address_check_threshold(threshold).await?)
 }
 
 #[post("/api/network")]
 pub async fn network() -> Result<Network, ApiError> {
-    neptune_rpc::network().await
+    // called once when the app loads, so a convenient place to start
+    // server-side background tasks.
+    new_receipts::ensure_watching();
+    tip_monitor::ensure_watching();
+    reorg_monitor::ensure_watching();
+    node_stats_store::ensure_collecting();
+    notifier::ensure_watching();
+    node_supervisor::ensure_supervising();
+    tokio::spawn(logging::apply_saved_level());
+
+    Ok(neptune_rpc::network().await?)
 }
 
-#[post("/api/wallet_balance")]
-pub async fn wallet_balance() -> Result<NativeCurrencyAmount, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
+/// Samples of `metric` over the last `window`, oldest first.  Sampling
+/// starts with the server, and history is kept for a week.
+#[post("/api/node_stats_series")]
+pub async fn node_stats_series(
+    metric: NodeMetric,
+    window: StatsWindow,
+) -> Result<Vec<StatsSample>, ApiError> {
+    Ok(node_stats_store::series(metric, window))
+}
+
+/// All notifications, newest first.
+#[post("/api/notifications")]
+pub async fn notifications() -> Result<Vec<Notification>, ApiError> {
+    Ok(notification_store::load().await?)
+}
+
+/// Marks the notifications with the given ids as read, or all if `None`.
+#[post("/api/mark_notifications_read", headers: dioxus::fullstack::HeaderMap)]
+pub async fn mark_notifications_read(ids: Option<Vec<u64>>) -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(notification_store::mark_read(ids).await?)
+}
 
-    let balance = client
-        .confirmed_available_balance(tarpc::context::current(), token)
-        .await??;
+#[post("/api/clear_notifications", headers: dioxus::fullstack::HeaderMap)]
+pub async fn clear_notifications() -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(notification_store::clear().await?)
+}
+
+/// The latest comparison of the node's tip against the public nodes in
+/// `TIP_MONITOR_URLS`.  None if none are configured, or no check has
+/// completed yet.
+#[post("/api/tip_check")]
+pub async fn tip_check() -> Result<Option<TipCheck>, ApiError> {
+    Ok(tip_monitor::last())
+}
 
-    let json = serde_json::to_string(&balance)?;
-    dioxus_logger::tracing::info!("balance json: {}", json);
+/// The connected neptune-core's version, if known, and whether this app
+/// works with it.  Probes neptune-core, so that a node whose RPC API differs
+/// is found before its answers fail mid-use.
+#[post("/api/node_version")]
+pub async fn node_version() -> Result<NodeVersion, ApiError> {
+    Ok(node_version_check::check().await)
+}
 
-    Ok(balance)
+/// Competing blocks near the tip, and the reorgs seen since the server
+/// started, newest first.
+#[post("/api/chain_forks")]
+pub async fn chain_forks() -> Result<ChainForks, ApiError> {
+    Ok(reorg_monitor::forks().await?)
+}
+
+/// How far the node is through syncing: its height against an estimate of
+/// the network's tip, the recent sync rate, and the time left at that rate.
+#[post("/api/sync_progress")]
+pub async fn sync_progress() -> Result<SyncProgress, ApiError> {
+    Ok(sync_monitor::progress().await?)
+}
+
+/// Reports whether the connected wallet can spend, or is watch-only.
+///
+/// The result is limited to what the caller's role permits.
+#[post("/api/wallet_capabilities", headers: dioxus::fullstack::HeaderMap)]
+pub async fn wallet_capabilities() -> Result<WalletCapabilities, ApiError> {
+    let identity = auth::identify(&headers).await?;
+    Ok(identity.role.restrict(neptune_rpc::cached_wallet_capabilities().await?))
+}
+
+/// Lists the caller's sessions, most recently active first.  Admins see the
+/// sessions of all users.
+#[post("/api/sessions", headers: dioxus::fullstack::HeaderMap)]
+pub async fn sessions() -> Result<Vec<SessionInfo>, ApiError> {
+    let identity = auth::identify(&headers).await?;
+    let current_id = session_store::id_for(&identity.user, &headers);
+    let user = (identity.role < Role::Admin).then_some(identity.user.as_str());
+    Ok(session_store::list(user, &current_id).await)
+}
+
+/// Revokes a session, so that the device it belongs to may no longer use
+/// the wallet.  Returns the remaining sessions.
+///
+/// Only admins may revoke the sessions of other users.  The caller's own
+/// session cannot be revoked.
+#[post("/api/revoke_session", headers: dioxus::fullstack::HeaderMap)]
+pub async fn revoke_session(id: String) -> Result<Vec<SessionInfo>, ApiError> {
+    let identity = auth::identify(&headers).await?;
+    let current_id = session_store::id_for(&identity.user, &headers);
+    if id == current_id {
+        bail!("The current session cannot be revoked.");
+    }
+
+    let user = (identity.role < Role::Admin).then_some(identity.user.as_str());
+    let revoked = session_store::revoke(&id, user).await?;
+
+    event_log::record(event_log::WalletEvent::SessionRevoked {
+        user: revoked.user,
+        device: revoked.device,
+        ip: revoked.ip,
+        revoked_by: identity.user.clone(),
+    })
+    .await;
+    Ok(session_store::list(user, &current_id).await)
+}
+
+#[post("/api/wallet_balance")]
+pub async fn wallet_balance() -> Result<NativeCurrencyAmount, ApiError> {
+    static CACHE: RpcCache<NativeCurrencyAmount> = RpcCache::new();
+
+    Ok(CACHE
+        .get(|| async {
+            let client = &neptune_rpc::rpc_client().await?;
+            let balance = neptune_rpc::with_token(|token| async move {
+                Ok(client
+                    .confirmed_available_balance(
+                        neptune_rpc::call_context("confirmed_available_balance"),
+                        token,
+                    )
+                    .await??)
+            })
+            .await?;
+
+            let json = serde_json::to_string(&balance)?;
+            dioxus_logger::tracing::debug!(balance = %json, "wallet balance");
+
+            Ok(balance)
+        })
+        .await?)
+}
+
+/// What a send can spend now, and what of the balance it cannot.  Fetched
+/// fresh, bypassing the cache, as it gates the Send wizard.
+#[post("/api/spendable_balance")]
+pub async fn spendable_balance() -> Result<SpendableBalance, ApiError> {
+    let client = &neptune_rpc::rpc_client().await?;
+    let data = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .dashboard_overview_data(neptune_rpc::call_context("dashboard_overview_data"), token)
+            .await??)
+    })
+    .await?;
+
+    Ok(SpendableBalance::new(
+        data.confirmed_available_balance,
+        data.confirmed_total_balance,
+        data.unconfirmed_available_balance,
+    ))
 }
 
 #[post("/api/block_height")]
 pub async fn block_height() -> Result<BlockHeight, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let height = client
-        .block_height(tarpc::context::current(), token)
-        .await??;
-    Ok(height.into())
+    static CACHE: RpcCache<BlockHeight> = RpcCache::new();
+
+    Ok(CACHE
+        .get(|| async {
+            let client = &neptune_rpc::rpc_client().await?;
+            let height = neptune_rpc::with_token(|token| async move {
+                Ok(client
+                    .block_height(neptune_rpc::call_context("block_height"), token)
+                    .await??)
+            })
+            .await?;
+            Ok(height.into())
+        })
+        .await?)
 }
 
-#[post("/api/known_keys")]
+/// The wallet's spending keys, which hold secrets.  See [known_addresses] for
+/// just the addresses.
+#[post("/api/known_keys", headers: dioxus::fullstack::HeaderMap)]
 pub async fn known_keys() -> Result<Vec<SpendingKey>, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let known_keys = client
-        .known_keys(tarpc::context::current(), token)
-        .await??;
+    auth::authorize(&headers, Role::Admin).await?;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let known_keys = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .known_keys(neptune_rpc::call_context("known_keys"), token)
+            .await??)
+    })
+    .await?;
     Ok(known_keys)
 }
 
-#[post("/api/next_receiving_address")]
+/// The addresses of the wallet's keys, in derivation order.
+#[post("/api/known_addresses")]
+pub async fn known_addresses() -> Result<Vec<ReceivingAddress>, ApiError> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let known_keys = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .known_keys(neptune_rpc::call_context("known_keys"), token)
+            .await??)
+    })
+    .await?;
+    Ok(known_keys.iter().map(|key| key.to_address()).collect())
+}
+
+#[post("/api/next_receiving_address", headers: dioxus::fullstack::HeaderMap)]
 pub async fn next_receiving_address(key_type: KeyType) -> Result<ReceivingAddress, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
+    auth::authorize(&headers, Role::Spender).await?;
+    let client = &neptune_rpc::rpc_client().await?;
 
-    let address = client
-        .next_receiving_address(tarpc::context::current(), token, key_type)
-        .await??;
+    let address = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .next_receiving_address(
+                neptune_rpc::call_context("next_receiving_address"),
+                token,
+                key_type,
+            )
+            .await??)
+    })
+    .await?;
     Ok(address)
 }
 
-#[post("/api/send")]
+/// Returns the locally stored labels for receiving addresses.
+#[post("/api/address_labels")]
+pub async fn address_labels() -> Result<AddressLabels, ApiError> {
+    Ok(address_label_store::load().await?)
+}
+
+/// Sets the label of a bech32m `address`.  An empty label removes it.
+#[post("/api/set_address_label", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_address_label(address: String, label: String) -> Result<AddressLabels, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    let network = neptune_rpc::network().await?;
+    if ReceivingAddress::from_bech32m(&address, network).is_err() {
+        return Err(ApiError::InvalidAddress(format!(
+            "Not a valid {} address.",
+            network
+        )));
+    }
+    let label = label.trim().to_string();
+    Ok(address_label_store::update(|labels| {
+        labels.insert(address, label);
+    })
+    .await?)
+}
+
+/// Returns how much each of the wallet's addresses has received.
+///
+/// neptune-core does not record which address a UTXO was sent to, so this
+/// matches the announcements in each block of the wallet history against the
+/// receiver identifiers of the known keys.  UTXOs whose notification was
+/// sent off-chain are not counted.
+#[post("/api/address_usage")]
+pub async fn address_usage() -> Result<AddressUsages, ApiError> {
+    use num_traits::Zero;
+
+    let client = &neptune_rpc::rpc_client().await?;
+    let network = neptune_rpc::network().await?;
+
+    let known_keys = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .known_keys(neptune_rpc::call_context("known_keys"), token)
+            .await??)
+    })
+    .await?;
+    let mut receivers = HashMap::new();
+    for key in known_keys {
+        let address = key.to_address();
+        receivers.insert(address.receiver_identifier(), address.to_bech32m(network)?);
+    }
+
+    // funds received in each block, in history order.
+    let history = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .history(neptune_rpc::call_context("history"), token)
+            .await??)
+    })
+    .await?;
+    let mut received: Vec<(Digest, NativeCurrencyAmount)> = vec![];
+    for (digest, _, _, amount) in history {
+        if amount <= NativeCurrencyAmount::zero() {
+            continue;
+        }
+        match received.iter_mut().find(|(d, _)| *d == digest) {
+            Some((_, sum)) => *sum = *sum + amount,
+            None => received.push((digest, amount)),
+        }
+    }
+
+    let mut usages = AddressUsages::new();
+    for (digest, amount) in received {
+        let announcements = neptune_rpc::with_token(|token| async move {
+            Ok(client
+                .announcements_in_block(
+                    neptune_rpc::call_context("announcements_in_block"),
+                    token,
+                    BlockSelector::Digest(digest),
+                )
+                .await??)
+        })
+        .await?
+        .unwrap_or_default();
+
+        // announcements start with a key-type flag, then the receiver identifier.
+        let mut counts: HashMap<&String, usize> = HashMap::new();
+        for announcement in &announcements {
+            if let Some(address) = announcement
+                .message
+                .get(1)
+                .and_then(|receiver_id| receivers.get(receiver_id))
+            {
+                *counts.entry(address).or_default() += 1;
+            }
+        }
+
+        let is_shared = counts.len() > 1;
+        for (address, count) in counts {
+            let usage = usages.entry(address.clone()).or_default();
+            usage.received_count += count;
+            if is_shared {
+                usage.total_is_partial = true;
+            } else {
+                usage.total_received = usage.total_received + amount;
+            }
+        }
+    }
+    Ok(usages)
+}
+
+#[post("/api/send", headers: dioxus::fullstack::HeaderMap)]
 pub async fn send(
     outputs: Vec<OutputFormat>,
     change_policy: ChangePolicy,
     fee: NativeCurrencyAmount,
 ) -> Result<(TransactionKernelId, TransactionDetails), ApiError> {
-    neptune_rpc::send(outputs, change_policy, fee).await
+    auth::authorize(&headers, Role::Spender).await?;
+    neptune_rpc::require_spending().await?;
+
+    let do_not_spend = do_not_spend_indices().await?;
+
+    let num_outputs = outputs.len();
+    let inputs = neptune_rpc::InputSelection::Excluding(do_not_spend);
+    let result = neptune_rpc::send(outputs, change_policy, fee, None, inputs).await;
+    let txid = result.as_ref().map(|(txid, _)| *txid);
+    record_sent("send", txid, num_outputs, fee).await;
+    Ok(result?)
+}
+
+/// Like [send], but the transaction also carries `message` as a public
+/// announcement.
+#[post("/api/send_with_announcement", headers: dioxus::fullstack::HeaderMap)]
+pub async fn send_with_announcement(
+    outputs: Vec<OutputFormat>,
+    change_policy: ChangePolicy,
+    fee: NativeCurrencyAmount,
+    message: Vec<BFieldElement>,
+) -> Result<(TransactionKernelId, TransactionDetails), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    neptune_rpc::require_spending().await?;
+
+    check_announcement(&message)?;
+
+    let do_not_spend = do_not_spend_indices().await?;
+
+    let num_outputs = outputs.len();
+    let inputs = neptune_rpc::InputSelection::Excluding(do_not_spend);
+    let result = neptune_rpc::send(outputs, change_policy, fee, Some(message), inputs).await;
+    let txid = result.as_ref().map(|(txid, _)| *txid);
+    record_sent("send_with_announcement", txid, num_outputs, fee).await;
+    Ok(result?)
+}
+
+/// Like [send], but spends all of the UTXOs with the given AOCL leaf indices,
+/// as chosen by the user, rather than those neptune-core would pick.  What
+/// they hold beyond the outputs and fee is returned as change.
+///
+/// UTXOs marked do-not-spend are refused.  `announcement`, if any, is
+/// attached as by [send_with_announcement].
+#[post("/api/send_from_utxos", headers: dioxus::fullstack::HeaderMap)]
+pub async fn send_from_utxos(
+    outputs: Vec<OutputFormat>,
+    change_policy: ChangePolicy,
+    fee: NativeCurrencyAmount,
+    aocl_indices: Vec<u64>,
+    announcement: Option<Vec<BFieldElement>>,
+) -> Result<(TransactionKernelId, TransactionDetails), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    neptune_rpc::require_spending().await?;
+
+    if aocl_indices.is_empty() {
+        bail!("Select at least one UTXO to spend.");
+    }
+    if let Some(message) = &announcement {
+        check_announcement(message)?;
+    }
+
+    let annotations = utxo_annotation_store::load().await?;
+    if let Some(index) = aocl_indices
+        .iter()
+        .find(|i| annotations.get(i).is_some_and(|a| a.do_not_spend))
+    {
+        bail!("UTXO {} is marked do-not-spend.", index);
+    }
+
+    let num_outputs = outputs.len();
+    let inputs = neptune_rpc::InputSelection::Exactly(aocl_indices);
+    let result = neptune_rpc::send(outputs, change_policy, fee, announcement, inputs).await;
+    let txid = result.as_ref().map(|(txid, _)| *txid);
+    record_sent("send_from_utxos", txid, num_outputs, fee).await;
+    Ok(result?)
+}
+
+/// Records the outcome of sending a transaction with `num_outputs` outputs
+/// and `fee`: a Send event, or an Error event for `context` if it failed.
+#[cfg(not(target_arch = "wasm32"))]
+async fn record_sent(
+    context: &str,
+    result: Result<TransactionKernelId, &anyhow::Error>,
+    num_outputs: usize,
+    fee: NativeCurrencyAmount,
+) {
+    match result {
+        Ok(txid) => {
+            event_log::record(event_log::WalletEvent::Send {
+                txid: txid.to_string(),
+                num_outputs,
+                fee: fee.to_string(),
+            })
+            .await;
+        }
+        Err(e) => event_log::record_error(context, e).await,
+    }
+}
+
+/// Refuses an announcement that is empty or too large to attach.
+#[cfg(not(target_arch = "wasm32"))]
+fn check_announcement(message: &[BFieldElement]) -> Result<(), ApiError> {
+    if message.is_empty() {
+        bail!("The announcement is empty.");
+    }
+    if message.len() > announcement_input::MAX_ANNOUNCEMENT_ELEMENTS {
+        bail!(
+            "The announcement may have at most {} field elements.",
+            announcement_input::MAX_ANNOUNCEMENT_ELEMENTS
+        );
+    }
+    Ok(())
+}
+
+/// The progress of the send in flight, or None if no send is in flight.
+#[post("/api/send_progress")]
+pub async fn send_progress() -> Result<Option<SendProgress>, ApiError> {
+    Ok(send_monitor::current())
+}
+
+/// Returns the tracked sends, most recently started first.
+#[post("/api/send_jobs")]
+pub async fn send_jobs() -> Result<Vec<SendJob>, ApiError> {
+    Ok(send_job_store::load().await?)
+}
+
+/// Saves a tracked send, replacing any with the same id.  Returns all jobs.
+#[post("/api/save_send_job", headers: dioxus::fullstack::HeaderMap)]
+pub async fn save_send_job(job: SendJob) -> Result<Vec<SendJob>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(send_job_store::save(job).await?)
+}
+
+#[post("/api/mark_send_jobs_seen", headers: dioxus::fullstack::HeaderMap)]
+pub async fn mark_send_jobs_seen(ids: Vec<u64>) -> Result<Vec<SendJob>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(send_job_store::mark_seen(&ids).await?)
+}
+
+/// Forgets the finished sends.  Returns those still in flight.
+#[post("/api/clear_send_jobs", headers: dioxus::fullstack::HeaderMap)]
+pub async fn clear_send_jobs() -> Result<Vec<SendJob>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(send_job_store::clear_finished().await?)
+}
+
+/// The AOCL leaf indices of the UTXOs marked do-not-spend.
+#[cfg(not(target_arch = "wasm32"))]
+async fn do_not_spend_indices() -> anyhow::Result<Vec<u64>> {
+    Ok(utxo_annotation_store::load()
+        .await?
+        .into_iter()
+        .filter(|(_, annotation)| annotation.do_not_spend)
+        .map(|(index, _)| index)
+        .collect())
+}
+
+/// Returns the saved Send drafts, most recently saved first.
+#[post("/api/send_drafts", headers: dioxus::fullstack::HeaderMap)]
+pub async fn send_drafts() -> Result<Vec<SendDraft>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(send_draft_store::load().await?)
+}
+
+/// Saves a Send draft, replacing any draft of the same name.
+#[post("/api/save_send_draft", headers: dioxus::fullstack::HeaderMap)]
+pub async fn save_send_draft(draft: SendDraft) -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(send_draft_store::save(draft).await?)
+}
+
+#[post("/api/delete_send_draft", headers: dioxus::fullstack::HeaderMap)]
+pub async fn delete_send_draft(name: String) -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(send_draft_store::delete(&name).await?)
+}
+
+/// Returns the addresses recently paid from this wallet, most recent first.
+#[post("/api/recent_recipients")]
+pub async fn recent_recipients() -> Result<Vec<RecentRecipient>, ApiError> {
+    Ok(recent_recipient_store::load().await?)
+}
+
+/// Remembers the recipients of a successful send, returning the updated
+/// recent recipients.
+#[post("/api/record_recent_recipients", headers: dioxus::fullstack::HeaderMap)]
+pub async fn record_recent_recipients(
+    recipients: Vec<RecentRecipient>,
+) -> Result<Vec<RecentRecipient>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(recent_recipient_store::record(recipients).await?)
+}
+
+/// Removes an address from the recent recipients.
+#[post("/api/forget_recent_recipient", headers: dioxus::fullstack::HeaderMap)]
+pub async fn forget_recent_recipient(address: String) -> Result<Vec<RecentRecipient>, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(recent_recipient_store::forget(&address).await?)
 }
 
 #[server(input = Json, output = Json)]
 #[post("/api/history")]
 pub async fn history(
 ) -> Result<Vec<(Digest, BlockHeight, Timestamp, NativeCurrencyAmount)>, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let history = client.history(tarpc::context::current(), token).await??;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let history = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .history(neptune_rpc::call_context("history"), token)
+            .await??)
+    })
+    .await?;
+    new_receipts::process(&history).await;
     Ok(history)
 }
 
+/// The `limit` history rows from `offset`, in `sort` order, with one row per
+/// block.
+#[server(input = Json, output = Json)]
+#[post("/api/history_page")]
+pub async fn history_page(
+    offset: usize,
+    limit: usize,
+    sort: HistorySort,
+) -> Result<HistoryPage, ApiError> {
+    // long enough to serve the pages requested while the user scrolls.
+    static CACHE: RpcCache<Vec<HistoryRow>> =
+        RpcCache::with_ttl(std::time::Duration::from_secs(10));
+
+    let rows = CACHE
+        .get(|| async {
+            let client = &neptune_rpc::rpc_client().await?;
+
+            let history = neptune_rpc::with_token(|token| async move {
+                Ok(client
+                    .history(neptune_rpc::call_context("history"), token)
+                    .await??)
+            })
+            .await?;
+            new_receipts::process(&history).await;
+            Ok(history_page::by_block(&history))
+        })
+        .await?;
+    Ok(history_page::page(rows, offset, limit, sort))
+}
+
+/// Returns the private notes on transactions, keyed by kernel id.
+#[post("/api/tx_notes")]
+pub async fn tx_notes() -> Result<TxNotes, ApiError> {
+    Ok(link_confirmed_tx_notes().await?)
+}
+
+/// Sets the private note on a transaction.  An empty note removes it.
+#[post("/api/set_tx_note", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_tx_note(txid: TransactionKernelId, text: String) -> Result<TxNotes, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    let text = text.trim().to_string();
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    Ok(tx_note_store::update(|notes| {
+        match notes.get_mut(&txid.to_string()) {
+            Some(note) => note.text = text,
+            None => {
+                notes.insert(
+                    txid.to_string(),
+                    TxNote {
+                        text,
+                        created_ms: now_ms,
+                        block: None,
+                        inputs: sent_txs::get(&txid)
+                            .map(|sent| sent.inputs)
+                            .unwrap_or_default(),
+                    },
+                );
+            }
+        }
+        true
+    })
+    .await?)
+}
+
+/// Links the notes on transactions sent by this app to the block they were
+/// confirmed in, returning all notes.
+///
+/// That is the block in which the wallet's UTXOs that the transaction spends
+/// were spent.  Notes on other transactions stay unlinked.
+#[cfg(not(target_arch = "wasm32"))]
+async fn link_confirmed_tx_notes() -> anyhow::Result<TxNotes> {
+    use neptune_types::ui_utxo::UtxoStatusEvent;
+
+    let notes = tx_note_store::load().await?;
+    if !notes
+        .values()
+        .any(|note| note.block.is_none() && !note.inputs.is_empty())
+    {
+        return Ok(notes);
+    }
+
+    let client = &neptune_rpc::rpc_client().await?;
+    let (utxos, history) = neptune_rpc::with_token(|token| async move {
+        let utxos = client
+            .list_utxos(neptune_rpc::call_context("list_utxos"), token)
+            .await??;
+        let history = client
+            .history(neptune_rpc::call_context("history"), token)
+            .await??;
+        Ok((utxos, history))
+    })
+    .await?;
+
+    let spent_in = |inputs: &[u64]| {
+        let height = utxos.iter().find_map(|utxo| match utxo.spent {
+            UtxoStatusEvent::Confirmed { block_height, .. }
+                if utxo.aocl_leaf_index.is_some_and(|i| inputs.contains(&i)) =>
+            {
+                Some(block_height)
+            }
+            _ => None,
+        })?;
+        let mut digests = history
+            .iter()
+            .filter(|(_, entry_height, _, _)| *entry_height == height)
+            .map(|(digest, _, _, _)| *digest);
+        let digest = digests.next()?;
+        // the wallet's history should hold one block per height.
+        digests.all(|other| other == digest).then_some(digest)
+    };
+
+    Ok(tx_note_store::update(|notes| {
+        let mut changed = false;
+        for note in notes.values_mut().filter(|note| note.block.is_none()) {
+            // not yet confirmed, or not sent by this app.
+            let Some(digest) = spent_in(&note.inputs) else {
+                continue;
+            };
+            note.block = Some(digest.to_hex());
+            changed = true;
+        }
+        changed
+    })
+    .await?)
+}
+
+/// Returns the locally stored tags and contacts for history entries.
+#[post("/api/history_annotations")]
+pub async fn history_annotations() -> Result<TxAnnotations, ApiError> {
+    Ok(tx_annotation_store::load().await?)
+}
+
+/// Sets the tag and/or contact of the history entries confirmed in the given
+/// blocks.  `None` leaves a field as is, an empty string clears it.
+#[post("/api/annotate_history", headers: dioxus::fullstack::HeaderMap)]
+pub async fn annotate_history(
+    block_digests: Vec<Digest>,
+    tag: Option<String>,
+    contact: Option<String>,
+) -> Result<TxAnnotations, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    let normalize = |value: Option<String>| {
+        value.map(|v| Some(v.trim().to_string()).filter(|v| !v.is_empty()))
+    };
+    let tag = normalize(tag);
+    let contact = normalize(contact);
+    Ok(tx_annotation_store::update(|annotations| {
+        for digest in block_digests {
+            let annotation = annotations.entry(digest.to_hex()).or_default();
+            if let Some(tag) = &tag {
+                annotation.tag = tag.clone();
+            }
+            if let Some(contact) = &contact {
+                annotation.contact = contact.clone();
+            }
+        }
+    })
+    .await?)
+}
+
+/// Returns the fiat prices captured when funds were received, oldest first.
+#[server(input = Json, output = Json)]
+#[post("/api/cost_basis")]
+pub async fn cost_basis() -> Result<Vec<CostBasisEntry>, ApiError> {
+    Ok(cost_basis_store::load().await?)
+}
+
 #[server(input = Json, output = Json)]
 #[post("/api/list_utxos")]
 pub async fn list_utxos() -> Result<Vec<UiUtxo>, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let ui_utxos = client
-        .list_utxos(tarpc::context::current(), token)
-        .await??;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let ui_utxos = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .list_utxos(neptune_rpc::call_context("list_utxos"), token)
+            .await??)
+    })
+    .await?;
     Ok(ui_utxos)
 }
 
+/// Returns the wallet's confirmed, unspent balance of each asset it holds,
+/// native coins first.
+#[server(input = Json, output = Json)]
+#[post("/api/list_assets")]
+pub async fn list_assets() -> Result<Vec<AssetBalance>, ApiError> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let ui_utxos = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .list_utxos(neptune_rpc::call_context("list_utxos"), token)
+            .await??)
+    })
+    .await?;
+    Ok(asset::balances(&ui_utxos))
+}
+
+/// Returns the locally stored tags, labels and do-not-spend flags for UTXOs.
+#[post("/api/utxo_annotations")]
+pub async fn utxo_annotations() -> Result<UtxoAnnotations, ApiError> {
+    Ok(utxo_annotation_store::load().await?)
+}
+
+/// Adds `tag` to each UTXO identified by AOCL leaf index.
+#[post("/api/tag_utxos", headers: dioxus::fullstack::HeaderMap)]
+pub async fn tag_utxos(aocl_indices: Vec<u64>, tag: String) -> Result<UtxoAnnotations, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    let tag = tag.trim().to_string();
+    if tag.is_empty() {
+        bail!("Tag must not be empty.");
+    }
+    Ok(utxo_annotation_store::update(|annotations| {
+        for index in aocl_indices {
+            annotations.entry(index).or_default().tags.insert(tag.clone());
+        }
+    })
+    .await?)
+}
+
+/// Removes all tags from each UTXO identified by AOCL leaf index.
+#[post("/api/clear_utxo_tags", headers: dioxus::fullstack::HeaderMap)]
+pub async fn clear_utxo_tags(aocl_indices: Vec<u64>) -> Result<UtxoAnnotations, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(utxo_annotation_store::update(|annotations| {
+        for index in aocl_indices {
+            annotations.entry(index).or_default().tags.clear();
+        }
+    })
+    .await?)
+}
+
+/// Sets the label of the UTXO with AOCL leaf index `aocl_index`.  An empty
+/// label removes it.
+#[post("/api/set_utxo_label", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_utxo_label(aocl_index: u64, label: String) -> Result<UtxoAnnotations, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    let label = label.trim().to_string();
+    Ok(utxo_annotation_store::update(|annotations| {
+        annotations.entry(aocl_index).or_default().label = (!label.is_empty()).then_some(label);
+    })
+    .await?)
+}
+
+/// Sets or clears the do-not-spend flag on each UTXO identified by AOCL leaf index.
+#[post("/api/set_utxos_do_not_spend", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_utxos_do_not_spend(
+    aocl_indices: Vec<u64>,
+    do_not_spend: bool,
+) -> Result<UtxoAnnotations, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(utxo_annotation_store::update(|annotations| {
+        for index in aocl_indices {
+            annotations.entry(index).or_default().do_not_spend = do_not_spend;
+        }
+    })
+    .await?)
+}
+
+/// Takes a snapshot of all the wallet's UTXOs at the current tip, in
+/// `format`, for audit.  See [utxo_snapshot].
+#[post("/api/export_utxo_snapshot", headers: dioxus::fullstack::HeaderMap)]
+pub async fn export_utxo_snapshot(format: SnapshotFormat) -> Result<SnapshotExport, ApiError> {
+    use neptune_types::ui_utxo::UtxoStatusEvent;
+    use num_traits::Zero;
+    use sha2::Digest as _;
+    use utxo_snapshot::SnapshotStatus;
+    use utxo_snapshot::SnapshotUtxo;
+    use utxo_snapshot::UtxoSnapshot;
+
+    auth::authorize(&headers, Role::Spender).await?;
+
+    const MAX_ATTEMPTS: u32 = 3;
+
+    let client = &neptune_rpc::rpc_client().await?;
+    let network = neptune_rpc::network().await?;
+
+    // a block arriving while the UTXOs and their digests are read would
+    // leave them out of step with the tip named.
+    let mut attempt = 1;
+    let (tip, ui_utxos, digests) = loop {
+        let height = neptune_rpc::with_token(|token| async move {
+            Ok(client
+                .block_height(neptune_rpc::call_context("block_height"), token)
+                .await??)
+        })
+        .await?;
+        let ui_utxos = neptune_rpc::with_token(|token| async move {
+            Ok(client
+                .list_utxos(neptune_rpc::call_context("list_utxos"), token)
+                .await??)
+        })
+        .await?;
+        let mut digests = HashMap::new();
+        for leaf_index in ui_utxos.iter().filter_map(|utxo| utxo.aocl_leaf_index) {
+            let digest = neptune_rpc::with_token(|token| async move {
+                Ok(client
+                    .utxo_digest(neptune_rpc::call_context("utxo_digest"), token, leaf_index)
+                    .await??)
+            })
+            .await?;
+            if let Some(digest) = digest {
+                digests.insert(leaf_index, digest.to_hex());
+            }
+        }
+        let tip = neptune_rpc::with_token(|token| async move {
+            Ok(client
+                .block_info(
+                    neptune_rpc::call_context("block_info"),
+                    token,
+                    BlockSelector::Height(height),
+                )
+                .await??)
+        })
+        .await?;
+        match tip {
+            Some(tip) if tip.is_tip => break (tip, ui_utxos, digests),
+            _ if attempt < MAX_ATTEMPTS => attempt += 1,
+            _ => bail!("New blocks kept arriving while the snapshot was taken. Try again."),
+        }
+    };
+
+    let rfc3339 = |timestamp: Timestamp| {
+        chrono::DateTime::from_timestamp_millis(timestamp.to_millis() as i64)
+            .map(|d| d.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+    };
+    let mut total_unspent = NativeCurrencyAmount::zero();
+    let mut utxos: Vec<SnapshotUtxo> = ui_utxos
+        .iter()
+        .map(|utxo| {
+            let status = SnapshotStatus::of(&utxo.received, &utxo.spent);
+            if status == SnapshotStatus::Unspent {
+                total_unspent = total_unspent + utxo.amount;
+            }
+            let (block_height, received_at) = match utxo.received {
+                UtxoStatusEvent::Confirmed {
+                    block_height,
+                    timestamp,
+                } => (Some(u64::from(block_height)), rfc3339(timestamp)),
+                _ => (None, None),
+            };
+            SnapshotUtxo {
+                aocl_leaf_index: utxo.aocl_leaf_index,
+                utxo_digest: utxo
+                    .aocl_leaf_index
+                    .and_then(|i| digests.get(&i).cloned()),
+                amount: utxo.amount.to_string(),
+                status,
+                block_height,
+                received_at,
+                release_date: utxo.release_date.and_then(rfc3339),
+            }
+        })
+        .collect();
+    utxos.sort_by_key(|utxo| (utxo.block_height.is_none(), utxo.block_height, utxo.aocl_leaf_index));
+
+    let taken_at = chrono::Utc::now();
+    let snapshot = UtxoSnapshot {
+        network: network.to_string(),
+        generator: format!("neptune-proton {}", env!("CARGO_PKG_VERSION")),
+        taken_at: taken_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        tip_height: u64::from(tip.height),
+        tip_digest: tip.digest.to_hex(),
+        total_unspent: total_unspent.to_string(),
+        utxos,
+    };
+    let contents = snapshot.to_format(format);
+    let sha256 = format!("{:x}", sha2::Sha256::digest(contents.as_bytes()));
+    Ok(SnapshotExport {
+        file_name: format!(
+            "utxos-{}-{}.{}",
+            network,
+            taken_at.format("%Y%m%d-%H%M%S"),
+            format.extension()
+        ),
+        contents,
+        sha256,
+    })
+}
+
+/// Exports the address labels, history tags and contacts, and UTXO tags and
+/// labels in the interchange format described in [wallet_metadata].
+#[post("/api/export_wallet_metadata", headers: dioxus::fullstack::HeaderMap)]
+pub async fn export_wallet_metadata() -> Result<String, ApiError> {
+    use wallet_metadata::AddressEntry;
+    use wallet_metadata::TransactionEntry;
+    use wallet_metadata::UtxoEntry;
+
+    auth::authorize(&headers, Role::Spender).await?;
+
+    let mut metadata = WalletMetadata::new(neptune_rpc::network().await?.to_string());
+    metadata.generator = Some(format!("neptune-proton {}", env!("CARGO_PKG_VERSION")));
+    metadata.exported_at =
+        Some(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+
+    metadata.addresses = address_label_store::load()
+        .await?
+        .into_iter()
+        .map(|(address, label)| AddressEntry { address, label })
+        .collect();
+    metadata.addresses.sort_by(|a, b| a.address.cmp(&b.address));
+
+    metadata.transactions = tx_annotation_store::load()
+        .await?
+        .into_iter()
+        .map(|(block_digest, annotation)| TransactionEntry {
+            block_digest,
+            tag: annotation.tag,
+            contact: annotation.contact,
+        })
+        .collect();
+    metadata.transactions.sort_by(|a, b| a.block_digest.cmp(&b.block_digest));
+
+    metadata.utxos = utxo_annotation_store::load()
+        .await?
+        .into_iter()
+        .map(|(aocl_leaf_index, annotation)| UtxoEntry {
+            aocl_leaf_index,
+            tags: annotation.tags,
+            label: annotation.label,
+            do_not_spend: annotation.do_not_spend,
+        })
+        .collect();
+    metadata.utxos.sort_by_key(|utxo| utxo.aocl_leaf_index);
+
+    Ok(metadata.to_json())
+}
+
+/// Imports metadata in the interchange format described in
+/// [wallet_metadata].  Imported entries replace stored ones with the same
+/// key; others are kept.
+#[post("/api/import_wallet_metadata", headers: dioxus::fullstack::HeaderMap)]
+pub async fn import_wallet_metadata(json: String) -> Result<MetadataImportSummary, ApiError> {
+    use utxo_annotation::UtxoAnnotation;
+
+    auth::authorize(&headers, Role::Spender).await?;
+
+    let metadata = WalletMetadata::from_json(&json).map_err(|e| anyhow::anyhow!(e))?;
+    let network = neptune_rpc::network().await?;
+    if metadata.network != network.to_string() {
+        bail!(
+            "The metadata is for {}, but the node runs on {}.",
+            metadata.network,
+            network
+        );
+    }
+
+    let normalize =
+        |value: Option<String>| value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    let mut summary = MetadataImportSummary::default();
+
+    let mut labels = vec![];
+    for entry in metadata.addresses {
+        let label = entry.label.trim().to_string();
+        if label.is_empty() || ReceivingAddress::from_bech32m(&entry.address, network).is_err() {
+            summary.skipped += 1;
+            continue;
+        }
+        labels.push((entry.address, label));
+    }
+    summary.addresses = labels.len();
+    address_label_store::update(|stored| stored.extend(labels)).await?;
+
+    let mut tx_annotations = vec![];
+    for entry in metadata.transactions {
+        let Ok(digest) = Digest::try_from_hex(&entry.block_digest) else {
+            summary.skipped += 1;
+            continue;
+        };
+        let annotation = TxAnnotation {
+            tag: normalize(entry.tag),
+            contact: normalize(entry.contact),
+        };
+        tx_annotations.push((digest.to_hex(), annotation));
+    }
+    summary.transactions = tx_annotations.len();
+    tx_annotation_store::update(|stored| stored.extend(tx_annotations)).await?;
+
+    let utxo_annotations: Vec<_> = metadata
+        .utxos
+        .into_iter()
+        .map(|entry| {
+            let annotation = UtxoAnnotation {
+                tags: entry
+                    .tags
+                    .into_iter()
+                    .filter_map(|tag| normalize(Some(tag)))
+                    .collect(),
+                label: normalize(entry.label),
+                do_not_spend: entry.do_not_spend,
+            };
+            (entry.aocl_leaf_index, annotation)
+        })
+        .collect();
+    summary.utxos = utxo_annotations.len();
+    utxo_annotation_store::update(|stored| stored.extend(utxo_annotations)).await?;
+
+    Ok(summary)
+}
+
+/// Spends exactly the given UTXOs to a fresh address of our own, merging them
+/// into a single UTXO.
+///
+/// Unlike [send], inputs are chosen by the caller rather than neptune-core.
+/// UTXOs marked do-not-spend are refused.
+#[post("/api/consolidate_utxos", headers: dioxus::fullstack::HeaderMap)]
+pub async fn consolidate_utxos(
+    aocl_indices: Vec<u64>,
+    fee: NativeCurrencyAmount,
+) -> Result<(TransactionKernelId, TransactionDetails), ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    neptune_rpc::require_spending().await?;
+
+    if aocl_indices.len() < 2 {
+        bail!("Select at least two UTXOs to consolidate.");
+    }
+
+    let annotations = utxo_annotation_store::load().await?;
+    if let Some(index) = aocl_indices
+        .iter()
+        .find(|i| annotations.get(i).is_some_and(|a| a.do_not_spend))
+    {
+        bail!("UTXO {} is marked do-not-spend.", index);
+    }
+
+    let result = neptune_rpc::consolidate(aocl_indices.clone(), fee).await;
+    let txid = result.as_ref().map(|(txid, _)| *txid);
+    record_sent("consolidate_utxos", txid, 1, fee).await;
+    Ok(result?)
+}
+
+/// Broadcasts a transaction carrying `message` as an announcement, for
+/// testing announcement-based features.  Needs developer tools enabled.
+///
+/// The transaction has no outputs besides change.
+#[post("/api/broadcast_announcement", headers: dioxus::fullstack::HeaderMap)]
+pub async fn broadcast_announcement(
+    message: Vec<BFieldElement>,
+    fee: NativeCurrencyAmount,
+) -> Result<TransactionKernelId, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    neptune_rpc::require_dev_tools().await?;
+    neptune_rpc::require_spending().await?;
+
+    if message.is_empty() {
+        bail!("The announcement is empty.");
+    }
+
+    let num_elements = message.len();
+    let result = neptune_rpc::broadcast_announcement(message, fee).await;
+    match &result {
+        Ok(txid) => {
+            event_log::record(event_log::WalletEvent::AnnouncementBroadcast {
+                txid: txid.to_string(),
+                num_elements,
+                fee: fee.to_string(),
+            })
+            .await;
+        }
+        Err(e) => event_log::record_error("broadcast_announcement", e).await,
+    }
+    Ok(result?)
+}
+
+/// Broadcasts a serialized signed transaction, eg one signed by another
+/// wallet.  `bytes` is the bincode of neptune-core's transaction creation
+/// artifacts.
+///
+/// neptune-core validates the transaction before broadcasting it.
+#[post("/api/broadcast_transaction", headers: dioxus::fullstack::HeaderMap)]
+pub async fn broadcast_transaction(bytes: Vec<u8>) -> Result<TransactionKernelId, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+
+    let result = neptune_rpc::broadcast_transaction(&bytes).await;
+    match &result {
+        Ok((txid, num_outputs, fee)) => {
+            record_sent("broadcast_transaction", Ok(*txid), *num_outputs, *fee).await
+        }
+        Err(e) => event_log::record_error("broadcast_transaction", e).await,
+    }
+    Ok(result.map(|(txid, _, _)| txid)?)
+}
+
+/// The `number` mempool transactions from `start_index`, in `sort` order.
+/// `only_mine` leaves out transactions that do not affect this wallet.
+#[server(input = Json, output = Json)]
 #[post("/api/mempool_overview")]
 pub async fn mempool_overview(
     start_index: usize,
     number: usize,
-) -> Result<Vec<MempoolTransactionInfo>, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let data = client
-        .mempool_overview(tarpc::context::current(), token, start_index, number)
-        .await??;
-    Ok(data)
+    sort: MempoolSort,
+    only_mine: bool,
+) -> Result<MempoolPage, ApiError> {
+    // neptune-core cannot sort, so the whole mempool is fetched and sorted
+    // here.  The UI polls it, and pages through it, every few seconds.
+    static CACHE: RpcCache<Vec<MempoolTransactionInfo>> =
+        RpcCache::with_ttl(std::time::Duration::from_secs(2));
+
+    let mut txs = CACHE.get(neptune_rpc::mempool_transactions).await?;
+    if only_mine {
+        txs.retain(mempool_page::affects_wallet);
+    }
+    Ok(mempool_page::page(txs, start_index, number, sort))
 }
 
 #[post("/api/mempool_tx_kernel")]
 pub async fn mempool_tx_kernel(
     txid: TransactionKernelId,
 ) -> Result<Option<TransactionKernel>, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let data = client
-        .mempool_tx_kernel(tarpc::context::current(), token, txid)
-        .await??;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let data = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .mempool_tx_kernel(neptune_rpc::call_context("mempool_tx_kernel"), token, txid)
+            .await??)
+    })
+    .await?;
     Ok(data)
 }
 
+/// Returns the unconfirmed transactions in the mempool that affect the
+/// wallet's balance, ie those sent from the wallet and those paying it.
+#[post("/api/pending_transactions")]
+pub async fn pending_transactions() -> Result<Vec<MempoolTransactionInfo>, ApiError> {
+    Ok(neptune_rpc::mempool_transactions()
+        .await?
+        .into_iter()
+        .filter(mempool_page::affects_wallet)
+        .collect())
+}
+
+/// The payments the wallet expects but has not yet received: incoming
+/// transactions in the mempool, and the number of UTXOs it was notified of
+/// that are not in a block yet.
+#[post("/api/expected_utxos")]
+pub async fn expected_utxos() -> Result<ExpectedUtxos, ApiError> {
+    use num_traits::Zero;
+
+    // as of neptune-core v0.3.0 the balance effect fields are backwards: the
+    // negative effect is what the wallet gains, the positive what it spends.
+    // transactions that also spend the wallet's funds are its own sends, and
+    // what they add is change.
+    let in_mempool = neptune_rpc::mempool_transactions()
+        .await?
+        .into_iter()
+        .filter(|tx| {
+            !tx.negative_balance_effect.is_zero() && tx.positive_balance_effect.is_zero()
+        })
+        .map(|tx| ExpectedUtxo {
+            txid: tx.id,
+            amount: tx.negative_balance_effect,
+            synced: tx.synced,
+        })
+        .collect();
+    let unclaimed = neptune_rpc::num_unclaimed_utxos().await?;
+
+    Ok(ExpectedUtxos {
+        in_mempool,
+        unclaimed,
+    })
+}
+
 #[post("/api/block_info")]
 pub async fn block_info(selector: BlockSelector) -> Result<Option<BlockInfo>, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let data = client
-        .block_info(tarpc::context::current(), token, selector)
-        .await??;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let data = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .block_info(neptune_rpc::call_context("block_info"), token, selector)
+            .await??)
+    })
+    .await?;
     Ok(data)
 }
 
+/// Finds what `query` names: a block, by height or digest, a transaction in
+/// the mempool, by kernel id, or one of the wallet's addresses.
+#[post("/api/search")]
+pub async fn search(query: String) -> Result<SearchResult, ApiError> {
+    let query = query.trim();
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let selector = if let Ok(height) = query.parse::<u64>() {
+        Some(BlockSelector::Height(height.into()))
+    } else {
+        Digest::try_from_hex(query).ok().map(BlockSelector::Digest)
+    };
+    if let Some(selector) = selector {
+        let block = neptune_rpc::with_token(|token| async move {
+            Ok(client
+                .block_info(neptune_rpc::call_context("block_info"), token, selector)
+                .await??)
+        })
+        .await?;
+        if block.is_some() {
+            return Ok(SearchResult::Block(selector));
+        }
+    }
+
+    // kernel ids are digests too, so are tried once no block matches.
+    if let Ok(txid) = query.parse::<TransactionKernelId>() {
+        let kernel = neptune_rpc::with_token(|token| async move {
+            Ok(client
+                .mempool_tx_kernel(neptune_rpc::call_context("mempool_tx_kernel"), token, txid)
+                .await??)
+        })
+        .await?;
+        if kernel.is_some() {
+            return Ok(SearchResult::MempoolTx(txid));
+        }
+    }
+
+    let network = neptune_rpc::network().await?;
+    if let Ok(address) = ReceivingAddress::from_bech32m(query, network) {
+        let known_keys = neptune_rpc::with_token(|token| async move {
+            Ok(client
+                .known_keys(neptune_rpc::call_context("known_keys"), token)
+                .await??)
+        })
+        .await?;
+        if known_keys.iter().any(|key| key.to_address() == address) {
+            return Ok(SearchResult::Address);
+        }
+    }
+
+    Ok(SearchResult::NotFound)
+}
+
 #[post("/api/dashboard_overview_data")]
 pub async fn dashboard_overview_data() -> Result<DashBoardOverviewDataFromClient, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let data = client
-        .dashboard_overview_data(tarpc::context::current(), token)
-        .await??;
-    Ok(data)
+    static CACHE: RpcCache<DashBoardOverviewDataFromClient> = RpcCache::new();
+
+    Ok(CACHE
+        .get(|| async {
+            let client = &neptune_rpc::rpc_client().await?;
+            neptune_rpc::with_token(|token| async move {
+                Ok(client
+                    .dashboard_overview_data(
+                        neptune_rpc::call_context("dashboard_overview_data"),
+                        token,
+                    )
+                    .await??)
+            })
+            .await
+        })
+        .await?)
 }
 
 #[post("/api/peer_info")]
 pub async fn peer_info() -> Result<Vec<NeptunePeerInfo>, ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
-
-    let data = client.peer_info(tarpc::context::current(), token).await??;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let data = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .peer_info(neptune_rpc::call_context("peer_info"), token)
+            .await??)
+    })
+    .await?;
     Ok(data)
 }
 
-#[post("/api/clear_all_standings")]
-pub async fn clear_all_standings() -> Result<(), ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
+/// Locates connected peers with the local GeoIP database, if one is
+/// configured with `GEOIP_DATABASE`.  Makes no external requests.
+#[post("/api/peer_geo")]
+pub async fn peer_geo() -> Result<PeerGeoReport, ApiError> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let peers = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .peer_info(neptune_rpc::call_context("peer_info"), token)
+            .await??)
+    })
+    .await?;
+    Ok(PeerGeoReport {
+        database: geoip::database_name(),
+        peers: peers
+            .iter()
+            .map(|peer| geoip::locate(peer.connected_address()))
+            .collect(),
+    })
+}
 
-    Ok(client
-        .clear_all_standings(tarpc::context::current(), token)
-        .await??)
+#[post("/api/clear_all_standings", headers: dioxus::fullstack::HeaderMap)]
+pub async fn clear_all_standings() -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .clear_all_standings(neptune_rpc::call_context("clear_all_standings"), token)
+            .await??)
+    })
+    .await?;
+
+    event_log::record(event_log::WalletEvent::PeerStandingCleared { ip: None }).await;
+    Ok(())
 }
 
-#[post("/api/clear_standing_by_ip")]
+#[post("/api/clear_standing_by_ip", headers: dioxus::fullstack::HeaderMap)]
 pub async fn clear_standing_by_ip(ip: IpAddr) -> Result<(), ApiError> {
-    let client = neptune_rpc::rpc_client().await?;
-    let token = neptune_rpc::get_token().await?;
+    auth::authorize(&headers, Role::Admin).await?;
+    let client = &neptune_rpc::rpc_client().await?;
+
+    neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .clear_standing_by_ip(neptune_rpc::call_context("clear_standing_by_ip"), token, ip)
+            .await??)
+    })
+    .await?;
+
+    event_log::record(event_log::WalletEvent::PeerStandingCleared { ip: Some(ip) }).await;
+    Ok(())
+}
 
-    Ok(client
-        .clear_standing_by_ip(tarpc::context::current(), token, ip)
-        .await??)
+/// Checks whether the node's peer-to-peer port is reachable from the internet.
+///
+/// If `REACHABILITY_CHECKER_URL` is set, that service is asked to connect to
+/// the port. Otherwise, or if the service fails, reachability is inferred
+/// from whether any connected peer dialed in to us.
+#[post("/api/check_reachability", headers: dioxus::fullstack::HeaderMap)]
+pub async fn check_reachability() -> Result<ReachabilityReport, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    Ok(neptune_rpc::check_reachability().await?)
+}
+
+/// Stores whether the client is on a metered connection, so that background
+/// polling is throttled and large exports deferred, returning the updated
+/// prefs.
+#[post("/api/set_metered_connection", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_metered_connection(metered: bool) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Spender).await?;
+    let prefs = prefs_store::set_metered_connection(metered).await?;
+    if !metered {
+        event_log::flush_deferred().await;
+    }
+    Ok(prefs)
 }
 
 #[post("/api/fiat_prices")]
@@ -219,6 +1885,100 @@ pub async fn fiat_prices() -> Result<PriceMap, ApiError> {
     Ok(price_caching::get_cached_fiat_prices().await?)
 }
 
+/// Returns NPT's fiat prices on past dates, each "YYYY-MM-DD" (UTC).
+///
+/// Dates whose prices are not yet known are omitted; a later call may return
+/// them, as only a limited number are fetched per call.
+#[post("/api/historical_prices")]
+pub async fn historical_prices(dates: Vec<String>) -> Result<HashMap<String, PriceMap>, ApiError> {
+    Ok(price_history::lookup(dates).await?)
+}
+
+/// Reports the health of each configured price provider, in failover order.
+#[post("/api/price_provider_status")]
+pub async fn price_provider_status() -> Result<Vec<PriceProviderStatus>, ApiError> {
+    Ok(price_caching::provider_status())
+}
+
+/// Latency and failures of each neptune-core RPC method since the server
+/// started, slowest first.
+#[post("/api/rpc_metrics", headers: dioxus::fullstack::HeaderMap)]
+pub async fn rpc_metrics() -> Result<RpcMetrics, ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    Ok(rpc_metrics_store::snapshot())
+}
+
+/// Returns a JSON snapshot of versions, settings, recent events and metrics,
+/// for attaching to a support request.  Receiving addresses and identifying
+/// details are only included if `include_addresses` is set.
+#[post("/api/support_bundle", headers: dioxus::fullstack::HeaderMap)]
+pub async fn support_bundle(include_addresses: bool) -> Result<String, ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    Ok(support_bundle::build(include_addresses).await?)
+}
+
+/// Recent server log entries at `level` or more severe, oldest first.  Pass
+/// the `seq` of the last entry seen as `after` to get only newer ones.
+#[post("/api/recent_logs", headers: dioxus::fullstack::HeaderMap)]
+pub async fn recent_logs(level: LogLevel, after: Option<u64>) -> Result<Vec<LogEntry>, ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    Ok(logging::recent(level, after))
+}
+
+/// Sets the least severe server log entries that are recorded, returning the
+/// updated prefs.  Overrides the `LOG_LEVEL` env var.
+#[post("/api/set_log_level", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_log_level(level: LogLevel) -> Result<UserPrefs, ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    let prefs = prefs_store::set_log_level(level).await?;
+    logging::set_level(level);
+    Ok(prefs)
+}
+
+/// How the wallet runs neptune-core, if it does.
+#[post("/api/managed_node_config", headers: dioxus::fullstack::HeaderMap)]
+pub async fn managed_node_config() -> Result<ManagedNodeConfig, ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    Ok(managed_node_store::load().await?)
+}
+
+/// Stores how the wallet runs neptune-core, and restarts or stops it to
+/// match.
+#[post("/api/set_managed_node_config", headers: dioxus::fullstack::HeaderMap)]
+pub async fn set_managed_node_config(config: ManagedNodeConfig) -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    if config.enabled && config.binary.trim().is_empty() {
+        bail!("Choose the neptune-core executable.");
+    }
+    managed_node_store::save(&config).await?;
+    node_supervisor::ensure_supervising();
+    node_supervisor::reload();
+    Ok(())
+}
+
+/// Whether the managed neptune-core runs and answers.
+#[post("/api/managed_node_status")]
+pub async fn managed_node_status() -> Result<ManagedNodeStatus, ApiError> {
+    node_supervisor::ensure_supervising();
+    Ok(node_supervisor::status())
+}
+
+/// Stops the managed neptune-core and starts it again.
+#[post("/api/restart_managed_node", headers: dioxus::fullstack::HeaderMap)]
+pub async fn restart_managed_node() -> Result<(), ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    node_supervisor::reload();
+    Ok(())
+}
+
+/// Recent output of the managed neptune-core, oldest first.  Pass the `seq`
+/// of the last line seen as `after` to get only newer ones.
+#[post("/api/node_logs", headers: dioxus::fullstack::HeaderMap)]
+pub async fn node_logs(after: Option<u64>) -> Result<Vec<NodeLogLine>, ApiError> {
+    auth::authorize(&headers, Role::Admin).await?;
+    Ok(node_supervisor::logs(after))
+}
+
 #[get("/api/neptune_core_rpc_socket_addr")]
 pub async fn neptune_core_rpc_socket_addr() -> Result<SocketAddr, ApiError> {
     Ok(SocketAddr::new(
@@ -228,23 +1988,28 @@ pub async fn neptune_core_rpc_socket_addr() -> Result<SocketAddr, ApiError> {
 }
 
 /// Asynchronously retrieves the SecretKeyMaterial by reading the wallet.dat file.
-#[post("/api/get_wallet_secret_key")]
+#[post("/api/get_wallet_secret_key", headers: dioxus::fullstack::HeaderMap)]
 pub async fn get_wallet_secret_key() -> Result<SecretKeyMaterial, ApiError> {
     use anyhow::Context;
 
-    let cookie_hint = neptune_rpc::cookie_hint().await?;
+    auth::authorize(&headers, Role::Admin).await?;
+    if !neptune_rpc::wallet_capabilities().await?.can_export_secret {
+        bail!("This is a watch-only wallet. It holds no secret to export.");
+    }
+
+    let data_dir = neptune_rpc::data_directory().await?;
 
     // Note: We use tokio::task::spawn_blocking for file I/O as it blocks the thread.
     // This is required for non-async I/O operations like WalletFile::read_from_file.
-    tokio::task::spawn_blocking(move || {
+    let secret_key = tokio::task::spawn_blocking(move || -> anyhow::Result<_> {
         // 1. Get the wallet directory path
-        let wallet_dir = cookie_hint.data_directory.wallet_directory_path();
+        let wallet_dir = data_dir.wallet_directory_path();
 
         // 2. Determine wallet file path and check existence
         let wallet_file = WalletFileContext::wallet_secret_path(&wallet_dir);
 
         if !wallet_file.exists() {
-            anyhow::bail!(
+            bail!(
                 "Wallet file not found at: {}. Please generate or import a wallet first.",
                 wallet_file.display()
             );
@@ -258,7 +2023,96 @@ pub async fn get_wallet_secret_key() -> Result<SecretKeyMaterial, ApiError> {
             ))?;
 
         Ok(wallet_secret.secret_key())
-    }).await?
+    }).await??;
+    Ok(secret_key)
+}
+
+/// neptune-core v0.5 keeps its wallet secret in plain text, and cannot
+/// encrypt it.
+#[cfg(not(target_arch = "wasm32"))]
+const WALLET_PASSPHRASE_SUPPORTED: bool = false;
+
+/// Whether neptune-core's wallet file is encrypted, and whether neptune-core
+/// could encrypt it.
+#[post("/api/wallet_encryption_status")]
+pub async fn wallet_encryption_status() -> Result<WalletEncryptionStatus, ApiError> {
+    let data_dir = neptune_rpc::data_directory().await?;
+    let wallet_file = WalletFileContext::wallet_secret_path(&data_dir.wallet_directory_path());
+    let state = {
+        let wallet_file = wallet_file.clone();
+        tokio::task::spawn_blocking(move || {
+            if !wallet_file.exists() {
+                return WalletFileState::Missing;
+            }
+            match WalletFile::read_from_file(&wallet_file) {
+                Ok(_) => WalletFileState::PlainText,
+                Err(e) => WalletFileState::Unreadable(e.to_string()),
+            }
+        })
+        .await?
+    };
+    Ok(WalletEncryptionStatus {
+        wallet_file: wallet_file.display().to_string(),
+        state,
+        passphrase_supported: WALLET_PASSPHRASE_SUPPORTED,
+    })
+}
+
+/// Restores the wallet of a seed phrase, for moving a wallet onto this
+/// machine.  Writes neptune-core's wallet file if there is none yet, and
+/// never replaces another wallet.  `network` is the one neptune-core runs
+/// on, used while it is not running.
+#[post("/api/import_wallet", headers: dioxus::fullstack::HeaderMap)]
+pub async fn import_wallet(words: Vec<String>, network: Network) -> Result<WalletImport, ApiError> {
+    use anyhow::Context;
+
+    auth::authorize(&headers, Role::Admin).await?;
+    let secret = match SecretKeyMaterial::from_phrase(&words) {
+        Ok(secret) => secret,
+        Err(e) => bail!("These words are not a Neptune seed phrase: {e}"),
+    };
+
+    let wallet_dir = neptune_rpc::wallet_directory(network).await?;
+    let outcome = tokio::task::spawn_blocking(move || -> anyhow::Result<_> {
+        let wallet_file = WalletFileContext::wallet_secret_path(&wallet_dir);
+        if wallet_file.exists() {
+            let existing = WalletFile::read_from_file(&wallet_file).context(format!(
+                "Could not read WalletFile from disk at {}",
+                wallet_file.display()
+            ))?;
+            return Ok(if existing.secret_key() == secret {
+                WalletImport::AlreadyInUse
+            } else {
+                WalletImport::OtherWalletExists {
+                    wallet_dir: wallet_dir.display().to_string(),
+                }
+            });
+        }
+
+        std::fs::create_dir_all(&wallet_dir)
+            .with_context(|| format!("Could not create {}", wallet_dir.display()))?;
+        WalletFile::new(secret)
+            .save_to_disk(&wallet_file)
+            .with_context(|| format!("Could not write {}", wallet_file.display()))?;
+        Ok(WalletImport::Written {
+            wallet_file: wallet_file.display().to_string(),
+            node_restarted: false,
+        })
+    })
+    .await??;
+
+    if let WalletImport::Written { wallet_file, .. } = outcome {
+        let node_restarted = managed_node_store::load().await?.enabled;
+        if node_restarted {
+            node_supervisor::reload();
+        }
+        event_log::record(event_log::WalletEvent::WalletImported { node_restarted }).await;
+        return Ok(WalletImport::Written {
+            wallet_file,
+            node_restarted,
+        });
+    }
+    Ok(outcome)
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -266,23 +2120,137 @@ pub async fn get_wallet_secret_key() -> Result<SecretKeyMaterial, ApiError> {
 mod neptune_rpc {
     // use neptune_cash::api::export::Transaction;
     // use neptune_cash::api::export::TransactionDetails;
+    use std::future::Future;
+    use std::net::IpAddr;
     use std::net::Ipv4Addr;
     use std::net::SocketAddr;
-
+    use std::path::PathBuf;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::sync::OnceLock;
+    use std::time::Duration;
+
+    use neptune_cash::api::export::TxCreationArtifacts;
+    use neptune_cash::application::config::data_directory::DataDirectory;
     use neptune_cash::application::rpc::auth as rpc_auth;
     use neptune_cash::application::rpc::server::RPCClient;
+    use neptune_cash::application::rpc::server::RPCRequest;
+    use neptune_cash::application::rpc::server::RPCResponse;
     use neptune_types::change_policy::ChangePolicy;
+    use neptune_types::mempool_transaction_info::MempoolTransactionInfo;
     use neptune_types::native_currency_amount::NativeCurrencyAmount;
     use neptune_types::network::Network;
     use neptune_types::output_format::OutputFormat;
     use neptune_types::transaction_details::TransactionDetails;
     use neptune_types::transaction_kernel_id::TransactionKernelId;
+    use neptune_types::wallet_file_context::WalletFileContext;
     use tarpc::client;
     use tarpc::context;
     use tarpc::tokio_serde::formats::Json;
 
+    use super::data_dir_store;
+    use super::nc_convert;
+    use super::nc_convert::FromNc;
+    use super::nc_convert::ToNc;
     use super::rpc_api;
-    use super::ApiError;
+    use super::rpc_connection;
+    use super::rpc_metrics_store::Metered;
+    use super::ReachabilityReport;
+    use crate::api_error::Failure;
+    use crate::reachability::Reachability;
+    use crate::reachability::ReachabilityMethod;
+    use crate::send_monitor::SendTracker;
+    use crate::send_progress::SendStage;
+    use super::WalletCapabilities;
+
+    /// How often a send is attempted when the connection to neptune-core is
+    /// lost before broadcasting.
+    const MAX_SEND_ATTEMPTS: u32 = 3;
+
+    /// The wait before the first retry of a send.  Doubles for each retry.
+    const SEND_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+    /// Calls made while the app loads give up after this long, so that a slow
+    /// node shows the connection dialog rather than hanging the first paint.
+    const STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// How long an RPC call may take, unless configured otherwise.
+    const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(20);
+
+    /// Calls that prove a transaction or scan the whole wallet take far longer.
+    const SLOW_RPC_TIMEOUTS: [(&str, Duration); 7] = [
+        ("send", Duration::from_secs(10 * 60)),
+        ("generate_witness_proof", Duration::from_secs(10 * 60)),
+        ("assemble_transaction_artifacts", Duration::from_secs(10 * 60)),
+        ("record_and_broadcast_transaction", Duration::from_secs(2 * 60)),
+        ("history", Duration::from_secs(60)),
+        ("list_utxos", Duration::from_secs(60)),
+        ("spendable_inputs", Duration::from_secs(60)),
+    ];
+
+    /// The timeout of the RPC `method`.
+    ///
+    /// The `RPC_TIMEOUTS` env var overrides timeouts as comma-separated
+    /// `method=seconds` pairs, eg "send=1200,history=120".  The method
+    /// `default` stands for all methods not listed.
+    pub fn rpc_timeout(method: &str) -> Duration {
+        static OVERRIDES: OnceLock<HashMap<String, Duration>> = OnceLock::new();
+        let overrides = OVERRIDES.get_or_init(|| {
+            std::env::var("RPC_TIMEOUTS")
+                .unwrap_or_default()
+                .split(',')
+                .filter_map(|pair| {
+                    let (name, secs) = pair.split_once('=')?;
+                    let secs = secs.trim().parse().ok()?;
+                    Some((name.trim().to_string(), Duration::from_secs(secs)))
+                })
+                .collect()
+        });
+
+        overrides
+            .get(method)
+            .copied()
+            .or_else(|| {
+                SLOW_RPC_TIMEOUTS
+                    .iter()
+                    .find(|(name, _)| *name == method)
+                    .map(|(_, timeout)| *timeout)
+            })
+            .or_else(|| overrides.get("default").copied())
+            .unwrap_or(DEFAULT_RPC_TIMEOUT)
+    }
+
+    /// A context for calling the RPC `method`, whose deadline is the method's
+    /// [rpc_timeout].
+    ///
+    /// Once the deadline passes, tarpc fails the call with `DeadlineExceeded`
+    /// and tells neptune-core to cancel it.  It does the same when the call is
+    /// dropped, eg because the browser that made the request went away.
+    pub fn call_context(method: &str) -> context::Context {
+        let timeout = rpc_timeout(method);
+        dioxus_logger::tracing::trace!(method, timeout_ms = timeout.as_millis() as u64, "rpc call");
+        let mut ctx = context::current();
+        ctx.deadline = std::time::Instant::now() + timeout;
+        ctx
+    }
+
+    /// Last values seen, so that later page loads need not wait on neptune-core.
+    static LAST_NETWORK: Mutex<Option<Network>> = Mutex::new(None);
+    static LAST_CAPABILITIES: Mutex<Option<WalletCapabilities>> = Mutex::new(None);
+
+    async fn with_startup_timeout<T>(
+        fut: impl Future<Output = anyhow::Result<T>>,
+    ) -> anyhow::Result<T> {
+        tokio::time::timeout(STARTUP_TIMEOUT, fut)
+            .await
+            .map_err(|_| {
+                Failure::Timeout(format!(
+                    "neptune-core did not respond within {} seconds",
+                    STARTUP_TIMEOUT.as_secs()
+                ))
+            })?
+    }
 
     pub fn neptune_core_rpc_port() -> u16 {
         const DEFAULT_PORT: u16 = 9799;
@@ -292,100 +2260,741 @@ mod neptune_rpc {
             .unwrap_or(DEFAULT_PORT)
     }
 
-    async fn gen_rpc_client() -> Result<rpc_api::RPCClient, ApiError> {
-        let server_socket = SocketAddr::new(
-            std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
-            neptune_core_rpc_port(),
-        );
-        let transport = tarpc::serde_transport::tcp::connect(server_socket, Json::default).await?;
+    /// Clients whose calls are recorded for [super::rpc_metrics].
+    pub type ApiClient =
+        rpc_api::RPCClient<Metered<client::Channel<rpc_api::RPCRequest, rpc_api::RPCResponse>>>;
+    type NcClient = RPCClient<Metered<client::Channel<RPCRequest, RPCResponse>>>;
 
-        Ok(rpc_api::RPCClient::new(client::Config::default(), transport).spawn())
-    }
+    /// Shared by all calls.  See [rpc_connection].
+    static RPC_CLIENTS: rpc_connection::Pool<ApiClient> = rpc_connection::Pool::new();
+    static NC_RPC_CLIENTS: rpc_connection::Pool<NcClient> = rpc_connection::Pool::new();
 
-    async fn gen_nc_rpc_client() -> Result<RPCClient, ApiError> {
-        let server_socket = SocketAddr::new(
+    fn rpc_socket() -> SocketAddr {
+        SocketAddr::new(
             std::net::IpAddr::V4(Ipv4Addr::LOCALHOST),
             neptune_core_rpc_port(),
-        );
-        let transport = tarpc::serde_transport::tcp::connect(server_socket, Json::default).await?;
+        )
+    }
+
+    async fn gen_rpc_client(alive: Arc<AtomicBool>) -> anyhow::Result<ApiClient> {
+        let transport = tarpc::serde_transport::tcp::connect(rpc_socket(), Json::default).await?;
 
-        Ok(RPCClient::new(client::Config::default(), transport).spawn())
+        let client::NewClient { client: channel, dispatch } =
+            client::new(client::Config::default(), transport);
+        rpc_connection::watch(dispatch, alive);
+        Ok(ApiClient::from(Metered(channel)))
     }
-    pub async fn rpc_client() -> Result<rpc_api::RPCClient, ApiError> {
-        // no caching for now.  very fast to establish a connection on localhost
-        // and this way there is no need to invalidate cache on connection error.
-        gen_rpc_client().await
+
+    async fn gen_nc_rpc_client() -> anyhow::Result<NcClient> {
+        NC_RPC_CLIENTS
+            .get(|alive| async move {
+                let transport =
+                    tarpc::serde_transport::tcp::connect(rpc_socket(), Json::default).await?;
+
+                let client::NewClient { client: channel, dispatch } =
+                    client::new(client::Config::default(), transport);
+                rpc_connection::watch(dispatch, alive);
+                Ok(NcClient::from(Metered(channel)))
+            })
+            .await
+    }
+
+    pub async fn rpc_client() -> anyhow::Result<ApiClient> {
+        RPC_CLIENTS.get(gen_rpc_client).await
     }
 
-    pub async fn cookie_hint() -> Result<rpc_auth::CookieHint, ApiError> {
+    pub async fn cookie_hint() -> anyhow::Result<rpc_auth::CookieHint> {
         let client = rpc_client().await?;
-        Ok(client.cookie_hint(context::current()).await??)
+        Ok(client.cookie_hint(call_context("cookie_hint")).await??)
     }
 
-    async fn gen_token() -> Result<rpc_auth::Token, ApiError> {
+    /// neptune-core's data directory for the network it runs on: the one the
+    /// user chose, if any, else the one neptune-core reports.
+    pub async fn data_directory() -> anyhow::Result<DataDirectory> {
         let hint = cookie_hint().await?;
-        Ok(rpc_auth::Cookie::try_load(&hint.data_directory)
-            .await?
-            .into())
+        match data_dir_store::root().await? {
+            Some(root) => DataDirectory::get(Some(root), hint.network),
+            None => Ok(hint.data_directory),
+        }
     }
 
-    pub async fn get_token() -> Result<rpc_auth::Token, ApiError> {
-        // no caching for now. it's fast enough just to get from disk each time
-        // and no need to invalidate upon connection error.
-        return gen_token().await;
+    /// neptune-core's wallet directory.  While neptune-core is not running,
+    /// that of `network` in the chosen data directory, or the default one.
+    pub async fn wallet_directory(network: Network) -> anyhow::Result<PathBuf> {
+        let data_dir = match data_directory().await {
+            Ok(data_dir) => data_dir,
+            Err(_) => DataDirectory::get(
+                data_dir_store::root().await?,
+                network
+                    .to_string()
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("{e}"))?,
+            )?,
+        };
+        Ok(data_dir.wallet_directory_path())
     }
 
-    async fn get_network() -> Result<Network, ApiError> {
+    /// Checks that `root` is a neptune-core data directory, as passed to its
+    /// `--data-dir`, that holds a wallet and a readable cookie for the network
+    /// neptune-core runs on.
+    pub async fn check_data_directory(root: PathBuf) -> anyhow::Result<()> {
+        let hint = cookie_hint().await?;
+        let data_dir = DataDirectory::get(Some(root.clone()), hint.network)?;
+        let wallet_dir = data_dir.wallet_directory_path();
+        let (is_dir, has_wallet) = tokio::task::spawn_blocking(move || {
+            (root.is_dir(), wallet_dir.is_dir())
+        })
+        .await?;
+
+        if !is_dir {
+            anyhow::bail!("That is not a directory.");
+        }
+        if !has_wallet {
+            anyhow::bail!(
+                "{data_dir} holds no {} wallet. Choose the directory neptune-core was given with --data-dir.",
+                hint.network
+            );
+        }
+        if let Err(e) = rpc_auth::Cookie::try_load(&data_dir).await {
+            anyhow::bail!("Could not read neptune-core's RPC cookie in {data_dir}: {e}");
+        }
+        Ok(())
+    }
+
+    /// Reads the cookie that neptune-core writes to its data directory.
+    /// neptune-core answering while its cookie cannot be read usually means
+    /// this app runs as another user, or cannot see that directory.
+    async fn gen_token() -> anyhow::Result<rpc_auth::Token> {
+        let data_dir = data_directory().await?;
+        match rpc_auth::Cookie::try_load(&data_dir).await {
+            Ok(cookie) => Ok(cookie.into()),
+            Err(e) => Err(Failure::CookieUnavailable(format!(
+                "Could not read neptune-core's RPC cookie in {data_dir}: {e}"
+            ))
+            .into()),
+        }
+    }
+
+    /// The token for authenticating RPC calls, cached until neptune-core
+    /// reconnects or rejects it.  Calls take it through [with_token] instead,
+    /// so that a rejected token is reloaded; this only checks that the
+    /// cookie can be read.
+    pub async fn get_token() -> anyhow::Result<rpc_auth::Token> {
+        rpc_connection::token(gen_token).await
+    }
+
+    /// Runs `call` with the token, reading neptune-core's cookie again and
+    /// retrying once if the token is rejected.  See
+    /// [rpc_connection::with_token].
+    pub async fn with_token<T, C, Fut>(call: C) -> anyhow::Result<T>
+    where
+        C: Fn(rpc_auth::Token) -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        rpc_connection::with_token(gen_token, call).await
+    }
+
+    async fn get_network() -> anyhow::Result<Network> {
         let client = rpc_client().await?;
-        let network = client.network(tarpc::context::current()).await??;
+        let network = client.network(call_context("network")).await??;
         Ok(network)
     }
 
-    pub async fn network() -> Result<Network, ApiError> {
-        // no caching for now. it's fast enough just to query from neptune-core
-        // and no need to invalidate upon connection error.
-        get_network().await
+    /// Returns the last known network at once, if any, refreshing it in the
+    /// background.  Otherwise queries neptune-core.
+    pub async fn network() -> anyhow::Result<Network> {
+        let last = *LAST_NETWORK.lock().unwrap();
+        match last {
+            Some(network) => {
+                tokio::spawn(async {
+                    let _ = refresh_network().await;
+                });
+                Ok(network)
+            }
+            None => refresh_network().await,
+        }
     }
 
+    async fn refresh_network() -> anyhow::Result<Network> {
+        let network = with_startup_timeout(get_network()).await?;
+        *LAST_NETWORK.lock().unwrap() = Some(network);
+        Ok(network)
+    }
+
+    /// Like [network], for [wallet_capabilities].
+    ///
+    /// Only for display.  Guards against spending must call
+    /// [wallet_capabilities], which always checks.
+    pub async fn cached_wallet_capabilities() -> anyhow::Result<WalletCapabilities> {
+        let last = *LAST_CAPABILITIES.lock().unwrap();
+        match last {
+            Some(capabilities) => {
+                tokio::spawn(async {
+                    let _ = with_startup_timeout(wallet_capabilities()).await;
+                });
+                Ok(capabilities)
+            }
+            None => with_startup_timeout(wallet_capabilities()).await,
+        }
+    }
+
+    /// Determines what the wallet can do.
+    ///
+    /// The wallet is watch-only if the `WATCH_ONLY` env var is set, or if
+    /// neptune-core's wallet directory holds no wallet secret.
+    ///
+    /// Developer tools are enabled by the `DEV_MODE` env var.
+    pub async fn wallet_capabilities() -> anyhow::Result<WalletCapabilities> {
+        let dev_tools = env_flag("DEV_MODE");
+        if env_flag("WATCH_ONLY") {
+            return Ok(WalletCapabilities::watch_only().with_dev_tools(dev_tools));
+        }
+
+        let data_dir = data_directory().await?;
+        let wallet_dir = data_dir.wallet_directory_path();
+        let has_secret = tokio::task::spawn_blocking(move || {
+            WalletFileContext::wallet_secret_path(&wallet_dir).exists()
+        })
+        .await?;
+
+        let capabilities = if has_secret {
+            WalletCapabilities::full()
+        } else {
+            WalletCapabilities::watch_only()
+        }
+        .with_dev_tools(dev_tools);
+        *LAST_CAPABILITIES.lock().unwrap() = Some(capabilities);
+        Ok(capabilities)
+    }
+
+    /// Reads a boolean env var: "true" or "1".
+    fn env_flag(name: &str) -> bool {
+        match std::env::var(name) {
+            Ok(val) => val.eq_ignore_ascii_case("true") || val == "1",
+            Err(_) => false,
+        }
+    }
+
+    /// Fails unless developer tools are enabled.
+    pub async fn require_dev_tools() -> anyhow::Result<()> {
+        if !wallet_capabilities().await?.dev_tools {
+            anyhow::bail!("Developer tools are disabled. Set DEV_MODE=true to enable them.");
+        }
+        Ok(())
+    }
+
+    pub async fn check_reachability() -> anyhow::Result<ReachabilityReport> {
+        let client = &rpc_client().await?;
+
+        let (listen_address, peers) = with_token(|token| async move {
+            let listen_address = client
+                .own_listen_address_for_peers(call_context("own_listen_address_for_peers"), token)
+                .await??;
+            let peers = client.peer_info(call_context("peer_info"), token).await??;
+            Ok((listen_address, peers))
+        })
+        .await?;
+        let num_inbound_peers = peers.iter().filter(|p| p.connection_is_inbound()).count();
+
+        let mut report = ReachabilityReport {
+            listen_address,
+            public_ip: None,
+            method: ReachabilityMethod::InboundPeers,
+            result: Reachability::Unknown,
+            num_inbound_peers,
+            num_outbound_peers: peers.len() - num_inbound_peers,
+            checker_error: None,
+        };
+
+        let Some(listen) = listen_address else {
+            report.result = Reachability::Unreachable;
+            return Ok(report);
+        };
+
+        if let Some(template) = std::env::var("REACHABILITY_CHECKER_URL")
+            .ok()
+            .filter(|s| !s.is_empty())
+        {
+            let url = template.replace("{port}", &listen.port().to_string());
+            match query_reachability_checker(&url).await {
+                Ok(response) => {
+                    report.method = ReachabilityMethod::ExternalChecker { url };
+                    report.public_ip = response.ip;
+                    report.result = if response.reachable {
+                        Reachability::Reachable
+                    } else {
+                        Reachability::Unreachable
+                    };
+                    return Ok(report);
+                }
+                Err(e) => report.checker_error = Some(format!("{:#}", e)),
+            }
+        }
+
+        // an inbound connection proves the port is reachable. The absence of
+        // one proves nothing, as peers may just not have found us yet.
+        if num_inbound_peers > 0 {
+            report.result = Reachability::Reachable;
+        }
+        Ok(report)
+    }
+
+    /// The response expected from a `REACHABILITY_CHECKER_URL` service.
+    #[derive(serde::Deserialize)]
+    struct CheckerResponse {
+        reachable: bool,
+        ip: Option<IpAddr>,
+    }
+
+    async fn query_reachability_checker(url: &str) -> anyhow::Result<CheckerResponse> {
+        let response = reqwest::Client::new()
+            .get(url)
+            .timeout(std::time::Duration::from_secs(20))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<CheckerResponse>()
+            .await?;
+        Ok(response)
+    }
+
+    /// Fails if the wallet is watch-only.
+    pub async fn require_spending() -> anyhow::Result<()> {
+        if wallet_capabilities().await?.is_watch_only() {
+            anyhow::bail!("This is a watch-only wallet. It cannot send transactions.");
+        }
+        Ok(())
+    }
+
+    /// Which of the spendable UTXOs [send] spends, by AOCL leaf index.
+    pub enum InputSelection {
+        /// As many as cover the outputs and fee, skipping these.
+        Excluding(Vec<u64>),
+
+        /// All of these, and no others.
+        Exactly(Vec<u64>),
+    }
+
+    /// Sends to `outputs`, with `announcement` as an extra announcement if
+    /// given.  Reports each stage to [crate::send_monitor], so the UI can
+    /// show progress while this runs.
+    ///
+    /// Uses neptune-core's lower-level transaction APIs, so that the stages
+    /// can be told apart and the announcement added to the transaction
+    /// details before proving.  Inputs are picked as `input_selection` says.
+    ///
+    /// Losing the connection to neptune-core before broadcasting is retried
+    /// up to [MAX_SEND_ATTEMPTS] times, as nothing was sent yet.  Once
+    /// broadcasting started it is not, as the transaction may have gone out.
     pub async fn send(
         outputs: Vec<OutputFormat>,
         change_policy: ChangePolicy,
         fee: NativeCurrencyAmount,
-    ) -> Result<(TransactionKernelId, TransactionDetails), ApiError> {
-        let serialized = bincode::serialize(&outputs).unwrap();
-        let nc_outputs: Vec<neptune_cash::api::export::OutputFormat> =
-            bincode::deserialize(&serialized).unwrap();
+        announcement: Option<Vec<super::BFieldElement>>,
+        input_selection: InputSelection,
+    ) -> anyhow::Result<(TransactionKernelId, TransactionDetails)> {
+        use neptune_cash::api::export::TxInputList;
+
+        let tracker = SendTracker::start();
+
+        let nc_announcement = announcement
+            .map(|message| nc_convert::announcement(&message))
+            .transpose()?;
+
+        let nc_outputs = outputs.to_nc()?;
+        let nc_change_policy = change_policy.to_nc()?;
+        let nc_fee = fee.to_nc()?;
+
+        let nc_outputs = &nc_outputs;
+        let nc_change_policy = &nc_change_policy;
+        let nc_announcement = &nc_announcement;
+        let input_selection = &input_selection;
+        let tracker = &tracker;
+
+        let mut attempt = 1;
+        let (client, tx_artifacts) = loop {
+            let built = async {
+                let client = &gen_nc_rpc_client().await?;
+                let tx_artifacts = with_token(|token| async move {
+                    let tx_outputs = client
+                        .generate_tx_outputs(call_context("generate_tx_outputs"), token, nc_outputs.clone())
+                        .await??;
+                    let needed = tx_outputs.total_native_coins() + nc_fee;
+
+                    let spendable = client
+                        .spendable_inputs(call_context("spendable_inputs"), token)
+                        .await??;
+
+                    let inputs = match input_selection {
+                        InputSelection::Excluding(do_not_spend) => {
+                            let mut selected = vec![];
+                            for input in spendable
+                                .into_iter()
+                                .filter(|input| !do_not_spend.contains(&input.mutator_set_mp().aocl_leaf_index))
+                            {
+                                if TxInputList::from(selected.clone()).total_native_coins() >= needed {
+                                    break;
+                                }
+                                selected.push(input);
+                            }
+                            let inputs = TxInputList::from(selected);
+                            if inputs.total_native_coins() < needed {
+                                anyhow::bail!(Failure::InsufficientBalance(
+                                    "The spendable balance does not cover the amount and fee.".to_string()
+                                ));
+                            }
+                            inputs
+                        }
+                        InputSelection::Exactly(aocl_indices) => {
+                            let inputs: TxInputList = spendable
+                                .into_iter()
+                                .filter(|input| aocl_indices.contains(&input.mutator_set_mp().aocl_leaf_index))
+                                .collect::<Vec<_>>()
+                                .into();
+                            if inputs.len() != aocl_indices.len() {
+                                anyhow::bail!(
+                                    "Only {} of the {} selected UTXOs are currently spendable.",
+                                    inputs.len(),
+                                    aocl_indices.len()
+                                );
+                            }
+                            if inputs.total_native_coins() < needed {
+                                anyhow::bail!(Failure::InsufficientBalance(
+                                    "The selected UTXOs do not cover the amount and fee.".to_string()
+                                ));
+                            }
+                            inputs
+                        }
+                    };
+
+                    tracker.set_stage(SendStage::Building);
+                    let mut details = client
+                        .generate_tx_details(
+                            call_context("generate_tx_details"),
+                            token,
+                            inputs,
+                            tx_outputs,
+                            nc_change_policy.clone(),
+                            nc_fee,
+                        )
+                        .await??;
+                    details.extra_announcements.extend(nc_announcement.clone());
+
+                    tracker.set_stage(SendStage::Proving);
+                    let proof = client
+                        .generate_witness_proof(call_context("generate_witness_proof"), token, details.clone())
+                        .await??;
+
+                    let tx_artifacts = client
+                        .assemble_transaction_artifacts(call_context("assemble_transaction_artifacts"), token, details, proof)
+                        .await??;
+                    Ok(tx_artifacts)
+                })
+                .await?;
+                anyhow::Ok((client.clone(), tx_artifacts))
+            }
+            .await;
+
+            match built {
+                Ok(built) => break built,
+                Err(e) if attempt < MAX_SEND_ATTEMPTS && crate::api_error::is_transient(&e) => {
+                    dioxus_logger::tracing::warn!(attempt, error = %format!("{:#}", e), "send failed, retrying");
+                    tokio::time::sleep(SEND_RETRY_DELAY * 2u32.pow(attempt - 1)).await;
+                    attempt += 1;
+                    tracker.set_attempt(attempt);
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        tracker.set_stage(SendStage::Broadcasting);
+        record_and_broadcast(&client, &tx_artifacts).await?;
+
+        let tx_kernel_id = TransactionKernelId::from_nc(&tx_artifacts.transaction().txid())?;
+        let tx_details = TransactionDetails::from_nc(tx_artifacts.details())?;
+        Ok((tx_kernel_id, tx_details))
+    }
 
-        let serialized = bincode::serialize(&change_policy).unwrap();
-        let nc_change_policy: neptune_cash::api::export::ChangePolicy =
-            bincode::deserialize(&serialized).unwrap();
+    /// Spends exactly the inputs with the given AOCL leaf indices to a new
+    /// Generation address of this wallet, less `fee`.
+    ///
+    /// Uses neptune-core's lower-level transaction APIs so that the inputs are
+    /// not subject to neptune-core's own input selection.
+    pub async fn consolidate(
+        aocl_indices: Vec<u64>,
+        fee: NativeCurrencyAmount,
+    ) -> anyhow::Result<(TransactionKernelId, TransactionDetails)> {
+        use neptune_cash::api::export::ChangePolicy as NcChangePolicy;
+        use neptune_cash::api::export::KeyType as NcKeyType;
+        use neptune_cash::api::export::OutputFormat as NcOutputFormat;
+        use neptune_cash::api::export::TxInputList;
+        use num_traits::CheckedSub;
+
+        let nc_fee = fee.to_nc()?;
+
+        let client = &gen_nc_rpc_client().await?;
+        let aocl_indices = &aocl_indices;
+        let tx_artifacts = with_token(|token| async move {
+            let spendable = client
+                .spendable_inputs(call_context("spendable_inputs"), token)
+                .await??;
+
+            let inputs: TxInputList = spendable
+                .into_iter()
+                .filter(|input| aocl_indices.contains(&input.mutator_set_mp().aocl_leaf_index))
+                .collect::<Vec<_>>()
+                .into();
+
+            if inputs.len() != aocl_indices.len() {
+                anyhow::bail!(
+                    "Only {} of the {} selected UTXOs are currently spendable.",
+                    inputs.len(),
+                    aocl_indices.len()
+                );
+            }
+
+            let total = inputs.total_native_coins();
+            let Some(amount) = total.checked_sub(&nc_fee) else {
+                anyhow::bail!(Failure::InsufficientBalance(
+                    "Fee exceeds the total of the selected UTXOs.".to_string()
+                ));
+            };
+
+            let address = client
+                .next_receiving_address(call_context("next_receiving_address"), token, NcKeyType::Generation)
+                .await??;
+
+            let outputs = client
+                .generate_tx_outputs(
+                    call_context("generate_tx_outputs"),
+                    token,
+                    vec![NcOutputFormat::AddressAndAmount(address, amount)],
+                )
+                .await??;
+
+            let details = client
+                .generate_tx_details(
+                    call_context("generate_tx_details"),
+                    token,
+                    inputs,
+                    outputs,
+                    NcChangePolicy::default(),
+                    nc_fee,
+                )
+                .await??;
+
+            let proof = client
+                .generate_witness_proof(call_context("generate_witness_proof"), token, details.clone())
+                .await??;
+
+            let tx_artifacts = client
+                .assemble_transaction_artifacts(call_context("assemble_transaction_artifacts"), token, details, proof)
+                .await??;
+            Ok(tx_artifacts)
+        })
+        .await?;
+
+        record_and_broadcast(client, &tx_artifacts).await?;
+
+        let tx_kernel_id = TransactionKernelId::from_nc(&tx_artifacts.transaction().txid())?;
+        let tx_details = TransactionDetails::from_nc(tx_artifacts.details())?;
+        Ok((tx_kernel_id, tx_details))
+    }
 
-        let serialized = bincode::serialize(&fee).unwrap();
-        let nc_fee: neptune_cash::api::export::NativeCurrencyAmount =
-            bincode::deserialize(&serialized).unwrap();
+    /// Broadcasts a transaction that carries `message` as an announcement,
+    /// and otherwise only returns change to this wallet, less `fee`.
+    ///
+    /// Inputs are taken from the spendable UTXOs until they cover the fee.
+    /// Relies on neptune-core including `extra_announcements` of the
+    /// transaction details in the transaction it assembles.
+    pub async fn broadcast_announcement(
+        message: Vec<super::BFieldElement>,
+        fee: NativeCurrencyAmount,
+    ) -> anyhow::Result<TransactionKernelId> {
+        use neptune_cash::api::export::ChangePolicy as NcChangePolicy;
+        use neptune_cash::api::export::TxInputList;
+
+        let nc_announcement = nc_convert::announcement(&message)?;
+
+        let nc_fee = fee.to_nc()?;
+
+        let client = &gen_nc_rpc_client().await?;
+        let nc_announcement = &nc_announcement;
+        let tx_artifacts = with_token(|token| async move {
+            let spendable = client
+                .spendable_inputs(call_context("spendable_inputs"), token)
+                .await??;
+
+            let mut selected = vec![];
+            for input in spendable {
+                if TxInputList::from(selected.clone()).total_native_coins() >= nc_fee {
+                    break;
+                }
+                selected.push(input);
+            }
+            let inputs = TxInputList::from(selected);
+            if inputs.total_native_coins() < nc_fee {
+                anyhow::bail!(Failure::InsufficientBalance(
+                    "The spendable balance does not cover the fee.".to_string()
+                ));
+            }
+
+            let outputs = client
+                .generate_tx_outputs(call_context("generate_tx_outputs"), token, vec![])
+                .await??;
+
+            let mut details = client
+                .generate_tx_details(
+                    call_context("generate_tx_details"),
+                    token,
+                    inputs,
+                    outputs,
+                    NcChangePolicy::default(),
+                    nc_fee,
+                )
+                .await??;
+            details.extra_announcements.push(nc_announcement.clone());
+
+            let proof = client
+                .generate_witness_proof(call_context("generate_witness_proof"), token, details.clone())
+                .await??;
+
+            let tx_artifacts = client
+                .assemble_transaction_artifacts(call_context("assemble_transaction_artifacts"), token, details, proof)
+                .await??;
+            Ok(tx_artifacts)
+        })
+        .await?;
+
+        record_and_broadcast(client, &tx_artifacts).await?;
+
+        let tx_kernel_id = TransactionKernelId::from_nc(&tx_artifacts.transaction().txid())?;
+        Ok(tx_kernel_id)
+    }
 
-        let client = gen_nc_rpc_client().await?;
-        let token = get_token().await?;
+    /// Broadcasts a signed transaction, given as the bincode of
+    /// neptune-core's `TxCreationArtifacts`.
+    /// Returns the transaction's kernel id, number of outputs and fee.
+    pub async fn broadcast_transaction(
+        bytes: &[u8],
+    ) -> anyhow::Result<(TransactionKernelId, usize, NativeCurrencyAmount)> {
+        let tx_artifacts: TxCreationArtifacts = bincode::deserialize(bytes)
+            .map_err(|_| anyhow::anyhow!("Not a serialized signed transaction."))?;
+        let details = tx_artifacts.details();
+        let tx_network = details.network.to_string();
+        let network = network().await?.to_string();
+        if tx_network != network {
+            anyhow::bail!(
+                "This transaction is for {}, but the node runs on {}.",
+                tx_network,
+                network
+            );
+        }
+        let num_outputs = details.tx_outputs.len();
+        let fee = NativeCurrencyAmount::from_nau(details.fee.to_nau());
 
-        let tx_artifacts = client
-            .send(
-                tarpc::context::current(),
-                token,
-                nc_outputs,
-                nc_change_policy,
-                nc_fee,
-            )
-            .await??;
+        let client = &gen_nc_rpc_client().await?;
+        record_and_broadcast(client, &tx_artifacts).await?;
 
-        let serialized = bincode::serialize(&tx_artifacts.transaction().txid()).unwrap();
-        let tx_kernel_id: TransactionKernelId = bincode::deserialize(&serialized).unwrap();
+        let tx_kernel_id = TransactionKernelId::from_nc(&tx_artifacts.transaction().txid())?;
+        Ok((tx_kernel_id, num_outputs, fee))
+    }
 
-        let serialized = bincode::serialize(tx_artifacts.details()).unwrap();
-        let tx_details: TransactionDetails = bincode::deserialize(&serialized).unwrap();
-        Ok((tx_kernel_id, tx_details))
+    /// Records and broadcasts a proven transaction, and remembers it in
+    /// [crate::sent_txs].
+    ///
+    /// Called with its own [with_token], apart from building and proving, so
+    /// that a rejected token does not prove the transaction again.  A
+    /// transient failure leaves it unknown whether the node broadcast the
+    /// transaction, which the error says.
+    async fn record_and_broadcast(
+        client: &NcClient,
+        tx_artifacts: &TxCreationArtifacts,
+    ) -> anyhow::Result<()> {
+        let broadcast = with_token(|token| async move {
+            client
+                .record_and_broadcast_transaction(call_context("record_and_broadcast_transaction"), token, tx_artifacts.clone())
+                .await??;
+            Ok(())
+        })
+        .await;
+        if let Err(e) = broadcast {
+            if crate::api_error::is_transient(&e) {
+                return Err(e.context(Failure::BroadcastUnknown(
+                    "The transaction may have been broadcast; check the mempool before sending again".to_string(),
+                )));
+            }
+            return Err(e);
+        }
+
+        let details = tx_artifacts.details();
+        crate::sent_txs::insert(
+            TransactionKernelId::from_nc(&tx_artifacts.transaction().txid())?,
+            crate::sent_txs::SentTx {
+                fee: NativeCurrencyAmount::from_nau(details.fee.to_nau()),
+                inputs: details
+                    .tx_inputs
+                    .clone()
+                    .into_iter()
+                    .map(|input| input.mutator_set_mp().aocl_leaf_index)
+                    .collect(),
+            },
+        );
+        Ok(())
+    }
+
+    /// The number of UTXOs the wallet expects but has not received.  An
+    /// estimate, as a UTXO may also arrive without being expected.
+    pub async fn num_unclaimed_utxos() -> anyhow::Result<u64> {
+        let client = &rpc_client().await?;
+
+        // expected UTXOs include those already received.
+        with_token(|token| async move {
+            let expected = client
+                .num_expected_utxos(call_context("num_expected_utxos"), token)
+                .await??;
+            let received = client.list_utxos(call_context("list_utxos"), token).await??.len() as u64;
+            Ok(expected.saturating_sub(received))
+        })
+        .await
+    }
+
+    /// True if the wallet has never received or sent anything.
+    pub async fn wallet_is_new() -> anyhow::Result<bool> {
+        let client = &rpc_client().await?;
+        with_token(|token| async move {
+            Ok(client.history(call_context("history"), token).await??.is_empty())
+        })
+        .await
+    }
+
+    /// Every transaction in the mempool, fetched a page at a time.
+    pub async fn mempool_transactions() -> anyhow::Result<Vec<MempoolTransactionInfo>> {
+        const PAGE_SIZE: usize = 500;
+
+        let client = &rpc_client().await?;
+
+        let mut txs = vec![];
+        let mut start_index = 0;
+        loop {
+            let page = with_token(|token| async move {
+                Ok(client
+                    .mempool_overview(call_context("mempool_overview"), token, start_index, PAGE_SIZE)
+                    .await??)
+            })
+            .await?;
+            let page_len = page.len();
+            txs.extend(page);
+
+            if page_len < PAGE_SIZE {
+                break;
+            }
+            start_index += PAGE_SIZE;
+        }
+        Ok(txs)
     }
 
-    // fn tx_artifacts_to_tx_details(tx_artifacts: TxCreationArtifacts) -> Result<TransactionDetails, ApiError> {
+    // fn tx_artifacts_to_tx_details(tx_artifacts: TxCreationArtifacts) -> anyhow::Result<TransactionDetails> {
     //     let json = serde_json::to_string(tx_artifacts.details())?;
     //     let tx_details: TransactionDetails = serde_json::from_str(&json)?;
     //     Ok(tx_details)
@@ -0,0 +1,22 @@
+//! Server log entries, as tailed by the Logs screen.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::prefs::log_level::LogLevel;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// Increases by one with each entry recorded, so that a client can ask
+    /// for only the entries after the last it has seen.
+    pub seq: u64,
+    /// unix time in milliseconds
+    pub at_ms: u64,
+    pub level: LogLevel,
+    /// The module that logged the entry, eg "api::notifier".
+    pub target: String,
+    /// The spans the entry was logged in, outermost first, eg "notifier".
+    pub spans: String,
+    /// The message, followed by any fields as `name=value`.
+    pub message: String,
+}
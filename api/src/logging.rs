@@ -0,0 +1,210 @@
+//! Logging setup.
+//!
+//! On the server, log entries go to stderr and into a buffer of recent entries
+//! that the Logs screen tails.  The level comes from the `LOG_LEVEL` env var,
+//! and can be changed at runtime from the app, which saves it as a pref.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use server::apply_saved_level;
+#[cfg(not(target_arch = "wasm32"))]
+pub use server::init;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use server::recent;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use server::set_level;
+
+/// Installs the logger.  Call once, at the start of `main`.
+#[cfg(target_arch = "wasm32")]
+pub fn init() {
+    dioxus_logger::init(dioxus_logger::tracing::Level::INFO).expect("failed to init logger");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod server {
+    use std::collections::VecDeque;
+    use std::fmt::Write;
+    use std::sync::atomic::AtomicU64;
+    use std::sync::atomic::Ordering;
+    use std::sync::Mutex;
+    use std::sync::OnceLock;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    use dioxus_logger::tracing::field::Field;
+    use dioxus_logger::tracing::field::Visit;
+    use dioxus_logger::tracing::span;
+    use dioxus_logger::tracing::Event;
+    use dioxus_logger::tracing::Level;
+    use dioxus_logger::tracing::Subscriber;
+    use tracing_subscriber::filter::LevelFilter;
+    use tracing_subscriber::layer::Context;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::registry::LookupSpan;
+    use tracing_subscriber::reload;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::Layer;
+    use tracing_subscriber::Registry;
+
+    use crate::log_entry::LogEntry;
+    use crate::prefs::log_level::LogLevel;
+    use crate::prefs_store;
+
+    /// The most entries kept.  Older entries are dropped.
+    const MAX_RECENT: usize = 2_000;
+
+    /// Recent entries, oldest first.
+    static RECENT: Mutex<VecDeque<LogEntry>> = Mutex::new(VecDeque::new());
+
+    static NEXT_SEQ: AtomicU64 = AtomicU64::new(1);
+
+    static LEVEL: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+    /// Installs the logger.  Call once, at the start of `main`.
+    pub fn init() {
+        let level = LogLevel::from_env().unwrap_or_default();
+        let (filter, handle) = reload::Layer::new(level_filter(level));
+        let _ = LEVEL.set(handle);
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(tracing_subscriber::fmt::layer())
+            .with(RecentLayer)
+            .try_init()
+            .expect("failed to init logger");
+    }
+
+    /// Changes the level from now on.
+    pub fn set_level(level: LogLevel) {
+        let Some(handle) = LEVEL.get() else {
+            return;
+        };
+        if let Err(e) = handle.reload(level_filter(level)) {
+            dioxus_logger::tracing::warn!(error = %e, "could not change log level");
+        }
+    }
+
+    /// Applies the level saved in the prefs, which overrides `LOG_LEVEL`.
+    pub async fn apply_saved_level() {
+        match prefs_store::load().await {
+            Ok(prefs) => set_level(prefs.log_level()),
+            Err(e) => dioxus_logger::tracing::debug!(error = %e, "could not load log level"),
+        }
+    }
+
+    /// The recorded entries at `level` or more severe, with a `seq` above
+    /// `after`, oldest first.
+    pub fn recent(level: LogLevel, after: Option<u64>) -> Vec<LogEntry> {
+        let after = after.unwrap_or(0);
+        RECENT
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|entry| entry.seq > after && entry.level <= level)
+            .cloned()
+            .collect()
+    }
+
+    fn level_filter(level: LogLevel) -> LevelFilter {
+        match level {
+            LogLevel::Error => LevelFilter::ERROR,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+
+    fn log_level(level: &Level) -> LogLevel {
+        match *level {
+            Level::ERROR => LogLevel::Error,
+            Level::WARN => LogLevel::Warn,
+            Level::INFO => LogLevel::Info,
+            Level::DEBUG => LogLevel::Debug,
+            Level::TRACE => LogLevel::Trace,
+        }
+    }
+
+    /// Collects an event's or span's fields as text.
+    #[derive(Default)]
+    struct Fields {
+        message: String,
+        /// fields other than the message, each preceded by a space.
+        rest: String,
+    }
+
+    impl Visit for Fields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message.push_str(value);
+            } else {
+                let _ = write!(self.rest, " {}={}", field.name(), value);
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                let _ = write!(self.message, "{:?}", value);
+            } else {
+                let _ = write!(self.rest, " {}={:?}", field.name(), value);
+            }
+        }
+    }
+
+    /// A span's fields, as text, kept in the span's extensions.
+    struct SpanFields(String);
+
+    /// Records each event into [RECENT].
+    struct RecentLayer;
+
+    impl<S> Layer<S> for RecentLayer
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+            let mut fields = Fields::default();
+            attrs.record(&mut fields);
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(SpanFields(fields.rest));
+            }
+        }
+
+        fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+
+            let spans = ctx
+                .event_scope(event)
+                .map(|scope| {
+                    scope
+                        .from_root()
+                        .map(|span| match span.extensions().get::<SpanFields>() {
+                            Some(SpanFields(f)) if !f.is_empty() => {
+                                format!("{}{{{}}}", span.name(), f.trim_start())
+                            }
+                            _ => span.name().to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(":")
+                })
+                .unwrap_or_default();
+
+            let metadata = event.metadata();
+            let entry = LogEntry {
+                seq: NEXT_SEQ.fetch_add(1, Ordering::Relaxed),
+                at_ms: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64,
+                level: log_level(metadata.level()),
+                target: metadata.target().to_string(),
+                spans,
+                message: fields.message + &fields.rest,
+            };
+
+            let mut recent = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+            if recent.len() >= MAX_RECENT {
+                recent.pop_front();
+            }
+            recent.push_back(entry);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! A neptune-core process that the wallet starts and supervises, for desktop
+//! users who would rather not run neptune-core themselves.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How to run the managed neptune-core.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManagedNodeConfig {
+    /// Whether the wallet runs neptune-core.  Off by default, as most users
+    /// run it themselves.
+    #[serde(default)]
+    pub enabled: bool,
+    /// The neptune-core executable.  A bare name is looked up on the PATH.
+    #[serde(default = "default_binary")]
+    pub binary: String,
+    /// Arguments passed to neptune-core, eg `--network testnet`.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl Default for ManagedNodeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            binary: default_binary(),
+            args: vec![],
+        }
+    }
+}
+
+fn default_binary() -> String {
+    "neptune-core".to_string()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ManagedNodeState {
+    /// The wallet does not run neptune-core.
+    Disabled,
+    /// The process is running.
+    Running {
+        pid: u32,
+        /// unix time in milliseconds
+        since_ms: u64,
+    },
+    /// The process exited, or could not be started, and is started again
+    /// after a delay.
+    Restarting { retry_at_ms: u64 },
+    /// The process is being shut down, eg because it was disabled.
+    Stopping,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManagedNodeStatus {
+    pub state: ManagedNodeState,
+    /// True if neptune-core answered the last health check.  Checked only
+    /// while running.
+    pub responding: bool,
+    /// Times the process was started again after exiting or failing to start.
+    pub restarts: u32,
+    /// Why the process last exited or failed to start, if it did.
+    pub last_exit: Option<String>,
+}
+
+/// A line the managed neptune-core wrote to stdout or stderr.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeLogLine {
+    /// Increases by one with each line, so that a client can ask for only the
+    /// lines after the last it has seen.
+    pub seq: u64,
+    /// unix time in milliseconds
+    pub at_ms: u64,
+    pub stderr: bool,
+    pub text: String,
+}
@@ -0,0 +1,18 @@
+//! Persists the [ManagedNodeConfig].
+//!
+//! The config is needed to start neptune-core, before its data directory is
+//! known, so it is kept in this app's own config directory rather than with
+//! the other prefs.
+
+use crate::json_store::JsonStore;
+use crate::managed_node::ManagedNodeConfig;
+
+static STORE: JsonStore<ManagedNodeConfig> = JsonStore::in_config_dir("managed-node.json");
+
+pub async fn load() -> anyhow::Result<ManagedNodeConfig> {
+    STORE.load().await
+}
+
+pub async fn save(config: &ManagedNodeConfig) -> anyhow::Result<()> {
+    STORE.save(config).await
+}
@@ -0,0 +1,88 @@
+//! The mempool a page at a time, sorted by the server so that large mempools
+//! need not be sent to, and sorted by, the UI.
+
+use neptune_types::mempool_transaction_info::MempoolTransactionInfo;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use num_traits::CheckedSub;
+use num_traits::Zero;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MempoolSortColumn {
+    Id,
+    ProofType,
+    Inputs,
+    Outputs,
+    BalanceEffect,
+    #[default]
+    Fee,
+    Synced,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MempoolSort {
+    pub column: MempoolSortColumn,
+    pub descending: bool,
+}
+
+impl Default for MempoolSort {
+    /// Highest fee first, ie the order in which miners pick transactions.
+    fn default() -> Self {
+        Self {
+            column: MempoolSortColumn::Fee,
+            descending: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MempoolPage {
+    pub txs: Vec<MempoolTransactionInfo>,
+    /// The number of transactions in the whole mempool.
+    pub total: usize,
+}
+
+/// True if the transaction spends from, or pays to, this wallet.
+pub fn affects_wallet(tx: &MempoolTransactionInfo) -> bool {
+    !tx.positive_balance_effect.is_zero() || !tx.negative_balance_effect.is_zero()
+}
+
+/// The balance effect as a single amount, for sorting.
+fn balance_effect(tx: &MempoolTransactionInfo) -> NativeCurrencyAmount {
+    tx.positive_balance_effect
+        .checked_sub(&tx.negative_balance_effect)
+        .unwrap_or_default()
+}
+
+/// Sorts `txs` and returns the `number` transactions from `start_index`.
+pub fn page(
+    mut txs: Vec<MempoolTransactionInfo>,
+    start_index: usize,
+    number: usize,
+    sort: MempoolSort,
+) -> MempoolPage {
+    txs.sort_by(|a, b| {
+        let ordering = match sort.column {
+            MempoolSortColumn::Id => a.id.cmp(&b.id),
+            MempoolSortColumn::ProofType => {
+                a.proof_type.to_string().cmp(&b.proof_type.to_string())
+            }
+            MempoolSortColumn::Inputs => a.num_inputs.cmp(&b.num_inputs),
+            MempoolSortColumn::Outputs => a.num_outputs.cmp(&b.num_outputs),
+            MempoolSortColumn::BalanceEffect => balance_effect(a).cmp(&balance_effect(b)),
+            MempoolSortColumn::Fee => a.fee.cmp(&b.fee),
+            MempoolSortColumn::Synced => a.synced.cmp(&b.synced),
+        };
+        if sort.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    let total = txs.len();
+    MempoolPage {
+        txs: txs.into_iter().skip(start_index).take(number).collect(),
+        total,
+    }
+}
@@ -0,0 +1,108 @@
+//! Conversions between neptune_types values, which the UI can compile to
+//! wasm, and their twins in neptune-cash, which the RPC client speaks.
+//!
+//! Neither crate is ours, so `From` cannot be implemented between them;
+//! [ToNc] and [FromNc] stand in.  Amounts and transaction ids convert
+//! directly.  The remaining types hold neptune-cash internals that are not
+//! public, such as mutator set membership proofs, and are carried across by
+//! their shared serde representation.  Either way a failure is an error,
+//! never a panic.
+
+use anyhow::Context;
+use neptune_cash::api::export::Announcement as NcAnnouncement;
+use neptune_cash::api::export::ChangePolicy as NcChangePolicy;
+use neptune_cash::api::export::NativeCurrencyAmount as NcNativeCurrencyAmount;
+use neptune_cash::api::export::OutputFormat as NcOutputFormat;
+use neptune_cash::api::export::TransactionDetails as NcTransactionDetails;
+use neptune_cash::api::export::TransactionKernelId as NcTransactionKernelId;
+use neptune_types::change_policy::ChangePolicy;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::output_format::OutputFormat;
+use neptune_types::transaction_details::TransactionDetails;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use twenty_first::prelude::BFieldElement;
+
+/// Converts a neptune_types value to its neptune-cash twin.
+pub trait ToNc {
+    type Nc;
+
+    fn to_nc(&self) -> anyhow::Result<Self::Nc>;
+}
+
+/// Converts a neptune-cash value to its neptune_types twin.
+pub trait FromNc<T>: Sized {
+    fn from_nc(value: &T) -> anyhow::Result<Self>;
+}
+
+/// Converts through the serde representation that both crates share.
+fn via_serde<S: Serialize, T: DeserializeOwned>(value: &S) -> anyhow::Result<T> {
+    let bytes = bincode::serialize(value)?;
+    bincode::deserialize(&bytes).with_context(|| {
+        format!(
+            "Could not convert {} to {}",
+            std::any::type_name::<S>(),
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+impl ToNc for NativeCurrencyAmount {
+    type Nc = NcNativeCurrencyAmount;
+
+    fn to_nc(&self) -> anyhow::Result<Self::Nc> {
+        Ok(NcNativeCurrencyAmount::from_nau(self.to_nau()))
+    }
+}
+
+impl FromNc<NcNativeCurrencyAmount> for NativeCurrencyAmount {
+    fn from_nc(value: &NcNativeCurrencyAmount) -> anyhow::Result<Self> {
+        Ok(NativeCurrencyAmount::from_nau(value.to_nau()))
+    }
+}
+
+impl FromNc<NcTransactionKernelId> for TransactionKernelId {
+    fn from_nc(value: &NcTransactionKernelId) -> anyhow::Result<Self> {
+        // both print and parse as the hex of the kernel's digest.
+        value
+            .to_string()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Could not convert transaction id {}", value))
+    }
+}
+
+impl ToNc for ChangePolicy {
+    type Nc = NcChangePolicy;
+
+    fn to_nc(&self) -> anyhow::Result<Self::Nc> {
+        via_serde(self)
+    }
+}
+
+impl ToNc for OutputFormat {
+    type Nc = NcOutputFormat;
+
+    fn to_nc(&self) -> anyhow::Result<Self::Nc> {
+        via_serde(self)
+    }
+}
+
+impl FromNc<NcTransactionDetails> for TransactionDetails {
+    fn from_nc(value: &NcTransactionDetails) -> anyhow::Result<Self> {
+        via_serde(value)
+    }
+}
+
+impl<T: ToNc> ToNc for Vec<T> {
+    type Nc = Vec<T::Nc>;
+
+    fn to_nc(&self) -> anyhow::Result<Self::Nc> {
+        self.iter().map(ToNc::to_nc).collect()
+    }
+}
+
+/// An announcement is just its message.
+pub fn announcement(message: &[BFieldElement]) -> anyhow::Result<NcAnnouncement> {
+    via_serde(&message)
+}
@@ -0,0 +1,174 @@
+//! Detects incoming funds, and confirmed sends, as they appear in the wallet
+//! history.
+//!
+//! Reacting to a receipt at the time it happens lets us export an event,
+//! capture the fiat price and notify the user. A background task polls the history so that this
+//! does not depend on which screen the user has open.
+//!
+//! The last block processed is stored with the wallet, so that receipts that
+//! arrived while the server was down are reported once it runs again.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use dioxus_logger::tracing::Instrument;
+use neptune_types::block_height::BlockHeight;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::timestamp::Timestamp;
+use num_traits::Zero;
+use twenty_first::tip5::Digest;
+
+use crate::bandwidth;
+use crate::cost_basis_store;
+use crate::event_log;
+use crate::neptune_rpc;
+use crate::notification::NotificationKind;
+use crate::notification_store;
+use crate::receipt_store;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Funds received in a single block, netted over all of the block's entries.
+/// A negative amount means that funds were spent.
+#[derive(Clone, Copy, Debug)]
+pub struct Receipt {
+    pub block_digest: Digest,
+    pub block_height: BlockHeight,
+    pub timestamp: Timestamp,
+    pub amount: NativeCurrencyAmount,
+}
+
+/// Returns the per-block changes in `history` above `last_block`, whether
+/// incoming or outgoing, and the highest block of `history`, to pass as
+/// `last_block` next time.
+///
+/// A block at the height of `last_block` but with another digest replaced it
+/// in a reorg, and is new.
+pub fn detect(
+    history: &[(Digest, BlockHeight, Timestamp, NativeCurrencyAmount)],
+    last_block: Option<(Digest, BlockHeight)>,
+) -> (Vec<Receipt>, Option<(Digest, BlockHeight)>) {
+    let is_new = |digest: Digest, height: BlockHeight| match last_block {
+        None => true,
+        Some((last_digest, last_height)) => {
+            let (height, last_height) = (u64::from(height), u64::from(last_height));
+            height > last_height || (height == last_height && digest != last_digest)
+        }
+    };
+
+    // a block may contain several entries for this wallet; net them per block.
+    let mut per_block: Vec<Receipt> = vec![];
+    for (digest, height, timestamp, amount) in history {
+        if !is_new(*digest, *height) {
+            continue;
+        }
+        match per_block
+            .iter_mut()
+            .find(|r| r.block_digest == *digest && r.block_height == *height)
+        {
+            Some(r) => r.amount = r.amount + *amount,
+            None => per_block.push(Receipt {
+                block_digest: *digest,
+                block_height: *height,
+                timestamp: *timestamp,
+                amount: *amount,
+            }),
+        }
+    }
+
+    let highest = history
+        .iter()
+        .map(|(digest, height, _, _)| (*digest, *height))
+        .max_by_key(|(_, height)| u64::from(*height))
+        .or(last_block);
+
+    let changes = per_block
+        .into_iter()
+        .filter(|r| !r.amount.is_zero())
+        .collect();
+    (changes, highest)
+}
+
+/// Exports and captures the cost basis of any new receipts in `history`, and
+/// raises notifications for them and for confirmed sends.
+pub async fn process(history: &[(Digest, BlockHeight, Timestamp, NativeCurrencyAmount)]) {
+    let mut changes = vec![];
+    let updated = receipt_store::update(|processed| {
+        let (new, last_block) = detect(history, processed.last_block);
+        // the first read only records where the history ends, so that
+        // existing history is not reported as new.
+        if processed.seeded {
+            changes = new;
+        }
+        processed.seeded = true;
+        processed.last_block = last_block;
+    })
+    .await;
+    if let Err(e) = updated {
+        dioxus_logger::tracing::warn!(error = %e, "could not store processed receipts");
+        return;
+    }
+
+    let (receipts, spends): (Vec<Receipt>, Vec<Receipt>) = changes
+        .into_iter()
+        .partition(|r| r.amount > NativeCurrencyAmount::zero());
+
+    for spend in &spends {
+        notify(NotificationKind::SendConfirmed {
+            amount: -spend.amount,
+            block_height: spend.block_height.into(),
+        })
+        .await;
+    }
+    for receipt in &receipts {
+        notify(NotificationKind::FundsReceived {
+            amount: receipt.amount,
+            block_height: receipt.block_height.into(),
+        })
+        .await;
+    }
+
+    event_log::record_receipts(&receipts).await;
+    // also fills in the prices of earlier receipts, if they could not be
+    // fetched then.
+    if let Err(e) = cost_basis_store::capture(&receipts).await {
+        dioxus_logger::tracing::warn!(error = %e, "could not store receipts for cost basis");
+    }
+}
+
+async fn notify(kind: NotificationKind) {
+    if let Err(e) = notification_store::push(kind).await {
+        dioxus_logger::tracing::warn!(error = %e, "could not store notification");
+    }
+}
+
+/// Starts the background task that polls the wallet history, if not running.
+pub fn ensure_watching() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    STARTED.get_or_init(|| {
+        tokio::spawn(
+            async {
+                loop {
+                    if let Err(e) = poll().await {
+                        dioxus_logger::tracing::debug!(error = %e, "poll failed");
+                    }
+                    tokio::time::sleep(bandwidth::scale(POLL_INTERVAL).await).await;
+                }
+            }
+            .instrument(dioxus_logger::tracing::info_span!("receipt_watcher")),
+        );
+    });
+}
+
+async fn poll() -> anyhow::Result<()> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let history = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .history(neptune_rpc::call_context("history"), token)
+            .await??)
+    })
+    .await?;
+    process(&history).await;
+    Ok(())
+}
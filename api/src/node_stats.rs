@@ -0,0 +1,44 @@
+//! Time series of node health, sampled in the background while the server
+//! runs, for the Node Stats screen.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A quantity sampled over time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(strum::EnumIter)]
+pub enum NodeMetric {
+    /// Seconds between a block and its predecessor, sampled per new block.
+    BlockInterval,
+    /// Bytes of transactions waiting in the mempool.
+    MempoolSize,
+    PeerCount,
+    /// The height of the node's tip.  Climbs steeply while syncing.
+    BlockHeight,
+}
+
+/// How far back a series goes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, strum::EnumIter)]
+pub enum StatsWindow {
+    Hour,
+    #[default]
+    Day,
+    Week,
+}
+
+impl StatsWindow {
+    pub fn secs(&self) -> u64 {
+        match self {
+            Self::Hour => 60 * 60,
+            Self::Day => 24 * 60 * 60,
+            Self::Week => 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatsSample {
+    /// unix time in seconds
+    pub time: u64,
+    pub value: f64,
+}
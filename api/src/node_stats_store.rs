@@ -0,0 +1,184 @@
+//! Samples [NodeMetric]s once a minute into ring buffers, persisted as a
+//! JSON file alongside the neptune-core wallet so that history survives a
+//! restart.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use dioxus_logger::tracing::Instrument;
+use neptune_types::block_selector::BlockSelector;
+use neptune_types::block_selector::BlockSelectorLiteral;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::bandwidth;
+use crate::json_store::JsonStore;
+use crate::neptune_rpc;
+use crate::node_stats::NodeMetric;
+use crate::node_stats::StatsSample;
+use crate::node_stats::StatsWindow;
+
+static STORE: JsonStore<Series> =
+    JsonStore::in_wallet_dir("neptune-proton-node-stats.json").compact();
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A week of samples at one a minute.  Block intervals are sampled per
+/// block, so fit comfortably.
+const MAX_SAMPLES: usize = 7 * 24 * 60;
+
+/// Samples taken between writes to disk.
+const SAVE_EVERY: usize = 10;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct Series {
+    #[serde(default)]
+    samples: BTreeMap<NodeMetric, VecDeque<StatsSample>>,
+    /// The last block whose interval was recorded.
+    #[serde(default)]
+    last_interval_height: u64,
+}
+
+impl Series {
+    fn push(&mut self, metric: NodeMetric, sample: StatsSample) {
+        let samples = self.samples.entry(metric).or_default();
+        samples.push_back(sample);
+        while samples.len() > MAX_SAMPLES {
+            samples.pop_front();
+        }
+    }
+}
+
+static SERIES: Mutex<Option<Series>> = Mutex::new(None);
+
+/// Starts the background task that samples node stats, if not already
+/// running.
+pub fn ensure_collecting() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    STARTED.get_or_init(|| {
+        tokio::spawn(
+            async {
+                let restored = match STORE.load().await {
+                    Ok(series) => series,
+                    Err(e) => {
+                        dioxus_logger::tracing::warn!(error = %e, "could not restore samples");
+                        Series::default()
+                    }
+                };
+                *lock() = Some(restored);
+
+                let mut num_unsaved = 0;
+                loop {
+                    match sample().await {
+                        Ok(()) => num_unsaved += 1,
+                        Err(e) => dioxus_logger::tracing::debug!(error = %e, "sample failed"),
+                    }
+                    if num_unsaved >= SAVE_EVERY {
+                        num_unsaved = 0;
+                        if let Err(e) = write().await {
+                            dioxus_logger::tracing::warn!(error = %e, "could not save samples");
+                        }
+                    }
+                    tokio::time::sleep(bandwidth::scale(SAMPLE_INTERVAL).await).await;
+                }
+            }
+            .instrument(dioxus_logger::tracing::info_span!("node_stats")),
+        );
+    });
+}
+
+/// The samples of `metric` within `window`, oldest first.
+pub fn series(metric: NodeMetric, window: StatsWindow) -> Vec<StatsSample> {
+    let since = now().saturating_sub(window.secs());
+    lock()
+        .as_ref()
+        .and_then(|series| series.samples.get(&metric))
+        .map(|samples| samples.iter().filter(|s| s.time >= since).copied().collect())
+        .unwrap_or_default()
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Series>> {
+    SERIES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+async fn sample() -> anyhow::Result<()> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let (data, intervals) = neptune_rpc::with_token(|token| async move {
+        let data = client
+            .dashboard_overview_data(neptune_rpc::call_context("dashboard_overview_data"), token)
+            .await??;
+        let intervals = client
+            .block_intervals(
+                neptune_rpc::call_context("block_intervals"),
+                token,
+                BlockSelector::Special(BlockSelectorLiteral::Tip),
+                Some(1),
+            )
+            .await??
+            .unwrap_or_default();
+        Ok((data, intervals))
+    })
+    .await?;
+
+    let time = now();
+    let mut guard = lock();
+    let series = guard.get_or_insert_with(Series::default);
+
+    series.push(
+        NodeMetric::MempoolSize,
+        StatsSample {
+            time,
+            value: data.mempool_size as f64,
+        },
+    );
+    if let Some(peer_count) = data.peer_count {
+        series.push(
+            NodeMetric::PeerCount,
+            StatsSample {
+                time,
+                value: peer_count as f64,
+            },
+        );
+    }
+    series.push(
+        NodeMetric::BlockHeight,
+        StatsSample {
+            time,
+            value: u64::from(data.tip_header.height) as f64,
+        },
+    );
+    // (height, milliseconds since the previous block)
+    for (height, interval_ms) in intervals {
+        if height > series.last_interval_height {
+            series.last_interval_height = height;
+            series.push(
+                NodeMetric::BlockInterval,
+                StatsSample {
+                    time,
+                    value: interval_ms as f64 / 1000.0,
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn write() -> anyhow::Result<()> {
+    let Some(series) = lock().clone() else {
+        return Ok(());
+    };
+    STORE.save(&series).await
+}
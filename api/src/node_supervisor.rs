@@ -0,0 +1,301 @@
+//! Runs the managed neptune-core, if enabled, and keeps it running.
+//!
+//! A process that exits, or cannot be started, is started again after a
+//! delay that doubles with each failure in a row.  While it runs, its RPC
+//! server is checked periodically, and its output is kept for the Node Logs
+//! screen.  Changing the config stops the process, asking neptune-core to
+//! shut down first, and starts it again with the new config.
+
+use std::collections::VecDeque;
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use dioxus_logger::tracing::Instrument;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncRead;
+use tokio::io::BufReader;
+use tokio::process::Child;
+use tokio::process::Command;
+use tokio::sync::Notify;
+
+use crate::managed_node::ManagedNodeConfig;
+use crate::managed_node::ManagedNodeState;
+use crate::managed_node::ManagedNodeStatus;
+use crate::managed_node::NodeLogLine;
+use crate::managed_node_store;
+use crate::neptune_rpc;
+
+/// Lines of output kept.  Older lines are dropped.
+const MAX_LOG_LINES: usize = 2_000;
+
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Delay before the first restart after a failure.  Doubles with each further
+/// failure in a row.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// A process that ran this long started fine, so its exit is not counted as
+/// another failure in a row.
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+
+/// How long neptune-core may take to shut down once asked, before it is
+/// killed.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(60);
+
+struct State {
+    status: ManagedNodeStatus,
+    logs: VecDeque<NodeLogLine>,
+    next_seq: u64,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            status: ManagedNodeStatus {
+                state: ManagedNodeState::Disabled,
+                responding: false,
+                restarts: 0,
+                last_exit: None,
+            },
+            logs: VecDeque::new(),
+            // 0 stands for none seen, in [logs].
+            next_seq: 1,
+        }
+    }
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    f(guard.get_or_insert_with(State::new))
+}
+
+/// Wakes the supervisor when the config changes or a restart is asked for.
+/// A wake-up while it is busy is kept until it next waits.
+fn changed() -> &'static Notify {
+    static CHANGED: OnceLock<Notify> = OnceLock::new();
+    CHANGED.get_or_init(Notify::new)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Starts the background task that runs the managed neptune-core, if not
+/// already running.
+pub fn ensure_supervising() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    STARTED.get_or_init(|| {
+        tokio::spawn(
+            supervise().instrument(dioxus_logger::tracing::info_span!("node_supervisor")),
+        );
+    });
+}
+
+/// Stops the managed neptune-core, if running, and starts it again with the
+/// stored config, if enabled.
+pub fn reload() {
+    changed().notify_one();
+}
+
+pub fn status() -> ManagedNodeStatus {
+    with_state(|state| state.status.clone())
+}
+
+/// The lines of output kept, oldest first.  Pass the `seq` of the last line
+/// seen as `after` to get only newer ones.
+pub fn logs(after: Option<u64>) -> Vec<NodeLogLine> {
+    let after = after.unwrap_or(0);
+    with_state(|state| {
+        state
+            .logs
+            .iter()
+            .filter(|line| line.seq > after)
+            .cloned()
+            .collect()
+    })
+}
+
+fn push_log(stderr: bool, text: String) {
+    with_state(|state| {
+        state.logs.push_back(NodeLogLine {
+            seq: state.next_seq,
+            at_ms: now_ms(),
+            stderr,
+            text,
+        });
+        state.next_seq += 1;
+        if state.logs.len() > MAX_LOG_LINES {
+            state.logs.pop_front();
+        }
+    });
+}
+
+async fn forward_lines(reader: impl AsyncRead + Unpin, stderr: bool) {
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        push_log(stderr, line);
+    }
+}
+
+/// How a run of the process ended.
+enum RunEnd {
+    /// It exited, or could not be started, for the given reason.
+    Exited(String),
+    /// It was stopped to apply a changed config.
+    Reload,
+}
+
+async fn supervise() {
+    // failures in a row.
+    let mut failures: u32 = 0;
+    loop {
+        let config = managed_node_store::load().await.unwrap_or_else(|e| {
+            dioxus_logger::tracing::warn!(error = %e, "could not load the managed node config");
+            ManagedNodeConfig::default()
+        });
+        if !config.enabled {
+            with_state(|state| {
+                state.status.state = ManagedNodeState::Disabled;
+                state.status.responding = false;
+            });
+            changed().notified().await;
+            failures = 0;
+            continue;
+        }
+
+        let started = Instant::now();
+        let end = match spawn(&config) {
+            Ok(child) => run(child).await,
+            Err(e) => RunEnd::Exited(format!("Could not start {}: {e}", config.binary)),
+        };
+        let RunEnd::Exited(reason) = end else {
+            failures = 0;
+            continue;
+        };
+
+        dioxus_logger::tracing::warn!(reason = %reason, "managed neptune-core stopped");
+        if started.elapsed() >= STABLE_AFTER {
+            failures = 0;
+        }
+        failures = failures.saturating_add(1);
+        let backoff = INITIAL_BACKOFF
+            .saturating_mul(1 << (failures - 1).min(16))
+            .min(MAX_BACKOFF);
+        with_state(|state| {
+            state.status.state = ManagedNodeState::Restarting {
+                retry_at_ms: now_ms() + backoff.as_millis() as u64,
+            };
+            state.status.responding = false;
+            state.status.restarts += 1;
+            state.status.last_exit = Some(reason);
+        });
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => {}
+            _ = changed().notified() => failures = 0,
+        }
+    }
+}
+
+fn spawn(config: &ManagedNodeConfig) -> std::io::Result<Child> {
+    let mut child = Command::new(&config.binary)
+        .args(&config.args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // so that neptune-core does not outlive the app.
+        .kill_on_drop(true)
+        .spawn()?;
+
+    if let Some(stdout) = child.stdout.take() {
+        tokio::spawn(forward_lines(stdout, false));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        tokio::spawn(forward_lines(stderr, true));
+    }
+    dioxus_logger::tracing::info!(
+        binary = %config.binary,
+        args = ?config.args,
+        "started managed neptune-core"
+    );
+    Ok(child)
+}
+
+/// Waits for `child` to exit, checking its health meanwhile.  Stops it if
+/// the config changes.
+async fn run(mut child: Child) -> RunEnd {
+    with_state(|state| {
+        state.status.state = ManagedNodeState::Running {
+            pid: child.id().unwrap_or_default(),
+            since_ms: now_ms(),
+        };
+        state.status.responding = false;
+    });
+
+    let mut health_check = tokio::time::interval(HEALTH_CHECK_INTERVAL);
+    loop {
+        tokio::select! {
+            status = child.wait() => {
+                return RunEnd::Exited(match status {
+                    Ok(status) => format!("neptune-core exited: {status}"),
+                    Err(e) => format!("Could not wait for neptune-core: {e}"),
+                });
+            }
+            _ = changed().notified() => {
+                stop(child).await;
+                return RunEnd::Reload;
+            }
+            _ = health_check.tick() => {
+                // unauthenticated, so answers as soon as the RPC server is up.
+                let responding = neptune_rpc::cookie_hint().await.is_ok();
+                with_state(|state| state.status.responding = responding);
+            }
+        }
+    }
+}
+
+/// Asks neptune-core to shut down, and kills it if it has not within
+/// [SHUTDOWN_TIMEOUT].
+async fn stop(mut child: Child) {
+    with_state(|state| {
+        state.status.state = ManagedNodeState::Stopping;
+        state.status.responding = false;
+    });
+
+    let asked = async {
+        let client = &neptune_rpc::rpc_client().await?;
+        neptune_rpc::with_token(|token| async move {
+            client
+                .shutdown(neptune_rpc::call_context("shutdown"), token)
+                .await??;
+            Ok(())
+        })
+        .await
+    }
+    .await;
+    if let Err(e) = &asked {
+        dioxus_logger::tracing::warn!(error = %e, "could not ask neptune-core to shut down");
+    }
+
+    let exited = asked.is_ok()
+        && tokio::time::timeout(SHUTDOWN_TIMEOUT, child.wait())
+            .await
+            .is_ok();
+    if !exited {
+        if let Err(e) = child.kill().await {
+            dioxus_logger::tracing::warn!(error = %e, "could not kill neptune-core");
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Whether the connected neptune-core speaks the RPC API this app was built
+//! against.
+//!
+//! neptune-core does not report its version over RPC.  The version is known
+//! only for a managed node, whose executable is asked with `--version`.  For
+//! other nodes, a mismatch shows as answers that cannot be decoded, which is
+//! reported instead of the cryptic decoding error.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The oldest neptune-core release this app works with.
+pub const MIN_SUPPORTED: (u64, u64, u64) = (0, 5, 0);
+
+/// The first neptune-core release this app does not work with.  Releases
+/// before 1.0 may change the RPC API in any minor version.
+pub const FIRST_UNSUPPORTED: (u64, u64, u64) = (0, 6, 0);
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NodeCompatibility {
+    Supported,
+    TooOld,
+    TooNew,
+    /// The version is unknown, but neptune-core's answers could not be
+    /// decoded, as happens when its RPC API differs from this app's.
+    ApiMismatch {
+        error: String,
+    },
+    /// The version is unknown, and nothing suggests a problem.
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeVersion {
+    /// As neptune-core prints it, if known.
+    pub version: Option<String>,
+    pub compatibility: NodeCompatibility,
+}
+
+impl NodeVersion {
+    pub fn is_incompatible(&self) -> bool {
+        matches!(
+            self.compatibility,
+            NodeCompatibility::TooOld
+                | NodeCompatibility::TooNew
+                | NodeCompatibility::ApiMismatch { .. }
+        )
+    }
+}
+
+/// The supported releases, eg "0.5.0 up to, not including, 0.6.0".
+pub fn supported_range() -> String {
+    let (a, b, c) = MIN_SUPPORTED;
+    let (x, y, z) = FIRST_UNSUPPORTED;
+    format!("{a}.{b}.{c} up to, not including, {x}.{y}.{z}")
+}
+
+/// The first "major.minor.patch" in `text`, eg in "neptune-core 0.5.1".  A
+/// leading "v" and a pre-release suffix are ignored.
+pub fn parse_version(text: &str) -> Option<(u64, u64, u64)> {
+    text.split_whitespace().find_map(|word| {
+        let word = word.trim_start_matches('v');
+        let core = word.split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => {
+                Some((major, minor, patch))
+            }
+            _ => None,
+        }
+    })
+}
+
+pub fn compatibility_of(version: (u64, u64, u64)) -> NodeCompatibility {
+    if version < MIN_SUPPORTED {
+        NodeCompatibility::TooOld
+    } else if version >= FIRST_UNSUPPORTED {
+        NodeCompatibility::TooNew
+    } else {
+        NodeCompatibility::Supported
+    }
+}
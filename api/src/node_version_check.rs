@@ -0,0 +1,118 @@
+//! Checks the connected neptune-core against [crate::node_version], at
+//! startup and whenever the UI asks.
+//!
+//! Every failed RPC call is passed to [note_rpc_error], so that a mismatch
+//! noticed mid-use is reported too, not only one found by the check's probe.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::process::Command;
+
+use crate::managed_node_store;
+use crate::neptune_rpc;
+use crate::node_version;
+use crate::node_version::NodeCompatibility;
+use crate::node_version::NodeVersion;
+
+/// How long `neptune-core --version` may take.
+const VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What serde_json says when a value does not match the type expected.
+const DECODE_ERROR_PHRASES: [&str; 5] = [
+    "missing field",
+    "unknown variant",
+    "unknown field",
+    "invalid type",
+    "invalid length",
+];
+
+/// The last error that looked like neptune-core answering in another format.
+static API_MISMATCH: Mutex<Option<String>> = Mutex::new(None);
+
+fn api_mismatch() -> Option<String> {
+    API_MISMATCH
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+fn set_api_mismatch(error: Option<String>) {
+    *API_MISMATCH.lock().unwrap_or_else(|e| e.into_inner()) = error;
+}
+
+/// Remembers `error` if it looks like an answer that could not be decoded.
+pub fn note_rpc_error(error: &str) {
+    if DECODE_ERROR_PHRASES
+        .iter()
+        .any(|phrase| error.contains(phrase))
+    {
+        dioxus_logger::tracing::warn!(error, "neptune-core's answer could not be decoded");
+        set_api_mismatch(Some(error.to_string()));
+    }
+}
+
+pub async fn check() -> NodeVersion {
+    let version = managed_node_version().await;
+    let from_version = version
+        .as_deref()
+        .and_then(node_version::parse_version)
+        .map(node_version::compatibility_of);
+    if matches!(
+        from_version,
+        Some(NodeCompatibility::TooOld | NodeCompatibility::TooNew)
+    ) {
+        return NodeVersion {
+            version,
+            compatibility: from_version.unwrap_or(NodeCompatibility::Unknown),
+        };
+    }
+
+    // a decoded answer clears an earlier mismatch, eg after an upgrade.
+    match probe().await {
+        Ok(()) => set_api_mismatch(None),
+        Err(e) => note_rpc_error(&format!("{e:#}")),
+    }
+    let compatibility = match api_mismatch() {
+        Some(error) => NodeCompatibility::ApiMismatch { error },
+        None => from_version.unwrap_or(NodeCompatibility::Unknown),
+    };
+    NodeVersion {
+        version,
+        compatibility,
+    }
+}
+
+/// Makes a call with a large answer, so that most changes to the RPC API
+/// make it fail to decode.
+async fn probe() -> anyhow::Result<()> {
+    let client = &neptune_rpc::rpc_client().await?;
+    neptune_rpc::with_token(|token| async move {
+        client
+            .dashboard_overview_data(neptune_rpc::call_context("dashboard_overview_data"), token)
+            .await??;
+        Ok(())
+    })
+    .await
+}
+
+/// Asks the managed neptune-core's executable for its version.  None if the
+/// wallet does not run neptune-core, or the executable did not say.
+async fn managed_node_version() -> Option<String> {
+    let config = managed_node_store::load().await.ok()?;
+    if !config.enabled {
+        return None;
+    }
+    let output = tokio::time::timeout(
+        VERSION_TIMEOUT,
+        Command::new(&config.binary).arg("--version").output(),
+    )
+    .await
+    .ok()?
+    .ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+}
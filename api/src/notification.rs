@@ -0,0 +1,54 @@
+//! In-app notifications, raised by the server as it watches the wallet and
+//! the node, and listed in the notification center.
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: u64,
+    /// unix time in milliseconds
+    pub created_at_ms: u64,
+    pub read: bool,
+    pub kind: NotificationKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum NotificationKind {
+    /// Funds arrived in a block.
+    FundsReceived {
+        amount: NativeCurrencyAmount,
+        block_height: u64,
+    },
+    /// Funds left the wallet in a block, ie one of our sends was confirmed.
+    SendConfirmed {
+        amount: NativeCurrencyAmount,
+        block_height: u64,
+    },
+    /// neptune-core stopped responding.
+    ConnectionLost,
+    /// neptune-core responds again.
+    ConnectionRestored,
+    /// A transaction sent from this app has waited in the mempool for a
+    /// number of blocks, perhaps because its fee is too low.
+    LowFee {
+        txid: TransactionKernelId,
+        fee: NativeCurrencyAmount,
+        blocks_waiting: u64,
+    },
+    /// neptune-core rejected this app's RPC token, even after its cookie was
+    /// read again.  Calls that need authentication fail until it is fixed.
+    TokenRejected { error: String },
+}
+
+impl NotificationKind {
+    /// True for notifications that may need the user to act.
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            Self::ConnectionLost | Self::LowFee { .. } | Self::TokenRejected { .. }
+        )
+    }
+}
@@ -0,0 +1,76 @@
+//! Persists [Notification]s, with their read state, as a JSON file alongside
+//! the neptune-core wallet.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::json_store::JsonStore;
+use crate::notification::Notification;
+use crate::notification::NotificationKind;
+
+/// The oldest notifications are dropped beyond this.
+const MAX_NOTIFICATIONS: usize = 200;
+
+static STORE: JsonStore<StoredNotifications> =
+    JsonStore::in_wallet_dir("neptune-proton-notifications.json");
+
+#[derive(Default, Serialize, Deserialize)]
+struct StoredNotifications {
+    #[serde(default)]
+    next_id: u64,
+    /// oldest first
+    #[serde(default)]
+    notifications: Vec<Notification>,
+}
+
+/// Returns all notifications, newest first.
+pub async fn load() -> anyhow::Result<Vec<Notification>> {
+    let mut notifications = STORE.load().await?.notifications;
+    notifications.reverse();
+    Ok(notifications)
+}
+
+/// Adds an unread notification.
+pub async fn push(kind: NotificationKind) -> anyhow::Result<()> {
+    let mut stored = STORE.lock().await?;
+    stored.notifications.push(Notification {
+        id: stored.next_id,
+        created_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64,
+        read: false,
+        kind,
+    });
+    stored.next_id += 1;
+    let excess = stored.notifications.len().saturating_sub(MAX_NOTIFICATIONS);
+    stored.notifications.drain(..excess);
+
+    stored.save().await
+}
+
+/// Marks the notifications with the given ids as read, or all if `None`.
+pub async fn mark_read(ids: Option<Vec<u64>>) -> anyhow::Result<()> {
+    STORE
+        .update(|stored| {
+            for notification in &mut stored.notifications {
+                if ids
+                    .as_ref()
+                    .is_none_or(|ids| ids.contains(&notification.id))
+                {
+                    notification.read = true;
+                }
+            }
+        })
+        .await?;
+    Ok(())
+}
+
+/// Removes all notifications.
+pub async fn clear() -> anyhow::Result<()> {
+    STORE.update(|stored| stored.notifications.clear()).await?;
+    Ok(())
+}
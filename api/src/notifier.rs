@@ -0,0 +1,135 @@
+//! Raises notifications for changes in the node's state: loss and recovery of
+//! the connection to neptune-core, and sent transactions that linger in the
+//! mempool, which usually means that their fee is too low.
+//!
+//! Receipts and confirmed sends are notified by [crate::new_receipts].
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use dioxus_logger::tracing::Instrument;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+
+use crate::bandwidth;
+use crate::neptune_rpc;
+use crate::notification::NotificationKind;
+use crate::notification_store;
+use crate::rpc_connection;
+use crate::sent_txs;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Blocks a sent transaction may wait in the mempool before the user is
+/// warned that its fee may be too low.
+const LOW_FEE_BLOCKS: u64 = 3;
+
+#[derive(Default)]
+struct State {
+    /// Whether neptune-core responded to the last poll.  `None` until the
+    /// first poll.
+    connected: Option<bool>,
+    /// The tip height at which each sent transaction was first seen waiting,
+    /// and whether the user has been warned about it.
+    waiting: HashMap<TransactionKernelId, (u64, bool)>,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+/// Starts the background task that watches the node, if not already running.
+pub fn ensure_watching() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    STARTED.get_or_init(|| {
+        tokio::spawn(
+            async {
+                loop {
+                    for kind in poll().await {
+                        if let Err(e) = notification_store::push(kind).await {
+                            dioxus_logger::tracing::warn!(error = %e, "could not store notification");
+                        }
+                    }
+                    tokio::time::sleep(bandwidth::scale(POLL_INTERVAL).await).await;
+                }
+            }
+            .instrument(dioxus_logger::tracing::info_span!("notifier")),
+        );
+    });
+}
+
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    f(guard.get_or_insert_with(State::default))
+}
+
+/// Returns the notifications raised since the previous poll.
+async fn poll() -> Vec<NotificationKind> {
+    let result = check_mempool().await;
+
+    let connected = result.is_ok();
+    let was_connected = with_state(|state| state.connected.replace(connected));
+    let mut raised = match (was_connected, connected) {
+        // a node that is down when the app starts is worth reporting too.
+        (Some(true) | None, false) => vec![NotificationKind::ConnectionLost],
+        (Some(false), true) => vec![NotificationKind::ConnectionRestored],
+        _ => vec![],
+    };
+    match result {
+        Ok(low_fee) => raised.extend(low_fee),
+        Err(e) => {
+            rpc_connection::note_error(&e);
+            dioxus_logger::tracing::debug!(error = %e, "poll failed");
+        }
+    }
+    raised
+}
+
+/// Returns warnings for sent transactions that have waited in the mempool for
+/// [LOW_FEE_BLOCKS], once per transaction.
+async fn check_mempool() -> anyhow::Result<Vec<NotificationKind>> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let (height, in_mempool) = neptune_rpc::with_token(|token| async move {
+        let height: u64 = client
+            .block_height(neptune_rpc::call_context("block_height"), token)
+            .await??
+            .into();
+
+        let mut in_mempool = vec![];
+        for (txid, sent) in sent_txs::all() {
+            let kernel = client
+                .mempool_tx_kernel(neptune_rpc::call_context("mempool_tx_kernel"), token, txid)
+                .await??;
+            if kernel.is_some() {
+                in_mempool.push((txid, sent.fee));
+            } else {
+                // mined or dropped, so there is nothing more to watch.
+                sent_txs::remove(&txid);
+            }
+        }
+        Ok((height, in_mempool))
+    })
+    .await?;
+
+    Ok(with_state(|state| {
+        // forget transactions that were mined or dropped.
+        state
+            .waiting
+            .retain(|txid, _| in_mempool.iter().any(|(id, _)| id == txid));
+
+        let mut raised = vec![];
+        for (txid, fee) in in_mempool {
+            let (since, warned) = state.waiting.entry(txid).or_insert((height, false));
+            let blocks_waiting = height.saturating_sub(*since);
+            if !*warned && blocks_waiting >= LOW_FEE_BLOCKS {
+                *warned = true;
+                raised.push(NotificationKind::LowFee {
+                    txid,
+                    fee,
+                    blocks_waiting,
+                });
+            }
+        }
+        raised
+    }))
+}
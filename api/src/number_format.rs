@@ -0,0 +1,96 @@
+//! Locale-aware formatting of amounts for display.
+//!
+//! Amounts are formatted internally with a '.' separator and no digit
+//! grouping, eg "1234.56".  This converts them to what the user expects to
+//! see, eg "1,234.56" or "1.234,56".
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+
+use crate::fiat_amount::FiatAmount;
+use crate::prefs::amount_precision::AmountPrecision;
+use crate::prefs::decimal_separator::DecimalSeparator;
+use crate::prefs::language::Language;
+use crate::prefs::thousands_separator::ThousandsSeparator;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberFormat {
+    /// Decides where currency symbols go.
+    pub language: Language,
+    pub decimal_separator: DecimalSeparator,
+    pub thousands_separator: ThousandsSeparator,
+    /// Shortens NPT amounts.  Lossless formatting ignores it.
+    pub precision: AmountPrecision,
+}
+
+impl NumberFormat {
+    /// Formats a normalized amount string, eg "-1234.5" --> "-1.234,5".
+    ///
+    /// Anything that is not a plain decimal number is returned unchanged,
+    /// apart from the decimal separator.
+    pub fn format(&self, normalized: &str) -> String {
+        let (sign, unsigned) = match normalized.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", normalized),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (unsigned, None),
+        };
+
+        if integer.is_empty() || !integer.chars().all(|c| c.is_ascii_digit()) {
+            return self.decimal_separator.localize(normalized);
+        }
+
+        let mut out = String::from(sign);
+        out.push_str(&group_digits(integer, self.thousands_separator.as_str()));
+        if let Some(fraction) = fraction {
+            out.push(self.decimal_separator.as_char());
+            out.push_str(fraction);
+        }
+        out
+    }
+
+    /// Formats an NPT amount to the user's precision, without a code.
+    pub fn npt(&self, amount: &NativeCurrencyAmount) -> String {
+        self.format(&self.precision.apply(&amount.display_lossless()))
+    }
+
+    /// True if [Self::npt] does not show `amount` exactly.
+    pub fn npt_is_shortened(&self, amount: &NativeCurrencyAmount) -> bool {
+        !self.precision.is_exact(&amount.display_lossless())
+    }
+
+    /// Formats an NPT amount losslessly, without a code.
+    pub fn npt_lossless(&self, amount: &NativeCurrencyAmount) -> String {
+        self.format(&amount.display_lossless())
+    }
+
+    /// Formats a fiat amount, without symbol or code.
+    pub fn fiat(&self, amount: &FiatAmount) -> String {
+        // Display drops the sign of amounts between -1 and 0.
+        let abs = FiatAmount::new_from_minor(amount.as_minor_units().abs(), amount.currency());
+        let sign = if amount.as_minor_units() < 0 { "-" } else { "" };
+        format!("{}{}", sign, self.format(&abs.to_string()))
+    }
+
+    /// Formats a fiat amount followed by its currency code, eg "1,234.56 USD".
+    pub fn fiat_with_code(&self, amount: &FiatAmount) -> String {
+        format!("{} {}", self.fiat(amount), amount.currency().code())
+    }
+}
+
+/// Inserts `separator` between each group of three digits, from the right.
+fn group_digits(digits: &str, separator: &str) -> String {
+    if separator.is_empty() {
+        return digits.to_string();
+    }
+    let len = digits.len();
+    let mut out = String::with_capacity(len + len / 3 * separator.len());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (len - i) % 3 == 0 {
+            out.push_str(separator);
+        }
+        out.push(c);
+    }
+    out
+}
@@ -0,0 +1,40 @@
+//! The first-run onboarding, shown until the user finishes or skips it when
+//! neptune-core cannot be reached or the wallet is new.
+
+use neptune_types::network::Network;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Why onboarding is offered.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnboardingReason {
+    /// neptune-core did not answer, eg because it is not running yet.
+    NodeUnreachable(String),
+    /// The wallet has never received or sent anything.
+    NewWallet,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnboardingStatus {
+    /// True once the user finished or skipped onboarding.
+    pub complete: bool,
+    /// None if there is nothing to set up.
+    pub reason: Option<OnboardingReason>,
+}
+
+impl OnboardingStatus {
+    pub fn should_show(&self) -> bool {
+        !self.complete && self.reason.is_some()
+    }
+}
+
+/// The outcome of testing the connection to neptune-core.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RpcConnectionTest {
+    /// Nothing answered on the RPC port.
+    Unreachable { port: u16, error: String },
+    /// neptune-core answered, but its RPC cookie could not be read.
+    CookieUnavailable { network: Network, error: String },
+    /// neptune-core answered and accepted the cookie.
+    Connected { network: Network },
+}
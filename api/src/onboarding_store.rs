@@ -0,0 +1,29 @@
+//! Remembers whether the user finished, or skipped, the first-run onboarding.
+//!
+//! Onboarding runs before neptune-core may be reachable, so this is kept in
+//! this app's own config directory rather than with the other prefs.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::json_store::JsonStore;
+
+static STORE: JsonStore<StoredOnboarding> = JsonStore::in_config_dir("onboarding.json");
+
+#[derive(Default, Serialize, Deserialize)]
+struct StoredOnboarding {
+    #[serde(default)]
+    complete: bool,
+}
+
+/// True once the user finished or skipped onboarding.
+pub async fn complete() -> anyhow::Result<bool> {
+    Ok(STORE.load().await?.complete)
+}
+
+/// Records that the user finished or skipped onboarding, so that it is not
+/// shown again.
+pub async fn set_complete() -> anyhow::Result<()> {
+    STORE.update(|stored| stored.complete = true).await?;
+    Ok(())
+}
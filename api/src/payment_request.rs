@@ -0,0 +1,253 @@
+//! Payment request URIs, for sharing an address along with an amount and label.
+//!
+//! Format: `neptune:<address>?amount=<npt>&label=<text>&message=<text>`
+//!
+//! All parameters are optional. The amount is in NPT, with a '.' decimal
+//! separator. The label names the recipient, eg a shop, while the message
+//! describes the payment, eg an order number. Both are percent-encoded. The
+//! scheme and parameter names are matched case-insensitively, as QR codes are
+//! often uppercased to fit the compact alphanumeric encoding.
+
+use std::fmt;
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use thiserror::Error;
+
+const SCHEME: &str = "neptune:";
+
+/// An error that can occur when parsing a payment request URI.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParsePaymentRequestError {
+    /// The string does not start with the `neptune:` scheme.
+    #[error("not a payment request")]
+    NotAPaymentRequest,
+    /// There is no address after the scheme.
+    #[error("payment request has no address")]
+    MissingAddress,
+    /// The amount parameter is not a valid NPT amount.
+    #[error("invalid amount in payment request: {0}")]
+    InvalidAmount(String),
+    /// A parameter is not correctly percent-encoded.
+    #[error("invalid encoding in payment request")]
+    InvalidEncoding,
+}
+
+/// A request to pay a given address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentRequest {
+    /// The bech32m encoded receiving address.
+    pub address: String,
+    pub amount: Option<NativeCurrencyAmount>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+impl PaymentRequest {
+    pub fn new(address: String) -> Self {
+        Self {
+            address,
+            amount: None,
+            label: None,
+            message: None,
+        }
+    }
+
+    /// True if the request carries anything beyond the address.
+    pub fn has_details(&self) -> bool {
+        self.amount.is_some() || self.label.is_some() || self.message.is_some()
+    }
+
+    /// Parses a `neptune:` URI.
+    pub fn parse(s: &str) -> Result<Self, ParsePaymentRequestError> {
+        let s = s.trim();
+        let has_scheme = s
+            .get(..SCHEME.len())
+            .is_some_and(|p| p.eq_ignore_ascii_case(SCHEME));
+        if !has_scheme {
+            return Err(ParsePaymentRequestError::NotAPaymentRequest);
+        }
+
+        let rest = &s[SCHEME.len()..];
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        if address.is_empty() {
+            return Err(ParsePaymentRequestError::MissingAddress);
+        }
+
+        let mut request = Self::new(address.to_string());
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(value)?;
+            if key.eq_ignore_ascii_case("amount") {
+                if value.is_empty() {
+                    continue;
+                }
+                let amount = NativeCurrencyAmount::coins_from_str(&value)
+                    .map_err(|e| ParsePaymentRequestError::InvalidAmount(e.to_string()))?;
+                request.amount = Some(amount);
+            } else if key.eq_ignore_ascii_case("label") {
+                request.label = Some(value).filter(|l| !l.is_empty());
+            } else if key.eq_ignore_ascii_case("message") {
+                request.message = Some(value).filter(|m| !m.is_empty());
+            }
+            // unknown parameters are ignored, for forward compatibility.
+        }
+        Ok(request)
+    }
+}
+
+/// Formats the request as a `neptune:` URI.
+impl fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", SCHEME, self.address)?;
+        let mut separator = '?';
+        if let Some(amount) = &self.amount {
+            write!(f, "{}amount={}", separator, amount)?;
+            separator = '&';
+        }
+        if let Some(label) = &self.label {
+            write!(f, "{}label={}", separator, percent_encode(label))?;
+            separator = '&';
+        }
+        if let Some(message) = &self.message {
+            write!(f, "{}message={}", separator, percent_encode(message))?;
+        }
+        Ok(())
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+fn percent_decode(s: &str) -> Result<String, ParsePaymentRequestError> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = s
+                    .get(i + 1..i + 3)
+                    .ok_or(ParsePaymentRequestError::InvalidEncoding)?;
+                let byte = u8::from_str_radix(hex, 16)
+                    .map_err(|_| ParsePaymentRequestError::InvalidEncoding)?;
+                out.push(byte);
+                i += 3;
+            }
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).map_err(|_| ParsePaymentRequestError::InvalidEncoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "nolgam1qqxyz";
+
+    #[test]
+    fn scheme_and_keys_are_case_insensitive() {
+        let request = PaymentRequest::parse("NEPTUNE:nolgam1qqxyz?AMOUNT=1.5&Label=Shop").unwrap();
+        assert_eq!(request.address, ADDRESS);
+        assert_eq!(
+            request.amount,
+            Some(NativeCurrencyAmount::coins_from_str("1.5").unwrap())
+        );
+        assert_eq!(request.label.as_deref(), Some("Shop"));
+        assert_eq!(
+            PaymentRequest::parse("bitcoin:nolgam1qqxyz"),
+            Err(ParsePaymentRequestError::NotAPaymentRequest)
+        );
+        assert_eq!(
+            PaymentRequest::parse("neptune:?amount=1"),
+            Err(ParsePaymentRequestError::MissingAddress)
+        );
+    }
+
+    #[test]
+    fn missing_amount_is_none() {
+        let request = PaymentRequest::parse("neptune:nolgam1qqxyz").unwrap();
+        assert_eq!(request, PaymentRequest::new(ADDRESS.to_string()));
+        assert!(!request.has_details());
+
+        let request = PaymentRequest::parse("neptune:nolgam1qqxyz?amount=&label=Shop").unwrap();
+        assert_eq!(request.amount, None);
+        assert_eq!(request.label.as_deref(), Some("Shop"));
+    }
+
+    #[test]
+    fn invalid_amount_is_rejected() {
+        for uri in [
+            "neptune:nolgam1qqxyz?amount=abc",
+            "neptune:nolgam1qqxyz?amount=1,5",
+        ] {
+            assert!(
+                matches!(
+                    PaymentRequest::parse(uri),
+                    Err(ParsePaymentRequestError::InvalidAmount(_))
+                ),
+                "{uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn labels_are_percent_decoded() {
+        let request =
+            PaymentRequest::parse("neptune:nolgam1qqxyz?label=Caf%C3%A9%20Nord&message=Order+42")
+                .unwrap();
+        assert_eq!(request.label.as_deref(), Some("Café Nord"));
+        assert_eq!(request.message.as_deref(), Some("Order 42"));
+
+        for uri in [
+            "neptune:nolgam1qqxyz?label=100%",
+            "neptune:nolgam1qqxyz?label=%zz",
+            "neptune:nolgam1qqxyz?label=%FF",
+        ] {
+            assert_eq!(
+                PaymentRequest::parse(uri),
+                Err(ParsePaymentRequestError::InvalidEncoding),
+                "{uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let requests = [
+            PaymentRequest::new(ADDRESS.to_string()),
+            PaymentRequest {
+                address: ADDRESS.to_string(),
+                amount: Some(NativeCurrencyAmount::coins_from_str("0.25").unwrap()),
+                label: Some("Café & Co".to_string()),
+                message: Some("Order #42 + tip=5%".to_string()),
+            },
+            PaymentRequest {
+                address: ADDRESS.to_string(),
+                amount: None,
+                label: None,
+                message: Some("a?b&c".to_string()),
+            },
+        ];
+        for request in requests {
+            assert_eq!(PaymentRequest::parse(&request.to_string()), Ok(request));
+        }
+    }
+}
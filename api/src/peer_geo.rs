@@ -0,0 +1,98 @@
+//! Where connected peers are, as resolved from a local GeoIP database.
+//!
+//! Lookups never leave the machine.  Without a database, peers are listed
+//! with no location.
+
+use std::net::SocketAddr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PeerGeoReport {
+    /// File name of the GeoIP database used. None if none is configured.
+    pub database: Option<String>,
+    pub peers: Vec<PeerLocation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PeerLocation {
+    pub address: SocketAddr,
+    /// ISO 3166-1 alpha-2, eg `DE`.
+    pub country_code: Option<String>,
+    /// English name of the country.
+    pub country_name: Option<String>,
+    /// Only present in city-level databases.
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+impl PeerLocation {
+    pub fn unknown(address: SocketAddr) -> Self {
+        Self {
+            address,
+            country_code: None,
+            country_name: None,
+            latitude: None,
+            longitude: None,
+        }
+    }
+
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+/// The number of peers in a country.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CountrySummary {
+    /// None for peers that could not be located.
+    pub country_code: Option<String>,
+    pub country_name: Option<String>,
+    pub num_peers: usize,
+}
+
+impl PeerGeoReport {
+    /// Peers per country, most first.  Unlocated peers come last.
+    pub fn countries(&self) -> Vec<CountrySummary> {
+        let mut countries: Vec<CountrySummary> = vec![];
+        for peer in &self.peers {
+            match countries
+                .iter_mut()
+                .find(|c| c.country_code == peer.country_code)
+            {
+                Some(country) => country.num_peers += 1,
+                None => countries.push(CountrySummary {
+                    country_code: peer.country_code.clone(),
+                    country_name: peer.country_name.clone(),
+                    num_peers: 1,
+                }),
+            }
+        }
+        countries.sort_by(|a, b| {
+            a.country_code
+                .is_none()
+                .cmp(&b.country_code.is_none())
+                .then(b.num_peers.cmp(&a.num_peers))
+                .then(a.country_name.cmp(&b.country_name))
+        });
+        countries
+    }
+
+    /// True if the database has coordinates, ie is city-level.
+    pub fn has_coordinates(&self) -> bool {
+        self.peers.iter().any(|p| p.coordinates().is_some())
+    }
+}
+
+/// The flag emoji for an ISO 3166-1 alpha-2 country code.
+pub fn flag(country_code: &str) -> Option<String> {
+    if country_code.len() != 2 || !country_code.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    country_code
+        .to_ascii_uppercase()
+        .bytes()
+        .map(|b| char::from_u32(0x1F1E6 + u32::from(b - b'A')))
+        .collect()
+}
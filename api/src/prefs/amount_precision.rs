@@ -0,0 +1,138 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How a displayed NPT amount is shortened to its maximum decimals.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum AmountRounding {
+    /// Half away from zero, eg 1.23456 --> 1.2346.
+    #[default]
+    Round,
+
+    /// Drops the excess digits, eg 1.23456 --> 1.2345.  Never shows more
+    /// than the wallet holds.
+    Truncate,
+}
+
+/// How many decimals of an NPT amount are shown, and how the rest are
+/// dropped.  The exact amount is always available in the tooltip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmountPrecision {
+    /// `None` shows every decimal.
+    pub max_decimals: Option<u8>,
+    pub rounding: AmountRounding,
+}
+
+impl Default for AmountPrecision {
+    fn default() -> Self {
+        Self {
+            max_decimals: Some(DEFAULT_MAX_DECIMALS),
+            rounding: AmountRounding::default(),
+        }
+    }
+}
+
+/// Fits table cells, while still showing amounts down to 1/10,000 NPT.
+pub const DEFAULT_MAX_DECIMALS: u8 = 4;
+
+/// The maximum decimals offered in settings.  `None` shows every decimal.
+pub const MAX_DECIMALS_CHOICES: [Option<u8>; 6] =
+    [Some(0), Some(2), Some(4), Some(6), Some(8), None];
+
+impl AmountPrecision {
+    /// Same rounding, with at most `max_decimals`.
+    pub fn with_max_decimals(self, max_decimals: u8) -> Self {
+        Self {
+            max_decimals: Some(max_decimals),
+            ..self
+        }
+    }
+
+    /// True if [Self::apply] keeps every nonzero digit of `normalized`.
+    pub fn is_exact(&self, normalized: &str) -> bool {
+        match (self.max_decimals, normalized.split_once('.')) {
+            (Some(max), Some((_, fraction))) => {
+                fraction.bytes().skip(usize::from(max)).all(|b| b == b'0')
+            }
+            _ => true,
+        }
+    }
+
+    /// Shortens a normalized amount string, eg "-1234.56789" -->
+    /// "-1234.5679", dropping trailing zeros.  Anything that is not a plain
+    /// decimal number is returned unchanged.
+    pub fn apply(&self, normalized: &str) -> String {
+        let Some(max) = self.max_decimals.map(usize::from) else {
+            return normalized.to_string();
+        };
+        let (sign, unsigned) = match normalized.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", normalized),
+        };
+        let Some((integer, fraction)) = unsigned.split_once('.') else {
+            return normalized.to_string();
+        };
+        if integer.is_empty()
+            || !integer.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return normalized.to_string();
+        }
+        if fraction.len() <= max {
+            return normalized.to_string();
+        }
+
+        // all digits, so the fraction can be rounded as part of one number.
+        let mut digits: Vec<u8> = integer
+            .bytes()
+            .chain(fraction.bytes().take(max))
+            .map(|b| b - b'0')
+            .collect();
+        let round_up = self.rounding.is_round() && fraction.as_bytes()[max] >= b'5';
+        if round_up {
+            let mut i = digits.len();
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    break;
+                }
+                i -= 1;
+                if digits[i] == 9 {
+                    digits[i] = 0;
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+
+        let split = digits.len() - max;
+        let to_str = |d: &[u8]| d.iter().map(|d| char::from(b'0' + d)).collect::<String>();
+        let integer = to_str(&digits[..split]);
+        let fraction = to_str(&digits[split..]);
+        let fraction = fraction.trim_end_matches('0');
+
+        // a tiny amount truncated or rounded to zero loses its sign.
+        let is_zero = integer.bytes().all(|b| b == b'0') && fraction.is_empty();
+        let sign = if is_zero { "" } else { sign };
+
+        if fraction.is_empty() {
+            format!("{sign}{integer}")
+        } else {
+            format!("{sign}{integer}.{fraction}")
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The kinds of item that can be looked up in a block explorer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize, strum::Display)]
+pub enum ExplorerItem {
+    #[strum(serialize = "block")]
+    Block,
+    #[strum(serialize = "tx")]
+    Transaction,
+    #[strum(serialize = "address")]
+    Address,
+}
+
+/// An external block explorer, as a URL template.
+///
+/// `{type}` in the template is replaced with "block", "tx" or "address" and
+/// `{id}` with the block digest, transaction id or address, eg
+/// `https://explorer.example.com/{type}/{id}`.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct BlockExplorer {
+    template: String,
+}
+
+impl BlockExplorer {
+    /// Returns `None` unless the template contains `{id}`.
+    pub fn new(template: String) -> Option<Self> {
+        template.contains("{id}").then_some(Self { template })
+    }
+
+    /// Reads the `BLOCK_EXPLORER_URL` env var.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("BLOCK_EXPLORER_URL")
+            .ok()
+            .and_then(|t| Self::new(t.trim().to_string()))
+    }
+
+    /// Returns the explorer's URL for an item.
+    pub fn url(&self, item: ExplorerItem, id: &str) -> String {
+        self.template
+            .replace("{type}", &item.to_string())
+            .replace("{id}", id)
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The resolution the QR scanner asks the camera for.  Higher resolutions
+/// read denser codes from further away, but decode more slowly.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum CameraResolution {
+    /// 640x480.
+    #[default]
+    Low,
+
+    /// 1280x720.
+    Medium,
+
+    /// 1920x1080.
+    High,
+}
+
+impl CameraResolution {
+    /// Width and height in pixels.  Cameras that lack it use the closest
+    /// they support.
+    pub fn size(&self) -> (u32, u32) {
+        match self {
+            Self::Low => (640, 480),
+            Self::Medium => (1280, 720),
+            Self::High => (1920, 1080),
+        }
+    }
+}
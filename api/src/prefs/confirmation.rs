@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A confirmation dialog the user may ask not to be shown again.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize, strum::EnumIter,
+)]
+pub enum Confirmation {
+    /// Adding an address that is already in the recipient list.
+    DuplicateAddress,
+
+    /// Clearing the standing of one or all peers.
+    ClearStanding,
+
+    /// Sending more than half of the wallet balance.
+    LargeSend,
+}
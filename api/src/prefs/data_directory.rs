@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The neptune-core data directory that the app reads the RPC cookie and the
+/// wallet's files from.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct DataDirectoryChoice {
+    /// The root data directory, as passed to neptune-core's `--data-dir`, that
+    /// the user chose or the `NEPTUNE_DATA_DIR` env var gives.  None to use
+    /// the one neptune-core reports.
+    pub chosen: Option<String>,
+
+    /// True if [Self::chosen] comes from the `NEPTUNE_DATA_DIR` env var, which
+    /// cannot be changed from the UI.
+    pub from_env: bool,
+
+    /// The network's directory in use, eg "/home/alice/.local/share/neptune/main".
+    pub in_use: String,
+}
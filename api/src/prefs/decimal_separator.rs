@@ -0,0 +1,130 @@
+use std::env;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The character used to separate the integer and fractional parts of an
+/// amount, for display and entry.
+///
+/// Amounts are always stored and passed around internally with a '.'
+/// separator. This only affects what the user sees and types.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum DecimalSeparator {
+    /// `1234.56`
+    #[default]
+    #[strum(serialize = "period", serialize = ".")]
+    Period,
+
+    /// `1234,56`
+    #[strum(serialize = "comma", serialize = ",")]
+    Comma,
+}
+
+impl DecimalSeparator {
+    /// The separator character.
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::Period => '.',
+            Self::Comma => ',',
+        }
+    }
+
+    /// Converts an internal ('.' separated) amount string for display.
+    pub fn localize(&self, normalized: &str) -> String {
+        normalized.replace('.', &self.as_char().to_string())
+    }
+
+    /// Reads a typed or pasted amount, returning it '.' separated.
+    ///
+    /// This separator is the decimal point, and the other of ',' and '.'
+    /// separates groups of three digits, which are dropped.  Other characters
+    /// are ignored.  None if the input is ambiguous: a second decimal point,
+    /// a group separator after the decimal point, or groups that are not
+    /// three digits long, as in "0,5" when the period is the decimal point.
+    pub fn parse_input(&self, input: &str) -> Option<String> {
+        let decimal = self.as_char();
+        let grouping = match self {
+            Self::Period => ',',
+            Self::Comma => '.',
+        };
+
+        let mut normalized = String::new();
+        let mut has_decimal = false;
+        let mut has_grouping = false;
+        // digits since the last group separator.
+        let mut group_digits = 0;
+        for ch in input.chars() {
+            if ch.is_ascii_digit() {
+                normalized.push(ch);
+                group_digits += 1;
+            } else if ch == decimal {
+                if has_decimal || (has_grouping && group_digits != 3) {
+                    return None;
+                }
+                normalized.push('.');
+                has_decimal = true;
+            } else if ch == grouping {
+                if has_decimal || (has_grouping && group_digits != 3) || normalized.is_empty() {
+                    return None;
+                }
+                has_grouping = true;
+                group_digits = 0;
+            }
+        }
+        if has_grouping && !has_decimal && group_digits != 3 {
+            return None;
+        }
+        Some(normalized)
+    }
+
+    /// Reads the `DECIMAL_SEPARATOR` env var: "period", "comma", "." or ",".
+    pub fn from_env() -> Option<Self> {
+        env::var("DECIMAL_SEPARATOR")
+            .ok()
+            .and_then(|s| Self::from_str(s.trim()).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_reads_commas_as_grouping() {
+        let sep = DecimalSeparator::Period;
+        assert_eq!(sep.parse_input("1,234.56").as_deref(), Some("1234.56"));
+        assert_eq!(sep.parse_input("1.234,56"), None);
+        assert_eq!(sep.parse_input("0,5"), None);
+    }
+
+    #[test]
+    fn comma_reads_periods_as_grouping() {
+        let sep = DecimalSeparator::Comma;
+        assert_eq!(sep.parse_input("1,234.56"), None);
+        assert_eq!(sep.parse_input("1.234,56").as_deref(), Some("1234.56"));
+        assert_eq!(sep.parse_input("0,5").as_deref(), Some("0.5"));
+        assert_eq!(sep.parse_input("12,").as_deref(), Some("12."));
+    }
+
+    #[test]
+    fn plain_amounts_are_kept() {
+        let sep = DecimalSeparator::Period;
+        assert_eq!(sep.parse_input("12.5").as_deref(), Some("12.5"));
+        assert_eq!(sep.parse_input("1,234,567").as_deref(), Some("1234567"));
+        assert_eq!(sep.parse_input("1.2.3"), None);
+    }
+}
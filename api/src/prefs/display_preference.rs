@@ -23,6 +23,10 @@ pub enum DisplayPreference {
 
         /// The enum variant for the selected price data provider.
         provider: PriceProviderKind,
+
+        /// An optional second fiat currency, shown in parentheses after
+        /// amounts displayed in `fiat`.
+        secondary_fiat: Option<FiatCurrency>,
     },
 }
 
@@ -36,7 +40,8 @@ impl DisplayPreference {
     ///   defaults to false
     /// - `FIAT_CURRENCY`: "USD", "EUR", or "JPY".
     /// - `DISPLAY_AS_FIAT`: "true" to make fiat the default display.
-    /// - `PRICE_PROVIDER`: "coingecko" or "coinpaprika".
+    /// - `PRICE_PROVIDER`: "coingecko", "coinpaprika" or "custom".
+    /// - `SECONDARY_FIAT_CURRENCY`: eg "USD".  unset for none.
     pub fn from_env() -> Self {
         /// **Easy toggle:** Set to `true` to make NPT-only the default mode.
         /// This is the lowest priority setting.
@@ -65,10 +70,17 @@ impl DisplayPreference {
                 .and_then(|s| PriceProviderKind::from_str(&s).ok())
                 .unwrap_or_default();
 
+            // a secondary currency identical to the primary would be redundant.
+            let secondary_fiat = env::var("SECONDARY_FIAT_CURRENCY")
+                .ok()
+                .and_then(|s| FiatCurrency::from_str(&s).ok())
+                .filter(|c| *c != fiat);
+
             Self::FiatEnabled {
                 fiat,
                 display_as_fiat,
                 provider,
+                secondary_fiat,
             }
         }
     }
@@ -0,0 +1,79 @@
+use std::env;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::decimal_separator::DecimalSeparator;
+
+/// The language of the user interface.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum Language {
+    #[default]
+    #[strum(serialize = "en")]
+    English,
+
+    #[strum(serialize = "es")]
+    Spanish,
+
+    #[strum(serialize = "de")]
+    German,
+}
+
+impl Language {
+    /// The ISO 639-1 code, eg "en".
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::German => "de",
+        }
+    }
+
+    /// The language's name, in that language.
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Español",
+            Self::German => "Deutsch",
+        }
+    }
+
+    /// The decimal separator customary for the language.
+    pub fn decimal_separator(&self) -> DecimalSeparator {
+        match self {
+            Self::English => DecimalSeparator::Period,
+            Self::Spanish | Self::German => DecimalSeparator::Comma,
+        }
+    }
+
+    /// A chrono format string for dates, eg "%d.%m.%Y".
+    pub fn date_format(&self) -> &'static str {
+        match self {
+            Self::English => "%Y-%m-%d",
+            Self::Spanish => "%d/%m/%Y",
+            Self::German => "%d.%m.%Y",
+        }
+    }
+
+    /// Reads the `UI_LANGUAGE` env var, eg "en" or "de".
+    pub fn from_env() -> Self {
+        env::var("UI_LANGUAGE")
+            .ok()
+            .and_then(|s| Self::from_str(s.trim()).ok())
+            .unwrap_or_default()
+    }
+}
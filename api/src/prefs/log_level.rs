@@ -0,0 +1,39 @@
+use std::env;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The least severe server log entries that are recorded.  Ordered from
+/// least to most verbose.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::Display,
+)]
+#[strum(ascii_case_insensitive, serialize_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Reads the `LOG_LEVEL` env var, eg "debug".
+    pub fn from_env() -> Option<Self> {
+        env::var("LOG_LEVEL").ok()?.parse().ok()
+    }
+}
@@ -1,2 +1,13 @@
+pub mod amount_precision;
+pub mod block_explorer;
+pub mod camera_resolution;
+pub mod confirmation;
+pub mod data_directory;
+pub mod decimal_separator;
 pub mod display_preference;
+pub mod language;
+pub mod log_level;
+pub mod refresh_intervals;
+pub mod startup_check;
+pub mod thousands_separator;
 pub mod user_prefs;
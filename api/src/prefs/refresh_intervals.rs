@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A screen that polls neptune-core while it is shown.  The history and
+/// mempool tables do not poll, but follow the tip and mempool size that
+/// [RefreshScreen::Balance] polls for.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Debug,
+    Serialize,
+    Deserialize,
+    strum::EnumIter,
+)]
+pub enum RefreshScreen {
+    /// The balance, tip and mempool summary shared by all screens.
+    Balance,
+    Receive,
+    Utxos,
+    Peers,
+    BlockChain,
+    NodeStats,
+    Logs,
+    NodeLogs,
+}
+
+impl RefreshScreen {
+    /// Seconds between refreshes unless the user chose otherwise.
+    pub fn default_secs(self) -> u32 {
+        match self {
+            RefreshScreen::Logs | RefreshScreen::NodeLogs => 2,
+            RefreshScreen::Balance => 5,
+            RefreshScreen::Receive | RefreshScreen::Utxos => 10,
+            RefreshScreen::Peers | RefreshScreen::BlockChain | RefreshScreen::NodeStats => 60,
+        }
+    }
+}
+
+/// How often each screen refreshes.  Only intervals the user changed are
+/// stored, so new defaults reach everyone else.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct RefreshIntervals(BTreeMap<RefreshScreen, u32>);
+
+impl RefreshIntervals {
+    /// Seconds between refreshes of `screen`.  Never 0.
+    pub fn secs(&self, screen: RefreshScreen) -> u32 {
+        self.0
+            .get(&screen)
+            .copied()
+            .unwrap_or_else(|| screen.default_secs())
+            .max(1)
+    }
+
+    pub fn interval(&self, screen: RefreshScreen) -> Duration {
+        Duration::from_secs(self.secs(screen).into())
+    }
+
+    /// Sets the seconds between refreshes of `screen`.  Choosing the default
+    /// forgets the user's choice.
+    pub fn set(&mut self, screen: RefreshScreen, secs: u32) {
+        if secs == screen.default_secs() {
+            self.0.remove(&screen);
+        } else {
+            self.0.insert(screen, secs.max(1));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A check run when the app starts.  Anything found is summarized on the
+/// Balance screen.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize, strum::EnumIter,
+)]
+pub enum StartupCheck {
+    /// Saved Send drafts that were never sent.
+    SendDrafts,
+
+    /// Own transactions in the mempool that are not synced to the tip.
+    StuckTransactions,
+
+    /// UTXOs the wallet expects but has not yet received.
+    UnclaimedUtxos,
+}
@@ -0,0 +1,93 @@
+use std::env;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::decimal_separator::DecimalSeparator;
+
+/// The separator placed between groups of three digits in the integer part
+/// of a displayed amount, eg the ',' in `1,234.56`.
+///
+/// Like [DecimalSeparator], this only affects display. Amounts are never
+/// entered or passed around with grouping.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum ThousandsSeparator {
+    /// `1234.56`
+    #[strum(serialize = "none")]
+    None,
+
+    /// `1,234.56`
+    #[strum(serialize = "comma", serialize = ",")]
+    Comma,
+
+    /// `1.234,56`
+    #[strum(serialize = "period", serialize = ".")]
+    Period,
+
+    /// `1 234,56`
+    #[strum(serialize = "space", serialize = " ")]
+    Space,
+
+    /// `1'234.56`
+    #[strum(serialize = "apostrophe", serialize = "'")]
+    Apostrophe,
+}
+
+impl ThousandsSeparator {
+    /// The separator text. Empty for [ThousandsSeparator::None].
+    ///
+    /// Space is a narrow no-break space, so amounts never wrap mid-number.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Comma => ",",
+            Self::Period => ".",
+            Self::Space => "\u{202f}",
+            Self::Apostrophe => "'",
+        }
+    }
+
+    /// The separator customary alongside `decimal_separator`.
+    pub fn for_decimal(decimal_separator: DecimalSeparator) -> Self {
+        match decimal_separator {
+            DecimalSeparator::Period => Self::Comma,
+            DecimalSeparator::Comma => Self::Period,
+        }
+    }
+
+    /// True if this would be confused with `decimal_separator`.
+    pub fn conflicts_with(&self, decimal_separator: DecimalSeparator) -> bool {
+        self.as_str() == decimal_separator.as_char().to_string()
+    }
+
+    /// Reads the `THOUSANDS_SEPARATOR` env var: "none", "comma", "period",
+    /// "space", "apostrophe", or the character itself.
+    pub fn from_env() -> Option<Self> {
+        env::var("THOUSANDS_SEPARATOR").ok().and_then(|s| {
+            // a lone space would be trimmed away.
+            let s = if s == " " { s.as_str() } else { s.trim() };
+            Self::from_str(s).ok()
+        })
+    }
+
+    /// The `THOUSANDS_SEPARATOR` env var if set and usable with
+    /// `decimal_separator`, else the customary separator.
+    pub fn resolve(decimal_separator: DecimalSeparator) -> Self {
+        Self::from_env()
+            .filter(|t| !t.conflicts_with(decimal_separator))
+            .unwrap_or_else(|| Self::for_decimal(decimal_separator))
+    }
+}
@@ -1,16 +1,327 @@
+use neptune_types::address::KeyType;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::amount_precision::AmountPrecision;
+use super::block_explorer::BlockExplorer;
+use super::camera_resolution::CameraResolution;
+use super::confirmation::Confirmation;
+use super::decimal_separator::DecimalSeparator;
 use super::display_preference::DisplayPreference;
+use super::language::Language;
+use super::log_level::LogLevel;
+use super::refresh_intervals::RefreshIntervals;
+use super::startup_check::StartupCheck;
+use super::thousands_separator::ThousandsSeparator;
+use crate::fiat_currency::FiatCurrency;
+use crate::price_providers::PriceProviderKind;
 
 /// Represents all user prefs. Intended for saving to a file. editing in settings dialog, etc.
-#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct UserPrefs {
     display_preference: DisplayPreference,
+    decimal_separator: DecimalSeparator,
+    thousands_separator: ThousandsSeparator,
+    metered_connection: bool,
+    price_providers: Vec<PriceProviderKind>,
+    block_explorer: Option<BlockExplorer>,
+    #[serde(default)]
+    suppressed_confirmations: Vec<Confirmation>,
+    #[serde(default)]
+    language: Language,
+    pin_set: bool,
+    idle_lock_minutes: u32,
+    #[serde(default)]
+    disabled_startup_checks: Vec<StartupCheck>,
+    #[serde(default = "NativeCurrencyAmount::zero")]
+    address_check_threshold: NativeCurrencyAmount,
+    #[serde(default)]
+    log_level: LogLevel,
+    #[serde(default)]
+    refresh_intervals: RefreshIntervals,
+    #[serde(default = "refresh_enabled_default")]
+    refresh_enabled: bool,
+    #[serde(default)]
+    hide_amounts: bool,
+    #[serde(default)]
+    idle_blur_minutes: u32,
+    #[serde(default)]
+    amount_precision: AmountPrecision,
+    #[serde(default = "receive_key_type_default")]
+    receive_key_type: KeyType,
+    #[serde(default)]
+    scanner_device: Option<String>,
+    #[serde(default)]
+    scanner_resolution: CameraResolution,
+}
+
+impl Default for UserPrefs {
+    fn default() -> Self {
+        let display_preference = DisplayPreference::default();
+        let primary_provider = match display_preference {
+            DisplayPreference::FiatEnabled { provider, .. } => provider,
+            DisplayPreference::NptOnly => PriceProviderKind::default(),
+        };
+        let language = Language::from_env();
+        let decimal_separator =
+            DecimalSeparator::from_env().unwrap_or_else(|| language.decimal_separator());
+        Self {
+            display_preference,
+            decimal_separator,
+            thousands_separator: ThousandsSeparator::resolve(decimal_separator),
+            metered_connection: metered_connection_from_env(),
+            price_providers: PriceProviderKind::priority_from_env(primary_provider),
+            block_explorer: BlockExplorer::from_env(),
+            suppressed_confirmations: vec![],
+            language,
+            pin_set: false,
+            idle_lock_minutes: DEFAULT_IDLE_LOCK_MINUTES,
+            disabled_startup_checks: vec![],
+            address_check_threshold: NativeCurrencyAmount::zero(),
+            log_level: LogLevel::from_env().unwrap_or_default(),
+            refresh_intervals: RefreshIntervals::default(),
+            refresh_enabled: refresh_enabled_default(),
+            hide_amounts: false,
+            idle_blur_minutes: 0,
+            amount_precision: AmountPrecision::default(),
+            receive_key_type: receive_key_type_default(),
+            scanner_device: None,
+            scanner_resolution: CameraResolution::default(),
+        }
+    }
 }
 
 impl UserPrefs {
     pub fn display_preference(&self) -> &DisplayPreference {
         &self.display_preference
     }
+
+    pub fn set_display_preference(&mut self, display_preference: DisplayPreference) {
+        self.display_preference = display_preference;
+    }
+
+    /// Shows fiat amounts in `fiat`, keeping the other fiat settings, or the
+    /// defaults if fiat was off.
+    pub fn set_fiat_currency(&mut self, fiat: FiatCurrency) {
+        self.display_preference = match self.display_preference {
+            DisplayPreference::FiatEnabled {
+                display_as_fiat,
+                provider,
+                secondary_fiat,
+                ..
+            } => DisplayPreference::FiatEnabled {
+                fiat,
+                display_as_fiat,
+                provider,
+                secondary_fiat: secondary_fiat.filter(|c| *c != fiat),
+            },
+            DisplayPreference::NptOnly => DisplayPreference::FiatEnabled {
+                fiat,
+                display_as_fiat: true,
+                provider: PriceProviderKind::default(),
+                secondary_fiat: None,
+            },
+        };
+    }
+
+    pub fn decimal_separator(&self) -> DecimalSeparator {
+        self.decimal_separator
+    }
+
+    /// Groups the digits of displayed amounts. Never the same character as
+    /// the decimal separator.
+    pub fn thousands_separator(&self) -> ThousandsSeparator {
+        self.thousands_separator
+    }
+
+    /// True if the user declared the connection metered. The UI may also
+    /// detect this itself, where the platform allows.
+    pub fn metered_connection(&self) -> bool {
+        self.metered_connection
+    }
+
+    pub fn set_metered_connection(&mut self, metered: bool) {
+        self.metered_connection = metered;
+    }
+
+    /// Price providers in the order they are tried. If one fails, the next is
+    /// used.
+    pub fn price_providers(&self) -> &[PriceProviderKind] {
+        &self.price_providers
+    }
+
+    /// The external block explorer to link to, if configured.
+    pub fn block_explorer(&self) -> Option<&BlockExplorer> {
+        self.block_explorer.as_ref()
+    }
+
+    /// Confirmation dialogs the user asked not to be shown again.
+    pub fn suppressed_confirmations(&self) -> &[Confirmation] {
+        &self.suppressed_confirmations
+    }
+
+    pub fn set_suppressed_confirmations(&mut self, confirmations: Vec<Confirmation>) {
+        self.suppressed_confirmations = confirmations;
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Sets the UI language.  Unless the `DECIMAL_SEPARATOR` env var is set,
+    /// the decimal separator follows the language, and the thousands
+    /// separator follows it.
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+        if DecimalSeparator::from_env().is_none() {
+            self.decimal_separator = language.decimal_separator();
+        }
+        self.thousands_separator = ThousandsSeparator::resolve(self.decimal_separator);
+    }
+
+    /// True if a PIN locks the app.  The PIN itself never leaves the server.
+    pub fn pin_set(&self) -> bool {
+        self.pin_set
+    }
+
+    pub fn set_pin_set(&mut self, pin_set: bool) {
+        self.pin_set = pin_set;
+    }
+
+    /// Minutes without user activity after which the app locks, if a PIN is
+    /// set.  0 never locks.
+    pub fn idle_lock_minutes(&self) -> u32 {
+        self.idle_lock_minutes
+    }
+
+    pub fn set_idle_lock_minutes(&mut self, minutes: u32) {
+        self.idle_lock_minutes = minutes;
+    }
+
+    /// Minutes without user activity after which the app is blurred until
+    /// the user resumes, with the PIN if one is set.  0, the default, never
+    /// blurs.
+    pub fn idle_blur_minutes(&self) -> u32 {
+        self.idle_blur_minutes
+    }
+
+    pub fn set_idle_blur_minutes(&mut self, minutes: u32) {
+        self.idle_blur_minutes = minutes;
+    }
+
+    /// Startup checks the user turned off.
+    pub fn disabled_startup_checks(&self) -> &[StartupCheck] {
+        &self.disabled_startup_checks
+    }
+
+    pub fn set_disabled_startup_checks(&mut self, checks: Vec<StartupCheck>) {
+        self.disabled_startup_checks = checks;
+    }
+
+    /// Sends above this amount ask the user to re-enter the end of each
+    /// recipient address before confirming.  0 never asks.
+    pub fn address_check_threshold(&self) -> NativeCurrencyAmount {
+        self.address_check_threshold
+    }
+
+    pub fn set_address_check_threshold(&mut self, threshold: NativeCurrencyAmount) {
+        self.address_check_threshold = threshold;
+    }
+
+    /// The least severe server log entries that are recorded.
+    pub fn log_level(&self) -> LogLevel {
+        self.log_level
+    }
+
+    pub fn set_log_level(&mut self, level: LogLevel) {
+        self.log_level = level;
+    }
+
+    /// How often each screen polls neptune-core while shown.
+    pub fn refresh_intervals(&self) -> &RefreshIntervals {
+        &self.refresh_intervals
+    }
+
+    pub fn set_refresh_intervals(&mut self, intervals: RefreshIntervals) {
+        self.refresh_intervals = intervals;
+    }
+
+    /// False while the user has paused all background refreshing.
+    pub fn refresh_enabled(&self) -> bool {
+        self.refresh_enabled
+    }
+
+    pub fn set_refresh_enabled(&mut self, enabled: bool) {
+        self.refresh_enabled = enabled;
+    }
+
+    /// True while amounts are masked, for showing the wallet in public.
+    pub fn hide_amounts(&self) -> bool {
+        self.hide_amounts
+    }
+
+    pub fn set_hide_amounts(&mut self, hide: bool) {
+        self.hide_amounts = hide;
+    }
+
+    /// How many decimals of NPT amounts are shown, and how the rest are
+    /// dropped.
+    pub fn amount_precision(&self) -> AmountPrecision {
+        self.amount_precision
+    }
+
+    pub fn set_amount_precision(&mut self, precision: AmountPrecision) {
+        self.amount_precision = precision;
+    }
+
+    /// The address type the Receive screen offers first: the one last
+    /// generated there.
+    pub fn receive_key_type(&self) -> KeyType {
+        self.receive_key_type
+    }
+
+    pub fn set_receive_key_type(&mut self, key_type: KeyType) {
+        self.receive_key_type = key_type;
+    }
+
+    /// The camera the QR scanner last used, if the user chose one.
+    pub fn scanner_device(&self) -> Option<&str> {
+        self.scanner_device.as_deref()
+    }
+
+    pub fn set_scanner_device(&mut self, device: Option<String>) {
+        self.scanner_device = device;
+    }
+
+    /// The resolution the QR scanner asks the camera for.
+    pub fn scanner_resolution(&self) -> CameraResolution {
+        self.scanner_resolution
+    }
+
+    pub fn set_scanner_resolution(&mut self, resolution: CameraResolution) {
+        self.scanner_resolution = resolution;
+    }
+}
+
+/// The idle timeout until the user chooses one.
+pub const DEFAULT_IDLE_LOCK_MINUTES: u32 = 5;
+
+/// Background refreshing runs until the user pauses it.
+fn refresh_enabled_default() -> bool {
+    true
+}
+
+/// Receiving addresses are generation addresses until the user generates
+/// another type.
+fn receive_key_type_default() -> KeyType {
+    KeyType::Generation
+}
+
+/// Reads the `METERED_CONNECTION` env var: "true" or "1".
+fn metered_connection_from_env() -> bool {
+    match std::env::var("METERED_CONNECTION") {
+        Ok(val) => val.eq_ignore_ascii_case("true") || val == "1",
+        Err(_) => false,
+    }
 }
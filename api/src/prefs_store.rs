@@ -0,0 +1,353 @@
+//! Persists the user prefs that are changed from within the app as a JSON
+//! file in this app's config directory.  Other prefs come from env vars.
+//!
+//! The prefs are kept apart from neptune-core's wallet so that they, and the
+//! app lock PIN, are available while neptune-core is not.  Earlier versions
+//! kept them alongside the wallet, from where they are moved once.
+
+use neptune_types::address::KeyType;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::OnceCell;
+
+use crate::app_lock;
+use crate::fiat_currency::FiatCurrency;
+use crate::json_store::JsonStore;
+use crate::prefs::amount_precision::AmountPrecision;
+use crate::prefs::camera_resolution::CameraResolution;
+use crate::prefs::confirmation::Confirmation;
+use crate::prefs::display_preference::DisplayPreference;
+use crate::prefs::language::Language;
+use crate::prefs::log_level::LogLevel;
+use crate::prefs::refresh_intervals::RefreshIntervals;
+use crate::prefs::refresh_intervals::RefreshScreen;
+use crate::prefs::startup_check::StartupCheck;
+use crate::prefs::user_prefs::UserPrefs;
+
+static STORE: JsonStore<StoredPrefs> = JsonStore::in_config_dir("prefs.json");
+
+/// Where earlier versions kept the prefs.
+static WALLET_DIR_STORE: JsonStore<StoredPrefs> =
+    JsonStore::in_wallet_dir("neptune-proton-prefs.json");
+
+#[derive(Default, Serialize, Deserialize)]
+struct StoredPrefs {
+    #[serde(default)]
+    suppressed_confirmations: Vec<Confirmation>,
+    #[serde(default)]
+    language: Option<Language>,
+    /// Argon2 hash of the app lock PIN.
+    #[serde(default)]
+    pin_hash: Option<String>,
+    #[serde(default)]
+    idle_lock_minutes: Option<u32>,
+    #[serde(default)]
+    idle_blur_minutes: u32,
+    #[serde(default)]
+    disabled_startup_checks: Vec<StartupCheck>,
+    #[serde(default)]
+    address_check_threshold: Option<NativeCurrencyAmount>,
+    #[serde(default)]
+    log_level: Option<LogLevel>,
+    #[serde(default)]
+    refresh_intervals: RefreshIntervals,
+    #[serde(default)]
+    refresh_enabled: Option<bool>,
+    #[serde(default)]
+    hide_amounts: bool,
+    #[serde(default)]
+    amount_precision: Option<AmountPrecision>,
+    /// True if the user chose to see NPT only.
+    #[serde(default)]
+    npt_only: Option<bool>,
+    #[serde(default)]
+    fiat_currency: Option<FiatCurrency>,
+    #[serde(default)]
+    receive_key_type: Option<KeyType>,
+    #[serde(default)]
+    scanner_device: Option<String>,
+    #[serde(default)]
+    scanner_resolution: CameraResolution,
+    #[serde(default)]
+    metered_connection: Option<bool>,
+    /// True once these prefs replace any kept alongside the wallet: when
+    /// those were moved here, or when these were first changed.
+    #[serde(default)]
+    replaces_wallet_dir: bool,
+}
+
+impl StoredPrefs {
+    fn into_prefs(self) -> UserPrefs {
+        let mut prefs = UserPrefs::default();
+        self.apply(&mut prefs);
+        prefs
+    }
+
+    fn apply(self, prefs: &mut UserPrefs) {
+        prefs.set_suppressed_confirmations(self.suppressed_confirmations);
+        if let Some(language) = self.language {
+            prefs.set_language(language);
+        }
+        prefs.set_pin_set(self.pin_hash.is_some());
+        if let Some(minutes) = self.idle_lock_minutes {
+            prefs.set_idle_lock_minutes(minutes);
+        }
+        prefs.set_idle_blur_minutes(self.idle_blur_minutes);
+        prefs.set_disabled_startup_checks(self.disabled_startup_checks);
+        if let Some(threshold) = self.address_check_threshold {
+            prefs.set_address_check_threshold(threshold);
+        }
+        if let Some(level) = self.log_level {
+            prefs.set_log_level(level);
+        }
+        prefs.set_refresh_intervals(self.refresh_intervals);
+        if let Some(enabled) = self.refresh_enabled {
+            prefs.set_refresh_enabled(enabled);
+        }
+        prefs.set_hide_amounts(self.hide_amounts);
+        if let Some(precision) = self.amount_precision {
+            prefs.set_amount_precision(precision);
+        }
+        if self.npt_only == Some(true) {
+            prefs.set_display_preference(DisplayPreference::NptOnly);
+        } else if let Some(fiat) = self.fiat_currency {
+            prefs.set_fiat_currency(fiat);
+        }
+        if let Some(key_type) = self.receive_key_type {
+            prefs.set_receive_key_type(key_type);
+        }
+        prefs.set_scanner_device(self.scanner_device);
+        prefs.set_scanner_resolution(self.scanner_resolution);
+        if let Some(metered) = self.metered_connection {
+            prefs.set_metered_connection(metered);
+        }
+    }
+}
+
+/// Returns the store, once any prefs kept alongside the wallet were moved
+/// into it.
+///
+/// The move is tried once per run, as it needs neptune-core's data
+/// directory.  If that is unknown, it is tried again on the next run.
+async fn store() -> &'static JsonStore<StoredPrefs> {
+    static MOVED: OnceCell<()> = OnceCell::const_new();
+    MOVED
+        .get_or_init(|| async {
+            if let Err(e) = move_from_wallet_dir().await {
+                dioxus_logger::tracing::debug!(error = %e, "prefs not moved from the wallet dir");
+            }
+        })
+        .await;
+    &STORE
+}
+
+async fn move_from_wallet_dir() -> anyhow::Result<()> {
+    if STORE.load().await?.replaces_wallet_dir {
+        return Ok(());
+    }
+    let old = WALLET_DIR_STORE.load().await?;
+    STORE
+        .update(|stored| {
+            if !stored.replaces_wallet_dir {
+                *stored = StoredPrefs {
+                    replaces_wallet_dir: true,
+                    ..old
+                };
+            }
+        })
+        .await?;
+    Ok(())
+}
+
+/// Applies `f` to the stored prefs and saves them, returning the result.
+async fn update(f: impl FnOnce(&mut StoredPrefs)) -> anyhow::Result<StoredPrefs> {
+    store()
+        .await
+        .update(|stored| {
+            f(stored);
+            stored.replaces_wallet_dir = true;
+        })
+        .await
+}
+
+/// Returns the default prefs, overridden by any stored prefs.
+pub async fn load() -> anyhow::Result<UserPrefs> {
+    Ok(store().await.load().await?.into_prefs())
+}
+
+/// Stores the UI language, returning the updated prefs.
+pub async fn set_language(language: Language) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.language = Some(language)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Sets, changes or removes (if `new` is `None`) the app lock PIN, returning
+/// the updated prefs.  `current` must match the existing PIN, if any.
+pub async fn set_pin(current: Option<String>, new: Option<String>) -> anyhow::Result<UserPrefs> {
+    // checked without holding the store, as a wrong guess may be delayed.
+    let old_hash = store().await.load().await?.pin_hash;
+    if let Some(hash) = old_hash.clone() {
+        let current = current.unwrap_or_default();
+        if !app_lock::verify(current, hash).await? {
+            anyhow::bail!("The current PIN is incorrect.");
+        }
+    }
+    let new_hash = match new {
+        Some(pin) => Some(app_lock::hash(pin).await?),
+        None => None,
+    };
+
+    let mut changed_meanwhile = false;
+    let stored = update(|stored| {
+        if stored.pin_hash == old_hash {
+            stored.pin_hash = new_hash;
+        } else {
+            changed_meanwhile = true;
+        }
+    })
+    .await?;
+    if changed_meanwhile {
+        anyhow::bail!("The PIN was changed meanwhile.  Please try again.");
+    }
+    Ok(stored.into_prefs())
+}
+
+/// True if `pin` unlocks the app.  Also true if no PIN is set.
+pub async fn verify_pin(pin: String) -> anyhow::Result<bool> {
+    let hash = store().await.load().await?.pin_hash;
+    match hash {
+        Some(hash) => app_lock::verify(pin, hash).await,
+        None => Ok(true),
+    }
+}
+
+/// Stores the idle timeout after which the app locks, returning the updated
+/// prefs.
+pub async fn set_idle_lock_minutes(minutes: u32) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.idle_lock_minutes = Some(minutes)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores the idle timeout after which the app blurs, returning the updated
+/// prefs.
+pub async fn set_idle_blur_minutes(minutes: u32) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.idle_blur_minutes = minutes).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores the send amount above which recipient addresses are re-verified,
+/// returning the updated prefs.
+pub async fn set_address_check_threshold(
+    threshold: NativeCurrencyAmount,
+) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.address_check_threshold = Some(threshold)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores the server log level, returning the updated prefs.
+pub async fn set_log_level(level: LogLevel) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.log_level = Some(level)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores whether background refreshing runs, returning the updated prefs.
+pub async fn set_refresh_enabled(enabled: bool) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.refresh_enabled = Some(enabled)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores whether amounts are masked, returning the updated prefs.
+pub async fn set_hide_amounts(hide: bool) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.hide_amounts = hide).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores how NPT amounts are shortened, returning the updated prefs.
+pub async fn set_amount_precision(precision: AmountPrecision) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.amount_precision = Some(precision)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores the address type last generated on the Receive screen, returning
+/// the updated prefs.
+pub async fn set_receive_key_type(key_type: KeyType) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.receive_key_type = Some(key_type)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores the camera and resolution the QR scanner uses, returning the
+/// updated prefs.
+pub async fn set_scanner_camera(
+    device: Option<String>,
+    resolution: CameraResolution,
+) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| {
+        stored.scanner_device = device;
+        stored.scanner_resolution = resolution;
+    })
+    .await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores whether the connection is metered, returning the updated prefs.
+pub async fn set_metered_connection(metered: bool) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| stored.metered_connection = Some(metered)).await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores the fiat currency to show amounts in, or NPT only if `fiat` is
+/// None, returning the updated prefs.
+pub async fn set_fiat_currency(fiat: Option<FiatCurrency>) -> anyhow::Result<UserPrefs> {
+    let stored = update(|stored| {
+        stored.npt_only = Some(fiat.is_none());
+        if fiat.is_some() {
+            stored.fiat_currency = fiat;
+        }
+    })
+    .await?;
+    Ok(stored.into_prefs())
+}
+
+/// Stores how often `screen` refreshes, returning the updated intervals.
+pub async fn set_refresh_interval(
+    screen: RefreshScreen,
+    secs: u32,
+) -> anyhow::Result<RefreshIntervals> {
+    let stored = update(|stored| stored.refresh_intervals.set(screen, secs)).await?;
+    Ok(stored.refresh_intervals)
+}
+
+/// Records whether `confirmation` should be skipped, returning the updated
+/// list of suppressed confirmations.
+pub async fn set_confirmation_suppressed(
+    confirmation: Confirmation,
+    suppressed: bool,
+) -> anyhow::Result<Vec<Confirmation>> {
+    let stored = update(|stored| {
+        stored
+            .suppressed_confirmations
+            .retain(|c| *c != confirmation);
+        if suppressed {
+            stored.suppressed_confirmations.push(confirmation);
+        }
+    })
+    .await?;
+    Ok(stored.suppressed_confirmations)
+}
+
+/// Records whether `check` runs at startup, returning the updated list of
+/// disabled checks.
+pub async fn set_startup_check_enabled(
+    check: StartupCheck,
+    enabled: bool,
+) -> anyhow::Result<Vec<StartupCheck>> {
+    let stored = update(|stored| {
+        stored.disabled_startup_checks.retain(|c| *c != check);
+        if !enabled {
+            stored.disabled_startup_checks.push(check);
+        }
+    })
+    .await?;
+    Ok(stored.disabled_startup_checks)
+}
@@ -1,17 +1,29 @@
 //! Handles the caching logic for external price provider data.
 #![allow(dead_code)]
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use dioxus::prelude::ServerFnError;
 use tokio::sync::OnceCell;
 use tokio::sync::RwLock;
 
+use crate::bandwidth;
+use crate::prefs::user_prefs::UserPrefs;
 use crate::price_map::PriceMap;
-use crate::price_providers::coin_gecko::CoinGecko;
 use crate::price_providers::PriceProvider;
+use crate::price_providers::PriceProviderKind;
+use crate::price_providers::PriceProviderStatus;
+
+/// Observed health of each provider that has been tried.
+static HEALTH: LazyLock<Mutex<HashMap<PriceProviderKind, PriceProviderStatus>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 #[derive(Clone, Debug)]
 struct CachedPrices {
@@ -22,7 +34,8 @@ struct CachedPrices {
 /// Retrieves fiat prices, using a lazy, time-based cache.
 ///
 /// This function acts as a gatekeeper to the underlying price provider. It only
-/// calls the provider when the cache is empty or older than the defined `CACHE_DURATION`.
+/// calls the provider when the cache is empty or older than the defined `CACHE_DURATION`,
+/// which is stretched while on a metered connection.
 pub async fn get_cached_fiat_prices() -> Result<PriceMap, ServerFnError> {
     static CACHE: OnceCell<Arc<RwLock<Option<CachedPrices>>>> = OnceCell::const_new();
     const CACHE_DURATION: Duration = Duration::from_secs(60);
@@ -32,9 +45,11 @@ pub async fn get_cached_fiat_prices() -> Result<PriceMap, ServerFnError> {
         .await;
 
     // Check if a valid, non-stale cache entry exists first with a read lock.
+    let cache_duration = bandwidth::scale(CACHE_DURATION).await;
+
     let read_lock = cache_lock.read().await;
     if let Some(cache) = &*read_lock {
-        if cache.last_fetched.elapsed() < CACHE_DURATION {
+        if cache.last_fetched.elapsed() < cache_duration {
             return Ok(cache.price_map.clone());
         }
     }
@@ -45,14 +60,13 @@ pub async fn get_cached_fiat_prices() -> Result<PriceMap, ServerFnError> {
 
     // A crucial double-check: another task might have updated the cache while we were waiting for the write lock.
     if let Some(cache) = &*write_lock {
-        if cache.last_fetched.elapsed() < CACHE_DURATION {
+        if cache.last_fetched.elapsed() < cache_duration {
             return Ok(cache.price_map.clone());
         }
     }
 
     // We have the lock and the cache is confirmed to be stale. Fetch new data.
-    let provider = CoinGecko;
-    let new_price_map = provider.get_prices().await?;
+    let new_price_map = fetch_with_failover().await?;
 
     *write_lock = Some(CachedPrices {
         price_map: new_price_map.clone(),
@@ -61,3 +75,80 @@ pub async fn get_cached_fiat_prices() -> Result<PriceMap, ServerFnError> {
 
     Ok(new_price_map)
 }
+
+/// Tries each configured provider in priority order until one returns prices.
+async fn fetch_with_failover() -> Result<PriceMap, anyhow::Error> {
+    let providers = UserPrefs::default().price_providers().to_vec();
+
+    let mut errors = vec![];
+    for (priority, kind) in providers.into_iter().enumerate() {
+        let result = match kind.get_prices().await {
+            Ok(map) if map.is_empty() => Err(anyhow::anyhow!("returned no prices")),
+            r => r,
+        };
+        record_attempt(kind, priority, result.as_ref().err());
+
+        match result {
+            Ok(map) => return Ok(map),
+            Err(e) => {
+                dioxus_logger::tracing::warn!(provider = ?kind, error = %e, "price provider failed");
+                errors.push(format!("{:?}: {}", kind, e));
+            }
+        }
+    }
+
+    anyhow::bail!("All price providers failed. {}", errors.join("; "))
+}
+
+fn record_attempt(kind: PriceProviderKind, priority: usize, error: Option<&anyhow::Error>) {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    let mut health = HEALTH.lock().unwrap();
+    if error.is_none() {
+        for status in health.values_mut() {
+            status.is_active = false;
+        }
+    }
+
+    let status = health
+        .entry(kind)
+        .or_insert_with(|| PriceProviderStatus::new(kind, priority));
+    status.priority = priority;
+    match error {
+        None => {
+            status.is_active = true;
+            status.last_success_ms = Some(now_ms);
+            status.last_error = None;
+            status.consecutive_failures = 0;
+        }
+        Some(e) => {
+            status.last_failure_ms = Some(now_ms);
+            status.last_error = Some(e.to_string());
+            status.consecutive_failures += 1;
+        }
+    }
+}
+
+/// Returns the health of every configured provider, in priority order.
+///
+/// Providers not yet tried, because those before them succeeded, are reported
+/// with no successes or failures.
+pub fn provider_status() -> Vec<PriceProviderStatus> {
+    let health = HEALTH.lock().unwrap();
+    UserPrefs::default()
+        .price_providers()
+        .iter()
+        .enumerate()
+        .map(|(priority, kind)| {
+            let mut status = health
+                .get(kind)
+                .cloned()
+                .unwrap_or_else(|| PriceProviderStatus::new(*kind, priority));
+            status.priority = priority;
+            status
+        })
+        .collect()
+}
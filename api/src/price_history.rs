@@ -0,0 +1,120 @@
+//! Fiat prices of NPT on past dates.
+//!
+//! Daily prices are fetched from CoinGecko, the only configured provider that
+//! offers free historical data, and cached in a JSON file alongside the
+//! neptune-core wallet.  A past day's price does not change, so entries are
+//! kept indefinitely.  Today's price is still moving and is never cached.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use anyhow::Context;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+
+use crate::bandwidth;
+use crate::fiat_amount::FiatAmount;
+use crate::fiat_currency::FiatCurrency;
+use crate::json_store::JsonStore;
+use crate::price_map::PriceMap;
+
+/// Limits requests per lookup, to stay within CoinGecko's public rate limit.
+/// Remaining dates are fetched by later lookups.
+const MAX_FETCHES_PER_LOOKUP: usize = 10;
+
+/// Prices keyed by UTC date, "YYYY-MM-DD".  An empty map records that the
+/// provider had no price for that date, eg before NPT was listed.
+type PriceHistory = BTreeMap<String, PriceMap>;
+
+static STORE: JsonStore<PriceHistory> =
+    JsonStore::in_wallet_dir("neptune-proton-price-history.json");
+
+/// Returns the prices for each of `dates` ("YYYY-MM-DD") that is cached or
+/// can be fetched now.
+///
+/// Dates that are missing from the result may be retried later.  Nothing is
+/// fetched while on a metered connection.
+pub async fn lookup(dates: Vec<String>) -> anyhow::Result<HashMap<String, PriceMap>> {
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+
+    let mut history = STORE.lock().await?;
+
+    let mut found = HashMap::new();
+    let mut missing = vec![];
+    for date in dates {
+        let parsed = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+            .with_context(|| format!("Invalid date: {}", date))?;
+        match history.get(&date) {
+            Some(prices) => {
+                found.insert(date, prices.clone());
+            }
+            None if !missing.iter().any(|(d, _)| *d == date) => missing.push((date, parsed)),
+            None => {}
+        }
+    }
+
+    if bandwidth::is_metered().await {
+        return Ok(found);
+    }
+
+    let mut changed = false;
+    for (date, parsed) in missing.into_iter().take(MAX_FETCHES_PER_LOOKUP) {
+        match fetch(parsed).await {
+            Ok(prices) => {
+                if date < today {
+                    history.insert(date.clone(), prices.clone());
+                    changed = true;
+                }
+                found.insert(date, prices);
+            }
+            Err(e) => {
+                // most likely rate limited; the rest are tried next time.
+                dioxus_logger::tracing::warn!(%date, error = %e, "could not fetch price history");
+                break;
+            }
+        }
+    }
+
+    if changed {
+        history.save().await?;
+    }
+    Ok(found)
+}
+
+/// The structure of the JSON response from CoinGecko's coin history API.
+#[derive(Deserialize, Debug)]
+struct CoinGeckoHistoryResponse {
+    market_data: Option<CoinGeckoMarketData>,
+}
+
+#[derive(Deserialize, Debug)]
+struct CoinGeckoMarketData {
+    current_price: HashMap<String, f64>,
+}
+
+async fn fetch(date: chrono::NaiveDate) -> anyhow::Result<PriceMap> {
+    let url = format!(
+        "https://api.coingecko.com/api/v3/coins/neptune-cash/history?date={}&localization=false",
+        date.format("%d-%m-%Y")
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<CoinGeckoHistoryResponse>()
+        .await?;
+
+    let mut price_map = PriceMap::new();
+    if let Some(market_data) = resp.market_data {
+        for currency in FiatCurrency::iter() {
+            let code_lower = currency.code().to_lowercase();
+            if let Some(price) = market_data.current_price.get(&code_lower) {
+                price_map.insert(FiatAmount::new_from_float(*price, currency));
+            }
+        }
+    }
+    Ok(price_map)
+}
@@ -53,6 +53,11 @@ impl PriceMap {
             .map(|&amount| FiatAmount::new_from_minor(amount, currency))
     }
 
+    /// Returns `true` if the map holds no prices.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Returns an iterator over the prices in the map.
     ///
     /// The iterator yields `FiatAmount` instances.
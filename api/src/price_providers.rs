@@ -21,6 +21,8 @@ pub trait PriceProviderMeta {
     Copy,
     Debug,
     PartialEq,
+    Eq,
+    Hash,
     Default,
     Serialize,
     Deserialize,
@@ -33,6 +35,37 @@ pub enum PriceProviderKind {
     #[default]
     CoinGecko,
     CoinPaprika,
+    /// A self-hosted or third party endpoint set by the `PRICE_PROVIDER_URL`
+    /// env var.
+    Custom,
+}
+
+impl PriceProviderKind {
+    /// Orders providers for failover: `primary` first, then the remaining
+    /// public providers, then [Self::Custom] if a custom URL is configured.
+    ///
+    /// The `PRICE_PROVIDERS` env var, a comma-separated list such as
+    /// "coinpaprika,coingecko", overrides this.
+    pub fn priority_from_env(primary: Self) -> Vec<Self> {
+        if let Ok(list) = std::env::var("PRICE_PROVIDERS") {
+            let mut providers: Vec<Self> = vec![];
+            for kind in list.split(',').filter_map(|s| s.trim().parse().ok()) {
+                if !providers.contains(&kind) {
+                    providers.push(kind);
+                }
+            }
+            if !providers.is_empty() {
+                return providers;
+            }
+        }
+
+        let mut providers = vec![primary];
+        providers.extend(Self::iter().filter(|k| *k != primary && !k.is_custom()));
+        if !primary.is_custom() && custom_url::CustomUrl::from_env().is_some() {
+            providers.push(Self::Custom);
+        }
+        providers
+    }
 }
 
 // Implement the METADATA trait for the enum by dispatching to the real structs.
@@ -41,6 +74,7 @@ impl PriceProviderMeta for PriceProviderKind {
         match self {
             Self::CoinGecko => coin_gecko::CoinGecko.name(),
             Self::CoinPaprika => coin_paprika::CoinPaprika.name(),
+            Self::Custom => "Custom",
         }
     }
 
@@ -48,6 +82,7 @@ impl PriceProviderMeta for PriceProviderKind {
         match self {
             Self::CoinGecko => coin_gecko::CoinGecko.website(),
             Self::CoinPaprika => coin_paprika::CoinPaprika.website(),
+            Self::Custom => "PRICE_PROVIDER_URL",
         }
     }
 }
@@ -57,10 +92,52 @@ impl PriceProvider for PriceProviderKind {
         match self {
             Self::CoinGecko => coin_gecko::CoinGecko.get_prices().await,
             Self::CoinPaprika => coin_paprika::CoinPaprika.get_prices().await,
+            Self::Custom => match custom_url::CustomUrl::from_env() {
+                Some(provider) => provider.get_prices().await,
+                None => anyhow::bail!("PRICE_PROVIDER_URL is not set"),
+            },
         }
     }
 }
 
+/// Health of a price provider, as observed by the server.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PriceProviderStatus {
+    pub kind: PriceProviderKind,
+
+    /// Position in the failover chain, starting at 0.
+    pub priority: usize,
+
+    /// The most recent prices came from this provider.
+    pub is_active: bool,
+
+    /// Unix milliseconds of the last successful and failed fetches.
+    pub last_success_ms: Option<u64>,
+    pub last_failure_ms: Option<u64>,
+
+    pub last_error: Option<String>,
+    pub consecutive_failures: u32,
+}
+
+impl PriceProviderStatus {
+    pub fn new(kind: PriceProviderKind, priority: usize) -> Self {
+        Self {
+            kind,
+            priority,
+            is_active: false,
+            last_success_ms: None,
+            last_failure_ms: None,
+            last_error: None,
+            consecutive_failures: 0,
+        }
+    }
+
+    /// True if the last attempt to fetch from this provider succeeded.
+    pub fn is_healthy(&self) -> bool {
+        self.last_success_ms.is_some() && self.consecutive_failures == 0
+    }
+}
+
 /// A trait for any service that can provide fiat prices for NPT.
 #[allow(dead_code)]
 pub(crate) trait PriceProvider: PriceProviderMeta {
@@ -189,3 +266,63 @@ pub(crate) mod coin_paprika {
         }
     }
 }
+
+/// Provides price data from a user-configured URL.
+///
+/// The endpoint must respond to a GET with a JSON object mapping currency codes
+/// to the price of one NPT, eg `{"USD": 0.0123, "EUR": 0.0114}`.  Codes are
+/// case-insensitive and unknown codes are ignored.
+pub(crate) mod custom_url {
+    use super::*;
+
+    /// An implementation of the `PriceProvider` trait for a custom URL.
+    pub struct CustomUrl {
+        url: String,
+    }
+
+    impl CustomUrl {
+        /// Reads the `PRICE_PROVIDER_URL` env var, if set.
+        pub fn from_env() -> Option<Self> {
+            std::env::var("PRICE_PROVIDER_URL")
+                .ok()
+                .filter(|url| !url.trim().is_empty())
+                .map(|url| Self { url })
+        }
+    }
+
+    impl PriceProviderMeta for CustomUrl {
+        fn name(&self) -> &'static str {
+            "Custom"
+        }
+
+        fn website(&self) -> &'static str {
+            "PRICE_PROVIDER_URL"
+        }
+    }
+
+    impl PriceProvider for CustomUrl {
+        async fn get_prices(&self) -> Result<PriceMap, anyhow::Error> {
+            let client = reqwest::Client::new();
+            let resp = client
+                .get(&self.url)
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<HashMap<String, f64>>()
+                .await?;
+
+            let mut price_map = PriceMap::new();
+            for currency in FiatCurrency::iter() {
+                let price = resp
+                    .iter()
+                    .find(|(code, _)| code.eq_ignore_ascii_case(currency.code()))
+                    .map(|(_, price)| *price);
+                if let Some(price) = price {
+                    price_map.insert(FiatAmount::new_from_float(price, currency));
+                }
+            }
+
+            Ok(price_map)
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Results of checking whether the node's peer-to-peer port is reachable
+//! from the internet.
+//!
+//! A node that cannot accept incoming connections can still connect out to
+//! peers, but will typically have fewer of them.
+
+use std::net::IpAddr;
+use std::net::SocketAddr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The outcome of a reachability check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, strum::EnumIs)]
+pub enum Reachability {
+    Reachable,
+    Unreachable,
+    /// The check could not determine reachability either way.
+    Unknown,
+}
+
+/// How reachability was determined.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReachabilityMethod {
+    /// An external service attempted to connect to the port.
+    ExternalChecker { url: String },
+    /// Inferred from whether any connected peer connected to us.
+    InboundPeers,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReachabilityReport {
+    /// The address neptune-core listens on for peers. None if it does not
+    /// accept incoming connections at all.
+    pub listen_address: Option<SocketAddr>,
+
+    /// Our public IP, if reported by the external checker.
+    pub public_ip: Option<IpAddr>,
+
+    pub method: ReachabilityMethod,
+    pub result: Reachability,
+
+    pub num_inbound_peers: usize,
+    pub num_outbound_peers: usize,
+
+    /// Set if the external checker was configured but failed. The result
+    /// then falls back to [ReachabilityMethod::InboundPeers].
+    pub checker_error: Option<String>,
+}
+
+impl ReachabilityReport {
+    /// Suggestions for the user, based on the result.
+    pub fn hints(&self) -> Vec<String> {
+        let Some(listen) = self.listen_address else {
+            return vec![
+                "neptune-core is not accepting incoming peer connections. Check its --peer-port and --max-num-peers settings.".to_string(),
+            ];
+        };
+        let port = listen.port();
+
+        match self.result {
+            Reachability::Reachable => vec![],
+            Reachability::Unreachable => vec![
+                format!("Forward TCP port {port} on your router to this machine."),
+                format!("Allow incoming TCP connections on port {port} in your firewall."),
+                "If your ISP uses carrier-grade NAT, incoming connections may not be possible without a VPN or relay.".to_string(),
+            ],
+            Reachability::Unknown => vec![
+                "No peer has connected to this node yet. This is normal shortly after startup, but may indicate a NAT or firewall problem.".to_string(),
+                format!("If the peer count stays low, check that TCP port {port} is forwarded and allowed through your firewall."),
+            ],
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Remembers the last block whose receipts were processed, so that receipts
+//! arriving while the server was down are still reported when it restarts.
+
+use neptune_types::block_height::BlockHeight;
+use serde::Deserialize;
+use serde::Serialize;
+use twenty_first::tip5::Digest;
+
+use crate::json_store::JsonStore;
+
+static STORE: JsonStore<ProcessedReceipts> =
+    JsonStore::in_wallet_dir("neptune-proton-receipts.json");
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ProcessedReceipts {
+    /// False until the history was first read, which is not reported.
+    #[serde(default)]
+    pub seeded: bool,
+
+    /// The highest block of the history when it was last processed.
+    #[serde(default)]
+    pub last_block: Option<(Digest, BlockHeight)>,
+}
+
+/// Applies `f` to the stored state, and saves the result if it changed.  The
+/// history is polled often, and mostly has nothing new.
+pub async fn update(f: impl FnOnce(&mut ProcessedReceipts)) -> anyhow::Result<()> {
+    let mut processed = STORE.lock().await?;
+    let before = (processed.seeded, processed.last_block);
+    f(&mut processed);
+    if (processed.seeded, processed.last_block) != before {
+        processed.save().await?;
+    }
+    Ok(())
+}
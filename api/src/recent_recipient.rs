@@ -0,0 +1,21 @@
+//! Addresses recently paid from this wallet, offered when picking a
+//! recipient in the Send wizard.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The number of recent recipients kept.  Older ones are forgotten.
+pub const MAX_RECENT_RECIPIENTS: usize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentRecipient {
+    /// The bech32m encoded receiving address.
+    pub address: String,
+
+    /// Who was paid, eg from a payment request.
+    pub label: Option<String>,
+
+    /// When a send to the address last succeeded, in unix milliseconds.  Set
+    /// by the server.
+    pub last_used_ms: u64,
+}
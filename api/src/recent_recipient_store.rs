@@ -0,0 +1,55 @@
+//! Persists [RecentRecipient]s as a JSON file alongside the neptune-core
+//! wallet.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::json_store::JsonStore;
+use crate::recent_recipient::RecentRecipient;
+use crate::recent_recipient::MAX_RECENT_RECIPIENTS;
+
+static STORE: JsonStore<Vec<RecentRecipient>> =
+    JsonStore::in_wallet_dir("neptune-proton-recent-recipients.json");
+
+/// Returns the recent recipients, most recently paid first.
+pub async fn load() -> anyhow::Result<Vec<RecentRecipient>> {
+    STORE.load().await
+}
+
+/// Moves `paid` to the front of the list, as paid now, keeping a previous
+/// label where no new one is given.  The oldest entries beyond
+/// [MAX_RECENT_RECIPIENTS] are dropped.
+pub async fn record(paid: Vec<RecentRecipient>) -> anyhow::Result<Vec<RecentRecipient>> {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    let mut recipients = STORE.lock().await?;
+    for mut recipient in paid.into_iter().rev() {
+        recipient.address = recipient.address.trim().to_string();
+        if recipient.address.is_empty() {
+            continue;
+        }
+        if let Some(i) = recipients
+            .iter()
+            .position(|r| r.address == recipient.address)
+        {
+            let previous = recipients.remove(i);
+            recipient.label = recipient.label.or(previous.label);
+        }
+        recipient.last_used_ms = now_ms;
+        recipients.insert(0, recipient);
+    }
+    recipients.truncate(MAX_RECENT_RECIPIENTS);
+
+    recipients.save().await?;
+    Ok(recipients.into_inner())
+}
+
+/// Forgets `address`, returning the remaining recent recipients.
+pub async fn forget(address: &str) -> anyhow::Result<Vec<RecentRecipient>> {
+    STORE
+        .update(|recipients| recipients.retain(|r| r.address != address))
+        .await
+}
@@ -0,0 +1,243 @@
+//! Notices reorgs by watching the digests of the latest blocks, and finds
+//! competing blocks near the tip.
+//!
+//! A block the node had at some height that is replaced by another means its
+//! tip moved to another branch.  The wallet's history in the replaced blocks
+//! is remembered between polls, to tell whether the reorg touched it.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use dioxus_logger::tracing::Instrument;
+use neptune_types::block_selector::BlockSelector;
+use twenty_first::tip5::Digest;
+
+use crate::bandwidth;
+use crate::chain_reorg::ChainForks;
+use crate::chain_reorg::ForkedHeight;
+use crate::chain_reorg::ReorgEvent;
+use crate::event_log;
+use crate::event_log::WalletEvent;
+use crate::neptune_rpc;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Blocks below the tip that are watched.  Deeper reorgs are not noticed.
+const WATCH_DEPTH: usize = 100;
+
+/// Blocks below the tip searched for competing blocks.  Each takes an RPC.
+const FORK_SCAN_DEPTH: u64 = 20;
+
+/// The most reorgs kept.  Older ones are dropped.
+const MAX_REORGS: usize = 50;
+
+#[derive(Default)]
+struct Watched {
+    /// The digest at each watched height, as of the last poll.
+    chain: BTreeMap<u64, Digest>,
+    /// The blocks within the watched heights where the wallet has history.
+    wallet_blocks: HashSet<Digest>,
+    /// Newest last.
+    reorgs: VecDeque<ReorgEvent>,
+}
+
+static WATCHED: Mutex<Option<Watched>> = Mutex::new(None);
+
+fn with_watched<R>(f: impl FnOnce(&mut Watched) -> R) -> R {
+    let mut guard = WATCHED.lock().unwrap_or_else(|e| e.into_inner());
+    f(guard.get_or_insert_with(Watched::default))
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Starts the background task that watches for reorgs, if not already
+/// running.
+pub fn ensure_watching() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    STARTED.get_or_init(|| {
+        tokio::spawn(
+            async {
+                loop {
+                    if let Err(e) = poll().await {
+                        dioxus_logger::tracing::debug!(error = %e, "poll failed");
+                    }
+                    tokio::time::sleep(bandwidth::scale(POLL_INTERVAL).await).await;
+                }
+            }
+            .instrument(dioxus_logger::tracing::info_span!("reorg_monitor")),
+        );
+    });
+}
+
+/// The digests of the latest blocks by height.  Fails if the tip moved while
+/// they were fetched, so that heights and digests never mismatch.
+async fn latest_chain() -> anyhow::Result<BTreeMap<u64, Digest>> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let (height, digests, height_after) = neptune_rpc::with_token(|token| async move {
+        let height: u64 = client
+            .block_height(neptune_rpc::call_context("block_height"), token)
+            .await??
+            .into();
+        // tip first, then its ancestors.
+        let digests = client
+            .latest_tip_digests(
+                neptune_rpc::call_context("latest_tip_digests"),
+                token,
+                WATCH_DEPTH,
+            )
+            .await??;
+        let height_after: u64 = client
+            .block_height(neptune_rpc::call_context("block_height"), token)
+            .await??
+            .into();
+        Ok((height, digests, height_after))
+    })
+    .await?;
+    if height_after != height {
+        anyhow::bail!("the tip moved while fetching the latest blocks");
+    }
+
+    Ok(digests
+        .into_iter()
+        .zip((0..=height).rev())
+        .map(|(digest, height)| (height, digest))
+        .collect())
+}
+
+async fn poll() -> anyhow::Result<()> {
+    let chain = latest_chain().await?;
+    let Some((&tip_height, &tip)) = chain.last_key_value() else {
+        return Ok(());
+    };
+    let (old_chain, old_wallet_blocks) =
+        with_watched(|w| (w.chain.clone(), w.wallet_blocks.clone()));
+    if old_chain.last_key_value() == Some((&tip_height, &tip)) {
+        return Ok(());
+    }
+
+    // the lowest watched height whose block was replaced.
+    let replaced_from = old_chain
+        .iter()
+        .find(|(height, digest)| chain.get(height).is_some_and(|new| new != *digest))
+        .map(|(height, _)| *height);
+
+    let client = &neptune_rpc::rpc_client().await?;
+    let history = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .history(neptune_rpc::call_context("history"), token)
+            .await??)
+    })
+    .await?;
+    let lowest_watched = chain.first_key_value().map(|(h, _)| *h).unwrap_or_default();
+    let wallet_blocks: HashSet<Digest> = history
+        .iter()
+        .filter(|(_, height, _, _)| u64::from(*height) >= lowest_watched)
+        .map(|(digest, _, _, _)| *digest)
+        .collect();
+
+    let reorg = replaced_from.and_then(|from| {
+        let (&old_height, &old_tip) = old_chain.last_key_value()?;
+        let fork_height = from.saturating_sub(1);
+        let replaced: HashSet<Digest> = old_chain.range(from..).map(|(_, d)| *d).collect();
+        let affects_wallet = !old_wallet_blocks.is_disjoint(&replaced)
+            || history.iter().any(|(_, height, _, _)| u64::from(*height) > fork_height);
+        Some(ReorgEvent {
+            at_ms: now_ms(),
+            fork_height,
+            old_tip,
+            old_height,
+            new_tip: tip,
+            new_height: tip_height,
+            affects_wallet,
+        })
+    });
+
+    with_watched(|w| {
+        w.chain = chain;
+        w.wallet_blocks = wallet_blocks;
+        if let Some(reorg) = &reorg {
+            w.reorgs.push_back(reorg.clone());
+            while w.reorgs.len() > MAX_REORGS {
+                w.reorgs.pop_front();
+            }
+        }
+    });
+
+    if let Some(reorg) = reorg {
+        dioxus_logger::tracing::warn!(
+            depth = reorg.depth(),
+            fork_height = reorg.fork_height,
+            affects_wallet = reorg.affects_wallet,
+            "chain reorganised"
+        );
+        event_log::record(WalletEvent::Reorg {
+            fork_height: reorg.fork_height,
+            depth: reorg.depth(),
+            old_tip: reorg.old_tip.to_hex(),
+            new_tip: reorg.new_tip.to_hex(),
+            affects_wallet: reorg.affects_wallet,
+        })
+        .await;
+    }
+    Ok(())
+}
+
+/// The competing blocks near the tip, and the reorgs seen so far.
+pub async fn forks() -> anyhow::Result<ChainForks> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let (tip_height, forks) = neptune_rpc::with_token(|token| async move {
+        let tip_height: u64 = client
+            .block_height(neptune_rpc::call_context("block_height"), token)
+            .await??
+            .into();
+        let mut forks = vec![];
+        for height in (tip_height.saturating_sub(FORK_SCAN_DEPTH)..=tip_height).rev() {
+            let digests = client
+                .block_digests_by_height(
+                    neptune_rpc::call_context("block_digests_by_height"),
+                    token,
+                    height.into(),
+                )
+                .await??;
+            if digests.len() < 2 {
+                continue;
+            }
+            let canonical = client
+                .block_digest(
+                    neptune_rpc::call_context("block_digest"),
+                    token,
+                    BlockSelector::Height(height.into()),
+                )
+                .await??;
+            let Some(canonical) = canonical else {
+                continue;
+            };
+            forks.push(ForkedHeight {
+                height,
+                canonical,
+                others: digests.into_iter().filter(|d| *d != canonical).collect(),
+            });
+        }
+        Ok((tip_height, forks))
+    })
+    .await?;
+
+    Ok(ChainForks {
+        tip_height,
+        forks,
+        reorgs: with_watched(|w| w.reorgs.iter().rev().cloned().collect()),
+    })
+}
@@ -0,0 +1,54 @@
+//! Shares the result of an RPC between callers that ask for it at about the
+//! same time.
+//!
+//! Several screens poll the same endpoints, eg the balance and block height,
+//! and a page load can request each several times at once.  A cached value is
+//! reused for [TTL], and callers arriving while it is being fetched wait for
+//! that one fetch instead of making their own.  Failures are not cached, so
+//! the next caller tries again.
+
+use std::future::Future;
+use std::time::Duration;
+use std::time::Instant;
+
+/// How long a fetched value is reused, unless the cache says otherwise.
+/// Short enough that polls still see fresh data.
+pub const TTL: Duration = Duration::from_millis(500);
+
+/// The cached result of one endpoint.
+pub struct RpcCache<T> {
+    ttl: Duration,
+    // a tokio mutex, held while fetching, so that concurrent callers wait
+    // for the fetch in progress.
+    state: tokio::sync::Mutex<Option<(Instant, T)>>,
+}
+
+impl<T: Clone> RpcCache<T> {
+    pub const fn new() -> Self {
+        Self::with_ttl(TTL)
+    }
+
+    pub const fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            state: tokio::sync::Mutex::const_new(None),
+        }
+    }
+
+    /// Returns the cached value if it is younger than the cache's TTL, else
+    /// fetches a new one with `fetch`.
+    pub async fn get<Fut>(&self, fetch: impl FnOnce() -> Fut) -> anyhow::Result<T>
+    where
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let mut state = self.state.lock().await;
+        if let Some((fetched_at, value)) = &*state {
+            if fetched_at.elapsed() < self.ttl {
+                return Ok(value.clone());
+            }
+        }
+        let value = fetch().await?;
+        *state = Some((Instant::now(), value.clone()));
+        Ok(value)
+    }
+}
@@ -0,0 +1,255 @@
+//! Shares one connection to neptune-core's RPC server, and one auth token,
+//! between all API calls.
+//!
+//! A pooled connection is replaced once its dispatch task ends, ie when
+//! neptune-core closes it or restarts.  Failed attempts to reconnect back off
+//! exponentially, so that a node that is down is not hammered by every poll.
+//!
+//! neptune-core writes a new cookie each time it starts, so the cached token
+//! is dropped whenever a new connection is made, and on any auth error.
+//! Every call is made through [with_token], which retries it once with the
+//! reloaded token, and if neptune-core rejects that too, the user is
+//! notified.
+
+use std::future::Future;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use neptune_cash::application::rpc::auth as rpc_auth;
+use neptune_cash::application::rpc::server::error::RpcError;
+
+use crate::api_error::Failure;
+use crate::notification::NotificationKind;
+use crate::notification_store;
+
+/// Delay before the first retry after a failed connection attempt.  Doubles
+/// with each further failure.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+static TOKEN: Mutex<TokenState> = Mutex::new(TokenState {
+    token: None,
+    rejected: false,
+    reloaded: false,
+});
+
+struct TokenState {
+    token: Option<rpc_auth::Token>,
+    /// neptune-core rejected the last token, so the next one loaded is a
+    /// reload.
+    rejected: bool,
+    /// The token was loaded after a rejection, and has not been accepted
+    /// yet.  If it is rejected too, reading the cookie again did not help.
+    reloaded: bool,
+}
+
+fn with_token_state<R>(f: impl FnOnce(&mut TokenState) -> R) -> R {
+    f(&mut TOKEN.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// A client whose dispatch task clears `alive` when the connection closes.
+struct Pooled<C> {
+    client: C,
+    alive: Arc<AtomicBool>,
+}
+
+struct PoolState<C> {
+    pooled: Option<Pooled<C>>,
+    /// Connection attempts failed in a row.
+    failures: u32,
+    /// No new attempt is made before this, after a failure.
+    retry_at: Option<Instant>,
+}
+
+/// A lazily established, shared client connection.
+pub struct Pool<C> {
+    // a tokio mutex, so that concurrent callers wait for one attempt to
+    // connect instead of each making their own.
+    state: tokio::sync::Mutex<PoolState<C>>,
+}
+
+impl<C: Clone> Pool<C> {
+    pub const fn new() -> Self {
+        Self {
+            state: tokio::sync::Mutex::const_new(PoolState {
+                pooled: None,
+                failures: 0,
+                retry_at: None,
+            }),
+        }
+    }
+
+    /// Returns the pooled client, connecting first if there is none or its
+    /// connection has closed.
+    ///
+    /// `connect` is passed the flag to hand to [watch] along with the new
+    /// client's dispatch task.
+    pub async fn get<Fut>(&self, connect: impl FnOnce(Arc<AtomicBool>) -> Fut) -> anyhow::Result<C>
+    where
+        Fut: Future<Output = anyhow::Result<C>>,
+    {
+        let mut state = self.state.lock().await;
+        if let Some(pooled) = &state.pooled {
+            if pooled.alive.load(Ordering::Acquire) {
+                return Ok(pooled.client.clone());
+            }
+            state.pooled = None;
+        }
+
+        if let Some(retry_at) = state.retry_at {
+            let now = Instant::now();
+            if now < retry_at {
+                anyhow::bail!(Failure::ConnectionLost(format!(
+                    "neptune-core is unreachable.  Retrying in {:.1} seconds.",
+                    (retry_at - now).as_secs_f32()
+                )));
+            }
+        }
+
+        let alive = Arc::new(AtomicBool::new(true));
+        match connect(alive.clone()).await {
+            Ok(client) => {
+                state.failures = 0;
+                state.retry_at = None;
+                state.pooled = Some(Pooled {
+                    client: client.clone(),
+                    alive,
+                });
+                invalidate_token();
+                Ok(client)
+            }
+            Err(e) => {
+                state.failures = state.failures.saturating_add(1);
+                let backoff = INITIAL_BACKOFF
+                    .saturating_mul(1 << (state.failures - 1).min(16))
+                    .min(MAX_BACKOFF);
+                state.retry_at = Some(Instant::now() + backoff);
+                dioxus_logger::tracing::debug!(
+                    failures = state.failures,
+                    backoff_ms = backoff.as_millis() as u64,
+                    error = %e,
+                    "could not connect to neptune-core"
+                );
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Runs a client's dispatch task, clearing `alive` once it ends.
+pub fn watch<D>(dispatch: D, alive: Arc<AtomicBool>)
+where
+    D: Future + Send + 'static,
+{
+    tokio::spawn(async move {
+        let _ = dispatch.await;
+        alive.store(false, Ordering::Release);
+        dioxus_logger::tracing::debug!("rpc connection to neptune-core closed");
+    });
+}
+
+/// Returns the cached token, loading it with `load` if there is none.
+pub async fn token<Fut>(load: impl FnOnce() -> Fut) -> anyhow::Result<rpc_auth::Token>
+where
+    Fut: Future<Output = anyhow::Result<rpc_auth::Token>>,
+{
+    Ok(cached_or_load(load).await?.0)
+}
+
+/// The token, and whether it was just loaded.
+async fn cached_or_load<Fut>(load: impl FnOnce() -> Fut) -> anyhow::Result<(rpc_auth::Token, bool)>
+where
+    Fut: Future<Output = anyhow::Result<rpc_auth::Token>>,
+{
+    if let Some(token) = with_token_state(|state| state.token) {
+        return Ok((token, false));
+    }
+    let token = load().await?;
+    with_token_state(|state| {
+        state.token = Some(token);
+        state.reloaded = state.rejected;
+        state.rejected = false;
+    });
+    Ok((token, true))
+}
+
+/// Runs `call` with the cached token, loading it with `load` if there is
+/// none.
+///
+/// If neptune-core rejects a cached token, eg because it restarted and wrote
+/// a new cookie, the token is loaded again and `call` made once more.  A
+/// token that was just loaded is not loaded again.
+pub async fn with_token<T, L, LFut, C, CFut>(load: L, call: C) -> anyhow::Result<T>
+where
+    L: Fn() -> LFut,
+    LFut: Future<Output = anyhow::Result<rpc_auth::Token>>,
+    C: Fn(rpc_auth::Token) -> CFut,
+    CFut: Future<Output = anyhow::Result<T>>,
+{
+    let (token, fresh) = cached_or_load(&load).await?;
+    let result = match call(token).await {
+        Err(e) if is_auth_error(&e) && !fresh => {
+            dioxus_logger::tracing::info!(
+                "neptune-core rejected the RPC token; reading its cookie again"
+            );
+            note_error(&e);
+            call(cached_or_load(&load).await?.0).await
+        }
+        result => result,
+    };
+    match &result {
+        Ok(_) => with_token_state(|state| state.reloaded = false),
+        Err(e) => note_error(e),
+    }
+    result
+}
+
+/// Drops the cached token, eg because neptune-core restarted.  Unlike a
+/// rejection, this is no reason to think the next token will not work.
+pub fn invalidate_token() {
+    with_token_state(|state| {
+        state.token = None;
+        state.rejected = false;
+        state.reloaded = false;
+    });
+}
+
+fn is_auth_error(e: &anyhow::Error) -> bool {
+    e.chain()
+        .any(|cause| matches!(cause.downcast_ref::<RpcError>(), Some(RpcError::Auth(_))))
+}
+
+/// Drops the cached token if `e` shows that neptune-core rejected it, so
+/// that the next call reloads the cookie.  If the rejected token was itself
+/// a reload, the user is notified, as reading the cookie again did not help.
+pub fn note_error(e: &anyhow::Error) {
+    if !is_auth_error(e) {
+        return;
+    }
+    let reload_failed = with_token_state(|state| {
+        let reload_failed = state.token.is_some() && state.reloaded;
+        state.token = None;
+        state.rejected = true;
+        state.reloaded = false;
+        reload_failed
+    });
+    if reload_failed {
+        dioxus_logger::tracing::warn!(
+            error = %e,
+            "neptune-core rejected the RPC token read again from its cookie"
+        );
+        let error = format!("{e:#}");
+        tokio::spawn(async move {
+            if let Err(e) =
+                notification_store::push(NotificationKind::TokenRejected { error }).await
+            {
+                dioxus_logger::tracing::warn!("could not store notification: {e}");
+            }
+        });
+    }
+}
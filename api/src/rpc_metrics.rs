@@ -0,0 +1,47 @@
+//! Latency and failures of the calls made to neptune-core since the server
+//! started, for troubleshooting a slow or flaky node.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How often one RPC method was called, and how long it took.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcMethodMetrics {
+    pub method: String,
+    pub calls: u64,
+    /// Calls that timed out or lost the connection.
+    pub errors: u64,
+    pub mean_ms: u64,
+    pub max_ms: u64,
+    pub last_ms: u64,
+}
+
+impl RpcMethodMetrics {
+    /// The share of calls that failed, in percent.
+    pub fn error_rate(&self) -> f64 {
+        if self.calls == 0 {
+            return 0.0;
+        }
+        self.errors as f64 / self.calls as f64 * 100.0
+    }
+}
+
+/// A call that failed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcFailure {
+    pub method: String,
+    /// unix time in milliseconds that the call ended.
+    pub at_ms: u64,
+    pub elapsed_ms: u64,
+    pub error: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcMetrics {
+    /// unix time in milliseconds that recording started.
+    pub since_ms: u64,
+    /// Slowest mean latency first.
+    pub methods: Vec<RpcMethodMetrics>,
+    /// Newest first.
+    pub recent_failures: Vec<RpcFailure>,
+}
@@ -0,0 +1,135 @@
+//! Records the latency and outcome of every call to neptune-core.
+//!
+//! The tarpc clients are built on a [Metered] channel, which times each
+//! request by its method name, so call sites need not do anything.  Errors
+//! that neptune-core returns inside a response, such as a stale auth token,
+//! are not seen by the channel and not counted.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use tarpc::client::stub::Stub;
+use tarpc::client::RpcError;
+use tarpc::context;
+
+use crate::node_version_check;
+use crate::rpc_metrics::RpcFailure;
+use crate::rpc_metrics::RpcMethodMetrics;
+use crate::rpc_metrics::RpcMetrics;
+
+/// Failures kept for display.
+const MAX_RECENT_FAILURES: usize = 50;
+
+#[derive(Default)]
+struct MethodTotals {
+    calls: u64,
+    errors: u64,
+    total: Duration,
+    max: Duration,
+    last: Duration,
+}
+
+struct Recorded {
+    since_ms: u64,
+    methods: HashMap<&'static str, MethodTotals>,
+    failures: VecDeque<RpcFailure>,
+}
+
+static RECORDED: LazyLock<Mutex<Recorded>> = LazyLock::new(|| {
+    Mutex::new(Recorded {
+        since_ms: now_ms(),
+        methods: HashMap::new(),
+        failures: VecDeque::new(),
+    })
+});
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+fn with_recorded<R>(f: impl FnOnce(&mut Recorded) -> R) -> R {
+    f(&mut RECORDED.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+fn record(method: &'static str, elapsed: Duration, error: Option<String>) {
+    with_recorded(|recorded| {
+        let totals = recorded.methods.entry(method).or_default();
+        totals.calls += 1;
+        totals.total += elapsed;
+        totals.max = totals.max.max(elapsed);
+        totals.last = elapsed;
+        if let Some(error) = error {
+            totals.errors += 1;
+            if recorded.failures.len() == MAX_RECENT_FAILURES {
+                recorded.failures.pop_back();
+            }
+            recorded.failures.push_front(RpcFailure {
+                method: method.to_string(),
+                at_ms: now_ms(),
+                elapsed_ms: elapsed.as_millis() as u64,
+                error,
+            });
+        }
+    });
+}
+
+pub fn snapshot() -> RpcMetrics {
+    with_recorded(|recorded| {
+        let mut methods: Vec<RpcMethodMetrics> = recorded
+            .methods
+            .iter()
+            .map(|(method, totals)| RpcMethodMetrics {
+                method: method.to_string(),
+                calls: totals.calls,
+                errors: totals.errors,
+                mean_ms: totals
+                    .total
+                    .checked_div(totals.calls.max(1) as u32)
+                    .unwrap_or_default()
+                    .as_millis() as u64,
+                max_ms: totals.max.as_millis() as u64,
+                last_ms: totals.last.as_millis() as u64,
+            })
+            .collect();
+        methods.sort_by(|a, b| b.mean_ms.cmp(&a.mean_ms).then(a.method.cmp(&b.method)));
+        RpcMetrics {
+            since_ms: recorded.since_ms,
+            methods,
+            recent_failures: recorded.failures.iter().cloned().collect(),
+        }
+    })
+}
+
+/// A tarpc channel that records each call.
+#[derive(Clone, Debug)]
+pub struct Metered<S>(pub S);
+
+impl<S: Stub> Stub for Metered<S> {
+    type Req = S::Req;
+    type Resp = S::Resp;
+
+    async fn call(
+        &self,
+        ctx: context::Context,
+        request_name: &'static str,
+        request: Self::Req,
+    ) -> Result<Self::Resp, RpcError> {
+        let start = Instant::now();
+        let result = self.0.call(ctx, request_name, request).await;
+        let error = result.as_ref().err().map(|e| e.to_string());
+        if let Some(error) = &error {
+            node_version_check::note_rpc_error(error);
+        }
+        record(request_name, start.elapsed(), error);
+        result
+    }
+}
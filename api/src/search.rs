@@ -0,0 +1,17 @@
+//! What a search of blocks, transactions and addresses found.
+
+use neptune_types::block_selector::BlockSelector;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SearchResult {
+    /// A block, by height or digest.
+    Block(BlockSelector),
+    /// A transaction waiting in the mempool.
+    MempoolTx(TransactionKernelId),
+    /// One of the wallet's own addresses.
+    Address,
+    NotFound,
+}
@@ -0,0 +1,40 @@
+//! Saved, unsent states of the Send wizard.
+//!
+//! A draft records what the user typed, not validated values, so that an
+//! incomplete send can be saved and finished later, eg once prices move or a
+//! payment is approved.
+
+use neptune_types::change_policy::ChangePolicy;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::fiat_currency::FiatCurrency;
+
+/// An amount as entered, in NPT or a fiat currency.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftAmount {
+    pub value: String,
+
+    /// The fiat currency `value` was entered in, or `None` for NPT.
+    pub fiat: Option<FiatCurrency>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftRecipient {
+    pub address: String,
+    pub amount: DraftAmount,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SendDraft {
+    /// Chosen by the user.  Saving a draft under an existing name replaces it.
+    pub name: String,
+    pub recipients: Vec<DraftRecipient>,
+    pub fee: DraftAmount,
+    pub change_policy: ChangePolicy,
+
+    /// When the draft was saved, in unix milliseconds.  Set by the server.
+    pub saved_at_ms: u64,
+}
@@ -0,0 +1,43 @@
+//! Persists [SendDraft]s as a JSON file alongside the neptune-core wallet.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::json_store::JsonStore;
+use crate::send_draft::SendDraft;
+
+static STORE: JsonStore<Vec<SendDraft>> =
+    JsonStore::in_wallet_dir("neptune-proton-send-drafts.json");
+
+/// Returns all stored drafts, most recently saved first.
+pub async fn load() -> anyhow::Result<Vec<SendDraft>> {
+    STORE.load().await
+}
+
+/// Stores `draft`, replacing any draft of the same name.
+pub async fn save(mut draft: SendDraft) -> anyhow::Result<()> {
+    draft.name = draft.name.trim().to_string();
+    if draft.name.is_empty() {
+        anyhow::bail!("Draft name must not be empty.");
+    }
+    draft.saved_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    STORE
+        .update(|drafts| {
+            drafts.retain(|d| d.name != draft.name);
+            drafts.insert(0, draft);
+        })
+        .await?;
+    Ok(())
+}
+
+/// Deletes the draft named `name`, if any.
+pub async fn delete(name: &str) -> anyhow::Result<()> {
+    STORE
+        .update(|drafts| drafts.retain(|d| d.name != name))
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Sends made from the Send wizard, tracked so that their outcome can be
+//! seen after leaving the Send screen, or after restarting the app.
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ApiError;
+
+/// The number of finished jobs kept.  Older ones are forgotten.
+pub const MAX_SEND_JOBS: usize = 20;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, strum::EnumIs)]
+pub enum SendJobStatus {
+    /// Being built, proved or broadcast.
+    InFlight,
+    Sent {
+        txid: TransactionKernelId,
+    },
+    Failed {
+        error: ApiError,
+    },
+    /// Cut short, eg by closing the app, before its outcome was known.  The
+    /// transaction may still have been sent; see the history.
+    Interrupted,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SendJob {
+    /// Unique among the jobs, chosen by the client.
+    pub id: u64,
+
+    /// When the send started, in unix milliseconds.
+    pub started_ms: u64,
+
+    /// When the send returned, in unix milliseconds.
+    pub finished_ms: Option<u64>,
+
+    pub num_recipients: usize,
+
+    /// The total paid to the recipients, without the fee.
+    pub amount: NativeCurrencyAmount,
+
+    pub fee: NativeCurrencyAmount,
+
+    pub status: SendJobStatus,
+
+    /// Whether the user has seen the outcome.  Unseen finished jobs are
+    /// pointed out in the header.
+    pub seen: bool,
+}
@@ -0,0 +1,87 @@
+//! Persists [SendJob]s as a JSON file alongside the neptune-core wallet.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::json_store::JsonStore;
+use crate::send_job::SendJob;
+use crate::send_job::SendJobStatus;
+use crate::send_job::MAX_SEND_JOBS;
+use crate::send_monitor;
+
+/// How long a job may be in flight without a send being tracked, before it
+/// counts as interrupted.  Covers the moment between saving a job and
+/// starting its send.
+const INTERRUPTED_AFTER_MS: u64 = 60_000;
+
+static STORE: JsonStore<Vec<SendJob>> = JsonStore::in_wallet_dir("neptune-proton-send-jobs.json");
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Returns the jobs, most recently started first.
+///
+/// Jobs still in flight while no send is, were cut short before their
+/// outcome was saved, and are marked [SendJobStatus::Interrupted].
+pub async fn load() -> anyhow::Result<Vec<SendJob>> {
+    let mut jobs = STORE.lock().await?;
+    if send_monitor::current().is_none() {
+        let now_ms = now_ms();
+        let mut changed = false;
+        for job in jobs.iter_mut().filter(|job| job.status.is_in_flight()) {
+            if now_ms.saturating_sub(job.started_ms) > INTERRUPTED_AFTER_MS {
+                job.status = SendJobStatus::Interrupted;
+                job.seen = false;
+                changed = true;
+            }
+        }
+        if changed {
+            jobs.save().await?;
+        }
+    }
+    Ok(jobs.into_inner())
+}
+
+/// Saves `job`, replacing any job with the same id.  The oldest finished
+/// jobs beyond [MAX_SEND_JOBS] are dropped.
+pub async fn save(job: SendJob) -> anyhow::Result<Vec<SendJob>> {
+    STORE
+        .update(|jobs| {
+            match jobs.iter_mut().find(|j| j.id == job.id) {
+                Some(existing) => *existing = job,
+                None => jobs.insert(0, job),
+            }
+            jobs.sort_by(|a, b| b.started_ms.cmp(&a.started_ms));
+            let mut num_finished = 0;
+            jobs.retain(|j| {
+                if j.status.is_in_flight() {
+                    return true;
+                }
+                num_finished += 1;
+                num_finished <= MAX_SEND_JOBS
+            });
+        })
+        .await
+}
+
+/// Marks the jobs with the given ids as seen, returning all jobs.
+pub async fn mark_seen(ids: &[u64]) -> anyhow::Result<Vec<SendJob>> {
+    STORE
+        .update(|jobs| {
+            for job in jobs.iter_mut().filter(|j| ids.contains(&j.id)) {
+                job.seen = true;
+            }
+        })
+        .await
+}
+
+/// Forgets the finished jobs, returning those still in flight.
+pub async fn clear_finished() -> anyhow::Result<Vec<SendJob>> {
+    STORE
+        .update(|jobs| jobs.retain(|j| j.status.is_in_flight()))
+        .await
+}
@@ -0,0 +1,83 @@
+//! Tracks the stage of the send in flight, so that the UI can poll it while
+//! the send call itself is still waiting.
+//!
+//! Only one send is tracked.  Sends are made one at a time from the Send
+//! screen, and a second concurrent send simply takes over the tracking.
+
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::send_progress::SendProgress;
+use crate::send_progress::SendStage;
+
+struct InFlight {
+    /// Distinguishes this send from one that took over the tracking.
+    id: u64,
+    stage: SendStage,
+    attempt: u32,
+    started: Instant,
+    stage_started: Instant,
+}
+
+static IN_FLIGHT: Mutex<Option<InFlight>> = Mutex::new(None);
+
+/// The tracking of a send.  Stops tracking when dropped, however the send
+/// ended.
+pub struct SendTracker {
+    id: u64,
+}
+
+impl SendTracker {
+    /// Starts tracking a send, at [SendStage::SelectingInputs].
+    pub fn start() -> Self {
+        let mut in_flight = IN_FLIGHT.lock().unwrap_or_else(|e| e.into_inner());
+        let id = in_flight.as_ref().map_or(0, |send| send.id.wrapping_add(1));
+        let now = Instant::now();
+        *in_flight = Some(InFlight {
+            id,
+            stage: SendStage::SelectingInputs,
+            attempt: 1,
+            started: now,
+            stage_started: now,
+        });
+        Self { id }
+    }
+
+    pub fn set_stage(&self, stage: SendStage) {
+        let mut in_flight = IN_FLIGHT.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(send) = in_flight.as_mut().filter(|send| send.id == self.id) {
+            send.stage = stage;
+            send.stage_started = Instant::now();
+        }
+    }
+
+    /// Starts attempt number `attempt`, at [SendStage::SelectingInputs].
+    pub fn set_attempt(&self, attempt: u32) {
+        let mut in_flight = IN_FLIGHT.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(send) = in_flight.as_mut().filter(|send| send.id == self.id) {
+            send.attempt = attempt;
+            send.stage = SendStage::SelectingInputs;
+            send.stage_started = Instant::now();
+        }
+    }
+}
+
+impl Drop for SendTracker {
+    fn drop(&mut self) {
+        let mut in_flight = IN_FLIGHT.lock().unwrap_or_else(|e| e.into_inner());
+        if in_flight.as_ref().is_some_and(|send| send.id == self.id) {
+            *in_flight = None;
+        }
+    }
+}
+
+/// The progress of the send in flight, if any.
+pub fn current() -> Option<SendProgress> {
+    let in_flight = IN_FLIGHT.lock().unwrap_or_else(|e| e.into_inner());
+    in_flight.as_ref().map(|send| SendProgress {
+        stage: send.stage,
+        elapsed_secs: send.started.elapsed().as_secs(),
+        stage_elapsed_secs: send.stage_started.elapsed().as_secs(),
+        attempt: send.attempt,
+    })
+}
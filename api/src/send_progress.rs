@@ -0,0 +1,35 @@
+//! How far an in-flight send has got, for the progress shown while it is
+//! built and proved.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The stages of a send, in the order they happen.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, strum::EnumIter,
+)]
+pub enum SendStage {
+    /// Choosing the UTXOs that cover the amount and fee.
+    SelectingInputs,
+    /// Generating the outputs and the transaction details.
+    Building,
+    /// Proving the transaction.  Usually the longest stage by far.
+    Proving,
+    /// Recording the transaction in the mempool and telling peers.
+    Broadcasting,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SendProgress {
+    pub stage: SendStage,
+
+    /// Seconds since the send started.
+    pub elapsed_secs: u64,
+
+    /// Seconds since the current stage started.
+    pub stage_elapsed_secs: u64,
+
+    /// Starts at 1, and counts up as the send is retried after losing the
+    /// connection to neptune-core.
+    pub attempt: u32,
+}
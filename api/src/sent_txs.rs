@@ -0,0 +1,62 @@
+//! Remembers the transactions sent through this app.
+//!
+//! neptune-core's mempool does not say which transactions are the wallet's
+//! own, so the ones we sent are kept here, to warn when one waits long
+//! enough that its fee is likely too low, and to find the block a noted
+//! transaction is confirmed in.
+//!
+//! A transaction is forgotten once it leaves the mempool, whether mined or
+//! dropped.  The registry lives in memory only and is lost when the server
+//! restarts.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+
+/// A transaction sent through this app.
+#[derive(Clone, Debug)]
+pub struct SentTx {
+    pub fee: NativeCurrencyAmount,
+    /// The AOCL leaf indices of the UTXOs spent.
+    pub inputs: Vec<u64>,
+}
+
+fn registry() -> &'static Mutex<HashMap<TransactionKernelId, SentTx>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<TransactionKernelId, SentTx>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+pub fn insert(txid: TransactionKernelId, sent: SentTx) {
+    registry()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(txid, sent);
+}
+
+pub fn get(txid: &TransactionKernelId) -> Option<SentTx> {
+    registry()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(txid)
+        .cloned()
+}
+
+pub fn remove(txid: &TransactionKernelId) {
+    registry()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(txid);
+}
+
+/// All remembered transactions.
+pub fn all() -> Vec<(TransactionKernelId, SentTx)> {
+    registry()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|(txid, sent)| (*txid, sent.clone()))
+        .collect()
+}
@@ -0,0 +1,27 @@
+//! Devices that have used the wallet, as listed in Settings so that the user
+//! can revoke any they do not recognise.
+
+use std::net::IpAddr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionInfo {
+    /// Stable for a given user, device and IP address.
+    pub id: String,
+    pub user: String,
+
+    /// The browser or app, from its User-Agent header.
+    pub device: String,
+
+    /// The client's address as forwarded by a reverse proxy, if any.
+    pub ip: Option<IpAddr>,
+
+    /// When the session last made an authenticated request, in unix
+    /// milliseconds.
+    pub last_active_ms: u64,
+
+    /// True for the session that requested the list.
+    pub current: bool,
+}
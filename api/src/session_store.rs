@@ -0,0 +1,147 @@
+//! Tracks the sessions that make authenticated requests.
+//!
+//! A session is a user on a device: the same user agent at the same client IP
+//! address.  Activity is only kept in memory.  Revocations are persisted as a
+//! JSON file alongside the neptune-core wallet, so a revoked device stays
+//! locked out across restarts.
+//!
+//! Revoking is not a substitute for removing a user's access at the identity
+//! provider: a revoked device that changes its user agent or address starts a
+//! new session.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::net::IpAddr;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use dioxus::fullstack::HeaderMap;
+use tokio::sync::Mutex;
+
+use crate::api_error::Failure;
+use crate::json_store::JsonStore;
+use crate::session::SessionInfo;
+
+static REVOKED: JsonStore<HashSet<String>> =
+    JsonStore::in_wallet_dir("neptune-proton-revoked-sessions.json");
+
+struct Store {
+    active: BTreeMap<String, SessionInfo>,
+
+    /// ids of revoked sessions.  `None` until read from disk.
+    revoked: Option<HashSet<String>>,
+}
+
+static STORE: Mutex<Store> = Mutex::const_new(Store {
+    active: BTreeMap::new(),
+    revoked: None,
+});
+
+async fn revoked(store: &mut Store) -> anyhow::Result<&mut HashSet<String>> {
+    if store.revoked.is_none() {
+        store.revoked = Some(REVOKED.load().await?);
+    }
+    Ok(store.revoked.get_or_insert_with(HashSet::new))
+}
+
+fn device(headers: &HeaderMap) -> String {
+    headers
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .unwrap_or("Unknown device")
+        .to_string()
+}
+
+/// The client address forwarded by a reverse proxy.  `None` for direct
+/// connections, eg the desktop app.
+fn client_ip(headers: &HeaderMap) -> Option<IpAddr> {
+    let forwarded_for = headers
+        .get("x-forwarded-for")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(',').next());
+    let real_ip = headers.get("x-real-ip").and_then(|v| v.to_str().ok());
+
+    forwarded_for.or(real_ip)?.trim().parse().ok()
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// The id of the session making a request.
+pub fn id_for(user: &str, headers: &HeaderMap) -> String {
+    let mut hasher = DefaultHasher::new();
+    (user, device(headers), client_ip(headers)).hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Records activity by `user`, or fails if the session was revoked.
+pub async fn touch(user: &str, headers: &HeaderMap) -> anyhow::Result<()> {
+    let id = id_for(user, headers);
+    let mut store = STORE.lock().await;
+
+    if revoked(&mut store).await?.contains(&id) {
+        anyhow::bail!(Failure::AuthFailed(
+            "This device's session was revoked.".to_string()
+        ));
+    }
+
+    store.active.insert(
+        id.clone(),
+        SessionInfo {
+            id,
+            user: user.to_string(),
+            device: device(headers),
+            ip: client_ip(headers),
+            last_active_ms: now_ms(),
+            current: false,
+        },
+    );
+    Ok(())
+}
+
+/// Returns the sessions of `user`, or of all users if `None`, most recently
+/// active first.
+pub async fn list(user: Option<&str>, current_id: &str) -> Vec<SessionInfo> {
+    let store = STORE.lock().await;
+
+    let mut sessions: Vec<SessionInfo> = store
+        .active
+        .values()
+        .filter(|s| user.is_none_or(|u| s.user == u))
+        .map(|s| SessionInfo {
+            current: s.id == current_id,
+            ..s.clone()
+        })
+        .collect();
+    sessions.sort_by(|a, b| b.last_active_ms.cmp(&a.last_active_ms));
+    sessions
+}
+
+/// Revokes the session `id`, returning it.  If `user` is given, the session
+/// must belong to that user.
+pub async fn revoke(id: &str, user: Option<&str>) -> anyhow::Result<SessionInfo> {
+    let mut store = STORE.lock().await;
+
+    let session = store
+        .active
+        .get(id)
+        .filter(|s| user.is_none_or(|u| s.user == u))
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Session not found."))?;
+
+    let revoked = revoked(&mut store).await?;
+    revoked.insert(id.to_string());
+    REVOKED.save(revoked).await?;
+
+    store.active.remove(id);
+    Ok(session)
+}
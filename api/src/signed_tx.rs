@@ -0,0 +1,27 @@
+//! Signed transactions submitted for broadcast, eg ones signed by another
+//! wallet.  A signed transaction is neptune-core's `TxCreationArtifacts`,
+//! in bincode, given as hex.
+
+/// Reads a signed transaction given as hex, returning its bincode bytes.
+/// Whitespace and a leading "0x" are ignored.
+pub fn signed_tx_bytes(text: &str) -> Result<Vec<u8>, String> {
+    let hex: String = text.split_whitespace().collect();
+    let hex = hex.strip_prefix("0x").unwrap_or(&hex);
+    match from_hex(hex) {
+        Some(bytes) if !bytes.is_empty() => Ok(bytes),
+        _ => Err("Enter a signed transaction as hex.".to_string()),
+    }
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+        })
+        .collect()
+}
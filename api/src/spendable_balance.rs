@@ -0,0 +1,59 @@
+//! What a send can spend, and what of the balance it cannot, checked before
+//! the Send wizard's Review step.
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use num_traits::CheckedSub;
+use num_traits::Zero;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpendableBalance {
+    /// Confirmed, not time-locked, and not spent by a pending transaction.
+    pub available: NativeCurrencyAmount,
+
+    /// Confirmed, but time-locked.
+    pub time_locked: NativeCurrencyAmount,
+
+    /// Received in transactions that are not yet confirmed.
+    pub unconfirmed: NativeCurrencyAmount,
+
+    /// Spent by this wallet's own transactions that are not yet confirmed.
+    pub pending_spent: NativeCurrencyAmount,
+}
+
+impl SpendableBalance {
+    /// Splits the node's balances.  The unconfirmed balances are the
+    /// confirmed ones with the mempool applied.
+    pub fn new(
+        confirmed_available: NativeCurrencyAmount,
+        confirmed_total: NativeCurrencyAmount,
+        unconfirmed_available: NativeCurrencyAmount,
+    ) -> Self {
+        let available = if unconfirmed_available < confirmed_available {
+            unconfirmed_available
+        } else {
+            confirmed_available
+        };
+        Self {
+            available,
+            time_locked: excess(confirmed_total, confirmed_available),
+            unconfirmed: excess(unconfirmed_available, confirmed_available),
+            pending_spent: excess(confirmed_available, unconfirmed_available),
+        }
+    }
+
+    /// How much `total` exceeds what can be spent, if it does.
+    pub fn shortfall(&self, total: NativeCurrencyAmount) -> Option<NativeCurrencyAmount> {
+        (total > self.available).then(|| excess(total, self.available))
+    }
+}
+
+/// How much `a` exceeds `b`, or 0.
+fn excess(a: NativeCurrencyAmount, b: NativeCurrencyAmount) -> NativeCurrencyAmount {
+    if a > b {
+        a.checked_sub(&b).unwrap_or_default()
+    } else {
+        NativeCurrencyAmount::zero()
+    }
+}
@@ -0,0 +1,52 @@
+//! Checks run when the app starts, for unfinished business the user may have
+//! forgotten about.
+
+use strum::IntoEnumIterator;
+
+use crate::attention::AttentionItem;
+use crate::neptune_rpc;
+use crate::prefs::startup_check::StartupCheck;
+use crate::prefs_store;
+
+/// Runs the checks the user has not disabled.
+///
+/// A check that fails, eg because neptune-core is unreachable, is skipped so
+/// that the others still report.
+pub async fn run_enabled() -> anyhow::Result<Vec<AttentionItem>> {
+    let prefs = prefs_store::load().await?;
+    let disabled = prefs.disabled_startup_checks();
+
+    let mut items = vec![];
+    for check in StartupCheck::iter().filter(|c| !disabled.contains(c)) {
+        match run(check).await {
+            Ok(found) => items.extend(found),
+            Err(e) => dioxus_logger::tracing::debug!(?check, error = %e, "startup check failed"),
+        }
+    }
+    Ok(items)
+}
+
+async fn run(check: StartupCheck) -> anyhow::Result<Vec<AttentionItem>> {
+    match check {
+        StartupCheck::SendDrafts => {
+            let count = crate::send_drafts().await?.len();
+            Ok((count > 0)
+                .then_some(AttentionItem::SendDrafts { count })
+                .into_iter()
+                .collect())
+        }
+        StartupCheck::StuckTransactions => Ok(crate::pending_transactions()
+            .await?
+            .into_iter()
+            .filter(|tx| !tx.synced)
+            .map(|tx| AttentionItem::StuckTransaction { txid: tx.id })
+            .collect()),
+        StartupCheck::UnclaimedUtxos => {
+            let count = neptune_rpc::num_unclaimed_utxos().await?;
+            Ok((count > 0)
+                .then_some(AttentionItem::UnclaimedUtxos { count })
+                .into_iter()
+                .collect())
+        }
+    }
+}
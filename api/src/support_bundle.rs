@@ -0,0 +1,240 @@
+//! Builds a snapshot of the app's state to attach to support requests.
+//!
+//! The bundle is a single JSON document.  It never contains key material.
+//! Receiving addresses, user names, devices and IP addresses are left out
+//! unless the user opts in.
+//!
+//! neptune-core does not report its version over RPC, so the node is
+//! described by its network, tip and sync status instead.
+
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+
+use crate::bandwidth;
+use crate::event_log;
+use crate::event_log::WalletEvent;
+use crate::neptune_rpc;
+use crate::prefs::user_prefs::UserPrefs;
+use crate::prefs_store;
+use crate::price_caching;
+use crate::price_providers::PriceProviderStatus;
+use crate::wallet_capabilities::WalletCapabilities;
+
+/// The env vars the app reads.  Only whether each is set is reported, as
+/// values may contain URLs or paths.
+const ENV_VARS: [&str; 14] = [
+    "AUTH_PROVIDER",
+    "BLOCK_EXPLORER_URL",
+    "DEV_MODE",
+    "EVENT_LOG_FILE",
+    "EVENT_LOG_SYSLOG",
+    "GEOIP_DATABASE",
+    "METERED_CONNECTION",
+    "NEPTUNE_CORE_RPC_PORT",
+    "NEPTUNE_DATA_DIR",
+    "PRICE_PROVIDERS",
+    "PRICE_PROVIDER_URL",
+    "REACHABILITY_CHECKER_URL",
+    "TIP_MONITOR_MAX_LAG",
+    "TIP_MONITOR_URLS",
+    "WATCH_ONLY",
+];
+
+/// Words longer than this in error messages are assumed to be addresses.
+const MAX_WORD_LEN: usize = 100;
+
+const REDACTED: &str = "[redacted]";
+
+#[derive(Serialize)]
+struct SupportBundle {
+    /// unix time in milliseconds
+    generated_at_ms: u128,
+    app_version: &'static str,
+    node: Result<NodeInfo, String>,
+    config: Config,
+    metrics: Metrics,
+    /// oldest first
+    recent_events: Vec<Event>,
+    /// the failures among `recent_events`
+    errors: Vec<Event>,
+    /// only if the user opted in
+    receiving_addresses: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct NodeInfo {
+    network: String,
+    tip_height: u64,
+    syncing: bool,
+    peer_count: Option<usize>,
+    max_num_peers: usize,
+    mempool_tx_count: usize,
+    mempool_size: usize,
+    proving_capability: String,
+}
+
+#[derive(Serialize)]
+struct Config {
+    env_vars_set: Vec<&'static str>,
+    prefs: Option<UserPrefs>,
+    capabilities: Option<WalletCapabilities>,
+}
+
+#[derive(Serialize)]
+struct Metrics {
+    rpc_round_trip_ms: Option<u128>,
+    metered: bool,
+    price_providers: Vec<PriceProviderStatus>,
+}
+
+#[derive(Serialize)]
+struct Event {
+    ts: u128,
+    #[serde(flatten)]
+    event: WalletEvent,
+}
+
+/// Builds the bundle, returning it as pretty-printed JSON.
+pub async fn build(include_addresses: bool) -> anyhow::Result<String> {
+    let recent_events: Vec<Event> = event_log::recent()
+        .into_iter()
+        .map(|(ts, event)| Event {
+            ts,
+            event: if include_addresses {
+                event
+            } else {
+                sanitize(event)
+            },
+        })
+        .collect();
+    let errors = recent_events
+        .iter()
+        .filter(|e| matches!(e.event, WalletEvent::Error { .. }))
+        .map(|e| Event {
+            ts: e.ts,
+            event: e.event.clone(),
+        })
+        .collect();
+
+    let receiving_addresses = if include_addresses {
+        Some(receiving_addresses().await?)
+    } else {
+        None
+    };
+
+    let bundle = SupportBundle {
+        generated_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis(),
+        app_version: env!("CARGO_PKG_VERSION"),
+        node: node_info().await.map_err(|e| e.to_string()),
+        config: Config {
+            env_vars_set: ENV_VARS
+                .into_iter()
+                .filter(|name| std::env::var_os(name).is_some())
+                .collect(),
+            prefs: prefs_store::load().await.ok(),
+            capabilities: neptune_rpc::cached_wallet_capabilities().await.ok(),
+        },
+        metrics: Metrics {
+            rpc_round_trip_ms: rpc_round_trip_ms().await,
+            metered: bandwidth::is_metered().await,
+            price_providers: price_caching::provider_status()
+                .into_iter()
+                .map(|status| PriceProviderStatus {
+                    // may contain the provider URL.
+                    last_error: None,
+                    ..status
+                })
+                .collect(),
+        },
+        recent_events,
+        errors,
+        receiving_addresses,
+    };
+    Ok(serde_json::to_string_pretty(&bundle)?)
+}
+
+async fn node_info() -> anyhow::Result<NodeInfo> {
+    let client = &neptune_rpc::rpc_client().await?;
+
+    let network = neptune_rpc::network().await?;
+    let data = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .dashboard_overview_data(neptune_rpc::call_context("dashboard_overview_data"), token)
+            .await??)
+    })
+    .await?;
+    Ok(NodeInfo {
+        network: network.to_string(),
+        tip_height: data.tip_header.height.into(),
+        syncing: data.syncing,
+        peer_count: data.peer_count,
+        max_num_peers: data.max_num_peers,
+        mempool_tx_count: data.mempool_total_tx_count,
+        mempool_size: data.mempool_size,
+        proving_capability: data.proving_capability.to_string(),
+    })
+}
+
+/// Times a cheap call to neptune-core.  `None` if it failed.
+async fn rpc_round_trip_ms() -> Option<u128> {
+    let client = &neptune_rpc::rpc_client().await.ok()?;
+
+    let start = Instant::now();
+    neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .block_height(neptune_rpc::call_context("block_height"), token)
+            .await??)
+    })
+    .await
+    .ok()?;
+    Some(start.elapsed().as_millis())
+}
+
+async fn receiving_addresses() -> anyhow::Result<Vec<String>> {
+    let client = &neptune_rpc::rpc_client().await?;
+    let network = neptune_rpc::network().await?;
+
+    neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .known_keys(neptune_rpc::call_context("known_keys"), token)
+            .await??)
+    })
+    .await?
+    .into_iter()
+    .map(|key| key.to_address().to_bech32m(network))
+    .collect()
+}
+
+/// Removes what could identify the user or their addresses.
+fn sanitize(event: WalletEvent) -> WalletEvent {
+    match event {
+        WalletEvent::PeerStandingCleared { .. } => WalletEvent::PeerStandingCleared { ip: None },
+        WalletEvent::SessionRevoked { .. } => WalletEvent::SessionRevoked {
+            user: REDACTED.to_string(),
+            device: REDACTED.to_string(),
+            ip: None,
+            revoked_by: REDACTED.to_string(),
+        },
+        WalletEvent::Error { context, message } => WalletEvent::Error {
+            context,
+            message: message
+                .split(' ')
+                .map(|word| {
+                    if word.len() > MAX_WORD_LEN {
+                        REDACTED
+                    } else {
+                        word
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        },
+        event => event,
+    }
+}
@@ -0,0 +1,95 @@
+//! Estimates sync progress from the node's tip, and the rate at which it
+//! climbs between calls.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::neptune_rpc;
+use crate::sync_progress::SyncProgress;
+use crate::sync_progress::TipEstimate;
+use crate::tip_monitor;
+
+/// neptune-core's target time between blocks.
+const TARGET_BLOCK_INTERVAL: Duration = Duration::from_secs(588);
+
+/// How far back heights are kept to work out the sync rate.  Long enough to
+/// smooth over the batches blocks arrive in.
+const RATE_WINDOW: Duration = Duration::from_secs(2 * 60);
+
+/// The least time between the oldest and newest height for a rate to be
+/// given.
+const MIN_RATE_SPAN: Duration = Duration::from_secs(10);
+
+/// (unix time in milliseconds, height), oldest first.
+static HEIGHTS: Mutex<VecDeque<(u64, u64)>> = Mutex::new(VecDeque::new());
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Records `height`, and returns the blocks per second over [RATE_WINDOW].
+fn record(now_ms: u64, height: u64) -> Option<f64> {
+    let mut heights = HEIGHTS.lock().unwrap_or_else(|e| e.into_inner());
+    // a reorg or a changed node makes the history meaningless.
+    if heights.back().is_some_and(|&(_, last)| height < last) {
+        heights.clear();
+    }
+    heights.push_back((now_ms, height));
+    let since = now_ms.saturating_sub(RATE_WINDOW.as_millis() as u64);
+    while heights.front().is_some_and(|&(at, _)| at < since) {
+        heights.pop_front();
+    }
+
+    let &(first_at, first_height) = heights.front()?;
+    let span_ms = now_ms.saturating_sub(first_at);
+    if span_ms < MIN_RATE_SPAN.as_millis() as u64 {
+        return None;
+    }
+    Some((height - first_height) as f64 * 1000.0 / span_ms as f64)
+}
+
+pub async fn progress() -> anyhow::Result<SyncProgress> {
+    let client = &neptune_rpc::rpc_client().await?;
+    let data = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .dashboard_overview_data(neptune_rpc::call_context("dashboard_overview_data"), token)
+            .await??)
+    })
+    .await?;
+
+    let now = now_ms();
+    let height = u64::from(data.tip_header.height);
+    let blocks_per_sec = record(now, height);
+
+    let reference_height = tip_monitor::last().and_then(|check| check.reference_height);
+    let (network_tip, estimate) = match reference_height {
+        Some(reference) => (reference, TipEstimate::PublicNodes),
+        // a synced node's tip is the network's, however long ago it was mined.
+        None if !data.syncing => (height, TipEstimate::BlockTime),
+        None => {
+            let tip_age_ms = now.saturating_sub(data.tip_header.timestamp.to_millis());
+            let missed = tip_age_ms / TARGET_BLOCK_INTERVAL.as_millis() as u64;
+            (height + missed, TipEstimate::BlockTime)
+        }
+    };
+    let network_tip = network_tip.max(height);
+
+    let eta_secs = blocks_per_sec
+        .filter(|rate| *rate > 0.0)
+        .map(|rate| (network_tip.saturating_sub(height) as f64 / rate).round() as u64);
+
+    Ok(SyncProgress {
+        syncing: data.syncing,
+        height,
+        network_tip,
+        estimate,
+        blocks_per_sec,
+        eta_secs,
+    })
+}
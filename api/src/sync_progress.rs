@@ -0,0 +1,51 @@
+//! How far the node is through syncing the chain, for the progress bar shown
+//! while it syncs.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Where the network tip estimate came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TipEstimate {
+    /// The median height reported by the public nodes in `TIP_MONITOR_URLS`.
+    PublicNodes,
+    /// Extrapolated from the age of the local tip and the target block
+    /// interval.  Rough, as blocks do not arrive like clockwork.
+    BlockTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyncProgress {
+    pub syncing: bool,
+
+    /// The height of the node's tip.
+    pub height: u64,
+
+    /// The estimated height of the network's tip.  Never below
+    /// [height](Self::height).
+    pub network_tip: u64,
+
+    pub estimate: TipEstimate,
+
+    /// Blocks synced per second lately.  None until there is enough history
+    /// to tell.
+    pub blocks_per_sec: Option<f64>,
+
+    /// Seconds until synced at the current rate.  None if the rate is not
+    /// known, or is 0.
+    pub eta_secs: Option<u64>,
+}
+
+impl SyncProgress {
+    pub fn blocks_left(&self) -> u64 {
+        self.network_tip.saturating_sub(self.height)
+    }
+
+    /// The part synced, between 0 and 1.
+    pub fn fraction(&self) -> f64 {
+        if self.network_tip == 0 {
+            return 1.0;
+        }
+        (self.height as f64 / self.network_tip as f64).clamp(0.0, 1.0)
+    }
+}
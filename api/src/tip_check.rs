@@ -0,0 +1,48 @@
+//! Results of comparing the local node's tip against public nodes.
+//!
+//! A node whose tip falls behind, or runs ahead of, the rest of the network
+//! may be stalled, or eclipsed by peers feeding it a private chain.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The tip reported by one public node or explorer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReferenceTip {
+    pub url: String,
+    /// None if the reference could not be queried.
+    pub height: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TipCheck {
+    pub local_height: u64,
+
+    /// The median of the heights reported by the references.  None if none
+    /// of them answered.
+    pub reference_height: Option<u64>,
+
+    pub references: Vec<ReferenceTip>,
+
+    /// Blocks the local tip may differ from the references by.
+    pub max_lag: u64,
+
+    /// unix time in milliseconds
+    pub checked_at_ms: u64,
+}
+
+impl TipCheck {
+    /// Blocks the local tip is ahead of the references, negative if behind.
+    pub fn divergence(&self) -> Option<i64> {
+        self.reference_height
+            .map(|reference| self.local_height as i64 - reference as i64)
+    }
+
+    /// True if the local tip differs from the references by more than
+    /// [max_lag](Self::max_lag) blocks.
+    pub fn is_diverged(&self) -> bool {
+        self.divergence()
+            .is_some_and(|blocks| blocks.unsigned_abs() > self.max_lag)
+    }
+}
@@ -0,0 +1,156 @@
+//! Watches for the local node's tip diverging from public nodes.
+//!
+//! The public nodes or explorers to compare against are listed in the
+//! `TIP_MONITOR_URLS` env var.  Each must respond to a GET with the tip
+//! height, either as a bare number or as JSON: {"height": <number>}.  The
+//! monitor does not run unless at least one is configured.
+
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use dioxus_logger::tracing::Instrument;
+
+use crate::bandwidth;
+use crate::event_log;
+use crate::event_log::WalletEvent;
+use crate::neptune_rpc;
+use crate::tip_check::ReferenceTip;
+use crate::tip_check::TipCheck;
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Blocks the local tip may differ by unless `TIP_MONITOR_MAX_LAG` is set.
+/// Allows for a few blocks of propagation delay.
+const DEFAULT_MAX_LAG: u64 = 6;
+
+static LAST: Mutex<Option<TipCheck>> = Mutex::new(None);
+
+/// Starts the background task that compares tips, if any references are
+/// configured and it is not already running.
+pub fn ensure_watching() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    if reference_urls().is_empty() {
+        return;
+    }
+    STARTED.get_or_init(|| {
+        tokio::spawn(
+            async {
+                loop {
+                    match check().await {
+                        Ok(check) => remember(check).await,
+                        Err(e) => dioxus_logger::tracing::debug!(error = %e, "check failed"),
+                    }
+                    tokio::time::sleep(bandwidth::scale(CHECK_INTERVAL).await).await;
+                }
+            }
+            .instrument(dioxus_logger::tracing::info_span!("tip_monitor")),
+        );
+    });
+}
+
+/// The most recent check, if any has completed.
+pub fn last() -> Option<TipCheck> {
+    LAST.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Stores `check`, recording an event when the tip starts to diverge.
+async fn remember(check: TipCheck) {
+    let was_diverged = last().is_some_and(|c| c.is_diverged());
+    let is_diverged = check.is_diverged();
+    *LAST.lock().unwrap_or_else(|e| e.into_inner()) = Some(check.clone());
+
+    if is_diverged && !was_diverged {
+        event_log::record(WalletEvent::TipDiverged {
+            local_height: check.local_height,
+            reference_height: check.reference_height.unwrap_or_default(),
+        })
+        .await;
+    }
+}
+
+fn reference_urls() -> Vec<String> {
+    std::env::var("TIP_MONITOR_URLS")
+        .unwrap_or_default()
+        .split(',')
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+        .collect()
+}
+
+fn max_lag() -> u64 {
+    std::env::var("TIP_MONITOR_MAX_LAG")
+        .ok()
+        .and_then(|val| val.parse().ok())
+        .unwrap_or(DEFAULT_MAX_LAG)
+}
+
+async fn check() -> anyhow::Result<TipCheck> {
+    let client = &neptune_rpc::rpc_client().await?;
+    let local_height = neptune_rpc::with_token(|token| async move {
+        Ok(client
+            .block_height(neptune_rpc::call_context("block_height"), token)
+            .await??)
+    })
+    .await?
+    .into();
+
+    let mut references = vec![];
+    for url in reference_urls() {
+        let reference = match query_height(&url).await {
+            Ok(height) => ReferenceTip {
+                url,
+                height: Some(height),
+                error: None,
+            },
+            Err(e) => ReferenceTip {
+                url,
+                height: None,
+                error: Some(format!("{:#}", e)),
+            },
+        };
+        references.push(reference);
+    }
+
+    // the median, so that a single lagging or lying reference has no effect.
+    let mut heights: Vec<u64> = references.iter().filter_map(|r| r.height).collect();
+    heights.sort_unstable();
+    let reference_height = heights.get(heights.len() / 2).copied();
+
+    Ok(TipCheck {
+        local_height,
+        reference_height,
+        references,
+        max_lag: max_lag(),
+        checked_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64,
+    })
+}
+
+async fn query_height(url: &str) -> anyhow::Result<u64> {
+    #[derive(serde::Deserialize)]
+    struct HeightResponse {
+        height: u64,
+    }
+
+    let body = reqwest::Client::new()
+        .get(url)
+        .timeout(REQUEST_TIMEOUT)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    let body = body.trim();
+    match body.parse::<u64>() {
+        Ok(height) => Ok(height),
+        Err(_) => Ok(serde_json::from_str::<HeightResponse>(body)?.height),
+    }
+}
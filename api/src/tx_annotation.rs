@@ -0,0 +1,29 @@
+//! Local, user-supplied metadata about wallet history entries.
+//!
+//! neptune-core knows nothing about these.  They are stored by neptune-proton
+//! and keyed by the hex digest of the block the entry was confirmed in, as
+//! the history lists one entry per block.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Metadata for a single history entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxAnnotation {
+    /// A budget category, eg "groceries" or "rent".
+    pub tag: Option<String>,
+
+    /// Who the funds were sent to or received from.
+    pub contact: Option<String>,
+}
+
+impl TxAnnotation {
+    pub fn is_empty(&self) -> bool {
+        self.tag.is_none() && self.contact.is_none()
+    }
+}
+
+/// All annotations, keyed by block digest in hex.
+pub type TxAnnotations = HashMap<String, TxAnnotation>;
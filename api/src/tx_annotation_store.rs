@@ -0,0 +1,24 @@
+//! Persists [TxAnnotations] as a JSON file alongside the neptune-core wallet.
+
+use crate::json_store::JsonStore;
+use crate::tx_annotation::TxAnnotations;
+
+static STORE: JsonStore<TxAnnotations> =
+    JsonStore::in_wallet_dir("neptune-proton-tx-annotations.json");
+
+/// Returns all stored annotations.
+pub async fn load() -> anyhow::Result<TxAnnotations> {
+    STORE.load().await
+}
+
+/// Applies `f` to the stored annotations and saves the result.
+///
+/// Entries left empty by `f` are dropped.
+pub async fn update(f: impl FnOnce(&mut TxAnnotations)) -> anyhow::Result<TxAnnotations> {
+    STORE
+        .update(|annotations| {
+            f(annotations);
+            annotations.retain(|_, a| !a.is_empty());
+        })
+        .await
+}
@@ -0,0 +1,42 @@
+//! Private notes on transactions, eg "rent for May".
+//!
+//! Notes are stored by neptune-proton only, and never broadcast.  They are
+//! keyed by transaction kernel id, which neptune-core does not report for
+//! confirmed history entries.  A note on a transaction sent by this app
+//! records the UTXOs it spends, and is linked to the block they are spent
+//! in.  Other notes are not linked to a block.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxNote {
+    pub text: String,
+
+    /// When the note was first written, in unix milliseconds.  Set by the
+    /// server.
+    pub created_ms: u64,
+
+    /// The hex digest of the block the transaction was confirmed in, once
+    /// known.
+    pub block: Option<String>,
+
+    /// The AOCL leaf indices of the UTXOs the transaction spends, if this app
+    /// sent it.  Set by the server.
+    #[serde(default)]
+    pub inputs: Vec<u64>,
+}
+
+/// All notes, keyed by transaction kernel id.
+pub type TxNotes = HashMap<String, TxNote>;
+
+/// The kernel id and note of the transaction confirmed in `block_hex`, if
+/// any.
+pub fn note_for_block<'a>(notes: &'a TxNotes, block_hex: &str) -> Option<(&'a str, &'a TxNote)> {
+    notes
+        .iter()
+        .find(|(_, note)| note.block.as_deref() == Some(block_hex))
+        .map(|(txid, note)| (txid.as_str(), note))
+}
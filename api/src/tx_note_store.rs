@@ -0,0 +1,24 @@
+//! Persists [TxNotes] as a JSON file alongside the neptune-core wallet.
+
+use crate::json_store::JsonStore;
+use crate::tx_note::TxNotes;
+
+static STORE: JsonStore<TxNotes> = JsonStore::in_wallet_dir("neptune-proton-tx-notes.json");
+
+/// Returns all stored notes.
+pub async fn load() -> anyhow::Result<TxNotes> {
+    STORE.load().await
+}
+
+/// Applies `f` to the stored notes and saves the result, if `f` returns
+/// true.
+///
+/// Notes left empty by `f` are dropped.
+pub async fn update(f: impl FnOnce(&mut TxNotes) -> bool) -> anyhow::Result<TxNotes> {
+    let mut notes = STORE.lock().await?;
+    if f(&mut notes) {
+        notes.retain(|_, note| !note.text.is_empty());
+        notes.save().await?;
+    }
+    Ok(notes.into_inner())
+}
@@ -0,0 +1,34 @@
+//! Local, user-supplied metadata about UTXOs.
+//!
+//! neptune-core knows nothing about these. They are stored by neptune-proton
+//! and keyed by the UTXO's AOCL leaf index, which is stable once a UTXO is
+//! confirmed.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Metadata for a single UTXO.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UtxoAnnotation {
+    /// Free-form tags, eg "savings" or "from exchange".
+    pub tags: BTreeSet<String>,
+
+    /// A note about this UTXO in particular, eg who paid it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// The user asked that this UTXO not be spent.
+    pub do_not_spend: bool,
+}
+
+impl UtxoAnnotation {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.label.is_none() && !self.do_not_spend
+    }
+}
+
+/// All annotations, keyed by AOCL leaf index.
+pub type UtxoAnnotations = HashMap<u64, UtxoAnnotation>;
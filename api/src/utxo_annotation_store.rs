@@ -0,0 +1,24 @@
+//! Persists [UtxoAnnotations] as a JSON file alongside the neptune-core wallet.
+
+use crate::json_store::JsonStore;
+use crate::utxo_annotation::UtxoAnnotations;
+
+static STORE: JsonStore<UtxoAnnotations> =
+    JsonStore::in_wallet_dir("neptune-proton-utxo-annotations.json");
+
+/// Returns all stored annotations.
+pub async fn load() -> anyhow::Result<UtxoAnnotations> {
+    STORE.load().await
+}
+
+/// Applies `f` to the stored annotations and saves the result.
+///
+/// Entries left empty by `f` are dropped.
+pub async fn update(f: impl FnOnce(&mut UtxoAnnotations)) -> anyhow::Result<UtxoAnnotations> {
+    STORE
+        .update(|annotations| {
+            f(annotations);
+            annotations.retain(|_, a| !a.is_empty());
+        })
+        .await
+}
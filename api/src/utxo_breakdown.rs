@@ -0,0 +1,71 @@
+//! Splits the confirmed, unspent UTXOs by whether a send can spend them now,
+//! to explain why the total balance cannot all be sent.
+
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::timestamp::Timestamp;
+use neptune_types::ui_utxo::UiUtxo;
+use neptune_types::ui_utxo::UtxoStatusEvent;
+use num_traits::Zero;
+
+use crate::utxo_annotation::UtxoAnnotations;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UtxoBreakdown {
+    /// Released, and not marked do-not-spend.
+    pub available: NativeCurrencyAmount,
+
+    /// Released, but marked do-not-spend.
+    pub do_not_spend: NativeCurrencyAmount,
+
+    /// Not released yet.
+    pub time_locked: NativeCurrencyAmount,
+
+    /// When the next time-locked UTXOs are released, and their total.
+    pub next_release: Option<(Timestamp, NativeCurrencyAmount)>,
+}
+
+/// True if `utxo` is confirmed, unspent and released as of `now_ms`, in unix
+/// milliseconds, whether or not it is marked do-not-spend.
+pub fn is_spendable_now(utxo: &UiUtxo, now_ms: u64) -> bool {
+    matches!(utxo.spent, UtxoStatusEvent::None)
+        && matches!(utxo.received, UtxoStatusEvent::Confirmed { .. })
+        && !utxo.release_date.is_some_and(|ts| ts.to_millis() > now_ms)
+}
+
+/// Splits the confirmed, unspent `utxos` as of `now_ms`, in unix
+/// milliseconds.
+pub fn breakdown(utxos: &[UiUtxo], annotations: &UtxoAnnotations, now_ms: u64) -> UtxoBreakdown {
+    let zero = NativeCurrencyAmount::zero();
+    let mut breakdown = UtxoBreakdown {
+        available: zero,
+        do_not_spend: zero,
+        time_locked: zero,
+        next_release: None,
+    };
+
+    for utxo in utxos.iter().filter(|u| {
+        matches!(u.spent, UtxoStatusEvent::None)
+            && matches!(u.received, UtxoStatusEvent::Confirmed { .. })
+    }) {
+        if let Some(release) = utxo.release_date.filter(|ts| ts.to_millis() > now_ms) {
+            breakdown.time_locked = breakdown.time_locked + utxo.amount;
+            breakdown.next_release = match breakdown.next_release {
+                Some((next, amount)) if next.to_millis() == release.to_millis() => {
+                    Some((next, amount + utxo.amount))
+                }
+                Some((next, amount)) if next.to_millis() < release.to_millis() => {
+                    Some((next, amount))
+                }
+                _ => Some((release, utxo.amount)),
+            };
+        } else if utxo
+            .aocl_leaf_index
+            .is_some_and(|i| annotations.get(&i).is_some_and(|a| a.do_not_spend))
+        {
+            breakdown.do_not_spend = breakdown.do_not_spend + utxo.amount;
+        } else {
+            breakdown.available = breakdown.available + utxo.amount;
+        }
+    }
+    breakdown
+}
@@ -0,0 +1,158 @@
+//! A record of the wallet's UTXOs at one point in time, for auditors and
+//! accountants.
+//!
+//! A snapshot names the tip it was taken at, by height and digest, so that
+//! it can be checked against the chain.  neptune-core cannot sign messages,
+//! so the export is instead accompanied by the SHA-256 checksum of the file,
+//! to be handed over separately, eg in the email sending it.
+
+use neptune_types::ui_utxo::UtxoStatusEvent;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The file formats a snapshot can be exported in.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::EnumIter,
+    strum::Display,
+)]
+pub enum SnapshotFormat {
+    #[default]
+    #[strum(to_string = "JSON")]
+    Json,
+    #[strum(to_string = "CSV")]
+    Csv,
+}
+
+impl SnapshotFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// Where a UTXO stands, as of the snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SnapshotStatus {
+    /// Confirmed and not spent: part of the holdings.
+    Unspent,
+    /// Confirmed, and spent by a transaction not yet confirmed.
+    Spending,
+    /// Confirmed, and spent in a confirmed block.
+    Spent,
+    /// In the mempool, or expected, but not confirmed.
+    Unconfirmed,
+    /// Never confirmed.
+    Abandoned,
+}
+
+impl SnapshotStatus {
+    /// The status of a UTXO `received` and `spent` as given.  A spend that
+    /// was abandoned leaves the UTXO unspent.
+    pub fn of(received: &UtxoStatusEvent, spent: &UtxoStatusEvent) -> Self {
+        match (received, spent) {
+            (UtxoStatusEvent::Confirmed { .. }, UtxoStatusEvent::Confirmed { .. }) => Self::Spent,
+            (
+                UtxoStatusEvent::Confirmed { .. },
+                UtxoStatusEvent::None | UtxoStatusEvent::Abandoned,
+            ) => Self::Unspent,
+            (UtxoStatusEvent::Confirmed { .. }, _) => Self::Spending,
+            (UtxoStatusEvent::Abandoned, _) => Self::Abandoned,
+            _ => Self::Unconfirmed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotUtxo {
+    pub aocl_leaf_index: Option<u64>,
+    /// The hex digest neptune-core holds for the UTXO's AOCL leaf.
+    pub utxo_digest: Option<String>,
+    /// In NPT.
+    pub amount: String,
+    pub status: SnapshotStatus,
+    /// The height of the block that confirmed the UTXO.
+    pub block_height: Option<u64>,
+    /// When that block was made, in RFC 3339.
+    pub received_at: Option<String>,
+    /// When a time-locked UTXO can be spent, in RFC 3339.
+    pub release_date: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UtxoSnapshot {
+    pub network: String,
+    pub generator: String,
+    /// When the snapshot was taken, in RFC 3339.
+    pub taken_at: String,
+    pub tip_height: u64,
+    /// The hex digest of the tip block.
+    pub tip_digest: String,
+    /// The total of the unspent UTXOs, in NPT.
+    pub total_unspent: String,
+    pub utxos: Vec<SnapshotUtxo>,
+}
+
+impl UtxoSnapshot {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// One row per UTXO, after `#` comment lines naming the tip and total.
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!(
+            "# network: {}\n# generator: {}\n# taken_at: {}\n# tip_height: {}\n# tip_digest: {}\n# total_unspent: {}\n",
+            self.network,
+            self.generator,
+            self.taken_at,
+            self.tip_height,
+            self.tip_digest,
+            self.total_unspent,
+        );
+        csv.push_str(
+            "aocl_leaf_index,utxo_digest,amount,status,block_height,received_at,release_date\n",
+        );
+        for utxo in &self.utxos {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                utxo.aocl_leaf_index
+                    .map(|i| i.to_string())
+                    .unwrap_or_default(),
+                utxo.utxo_digest.clone().unwrap_or_default(),
+                utxo.amount,
+                utxo.status,
+                utxo.block_height.map(|h| h.to_string()).unwrap_or_default(),
+                utxo.received_at.clone().unwrap_or_default(),
+                utxo.release_date.clone().unwrap_or_default(),
+            ));
+        }
+        csv
+    }
+
+    pub fn to_format(&self, format: SnapshotFormat) -> String {
+        match format {
+            SnapshotFormat::Json => self.to_json(),
+            SnapshotFormat::Csv => self.to_csv(),
+        }
+    }
+}
+
+/// A snapshot as written to a file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotExport {
+    pub file_name: String,
+    pub contents: String,
+    /// The hex SHA-256 checksum of `contents`, as `sha256sum` prints it.
+    pub sha256: String,
+}
@@ -0,0 +1,63 @@
+//! What the connected wallet is able to do.
+//!
+//! A watch-only wallet holds public keys / addresses but no spending secret,
+//! eg to monitor a cold wallet. It can observe balances and history but can
+//! neither send nor reveal a seed phrase.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletCapabilities {
+    /// The wallet can sign and send transactions.
+    pub can_spend: bool,
+
+    /// The wallet secret is available for backup, eg as a seed phrase.
+    pub can_export_secret: bool,
+
+    /// Tools for protocol developers are shown, eg to broadcast arbitrary
+    /// announcements.  Set by the `DEV_MODE` env var.
+    #[serde(default)]
+    pub dev_tools: bool,
+
+    /// The app lock PIN may be entered and the security settings changed.
+    /// Always true, unless the caller's role forbids it.
+    #[serde(default)]
+    pub can_use_app_lock: bool,
+}
+
+impl Default for WalletCapabilities {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
+impl WalletCapabilities {
+    /// A wallet holding its spending secret.
+    pub fn full() -> Self {
+        Self {
+            can_spend: true,
+            can_export_secret: true,
+            dev_tools: false,
+            can_use_app_lock: true,
+        }
+    }
+
+    /// A wallet holding only public keys / addresses.
+    pub fn watch_only() -> Self {
+        Self {
+            can_spend: false,
+            can_export_secret: false,
+            dev_tools: false,
+            can_use_app_lock: true,
+        }
+    }
+
+    pub fn is_watch_only(&self) -> bool {
+        !self.can_spend
+    }
+
+    pub fn with_dev_tools(self, dev_tools: bool) -> Self {
+        Self { dev_tools, ..self }
+    }
+}
@@ -0,0 +1,33 @@
+//! Whether neptune-core's wallet file is encrypted.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletEncryptionStatus {
+    /// Where neptune-core keeps the wallet secret.
+    pub wallet_file: String,
+
+    pub state: WalletFileState,
+
+    /// True if neptune-core can encrypt its wallet file, so that a
+    /// passphrase can be set or changed.
+    pub passphrase_supported: bool,
+}
+
+/// What was found at the wallet file's path.
+///
+/// neptune-core v0.5 cannot encrypt its wallet file, so a file it cannot
+/// read is reported as unreadable rather than taken to be encrypted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WalletFileState {
+    /// No wallet yet.  neptune-core creates one when it first starts.
+    Missing,
+
+    /// The secret is kept in plain text.
+    PlainText,
+
+    /// The file could not be read, eg for lack of permissions, or is
+    /// corrupt.  Holds the reason.
+    Unreadable(String),
+}
@@ -0,0 +1,24 @@
+//! Restores a wallet from its seed phrase, so that it can be moved onto a new
+//! machine from within the app.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// What importing a seed phrase did.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WalletImport {
+    /// The wallet file was written.  neptune-core uses it once started, and
+    /// then finds the wallet's funds as it syncs.
+    Written {
+        wallet_file: String,
+        /// True if the managed neptune-core was restarted to use it.
+        node_restarted: bool,
+    },
+
+    /// The phrase is that of the wallet in use already.
+    AlreadyInUse,
+
+    /// Another wallet is in `wallet_dir`.  It is never overwritten: the user
+    /// must stop neptune-core and move it away first.
+    OtherWalletExists { wallet_dir: String },
+}
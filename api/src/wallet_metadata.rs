@@ -0,0 +1,132 @@
+//! A versioned JSON format for sharing user metadata with other Neptune
+//! tools, eg a CLI or another wallet.
+//!
+//! The metadata is what the user added to the wallet, not anything
+//! neptune-core knows: address labels, history tags and contacts, and UTXO
+//! tags and labels.  It holds no key material.
+//!
+//! ```json
+//! {
+//!   "format": "neptune-wallet-metadata",
+//!   "version": 1,
+//!   "network": "main",
+//!   "generator": "neptune-proton 0.2.1",
+//!   "exported_at": "2026-01-31T12:00:00Z",
+//!   "addresses": [
+//!     { "address": "nolgam1...", "label": "donations" }
+//!   ],
+//!   "transactions": [
+//!     { "block_digest": "<hex>", "tag": "rent", "contact": "landlord" }
+//!   ],
+//!   "utxos": [
+//!     { "aocl_leaf_index": 42, "tags": ["savings"], "label": "from Alice", "do_not_spend": true }
+//!   ]
+//! }
+//! ```
+//!
+//! - `format`, `version` and `network` are required.  All else is optional.
+//! - `address` is bech32m.  `block_digest` is the hex digest of the block a
+//!   transaction was confirmed in.  `aocl_leaf_index` identifies a UTXO.
+//! - Readers ignore fields they do not know.  Fields may be added without a
+//!   version change.  `version` is raised when existing fields change
+//!   meaning, and readers reject versions newer than they know.
+//! - On import, entries replace the stored entry for the same key.  Stored
+//!   entries whose key is not in the file are kept.
+
+use std::collections::BTreeSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const FORMAT: &str = "neptune-wallet-metadata";
+pub const VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletMetadata {
+    pub format: String,
+    pub version: u32,
+    /// The network the metadata is for, eg `main`.
+    pub network: String,
+    /// The tool that wrote the file, with its version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator: Option<String>,
+    /// RFC 3339
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exported_at: Option<String>,
+    #[serde(default)]
+    pub addresses: Vec<AddressEntry>,
+    #[serde(default)]
+    pub transactions: Vec<TransactionEntry>,
+    #[serde(default)]
+    pub utxos: Vec<UtxoEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressEntry {
+    pub address: String,
+    pub label: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionEntry {
+    pub block_digest: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UtxoEntry {
+    pub aocl_leaf_index: u64,
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub do_not_spend: bool,
+}
+
+impl WalletMetadata {
+    pub fn new(network: String) -> Self {
+        Self {
+            format: FORMAT.to_string(),
+            version: VERSION,
+            network,
+            generator: None,
+            exported_at: None,
+            addresses: vec![],
+            transactions: vec![],
+            utxos: vec![],
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let metadata: Self = serde_json::from_str(json)
+            .map_err(|e| format!("Not a wallet metadata file: {e}"))?;
+        if metadata.format != FORMAT {
+            return Err(format!("Unknown format '{}'.", metadata.format));
+        }
+        if metadata.version > VERSION {
+            return Err(format!(
+                "The file is version {}. This version of neptune-proton reads up to version {}.",
+                metadata.version, VERSION
+            ));
+        }
+        Ok(metadata)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+/// What an import changed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataImportSummary {
+    pub addresses: usize,
+    pub transactions: usize,
+    pub utxos: usize,
+    /// Entries left out as invalid, eg an address for another network.
+    pub skipped: usize,
+}
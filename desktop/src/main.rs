@@ -7,7 +7,7 @@ use dioxus::prelude::*;
 use image::ImageReader;
 
 fn main() {
-    dioxus_logger::init(dioxus_logger::tracing::Level::INFO).expect("failed to init logger");
+    api::logging::init();
 
     launch_without_menubar();
     //    dioxus::launch(App);
@@ -0,0 +1,651 @@
+## Shared
+
+common-amount-hidden = Beträge sind ausgeblendet. Blenden Sie sie mit dem Augen-Symbol in der Kopfzeile ein.
+common-back = Zurück
+common-cancel = Abbrechen
+common-clear-selection = Auswahl aufheben
+common-close = Schließen
+common-copied = In die Zwischenablage kopiert.
+common-copy-failed = Kopieren in die Zwischenablage fehlgeschlagen.
+common-copy-label = In die Zwischenablage kopieren
+common-delete = Löschen
+common-dont-ask-again = Nicht erneut fragen
+common-error = Fehler
+common-export-failed = Export fehlgeschlagen: { $error }
+common-exported = Exportiert.
+common-retry = Erneut versuchen
+common-save = Speichern
+common-selected = { $count } ausgewählt
+
+## Balance
+
+balance-title = Wallet-Übersicht
+balance-load-failed = Übersichtsdaten konnten nicht geladen werden: { $error }
+balance-confirmed = Bestätigter Kontostand
+balance-unconfirmed = Unbestätigter Kontostand
+balance-available = Verfügbar
+balance-time-locked = Zeitgesperrt
+balance-total = Gesamt
+balance-blockchain = Blockchain
+balance-network = Netzwerk
+balance-status = Status
+balance-syncing = Synchronisiere...
+balance-synced = Synchronisiert
+balance-tip = Spitze
+balance-mempool = Mempool
+balance-transactions = Transaktionen
+balance-size-bytes = Größe (Bytes)
+balance-network-peers = Netzwerk-Peers
+balance-connected-peers = Verbundene Peers
+balance-max-peers = Max. Peers
+balance-node-info = Knoteninfo
+balance-mining-status = Mining-Status
+balance-proving-capability = Beweisfähigkeit
+balance-attention = Handlungsbedarf
+balance-attention-dismiss = Ausblenden
+balance-attention-drafts = { $count ->
+        [one] 1 nicht gesendeter Sendeentwurf
+       *[other] { $count } nicht gesendete Sendeentwürfe
+    }
+balance-attention-drafts-action = Entwürfe ansehen
+balance-attention-stuck-tx = Transaktion { $txid } ist nicht mit der Spitze synchronisiert und kann so nicht gemint werden
+balance-attention-stuck-tx-action = Beheben
+balance-attention-unclaimed = { $count ->
+        [one] 1 erwarteter UTXO ist nicht angekommen
+       *[other] { $count } erwartete UTXOs sind nicht angekommen
+    }
+balance-attention-unclaimed-action = UTXOs anzeigen
+balance-assets = Vermögenswerte
+balance-asset-utxos = { $count ->
+        [one] 1 UTXO
+       *[other] { $count } UTXOs
+    }
+balance-asset-no-amount = Betrag unbekannt
+
+## Send
+
+send-add-recipients = Empfänger hinzufügen
+send-spendable-now = Jetzt verfügbar:
+send-spendable-time-locked = Zeitgesperrt:
+send-spendable-next-release = nächste Freigabe am { $date }:
+send-spendable-do-not-spend = Als nicht auszugeben markiert:
+send-recipient-address = Empfängeradresse
+send-address-placeholder = Klicken, um eine Adresse einzufügen oder zu scannen...
+send-address-empty = Geben Sie die Adresse des Empfängers ein oder scannen Sie sie.
+send-address-truncated = Diese Adresse ist unvollständig. Kopieren Sie die ganze Adresse, nicht eine gekürzte.
+send-address-malformed = Dies ist keine Neptune-Adresse.
+send-address-checksum = Diese Adresse enthält einen Tippfehler: Ihre Prüfsumme stimmt nicht.
+send-address-wrong-network = Diese Adresse ({ $prefix }...) gehört zu einem anderen Netzwerk. Diese Wallet ist im Netzwerk { $network }.
+send-address-unsupported = Diese Wallet kann Adressen dieses Typs ({ $prefix }...) nicht bezahlen.
+send-amount-npt = Betrag (NPT)
+send-amount-fiat = Betrag ({ $currency })
+send-amount-positive = Der Betrag muss > 0 sein.
+send-toggle-currency = Zwischen Neptune Cash (NPT) und { $name } ({ $code }) wechseln
+send-done = Fertig
+send-edit = Bearbeiten
+send-add-another-recipient = Weiteren Empfänger hinzufügen
+send-next-set-fee = Weiter: Gebühr
+send-set-address = Adresse festlegen
+send-choose-action = Wählen Sie eine Aktion.
+send-choose-action-for = Wählen Sie eine Aktion für Empfänger Nummer { $number }.
+send-paste-address = Adresse einfügen
+send-scan-qr = QR-Code scannen
+send-upload-qr = Aus Datei importieren
+send-recent-recipients = Letzte Empfänger
+send-recent-last-used = Zuletzt bezahlt am { $date }
+send-recent-forget = Diesen Empfänger vergessen
+send-invalid-payment-request = Ungültige Zahlungsanforderung im QR-Code: { $error }
+send-duplicate-title = Doppelte Adresse
+send-duplicate-message = Diese Adresse ist bereits in der Empfängerliste. Möchten Sie sie erneut hinzufügen?
+send-merge-duplicates-message = { $count ->
+        [one] 1 Empfänger zahlt an eine Adresse, die bereits in der Liste steht. Werden sie zu einer Ausgabe zusammengeführt, wird die Transaktion kleiner und die Gebühr niedriger.
+       *[other] { $count } Empfänger zahlen an Adressen, die bereits in der Liste stehen. Werden sie zu je einer Ausgabe zusammengeführt, wird die Transaktion kleiner und die Gebühr niedriger.
+    }
+send-merge-duplicates = Zusammenführen
+send-proceed-anyway = Trotzdem fortfahren
+
+send-drafts = Entwürfe
+send-saved-drafts = Gespeicherte Entwürfe
+send-no-drafts = Keine gespeicherten Entwürfe.
+send-drafts-load-failed = Entwürfe konnten nicht geladen werden: { $error }
+send-draft-summary = { $recipients ->
+        [one] 1 Empfänger
+       *[other] { $recipients } Empfänger
+    }, gespeichert { $saved }
+send-resume = Fortsetzen
+send-draft-foreign-currency = Dieser Entwurf enthält Beträge in { $currency }. Wählen Sie { $currency } als Währung, um ihn fortzusetzen.
+send-save-draft = Entwurf speichern
+send-draft-name = Name
+send-draft-name-placeholder = z. B. Miete, März
+send-draft-replaced = Ein Entwurf mit demselben Namen wird ersetzt.
+
+send-set-fee = Gebühr festlegen
+send-subtotal = Zwischensumme:{" "}
+send-fee-npt = Gebühr (NPT)
+send-fee-fiat = Gebühr ({ $currency })
+send-total-spend = Gesamtausgabe
+send-funds-short = Nicht genügend verfügbares Guthaben. Es fehlen
+send-funds-available = Jetzt verfügbar:
+send-funds-time-locked = Zeitgesperrt, noch nicht verfügbar:
+send-funds-unconfirmed = Eingehend, wartet auf Bestätigung:
+send-funds-pending = Von Ihren unbestätigten Transaktionen verwendet:
+send-next-review = Weiter: Überprüfen
+
+send-review-title = Transaktion überprüfen
+send-review-intro = Bitte überprüfen Sie die folgenden Angaben. Diese Aktion kann nicht rückgängig gemacht werden.
+send-address-check-title = Adressen prüfen
+send-address-check-intro = Dies ist eine große Zahlung. Geben Sie die letzten { $count } Zeichen jeder Empfängeradresse ein, abgelesen an ihrer Quelle, um sich vor einer in der Zwischenablage ausgetauschten Adresse zu schützen.
+send-address-check-label = Empfänger { $number }
+send-recipients = Empfänger:
+send-fee = Gebühr:{" "}
+send-total-spend-label = Gesamtausgabe:{" "}
+send-estimated-suffix = {" "}geschätzt)
+send-confirm = Bestätigen und senden
+send-large-title = Großer Betrag
+send-large-message = Sie sind dabei, mehr als die Hälfte Ihres Wallet-Guthabens zu senden. Möchten Sie fortfahren?
+send-send = Senden
+
+send-sending = Transaktion wird gesendet...
+send-please-wait = Bitte warten.
+send-stage-selecting-inputs = Eingänge auswählen
+send-stage-building = Transaktion erstellen
+send-stage-proving = Beweis erzeugen
+send-stage-broadcasting = Übertragen
+send-stage-proving-hint = Das Erzeugen des Beweises kann je nach Rechner, auf dem der Knoten läuft, einige Minuten dauern.
+send-stage-proving-capability = Der Knoten erzeugt Beweise als { $capability }.
+send-stage-retrying = Die Verbindung zu neptune-core wurde unterbrochen. Neuer Versuch, Versuch { $attempt }.
+send-queue-heading = Letzte Überweisungen
+send-queue-title = Letzte Überweisungen, { $count } mit neuem Ergebnis
+send-queue-title-in-flight = { $count ->
+    [one] Eine Transaktion wird gesendet
+   *[other] { $count } Transaktionen werden gesendet
+}
+send-queue-recipients = { $count ->
+    [one] 1 Empfänger,
+   *[other] { $count } Empfänger,
+}
+send-queue-fee = Gebühr
+send-queue-in-flight = Wird erstellt und bewiesen. Sie können diese Seite währenddessen verlassen.
+send-queue-interrupted = Die App wurde geschlossen, bevor diese Überweisung abgeschlossen war. Die Transaktion wurde möglicherweise dennoch gesendet.
+send-queue-check-history = Verlauf prüfen
+send-queue-clear = Abgeschlossene entfernen
+send-status-title = Transaktionsstatus
+send-success = Transaktion erfolgreich gesendet!
+send-transaction-id = Transaktions-ID
+send-view-in-mempool = Im Mempool anzeigen
+send-another = Weitere Transaktion senden
+send-error-title = Fehler beim Senden der Transaktion
+send-failure-connection = Knoten nicht erreichbar
+send-failure-funds = Nicht genügend Guthaben
+send-failure-invalid = Die Transaktion ist ungültig
+send-failure-broadcast-unknown = Die Transaktion wurde möglicherweise gesendet
+send-edit-recipients = Empfänger bearbeiten
+send-edit-fee = Gebühr bearbeiten
+send-retry = Erneut versuchen
+send-error-insufficient = Das verfügbare Guthaben deckt Betrag und Gebühr nicht. Verringern Sie eines davon oder warten Sie, bis eingehende Beträge bestätigt sind.
+send-error-connection = neptune-core war nicht erreichbar. Prüfen Sie den Mempool-Bildschirm, bevor Sie es erneut versuchen, falls die Transaktion doch gesendet wurde.
+send-error-timeout = neptune-core hat nicht rechtzeitig geantwortet. Die Transaktion könnte dennoch gesendet werden. Prüfen Sie daher den Mempool-Bildschirm, bevor Sie es erneut versuchen.
+send-error-invalid-address = Prüfen Sie die Empfängeradressen und ob sie zu diesem Netzwerk gehören.
+send-error-auth = Das Senden wurde abgelehnt. Ihr Konto darf möglicherweise nicht senden, oder die Zugangsdaten des Knotens haben sich geändert.
+send-error-broadcast-unknown = Die Verbindung zu neptune-core brach ab, während die Transaktion gesendet wurde. Prüfen Sie die Bildschirme Mempool und Verlauf, bevor Sie erneut senden, da ein erneutes Senden doppelt zahlen kann.
+send-error-cookie = Diese App kann das RPC-Cookie von neptune-core nicht lesen und daher nicht auf die Wallet zugreifen. Führen Sie sie unter demselben Benutzer wie neptune-core und mit Zugriff auf dessen Datenverzeichnis aus.
+send-advanced-options = Erweiterte Optionen
+send-change-title = Wechselgeld senden an
+send-change-next-symmetric = Einen neuen symmetrischen Schlüssel dieser Wallet (Standard)
+send-change-next-generation = Eine neue Generation-Adresse dieser Wallet
+send-change-existing = Bestehende Adresse { $address }
+send-change-exact = Nirgendwohin: fehlschlagen, wenn die Eingänge Betrag und Gebühr nicht genau decken
+send-change-intro = Wechselgeld ist, was von den ausgegebenen UTXOs nach Betrag und Gebühr übrig bleibt. Es wird stets on-chain angekündigt, damit es aus der Seed-Phrase wiederhergestellt werden kann.
+send-announcement-title = Öffentliche Ankündigung
+send-announcement-intro = Hängt der Transaktion eine Nachricht an, die jeder dauerhaft auf der Blockchain lesen kann. Lassen Sie das Feld leer, um ohne Ankündigung zu senden. Eine größere Ankündigung kann eine höhere Gebühr erfordern.
+send-announcement-encoding = Kodierung
+send-announcement-message = Nachricht
+send-announcement-elements = { $count } von höchstens { $max } Feldelementen
+send-announcement-review = Öffentliche Ankündigung
+send-announcement-review-size = { $encoding }, { $count } Feldelemente
+send-coin-control = Auszugebende UTXOs wählen
+send-coin-control-intro = Gibt genau die markierten UTXOs aus, z. B. um bestimmte Coins getrennt zu halten. Was sie über Betrag und Gebühr hinaus enthalten, kommt als Wechselgeld zurück. Ist keiner markiert, wählt die Wallet. Auf dem UTXO-Bildschirm als nicht ausgebbar markierte UTXOs können nicht gewählt werden.
+send-coin-control-none = Derzeit können keine UTXOs ausgegeben werden.
+send-coin-control-do-not-spend = Als nicht ausgebbar markiert
+send-coin-control-chosen = { $count ->
+        [one] 1 UTXO gewählt, insgesamt
+       *[other] { $count } UTXOs gewählt, insgesamt
+    }
+send-coin-control-short = Nicht genug für Betrag und Gebühr. Es fehlen
+
+## Broadcast
+
+broadcast-title = Transaktion senden
+broadcast-intro = Senden Sie eine anderswo signierte Transaktion, z. B. von einer anderen Wallet.  Fügen Sie deren Hex ein, oder öffnen Sie eine Datei, die es enthält.  Der Knoten prüft sie vor dem Senden.
+broadcast-placeholder = Signierte Transaktion, als Hex
+broadcast-size = { $bytes } Bytes
+broadcast-open-file = Datei öffnen
+broadcast-submit = Senden
+broadcast-broadcasting = Wird gesendet...
+broadcast-done = Transaktion gesendet: { $txid }
+broadcast-rejected = Die Transaktion wurde abgelehnt
+
+## Node Stats
+
+node-stats-title = Knotenstatistik
+node-stats-intro = Wird jede Minute erfasst, solange neptune-proton läuft.  Eine Woche Verlauf wird aufbewahrt.
+node-stats-window-hour = 1 Stunde
+node-stats-window-day = 24 Stunden
+node-stats-window-week = 7 Tage
+node-stats-block-interval = Blockintervall
+node-stats-mempool-size = Mempool-Größe
+node-stats-peer-count = Anzahl Peers
+node-stats-sync-progress = Synchronisierungsfortschritt
+node-stats-axis-time = Zeit
+node-stats-axis-minutes = Minuten
+node-stats-axis-kb = kB
+node-stats-axis-peers = Peers
+node-stats-axis-height = Blockhöhe
+node-stats-no-data = Noch nicht genügend Messwerte.
+
+## Logs
+
+logs-title = Protokoll
+logs-intro = Aktuelle Protokolleinträge des neptune-proton-Servers, neueste zuerst.  Eine Änderung der Stufe ändert auch, was der Server ab jetzt aufzeichnet.
+logs-level = Protokollstufe
+logs-level-error = Fehler
+logs-level-warn = Warnung
+logs-level-info = Info
+logs-level-debug = Debug
+logs-level-trace = Trace
+logs-pause = Anhalten
+logs-resume = Fortsetzen
+logs-clear = Leeren
+logs-none = Noch keine Protokolleinträge.
+logs-col-time = Zeit
+logs-col-level = Stufe
+logs-col-source = Quelle
+logs-col-message = Meldung
+
+## Node Logs
+
+node-logs-title = Ausgabe von neptune-core
+node-logs-restart = Neu starten
+node-logs-none = Noch keine Ausgabe.
+node-logs-state-disabled = neptune-core wird nicht von dieser App ausgeführt.
+node-logs-state-running = Läuft (PID { $pid }) seit { $since }.
+node-logs-state-starting = Gestartet (PID { $pid }), warte auf den RPC-Server…
+node-logs-state-restarting = Beendet.  Erneuter Start um { $at }.
+node-logs-state-stopping = Wird beendet…
+node-logs-restarts = { $count ->
+        [one] (einmal neu gestartet)
+       *[other] ({ $count }-mal neu gestartet)
+    }
+node-logs-last-exit = Zuletzt beendet: { $reason }
+
+## Receive
+
+receive-title = Geld empfangen
+receive-select-type = Adresstyp auswählen:
+receive-type-generation = Generation
+receive-type-symmetric = Symmetrischer Schlüssel
+receive-type-size = Größe
+receive-type-sharing = Weitergabe
+receive-type-scanning = Wer Zahlungen finden kann
+receive-generation-size = Lang, etwa 4.000 Zeichen. Am besten als QR-Code oder per Kopieren und Einfügen weitergeben.
+receive-generation-sharing = Kann an jeden weitergegeben werden, der Sie bezahlt. Damit kann man Sie nur bezahlen.
+receive-generation-scanning = Nur diese Wallet kann Zahlungen an sie finden und ausgeben.
+receive-symmetric-size = Kurz. Zahlungen an sie brauchen weniger Platz in der Blockchain.
+receive-symmetric-sharing = Geben Sie sie niemals weiter. Sie dient dazu, Guthaben zwischen Ihren eigenen Wallets zu verschieben.
+receive-symmetric-scanning = Jeder, der sie besitzt, kann Zahlungen an sie finden und ausgeben.
+receive-symmetric-acknowledge = Ich verstehe, dass symmetrische Schlüssel nur zum Senden zwischen Wallets verwendet werden dürfen, die ich kontrolliere. Wer sie erhält, kann mein Guthaben ausgeben.
+receive-generate = Neue Empfangsadresse erzeugen
+receive-generating = Wird erzeugt...
+receive-pending-retry = Neuer Versuch ausstehend...
+receive-connection-lost = Verbindung verloren. Neuer Versuch, sobald die Verbindung wiederhergestellt ist...
+receive-symmetric-do-not-share = Mit niemandem teilen.
+receive-symmetric-notice = Dies ist ein symmetrischer Schlüssel bzw. eine symmetrische Adresse.
+receive-symmetric-risk = Jeder, der ihn besitzt, kann das zugehörige Guthaben ausgeben.
+receive-share-address = Teilen Sie diese Adresse, um Geld zu empfangen.
+receive-scan-payment-request = Scannen Sie den QR-Code, um die Zahlungsanforderung zu erhalten.
+receive-scan-address = Scannen Sie den QR-Code, um die vollständige Adresse zu erhalten.
+receive-request-amount = Einen bestimmten Betrag anfordern
+receive-amount-npt = Betrag (NPT)
+receive-optional = Optional
+receive-label = Bezeichnung
+receive-label-placeholder = Optional, z. B. Rechnungsnummer
+receive-generate-another = Weitere erzeugen
+receive-share = Teilen
+receive-share-title = Meine Neptune-Adresse
+receive-share-copied = Teilen ist nicht verfügbar, daher wurde die Adresse stattdessen kopiert.
+
+receive-expected-title = Eingehend
+receive-expected-none = Keine eingehenden Zahlungen. Eine Zahlung erscheint hier, sobald sie den Mempool erreicht.
+receive-expected-transaction = Transaktion
+receive-expected-amount = Betrag
+receive-expected-status = Status
+receive-expected-in-mempool = Im Mempool
+receive-expected-updating = Im Mempool, Beweis wird aktualisiert
+receive-expected-unclaimed = { $count ->
+        [one] 1 weiteres erwartetes UTXO ist noch nicht in einem Block angekommen.
+       *[other] { $count } weitere erwartete UTXOs sind noch nicht in einem Block angekommen.
+    }
+
+## History
+
+history-title = Verlauf
+history-load-failed = Verlauf konnte nicht geladen werden: { $error }
+history-empty-title = Keine Transaktionen
+history-empty-description = Sie haben noch keine in einem Block bestätigten Transaktionen. Unbestätigte Transaktionen finden Sie im Mempool.
+history-fiat-at-time = Fiat-Wert zum Zeitpunkt der Transaktion
+history-copy-csv = Als CSV kopieren
+history-export-selected = Auswahl exportieren
+history-date = Datum
+history-type = Typ
+history-amount = Betrag
+history-value-at-time = Wert am Tag
+history-value-at-time-tooltip = Wert am Tag der Bestätigung der Transaktion
+history-block = Block
+history-received = Empfangen
+history-sent = Gesendet
+history-pending = Ausstehend
+history-pending-tooltip = Im Mempool, noch nicht in einem Block bestätigt (0 Bestätigungen)
+history-confirmations = Bestätigungen
+history-price-unavailable = Preis noch nicht verfügbar
+history-unconfirmed-note = Hinweis: Ausstehende Transaktionen stehen oben, bis sie in einem Block bestätigt sind.
+history-annotate = Kategorie / Kontakt
+history-annotate-title = { $count } Transaktionen kategorisieren
+history-tag = Kategorie
+history-tag-placeholder = z. B. Lebensmittel
+history-contact = Kontakt
+history-contact-placeholder = z. B. Alice
+history-annotate-hint = Nur bearbeitete Felder werden geändert.  Ein leeres Feld wird gelöscht.
+
+## Transaction Notes
+
+tx-note-title = Notiz zur Transaktion
+tx-note-label = Notiz
+tx-note-placeholder = z. B. Miete für Mai
+tx-note-private = Nur auf diesem Rechner gespeichert, nie übertragen.
+tx-note-add = Notiz hinzufügen
+tx-note-edit = Notiz bearbeiten
+
+## Reports
+
+reports-title = Ausgabenberichte
+reports-empty-title = Keine Ausgaben
+reports-empty-description = Von dieser Wallet gesendete Transaktionen werden hier nach Monat zusammengefasst.
+reports-group-by = Gruppieren nach
+reports-tag = Kategorie
+reports-contact = Kontakt
+reports-monthly-totals = Monatssummen
+reports-month-breakdown = Ausgaben im { $month }
+reports-category = Kategorie
+reports-transactions = Transaktionen
+reports-share = Anteil
+reports-total = Summe
+reports-untagged = Ohne Kategorie
+reports-no-contact = Ohne Kontakt
+reports-partial-prices = * Für einige Transaktionen liegt noch kein Tagespreis vor; sie fehlen im Fiat-Wert.
+reports-hint = Kategorien und Kontakte werden durch Auswahl von Transaktionen in der Historie gesetzt.
+reports-year-title = Jahresübersicht
+reports-year = Jahr
+reports-year-received = Empfangen
+reports-year-sent = Gesendet, inklusive Gebühren
+reports-year-fees = Gebühren
+reports-year-fees-hint = Gebühren sind nur für Sendungen bekannt, die mit dieser App erstellt wurden und noch in ihrer Liste stehen. Beträge werden je Block verrechnet, sodass Sendungen an sich selbst nur mit der Gebühr zählen.
+reports-year-export-csv = CSV exportieren
+reports-year-print = Drucken oder als PDF speichern
+
+## Onboarding
+
+onboarding-title = Willkommen bei Neptune Wallet
+onboarding-step = Schritt { $step } von { $steps }
+onboarding-skip = Einrichtung überspringen
+onboarding-next = Weiter
+onboarding-finish = Fertigstellen
+onboarding-details = Details
+onboarding-unreachable-intro = Diese Wallet arbeitet mit neptune-core, dem Neptune-Knoten, der nicht erreicht werden konnte. Diese Schritte helfen Ihnen, ihn zu starten und sich mit ihm zu verbinden.
+onboarding-new-wallet-intro = Ihre Wallet ist neu. Diese Schritte prüfen die Verbindung zu neptune-core und lassen Sie wählen, wie Beträge angezeigt werden.
+onboarding-network = Netzwerk
+onboarding-network-intro = Wählen Sie das Netzwerk, dem neptune-core beitreten soll.
+onboarding-network-main = das Hauptnetzwerk, für echtes Guthaben
+onboarding-network-testnet = ein Testnetzwerk, zum Ausprobieren mit wertlosen Coins
+onboarding-network-regtest = ein lokales Testnetzwerk, für die Entwicklung
+onboarding-node = neptune-core starten
+onboarding-node-install = Installieren Sie neptune-core, falls noch nicht geschehen, von der Release-Seite:
+onboarding-node-start = Starten Sie es mit:
+onboarding-node-port = Diese App verbindet sich mit dem RPC-Port von neptune-core, 9799, sofern die Umgebungsvariable NEPTUNE_CORE_RPC_PORT nichts anderes angibt.
+onboarding-node-data-dir = Falls neptune-core seine Daten nicht am Standardort ablegt, z. B. mit --data-dir, können Sie im nächsten Schritt diese App darauf verweisen.
+onboarding-connection = Verbindung testen
+onboarding-connection-test = Erneut testen
+onboarding-connection-testing = Verbindung zu neptune-core wird hergestellt…
+onboarding-connection-ok = Mit neptune-core auf { $network } verbunden.
+onboarding-connection-network-mismatch = neptune-core läuft auf { $network }, nicht auf { $chosen } wie gewählt. Starten Sie es mit --network { $chosen } neu, falls dies nicht beabsichtigt war.
+onboarding-connection-unreachable = Auf RPC-Port { $port } hat nichts geantwortet. Prüfen Sie, ob neptune-core läuft und fertig gestartet ist.
+onboarding-connection-cookie = neptune-core hat geantwortet, aber diese App kann sein RPC-Cookie nicht lesen. Führen Sie diese App als derselbe Benutzer wie neptune-core aus, oder wählen Sie unten sein Datenverzeichnis.
+onboarding-connection-checks = Alle Prüfungen
+onboarding-currency = Währung
+onboarding-currency-intro = Wählen Sie eine Währung, in der Beträge neben NPT angezeigt werden. Die Kurse stammen von öffentlichen Kursanbietern.
+onboarding-currency-npt-only = Nur NPT
+
+## Settings
+
+settings-title = Einstellungen
+settings-language = Sprache
+settings-amounts = Beträge
+settings-amount-decimals = Angezeigte Nachkommastellen
+settings-amount-decimals-all = Alle
+settings-amount-decimals-count = { $count }
+settings-amount-rounding = Weitere Nachkommastellen werden
+settings-amount-rounding-round = Gerundet
+settings-amount-rounding-truncate = Abgeschnitten
+settings-amount-help = Gilt für NPT-Beträge. Tabellen zeigen höchstens 4 Nachkommastellen. Fahren Sie mit der Maus über einen Betrag, um ihn genau zu sehen.
+settings-confirmations = Bestätigungen
+settings-confirmations-intro = Vor folgenden Aktionen nachfragen:
+settings-confirm-duplicate-address = Hinzufügen einer Empfängeradresse, die bereits in der Liste ist
+settings-confirm-clear-standing = Zurücksetzen des Ansehens von Peers
+settings-confirm-large-send = Senden von mehr als der Hälfte des Wallet-Guthabens
+settings-startup-checks = Prüfungen beim Start
+settings-startup-checks-intro = Beim Start der App suchen nach:
+settings-startup-check-drafts = Nicht gesendeten Sendeentwürfen
+settings-startup-check-stuck = Eigenen, im Mempool hängenden Transaktionen
+settings-startup-check-unclaimed = Erwarteten UTXOs, die nicht angekommen sind
+settings-refresh = Aktualisierungsintervalle
+settings-refresh-intro = Wie oft jede Ansicht neue Daten vom Knoten abruft, während sie angezeigt wird.  Längere Intervalle verbrauchen weniger Datenvolumen.
+settings-refresh-seconds = { $seconds ->
+        [one] Jede Sekunde
+       *[other] Alle { $seconds } Sekunden
+    }
+settings-refresh-default = { $seconds ->
+        [one] Jede Sekunde (Standard)
+       *[other] Alle { $seconds } Sekunden (Standard)
+    }
+settings-data-dir = Datenverzeichnis von neptune-core
+settings-data-dir-intro = Wo diese App das RPC-Cookie und die Wallet-Dateien von neptune-core liest.  Lassen Sie das Feld leer, um das von neptune-core gemeldete Verzeichnis zu verwenden.  Wählen Sie das Verzeichnis, das neptune-core mit --data-dir übergeben wurde, falls diese App es unter einem anderen Pfad sieht.
+settings-data-dir-in-use = Verwendet: { $path }
+settings-data-dir-from-env = Durch die Umgebungsvariable NEPTUNE_DATA_DIR festgelegt.
+settings-data-dir-placeholder = Das von neptune-core gemeldete Verzeichnis
+settings-data-dir-browse = Durchsuchen…
+settings-data-dir-reset = Verzeichnis von neptune-core verwenden
+settings-data-dir-saved = Datenverzeichnis gespeichert.
+settings-data-dir-failed = Dieses Verzeichnis kann nicht verwendet werden: { $error }
+settings-data-dir-load-failed = Das Datenverzeichnis konnte nicht gelesen werden: { $error }
+settings-managed-node = neptune-core ausführen
+settings-managed-node-intro = Lassen Sie diese App neptune-core starten und bei einem Absturz erneut starten.  Die Ausgabe sehen Sie unter Node Logs.  Wenn Sie --rpc-port angeben, muss er mit dem NEPTUNE_CORE_RPC_PORT übereinstimmen, mit dem diese App gestartet wurde.
+settings-managed-node-enable = neptune-core aus dieser App ausführen
+settings-managed-node-binary = neptune-core-Programm
+settings-managed-node-args = Argumente
+settings-managed-node-args-hint = Durch Leerzeichen getrennt, zum Beispiel --network main --data-dir /pfad
+settings-managed-node-saved = Gespeichert.  neptune-core wird mit den neuen Einstellungen neu gestartet.
+settings-managed-node-failed = Speichern fehlgeschlagen: { $error }
+settings-managed-node-load-failed = Die Einstellungen konnten nicht gelesen werden: { $error }
+settings-managed-node-logs = Node Logs anzeigen
+settings-sessions = Geräte & Sitzungen
+settings-sessions-load-failed = Sitzungen konnten nicht geladen werden: { $error }
+settings-session-device = Gerät
+settings-session-ip = IP-Adresse
+settings-session-last-active = Zuletzt aktiv
+settings-session-current = Dieses Gerät
+settings-session-revoke = Widerrufen
+settings-session-revoke-failed = Sitzung konnte nicht widerrufen werden: { $error }
+settings-metadata = Bezeichnungen und Tags
+settings-metadata-intro = Exportieren Sie Adressbezeichnungen, Transaktions-Tags und -Kontakte sowie UTXO-Tags im Neptune-Wallet-Metadatenformat, um sie in anderen Neptune-Werkzeugen zu nutzen.  Beim Import werden Einträge für dieselbe Adresse, Transaktion oder UTXO ersetzt, alle anderen bleiben erhalten.
+settings-metadata-export = Exportieren
+settings-metadata-import = Importieren
+settings-metadata-imported = { $addresses } Adressbezeichnungen, { $transactions } Transaktionsanmerkungen und { $utxos } UTXO-Anmerkungen importiert.  { $skipped } ungültige Einträge übersprungen.
+settings-metadata-import-failed = Import fehlgeschlagen: { $error }
+settings-import-wallet = Wallet importieren
+settings-import-wallet-intro = Stellen Sie eine Wallet aus ihrer Seed-Phrase wieder her, z. B. um sie auf diesen Rechner zu übertragen.
+settings-import-wallet-open = Wallet aus ihrer Seed-Phrase importieren
+settings-diagnostics = Diagnose
+settings-support-bundle-intro = Ein Support-Paket enthält die App-Version, den Knotenstatus, die Einstellungen, aktuelle Ereignisse und Fehler sowie Leistungsmetriken.  Es enthält niemals Schlüssel.
+settings-health-open = Systemprüfung ausführen
+settings-support-bundle-include-addresses = Meine Empfangsadressen, Benutzernamen und IP-Adressen einschließen
+settings-support-bundle-generate = Support-Paket erstellen
+settings-advanced = Erweitert
+settings-rpc-metrics = RPC-Leistung
+settings-rpc-metrics-intro = Wie lange Aufrufe an neptune-core dauern und welche fehlschlugen, seit der Server der App gestartet wurde.  Fehler, die neptune-core in einer Antwort meldet, werden nicht gezählt, nur Zeitüberschreitungen und Verbindungsabbrüche.
+settings-rpc-metrics-none = Noch keine Aufrufe an neptune-core.
+settings-rpc-metrics-since = Seit { $time }
+settings-rpc-metrics-method = Methode
+settings-rpc-metrics-calls = Aufrufe
+settings-rpc-metrics-errors = Fehler
+settings-rpc-metrics-mean = Mittel
+settings-rpc-metrics-max = Langsamster
+settings-rpc-metrics-failures = Letzte Fehler
+settings-rpc-metrics-no-failures = Kein Aufruf ist fehlgeschlagen.
+settings-rpc-metrics-refresh = Aktualisieren
+settings-developer = Entwicklerwerkzeuge
+settings-announcement-intro = Sendet eine Transaktion mit einer beliebigen Ankündigung, um ankündigungsbasierte Funktionen zu testen.  Die Transaktion gibt nur das Wechselgeld abzüglich der Gebühr an diese Wallet zurück.
+settings-announcement-encoding = Kodierung
+settings-announcement-message = Ankündigung
+settings-announcement-elements = { $count } Feldelemente
+settings-announcement-fee = Gebühr
+settings-announcement-broadcast = Ankündigung senden
+settings-announcement-sent = Gesendet in Transaktion { $txid }
+
+## Notifications
+
+notification-title = Benachrichtigungen
+notification-bell-title = { $count ->
+        [0] Keine ungelesenen Benachrichtigungen
+        [one] 1 ungelesene Benachrichtigung
+       *[other] { $count } ungelesene Benachrichtigungen
+    }
+notification-none = Noch nichts zu melden.
+notification-mark-all-read = Alle als gelesen markieren
+notification-clear = Leeren
+notification-funds-received = Empfangen in Block { $height }:
+notification-send-confirmed = Gesendet in Block { $height }:
+notification-connection-lost = Die Verbindung zu neptune-core wurde unterbrochen.
+notification-connection-restored = Wieder mit neptune-core verbunden.
+notification-low-fee = Transaktion { $txid } wartet seit { $blocks } Blöcken darauf, gemint zu werden. Die Gebühr ist möglicherweise zu niedrig:
+notification-low-fee-action = Anzeigen
+notification-token-rejected = neptune-core hat das RPC-Token dieser App abgelehnt, auch nachdem das Cookie erneut gelesen wurde. Führen Sie diese App als derselbe Benutzer wie neptune-core aus, oder prüfen Sie dessen Datenverzeichnis.
+notification-token-rejected-action = Systemprüfung
+
+## Search
+
+search-label = Suchen
+search-placeholder = Block, Transaktion oder Adresse
+search-not-found = Nichts gefunden.
+
+## Lock
+
+lock-title = Wallet gesperrt
+lock-intro = Geben Sie Ihre PIN ein, um das Wallet zu entsperren.
+lock-pin-placeholder = PIN oder Passphrase
+lock-unlock = Entsperren
+lock-wrong-pin = Falsche PIN.
+lock-reauth-title = Bestätigen Sie Ihre Identität
+lock-reauth-intro = Geben Sie Ihre PIN ein, um fortzufahren.
+lock-idle-title = Pausiert während Ihrer Abwesenheit
+lock-idle-intro = Das Wallet wurde nach einer Zeit der Inaktivität ausgeblendet.
+lock-idle-resume = Fortsetzen
+
+settings-security = Sicherheit
+settings-security-intro = Eine PIN oder Passphrase sperrt das Wallet beim Start und nach einer Zeit der Inaktivität und wird vor dem Senden oder dem Anzeigen der Seed-Phrase erneut abgefragt.
+settings-current-pin = Aktuelle PIN
+settings-new-pin = Neue PIN
+settings-confirm-pin = Neue PIN bestätigen
+settings-set-pin = PIN festlegen
+settings-change-pin = PIN ändern
+settings-remove-pin = PIN entfernen
+settings-lock-now = Jetzt sperren
+settings-pin-mismatch = Die neuen PINs stimmen nicht überein.
+settings-pin-saved = PIN gespeichert.
+settings-pin-removed = PIN entfernt.
+settings-idle-lock = Sperren nach Inaktivität
+settings-idle-lock-never = Nie
+settings-idle-lock-minutes = { $minutes ->
+        [one] 1 Minute
+       *[other] { $minutes } Minuten
+    }
+settings-idle-blur = Unscharf nach Inaktivität
+settings-idle-blur-help = Verbirgt den Bildschirm, bis Sie zurückkehren, etwa an einem unbeaufsichtigten Rechner. Ist eine PIN gesetzt, wird sie zum Fortsetzen benötigt.
+settings-address-check = Adressen erneut prüfen bei Zahlungen über (NPT)
+settings-address-check-off = Aus
+settings-address-check-help = Größere Zahlungen verlangen das Ende jeder Empfängeradresse, bevor sie bestätigt werden können. Leer lassen zum Abschalten.
+
+settings-wallet-encryption = Wallet-Datei
+settings-wallet-missing = Unter { $file } gibt es noch keine Wallet-Datei. neptune-core legt sie beim ersten Start an.
+settings-wallet-not-encrypted = Die Wallet-Datei ist nicht verschlüsselt: Wer { $file } lesen kann, kann über ihr Guthaben verfügen.
+settings-wallet-unreadable = Die Wallet-Datei { $file } konnte nicht gelesen werden: { $error }
+settings-wallet-encryption-unsupported = Diese Version von neptune-core kann ihre Wallet-Datei nicht verschlüsseln. So schützen Sie sie:
+settings-wallet-protect-disk = Aktivieren Sie die Festplattenverschlüsselung, z. B. FileVault, BitLocker oder LUKS.
+settings-wallet-protect-permissions = Machen Sie das Wallet-Verzeichnis nur für das Konto lesbar, unter dem neptune-core läuft.
+settings-wallet-protect-backup = Bewahren Sie die Seed-Phrase aufgeschrieben an einem sicheren Ort auf, getrennt von diesem Gerät.
+
+## Import Wallet
+
+import-wallet-title = Wallet importieren
+import-wallet-intro = Geben Sie die Seed-Phrase der wiederherzustellenden Wallet ein oder scannen Sie sie von einem QR-Code. neptune-core findet das Guthaben der Wallet dann beim Synchronisieren. Eine vorhandene Wallet wird nie ersetzt.
+import-wallet-words = Seed-Wörter
+import-wallet-words-placeholder = Die Wörter, durch Leerzeichen getrennt
+import-wallet-word-count = { $count ->
+        [one] 1 Wort eingegeben. Neptune-Seed-Phrasen haben 18 Wörter.
+       *[other] { $count } Wörter eingegeben. Neptune-Seed-Phrasen haben 18 Wörter.
+    }
+import-wallet-unknown-words = Keine Seed-Wörter: { $words }
+import-wallet-bad-checksum = Die Wörter ergeben keine gültige Seed-Phrase. Prüfen Sie Schreibweise und Reihenfolge.
+import-wallet-valid = Gültige Seed-Phrase.
+import-wallet-scan = QR-Code scannen
+import-wallet-import = Importieren
+import-wallet-importing = Wird importiert...
+import-wallet-written = Die Wallet wurde nach { $file } geschrieben.
+import-wallet-node-restarted = neptune-core wird neu gestartet, um sie zu verwenden.
+import-wallet-start-node = Starten Sie neptune-core, oder starten Sie es neu, falls es läuft, um sie zu verwenden.
+import-wallet-already-in-use = Dies ist bereits die verwendete Wallet.
+import-wallet-other-exists = neptune-core hat bereits eine andere Wallet, die nie ersetzt wird.
+import-wallet-step-backup = Falls diese Wallet Guthaben enthalten könnte, exportieren Sie zuerst ihre Seed-Phrase unter Adressen.
+import-wallet-step-stop = Beenden Sie neptune-core.
+import-wallet-step-move = Verschieben Sie das Wallet-Verzeichnis { $dir } an einen sicheren Ort.
+import-wallet-step-retry = Importieren Sie erneut und starten Sie dann neptune-core.
+
+## Health Check
+health-title = Systemprüfung
+health-intro = Prüft die Verbindung zu neptune-core, dessen RPC-Cookie, das Datenverzeichnis, in dem diese App ihre Dateien ablegt, und die Preisanbieter.
+health-checking = Wird geprüft…
+health-check-again = Erneut prüfen
+health-checked-at = Geprüft { $time }
+health-summary-ok = Alles, was die Wallet benötigt, funktioniert.
+health-summary-failed = Einige Prüfungen sind fehlgeschlagen.
+health-rpc-connection = Verbindung zu neptune-core
+health-auth-token = RPC-Cookie und Token
+health-data-directory = Datenverzeichnis
+health-price-providers = Preisanbieter
+health-ok = OK
+health-warning = Warnung
+health-failed = Fehlgeschlagen
+health-skipped = Übersprungen
+
+## QR Scanner
+
+qr-scanner-permission-prompt = Warte auf die Erlaubnis, die Kamera zu verwenden...
+qr-scanner-permission-denied = Kamerazugriff ist blockiert
+qr-scanner-permission-android = Um zu scannen, erlauben Sie die Kamera unter Einstellungen > Apps > Neptune Proton > Berechtigungen > Kamera und versuchen Sie es dann erneut.
+qr-scanner-permission-ios = Um zu scannen, aktivieren Sie die Kamera unter Einstellungen > Neptune Proton und versuchen Sie es dann erneut.
+qr-scanner-permission-browser = Um zu scannen, erlauben Sie die Kamera für diese Seite in den Website-Einstellungen Ihres Browsers, meist hinter dem Symbol neben der Adressleiste, und versuchen Sie es dann erneut.
+qr-scanner-no-camera = Keine Kamera gefunden
+qr-scanner-no-camera-help = Dieses Gerät hat keine Kamera, die zum Scannen verwendet werden kann.
+qr-scanner-use-file = Sie können den QR-Code stattdessen aus einer Datei lesen.
+qr-scanner-try-camera = Kamera erneut versuchen
+qr-scanner-camera = Kamera
+qr-scanner-resolution = Auflösung
+qr-scanner-torch = Taschenlampe
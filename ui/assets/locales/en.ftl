@@ -0,0 +1,651 @@
+## Shared
+
+common-amount-hidden = Amounts are hidden. Show them with the eye button in the header.
+common-back = Back
+common-cancel = Cancel
+common-clear-selection = Clear Selection
+common-close = Close
+common-copied = Copied to clipboard.
+common-copy-failed = Could not copy to clipboard.
+common-copy-label = Copy to clipboard
+common-delete = Delete
+common-dont-ask-again = Don't ask me again
+common-error = Error
+common-export-failed = Export failed: { $error }
+common-exported = Exported.
+common-retry = Retry
+common-save = Save
+common-selected = { $count } selected
+
+## Balance
+
+balance-title = Wallet Overview
+balance-load-failed = Failed to load dashboard data: { $error }
+balance-confirmed = Confirmed Balance
+balance-unconfirmed = Unconfirmed Balance
+balance-available = Available
+balance-time-locked = Time-locked
+balance-total = Total
+balance-blockchain = Blockchain
+balance-network = Network
+balance-status = Status
+balance-syncing = Syncing...
+balance-synced = Synced
+balance-tip = Tip
+balance-mempool = Mempool
+balance-transactions = Transactions
+balance-size-bytes = Size (bytes)
+balance-network-peers = Network Peers
+balance-connected-peers = Connected Peers
+balance-max-peers = Max Peers
+balance-node-info = Node Info
+balance-mining-status = Mining Status
+balance-proving-capability = Proving Capability
+balance-attention = Needs Attention
+balance-attention-dismiss = Dismiss
+balance-attention-drafts = { $count ->
+        [one] 1 unsent Send draft
+       *[other] { $count } unsent Send drafts
+    }
+balance-attention-drafts-action = Review drafts
+balance-attention-stuck-tx = Transaction { $txid } is not synced to the tip and cannot be mined as is
+balance-attention-stuck-tx-action = Resolve
+balance-attention-unclaimed = { $count ->
+        [one] 1 expected UTXO has not arrived
+       *[other] { $count } expected UTXOs have not arrived
+    }
+balance-attention-unclaimed-action = View UTXOs
+balance-assets = Assets
+balance-asset-utxos = { $count ->
+        [one] 1 UTXO
+       *[other] { $count } UTXOs
+    }
+balance-asset-no-amount = amount unknown
+
+## Send
+
+send-add-recipients = Add Recipients
+send-spendable-now = Spendable now:
+send-spendable-time-locked = Time-locked:
+send-spendable-next-release = next release on { $date }:
+send-spendable-do-not-spend = Marked do-not-spend:
+send-recipient-address = Recipient Address
+send-address-placeholder = Click to paste or scan an address...
+send-address-empty = Enter or scan the recipient's address.
+send-address-truncated = This address is incomplete. Copy the whole address, not a shortened one.
+send-address-malformed = This is not a Neptune address.
+send-address-checksum = This address contains a typo: its checksum does not match.
+send-address-wrong-network = This address ({ $prefix }...) is for another network. This wallet is on { $network }.
+send-address-unsupported = This wallet cannot pay addresses of this type ({ $prefix }...).
+send-amount-npt = Amount (NPT)
+send-amount-fiat = Amount ({ $currency })
+send-amount-positive = Amount must be > 0.
+send-toggle-currency = Toggle between Neptune Cash (NPT) and { $name } ({ $code })
+send-done = Done
+send-edit = Edit
+send-add-another-recipient = Add Another Recipient
+send-next-set-fee = Next: Set Fee
+send-set-address = Set Address
+send-choose-action = Choose an action.
+send-choose-action-for = Choose an action for recipient number { $number }.
+send-paste-address = Paste Address
+send-scan-qr = Scan QR Code
+send-upload-qr = Import from File
+send-recent-recipients = Recent recipients
+send-recent-last-used = Last paid { $date }
+send-recent-forget = Forget this recipient
+send-invalid-payment-request = Invalid payment request from QR: { $error }
+send-duplicate-title = Duplicate Address
+send-duplicate-message = This address is already in the recipient list. Do you want to add it again?
+send-merge-duplicates-message = { $count ->
+        [one] 1 recipient pays an address that is already in the list. Merging them into a single output makes the transaction smaller and the fee lower.
+       *[other] { $count } recipients pay addresses that are already in the list. Merging them into single outputs makes the transaction smaller and the fee lower.
+    }
+send-merge-duplicates = Merge
+send-proceed-anyway = Proceed Anyway
+
+send-drafts = Drafts
+send-saved-drafts = Saved Drafts
+send-no-drafts = No saved drafts.
+send-drafts-load-failed = Could not load drafts: { $error }
+send-draft-summary = { $recipients ->
+        [one] 1 recipient
+       *[other] { $recipients } recipients
+    }, saved { $saved }
+send-resume = Resume
+send-draft-foreign-currency = This draft has amounts in { $currency }. Select { $currency } as your currency to resume it.
+send-save-draft = Save Draft
+send-draft-name = Name
+send-draft-name-placeholder = eg Rent, March
+send-draft-replaced = A draft with the same name is replaced.
+
+send-set-fee = Set Fee
+send-subtotal = Subtotal:{" "}
+send-fee-npt = Fee (NPT)
+send-fee-fiat = Fee ({ $currency })
+send-total-spend = Total Spend
+send-funds-short = Not enough spendable funds. Short by
+send-funds-available = Spendable now:
+send-funds-time-locked = Time-locked, not yet spendable:
+send-funds-unconfirmed = Incoming, waiting for confirmation:
+send-funds-pending = In use by your unconfirmed transactions:
+send-next-review = Next: Review
+
+send-review-title = Review Transaction
+send-review-intro = Please review the details below. This action cannot be undone.
+send-address-check-title = Verify addresses
+send-address-check-intro = This is a large send. Enter the last { $count } characters of each recipient address, read from where you got it, to guard against an address replaced in your clipboard.
+send-address-check-label = Recipient { $number }
+send-recipients = Recipients:
+send-fee = Fee:{" "}
+send-total-spend-label = Total Spend:{" "}
+send-estimated-suffix = {" "}est.)
+send-confirm = Confirm & Send
+send-large-title = Large Send
+send-large-message = You are about to send more than half of your wallet balance. Do you want to continue?
+send-send = Send
+
+send-sending = Sending Transaction...
+send-please-wait = Please wait.
+send-stage-selecting-inputs = Selecting inputs
+send-stage-building = Building the transaction
+send-stage-proving = Proving
+send-stage-broadcasting = Broadcasting
+send-stage-proving-hint = Proving can take several minutes, depending on the computer running the node.
+send-stage-proving-capability = The node proves as { $capability }.
+send-stage-retrying = The connection to neptune-core was lost. Trying again, attempt { $attempt }.
+send-queue-heading = Recent sends
+send-queue-title = Recent sends, { $count } with a new outcome
+send-queue-title-in-flight = { $count ->
+    [one] A transaction is being sent
+   *[other] { $count } transactions are being sent
+}
+send-queue-recipients = { $count ->
+    [one] 1 recipient,
+   *[other] { $count } recipients,
+}
+send-queue-fee = Fee
+send-queue-in-flight = Being built and proved. You can leave this screen meanwhile.
+send-queue-interrupted = The app was closed before this send finished. The transaction may still have been sent.
+send-queue-check-history = Check the history
+send-queue-clear = Clear finished
+send-status-title = Transaction Status
+send-success = Transaction sent successfully!
+send-transaction-id = Transaction ID
+send-view-in-mempool = View in Mempool
+send-another = Send Another Transaction
+send-error-title = Error Sending Transaction
+send-failure-connection = Could Not Reach the Node
+send-failure-funds = Not Enough Funds
+send-failure-invalid = The Transaction Is Not Valid
+send-failure-broadcast-unknown = The Transaction May Have Been Sent
+send-edit-recipients = Edit recipients
+send-edit-fee = Edit fee
+send-retry = Retry
+send-error-insufficient = The spendable balance does not cover the amount and fee. Lower either, or wait for incoming funds to confirm.
+send-error-connection = neptune-core could not be reached. Check the Mempool screen before trying again, in case the transaction went out.
+send-error-timeout = neptune-core did not respond in time. The transaction may still go out, so check the Mempool screen before trying again.
+send-error-invalid-address = Check the recipient addresses, and that they are for this network.
+send-error-auth = Sending was refused. Your account may not be permitted to send, or the node's credentials changed.
+send-error-broadcast-unknown = The connection to neptune-core failed while it was broadcasting the transaction. Check the Mempool and History screens before sending again, as sending again may pay twice.
+send-error-cookie = This app cannot read neptune-core's RPC cookie, so it cannot use the wallet. Run it as the same user as neptune-core, with access to its data directory.
+send-advanced-options = Advanced options
+send-change-title = Send change to
+send-change-next-symmetric = A new symmetric key of this wallet (default)
+send-change-next-generation = A new generation address of this wallet
+send-change-existing = Existing address { $address }
+send-change-exact = Nowhere: fail unless the inputs match the amount and fee exactly
+send-change-intro = Change is what is left of the spent UTXOs after the amount and fee. It is always announced on-chain, so it can be recovered from the seed phrase.
+send-announcement-title = Public announcement
+send-announcement-intro = Attaches a message to the transaction that anyone can read on the blockchain, forever. Leave it empty to send without one. A larger announcement may need a higher fee.
+send-announcement-encoding = Encoding
+send-announcement-message = Message
+send-announcement-elements = { $count } of at most { $max } field elements
+send-announcement-review = Public announcement
+send-announcement-review-size = { $encoding }, { $count } field elements
+send-coin-control = Choose UTXOs to spend
+send-coin-control-intro = Spends exactly the UTXOs checked, eg to keep some coins apart. What they hold beyond the amount and fee is returned as change. With none checked, the wallet chooses. UTXOs marked do-not-spend on the UTXOs screen cannot be chosen.
+send-coin-control-none = No UTXOs can be spent now.
+send-coin-control-do-not-spend = Marked do-not-spend
+send-coin-control-chosen = { $count ->
+        [one] 1 UTXO chosen, totalling
+       *[other] { $count } UTXOs chosen, totalling
+    }
+send-coin-control-short = Not enough for the amount and fee. Short by
+
+## Broadcast
+
+broadcast-title = Broadcast Transaction
+broadcast-intro = Submit a transaction signed elsewhere, eg by another wallet.  Paste its hex, or open a file holding it.  The node validates it before broadcasting.
+broadcast-placeholder = Signed transaction, as hex
+broadcast-size = { $bytes } bytes
+broadcast-open-file = Open File
+broadcast-submit = Broadcast
+broadcast-broadcasting = Broadcasting...
+broadcast-done = Transaction broadcast: { $txid }
+broadcast-rejected = The transaction was rejected
+
+## Node Stats
+
+node-stats-title = Node Stats
+node-stats-intro = Sampled every minute while neptune-proton runs.  A week of history is kept.
+node-stats-window-hour = 1 hour
+node-stats-window-day = 24 hours
+node-stats-window-week = 7 days
+node-stats-block-interval = Block interval
+node-stats-mempool-size = Mempool size
+node-stats-peer-count = Peer count
+node-stats-sync-progress = Sync progress
+node-stats-axis-time = Time
+node-stats-axis-minutes = Minutes
+node-stats-axis-kb = kB
+node-stats-axis-peers = Peers
+node-stats-axis-height = Block height
+node-stats-no-data = Not enough samples yet.
+
+## Logs
+
+logs-title = Logs
+logs-intro = Recent log entries of the neptune-proton server, newest first.  Changing the level also changes what the server records from now on.
+logs-level = Log level
+logs-level-error = Error
+logs-level-warn = Warning
+logs-level-info = Info
+logs-level-debug = Debug
+logs-level-trace = Trace
+logs-pause = Pause
+logs-resume = Resume
+logs-clear = Clear
+logs-none = No log entries yet.
+logs-col-time = Time
+logs-col-level = Level
+logs-col-source = Source
+logs-col-message = Message
+
+## Node Logs
+
+node-logs-title = neptune-core Output
+node-logs-restart = Restart
+node-logs-none = No output yet.
+node-logs-state-disabled = neptune-core is not run by this app.
+node-logs-state-running = Running (pid { $pid }) since { $since }.
+node-logs-state-starting = Started (pid { $pid }), waiting for its RPC server…
+node-logs-state-restarting = Stopped.  Starting again at { $at }.
+node-logs-state-stopping = Stopping…
+node-logs-restarts = { $count ->
+        [one] (restarted once)
+       *[other] (restarted { $count } times)
+    }
+node-logs-last-exit = Last stop: { $reason }
+
+## Receive
+
+receive-title = Receive Funds
+receive-select-type = Select Address Type:
+receive-type-generation = Generation
+receive-type-symmetric = Symmetric Key
+receive-type-size = Size
+receive-type-sharing = Sharing
+receive-type-scanning = Who can find payments
+receive-generation-size = Long, about 4,000 characters. Best shared as a QR code or by copy and paste.
+receive-generation-sharing = Safe to share with anyone who pays you. It only lets them pay you.
+receive-generation-scanning = Only this wallet can find and spend payments to it.
+receive-symmetric-size = Short. Payments to it take up less space on the blockchain.
+receive-symmetric-sharing = Never share it. It is for moving funds between wallets you control.
+receive-symmetric-scanning = Anyone who has it can find and spend payments to it.
+receive-symmetric-acknowledge = I understand that symmetric keys must only be used for sending between wallets I control. Sharing with others would enable them to spend my funds.
+receive-generate = Generate New Receiving Address
+receive-generating = Generating...
+receive-pending-retry = Pending Retry...
+receive-connection-lost = Connection Lost. Retrying when connection is restored...
+receive-symmetric-do-not-share = Do not share with anyone.
+receive-symmetric-notice = This is a symmetric key/address.
+receive-symmetric-risk = Anyone possessing it can spend associated funds.
+receive-share-address = Share this address to receive funds.
+receive-scan-payment-request = Scan the QR code to obtain the payment request.
+receive-scan-address = Scan the QR code to obtain the full address.
+receive-request-amount = Request a specific amount
+receive-amount-npt = Amount (NPT)
+receive-optional = Optional
+receive-label = Label
+receive-label-placeholder = Optional, eg invoice number
+receive-generate-another = Generate Another
+receive-share = Share
+receive-share-title = My Neptune address
+receive-share-copied = Sharing is not available, so the address was copied instead.
+
+receive-expected-title = Incoming
+receive-expected-none = No incoming payments. A payment shows up here once it reaches the mempool.
+receive-expected-transaction = Transaction
+receive-expected-amount = Amount
+receive-expected-status = Status
+receive-expected-in-mempool = In the mempool
+receive-expected-updating = In the mempool, proof being updated
+receive-expected-unclaimed = { $count ->
+        [one] 1 more expected UTXO has not arrived in a block yet.
+       *[other] { $count } more expected UTXOs have not arrived in a block yet.
+    }
+
+## History
+
+history-title = History
+history-load-failed = Failed to load history: { $error }
+history-empty-title = No Transactions
+history-empty-description = You have no transactions confirmed in a block yet.  You can also check the mempool for unconfirmed transactions.
+history-fiat-at-time = Fiat value at time of tx
+history-copy-csv = Copy as CSV
+history-export-selected = Export Selected
+history-date = Date
+history-type = Type
+history-amount = Amount
+history-value-at-time = Value at Time
+history-value-at-time-tooltip = Value on the day the transaction was confirmed
+history-block = Block
+history-received = Received
+history-sent = Sent
+history-pending = Pending
+history-pending-tooltip = In the mempool, not yet confirmed in a block (0 confirmations)
+history-confirmations = Confirmations
+history-price-unavailable = Price not available yet
+history-unconfirmed-note = Note: Pending transactions are listed first until they are confirmed in a block.
+history-annotate = Tag / Contact
+history-annotate-title = Tag { $count } Transactions
+history-tag = Tag
+history-tag-placeholder = eg. groceries
+history-contact = Contact
+history-contact-placeholder = eg. Alice
+history-annotate-hint = Only fields you edit are changed.  Leave a field empty to clear it.
+
+## Transaction Notes
+
+tx-note-title = Transaction Note
+tx-note-label = Note
+tx-note-placeholder = eg. Rent for May
+tx-note-private = Stored on this computer only, never broadcast.
+tx-note-add = Add note
+tx-note-edit = Edit note
+
+## Reports
+
+reports-title = Spending Reports
+reports-empty-title = No Spending
+reports-empty-description = Transactions sent from this wallet are summarized here by month.
+reports-group-by = Group by
+reports-tag = Tag
+reports-contact = Contact
+reports-monthly-totals = Monthly Totals
+reports-month-breakdown = Spending in { $month }
+reports-category = Category
+reports-transactions = Transactions
+reports-share = Share
+reports-total = Total
+reports-untagged = Untagged
+reports-no-contact = No contact
+reports-partial-prices = * Some transactions have no price for their day yet, and are left out of the fiat value.
+reports-hint = Set tags and contacts by selecting transactions on the History screen.
+reports-year-title = Yearly Summary
+reports-year = Year
+reports-year-received = Received
+reports-year-sent = Sent, including fees
+reports-year-fees = Fees
+reports-year-fees-hint = Fees are known only for sends made with this app and still in its list of sends. Amounts are netted per block, so sending to yourself counts only the fee.
+reports-year-export-csv = Export CSV
+reports-year-print = Print or Save as PDF
+
+## Onboarding
+
+onboarding-title = Welcome to Neptune Wallet
+onboarding-step = Step { $step } of { $steps }
+onboarding-skip = Skip Setup
+onboarding-next = Next
+onboarding-finish = Finish
+onboarding-details = Details
+onboarding-unreachable-intro = This wallet works with neptune-core, the Neptune node, which could not be reached. These steps help you start it and connect to it.
+onboarding-new-wallet-intro = Your wallet is new. These steps check the connection to neptune-core and let you choose how amounts are shown.
+onboarding-network = Network
+onboarding-network-intro = Choose the network for neptune-core to join.
+onboarding-network-main = the main network, for real funds
+onboarding-network-testnet = a test network, for trying things out with coins of no value
+onboarding-network-regtest = a local test network, for development
+onboarding-node = Start neptune-core
+onboarding-node-install = Install neptune-core, if you have not already, from its releases page:
+onboarding-node-start = Start it with:
+onboarding-node-port = This app connects to neptune-core's RPC port, 9799 unless the NEPTUNE_CORE_RPC_PORT env var says otherwise.
+onboarding-node-data-dir = If neptune-core keeps its data somewhere other than its default, eg with --data-dir, the next step lets you point this app to it.
+onboarding-connection = Test the Connection
+onboarding-connection-test = Test Again
+onboarding-connection-testing = Connecting to neptune-core…
+onboarding-connection-ok = Connected to neptune-core on { $network }.
+onboarding-connection-network-mismatch = neptune-core runs on { $network }, not { $chosen } as chosen. Restart it with --network { $chosen } if that was not intended.
+onboarding-connection-unreachable = Nothing answered on RPC port { $port }. Check that neptune-core is running and has finished starting.
+onboarding-connection-cookie = neptune-core answered, but this app cannot read its RPC cookie. Run this app as the same user as neptune-core, or choose its data directory below.
+onboarding-connection-checks = All checks
+onboarding-currency = Currency
+onboarding-currency-intro = Choose a currency to show amounts in next to NPT. Prices come from public price providers.
+onboarding-currency-npt-only = NPT only
+
+## Settings
+
+settings-title = Settings
+settings-language = Language
+settings-amounts = Amounts
+settings-amount-decimals = Decimals shown
+settings-amount-decimals-all = All
+settings-amount-decimals-count = { $count }
+settings-amount-rounding = Extra decimals are
+settings-amount-rounding-round = Rounded
+settings-amount-rounding-truncate = Truncated
+settings-amount-help = Applies to NPT amounts. Tables show at most 4 decimals. Hover an amount to see it exactly.
+settings-confirmations = Confirmations
+settings-confirmations-intro = Ask for confirmation before:
+settings-confirm-duplicate-address = Adding a recipient address that is already in the list
+settings-confirm-clear-standing = Clearing peer standings
+settings-confirm-large-send = Sending more than half of the wallet balance
+settings-startup-checks = Startup Checks
+settings-startup-checks-intro = When the app starts, look for:
+settings-startup-check-drafts = Unsent Send drafts
+settings-startup-check-stuck = Own transactions stuck in the mempool
+settings-startup-check-unclaimed = Expected UTXOs that have not arrived
+settings-refresh = Refresh Intervals
+settings-refresh-intro = How often each screen fetches new data from the node while it is shown.  Longer intervals use less bandwidth.
+settings-refresh-seconds = { $seconds ->
+        [one] Every second
+       *[other] Every { $seconds } seconds
+    }
+settings-refresh-default = { $seconds ->
+        [one] Every second (default)
+       *[other] Every { $seconds } seconds (default)
+    }
+settings-data-dir = neptune-core Data Directory
+settings-data-dir-intro = Where this app reads neptune-core's RPC cookie and wallet files.  Leave empty to use the directory neptune-core reports.  Choose the directory neptune-core was given with --data-dir if this app sees it under another path.
+settings-data-dir-in-use = In use: { $path }
+settings-data-dir-from-env = Set by the NEPTUNE_DATA_DIR env var.
+settings-data-dir-placeholder = The directory neptune-core reports
+settings-data-dir-browse = Browse…
+settings-data-dir-reset = Use neptune-core's
+settings-data-dir-saved = Data directory saved.
+settings-data-dir-failed = Could not use that directory: { $error }
+settings-data-dir-load-failed = Could not read the data directory: { $error }
+settings-managed-node = Run neptune-core
+settings-managed-node-intro = Let this app start neptune-core, and start it again if it stops.  Its output is shown under Node Logs.  If you pass --rpc-port, it must match the NEPTUNE_CORE_RPC_PORT this app was started with.
+settings-managed-node-enable = Run neptune-core from this app
+settings-managed-node-binary = neptune-core program
+settings-managed-node-args = Arguments
+settings-managed-node-args-hint = Separated by spaces, for example --network main --data-dir /path
+settings-managed-node-saved = Saved.  neptune-core is restarted with the new settings.
+settings-managed-node-failed = Could not save: { $error }
+settings-managed-node-load-failed = Could not read the settings: { $error }
+settings-managed-node-logs = Show Node Logs
+settings-sessions = Devices & Sessions
+settings-sessions-load-failed = Could not load sessions: { $error }
+settings-session-device = Device
+settings-session-ip = IP Address
+settings-session-last-active = Last Active
+settings-session-current = This device
+settings-session-revoke = Revoke
+settings-session-revoke-failed = Could not revoke session: { $error }
+settings-metadata = Labels and Tags
+settings-metadata-intro = Export address labels, transaction tags and contacts, and UTXO tags in the Neptune wallet metadata format, to use them in other Neptune tools.  Importing replaces entries for the same address, transaction or UTXO, and keeps the rest.
+settings-metadata-export = Export
+settings-metadata-import = Import
+settings-metadata-imported = Imported { $addresses } address labels, { $transactions } transaction annotations and { $utxos } UTXO annotations.  Skipped { $skipped } invalid entries.
+settings-metadata-import-failed = Import failed: { $error }
+settings-import-wallet = Import Wallet
+settings-import-wallet-intro = Restore a wallet from its seed phrase, eg to move it onto this machine.
+settings-import-wallet-open = Import a wallet from its seed phrase
+settings-diagnostics = Diagnostics
+settings-support-bundle-intro = A support bundle contains the app version, node status, settings, recent events and errors, and performance metrics.  It never contains keys.
+settings-health-open = Run a health check
+settings-support-bundle-include-addresses = Include my receiving addresses, user names and IP addresses
+settings-support-bundle-generate = Generate Support Bundle
+settings-advanced = Advanced
+settings-rpc-metrics = RPC Performance
+settings-rpc-metrics-intro = How long calls to neptune-core take, and which failed, since the app's server started.  Errors that neptune-core returns in an answer are not counted, only timeouts and lost connections.
+settings-rpc-metrics-none = No calls to neptune-core yet.
+settings-rpc-metrics-since = Since { $time }
+settings-rpc-metrics-method = Method
+settings-rpc-metrics-calls = Calls
+settings-rpc-metrics-errors = Errors
+settings-rpc-metrics-mean = Mean
+settings-rpc-metrics-max = Slowest
+settings-rpc-metrics-failures = Recent Failures
+settings-rpc-metrics-no-failures = No calls have failed.
+settings-rpc-metrics-refresh = Refresh
+settings-developer = Developer Tools
+settings-announcement-intro = Broadcasts a transaction carrying an arbitrary announcement, for testing announcement-based features.  The transaction only returns change to this wallet, less the fee.
+settings-announcement-encoding = Encoding
+settings-announcement-message = Announcement
+settings-announcement-elements = { $count } field elements
+settings-announcement-fee = Fee
+settings-announcement-broadcast = Broadcast Announcement
+settings-announcement-sent = Broadcast in transaction { $txid }
+
+## Notifications
+
+notification-title = Notifications
+notification-bell-title = { $count ->
+        [0] No unread notifications
+        [one] 1 unread notification
+       *[other] { $count } unread notifications
+    }
+notification-none = Nothing to report yet.
+notification-mark-all-read = Mark All Read
+notification-clear = Clear
+notification-funds-received = Received in block { $height }:
+notification-send-confirmed = Sent in block { $height }:
+notification-connection-lost = Lost the connection to neptune-core.
+notification-connection-restored = Reconnected to neptune-core.
+notification-low-fee = Transaction { $txid } has waited { $blocks } blocks to be mined. Its fee may be too low:
+notification-low-fee-action = View
+notification-token-rejected = neptune-core rejected this app's RPC token, even after reading its cookie again. Run this app as the same user as neptune-core, or check its data directory.
+notification-token-rejected-action = Health Check
+
+## Search
+
+search-label = Search
+search-placeholder = Block, transaction or address
+search-not-found = Nothing found.
+
+## Lock
+
+lock-title = Wallet Locked
+lock-intro = Enter your PIN to unlock the wallet.
+lock-pin-placeholder = PIN or passphrase
+lock-unlock = Unlock
+lock-wrong-pin = Incorrect PIN.
+lock-reauth-title = Confirm It's You
+lock-reauth-intro = Enter your PIN to continue.
+lock-idle-title = Paused While Away
+lock-idle-intro = The wallet was hidden after a period of inactivity.
+lock-idle-resume = Resume
+
+settings-security = Security
+settings-security-intro = A PIN or passphrase locks the wallet on startup and after a period of inactivity, and is asked for again before sending or displaying the seed phrase.
+settings-current-pin = Current PIN
+settings-new-pin = New PIN
+settings-confirm-pin = Confirm New PIN
+settings-set-pin = Set PIN
+settings-change-pin = Change PIN
+settings-remove-pin = Remove PIN
+settings-lock-now = Lock Now
+settings-pin-mismatch = The new PINs do not match.
+settings-pin-saved = PIN saved.
+settings-pin-removed = PIN removed.
+settings-idle-lock = Lock after inactivity
+settings-idle-lock-never = Never
+settings-idle-lock-minutes = { $minutes ->
+        [one] 1 minute
+       *[other] { $minutes } minutes
+    }
+settings-idle-blur = Blur after inactivity
+settings-idle-blur-help = Hides the screen until you return, eg on an unattended desktop. With a PIN set, the PIN is needed to resume.
+settings-address-check = Re-verify addresses for sends above (NPT)
+settings-address-check-off = Off
+settings-address-check-help = Larger sends ask for the end of each recipient address before they can be confirmed. Leave empty to turn off.
+
+settings-wallet-encryption = Wallet File
+settings-wallet-missing = There is no wallet file yet at { $file }. neptune-core creates one when it first starts.
+settings-wallet-not-encrypted = The wallet file is not encrypted: anyone who can read { $file } can spend its funds.
+settings-wallet-unreadable = The wallet file { $file } could not be read: { $error }
+settings-wallet-encryption-unsupported = This version of neptune-core cannot encrypt its wallet file. To protect it:
+settings-wallet-protect-disk = Turn on full-disk encryption, eg FileVault, BitLocker or LUKS.
+settings-wallet-protect-permissions = Make the wallet directory readable only by the account that runs neptune-core.
+settings-wallet-protect-backup = Keep the seed phrase written down somewhere safe, away from this machine.
+
+## Import Wallet
+
+import-wallet-title = Import Wallet
+import-wallet-intro = Enter the seed phrase of the wallet to restore, or scan it from a QR code. neptune-core then finds the wallet's funds as it syncs. An existing wallet is never replaced.
+import-wallet-words = Seed words
+import-wallet-words-placeholder = The words, separated by spaces
+import-wallet-word-count = { $count ->
+        [one] 1 word entered. Neptune seed phrases have 18 words.
+       *[other] { $count } words entered. Neptune seed phrases have 18 words.
+    }
+import-wallet-unknown-words = Not seed words: { $words }
+import-wallet-bad-checksum = The words do not form a valid seed phrase. Check their spelling and order.
+import-wallet-valid = Valid seed phrase.
+import-wallet-scan = Scan QR Code
+import-wallet-import = Import
+import-wallet-importing = Importing...
+import-wallet-written = The wallet was written to { $file }.
+import-wallet-node-restarted = neptune-core is restarting to use it.
+import-wallet-start-node = Start neptune-core, or restart it if it is running, to use it.
+import-wallet-already-in-use = This is the wallet in use already.
+import-wallet-other-exists = neptune-core already has another wallet, which is never replaced.
+import-wallet-step-backup = If that wallet may hold funds, export its seed phrase first, from Addresses.
+import-wallet-step-stop = Stop neptune-core.
+import-wallet-step-move = Move the wallet directory { $dir } somewhere safe.
+import-wallet-step-retry = Import again, then start neptune-core.
+
+## Health Check
+health-title = Health Check
+health-intro = Checks the connection to neptune-core, its RPC cookie, the data directory this app keeps its files in, and the price providers.
+health-checking = Checking…
+health-check-again = Check Again
+health-checked-at = Checked { $time }
+health-summary-ok = Everything the wallet needs is working.
+health-summary-failed = Some checks failed.
+health-rpc-connection = neptune-core connection
+health-auth-token = RPC cookie and token
+health-data-directory = Data directory
+health-price-providers = Price providers
+health-ok = OK
+health-warning = Warning
+health-failed = Failed
+health-skipped = Skipped
+
+## QR Scanner
+
+qr-scanner-permission-prompt = Waiting for permission to use the camera...
+qr-scanner-permission-denied = Camera access is blocked
+qr-scanner-permission-android = To scan, allow the camera in Settings > Apps > Neptune Proton > Permissions > Camera, then try again.
+qr-scanner-permission-ios = To scan, turn on Camera in Settings > Neptune Proton, then try again.
+qr-scanner-permission-browser = To scan, allow the camera for this site in your browser's site settings, usually behind the icon next to the address bar, then try again.
+qr-scanner-no-camera = No camera found
+qr-scanner-no-camera-help = This device has no camera that can be used for scanning.
+qr-scanner-use-file = You can read the QR code from a file instead.
+qr-scanner-try-camera = Try the Camera Again
+qr-scanner-camera = Camera
+qr-scanner-resolution = Resolution
+qr-scanner-torch = Torch
@@ -0,0 +1,651 @@
+## Shared
+
+common-amount-hidden = Los importes están ocultos. Muéstrelos con el botón del ojo en la cabecera.
+common-back = Atrás
+common-cancel = Cancelar
+common-clear-selection = Borrar selección
+common-close = Cerrar
+common-copied = Copiado al portapapeles.
+common-copy-failed = No se pudo copiar al portapapeles.
+common-copy-label = Copiar al portapapeles
+common-delete = Eliminar
+common-dont-ask-again = No volver a preguntar
+common-error = Error
+common-export-failed = Error al exportar: { $error }
+common-exported = Exportado.
+common-retry = Reintentar
+common-save = Guardar
+common-selected = { $count } seleccionados
+
+## Balance
+
+balance-title = Resumen de la cartera
+balance-load-failed = No se pudieron cargar los datos del panel: { $error }
+balance-confirmed = Saldo confirmado
+balance-unconfirmed = Saldo sin confirmar
+balance-available = Disponible
+balance-time-locked = Bloqueado por tiempo
+balance-total = Total
+balance-blockchain = Cadena de bloques
+balance-network = Red
+balance-status = Estado
+balance-syncing = Sincronizando...
+balance-synced = Sincronizado
+balance-tip = Punta
+balance-mempool = Mempool
+balance-transactions = Transacciones
+balance-size-bytes = Tamaño (bytes)
+balance-network-peers = Pares de la red
+balance-connected-peers = Pares conectados
+balance-max-peers = Máximo de pares
+balance-node-info = Información del nodo
+balance-mining-status = Estado de minería
+balance-proving-capability = Capacidad de prueba
+balance-attention = Requiere atención
+balance-attention-dismiss = Descartar
+balance-attention-drafts = { $count ->
+        [one] 1 borrador de envío sin enviar
+       *[other] { $count } borradores de envío sin enviar
+    }
+balance-attention-drafts-action = Revisar borradores
+balance-attention-stuck-tx = La transacción { $txid } no está sincronizada con la punta y no puede minarse así
+balance-attention-stuck-tx-action = Resolver
+balance-attention-unclaimed = { $count ->
+        [one] 1 UTXO esperado no ha llegado
+       *[other] { $count } UTXOs esperados no han llegado
+    }
+balance-attention-unclaimed-action = Ver UTXOs
+balance-assets = Activos
+balance-asset-utxos = { $count ->
+        [one] 1 UTXO
+       *[other] { $count } UTXOs
+    }
+balance-asset-no-amount = cantidad desconocida
+
+## Send
+
+send-add-recipients = Añadir destinatarios
+send-spendable-now = Disponible ahora:
+send-spendable-time-locked = Bloqueado temporalmente:
+send-spendable-next-release = próxima liberación el { $date }:
+send-spendable-do-not-spend = Marcado para no gastar:
+send-recipient-address = Dirección del destinatario
+send-address-placeholder = Haga clic para pegar o escanear una dirección...
+send-address-empty = Introduzca o escanee la dirección del destinatario.
+send-address-truncated = Esta dirección está incompleta. Copie la dirección entera, no una abreviada.
+send-address-malformed = Esto no es una dirección de Neptune.
+send-address-checksum = Esta dirección contiene un error: su suma de verificación no coincide.
+send-address-wrong-network = Esta dirección ({ $prefix }...) es de otra red. Esta cartera está en { $network }.
+send-address-unsupported = Esta cartera no puede pagar direcciones de este tipo ({ $prefix }...).
+send-amount-npt = Importe (NPT)
+send-amount-fiat = Importe ({ $currency })
+send-amount-positive = El importe debe ser > 0.
+send-toggle-currency = Alternar entre Neptune Cash (NPT) y { $name } ({ $code })
+send-done = Listo
+send-edit = Editar
+send-add-another-recipient = Añadir otro destinatario
+send-next-set-fee = Siguiente: comisión
+send-set-address = Establecer dirección
+send-choose-action = Elija una acción.
+send-choose-action-for = Elija una acción para el destinatario número { $number }.
+send-paste-address = Pegar dirección
+send-scan-qr = Escanear código QR
+send-upload-qr = Importar desde archivo
+send-recent-recipients = Destinatarios recientes
+send-recent-last-used = Último pago el { $date }
+send-recent-forget = Olvidar este destinatario
+send-invalid-payment-request = Solicitud de pago no válida en el QR: { $error }
+send-duplicate-title = Dirección duplicada
+send-duplicate-message = Esta dirección ya está en la lista de destinatarios. ¿Desea añadirla de nuevo?
+send-merge-duplicates-message = { $count ->
+        [one] 1 destinatario paga a una dirección que ya está en la lista. Unirlos en una sola salida reduce el tamaño de la transacción y la comisión.
+       *[other] { $count } destinatarios pagan a direcciones que ya están en la lista. Unirlos en salidas únicas reduce el tamaño de la transacción y la comisión.
+    }
+send-merge-duplicates = Unir
+send-proceed-anyway = Continuar de todos modos
+
+send-drafts = Borradores
+send-saved-drafts = Borradores guardados
+send-no-drafts = No hay borradores guardados.
+send-drafts-load-failed = No se pudieron cargar los borradores: { $error }
+send-draft-summary = { $recipients ->
+        [one] 1 destinatario
+       *[other] { $recipients } destinatarios
+    }, guardado { $saved }
+send-resume = Reanudar
+send-draft-foreign-currency = Este borrador tiene importes en { $currency }. Seleccione { $currency } como su moneda para reanudarlo.
+send-save-draft = Guardar borrador
+send-draft-name = Nombre
+send-draft-name-placeholder = p. ej. Alquiler, marzo
+send-draft-replaced = Se reemplaza cualquier borrador con el mismo nombre.
+
+send-set-fee = Comisión
+send-subtotal = Subtotal:{" "}
+send-fee-npt = Comisión (NPT)
+send-fee-fiat = Comisión ({ $currency })
+send-total-spend = Gasto total
+send-funds-short = No hay fondos disponibles suficientes. Faltan
+send-funds-available = Disponible ahora:
+send-funds-time-locked = Bloqueado por tiempo, aún no disponible:
+send-funds-unconfirmed = Entrante, esperando confirmación:
+send-funds-pending = En uso por sus transacciones sin confirmar:
+send-next-review = Siguiente: revisar
+
+send-review-title = Revisar transacción
+send-review-intro = Revise los detalles a continuación. Esta acción no se puede deshacer.
+send-address-check-title = Verificar direcciones
+send-address-check-intro = Este es un envío grande. Introduzca los últimos { $count } caracteres de cada dirección de destino, leídos de su origen, para protegerse de una dirección sustituida en el portapapeles.
+send-address-check-label = Destinatario { $number }
+send-recipients = Destinatarios:
+send-fee = Comisión:{" "}
+send-total-spend-label = Gasto total:{" "}
+send-estimated-suffix = {" "}aprox.)
+send-confirm = Confirmar y enviar
+send-large-title = Envío grande
+send-large-message = Está a punto de enviar más de la mitad del saldo de su cartera. ¿Desea continuar?
+send-send = Enviar
+
+send-sending = Enviando transacción...
+send-please-wait = Espere, por favor.
+send-stage-selecting-inputs = Seleccionando entradas
+send-stage-building = Creando la transacción
+send-stage-proving = Generando la prueba
+send-stage-broadcasting = Difundiendo
+send-stage-proving-hint = Generar la prueba puede tardar varios minutos, según el equipo que ejecuta el nodo.
+send-stage-proving-capability = El nodo genera pruebas como { $capability }.
+send-stage-retrying = Se perdió la conexión con neptune-core. Reintentando, intento { $attempt }.
+send-queue-heading = Envíos recientes
+send-queue-title = Envíos recientes, { $count } con un nuevo resultado
+send-queue-title-in-flight = { $count ->
+    [one] Se está enviando una transacción
+   *[other] Se están enviando { $count } transacciones
+}
+send-queue-recipients = { $count ->
+    [one] 1 destinatario,
+   *[other] { $count } destinatarios,
+}
+send-queue-fee = Comisión
+send-queue-in-flight = Se está creando y probando. Puede salir de esta pantalla mientras tanto.
+send-queue-interrupted = La aplicación se cerró antes de que terminara este envío. Es posible que la transacción se haya enviado igualmente.
+send-queue-check-history = Revisar el historial
+send-queue-clear = Borrar los terminados
+send-status-title = Estado de la transacción
+send-success = ¡Transacción enviada con éxito!
+send-transaction-id = ID de la transacción
+send-view-in-mempool = Ver en la mempool
+send-another = Enviar otra transacción
+send-error-title = Error al enviar la transacción
+send-failure-connection = No se pudo conectar con el nodo
+send-failure-funds = Fondos insuficientes
+send-failure-invalid = La transacción no es válida
+send-failure-broadcast-unknown = Es posible que la transacción se haya enviado
+send-edit-recipients = Editar destinatarios
+send-edit-fee = Editar comisión
+send-retry = Reintentar
+send-error-insufficient = El saldo disponible no cubre el monto y la comisión. Reduzca alguno de los dos o espere a que se confirmen los fondos entrantes.
+send-error-connection = No se pudo contactar con neptune-core. Revise la pantalla Mempool antes de volver a intentarlo, por si la transacción se envió.
+send-error-timeout = neptune-core no respondió a tiempo. La transacción aún podría enviarse, así que revise la pantalla Mempool antes de volver a intentarlo.
+send-error-invalid-address = Revise las direcciones de los destinatarios y que correspondan a esta red.
+send-error-auth = Se rechazó el envío. Es posible que su cuenta no tenga permiso para enviar o que las credenciales del nodo hayan cambiado.
+send-error-broadcast-unknown = La conexión con neptune-core se cortó mientras difundía la transacción. Revise las pantallas Mempool e Historial antes de volver a enviar, ya que enviar de nuevo podría pagar dos veces.
+send-error-cookie = Esta aplicación no puede leer la cookie RPC de neptune-core, por lo que no puede usar la cartera. Ejecútela con el mismo usuario que neptune-core y con acceso a su directorio de datos.
+send-advanced-options = Opciones avanzadas
+send-change-title = Enviar el cambio a
+send-change-next-symmetric = Una nueva clave simétrica de esta cartera (predeterminado)
+send-change-next-generation = Una nueva dirección de generación de esta cartera
+send-change-existing = Dirección existente { $address }
+send-change-exact = Ninguna parte: fallar salvo que las entradas igualen exactamente el importe y la comisión
+send-change-intro = El cambio es lo que queda de los UTXO gastados tras el importe y la comisión. Siempre se anuncia en la cadena, para que pueda recuperarse con la frase semilla.
+send-announcement-title = Anuncio público
+send-announcement-intro = Adjunta a la transacción un mensaje que cualquiera puede leer en la cadena de bloques, para siempre. Déjelo vacío para enviar sin anuncio. Un anuncio más grande puede requerir una comisión mayor.
+send-announcement-encoding = Codificación
+send-announcement-message = Mensaje
+send-announcement-elements = { $count } de un máximo de { $max } elementos de campo
+send-announcement-review = Anuncio público
+send-announcement-review-size = { $encoding }, { $count } elementos de campo
+send-coin-control = Elegir los UTXO a gastar
+send-coin-control-intro = Gasta exactamente los UTXO marcados, p. ej. para mantener algunas monedas aparte. Lo que contengan además del importe y la comisión se devuelve como cambio. Si no marca ninguno, la billetera elige. Los UTXO marcados como no gastables en la pantalla de UTXO no se pueden elegir.
+send-coin-control-none = Ahora no se puede gastar ningún UTXO.
+send-coin-control-do-not-spend = Marcado como no gastable
+send-coin-control-chosen = { $count ->
+        [one] 1 UTXO elegido, en total
+       *[other] { $count } UTXO elegidos, en total
+    }
+send-coin-control-short = No alcanza para el importe y la comisión. Faltan
+
+## Broadcast
+
+broadcast-title = Difundir transacción
+broadcast-intro = Envíe una transacción firmada en otro lugar, p. ej. por otra billetera.  Pegue su hex, o abra un archivo que lo contenga.  El nodo la valida antes de difundirla.
+broadcast-placeholder = Transacción firmada, en hex
+broadcast-size = { $bytes } bytes
+broadcast-open-file = Abrir archivo
+broadcast-submit = Difundir
+broadcast-broadcasting = Difundiendo...
+broadcast-done = Transacción difundida: { $txid }
+broadcast-rejected = La transacción fue rechazada
+
+## Node Stats
+
+node-stats-title = Estadísticas del nodo
+node-stats-intro = Se muestrea cada minuto mientras neptune-proton está en ejecución.  Se guarda una semana de historial.
+node-stats-window-hour = 1 hora
+node-stats-window-day = 24 horas
+node-stats-window-week = 7 días
+node-stats-block-interval = Intervalo entre bloques
+node-stats-mempool-size = Tamaño del mempool
+node-stats-peer-count = Número de pares
+node-stats-sync-progress = Progreso de sincronización
+node-stats-axis-time = Hora
+node-stats-axis-minutes = Minutos
+node-stats-axis-kb = kB
+node-stats-axis-peers = Pares
+node-stats-axis-height = Altura de bloque
+node-stats-no-data = Aún no hay suficientes muestras.
+
+## Logs
+
+logs-title = Registros
+logs-intro = Entradas recientes del registro del servidor de neptune-proton, las más nuevas primero.  Cambiar el nivel también cambia lo que el servidor registra a partir de ahora.
+logs-level = Nivel de registro
+logs-level-error = Error
+logs-level-warn = Advertencia
+logs-level-info = Información
+logs-level-debug = Depuración
+logs-level-trace = Traza
+logs-pause = Pausar
+logs-resume = Reanudar
+logs-clear = Limpiar
+logs-none = Aún no hay entradas de registro.
+logs-col-time = Hora
+logs-col-level = Nivel
+logs-col-source = Origen
+logs-col-message = Mensaje
+
+## Node Logs
+
+node-logs-title = Salida de neptune-core
+node-logs-restart = Reiniciar
+node-logs-none = Aún no hay salida.
+node-logs-state-disabled = Esta aplicación no ejecuta neptune-core.
+node-logs-state-running = En ejecución (pid { $pid }) desde las { $since }.
+node-logs-state-starting = Iniciado (pid { $pid }), esperando su servidor RPC…
+node-logs-state-restarting = Detenido.  Se iniciará de nuevo a las { $at }.
+node-logs-state-stopping = Deteniendo…
+node-logs-restarts = { $count ->
+        [one] (reiniciado una vez)
+       *[other] (reiniciado { $count } veces)
+    }
+node-logs-last-exit = Última detención: { $reason }
+
+## Receive
+
+receive-title = Recibir fondos
+receive-select-type = Seleccione el tipo de dirección:
+receive-type-generation = Generación
+receive-type-symmetric = Clave simétrica
+receive-type-size = Tamaño
+receive-type-sharing = Compartir
+receive-type-scanning = Quién puede encontrar los pagos
+receive-generation-size = Larga, de unos 4.000 caracteres. Es mejor compartirla como código QR o copiándola y pegándola.
+receive-generation-sharing = Puede compartirla con cualquiera que le pague. Solo le permite pagarle.
+receive-generation-scanning = Solo esta billetera puede encontrar y gastar los pagos que recibe.
+receive-symmetric-size = Corta. Los pagos que recibe ocupan menos espacio en la cadena de bloques.
+receive-symmetric-sharing = No la comparta nunca. Sirve para mover fondos entre billeteras que usted controla.
+receive-symmetric-scanning = Cualquiera que la tenga puede encontrar y gastar los pagos que recibe.
+receive-symmetric-acknowledge = Entiendo que las claves simétricas solo deben usarse para enviar entre carteras que controlo. Compartirlas con otros les permitiría gastar mis fondos.
+receive-generate = Generar nueva dirección de recepción
+receive-generating = Generando...
+receive-pending-retry = Reintento pendiente...
+receive-connection-lost = Conexión perdida. Se reintentará cuando se restablezca la conexión...
+receive-symmetric-do-not-share = No la comparta con nadie.
+receive-symmetric-notice = Esta es una clave/dirección simétrica.
+receive-symmetric-risk = Cualquiera que la posea puede gastar los fondos asociados.
+receive-share-address = Comparta esta dirección para recibir fondos.
+receive-scan-payment-request = Escanee el código QR para obtener la solicitud de pago.
+receive-scan-address = Escanee el código QR para obtener la dirección completa.
+receive-request-amount = Solicitar un importe concreto
+receive-amount-npt = Importe (NPT)
+receive-optional = Opcional
+receive-label = Etiqueta
+receive-label-placeholder = Opcional, p. ej. número de factura
+receive-generate-another = Generar otra
+receive-share = Compartir
+receive-share-title = Mi dirección de Neptune
+receive-share-copied = No es posible compartir, así que se ha copiado la dirección.
+
+receive-expected-title = Entrantes
+receive-expected-none = No hay pagos entrantes. Un pago aparece aquí en cuanto llega al mempool.
+receive-expected-transaction = Transacción
+receive-expected-amount = Importe
+receive-expected-status = Estado
+receive-expected-in-mempool = En el mempool
+receive-expected-updating = En el mempool, actualizando la prueba
+receive-expected-unclaimed = { $count ->
+        [one] 1 UTXO esperado más aún no ha llegado en un bloque.
+       *[other] { $count } UTXO esperados más aún no han llegado en un bloque.
+    }
+
+## History
+
+history-title = Historial
+history-load-failed = No se pudo cargar el historial: { $error }
+history-empty-title = Sin transacciones
+history-empty-description = Aún no tiene transacciones confirmadas en un bloque. También puede consultar la mempool para ver transacciones sin confirmar.
+history-fiat-at-time = Valor fiat en la fecha de la transacción
+history-copy-csv = Copiar como CSV
+history-export-selected = Exportar selección
+history-date = Fecha
+history-type = Tipo
+history-amount = Importe
+history-value-at-time = Valor en la fecha
+history-value-at-time-tooltip = Valor el día en que se confirmó la transacción
+history-block = Bloque
+history-received = Recibido
+history-sent = Enviado
+history-pending = Pendiente
+history-pending-tooltip = En la mempool, aún no confirmada en un bloque (0 confirmaciones)
+history-confirmations = Confirmaciones
+history-price-unavailable = Precio aún no disponible
+history-unconfirmed-note = Nota: las transacciones pendientes aparecen primero hasta que se confirman en un bloque.
+history-annotate = Etiqueta / Contacto
+history-annotate-title = Etiquetar { $count } transacciones
+history-tag = Etiqueta
+history-tag-placeholder = p. ej. supermercado
+history-contact = Contacto
+history-contact-placeholder = p. ej. Alicia
+history-annotate-hint = Solo se cambian los campos que edite.  Deje un campo vacío para borrarlo.
+
+## Transaction Notes
+
+tx-note-title = Nota de la transacción
+tx-note-label = Nota
+tx-note-placeholder = p. ej. Alquiler de mayo
+tx-note-private = Se guarda solo en este equipo, nunca se difunde.
+tx-note-add = Añadir nota
+tx-note-edit = Editar nota
+
+## Reports
+
+reports-title = Informes de gastos
+reports-empty-title = Sin gastos
+reports-empty-description = Las transacciones enviadas desde esta cartera se resumen aquí por mes.
+reports-group-by = Agrupar por
+reports-tag = Etiqueta
+reports-contact = Contacto
+reports-monthly-totals = Totales mensuales
+reports-month-breakdown = Gastos en { $month }
+reports-category = Categoría
+reports-transactions = Transacciones
+reports-share = Proporción
+reports-total = Total
+reports-untagged = Sin etiqueta
+reports-no-contact = Sin contacto
+reports-partial-prices = * Algunas transacciones aún no tienen precio para su día y no se incluyen en el valor fiat.
+reports-hint = Asigne etiquetas y contactos seleccionando transacciones en la pantalla Historial.
+reports-year-title = Resumen anual
+reports-year = Año
+reports-year-received = Recibido
+reports-year-sent = Enviado, con comisiones
+reports-year-fees = Comisiones
+reports-year-fees-hint = Las comisiones solo se conocen para los envíos hechos con esta aplicación que siguen en su lista de envíos. Los importes se compensan por bloque, por lo que un envío a sí mismo cuenta solo la comisión.
+reports-year-export-csv = Exportar CSV
+reports-year-print = Imprimir o guardar como PDF
+
+## Onboarding
+
+onboarding-title = Bienvenido a Neptune Wallet
+onboarding-step = Paso { $step } de { $steps }
+onboarding-skip = Omitir configuración
+onboarding-next = Siguiente
+onboarding-finish = Finalizar
+onboarding-details = Detalles
+onboarding-unreachable-intro = Esta cartera funciona con neptune-core, el nodo de Neptune, al que no se pudo conectar. Estos pasos le ayudan a iniciarlo y a conectarse a él.
+onboarding-new-wallet-intro = Su cartera es nueva. Estos pasos comprueban la conexión con neptune-core y le permiten elegir cómo se muestran los importes.
+onboarding-network = Red
+onboarding-network-intro = Elija la red a la que se unirá neptune-core.
+onboarding-network-main = la red principal, para fondos reales
+onboarding-network-testnet = una red de pruebas, para probar con monedas sin valor
+onboarding-network-regtest = una red de pruebas local, para desarrollo
+onboarding-node = Iniciar neptune-core
+onboarding-node-install = Instale neptune-core, si aún no lo ha hecho, desde su página de versiones:
+onboarding-node-start = Inícielo con:
+onboarding-node-port = Esta aplicación se conecta al puerto RPC de neptune-core, 9799 salvo que la variable de entorno NEPTUNE_CORE_RPC_PORT indique otro.
+onboarding-node-data-dir = Si neptune-core guarda sus datos en otro lugar distinto del predeterminado, p. ej. con --data-dir, el siguiente paso le permite indicárselo a esta aplicación.
+onboarding-connection = Probar la conexión
+onboarding-connection-test = Probar de nuevo
+onboarding-connection-testing = Conectando con neptune-core…
+onboarding-connection-ok = Conectado a neptune-core en { $network }.
+onboarding-connection-network-mismatch = neptune-core funciona en { $network }, no en { $chosen } como eligió. Reinícielo con --network { $chosen } si no era lo previsto.
+onboarding-connection-unreachable = Nada respondió en el puerto RPC { $port }. Compruebe que neptune-core está en marcha y ha terminado de iniciarse.
+onboarding-connection-cookie = neptune-core respondió, pero esta aplicación no puede leer su cookie RPC. Ejecute esta aplicación con el mismo usuario que neptune-core, o elija su directorio de datos abajo.
+onboarding-connection-checks = Todas las comprobaciones
+onboarding-currency = Moneda
+onboarding-currency-intro = Elija una moneda en la que mostrar los importes junto a NPT. Los precios provienen de proveedores de precios públicos.
+onboarding-currency-npt-only = Solo NPT
+
+## Settings
+
+settings-title = Ajustes
+settings-language = Idioma
+settings-amounts = Importes
+settings-amount-decimals = Decimales mostrados
+settings-amount-decimals-all = Todos
+settings-amount-decimals-count = { $count }
+settings-amount-rounding = Los decimales adicionales se
+settings-amount-rounding-round = Redondean
+settings-amount-rounding-truncate = Truncan
+settings-amount-help = Se aplica a los importes en NPT. Las tablas muestran como máximo 4 decimales. Pase el cursor sobre un importe para verlo exacto.
+settings-confirmations = Confirmaciones
+settings-confirmations-intro = Pedir confirmación antes de:
+settings-confirm-duplicate-address = Añadir una dirección de destinatario que ya está en la lista
+settings-confirm-clear-standing = Borrar la reputación de los pares
+settings-confirm-large-send = Enviar más de la mitad del saldo de la cartera
+settings-startup-checks = Comprobaciones al iniciar
+settings-startup-checks-intro = Al iniciar la aplicación, buscar:
+settings-startup-check-drafts = Borradores de envío sin enviar
+settings-startup-check-stuck = Transacciones propias atascadas en la mempool
+settings-startup-check-unclaimed = UTXOs esperados que no han llegado
+settings-refresh = Intervalos de actualización
+settings-refresh-intro = Con qué frecuencia cada pantalla obtiene datos nuevos del nodo mientras se muestra.  Los intervalos más largos consumen menos datos.
+settings-refresh-seconds = { $seconds ->
+        [one] Cada segundo
+       *[other] Cada { $seconds } segundos
+    }
+settings-refresh-default = { $seconds ->
+        [one] Cada segundo (predeterminado)
+       *[other] Cada { $seconds } segundos (predeterminado)
+    }
+settings-data-dir = Directorio de datos de neptune-core
+settings-data-dir-intro = Dónde esta aplicación lee la cookie RPC y los archivos de la cartera de neptune-core.  Déjelo vacío para usar el directorio que indica neptune-core.  Elija el directorio que se pasó a neptune-core con --data-dir si esta aplicación lo ve en otra ruta.
+settings-data-dir-in-use = En uso: { $path }
+settings-data-dir-from-env = Definido por la variable de entorno NEPTUNE_DATA_DIR.
+settings-data-dir-placeholder = El directorio que indica neptune-core
+settings-data-dir-browse = Examinar…
+settings-data-dir-reset = Usar el de neptune-core
+settings-data-dir-saved = Directorio de datos guardado.
+settings-data-dir-failed = No se puede usar ese directorio: { $error }
+settings-data-dir-load-failed = No se pudo leer el directorio de datos: { $error }
+settings-managed-node = Ejecutar neptune-core
+settings-managed-node-intro = Permita que esta aplicación inicie neptune-core y lo vuelva a iniciar si se detiene.  Su salida se muestra en Node Logs.  Si indica --rpc-port, debe coincidir con el NEPTUNE_CORE_RPC_PORT con el que se inició esta aplicación.
+settings-managed-node-enable = Ejecutar neptune-core desde esta aplicación
+settings-managed-node-binary = Programa neptune-core
+settings-managed-node-args = Argumentos
+settings-managed-node-args-hint = Separados por espacios, por ejemplo --network main --data-dir /ruta
+settings-managed-node-saved = Guardado.  neptune-core se reinicia con la nueva configuración.
+settings-managed-node-failed = No se pudo guardar: { $error }
+settings-managed-node-load-failed = No se pudo leer la configuración: { $error }
+settings-managed-node-logs = Ver Node Logs
+settings-sessions = Dispositivos y sesiones
+settings-sessions-load-failed = No se pudieron cargar las sesiones: { $error }
+settings-session-device = Dispositivo
+settings-session-ip = Dirección IP
+settings-session-last-active = Última actividad
+settings-session-current = Este dispositivo
+settings-session-revoke = Revocar
+settings-session-revoke-failed = No se pudo revocar la sesión: { $error }
+settings-metadata = Etiquetas
+settings-metadata-intro = Exporte las etiquetas de direcciones, las etiquetas y contactos de transacciones y las etiquetas de UTXO en el formato de metadatos de billetera de Neptune, para usarlos en otras herramientas de Neptune.  Al importar se reemplazan las entradas de la misma dirección, transacción o UTXO, y se conservan las demás.
+settings-metadata-export = Exportar
+settings-metadata-import = Importar
+settings-metadata-imported = Importadas { $addresses } etiquetas de direcciones, { $transactions } anotaciones de transacciones y { $utxos } anotaciones de UTXO.  Omitidas { $skipped } entradas no válidas.
+settings-metadata-import-failed = Error al importar: { $error }
+settings-import-wallet = Importar billetera
+settings-import-wallet-intro = Restaure una billetera a partir de su frase semilla, p. ej. para trasladarla a este equipo.
+settings-import-wallet-open = Importar una billetera a partir de su frase semilla
+settings-diagnostics = Diagnóstico
+settings-support-bundle-intro = Un paquete de soporte contiene la versión de la aplicación, el estado del nodo, la configuración, los eventos y errores recientes y métricas de rendimiento.  Nunca contiene claves.
+settings-health-open = Ejecutar una comprobación de estado
+settings-support-bundle-include-addresses = Incluir mis direcciones de recepción, nombres de usuario y direcciones IP
+settings-support-bundle-generate = Generar paquete de soporte
+settings-advanced = Avanzado
+settings-rpc-metrics = Rendimiento RPC
+settings-rpc-metrics-intro = Cuánto tardan las llamadas a neptune-core y cuáles fallaron desde que se inició el servidor de la aplicación.  Los errores que neptune-core devuelve en una respuesta no se cuentan, solo los tiempos de espera agotados y las conexiones perdidas.
+settings-rpc-metrics-none = Aún no hay llamadas a neptune-core.
+settings-rpc-metrics-since = Desde { $time }
+settings-rpc-metrics-method = Método
+settings-rpc-metrics-calls = Llamadas
+settings-rpc-metrics-errors = Errores
+settings-rpc-metrics-mean = Media
+settings-rpc-metrics-max = Más lenta
+settings-rpc-metrics-failures = Fallos recientes
+settings-rpc-metrics-no-failures = Ninguna llamada ha fallado.
+settings-rpc-metrics-refresh = Actualizar
+settings-developer = Herramientas de desarrollo
+settings-announcement-intro = Difunde una transacción con un anuncio arbitrario, para probar funciones basadas en anuncios.  La transacción solo devuelve el cambio a esta billetera, menos la comisión.
+settings-announcement-encoding = Codificación
+settings-announcement-message = Anuncio
+settings-announcement-elements = { $count } elementos de campo
+settings-announcement-fee = Comisión
+settings-announcement-broadcast = Difundir anuncio
+settings-announcement-sent = Difundido en la transacción { $txid }
+
+## Notifications
+
+notification-title = Notificaciones
+notification-bell-title = { $count ->
+        [0] No hay notificaciones sin leer
+        [one] 1 notificación sin leer
+       *[other] { $count } notificaciones sin leer
+    }
+notification-none = Aún no hay nada que informar.
+notification-mark-all-read = Marcar todo como leído
+notification-clear = Borrar
+notification-funds-received = Recibido en el bloque { $height }:
+notification-send-confirmed = Enviado en el bloque { $height }:
+notification-connection-lost = Se perdió la conexión con neptune-core.
+notification-connection-restored = Conexión con neptune-core restablecida.
+notification-low-fee = La transacción { $txid } lleva { $blocks } bloques esperando a ser minada. Es posible que su comisión sea demasiado baja:
+notification-low-fee-action = Ver
+notification-token-rejected = neptune-core rechazó el token RPC de esta aplicación, incluso después de volver a leer su cookie. Ejecute esta aplicación con el mismo usuario que neptune-core o revise su directorio de datos.
+notification-token-rejected-action = Comprobación de estado
+
+## Search
+
+search-label = Buscar
+search-placeholder = Bloque, transacción o dirección
+search-not-found = No se encontró nada.
+
+## Lock
+
+lock-title = Cartera bloqueada
+lock-intro = Introduzca su PIN para desbloquear la cartera.
+lock-pin-placeholder = PIN o frase de contraseña
+lock-unlock = Desbloquear
+lock-wrong-pin = PIN incorrecto.
+lock-reauth-title = Confirme su identidad
+lock-reauth-intro = Introduzca su PIN para continuar.
+lock-idle-title = En pausa durante su ausencia
+lock-idle-intro = La cartera se ocultó tras un periodo de inactividad.
+lock-idle-resume = Continuar
+
+settings-security = Seguridad
+settings-security-intro = Un PIN o frase de contraseña bloquea la cartera al iniciar y tras un periodo de inactividad, y se vuelve a pedir antes de enviar o mostrar la frase semilla.
+settings-current-pin = PIN actual
+settings-new-pin = Nuevo PIN
+settings-confirm-pin = Confirmar nuevo PIN
+settings-set-pin = Establecer PIN
+settings-change-pin = Cambiar PIN
+settings-remove-pin = Eliminar PIN
+settings-lock-now = Bloquear ahora
+settings-pin-mismatch = Los nuevos PIN no coinciden.
+settings-pin-saved = PIN guardado.
+settings-pin-removed = PIN eliminado.
+settings-idle-lock = Bloquear tras inactividad
+settings-idle-lock-never = Nunca
+settings-idle-lock-minutes = { $minutes ->
+        [one] 1 minuto
+       *[other] { $minutes } minutos
+    }
+settings-idle-blur = Difuminar tras inactividad
+settings-idle-blur-help = Oculta la pantalla hasta que vuelva, por ejemplo en un ordenador desatendido. Si hay un PIN configurado, se necesita para continuar.
+settings-address-check = Volver a verificar direcciones en envíos superiores a (NPT)
+settings-address-check-off = Desactivado
+settings-address-check-help = Los envíos mayores piden el final de cada dirección de destino antes de poder confirmarse. Déjelo vacío para desactivarlo.
+
+settings-wallet-encryption = Archivo de la billetera
+settings-wallet-missing = Todavía no hay archivo de billetera en { $file }. neptune-core lo crea la primera vez que se inicia.
+settings-wallet-not-encrypted = El archivo de la billetera no está cifrado: quien pueda leer { $file } puede gastar sus fondos.
+settings-wallet-unreadable = No se pudo leer el archivo de la billetera { $file }: { $error }
+settings-wallet-encryption-unsupported = Esta versión de neptune-core no puede cifrar su archivo de billetera. Para protegerlo:
+settings-wallet-protect-disk = Active el cifrado de disco completo, p. ej. FileVault, BitLocker o LUKS.
+settings-wallet-protect-permissions = Haga que el directorio de la billetera solo sea legible por la cuenta que ejecuta neptune-core.
+settings-wallet-protect-backup = Guarde la frase semilla por escrito en un lugar seguro, lejos de este equipo.
+
+## Import Wallet
+
+import-wallet-title = Importar billetera
+import-wallet-intro = Introduzca la frase semilla de la billetera que desea restaurar, o escanéela desde un código QR. neptune-core encontrará los fondos de la billetera al sincronizar. Nunca se reemplaza una billetera existente.
+import-wallet-words = Palabras semilla
+import-wallet-words-placeholder = Las palabras, separadas por espacios
+import-wallet-word-count = { $count ->
+        [one] 1 palabra introducida. Las frases semilla de Neptune tienen 18 palabras.
+       *[other] { $count } palabras introducidas. Las frases semilla de Neptune tienen 18 palabras.
+    }
+import-wallet-unknown-words = No son palabras semilla: { $words }
+import-wallet-bad-checksum = Las palabras no forman una frase semilla válida. Compruebe su ortografía y orden.
+import-wallet-valid = Frase semilla válida.
+import-wallet-scan = Escanear código QR
+import-wallet-import = Importar
+import-wallet-importing = Importando...
+import-wallet-written = La billetera se escribió en { $file }.
+import-wallet-node-restarted = neptune-core se está reiniciando para usarla.
+import-wallet-start-node = Inicie neptune-core, o reinícielo si está en marcha, para usarla.
+import-wallet-already-in-use = Esta ya es la billetera en uso.
+import-wallet-other-exists = neptune-core ya tiene otra billetera, que nunca se reemplaza.
+import-wallet-step-backup = Si esa billetera puede tener fondos, exporte primero su frase semilla desde Direcciones.
+import-wallet-step-stop = Detenga neptune-core.
+import-wallet-step-move = Mueva el directorio de la billetera { $dir } a un lugar seguro.
+import-wallet-step-retry = Vuelva a importar y luego inicie neptune-core.
+
+## Health Check
+health-title = Comprobación de estado
+health-intro = Comprueba la conexión con neptune-core, su cookie RPC, el directorio de datos donde esta aplicación guarda sus archivos y los proveedores de precios.
+health-checking = Comprobando…
+health-check-again = Comprobar de nuevo
+health-checked-at = Comprobado { $time }
+health-summary-ok = Todo lo que necesita la billetera funciona.
+health-summary-failed = Algunas comprobaciones fallaron.
+health-rpc-connection = Conexión con neptune-core
+health-auth-token = Cookie RPC y token
+health-data-directory = Directorio de datos
+health-price-providers = Proveedores de precios
+health-ok = Correcto
+health-warning = Advertencia
+health-failed = Fallido
+health-skipped = Omitido
+
+## QR Scanner
+
+qr-scanner-permission-prompt = Esperando permiso para usar la cámara...
+qr-scanner-permission-denied = El acceso a la cámara está bloqueado
+qr-scanner-permission-android = Para escanear, permita la cámara en Ajustes > Aplicaciones > Neptune Proton > Permisos > Cámara y vuelva a intentarlo.
+qr-scanner-permission-ios = Para escanear, active la cámara en Ajustes > Neptune Proton y vuelva a intentarlo.
+qr-scanner-permission-browser = Para escanear, permita la cámara para este sitio en la configuración del sitio de su navegador, normalmente tras el icono junto a la barra de direcciones, y vuelva a intentarlo.
+qr-scanner-no-camera = No se encontró ninguna cámara
+qr-scanner-no-camera-help = Este dispositivo no tiene ninguna cámara que pueda usarse para escanear.
+qr-scanner-use-file = Puede leer el código QR desde un archivo.
+qr-scanner-try-camera = Volver a intentar con la cámara
+qr-scanner-camera = Cámara
+qr-scanner-resolution = Resolución
+qr-scanner-torch = Linterna
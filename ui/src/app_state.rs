@@ -2,12 +2,14 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use api::price_map::PriceMap;
+use api::wallet_capabilities::WalletCapabilities;
 use neptune_types::network::Network;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct AppStateData {
     pub network: Network,
     pub price_map: PriceMap,
+    pub capabilities: WalletCapabilities,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -22,10 +24,11 @@ impl Deref for AppState {
 }
 
 impl AppState {
-    pub fn new(network: Network) -> Self {
+    pub fn new(network: Network, capabilities: WalletCapabilities) -> Self {
         Self(Arc::new(AppStateData {
             network,
             price_map: Default::default(),
+            capabilities,
         }))
     }
 }
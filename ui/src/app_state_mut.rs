@@ -1,8 +1,20 @@
 //! Defines the mutable, reactive state for the application's UI.
 
+use api::attention::AttentionItem;
+use api::notification::Notification;
+use api::prefs::amount_precision::AmountPrecision;
+use api::prefs::block_explorer::BlockExplorer;
+use api::prefs::confirmation::Confirmation;
+use api::prefs::decimal_separator::DecimalSeparator;
 use api::prefs::display_preference::DisplayPreference;
+use api::prefs::language::Language;
+use api::prefs::refresh_intervals::RefreshIntervals;
+use api::prefs::refresh_intervals::RefreshScreen;
+use api::prefs::startup_check::StartupCheck;
+use api::prefs::thousands_separator::ThousandsSeparator;
 use api::price_map::PriceMap;
 use dioxus::prelude::*;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
 
 /// A reactive state provided as a Dioxus context for mutable UI data.
 ///
@@ -16,4 +28,168 @@ pub struct AppStateMut {
 
     /// A single signal to manage the user's complete currency display preference.
     pub display_preference: Signal<DisplayPreference>,
+
+    /// The decimal separator used when displaying and entering amounts.
+    pub decimal_separator: Signal<DecimalSeparator>,
+
+    /// The separator between digit groups when displaying amounts.
+    pub thousands_separator: Signal<ThousandsSeparator>,
+
+    /// How many decimals of NPT amounts are shown.
+    pub amount_precision: Signal<AmountPrecision>,
+
+    /// True while on a metered connection. Background polling is throttled.
+    pub metered: Signal<bool>,
+
+    /// False while the window is hidden or minimized.  Background polling
+    /// and the QR scanner pause meanwhile.
+    pub visible: Signal<bool>,
+
+    /// The external block explorer to link to. `None` hides explorer links.
+    pub block_explorer: Signal<Option<BlockExplorer>>,
+
+    /// Confirmation dialogs the user asked not to be shown again.
+    pub suppressed_confirmations: Signal<Vec<Confirmation>>,
+
+    /// The language of the user interface.
+    pub language: Signal<Language>,
+
+    /// True if a PIN locks the app.
+    pub pin_set: Signal<bool>,
+
+    /// True while the lock screen is shown.
+    pub locked: Signal<bool>,
+
+    /// Minutes of inactivity after which the app locks.  0 never locks.
+    pub idle_lock_minutes: Signal<u32>,
+
+    /// True while the app is blurred for inactivity.
+    pub blurred: Signal<bool>,
+
+    /// Minutes of inactivity after which the app blurs.  0 never blurs.
+    pub idle_blur_minutes: Signal<u32>,
+
+    /// Sends above this amount require re-entering the end of each recipient
+    /// address.  0 never does.
+    pub address_check_threshold: Signal<NativeCurrencyAmount>,
+
+    /// How often each screen polls while shown.
+    pub refresh_intervals: Signal<RefreshIntervals>,
+
+    /// False while the user has paused background polling.
+    pub refresh_enabled: Signal<bool>,
+
+    /// True while amounts are masked with ••••.
+    pub hide_amounts: Signal<bool>,
+
+    /// Startup checks the user turned off.
+    pub disabled_startup_checks: Signal<Vec<StartupCheck>>,
+
+    /// What the startup checks found.  Cleared when the user dismisses it.
+    pub attention_items: Signal<Vec<AttentionItem>>,
+
+    /// Notifications raised by the server, newest first.
+    pub notifications: Signal<Vec<Notification>>,
+}
+
+impl AppStateMut {
+    /// True if the user asked not to be shown `confirmation` again.
+    ///
+    /// Does not subscribe to changes, as it is intended for event handlers.
+    pub fn is_suppressed(&self, confirmation: Confirmation) -> bool {
+        self.suppressed_confirmations.peek().contains(&confirmation)
+    }
+
+    /// Sets whether `confirmation` is shown, and saves the choice.
+    pub fn set_suppressed(&self, confirmation: Confirmation, suppressed: bool) {
+        let mut confirmations = self.suppressed_confirmations;
+        confirmations.with_mut(|c| {
+            c.retain(|x| *x != confirmation);
+            if suppressed {
+                c.push(confirmation);
+            }
+        });
+        spawn(async move {
+            if let Ok(saved) = api::set_confirmation_suppressed(confirmation, suppressed).await {
+                confirmations.set(saved);
+            }
+        });
+    }
+
+    /// Sets whether `check` runs at startup, and saves the choice.
+    pub fn set_startup_check_enabled(&self, check: StartupCheck, enabled: bool) {
+        let mut checks = self.disabled_startup_checks;
+        checks.with_mut(|c| {
+            c.retain(|x| *x != check);
+            if !enabled {
+                c.push(check);
+            }
+        });
+        spawn(async move {
+            if let Ok(saved) = api::set_startup_check_enabled(check, enabled).await {
+                checks.set(saved);
+            }
+        });
+    }
+
+    /// Sets how often `screen` refreshes, and saves the choice.
+    pub fn set_refresh_interval(&self, screen: RefreshScreen, secs: u32) {
+        let mut intervals = self.refresh_intervals;
+        intervals.with_mut(|i| i.set(screen, secs));
+        spawn(async move {
+            if let Ok(saved) = api::set_refresh_interval(screen, secs).await {
+                intervals.set(saved);
+            }
+        });
+    }
+
+    /// Pauses or resumes all background polling, and saves the choice.
+    pub fn set_refresh_enabled(&self, enabled: bool) {
+        let mut refresh_enabled = self.refresh_enabled;
+        refresh_enabled.set(enabled);
+        spawn(async move {
+            let _ = api::set_refresh_enabled(enabled).await;
+        });
+    }
+
+    /// Masks or shows all amounts, and saves the choice.
+    pub fn set_hide_amounts(&self, hide: bool) {
+        let mut hide_amounts = self.hide_amounts;
+        hide_amounts.set(hide);
+        spawn(async move {
+            let _ = api::set_hide_amounts(hide).await;
+        });
+    }
+
+    /// Changes how NPT amounts are shortened, and saves the choice.
+    pub fn set_amount_precision(&self, precision: AmountPrecision) {
+        let mut amount_precision = self.amount_precision;
+        amount_precision.set(precision);
+        spawn(async move {
+            let _ = api::set_amount_precision(precision).await;
+        });
+    }
+
+    /// Shows the lock screen, if a PIN is set.
+    pub fn lock(&self) {
+        let mut locked = self.locked;
+        if *self.pin_set.peek() {
+            locked.set(true);
+        }
+    }
+
+    /// Switches the UI language, and saves the choice.
+    pub fn set_language(&self, language: Language) {
+        let mut language_signal = self.language;
+        let mut decimal_separator = self.decimal_separator;
+        let mut thousands_separator = self.thousands_separator;
+        language_signal.set(language);
+        spawn(async move {
+            // the separators may follow the language.
+            if let Ok(prefs) = api::set_language(language).await {
+                decimal_separator.set(prefs.decimal_separator());
+                thousands_separator.set(prefs.thousands_separator());
+            }
+        });
+    }
 }
@@ -79,7 +79,9 @@ pub mod wasm32 {
         js_value.as_string()
     }
 
-    pub async fn read_file(extension: &str) -> Result<Option<String>, String> {
+    /// Prompts the user to select a file with one of `extensions` and reads
+    /// it as text.  Returns the file's name and content.
+    pub async fn read_file(extensions: &[&str]) -> Result<Option<(String, String)>, String> {
         let (tx, rx) = oneshot::channel();
         let window = web_sys::window().expect("no window");
         let document = window.document().expect("no document");
@@ -90,7 +92,13 @@ pub mod wasm32 {
             .dyn_into()
             .map_err(|_| "Failed to cast to HtmlInputElement".to_string())?;
         input.set_type("file");
-        input.set_accept(&format!(".{}", extension));
+        input.set_accept(
+            &extensions
+                .iter()
+                .map(|ext| format!(".{ext}"))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
         /*
                 input
                     .dyn_ref::<HtmlElement>()
@@ -103,11 +111,12 @@ pub mod wasm32 {
         let onchange_closure = Closure::once(move |event: web_sys::Event| {
             let input: HtmlInputElement = event.target().unwrap().dyn_into().unwrap();
             if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                let name = file.name();
                 let reader = FileReader::new().unwrap();
                 let reader_clone = reader.clone();
                 let onload_closure = Closure::once(move |_: web_sys::ProgressEvent| {
                     let result = reader_clone.result().unwrap();
-                    let _ = tx.send(Ok(result.as_string()));
+                    let _ = tx.send(Ok(result.as_string().map(|content| (name, content))));
                 });
                 reader.set_onload(Some(onload_closure.as_ref().unchecked_ref()));
                 reader.read_as_text(&file).unwrap();
@@ -127,6 +136,80 @@ pub mod wasm32 {
 
         rx.await.map_err(|e| e.to_string())?
     }
+
+    /// Reports whether the browser considers the connection metered, eg
+    /// cellular or with data saver enabled. None if the browser does not say.
+    pub fn connection_is_metered() -> Option<bool> {
+        let navigator = web_sys::window()?.navigator();
+        let connection = js_sys::Reflect::get(&navigator, &JsValue::from_str("connection")).ok()?;
+        if connection.is_undefined() {
+            return None;
+        }
+        let save_data = js_sys::Reflect::get(&connection, &JsValue::from_str("saveData"))
+            .ok()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let kind = js_sys::Reflect::get(&connection, &JsValue::from_str("type"))
+            .ok()
+            .and_then(|v| v.as_string());
+        Some(save_data || kind.as_deref() == Some("cellular"))
+    }
+
+    /// Offers `content` to the user as a download named `file_name`.
+    ///
+    /// Browsers do not report whether the file was actually saved, so this
+    /// returns `Ok(true)` once the download has been started.
+    pub async fn save_file(
+        file_name: &str,
+        _filter_name: &str,
+        _extension: &str,
+        content: String,
+    ) -> Result<bool, String> {
+        use base64::Engine;
+
+        let document = web_sys::window()
+            .and_then(|w| w.document())
+            .ok_or("no document")?;
+        let body = document.body().ok_or("no body")?;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(content);
+        let link: web_sys::HtmlElement = document
+            .create_element("a")
+            .map_err(|e| e.as_string().unwrap_or_default())?
+            .dyn_into()
+            .map_err(|_| "Failed to cast to HtmlElement".to_string())?;
+        link.set_attribute(
+            "href",
+            &format!("data:application/octet-stream;base64,{encoded}"),
+        )
+        .map_err(|e| e.as_string().unwrap_or_default())?;
+        link.set_attribute("download", file_name)
+            .map_err(|e| e.as_string().unwrap_or_default())?;
+
+        body.append_child(&link)
+            .map_err(|e| e.as_string().unwrap_or_default())?;
+        link.click();
+        body.remove_child(&link)
+            .map_err(|e| e.as_string().unwrap_or_default())?;
+        Ok(true)
+    }
+
+    /// Browsers cannot browse the files of the machine the server runs on.
+    pub const CAN_PICK_PATHS: bool = false;
+
+    /// The share sheet is only offered by the mobile apps.
+    pub const CAN_SHARE: bool = false;
+
+    pub async fn share(_title: &str, _text: &str) -> Result<bool, String> {
+        Err("Sharing is not supported here".to_string())
+    }
+
+    pub async fn pick_folder() -> Option<String> {
+        None
+    }
+
+    pub async fn pick_file_path() -> Option<String> {
+        None
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -168,10 +251,11 @@ pub mod non_wasm32 {
         clipboard.get().ok()
     }
 
-    /// Prompts the user to select a file and reads its content as a string.
-    pub async fn read_file(extension: &str) -> Result<Option<String>, String> {
+    /// Prompts the user to select a file with one of `extensions` and reads
+    /// it as text.  Returns the file's name and content.
+    pub async fn read_file(extensions: &[&str]) -> Result<Option<(String, String)>, String> {
         let file_handle = rfd::AsyncFileDialog::new()
-            .add_filter("SVG Files", &[extension])
+            .add_filter("Supported Files", extensions)
             .pick_file()
             .await;
 
@@ -179,9 +263,97 @@ pub mod non_wasm32 {
             let content = tokio::fs::read_to_string(handle.path())
                 .await
                 .map_err(|e| e.to_string())?;
-            Ok(Some(content))
+            Ok(Some((handle.file_name(), content)))
         } else {
             Ok(None)
         }
     }
+
+    pub const CAN_PICK_PATHS: bool = true;
+
+    /// Prompts the user to select a folder.  Returns its path, or None if the
+    /// user cancelled.
+    pub async fn pick_folder() -> Option<String> {
+        rfd::AsyncFileDialog::new()
+            .pick_folder()
+            .await
+            .map(|handle| handle.path().display().to_string())
+    }
+
+    /// Prompts the user to select a file.  Returns its path, or None if the
+    /// user cancelled.
+    pub async fn pick_file_path() -> Option<String> {
+        rfd::AsyncFileDialog::new()
+            .pick_file()
+            .await
+            .map(|handle| handle.path().display().to_string())
+    }
+
+    /// True on Android and iOS, whose webview can open the platform share
+    /// sheet.
+    pub const CAN_SHARE: bool = cfg!(any(target_os = "android", target_os = "ios"));
+
+    /// Opens the platform share sheet with `text`.
+    ///
+    /// Returns `Ok(false)` if the user dismissed it, and an error if the
+    /// share sheet is not available.
+    pub async fn share(title: &str, text: &str) -> Result<bool, String> {
+        if !CAN_SHARE {
+            return Err("Sharing is not supported here".to_string());
+        }
+        let js = format!(
+            r#"
+            if (!navigator.share) {{
+                return "unsupported";
+            }}
+            try {{
+                await navigator.share({{ title: {title}, text: {text} }});
+                return "shared";
+            }} catch (e) {{
+                return e.name === "AbortError" ? "dismissed" : e.toString();
+            }}
+            "#,
+            title = serde_json::to_string(title).map_err(|e| e.to_string())?,
+            text = serde_json::to_string(text).map_err(|e| e.to_string())?,
+        );
+        let result = dioxus::document::eval(&js)
+            .await
+            .map_err(|e| e.to_string())?;
+        match result.as_str() {
+            Some("shared") => Ok(true),
+            Some("dismissed") => Ok(false),
+            Some("unsupported") => Err("Sharing is not supported here".to_string()),
+            other => Err(other.unwrap_or_default().to_string()),
+        }
+    }
+
+    /// Metered connections cannot be detected on native platforms.
+    pub fn connection_is_metered() -> Option<bool> {
+        None
+    }
+
+    /// Prompts the user for a location and writes `content` there.
+    ///
+    /// Returns `Ok(false)` if the user cancelled.
+    pub async fn save_file(
+        file_name: &str,
+        filter_name: &str,
+        extension: &str,
+        content: String,
+    ) -> Result<bool, String> {
+        let file_handle = rfd::AsyncFileDialog::new()
+            .add_filter(filter_name, &[extension])
+            .set_file_name(file_name)
+            .save_file()
+            .await;
+
+        if let Some(handle) = file_handle {
+            tokio::fs::write(handle.path(), content)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
 }
@@ -1,16 +1,13 @@
 use dioxus::prelude::*;
 
-use crate::Screen; // Adjust this import to where your Screen enum is
+use crate::Route;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ActionLinkProps {
-    // In 0.7, use Signal<T> instead of &UseState<T>.
-    // Signals are Copy, so we don't need lifetimes or references here.
+    /// The screen to open.  Also the link's href, so that it can be opened in
+    /// a new tab or copied.
     #[props(optional)]
-    pub state: Option<Signal<Screen>>,
-
-    #[props(optional)]
-    pub to: Option<Screen>,
+    pub to: Option<Route>,
 
     // Note: MouseEvent is the standard type alias in Dioxus 0.7
     #[props(optional)]
@@ -23,16 +20,15 @@ pub struct ActionLinkProps {
 pub fn ActionLink(props: ActionLinkProps) -> Element {
     rsx! {
         a {
-            href: "#",
+            href: props.to.as_ref().map(|to| to.to_string()).unwrap_or_else(|| "#".to_string()),
             // 1. We specify MouseEvent explicitly to satisfy the compiler
             onclick: move |evt: MouseEvent| {
                 // 2. Prevent the "new tab" behavior
                 evt.prevent_default();
 
                 // 3. Handle Navigation
-                // We use 'mut' to get write access to the Signal
-                if let (Some(mut state_signal), Some(target)) = (props.state, &props.to) {
-                    state_signal.set(target.clone());
+                if let Some(target) = &props.to {
+                    navigator().push(target.clone());
                 }
 
                 // 4. Handle Custom Logic
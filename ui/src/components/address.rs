@@ -3,9 +3,11 @@
 //=============================================================================
 use std::rc::Rc;
 
+use api::prefs::block_explorer::ExplorerItem;
 use dioxus::prelude::*;
 use neptune_types::address::ReceivingAddress;
 
+use crate::components::external_link::ExternalLink;
 use crate::components::pico::Button;
 use crate::components::pico::CopyButton;
 use crate::components::pico::NoTitleModal;
@@ -53,6 +55,10 @@ pub fn Address(props: AddressProps) -> Element {
                     CopyButton {
                         text_to_copy: full_address(),
                     }
+                    ExternalLink {
+                        item: ExplorerItem::Address,
+                        id: full_address(),
+                    }
                     Button {
                         on_click: move |_| is_modal_open.set(false),
                         "Close"
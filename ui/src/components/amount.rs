@@ -6,6 +6,8 @@ use dioxus::prelude::*;
 use neptune_types::native_currency_amount::NativeCurrencyAmount;
 
 use crate::app_state_mut::AppStateMut;
+use crate::i18n::t;
+use crate::number_format;
 
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
 pub enum AmountType {
@@ -35,9 +37,15 @@ impl CurrencyFormat {
     }
 }
 
+/// The most NPT decimals shown in table cells, whatever the user's precision.
+pub const TABLE_MAX_DECIMALS: u8 = 4;
+
 /// A component that displays a currency amount and flips to an alternative
 /// currency on hover or tap-and-hold. It now accepts an optional `fiat_equivalent`
 /// to ensure precision for display values and is fully reactive to prop changes.
+///
+/// NPT amounts are shortened to the user's precision, or to `max_decimals` if
+/// that is fewer.  The tooltip always has the exact amount.
 #[component]
 #[allow(clippy::if_same_then_else)]
 pub fn Amount(
@@ -45,12 +53,19 @@ pub fn Amount(
     #[props(optional)] fiat_equivalent: Option<FiatAmount>,
     #[props(optional)] fixed: Option<AmountType>,
     #[props(default)] format: CurrencyFormat,
+    #[props(optional)] max_decimals: Option<u8>,
 ) -> Element {
     let app_state_mut = use_context::<AppStateMut>();
     let mut is_flipped = use_signal(|| false);
 
     let prices = app_state_mut.prices.read();
     let preference = *app_state_mut.display_preference.read();
+    let mut number_format = number_format::current();
+    if let Some(max) = max_decimals {
+        if number_format.precision.max_decimals.is_none_or(|m| m > max) {
+            number_format.precision = number_format.precision.with_max_decimals(max);
+        }
+    }
 
     // Derive display currencies from the new preference enum.
     let (main_currency_str, fiat_for_display) = match preference {
@@ -77,6 +92,21 @@ pub fn Amount(
         },
     };
 
+    // masked amounts keep their currency, but reveal nothing on hover.
+    if (app_state_mut.hide_amounts)() {
+        let code = if format.show_code() {
+            format!(" {main_currency_str}")
+        } else {
+            String::new()
+        };
+        return rsx! {
+            span {
+                title: t!("common-amount-hidden"),
+                "••••{code}"
+            }
+        };
+    }
+
     // Helper function to calculate the fiat value using precise integer math.
     let calculate_fiat_fallback = |amt: NativeCurrencyAmount, price: FiatAmount| -> FiatAmount {
         let npt_minor_units = amt.to_nau();
@@ -91,20 +121,19 @@ pub fn Amount(
             "{}{}{}",
             // no NPT symbol exists yet afaik.  maybe one day.
             if format.show_symbol() { "" } else { "" },
-            amt,
+            number_format.npt(&amt),
             if format.show_code() { " NPT" } else { "" },
         )
     };
 
     let format_fiat = |amt: FiatAmount| -> String {
         format!(
-            "{}{}{}",
+            "{}{}",
             if format.show_symbol() {
-                amt.currency().symbol()
+                amt.to_string_localized(&number_format)
             } else {
-                ""
+                number_format.fiat(&amt)
             },
-            amt,
             if format.show_code() {
                 " ".to_owned() + amt.currency().code()
             } else {
@@ -113,17 +142,38 @@ pub fn Amount(
         )
     };
 
+    // The secondary currency is only shown alongside the primary fiat amount,
+    // and only when its rate is known.
+    let secondary_price = match preference {
+        DisplayPreference::FiatEnabled {
+            secondary_fiat: Some(secondary),
+            ..
+        } => (*prices).as_ref().and_then(|p| p.get(secondary)),
+        _ => None,
+    };
+
+    let with_secondary = |primary: String, amt: NativeCurrencyAmount| -> String {
+        match secondary_price {
+            Some(price) => format!(
+                "{} ({})",
+                primary,
+                format_fiat(calculate_fiat_fallback(amt, price))
+            ),
+            None => primary,
+        }
+    };
+
     // Helper function to format an amount based on the currency string.
     let format_currency = |amt: NativeCurrencyAmount, currency_str: &str| -> String {
         if currency_str != "NPT" {
             if let Some(fc) = fiat_for_display {
                 if let Some(fiat_val) = fiat_equivalent {
-                    return format_fiat(fiat_val);
+                    return with_secondary(format_fiat(fiat_val), amt);
                 }
                 if let Some(price_map) = &*prices {
                     if let Some(price) = price_map.get(fc) {
                         let fiat_val = calculate_fiat_fallback(amt, price);
-                        return format_fiat(fiat_val);
+                        return with_secondary(format_fiat(fiat_val), amt);
                     }
                 }
             }
@@ -136,7 +186,7 @@ pub fn Amount(
     // It always shows the lossless amount. If fiat mode is enabled, it ALWAYS
     // shows the exchange rate, regardless of the currently displayed currency.
     let format_tooltip = |amt: NativeCurrencyAmount| -> String {
-        let lossless_part = format!("{} NPT", amt.display_lossless());
+        let lossless_part = format!("{} NPT", number_format.npt_lossless(&amt));
 
         // Step 1: Check if fiat mode is enabled. If not, we're done.
         let currency_for_rate = match preference {
@@ -157,13 +207,24 @@ pub fn Amount(
 
         if let Some(price_map) = &*prices {
             if let Some(price) = price_map.get(currency_for_rate) {
-                let rate_part = format!("1 NPT = {}", price.to_string_with_code());
+                let rate_part = format!("1 NPT = {}", number_format.fiat_with_code(&price));
                 let amt_part = if let Some(fiat_amt) = fiat_equivalent {
-                    fiat_amt.to_string_with_code()
+                    number_format.fiat_with_code(&fiat_amt)
                 } else {
-                    calculate_fiat_fallback(amt, price).to_string_with_code()
+                    number_format.fiat_with_code(&calculate_fiat_fallback(amt, price))
                 };
 
+                if let Some(secondary) = secondary_price {
+                    return format!(
+                        "{}\n\n{}\n{}\n\n{}\n1 NPT = {}",
+                        lossless_part,
+                        amt_part,
+                        number_format.fiat_with_code(&calculate_fiat_fallback(amt, secondary)),
+                        rate_part,
+                        number_format.fiat_with_code(&secondary)
+                    );
+                }
+
                 return format!("{}\n\n{}\n\n{}", lossless_part, amt_part, rate_part);
             }
         }
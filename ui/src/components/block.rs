@@ -8,7 +8,7 @@ use neptune_types::block_height::BlockHeight;
 use neptune_types::block_selector::BlockSelector;
 use twenty_first::tip5::Digest;
 
-use crate::Screen;
+use crate::Route;
 
 #[derive(Props, PartialEq, Clone)]
 pub struct BlockProps {
@@ -28,7 +28,6 @@ pub fn Block(props: BlockProps) -> Element {
     let height = *props_clone.height;
     let digest = props_clone.block_digest.clone();
     let abbreviated = use_memo(move || props_clone.abbreviated());
-    let mut active_screen = use_context::<Signal<Screen>>();
 
     rsx! {
 
@@ -37,7 +36,9 @@ pub fn Block(props: BlockProps) -> Element {
             style: "cursor: pointer;",
             title: "{abbreviated}",
             onclick: move |_| {
-                active_screen.set(Screen::Block(BlockSelector::Digest(*digest)));
+                navigator().push(Route::Block {
+                    selector: BlockSelector::Digest(*digest),
+                });
             },
             code {
 
@@ -0,0 +1,252 @@
+//=============================================================================
+// File: src/components/chart.rs
+//=============================================================================
+use dioxus::prelude::*;
+
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 260.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 15.0;
+const MARGIN_TOP: f64 = 15.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+
+const MIN_RADIUS: f64 = 3.0;
+const MAX_RADIUS: f64 = 14.0;
+
+/// A point in a [ScatterChart].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartPoint {
+    pub x: f64,
+    pub y: f64,
+
+    /// Relative weight, drawn as the circle's area. Equal sizes give a plain
+    /// scatter chart, differing ones a bubble chart.
+    pub size: f64,
+
+    /// Shown as a tooltip.
+    pub label: String,
+
+    /// Drawn in the primary colour, eg for selected items.
+    pub highlighted: bool,
+}
+
+/// Maps data values to a pixel range, linearly or logarithmically.
+#[derive(Clone, Copy)]
+struct Scale {
+    min: f64,
+    max: f64,
+    log: bool,
+}
+
+impl Scale {
+    fn new(values: impl Iterator<Item = f64>, log: bool) -> Self {
+        let (mut min, mut max) = values
+            .filter(|v| v.is_finite() && (!log || *v > 0.0))
+            .fold((f64::MAX, f64::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        if min > max {
+            (min, max) = (0.0, 1.0);
+        }
+        if log {
+            (min, max) = (min.log10(), max.log10());
+        }
+        if min == max {
+            (min, max) = (min - 0.5, max + 0.5);
+        }
+        Self { min, max, log }
+    }
+
+    /// Returns the position of `value` in 0..=1.
+    fn fraction(&self, value: f64) -> f64 {
+        let v = if self.log { value.max(f64::MIN_POSITIVE).log10() } else { value };
+        ((v - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Returns `n` evenly spaced values from min to max, in data units.
+    fn ticks(&self, n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| {
+                let v = self.min + (self.max - self.min) * i as f64 / (n - 1) as f64;
+                if self.log {
+                    10f64.powf(v)
+                } else {
+                    v
+                }
+            })
+            .collect()
+    }
+}
+
+/// A scatter/bubble chart rendered as SVG, scaling to the width of its
+/// container.
+#[component]
+pub fn ScatterChart(
+    points: Vec<ChartPoint>,
+    x_label: String,
+    y_label: String,
+    format_x: Callback<f64, String>,
+    format_y: Callback<f64, String>,
+    /// Plot y on a logarithmic scale, for values spanning orders of magnitude.
+    #[props(default = false)]
+    log_y: bool,
+    /// Called with the index of a clicked point.
+    on_point_click: Option<EventHandler<usize>>,
+) -> Element {
+    let x_scale = Scale::new(points.iter().map(|p| p.x), false);
+    let y_scale = Scale::new(points.iter().map(|p| p.y), log_y);
+    let max_size = points.iter().map(|p| p.size).fold(0.0, f64::max);
+
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_height = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let to_px_x = move |x: f64| MARGIN_LEFT + x_scale.fraction(x) * plot_width;
+    let to_px_y = move |y: f64| MARGIN_TOP + (1.0 - y_scale.fraction(y)) * plot_height;
+    let radius = |size: f64| {
+        if max_size <= 0.0 {
+            MIN_RADIUS
+        } else {
+            MIN_RADIUS + (MAX_RADIUS - MIN_RADIUS) * (size / max_size).sqrt()
+        }
+    };
+
+    rsx! {
+        svg {
+            view_box: "0 0 {WIDTH} {HEIGHT}",
+            width: "100%",
+            style: "max-height: 40vh; font-size: 11px;",
+            "aria-label": "{y_label} by {x_label}",
+
+            {axes(x_scale, y_scale, &x_label, &y_label, format_x, format_y)}
+
+            for (i , point) in points.iter().enumerate() {
+                circle {
+                    key: "{i}",
+                    cx: "{to_px_x(point.x)}",
+                    cy: "{to_px_y(point.y)}",
+                    r: "{radius(point.size)}",
+                    fill: if point.highlighted { "var(--pico-primary)" } else { "var(--pico-secondary)" },
+                    fill_opacity: "0.6",
+                    stroke: if point.highlighted { "var(--pico-primary)" } else { "none" },
+                    style: if on_point_click.is_some() { "cursor: pointer;" } else { "" },
+                    onclick: move |_| {
+                        if let Some(handler) = &on_point_click {
+                            handler.call(i);
+                        }
+                    },
+                    title { "{point.label}" }
+                }
+            }
+        }
+    }
+}
+
+/// A line chart rendered as SVG, scaling to the width of its container.
+/// Points are joined in the order given.
+#[component]
+pub fn LineChart(
+    points: Vec<(f64, f64)>,
+    x_label: String,
+    y_label: String,
+    format_x: Callback<f64, String>,
+    format_y: Callback<f64, String>,
+) -> Element {
+    let x_scale = Scale::new(points.iter().map(|p| p.0), false);
+    let y_scale = Scale::new(points.iter().map(|p| p.1), false);
+
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_height = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let path = points
+        .iter()
+        .map(|(x, y)| {
+            format!(
+                "{:.1},{:.1}",
+                MARGIN_LEFT + x_scale.fraction(*x) * plot_width,
+                MARGIN_TOP + (1.0 - y_scale.fraction(*y)) * plot_height
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        svg {
+            view_box: "0 0 {WIDTH} {HEIGHT}",
+            width: "100%",
+            style: "max-height: 40vh; font-size: 11px;",
+            "aria-label": "{y_label} by {x_label}",
+
+            {axes(x_scale, y_scale, &x_label, &y_label, format_x, format_y)}
+
+            polyline {
+                points: "{path}",
+                fill: "none",
+                stroke: "var(--pico-primary)",
+                stroke_width: "1.5",
+            }
+        }
+    }
+}
+
+/// Draws the axes of a chart, with tick values and labels.
+fn axes(
+    x_scale: Scale,
+    y_scale: Scale,
+    x_label: &str,
+    y_label: &str,
+    format_x: Callback<f64, String>,
+    format_y: Callback<f64, String>,
+) -> Element {
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_height = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let to_px_x = move |x: f64| MARGIN_LEFT + x_scale.fraction(x) * plot_width;
+    let to_px_y = move |y: f64| MARGIN_TOP + (1.0 - y_scale.fraction(y)) * plot_height;
+
+    rsx! {
+        // axes
+        line {
+            x1: "{MARGIN_LEFT}",
+            y1: "{MARGIN_TOP + plot_height}",
+            x2: "{WIDTH - MARGIN_RIGHT}",
+            y2: "{MARGIN_TOP + plot_height}",
+            stroke: "var(--pico-muted-border-color)",
+        }
+        line {
+            x1: "{MARGIN_LEFT}",
+            y1: "{MARGIN_TOP}",
+            x2: "{MARGIN_LEFT}",
+            y2: "{MARGIN_TOP + plot_height}",
+            stroke: "var(--pico-muted-border-color)",
+        }
+
+        for x in x_scale.ticks(5) {
+            text {
+                x: "{to_px_x(x)}",
+                y: "{MARGIN_TOP + plot_height + 14.0}",
+                text_anchor: "middle",
+                fill: "var(--pico-muted-color)",
+                "{format_x.call(x)}"
+            }
+        }
+        for y in y_scale.ticks(5) {
+            text {
+                x: "{MARGIN_LEFT - 6.0}",
+                y: "{to_px_y(y) + 4.0}",
+                text_anchor: "end",
+                fill: "var(--pico-muted-color)",
+                "{format_y.call(y)}"
+            }
+        }
+        text {
+            x: "{MARGIN_LEFT + plot_width / 2.0}",
+            y: "{HEIGHT - 6.0}",
+            text_anchor: "middle",
+            fill: "var(--pico-color)",
+            "{x_label}"
+        }
+        text {
+            x: "12",
+            y: "{MARGIN_TOP + plot_height / 2.0}",
+            text_anchor: "middle",
+            transform: "rotate(-90 12 {MARGIN_TOP + plot_height / 2.0})",
+            fill: "var(--pico-color)",
+            "{y_label}"
+        }
+    }
+}
@@ -1,13 +1,19 @@
 // ui/src/components/currency_amount_input.rs
 use dioxus::prelude::*;
 
+use crate::app_state_mut::AppStateMut;
 use crate::components::pico::Button;
 use crate::components::pico::ButtonType;
 use crate::hooks::use_is_touch_device::use_is_touch_device;
 
-// The NumericKeypad component is unchanged.
+/// On-screen keypad. Always emits "." for the decimal key; `decimal_separator`
+/// only affects the label shown on it.
 #[component]
-pub fn NumericKeypad(on_key_press: EventHandler<String>, on_close: EventHandler<()>) -> Element {
+pub fn NumericKeypad(
+    on_key_press: EventHandler<String>,
+    on_close: EventHandler<()>,
+    decimal_separator: char,
+) -> Element {
     let keys = [
         "1",
         "2",
@@ -36,7 +42,7 @@ pub fn NumericKeypad(on_key_press: EventHandler<String>, on_close: EventHandler<
 
         let mapped_key = match event_key_str {
             "Backspace" => Some("BACKSPACE"),
-            "." | "Decimal" => Some("."),
+            "." | "," | "Decimal" => Some("."),
             "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" => Some(event_key_str),
             _ => None,
         };
@@ -114,6 +120,8 @@ pub fn NumericKeypad(on_key_press: EventHandler<String>, on_close: EventHandler<
                                         y2: "15",
                                     }
                                 }
+                            } else if key == "." {
+                                "{decimal_separator}"
                             } else {
                                 "{key}"
                             }
@@ -146,6 +154,7 @@ pub fn CurrencyAmountInput(
     placeholder: String,
 ) -> Element {
     let is_touch_device = use_is_touch_device();
+    let decimal_separator = use_context::<AppStateMut>().decimal_separator;
     let is_popup_visible = use_memo(move || popup_state.read().is_some());
 
     let is_numerically_zero = value.trim().parse::<f64>() == Ok(0.0);
@@ -162,12 +171,19 @@ pub fn CurrencyAmountInput(
         }
     });
 
+    // `new_value` is as displayed, with the preferred decimal separator.
+    // input that cannot be read unambiguously is not taken.
     let mut handle_new_input = move |new_value: String| {
+        let Some(normalized) = decimal_separator().parse_input(&new_value) else {
+            let current = value_signal.read().clone();
+            on_input.call(current);
+            return;
+        };
         let mut sanitized = String::new();
         let mut has_decimal = false;
         let mut integer_digits = 0;
         let mut decimal_digits = 0;
-        for ch in new_value.chars() {
+        for ch in normalized.chars() {
             if ch.is_ascii_digit() {
                 if has_decimal {
                     if decimal_digits < max_decimals {
@@ -221,13 +237,14 @@ pub fn CurrencyAmountInput(
 
             spawn(async move {
                 let handle_keypad_press = move |key: String| {
-                    let current_val = value_signal.read().clone();
+                    let separator = decimal_separator();
+                    let current_val = separator.localize(&value_signal.read());
                     let new_val = if key == "BACKSPACE" {
                         let mut chars = current_val.chars();
                         chars.next_back();
                         chars.as_str().to_string()
                     } else {
-                        current_val + &key
+                        current_val + &separator.localize(&key)
                     };
                     handle_new_input_clone(new_val);
                 };
@@ -242,6 +259,7 @@ pub fn CurrencyAmountInput(
                         },
                         NumericKeypad {
                             on_key_press: handle_keypad_press,
+                            decimal_separator: decimal_separator().as_char(),
                             on_close: move |_| {
                                 spawn(async move {
                                     popup_state.set(None);
@@ -258,7 +276,11 @@ pub fn CurrencyAmountInput(
     let mut open_keypad_clone = open_keypad.clone();
 
     let show_placeholder = value.is_empty();
-    let display_value = if show_placeholder { "" } else { &value };
+    let display_value = if show_placeholder {
+        String::new()
+    } else {
+        decimal_separator().localize(&value)
+    };
 
     let focus_css = r#"
         input.hide-placeholder-focus:focus::placeholder {
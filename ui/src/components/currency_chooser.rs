@@ -2,6 +2,7 @@
 #![allow(non_snake_case)]
 
 use api::fiat_currency::FiatCurrency;
+use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
 
 #[derive(Props, PartialEq, Clone, Copy)]
@@ -109,6 +110,16 @@ pub fn CurrencyChooser(mut props: CurrencyChooserProps) -> Element {
                         style: "border-left: 1px solid var(--pico-secondary-border); padding: 0.1rem 0.2rem; cursor: pointer;",
                         onclick: move |_| is_open.toggle(),
                         title: "Choose national currency.",
+                        role: "button",
+                        tabindex: "0",
+                        "aria-label": "Choose national currency",
+                        "aria-expanded": if is_open() { "true" } else { "false" },
+                        onkeydown: move |evt| {
+                            if evt.key() == Key::Enter || evt.key() == Key::Character(" ".into()) {
+                                evt.prevent_default();
+                                is_open.toggle();
+                            }
+                        },
                         "↓"
                     }
                 }
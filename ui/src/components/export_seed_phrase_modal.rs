@@ -4,6 +4,8 @@
 use dioxus::prelude::*;
 use neptune_types::secret_key_material::SecretKeyMaterial;
 
+use crate::app_state_mut::AppStateMut;
+use crate::components::lock_screen::PinEntry;
 use crate::components::pico::Button;
 use crate::components::pico::ButtonType;
 use crate::components::pico::NoTitleModal;
@@ -11,17 +13,20 @@ use crate::components::pico::NoTitleModal;
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum BackupStage {
     Instructions,
+    /// The PIN is asked for again, if one is set.
+    Reauthenticating,
     DisplayingSeed,
 }
 
 #[component]
 pub fn ExportSeedPhraseModal(is_open: Signal<bool>) -> Element {
+    let pin_set = use_context::<AppStateMut>().pin_set;
     let mut stage = use_signal(|| BackupStage::Instructions);
 
     // Resource to fetch the seed phrase.
     // This automatically re-runs when 'stage' changes because stage() is read inside.
     let mut seed_words_resource = use_resource(move || async move {
-        if stage() == BackupStage::Instructions {
+        if stage() != BackupStage::DisplayingSeed {
             return Ok(None::<SecretKeyMaterial>);
         }
 
@@ -69,6 +74,12 @@ pub fn ExportSeedPhraseModal(is_open: Signal<bool>) -> Element {
                         }
                     }
                 },
+                BackupStage::Reauthenticating => rsx! {
+                    p { "Enter your PIN to display the seed words." }
+                    PinEntry {
+                        on_success: move |_| stage.set(BackupStage::DisplayingSeed),
+                    }
+                },
                 BackupStage::DisplayingSeed => rsx! {
                     match &*seed_words_resource.read() {
                         Some(Ok(Some(secret))) => rsx! {
@@ -126,7 +137,11 @@ pub fn ExportSeedPhraseModal(is_open: Signal<bool>) -> Element {
                         Button {
                             button_type: ButtonType::Primary,
                             on_click: move |_| {
-                                stage.set(BackupStage::DisplayingSeed);
+                                if pin_set() {
+                                    stage.set(BackupStage::Reauthenticating);
+                                } else {
+                                    stage.set(BackupStage::DisplayingSeed);
+                                }
                                 // The resource restart is triggered automatically because stage() is a dependency
                             },
                             "Display Seed Words"
@@ -0,0 +1,35 @@
+//=============================================================================
+// File: src/components/external_link.rs
+//=============================================================================
+use api::prefs::block_explorer::ExplorerItem;
+use dioxus::prelude::*;
+
+use crate::AppStateMut;
+
+/// An "Open in explorer" link for a block, transaction or address.
+///
+/// Renders nothing unless a block explorer is configured.
+#[component]
+pub fn ExternalLink(item: ExplorerItem, id: String) -> Element {
+    let block_explorer = use_context::<AppStateMut>().block_explorer;
+    let Some(url) = block_explorer
+        .read()
+        .as_ref()
+        .map(|explorer| explorer.url(item, &id))
+    else {
+        return rsx! {};
+    };
+
+    rsx! {
+        a {
+            href: "{url}",
+            target: "_blank",
+            rel: "noopener noreferrer",
+            title: "Open in explorer",
+            "aria-label": "Open in explorer",
+            style: "text-decoration: none; white-space: nowrap;",
+            onclick: |evt| evt.stop_propagation(),
+            "↗"
+        }
+    }
+}
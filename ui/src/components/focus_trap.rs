@@ -0,0 +1,88 @@
+//=============================================================================
+// File: src/components/focus_trap.rs
+//=============================================================================
+//! Keeps keyboard focus inside a modal while it is open, and returns it to
+//! where it was once the modal closes.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use dioxus::prelude::*;
+
+/// The elements that Tab moves between.
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Moves focus into the element with the given id, unless it already holds
+/// it, wraps Tab and Shift+Tab around its focusable elements, and remembers
+/// what had focus before.
+fn trap_script(id: &str) -> String {
+    format!(
+        r#"
+        const trap = document.getElementById("{id}");
+        if (trap) {{
+            window.__focusTrapReturns = window.__focusTrapReturns || {{}};
+            window.__focusTrapReturns["{id}"] = document.activeElement;
+            const focusables = () => Array.from(trap.querySelectorAll("{FOCUSABLE}"))
+                .filter(el => el.offsetParent !== null);
+            trap.addEventListener("keydown", (e) => {{
+                if (e.key !== "Tab") return;
+                const els = focusables();
+                if (els.length === 0) {{ e.preventDefault(); return; }}
+                const first = els[0];
+                const last = els[els.length - 1];
+                if (e.shiftKey && document.activeElement === first) {{
+                    e.preventDefault();
+                    last.focus();
+                }} else if (!e.shiftKey && document.activeElement === last) {{
+                    e.preventDefault();
+                    first.focus();
+                }}
+            }});
+            if (!trap.contains(document.activeElement)) {{
+                const first = focusables()[0];
+                if (first) first.focus();
+            }}
+        }}
+        "#
+    )
+}
+
+/// Returns focus to what had it before the trap with the given id was set.
+fn restore_script(id: &str) -> String {
+    format!(
+        r#"
+        const returns = window.__focusTrapReturns || {{}};
+        const previous = returns["{id}"];
+        delete returns["{id}"];
+        if (previous && document.contains(previous)) previous.focus();
+        "#
+    )
+}
+
+/// Traps focus within `children` while mounted.  Mount it only while the
+/// modal it belongs to is open.
+#[component]
+pub fn FocusTrap(children: Element) -> Element {
+    let id = use_hook(|| {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        format!("focus-trap-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+    });
+
+    let trap_id = id.clone();
+    use_effect(move || {
+        let _ = document::eval(&trap_script(&trap_id));
+    });
+    let restore_id = id.clone();
+    use_drop(move || {
+        let _ = document::eval(&restore_script(&restore_id));
+    });
+
+    rsx! {
+        div {
+            id: "{id}",
+            style: "display: contents;",
+            {children}
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//=============================================================================
+// File: src/components/lock_screen.rs
+//=============================================================================
+use dioxus::prelude::*;
+
+use crate::app_state_mut::AppStateMut;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::components::pico::Modal;
+use crate::i18n::t;
+
+/// A PIN field that calls `on_success` once the correct PIN is entered.
+#[component]
+pub fn PinEntry(on_success: EventHandler<()>) -> Element {
+    let mut pin = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+    let mut is_checking = use_signal(|| false);
+
+    let submit = move |e: FormEvent| {
+        e.prevent_default();
+        if is_checking() {
+            return;
+        }
+        is_checking.set(true);
+        spawn(async move {
+            match api::verify_lock_pin(pin()).await {
+                Ok(true) => {
+                    pin.set(String::new());
+                    error.set(None);
+                    on_success.call(());
+                }
+                Ok(false) => error.set(Some(t!("lock-wrong-pin"))),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_checking.set(false);
+        });
+    };
+
+    rsx! {
+        form {
+            onsubmit: submit,
+            input {
+                r#type: "password",
+                autocomplete: "current-password",
+                autofocus: true,
+                placeholder: t!("lock-pin-placeholder"),
+                value: "{pin}",
+                oninput: move |e| pin.set(e.value()),
+            }
+            if let Some(e) = error() {
+                small { "{e}" }
+            }
+            Button {
+                button_type: ButtonType::Primary,
+                disabled: pin().is_empty() || is_checking(),
+                {t!("lock-unlock")}
+            }
+        }
+    }
+}
+
+/// Covers the app while it is locked.
+#[component]
+pub fn LockScreen() -> Element {
+    let mut app_state_mut = use_context::<AppStateMut>();
+
+    rsx! {
+        div {
+            style: "display: flex; justify-content: center; align-items: center; min-height: 80vh;",
+            div {
+                style: "width: 100%; max-width: 24rem;",
+                Card {
+                    h3 { {t!("lock-title")} }
+                    p { {t!("lock-intro")} }
+                    PinEntry {
+                        on_success: move |_| {
+                            app_state_mut.locked.set(false);
+                            app_state_mut.blurred.set(false);
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Covers the blurred app after inactivity.  Resuming takes a click, or the
+/// PIN if one is set.
+#[component]
+pub fn IdleOverlay() -> Element {
+    let mut app_state_mut = use_context::<AppStateMut>();
+    let pin_set = (app_state_mut.pin_set)();
+
+    rsx! {
+        div {
+            style: "position: fixed; inset: 0; z-index: 9000; display: flex; justify-content: center; align-items: center; background: rgba(0, 0, 0, 0.3);",
+            div {
+                style: "width: 100%; max-width: 24rem;",
+                Card {
+                    h3 { {t!("lock-idle-title")} }
+                    if pin_set {
+                        p { {t!("lock-intro")} }
+                        PinEntry {
+                            on_success: move |_| app_state_mut.blurred.set(false),
+                        }
+                    } else {
+                        p { {t!("lock-idle-intro")} }
+                        Button {
+                            button_type: ButtonType::Primary,
+                            on_click: move |_| app_state_mut.blurred.set(false),
+                            {t!("lock-idle-resume")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Asks for the PIN again before a sensitive action.  Calls `on_success`,
+/// and closes, once it is entered.
+#[component]
+pub fn ReauthModal(is_open: Signal<bool>, on_success: EventHandler<()>) -> Element {
+    rsx! {
+        Modal {
+            is_open,
+            title: t!("lock-reauth-title"),
+            p { {t!("lock-reauth-intro")} }
+            PinEntry {
+                on_success: move |_| {
+                    is_open.set(false);
+                    on_success.call(());
+                },
+            }
+        }
+    }
+}
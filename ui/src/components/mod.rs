@@ -5,13 +5,23 @@ pub mod action_link;
 pub mod address;
 pub mod amount;
 pub mod block;
+pub mod chart;
 pub mod currency_amount_input;
 pub mod currency_chooser;
 pub mod digest_display;
 pub mod empty_state;
 pub mod export_seed_phrase_modal;
+pub mod external_link;
+pub mod focus_trap;
+pub mod lock_screen;
+pub mod notification_center;
 pub mod pico;
 pub mod qr_code;
 pub mod qr_processor;
 pub mod qr_scanner;
 pub mod qr_uploader;
+pub mod search_box;
+pub mod selection_bar;
+pub mod send_queue_status;
+pub mod skeleton;
+pub mod tx_note;
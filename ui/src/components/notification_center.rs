@@ -0,0 +1,182 @@
+//=============================================================================
+// File: src/components/notification_center.rs
+//=============================================================================
+use api::notification::Notification;
+use api::notification::NotificationKind;
+use dioxus::prelude::*;
+
+use crate::app_state_mut::AppStateMut;
+use crate::components::action_link::ActionLink;
+use crate::components::amount::Amount;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Modal;
+use crate::i18n::t;
+use crate::Route;
+
+fn abbreviate(s: &str) -> String {
+    if s.len() <= 12 {
+        return s.to_string();
+    }
+    format!("{}...{}", &s[0..6], &s[s.len() - 4..])
+}
+
+/// One entry of the list.  Clicking it marks it read.
+#[component]
+fn NotificationRow(notification: Notification) -> Element {
+    let mut notifications = use_context::<AppStateMut>().notifications;
+    let id = notification.id;
+    let date_time_format = format!("{} %H:%M", crate::i18n::date_format());
+    let created_at = chrono::DateTime::from_timestamp_millis(notification.created_at_ms as i64)
+        .map(|d| d.with_timezone(&chrono::Local).format(&date_time_format).to_string())
+        .unwrap_or_default();
+    let color = if notification.kind.is_warning() {
+        "var(--pico-del-color)"
+    } else {
+        "inherit"
+    };
+    let weight = if notification.read { "normal" } else { "bold" };
+
+    rsx! {
+        li {
+            style: "list-style: none; padding: 0.5rem 0; border-bottom: 1px solid var(--pico-muted-border-color); cursor: pointer;",
+            onclick: move |_| {
+                if notification.read {
+                    return;
+                }
+                // optimistic; the next poll corrects any failure.
+                if let Some(n) = notifications.write().iter_mut().find(|n| n.id == id) {
+                    n.read = true;
+                }
+                spawn(async move {
+                    let _ = api::mark_notifications_read(Some(vec![id])).await;
+                });
+            },
+            div {
+                style: "font-weight: {weight}; color: {color};",
+                match notification.kind.clone() {
+                    NotificationKind::FundsReceived { amount, block_height } => rsx! {
+                        {t!("notification-funds-received", height = block_height)}
+                        " "
+                        Amount { amount }
+                    },
+                    NotificationKind::SendConfirmed { amount, block_height } => rsx! {
+                        {t!("notification-send-confirmed", height = block_height)}
+                        " "
+                        Amount { amount }
+                    },
+                    NotificationKind::ConnectionLost => rsx! {
+                        {t!("notification-connection-lost")}
+                    },
+                    NotificationKind::ConnectionRestored => rsx! {
+                        {t!("notification-connection-restored")}
+                    },
+                    NotificationKind::LowFee { txid, fee, blocks_waiting } => rsx! {
+                        {t!(
+                            "notification-low-fee",
+                            txid = abbreviate(&txid.to_string()),
+                            blocks = blocks_waiting
+                        )}
+                        " "
+                        Amount { amount: fee }
+                        " — "
+                        ActionLink {
+                            to: Route::MempoolTx { tx_id: txid },
+                            {t!("notification-low-fee-action")}
+                        }
+                    },
+                    NotificationKind::TokenRejected { error } => rsx! {
+                        span { title: "{error}", {t!("notification-token-rejected")} }
+                        " — "
+                        ActionLink {
+                            to: Route::Health {},
+                            {t!("notification-token-rejected-action")}
+                        }
+                    },
+                }
+            }
+            small {
+                style: "color: var(--pico-muted-color);",
+                "{created_at}"
+            }
+        }
+    }
+}
+
+/// A bell in the header showing the number of unread notifications, which
+/// opens the notification list.
+#[component]
+pub fn NotificationBell() -> Element {
+    let mut notifications = use_context::<AppStateMut>().notifications;
+    let mut is_open = use_signal(|| false);
+    let num_unread = notifications.read().iter().filter(|n| !n.read).count();
+
+    // close the list when a link in it navigates away.
+    let route = use_route::<Route>();
+    use_effect(use_reactive((&route,), move |_| is_open.set(false)));
+
+    let mark_all_read = move |_| {
+        for n in notifications.write().iter_mut() {
+            n.read = true;
+        }
+        spawn(async move {
+            let _ = api::mark_notifications_read(None).await;
+        });
+    };
+    let clear = move |_| {
+        notifications.set(vec![]);
+        spawn(async move {
+            let _ = api::clear_notifications().await;
+        });
+    };
+
+    rsx! {
+        Button {
+            button_type: ButtonType::Secondary,
+            outline: num_unread == 0,
+            style: "padding: 0.2rem 0.5rem; font-size: 0.8rem;",
+            title: t!("notification-bell-title", count = num_unread),
+            aria_label: t!("notification-bell-title", count = num_unread),
+            on_click: move |_| is_open.set(true),
+            "🔔"
+            if num_unread > 0 {
+                " {num_unread}"
+            }
+        }
+        Modal {
+            is_open,
+            title: t!("notification-title"),
+            if notifications.read().is_empty() {
+                p {
+                    style: "color: var(--pico-muted-color);",
+                    {t!("notification-none")}
+                }
+            } else {
+                ul {
+                    style: "padding: 0; max-height: 60vh; overflow-y: auto;",
+                    for notification in notifications.read().iter().cloned() {
+                        NotificationRow {
+                            key: "{notification.id}",
+                            notification,
+                        }
+                    }
+                }
+                footer {
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        disabled: num_unread == 0,
+                        on_click: mark_all_read,
+                        {t!("notification-mark-all-read")}
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        on_click: clear,
+                        {t!("notification-clear")}
+                    }
+                }
+            }
+        }
+    }
+}
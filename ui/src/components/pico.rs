@@ -4,11 +4,16 @@
 #![allow(non_snake_case)] // Allow PascalCase for component function names
 #![allow(dead_code)] // Allow PascalCase for component function names
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
 
+use crate::components::focus_trap::FocusTrap;
+use crate::i18n::t;
+
 //=============================================================================
 // Layout Components
 //=============================================================================
@@ -87,9 +92,17 @@ pub struct CloseButtonProps {
 }
 
 pub fn CloseButton(props: CloseButtonProps) -> Element {
+    // the icon means nothing to a screen reader.
+    let label = if props.title.is_empty() {
+        t!("common-close")
+    } else {
+        props.title.clone()
+    };
+
     rsx! {
         button {
             title: "{props.title}",
+            "aria-label": "{label}",
             class: "close",
             class: "primary",
             onclick: move |evt| {
@@ -131,6 +144,13 @@ pub struct ButtonProps {
     style: String,
     #[props(default)]
     title: String,
+    /// Names the button for screen readers.  Needed when its content is only
+    /// an icon.
+    #[props(optional)]
+    aria_label: Option<String>,
+    /// Set for a button that toggles something on and off.
+    #[props(optional)]
+    aria_pressed: Option<bool>,
 }
 
 /// A versatile button component.
@@ -160,6 +180,8 @@ pub fn Button(props: ButtonProps) -> Element {
             },
             style: "{props.style}",
             title: "{props.title}",
+            "aria-label": props.aria_label.clone(),
+            "aria-pressed": props.aria_pressed.map(|pressed| pressed.to_string()),
             {props.children}
         }
     }
@@ -230,34 +252,52 @@ pub struct ModalProps {
 }
 
 pub fn Modal(mut props: ModalProps) -> Element {
+    let title_id = use_hook(|| format!("modal-title-{}", modal_id()));
+
     rsx! {
         if (props.is_open)() {
             dialog {
                 open: true,
-                article {
-
-
-                    header {
-
-
-                        a {
-                            href: "#",
-                            "aria-label": "Close",
-                            class: "close",
-                            onclick: move |_| props.is_open.set(false),
-                        }
-                        h3 {
-                            style: "margin-bottom: 0;",
-                            "{props.title}"
+                "aria-modal": "true",
+                "aria-labelledby": "{title_id}",
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Escape {
+                        props.is_open.set(false);
+                    }
+                },
+                FocusTrap {
+                    article {
+                        header {
+                            a {
+                                href: "#",
+                                role: "button",
+                                "aria-label": t!("common-close"),
+                                class: "close",
+                                onclick: move |evt| {
+                                    evt.prevent_default();
+                                    props.is_open.set(false);
+                                },
+                            }
+                            h3 {
+                                id: "{title_id}",
+                                style: "margin-bottom: 0;",
+                                "{props.title}"
+                            }
                         }
+                        {props.children}
                     }
-                    {props.children}
                 }
             }
         }
     }
 }
 
+/// A number unique to each modal, for ids that tie its parts together.
+fn modal_id() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
 // A modal with no title bar that closes on backdrop click or Escape key.
 #[derive(Props, PartialEq, Clone)]
 pub struct NoTitleModalProps {
@@ -272,15 +312,18 @@ pub fn NoTitleModal(mut props: NoTitleModalProps) -> Element {
                 tabindex: "0",
                 open: true,
                 autofocus: true,
+                "aria-modal": "true",
                 onclick: move |_| props.is_open.set(false),
                 onkeydown: move |evt| {
                     if evt.key() == Key::Escape {
                         props.is_open.set(false);
                     }
                 },
-                article {
-                    onclick: |evt| evt.stop_propagation(),
-                    {props.children}
+                FocusTrap {
+                    article {
+                        onclick: |evt| evt.stop_propagation(),
+                        {props.children}
+                    }
                 }
             }
         }
@@ -304,10 +347,12 @@ pub fn CopyButton(props: CopyButtonProps) -> Element {
             Button {
                 button_type: ButtonType::Secondary,
                 disabled: true,
+                aria_label: t!("common-copied"),
                 "Copied!"
             }
         } else {
             Button {
+                aria_label: t!("common-copy-label"),
                 on_click: move |_| {
                     let clipboard_text = props.text_to_copy.clone();
                     spawn({
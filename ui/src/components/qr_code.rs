@@ -8,9 +8,24 @@ use futures::StreamExt;
 use qrcode::render::svg;
 use qrcode::EcLevel;
 use qrcode::QrCode;
+use strum::IntoEnumIterator;
 
 const STATIC_CHUNK_SIZE: usize = 120;
 
+/// Aztec codes hold more per part, so long addresses need fewer parts.
+const AZTEC_CHUNK_SIZE: usize = 600;
+
+/// The error correction of Aztec codes, as a percentage of the symbol.
+const AZTEC_EC_PERCENT: u32 = 33;
+
+/// How long each part of an animated QR code is shown, unless the caller
+/// says otherwise.
+const DEFAULT_FRAME_DURATION_MS: u32 = 300;
+
+/// The range the viewer can adjust the frame duration within.
+const MIN_FRAME_DURATION_MS: u32 = 100;
+const MAX_FRAME_DURATION_MS: u32 = 2000;
+
 // The message now includes the filename for the save dialog.
 #[cfg(not(target_arch = "wasm32"))]
 #[derive(Clone)]
@@ -18,6 +33,41 @@ pub enum SaveFileAction {
     SaveSvg(String, String), // (svg_data, file_name)
 }
 
+/// The 2D barcodes that data can be shown as.
+///
+/// Aztec codes need no quiet zone and stay readable when printed small, and
+/// hold more data per part of an animated code than QR codes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum::EnumIter)]
+pub enum Symbology {
+    #[default]
+    Qr,
+    Aztec,
+}
+
+impl Symbology {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Qr => "QR",
+            Self::Aztec => "Aztec",
+        }
+    }
+
+    /// Data longer than this is split into the parts of an animated code.
+    fn chunk_size(&self) -> usize {
+        match self {
+            Self::Qr => STATIC_CHUNK_SIZE,
+            Self::Aztec => AZTEC_CHUNK_SIZE,
+        }
+    }
+
+    fn file_suffix(&self) -> &'static str {
+        match self {
+            Self::Qr => "qr",
+            Self::Aztec => "aztec",
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct QrCodeProps {
     pub data: String,
@@ -25,11 +75,71 @@ pub struct QrCodeProps {
     pub tooltip: Option<String>,
     #[props(optional)]
     pub caption: Option<String>,
+    /// Encode `data` as given. By default it is uppercased, which allows the
+    /// more compact alphanumeric QR encoding for bech32m addresses.
+    #[props(default)]
+    pub preserve_case: bool,
+    /// How long each part of an animated QR code is shown.  The viewer can
+    /// slow it down or speed it up.
+    #[props(default = DEFAULT_FRAME_DURATION_MS)]
+    pub frame_duration_ms: u32,
+    /// The barcode shown first.  The viewer can switch to another.
+    #[props(default)]
+    pub symbology: Symbology,
 }
 
 #[allow(non_snake_case)]
 pub fn QrCode(props: QrCodeProps) -> Element {
-    let uppercased_data = props.data.to_uppercase();
+    let mut symbology = use_signal(|| props.symbology);
+
+    // each symbology is its own template, so that switching remounts the
+    // code along with its hooks.
+    rsx! {
+        div {
+            style: "display: flex; flex-direction: column; align-items: center;",
+            match symbology() {
+                Symbology::Qr => rsx! {
+                    BarcodeView {
+                        code: QrCodeProps {
+                            symbology: Symbology::Qr,
+                            ..props.clone()
+                        },
+                    }
+                },
+                Symbology::Aztec => rsx! {
+                    BarcodeView {
+                        code: QrCodeProps {
+                            symbology: Symbology::Aztec,
+                            ..props.clone()
+                        },
+                    }
+                },
+            }
+            div {
+                style: "display: flex; gap: 0.25rem; margin-top: 4px;",
+                for s in Symbology::iter() {
+                    button {
+                        key: "{s.name()}",
+                        style: "font-size: 12px; padding: 2px 8px;",
+                        disabled: s == symbology(),
+                        onclick: move |_| symbology.set(s),
+                        "{s.name()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn BarcodeView(code: QrCodeProps) -> Element {
+    let props = code;
+    let symbology = props.symbology;
+    let qr_data = if props.preserve_case {
+        props.data.clone()
+    } else {
+        props.data.to_uppercase()
+    };
 
     #[cfg(not(target_arch = "wasm32"))]
     let save_file_coroutine =
@@ -51,12 +161,11 @@ pub fn QrCode(props: QrCodeProps) -> Element {
             }
         });
 
-    if uppercased_data.len() <= STATIC_CHUNK_SIZE {
-        // --- STATIC QR CODE LOGIC WITH DOWNLOAD ---
-        match QrCode::with_error_correction_level(uppercased_data.as_bytes(), EcLevel::H) {
-            Ok(code) => {
-                let svg_image_data =
-                    use_memo(move || code.render::<svg::Color>().min_dimensions(200, 200).build());
+    if qr_data.len() <= symbology.chunk_size() {
+        // --- STATIC CODE LOGIC WITH DOWNLOAD ---
+        match static_svg(&qr_data, symbology) {
+            Ok(svg_string) => {
+                let svg_image_data = use_memo(move || svg_string.clone());
 
                 let svg_data_url = use_memo(move || {
                     let encoded =
@@ -68,7 +177,7 @@ pub fn QrCode(props: QrCodeProps) -> Element {
                     let filename_base = if let Some(ref caption) = props.caption {
                         caption.clone()
                     } else {
-                        let data = uppercased_data.clone();
+                        let data = qr_data.clone();
                         if data.len() > 24 {
                             format!("{}...{}", &data[..12], &data[data.len() - 12..])
                         } else {
@@ -76,7 +185,7 @@ pub fn QrCode(props: QrCodeProps) -> Element {
                         }
                     };
                     let filename_base = filename_base.replace(' ', "_");
-                    move || format!("{}-qr.svg", filename_base)
+                    move || format!("{}-{}.svg", filename_base, symbology.file_suffix())
                 });
 
                 let tooltip_text = props.tooltip.as_deref().unwrap_or(&props.data);
@@ -92,7 +201,7 @@ pub fn QrCode(props: QrCodeProps) -> Element {
                                     save_file_coroutine.send(SaveFileAction::SaveSvg(svg_data, name));
                                 },
                                 style: "font-size: 12px; margin-top: 10px; padding: 4px 8px;",
-                                "Save QR to File"
+                                "Save {symbology.name()} to File"
                             }
                         }
                     }
@@ -103,7 +212,7 @@ pub fn QrCode(props: QrCodeProps) -> Element {
                                 href: "{svg_data_url}",
                                 download: "{file_name}",
                                 style: "font-size: 12px; margin-top: 10px;",
-                                "Download QR"
+                                "Download {symbology.name()}"
                             }
                         }
                     }
@@ -131,17 +240,52 @@ pub fn QrCode(props: QrCodeProps) -> Element {
             Err(e) => rsx! {
                 p {
                     style: "color: red; font-family: sans-serif; font-size: 14px; border: 1px solid red; padding: 10px; border-radius: 5px;",
-                    "Error generating QR code: {e}"
+                    "Error generating {symbology.name()} code: {e}"
                 }
             },
         }
     } else {
         // --- ANIMATED QR CODE LOGIC ---
+        // Parts are cycled by a timer rather than by the SVG's own animation,
+        // so that the viewer can pause or change the speed.
+        let frame_urls = use_memo({
+            let data = qr_data.clone();
+            move || {
+                frame_svgs(&data, symbology, Some(200))
+                    .into_iter()
+                    .map(|svg_string| {
+                        let encoded = base64::engine::general_purpose::STANDARD.encode(svg_string);
+                        format!("data:image/svg+xml;base64,{encoded}")
+                    })
+                    .collect::<Vec<_>>()
+            }
+        });
+        let mut current_frame = use_signal(|| 0_usize);
+        let mut frame_duration_ms = use_signal(|| {
+            props
+                .frame_duration_ms
+                .clamp(MIN_FRAME_DURATION_MS, MAX_FRAME_DURATION_MS)
+        });
+        let mut is_paused = use_signal(|| false);
+        use_future(move || async move {
+            loop {
+                let duration = *frame_duration_ms.peek();
+                crate::compat::sleep(std::time::Duration::from_millis(duration.into())).await;
+                let num_frames = frame_urls.peek().len();
+                if !*is_paused.peek() && num_frames > 0 {
+                    let next = (*current_frame.peek() + 1) % num_frames;
+                    current_frame.set(next);
+                }
+            }
+        });
+
+        // The self-animating SVG, at the chosen speed, for saving to a file.
         let animated_svg = use_memo({
-            let data = uppercased_data.clone();
-            move || generate_animated_svg(&data)
+            let data = qr_data.clone();
+            move || generate_animated_svg(&data, symbology, frame_duration_ms())
         });
 
+        #[cfg(target_arch = "wasm32")]
         let animated_svg_data_url = use_memo(move || {
             let svg_string = animated_svg.read();
             let base64_encoded = base64::engine::general_purpose::STANDARD.encode(&*svg_string);
@@ -152,7 +296,7 @@ pub fn QrCode(props: QrCodeProps) -> Element {
             let filename_base = if let Some(ref caption) = props.caption {
                 caption.clone()
             } else {
-                let data = uppercased_data.clone();
+                let data = qr_data.clone();
                 if data.len() > 24 {
                     format!("{}...{}", &data[..12], &data[data.len() - 12..])
                 } else {
@@ -160,12 +304,18 @@ pub fn QrCode(props: QrCodeProps) -> Element {
                 }
             };
             let filename_base = filename_base.replace(' ', "_");
-            move || format!("{}-qr.svg", filename_base)
+            move || format!("{}-{}.svg", filename_base, symbology.file_suffix())
         });
 
         let tooltip_text = props.tooltip.as_deref().unwrap_or(&props.data);
         let caption_text = props.caption.clone().unwrap_or_default();
-        let frame_count = uppercased_data.len().div_ceil(STATIC_CHUNK_SIZE);
+        let frame_count = frame_urls.read().len();
+        let frame_src = frame_urls
+            .read()
+            .get(current_frame())
+            .cloned()
+            .unwrap_or_default();
+        let frames_per_second = 1000.0 / frame_duration_ms() as f32;
 
         let download_element = {
             #[cfg(not(target_arch = "wasm32"))]
@@ -199,7 +349,7 @@ pub fn QrCode(props: QrCodeProps) -> Element {
             figure {
                 style: "margin: 0; display: flex; flex-direction: column; align-items: center;",
                 img {
-                    src: "{animated_svg_data_url}",
+                    src: "{frame_src}",
                     width: "200",
                     height: "200",
                     title: "{tooltip_text}",
@@ -212,7 +362,31 @@ pub fn QrCode(props: QrCodeProps) -> Element {
                 }
                 figcaption {
                     style: "text-align: center; font-size: 12px; margin-top: 4px; color: #555;",
-                    "Animated QR Code ({frame_count} parts)"
+                    "Animated {symbology.name()} Code (part {current_frame() + 1} of {frame_count}, {frames_per_second:.1} parts/s)"
+                }
+                div {
+                    style: "display: flex; gap: 0.25rem; margin-top: 4px; font-size: 12px;",
+                    button {
+                        style: "font-size: 12px; padding: 2px 8px;",
+                        disabled: frame_duration_ms() >= MAX_FRAME_DURATION_MS,
+                        onclick: move |_| {
+                            frame_duration_ms.set((frame_duration_ms() * 3 / 2).min(MAX_FRAME_DURATION_MS))
+                        },
+                        "Slower"
+                    }
+                    button {
+                        style: "font-size: 12px; padding: 2px 8px;",
+                        onclick: move |_| is_paused.toggle(),
+                        if is_paused() { "Play" } else { "Pause" }
+                    }
+                    button {
+                        style: "font-size: 12px; padding: 2px 8px;",
+                        disabled: frame_duration_ms() <= MIN_FRAME_DURATION_MS,
+                        onclick: move |_| {
+                            frame_duration_ms.set((frame_duration_ms() * 2 / 3).max(MIN_FRAME_DURATION_MS))
+                        },
+                        "Faster"
+                    }
                 }
                 {download_element}
             }
@@ -220,46 +394,142 @@ pub fn QrCode(props: QrCodeProps) -> Element {
     }
 }
 
-/// Generates a self-contained, animated SVG string for a multipart QR code.
-fn generate_animated_svg(data: &str) -> String {
-    const CHUNK_SIZE: usize = 120;
-    const FRAME_DURATION_MS: u32 = 300;
+/// Renders `data` as a single code.
+fn static_svg(data: &str, symbology: Symbology) -> Result<String, String> {
+    match symbology {
+        Symbology::Qr => QrCode::with_error_correction_level(data.as_bytes(), EcLevel::H)
+            .map(|code| code.render::<svg::Color>().min_dimensions(200, 200).build())
+            .map_err(|e| e.to_string()),
+        Symbology::Aztec => rxing::aztec::encoder::encode(data, AZTEC_EC_PERCENT, 0)
+            .map(|code| matrix_svg(code.getMatrix(), 200))
+            .map_err(|e| e.to_string()),
+    }
+}
 
+/// Renders each part of an animated code, all at the same size.
+fn frame_svgs(data: &str, symbology: Symbology, min_dimension: Option<u32>) -> Vec<String> {
+    let frames = frame_payloads(data, symbology.chunk_size());
+    match symbology {
+        Symbology::Qr => frame_codes(&frames)
+            .into_iter()
+            .map(|code| {
+                let mut renderer = code.render::<svg::Color>();
+                if let Some(min) = min_dimension {
+                    renderer.min_dimensions(min, min);
+                }
+                renderer.build()
+            })
+            .collect(),
+        Symbology::Aztec => aztec_frame_codes(&frames)
+            .iter()
+            .map(|code| matrix_svg(code.getMatrix(), min_dimension.unwrap_or(0)))
+            .collect(),
+    }
+}
+
+/// Splits `data` into the `P{part}/{total}/{chunk}` frames that
+/// [QrProcessor](crate::components::qr_processor::QrProcessor) reassembles.
+fn frame_payloads(data: &str, chunk_size: usize) -> Vec<String> {
     let chunks: Vec<_> = data
         .chars()
         .collect::<Vec<char>>()
-        .chunks(CHUNK_SIZE)
+        .chunks(chunk_size)
         .map(|c| c.iter().collect::<String>())
         .collect();
 
     let total_parts = chunks.len();
-    if total_parts == 0 {
-        return String::new();
-    }
-
-    let frames: Vec<_> = chunks
+    chunks
         .into_iter()
         .enumerate()
         .map(|(i, chunk)| format!("P{}/{}/{}", i + 1, total_parts, chunk))
-        .collect();
+        .collect()
+}
 
-    // --- Generate the first frame to establish the standard size ---
+/// Encodes each frame at the version needed by the first, so that all frames
+/// are the same size.
+///
+/// This assumes that no frame after the first will ever require a larger QR
+/// code version, which holds as only the last chunk may be shorter.
+fn frame_codes(frames: &[String]) -> Vec<QrCode> {
     let Some(first_frame_data) = frames.first() else {
-        return String::new();
+        return vec![];
     };
     let Ok(first_code) =
         QrCode::with_error_correction_level(first_frame_data.as_bytes(), EcLevel::L)
     else {
-        return String::new();
+        return vec![];
     };
-
-    // Use the version and error correction level from the first frame for all subsequent frames.
-    // This approach assumes that no frame after the first will ever require a
-    // larger QR code version.
     let version = first_code.version();
     let ec_level = first_code.error_correction_level();
 
-    let first_svg_str = first_code.render::<svg::Color>().build();
+    frames
+        .iter()
+        .filter_map(|frame_data| {
+            QrCode::with_version(frame_data.as_bytes(), version, ec_level).ok()
+        })
+        .collect()
+}
+
+/// Like [frame_codes], for Aztec codes.  Each frame has the layers of the
+/// first.
+fn aztec_frame_codes(frames: &[String]) -> Vec<rxing::aztec::encoder::AztecCode> {
+    let Some(first_frame_data) = frames.first() else {
+        return vec![];
+    };
+    let Ok(first_code) = rxing::aztec::encoder::encode(first_frame_data, AZTEC_EC_PERCENT, 0)
+    else {
+        return vec![];
+    };
+    // negative layers ask for a compact symbol.
+    let layers = first_code.getLayers() as i32;
+    let layers = if first_code.isCompact() {
+        -layers
+    } else {
+        layers
+    };
+
+    frames
+        .iter()
+        .filter_map(|frame_data| {
+            rxing::aztec::encoder::encode(frame_data, AZTEC_EC_PERCENT, layers).ok()
+        })
+        .collect()
+}
+
+/// Renders a barcode matrix in the layout of the `qrcode` crate's SVGs, so
+/// that [generate_animated_svg] and the uploader handle both alike.
+fn matrix_svg(matrix: &rxing::common::BitMatrix, min_dimension: u32) -> String {
+    // Aztec codes need no quiet zone.  A narrow one still helps scanners.
+    const QUIET_ZONE: u32 = 2;
+
+    let width = matrix.getWidth() + 2 * QUIET_ZONE;
+    let height = matrix.getHeight() + 2 * QUIET_ZONE;
+    let scale = min_dimension.div_ceil(width.max(height)).max(1);
+
+    let mut dark = String::new();
+    for y in 0..matrix.getHeight() {
+        for x in 0..matrix.getWidth() {
+            if matrix.get(x, y) {
+                dark.push_str(&format!("M{} {}h1v1h-1z", x + QUIET_ZONE, y + QUIET_ZONE));
+            }
+        }
+    }
+
+    format!(
+        r##"<?xml version="1.0" standalone="yes"?><svg xmlns="http://www.w3.org/2000/svg" version="1.1" width="{}" height="{}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges"><path fill="#fff" d="M0 0h{width}v{height}H0z"/><path fill="#000" d="{dark}"/></svg>"##,
+        width * scale,
+        height * scale,
+    )
+}
+
+/// Generates a self-contained, animated SVG string for a multipart code.
+fn generate_animated_svg(data: &str, symbology: Symbology, frame_duration_ms: u32) -> String {
+    let frame_svgs = frame_svgs(data, symbology, None);
+
+    // --- Use the first frame to establish the standard size ---
+    let Some(first_svg_str) = frame_svgs.first() else {
+        return String::new();
+    };
 
     let view_box = first_svg_str
         .split_once("viewBox=\"")
@@ -267,30 +537,21 @@ fn generate_animated_svg(data: &str) -> String {
         .map(|(vb, _)| vb)
         .unwrap_or("0 0 256 256");
 
-    // --- Generate all frame contents, forcing each to the same version ---
-    let frame_contents: Vec<String> = frames
+    // --- Extract the drawing of each frame ---
+    let frame_contents: Vec<String> = frame_svgs
         .iter()
-        .filter_map(|frame_data| {
-            QrCode::with_version(frame_data.as_bytes(), version, ec_level)
-                .ok()
-                .map(|code| {
-                    let svg_str = code.render::<svg::Color>().build();
-                    if let Some(path_start) = svg_str.find("<path") {
-                        if let Some(end_svg) = svg_str.rfind("</svg>") {
-                            return svg_str[path_start..end_svg].to_string();
-                        }
-                    }
-                    String::new()
-                })
+        .map(|svg_str| {
+            if let Some(path_start) = svg_str.find("<path") {
+                if let Some(end_svg) = svg_str.rfind("</svg>") {
+                    return svg_str[path_start..end_svg].to_string();
+                }
+            }
+            String::new()
         })
         .collect();
 
     let num_frames = frame_contents.len();
-    if num_frames == 0 {
-        return String::new();
-    }
-
-    let total_duration_ms = num_frames as u32 * FRAME_DURATION_MS;
+    let total_duration_ms = num_frames as u32 * frame_duration_ms;
     let frame_visibility_percentage = 100.0 / num_frames as f32;
 
     let style = format!(
@@ -310,7 +571,7 @@ fn generate_animated_svg(data: &str) -> String {
         .into_iter()
         .enumerate()
         .map(|(i, content)| {
-            let delay = i as u32 * FRAME_DURATION_MS;
+            let delay = i as u32 * frame_duration_ms;
             format!(r#"<g class="qr-frame" style="animation-delay: {delay}ms;">{content}</g>"#)
         })
         .collect::<String>();
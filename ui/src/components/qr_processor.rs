@@ -40,15 +40,9 @@ impl QrProcessor {
             return QrProcessResult::Error("Processing already completed.".to_string());
         }
 
-        let mut prepared_image = rqrr::PreparedImage::prepare(image_buffer);
-        let grids = prepared_image.detect_grids();
-
-        let Some(grid) = grids.first() else {
-            return QrProcessResult::Error("No QR code found in image.".to_string());
-        };
-
-        let Ok((_meta, content)) = grid.decode() else {
-            return QrProcessResult::Error("Failed to decode QR content.".to_string());
+        let content = match decode(image_buffer) {
+            Ok(content) => content,
+            Err(e) => return QrProcessResult::Error(e),
         };
 
         // Case 1: Simple, non-animated QR code
@@ -105,3 +99,51 @@ impl QrProcessor {
         QrProcessResult::Incomplete(num_scanned, total_expected)
     }
 }
+
+/// Decodes the QR or Aztec code in an image.
+pub fn decode(image_buffer: GrayImage) -> Result<String, String> {
+    let (width, height) = image_buffer.dimensions();
+    let luma = image_buffer.as_raw().clone();
+
+    let mut prepared_image = rqrr::PreparedImage::prepare(image_buffer);
+    if let Some(grid) = prepared_image.detect_grids().first() {
+        return grid
+            .decode()
+            .map(|(_meta, content)| content)
+            .map_err(|_| "Failed to decode QR content.".to_string());
+    }
+
+    rxing::helpers::detect_in_luma(luma, width, height, Some(rxing::BarcodeFormat::AZTEC))
+        .map(|result| result.getText().to_string())
+        .map_err(|_| "No QR or Aztec code found in image.".to_string())
+}
+
+/// Extracts an address or payment URI from the content of a `.txt` or
+/// `.json` file, for transfer without a camera.
+///
+/// A text file holds just the address or URI.  A JSON file holds either a
+/// string, or an object with an `address`, `uri` or `payment_request` field.
+pub fn decode_text_file(content: &str, is_json: bool) -> Result<String, String> {
+    let text = if is_json {
+        let value: serde_json::Value =
+            serde_json::from_str(content).map_err(|e| format!("Invalid JSON: {e}"))?;
+        match value {
+            serde_json::Value::String(s) => s,
+            serde_json::Value::Object(map) => ["address", "uri", "payment_request"]
+                .iter()
+                .find_map(|key| map.get(*key).and_then(|v| v.as_str()).map(str::to_string))
+                .ok_or_else(|| {
+                    "The JSON has no address, uri or payment_request field.".to_string()
+                })?,
+            _ => return Err("The JSON holds no address.".to_string()),
+        }
+    } else {
+        content.to_string()
+    };
+
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("The file is empty.".to_string());
+    }
+    Ok(text.to_string())
+}
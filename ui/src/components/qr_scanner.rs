@@ -4,9 +4,15 @@
 
 use std::collections::HashMap;
 
+use api::prefs::camera_resolution::CameraResolution;
 use dioxus::prelude::*;
 use serde::Deserialize;
 use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use crate::app_state_mut::AppStateMut;
+use crate::components::qr_uploader::QrUploader;
+use crate::i18n::t;
 
 // Tier 2 (Desktop): Uses Native Rust (Nokhwa).
 // UNIFIED: Linux, Windows, and macOS all use the native path now.
@@ -51,6 +57,18 @@ pub enum ScannerMessage {
     DeviceList {
         devices: Vec<VideoDevice>,
     },
+    /// The user or the platform denied access to the camera.
+    PermissionDenied,
+    /// There is no camera, or the webview cannot use it.
+    NoCamera,
+    /// Whether the camera has a torch that can be turned on.
+    TorchAvailable {
+        available: bool,
+    },
+    /// Frames decoded per second, sent in debug builds.
+    DecodeRate {
+        fps: f32,
+    },
     // Used specifically by Desktop to push frames to the UI
     FrameBase64 {
         data: String,
@@ -59,6 +77,27 @@ pub enum ScannerMessage {
     },
 }
 
+/// Why the camera cannot be used for scanning.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CameraProblem {
+    PermissionDenied,
+    NoCamera,
+}
+
+/// How to allow the camera again, on this platform.
+fn permission_instructions() -> String {
+    if cfg!(target_os = "android") {
+        t!("qr-scanner-permission-android")
+    } else if cfg!(target_os = "ios") {
+        t!("qr-scanner-permission-ios")
+    } else {
+        t!("qr-scanner-permission-browser")
+    }
+}
+
+/// Scans a QR code, or a sequence of them, with the camera.  If the camera
+/// cannot be used, explains why and offers to read the code from a file
+/// instead.
 #[component]
 pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> Element {
     let mut error_message = use_signal(|| None::<String>);
@@ -67,19 +106,76 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
 
     let mut video_devices = use_signal(Vec::<VideoDevice>::new);
     let mut selected_device_id = use_signal(String::new);
+    let mut resolution = use_signal(CameraResolution::default);
     let mut scanner_status = use_signal(|| "Initializing...".to_string());
 
     // Controls the horizontal flip (Mirroring)
     let mut mirror_feed = use_signal(|| true);
 
+    // The camera is released while the window is hidden.
+    let visible = use_context::<AppStateMut>().visible;
+    let mut scan_task = use_signal(|| None::<Task>);
+
+    // Set when the camera cannot be used.  Bumping `camera_attempt` asks for
+    // it again.
+    let mut camera_problem = use_signal(|| None::<CameraProblem>);
+    let mut camera_attempt = use_signal(|| 0_u32);
+
+    // The torch button is only shown if the camera has one.
+    let mut torch_available = use_signal(|| false);
+    let mut torch_on = use_signal(|| false);
+
+    // Shown in debug builds, to tune decoding on slow devices.
+    let mut decode_fps = use_signal(|| None::<f32>);
+
+    // The camera and resolution last used.  Scanning waits for them.
+    let mut prefs_loaded = use_signal(|| false);
+    use_future(move || async move {
+        if let Ok(prefs) = api::get_user_prefs().await {
+            if let Some(device) = prefs.scanner_device() {
+                selected_device_id.set(device.to_string());
+            }
+            resolution.set(prefs.scanner_resolution());
+        }
+        prefs_loaded.set(true);
+    });
+
+    // Remembers the camera and resolution the user picked.
+    let save_camera = move || {
+        let device = Some(selected_device_id.peek().clone()).filter(|d| !d.is_empty());
+        let resolution = *resolution.peek();
+        spawn(async move {
+            let _ = api::set_scanner_camera(device, resolution).await;
+        });
+    };
+
     // --- Main Logic Loop ---
     use_effect(move || {
-        // Rerun the effect whenever the selected_device_id changes
+        // Rerun the effect whenever the selected_device_id or resolution changes
         let device_id = selected_device_id.read().clone();
+        let resolution = resolution();
+        let _ = camera_attempt();
+        if !prefs_loaded() {
+            return;
+        }
 
-        spawn(async move {
+        // ... or the window is hidden or shown.
+        if let Some(task) = scan_task.take() {
+            task.cancel();
+        }
+        if !visible() {
+            platform_impl::stop_scanner();
+            scanner_status.set("Paused".into());
+            return;
+        }
+
+        // a new stream starts with the torch off.
+        torch_available.set(false);
+        torch_on.set(false);
+
+        let task = spawn(async move {
             scanner_status.set("Starting Camera...".into());
-            let mut rx = platform_impl::start_scanner(&device_id).await;
+            let mut rx = platform_impl::start_scanner(&device_id, resolution).await;
 
             while let Some(msg) = rx.recv().await {
                 match msg {
@@ -103,9 +199,23 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
                             &mut total_parts,
                         );
                     }
+                    ScannerMessage::PermissionDenied => {
+                        platform_impl::stop_scanner();
+                        camera_problem.set(Some(CameraProblem::PermissionDenied));
+                    }
+                    ScannerMessage::NoCamera => {
+                        platform_impl::stop_scanner();
+                        camera_problem.set(Some(CameraProblem::NoCamera));
+                    }
+                    ScannerMessage::TorchAvailable { available } => {
+                        torch_available.set(available)
+                    }
+                    ScannerMessage::DecodeRate { fps } => decode_fps.set(Some(fps)),
                     ScannerMessage::DeviceList { devices } => {
                         if video_devices.read().len() != devices.len() {
-                            if selected_device_id.read().is_empty() {
+                            // the remembered camera may be gone.
+                            let selected = selected_device_id.peek().clone();
+                            if !devices.iter().any(|d| d.id == selected) {
                                 if let Some(first) = devices.first() {
                                     selected_device_id.set(first.id.clone());
                                 }
@@ -141,8 +251,59 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
                 }
             }
         });
+        scan_task.set(Some(task));
     });
 
+    // Without a camera, the code can still be read from a file.
+    if let Some(problem) = camera_problem() {
+        let (title, explanation) = match problem {
+            CameraProblem::PermissionDenied => (
+                t!("qr-scanner-permission-denied"),
+                permission_instructions(),
+            ),
+            CameraProblem::NoCamera => (
+                t!("qr-scanner-no-camera"),
+                t!("qr-scanner-no-camera-help"),
+            ),
+        };
+        return rsx! {
+            div {
+                style: "display: flex; flex-direction: column; gap: 0.5rem; max-width: 500px; margin: auto;",
+                article {
+                    role: "alert",
+                    h4 { style: "margin-bottom: 0.5rem;", "{title}" }
+                    p { style: "margin: 0;", "{explanation}" }
+                }
+                p { {t!("qr-scanner-use-file")} }
+                QrUploader {
+                    on_scan: move |data| {
+                        on_scan.call(data);
+                        on_close.call(());
+                    },
+                    on_close,
+                }
+                div {
+                    style: "display: flex; justify-content: space-around; gap: 1rem; margin-top: 1rem;",
+                    button {
+                        class: "secondary",
+                        style: "margin: 0;",
+                        onclick: move |_| {
+                            camera_problem.set(None);
+                            error_message.set(None);
+                            camera_attempt += 1;
+                        },
+                        {t!("qr-scanner-try-camera")}
+                    }
+                    button {
+                        style: "margin: 0;",
+                        onclick: move |_| on_close.call(()),
+                        {t!("common-cancel")}
+                    }
+                }
+            }
+        };
+    }
+
     let error_display = error_message.read().as_ref().map(|err| {
         rsx! {
             p { style: "color: var(--pico-color-red-500);", "{err}" }
@@ -189,16 +350,17 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
         }
     };
 
-    // FIX: Placeholder for the commented-out <select> field
-    let device_selector_hidden = if !video_devices.read().is_empty() {
-        rsx! {
-            // This div replaces the previous cycling button/placeholder
-            div {
-                // Style to hide the select element entirely but keep it in the code
-                style: "position: absolute; width: 0; height: 0; overflow: hidden; opacity: 0;",
+    let camera_selectors = rsx! {
+        div {
+            style: "display: flex; gap: 0.5rem; width: 100%; max-width: 400px; margin: auto;",
+            if video_devices.read().len() > 1 {
                 select {
-                    aria_label: "Select Camera",
-                    onchange: move |event| selected_device_id.set(event.value()),
+                    aria_label: t!("qr-scanner-camera"),
+                    style: "margin: 0; flex: 2;",
+                    onchange: move |event| {
+                        selected_device_id.set(event.value());
+                        save_camera();
+                    },
                     for device in video_devices.read().iter() {
                         option {
                             key: "{device.id}",
@@ -209,9 +371,28 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
                     }
                 }
             }
+            select {
+                aria_label: t!("qr-scanner-resolution"),
+                style: "margin: 0; flex: 1;",
+                onchange: move |event| {
+                    if let Ok(r) = event.value().parse::<CameraResolution>() {
+                        resolution.set(r);
+                        save_camera();
+                    }
+                },
+                for r in CameraResolution::iter() {
+                    option {
+                        key: "{r}",
+                        value: "{r}",
+                        selected: resolution() == r,
+                        {
+                            let (width, height) = r.size();
+                            format!("{width}×{height}")
+                        }
+                    }
+                }
+            }
         }
-    } else {
-        rsx! {}
     };
 
     let flip_style = if *mirror_feed.read() {
@@ -226,8 +407,7 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
         div {
             style: "display: flex; flex-direction: column; gap: 0.5rem; max-width: 500px; margin: auto;",
 
-            // Inject the hidden select field here (FIX 1)
-            {device_selector_hidden}
+            {camera_selectors}
 
             // Only show error or the unified status/progress block, never both.
             if let Some(err_rsx) = error_display {
@@ -262,6 +442,13 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
                 }
             }
 
+            if let Some(fps) = decode_fps().filter(|_| cfg!(debug_assertions)) {
+                small {
+                    style: "text-align: center; color: var(--pico-muted-color);",
+                    "Decoding {fps:.1} frames/s"
+                }
+            }
+
             // Controls: Flip and Cancel
             div {
                 // Use space-around to separate the two buttons nicely
@@ -274,6 +461,22 @@ pub fn QrScanner(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> E
                     "{flip_button_text}"
                 }
 
+                if torch_available() {
+                    button {
+                        class: if torch_on() { "" } else { "secondary outline" },
+                        style: "white-space: nowrap; margin: 0;",
+                        "aria-pressed": "{torch_on()}",
+                        title: t!("qr-scanner-torch"),
+                        aria_label: t!("qr-scanner-torch"),
+                        onclick: move |_| {
+                            let on = !torch_on();
+                            torch_on.set(on);
+                            platform_impl::set_torch(on);
+                        },
+                        "🔦"
+                    }
+                }
+
                 button {
                     onclick: move |_| { on_close.call(()); },
                     style: "margin: 0; min-width: 100px;",
@@ -333,41 +536,105 @@ fn handle_scan_result(
 //=============================================================================
 #[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
 mod web_impl {
+    use api::prefs::camera_resolution::CameraResolution;
     use dioxus::prelude::*;
 
     use super::ScannerMessage;
     use super::VideoDevice;
+    use crate::i18n::t;
 
     const JS_QR_SOURCE: &str = include_str!("../../assets/js/jsQR.js");
 
+    /// Turns the camera off.  The scan loop idles without a video feed.
+    pub fn stop_scanner() {
+        let _ = document::eval(
+            r#"
+            const video = document.getElementById('qr-video');
+            if (video && video.srcObject) {
+                video.srcObject.getTracks().forEach(t => t.stop());
+                video.srcObject = null;
+            }
+            "#,
+        );
+    }
+
+    /// Turns the torch of the camera in use on or off.
+    pub fn set_torch(on: bool) {
+        let _ = document::eval(&format!(
+            r#"
+            const video = document.getElementById('qr-video');
+            const track = video && video.srcObject ? video.srcObject.getVideoTracks()[0] : null;
+            if (track) track.applyConstraints({{ advanced: [{{ torch: {on} }}] }}).catch(() => {{}});
+            "#
+        ));
+    }
+
     pub async fn start_scanner(
         device_id: &str,
+        resolution: CameraResolution,
     ) -> tokio::sync::mpsc::UnboundedReceiver<ScannerMessage> {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         let tx = std::sync::Arc::new(tx);
+        let (width, height) = resolution.size();
         let requested_device_id = device_id.to_string();
 
         let script = format!(
             r#"
-            // 1. Inject the bundled JS Library
-            {library_code}
+            // 1. The bundled JS Library, loaded into a worker, or on the main
+            // thread if workers are not available.
+            const jsQrSource = {library_source};
 
             // 2. Main Scanner Logic
             const video = document.getElementById('qr-video');
-            const canvas = document.getElementById('qr-canvas');
             if (!video) return;
 
+            if (!navigator.mediaDevices || !navigator.mediaDevices.getUserMedia) {{
+                dioxus.send({{type: "nocamera"}});
+                return;
+            }}
+
             if (video.srcObject) video.srcObject.getTracks().forEach(t => t.stop());
 
             let isRunning = true;
             let hasNativeAPI = ('BarcodeDetector' in window);
             let barcodeDetector = hasNativeAPI ? new BarcodeDetector({{formats: ['qr_code']}}) : null;
 
+            // jsQR decodes in a worker, so that slow decoding does not block the UI.
+            let worker = null;
+            if (!hasNativeAPI) {{
+                try {{
+                    const workerSource = jsQrSource + `
+                        self.onmessage = (e) => {{
+                            const {{ data, width, height }} = e.data;
+                            const code = jsQR(new Uint8ClampedArray(data), width, height, {{ inversionAttempts: "dontInvert" }});
+                            self.postMessage(code ? code.data : null);
+                        }};`;
+                    worker = new Worker(URL.createObjectURL(new Blob([workerSource], {{ type: "text/javascript" }})));
+                }} catch (e) {{
+                    worker = null;
+                }}
+                if (!worker && !window.jsQR) new Function(jsQrSource)();
+            }}
+
             async function run() {{
                 try {{
+                    // the permissions api is missing on some platforms, which then just prompt.
+                    try {{
+                        const permission = await navigator.permissions.query({{ name: "camera" }});
+                        if (permission.state === "denied") {{
+                            dioxus.send({{type: "permissiondenied"}});
+                            return;
+                        }}
+                        if (permission.state === "prompt") {{
+                            dioxus.send({{type: "status", msg: {prompt_msg}}});
+                        }}
+                    }} catch (e) {{}}
+
                     let constraints = {{ video: {{ facingMode: "environment" }} }};
                     const reqId = "{req_id}";
                     if (reqId && reqId !== "") constraints.video = {{ deviceId: {{ exact: reqId }} }};
+                    constraints.video.width = {{ ideal: {width} }};
+                    constraints.video.height = {{ ideal: {height} }};
 
                     const stream = await navigator.mediaDevices.getUserMedia(constraints);
 
@@ -377,6 +644,10 @@ mod web_impl {
                     video.setAttribute('playsinline', 'true');
                     await video.play();
 
+                    const track = stream.getVideoTracks()[0];
+                    const capabilities = track && track.getCapabilities ? track.getCapabilities() : {{}};
+                    dioxus.send({{type: "torchavailable", available: !!capabilities.torch}});
+
                     dioxus.send({{type: "status", msg: "Scanning (Live Feed)..."}});
 
                     try {{
@@ -387,37 +658,93 @@ mod web_impl {
                         dioxus.send({{type: "devicelist", devices: videoDevices}});
                     }} catch (e) {{}}
 
-                    const ctx = canvas.getContext('2d', {{ willReadFrequently: true }});
+                    // One frame is decoded at a time, at most every FRAME_INTERVAL_MS,
+                    // scaled down to MAX_DECODE_WIDTH.
+                    const FRAME_INTERVAL_MS = hasNativeAPI ? 100 : 150;
+                    const MAX_DECODE_WIDTH = 800;
+                    const frameCanvas = document.createElement('canvas');
+                    const ctx = frameCanvas.getContext('2d', {{ willReadFrequently: true }});
+                    let busy = false;
+                    let decodes = 0;
+                    let rateStart = performance.now();
+
+                    const decoded = (value) => {{
+                        busy = false;
+                        if (value) dioxus.send({{type: "content", value: value}});
+                        if ({debug}) {{
+                            decodes++;
+                            const now = performance.now();
+                            if (now - rateStart >= 1000) {{
+                                dioxus.send({{type: "decoderate", fps: decodes * 1000 / (now - rateStart)}});
+                                decodes = 0;
+                                rateStart = now;
+                            }}
+                        }}
+                    }};
+                    if (worker) {{
+                        worker.onmessage = (e) => decoded(e.data);
+                        worker.onerror = () => {{
+                            worker.terminate();
+                            worker = null;
+                            if (!window.jsQR) new Function(jsQrSource)();
+                            busy = false;
+                        }};
+                    }}
 
                     const scanFrame = async () => {{
-                        if (!video.isConnected) {{ if (stream) stream.getTracks().forEach(t => t.stop()); isRunning = false; return; }}
+                        if (!video.isConnected) {{
+                            if (stream) stream.getTracks().forEach(t => t.stop());
+                            if (worker) worker.terminate();
+                            isRunning = false;
+                            return;
+                        }}
                         if (!isRunning) return;
 
-                        if (video.readyState === video.HAVE_ENOUGH_DATA && video.videoWidth > 0) {{
+                        if (!busy && video.readyState === video.HAVE_ENOUGH_DATA && video.videoWidth > 0) {{
+                            busy = true;
                             try {{
                                 if (hasNativeAPI) {{
                                     const barcodes = await barcodeDetector.detect(video);
-                                    if (barcodes.length > 0) dioxus.send({{type: "content", value: barcodes[0].rawValue}});
-                                }} else if (window.jsQR && canvas) {{
-                                    if (canvas.width !== video.videoWidth) {{ canvas.width = video.videoWidth; canvas.height = video.videoHeight; }}
-                                    ctx.drawImage(video, 0, 0);
-                                    const imageData = ctx.getImageData(0, 0, canvas.width, canvas.height);
-                                    const code = jsQR(imageData.data, imageData.width, imageData.height, {{ inversionAttempts: "dontInvert" }});
-                                    if (code) dioxus.send({{type: "content", value: code.data}});
+                                    decoded(barcodes.length > 0 ? barcodes[0].rawValue : null);
+                                }} else {{
+                                    const scale = Math.min(1, MAX_DECODE_WIDTH / video.videoWidth);
+                                    const width = Math.round(video.videoWidth * scale);
+                                    const height = Math.round(video.videoHeight * scale);
+                                    if (frameCanvas.width !== width) {{ frameCanvas.width = width; frameCanvas.height = height; }}
+                                    ctx.drawImage(video, 0, 0, width, height);
+                                    const imageData = ctx.getImageData(0, 0, width, height);
+                                    if (worker) {{
+                                        worker.postMessage({{ data: imageData.data.buffer, width: width, height: height }}, [imageData.data.buffer]);
+                                    }} else if (window.jsQR) {{
+                                        const code = jsQR(imageData.data, width, height, {{ inversionAttempts: "dontInvert" }});
+                                        decoded(code ? code.data : null);
+                                    }} else {{
+                                        busy = false;
+                                    }}
                                 }}
-                            }} catch (err) {{}}
+                            }} catch (err) {{
+                                busy = false;
+                            }}
                         }}
-                        setTimeout(() => {{ if(isRunning) requestAnimationFrame(scanFrame); }}, hasNativeAPI ? 100 : 200);
+                        setTimeout(() => {{ if(isRunning) requestAnimationFrame(scanFrame); }}, FRAME_INTERVAL_MS);
                     }};
                     scanFrame();
                 }} catch(e) {{
-                    dioxus.send({{ type: "error", msg: e.toString() }});
+                    if (e.name === "NotAllowedError" || e.name === "SecurityError") {{
+                        dioxus.send({{type: "permissiondenied"}});
+                    }} else if (e.name === "NotFoundError" || e.name === "OverconstrainedError") {{
+                        dioxus.send({{type: "nocamera"}});
+                    }} else {{
+                        dioxus.send({{ type: "error", msg: e.toString() }});
+                    }}
                 }}
             }}
             run();
         "#,
-            library_code = JS_QR_SOURCE,
-            req_id = requested_device_id
+            library_source = serde_json::to_string(JS_QR_SOURCE).unwrap_or_default(),
+            req_id = requested_device_id,
+            debug = cfg!(debug_assertions),
+            prompt_msg = serde_json::to_string(&t!("qr-scanner-permission-prompt")).unwrap_or_default(),
         );
 
         spawn(async move {
@@ -441,6 +768,7 @@ mod native_impl {
     use std::collections::HashSet;
     use std::thread;
 
+    use api::prefs::camera_resolution::CameraResolution;
     use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
     use base64::engine::Engine;
     use nokhwa::pixel_format::RgbFormat;
@@ -451,11 +779,21 @@ mod native_impl {
 
     use super::ScannerMessage;
     use super::VideoDevice;
+    use crate::components::qr_processor;
+
+    /// Nothing to do: the capture thread exits once its receiver is dropped
+    /// with the cancelled scan task.
+    pub fn stop_scanner() {}
+
+    /// Desktop cameras have no torch.
+    pub fn set_torch(_: bool) {}
 
     pub async fn start_scanner(
         device_id: &str,
+        resolution: CameraResolution,
     ) -> tokio::sync::mpsc::UnboundedReceiver<ScannerMessage> {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let (width, height) = resolution.size();
 
         let req_index = if let Ok(idx) = device_id.parse::<u32>() {
             CameraIndex::Index(idx)
@@ -466,8 +804,8 @@ mod native_impl {
         thread::spawn(move || {
             let requested = RequestedFormat::new::<RgbFormat>(RequestedFormatType::Closest(
                 nokhwa::utils::CameraFormat::new_from(
-                    640,
-                    480,
+                    width,
+                    height,
                     nokhwa::utils::FrameFormat::MJPEG,
                     30,
                 ),
@@ -570,13 +908,9 @@ mod native_impl {
                         if last_scan.elapsed().as_millis() > 200 {
                             last_scan = std::time::Instant::now();
                             let gray_img = dyn_img.to_luma8();
-                            let mut img = rqrr::PreparedImage::prepare(gray_img);
-                            if let Some(grid) = img.detect_grids().first() {
-                                if let Ok((_, content)) = grid.decode() {
-                                    if tx.send(ScannerMessage::Content { value: content }).is_err()
-                                    {
-                                        break;
-                                    }
+                            if let Ok(content) = qr_processor::decode(gray_img) {
+                                if tx.send(ScannerMessage::Content { value: content }).is_err() {
+                                    break;
                                 }
                             }
                         }
@@ -600,8 +934,17 @@ mod native_impl {
     not(target_os = "ios")
 ))]
 mod server_impl {
+    use api::prefs::camera_resolution::CameraResolution;
+
     use super::ScannerMessage;
-    pub async fn start_scanner(_: &str) -> tokio::sync::mpsc::UnboundedReceiver<ScannerMessage> {
+    pub async fn start_scanner(
+        _: &str,
+        _: CameraResolution,
+    ) -> tokio::sync::mpsc::UnboundedReceiver<ScannerMessage> {
         tokio::sync::mpsc::unbounded_channel().1
     }
+
+    pub fn stop_scanner() {}
+
+    pub fn set_torch(_: bool) {}
 }
@@ -5,6 +5,7 @@ use dioxus::prelude::*;
 
 use crate::compat;
 use crate::components::pico::Button;
+use crate::components::qr_processor::decode_text_file;
 use crate::components::qr_processor::QrProcessResult;
 use crate::components::qr_processor::QrProcessor;
 
@@ -115,75 +116,76 @@ mod svg_reader {
     }
 }
 
+/// The file types the uploader reads: animated or static QR/Aztec SVGs, and
+/// text or JSON files holding an address or payment URI.
+const FILE_EXTENSIONS: [&str; 3] = ["svg", "txt", "json"];
+
+/// Opens a file picker for an SVG code, or a text or JSON file holding an
+/// address or payment URI.  Files can also be dropped onto it.
 #[component]
 pub fn QrUploader(on_scan: EventHandler<String>, on_close: EventHandler<()>) -> Element {
     let mut upload_progress = use_signal(|| (0, 0));
     let mut upload_error = use_signal(|| None::<String>);
     let mut is_processing = use_signal(|| false);
     let mut status_message = use_signal(|| "Waiting for file selection...".to_string());
+    let mut is_drag_over = use_signal(|| false);
 
-    use_effect(move || {
+    let process_file = move |file_name: String, content: String| {
         spawn(async move {
-            let file_content = match compat::read_file("svg").await {
-                Ok(Some(content)) => content,
-                Ok(None) => {
-                    on_close.call(());
-                    return;
-                }
-                Err(e) => {
-                    upload_error.set(Some(format!("Failed to read file: {}", e)));
-                    return;
-                }
-            };
-
-            is_processing.set(true);
-            status_message.set("Extracting frames...".to_string());
-
-            let (view_box, frames) = match svg_reader::extract_svg_details(&file_content) {
-                Ok(details) => details,
-                Err(e) => {
-                    upload_error.set(Some(e));
+            upload_error.set(None);
+            let extension = file_name
+                .rsplit_once('.')
+                .map(|(_, ext)| ext.to_lowercase())
+                .unwrap_or_default();
+            let result = match extension.as_str() {
+                "txt" | "json" => decode_text_file(&content, extension == "json"),
+                "svg" => {
+                    is_processing.set(true);
+                    let result =
+                        process_svg(&content, status_message, upload_progress).await;
                     is_processing.set(false);
-                    return;
+                    result
                 }
+                _ => Err(format!(
+                    "Unsupported file type. Choose a {} file.",
+                    FILE_EXTENSIONS.join(", ")
+                )),
             };
-
-            upload_progress.set((0, frames.len()));
-            let mut processor = QrProcessor::new();
-
-            for (i, frame_svg) in frames.iter().enumerate() {
-                status_message.set(format!("Processing frame {} of {}", i + 1, frames.len()));
-                upload_progress.set((i + 1, frames.len()));
-
-                let pixel_buffer = match svg_reader::render_svg_frame(frame_svg, &view_box) {
-                    Ok(pb) => pb,
-                    Err(e) => {
-                        upload_error.set(Some(format!("Failed to render SVG frame: {}", e)));
-                        is_processing.set(false);
-                        return;
-                    }
-                };
-                match processor.process_image(pixel_buffer) {
-                    QrProcessResult::Complete(data) => {
-                        on_scan.call(data);
-                        return;
-                    }
-                    QrProcessResult::Incomplete(_, _) => {
-                        compat::sleep(std::time::Duration::from_millis(1)).await;
-                    }
-                    QrProcessResult::Error(_) => {}
-                }
+            match result {
+                Ok(data) => on_scan.call(data),
+                Err(e) => upload_error.set(Some(e)),
             }
+        });
+    };
 
-            if !processor.is_complete() {
-                upload_error.set(Some(
-                    "Scanned all frames, but the QR code is still incomplete.".to_string(),
-                ));
+    let choose_file = move || {
+        spawn(async move {
+            match compat::read_file(&FILE_EXTENSIONS).await {
+                Ok(Some((file_name, content))) => process_file(file_name, content),
+                Ok(None) => status_message.set("Drop a file here, or choose one.".to_string()),
+                Err(e) => upload_error.set(Some(format!("Failed to read file: {}", e))),
             }
-            is_processing.set(false);
         });
+    };
+
+    use_effect(move || {
+        choose_file();
     });
 
+    let on_drop = move |e: DragEvent| {
+        e.prevent_default();
+        is_drag_over.set(false);
+        let Some(file) = e.files().into_iter().next() else {
+            return;
+        };
+        spawn(async move {
+            match file.read_string().await {
+                Ok(content) => process_file(file.name(), content),
+                Err(e) => upload_error.set(Some(format!("Failed to read file: {}", e))),
+            }
+        });
+    };
+
     rsx! {
         div {
 
@@ -191,35 +193,52 @@ pub fn QrUploader(on_scan: EventHandler<String>, on_close: EventHandler<()>) ->
             h3 {
 
 
-                "Processing QR Image"
+                "Import Address from File"
             }
-            if let Some(err) = upload_error() {
-                div {
-
-
+            div {
+                style: if is_drag_over() {
+                    "border: 2px dashed var(--pico-primary); border-radius: 0.5rem; padding: 1rem; min-width: 300px;"
+                } else {
+                    "border: 2px dashed var(--pico-muted-border-color); border-radius: 0.5rem; padding: 1rem; min-width: 300px;"
+                },
+                ondragover: move |e| {
+                    e.prevent_default();
+                    is_drag_over.set(true);
+                },
+                ondragleave: move |_| is_drag_over.set(false),
+                ondrop: on_drop,
+                if let Some(err) = upload_error() {
                     p {
                         style: "color: var(--pico-color-red-500);",
                         "{err}"
                     }
-                }
-            } else {
-                div {
-                    style: "display: flex; flex-direction: column; gap: 0.5rem; width: 100%; min-width: 300px;",
-                    p {
+                } else {
+                    div {
+                        style: "display: flex; flex-direction: column; gap: 0.5rem; width: 100%;",
+                        p {
 
 
-                        "{status_message}"
-                    }
-                    if *is_processing.read() {
-                        progress {
-                            max: "{upload_progress().1}",
-                            value: "{upload_progress().0}",
+                            "{status_message}"
+                        }
+                        if *is_processing.read() {
+                            progress {
+                                max: "{upload_progress().1}",
+                                value: "{upload_progress().0}",
+                            }
                         }
                     }
                 }
+                small {
+                    "SVG codes, or .txt / .json files holding an address or payment URI."
+                }
             }
             div {
-                style: "margin-top: 1rem;",
+                style: "margin-top: 1rem; display: flex; gap: 0.5rem;",
+                Button {
+                    disabled: is_processing(),
+                    on_click: move |_| choose_file(),
+                    "Choose File"
+                }
                 Button {
                     on_click: move |_| on_close.call(()),
                     "Close"
@@ -228,3 +247,34 @@ pub fn QrUploader(on_scan: EventHandler<String>, on_close: EventHandler<()>) ->
         }
     }
 }
+
+/// Scans each frame of an SVG code, reassembling animated codes.
+async fn process_svg(
+    content: &str,
+    mut status_message: Signal<String>,
+    mut upload_progress: Signal<(usize, usize)>,
+) -> Result<String, String> {
+    status_message.set("Extracting frames...".to_string());
+
+    let (view_box, frames) = svg_reader::extract_svg_details(content)?;
+
+    upload_progress.set((0, frames.len()));
+    let mut processor = QrProcessor::new();
+
+    for (i, frame_svg) in frames.iter().enumerate() {
+        status_message.set(format!("Processing frame {} of {}", i + 1, frames.len()));
+        upload_progress.set((i + 1, frames.len()));
+
+        let pixel_buffer = svg_reader::render_svg_frame(frame_svg, &view_box)
+            .map_err(|e| format!("Failed to render SVG frame: {}", e))?;
+        match processor.process_image(pixel_buffer) {
+            QrProcessResult::Complete(data) => return Ok(data),
+            QrProcessResult::Incomplete(_, _) => {
+                compat::sleep(std::time::Duration::from_millis(1)).await;
+            }
+            QrProcessResult::Error(_) => {}
+        }
+    }
+
+    Err("Scanned all frames, but the QR code is still incomplete.".to_string())
+}
@@ -0,0 +1,73 @@
+//=============================================================================
+// File: src/components/search_box.rs
+//=============================================================================
+use api::search::SearchResult;
+use dioxus::prelude::*;
+
+use crate::i18n::t;
+use crate::Route;
+
+/// A header search box that finds a block by height or digest, a mempool
+/// transaction by id, or one of the wallet's addresses, and opens its screen.
+#[component]
+pub fn SearchBox() -> Element {
+    let mut query = use_signal(String::new);
+    let mut is_searching = use_signal(|| false);
+    let mut message = use_signal(|| None::<String>);
+
+    let submit = move |e: FormEvent| {
+        e.prevent_default();
+        let q = query.read().trim().to_string();
+        if q.is_empty() || is_searching() {
+            return;
+        }
+        is_searching.set(true);
+        message.set(None);
+        spawn(async move {
+            let route = match api::search(q).await {
+                Ok(SearchResult::Block(selector)) => Some(Route::Block { selector }),
+                Ok(SearchResult::MempoolTx(tx_id)) => Some(Route::MempoolTx { tx_id }),
+                Ok(SearchResult::Address) => Some(Route::Addresses {}),
+                Ok(SearchResult::NotFound) => {
+                    message.set(Some(t!("search-not-found")));
+                    None
+                }
+                Err(e) => {
+                    message.set(Some(e.to_string()));
+                    None
+                }
+            };
+            if let Some(route) = route {
+                query.set(String::new());
+                navigator().push(route);
+            }
+            is_searching.set(false);
+        });
+    };
+
+    rsx! {
+        form {
+            style: "margin: 0; position: relative;",
+            onsubmit: submit,
+            input {
+                r#type: "search",
+                style: "margin: 0; padding: 0.2rem 0.75rem; height: auto; font-size: 0.8rem; width: 16rem;",
+                placeholder: t!("search-placeholder"),
+                "aria-label": t!("search-label"),
+                "aria-busy": "{is_searching}",
+                value: "{query}",
+                oninput: move |e| {
+                    query.set(e.value());
+                    message.set(None);
+                },
+            }
+            if let Some(m) = message() {
+                small {
+                    role: "status",
+                    style: "position: absolute; top: 100%; left: 0; white-space: nowrap; color: var(--pico-del-color);",
+                    "{m}"
+                }
+            }
+        }
+    }
+}
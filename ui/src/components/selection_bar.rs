@@ -0,0 +1,29 @@
+//=============================================================================
+// File: src/components/selection_bar.rs
+//=============================================================================
+use dioxus::prelude::*;
+
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::i18n::t;
+
+/// The toolbar shown above a table while rows are selected.
+///
+/// Shows the selection count, the bulk action buttons passed as children and
+/// a "Clear Selection" button.
+#[component]
+pub fn SelectionBar(count: usize, on_clear: EventHandler<()>, children: Element) -> Element {
+    rsx! {
+        div {
+            style: "display: flex; flex-wrap: wrap; align-items: center; gap: 0.5rem; margin-bottom: 1rem; padding: 0.5rem; border: 1px solid var(--pico-muted-border-color); border-radius: var(--pico-border-radius);",
+            span { {t!("common-selected", count = count)} }
+            {children}
+            Button {
+                button_type: ButtonType::Secondary,
+                style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0 0 0 auto;",
+                on_click: move |_| on_clear.call(()),
+                {t!("common-clear-selection")}
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Shows the sends in the background, in the header.
+
+use api::send_job::SendJob;
+use api::send_job::SendJobStatus;
+use dioxus::prelude::*;
+
+use crate::components::action_link::ActionLink;
+use crate::components::amount::Amount;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Modal;
+use crate::i18n::t;
+use crate::send_queue::SendQueue;
+use crate::Route;
+
+/// One send of the list.
+#[component]
+fn SendJobRow(job: SendJob) -> Element {
+    let queue = use_context::<SendQueue>();
+    let id = job.id;
+    let date_time_format = format!("{} %H:%M", crate::i18n::date_format());
+    let started_at = chrono::DateTime::from_timestamp_millis(job.started_ms as i64)
+        .map(|d| {
+            d.with_timezone(&chrono::Local)
+                .format(&date_time_format)
+                .to_string()
+        })
+        .unwrap_or_default();
+    let (icon, color) = match &job.status {
+        SendJobStatus::InFlight => ("⏳", "inherit"),
+        SendJobStatus::Sent { .. } => ("✓", "var(--pico-ins-color)"),
+        SendJobStatus::Failed { .. } | SendJobStatus::Interrupted => ("✗", "var(--pico-del-color)"),
+    };
+
+    rsx! {
+        li {
+            style: "list-style: none; padding: 0.5rem 0; border-bottom: 1px solid var(--pico-muted-border-color);",
+            div {
+                style: "color: {color};",
+                "{icon} "
+                {t!("send-queue-recipients", count = job.num_recipients)}
+                " "
+                Amount { amount: job.amount }
+            }
+            small {
+                style: "color: var(--pico-muted-color); display: block;",
+                "{started_at} · "
+                {t!("send-queue-fee")}
+                " "
+                Amount { amount: job.fee }
+            }
+            match job.status {
+                SendJobStatus::InFlight => rsx! {
+                    small { {t!("send-queue-in-flight")} }
+                },
+                SendJobStatus::Sent { txid } => rsx! {
+                    small {
+                        ActionLink {
+                            to: Route::MempoolTx { tx_id: txid },
+                            {t!("send-view-in-mempool")}
+                        }
+                    }
+                },
+                SendJobStatus::Failed { error } => rsx! {
+                    small { "{error}" }
+                    if queue.can_retry(id) {
+                        " "
+                        small {
+                            ActionLink {
+                                onclick: move |_| queue.retry(id),
+                                {t!("send-retry")}
+                            }
+                        }
+                    }
+                },
+                SendJobStatus::Interrupted => rsx! {
+                    small {
+                        {t!("send-queue-interrupted")}
+                        " "
+                        ActionLink {
+                            to: Route::History {},
+                            {t!("send-queue-check-history")}
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// A button in the header while sends are in flight, or their outcome is
+/// unseen, which opens the list of recent sends.  Opening it marks them all
+/// seen.
+#[component]
+pub fn SendQueueButton() -> Element {
+    let queue = use_context::<SendQueue>();
+    let mut is_open = use_signal(|| false);
+    let num_in_flight = queue.num_in_flight();
+    let num_unseen = queue.num_unseen();
+
+    // close the list when a link in it navigates away.
+    let route = use_route::<Route>();
+    use_effect(use_reactive((&route,), move |_| is_open.set(false)));
+
+    // outcomes that arrive while the list is open count as seen.
+    use_effect(move || {
+        if is_open() {
+            let unseen: Vec<u64> = queue
+                .jobs
+                .read()
+                .iter()
+                .filter(|j| !j.seen && !j.status.is_in_flight())
+                .map(|j| j.id)
+                .collect();
+            if !unseen.is_empty() {
+                queue.mark_seen(unseen);
+            }
+        }
+    });
+
+    if queue.jobs.read().is_empty() {
+        return rsx! {};
+    }
+
+    let title = if num_in_flight > 0 {
+        t!("send-queue-title-in-flight", count = num_in_flight)
+    } else {
+        t!("send-queue-title", count = num_unseen)
+    };
+
+    rsx! {
+        Button {
+            button_type: ButtonType::Secondary,
+            outline: num_in_flight == 0 && num_unseen == 0,
+            style: "padding: 0.2rem 0.5rem; font-size: 0.8rem;",
+            title: title.clone(),
+            aria_label: title,
+            on_click: move |_| is_open.set(true),
+            if num_in_flight > 0 {
+                "⏳ {num_in_flight}"
+            } else if num_unseen > 0 {
+                "📤 {num_unseen}"
+            } else {
+                "📤"
+            }
+        }
+        Modal {
+            is_open,
+            title: t!("send-queue-heading"),
+            ul {
+                style: "padding: 0; max-height: 60vh; overflow-y: auto;",
+                for job in queue.jobs.read().iter().cloned() {
+                    SendJobRow {
+                        key: "{job.id}",
+                        job,
+                    }
+                }
+            }
+            footer {
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    disabled: queue.jobs.read().iter().all(|j| j.status.is_in_flight()),
+                    on_click: move |_| queue.clear_finished(),
+                    {t!("send-queue-clear")}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Placeholder layouts shown while a screen's data loads.
+//!
+//! These mimic the shape of the content they stand in for, so the page does
+//! not jump about when the data arrives.
+use dioxus::prelude::*;
+
+/// The shimmer animation shared by all skeleton components.
+#[component]
+fn SkeletonStyle() -> Element {
+    rsx! {
+        style {
+            "
+            .skeleton {{
+                border-radius: var(--pico-border-radius);
+                background: linear-gradient(
+                    90deg,
+                    var(--pico-muted-border-color) 25%,
+                    var(--pico-card-sectioning-background-color) 50%,
+                    var(--pico-muted-border-color) 75%
+                );
+                background-size: 200% 100%;
+                animation: skeleton-shimmer 1.5s ease-in-out infinite;
+            }}
+            @keyframes skeleton-shimmer {{
+                from {{ background-position: 200% 0; }}
+                to {{ background-position: -200% 0; }}
+            }}
+            @media (prefers-reduced-motion: reduce) {{
+                .skeleton {{ animation: none; }}
+            }}
+            "
+        }
+    }
+}
+
+/// A single shimmering bar, standing in for a line of text.
+///
+/// Must be placed inside one of the skeleton containers below, which provide
+/// its styling.
+#[component]
+pub fn SkeletonLine(
+    #[props(default = "100%".to_string())] width: String,
+    #[props(default = "1rem".to_string())] height: String,
+) -> Element {
+    rsx! {
+        div {
+            class: "skeleton",
+            style: "width: {width}; height: {height}; margin-bottom: 0.75rem;",
+        }
+    }
+}
+
+/// A card with a title and a few lines of placeholder text.
+#[component]
+pub fn SkeletonCard(title: String, #[props(default = 3)] lines: usize) -> Element {
+    // vary the widths so the placeholder reads as text rather than a block.
+    const WIDTHS: [&str; 4] = ["60%", "85%", "40%", "70%"];
+
+    rsx! {
+        SkeletonStyle {}
+        article {
+            "aria-busy": "true",
+            h3 { "{title}" }
+            for i in 0..lines {
+                SkeletonLine { key: "{i}", width: WIDTHS[i % WIDTHS.len()].to_string() }
+            }
+        }
+    }
+}
+
+/// A card with a title and a grid of greyed-out sub-cards.
+#[component]
+pub fn SkeletonCardGrid(title: String, #[props(default = 4)] cards: usize) -> Element {
+    rsx! {
+        SkeletonStyle {}
+        article {
+            "aria-busy": "true",
+            h3 { "{title}" }
+            div {
+                style: "display: grid; grid-template-columns: repeat(auto-fit, minmax(250px, 1fr)); gap: 1rem 2rem;",
+                for i in 0..cards {
+                    div {
+                        key: "{i}",
+                        style: "border: 1px solid var(--pico-card-border-color); border-radius: var(--pico-border-radius); padding: 0.5rem;",
+                        SkeletonLine { width: "40%".to_string(), height: "1.25rem".to_string() }
+                        SkeletonLine { width: "90%".to_string() }
+                        SkeletonLine { width: "75%".to_string() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A card with a title and a table of shimmering rows.
+#[component]
+pub fn SkeletonTable(
+    title: String,
+    columns: usize,
+    #[props(default = 5)] rows: usize,
+) -> Element {
+    rsx! {
+        SkeletonStyle {}
+        article {
+            "aria-busy": "true",
+            h3 { "{title}" }
+            div {
+                style: "overflow-x: auto;",
+                table {
+                    thead {
+                        tr {
+                            for c in 0..columns {
+                                th { key: "{c}", SkeletonLine { width: "60%".to_string() } }
+                            }
+                        }
+                    }
+                    tbody {
+                        for r in 0..rows {
+                            tr {
+                                key: "{r}",
+                                for c in 0..columns {
+                                    td {
+                                        key: "{c}",
+                                        SkeletonLine { width: (if (r + c) % 2 == 0 { "80%" } else { "55%" }).to_string() }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
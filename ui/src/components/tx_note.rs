@@ -0,0 +1,142 @@
+//! Shows and edits the private note on a transaction.
+
+use dioxus::html::input_data::keyboard_types::Key;
+use dioxus::prelude::*;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
+
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::i18n::t;
+
+/// The note on `txid`, with a button to add or edit it.  Calls `on_saved`
+/// after each change.
+#[component]
+pub fn TxNoteEditor(
+    txid: TransactionKernelId,
+    #[props(optional)] on_saved: Option<EventHandler<()>>,
+) -> Element {
+    let mut notes = use_resource(move || async move { api::tx_notes().await });
+    let mut editing = use_signal(|| false);
+    let mut draft = use_signal(String::new);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal::<Option<String>>(|| None);
+
+    let note = notes
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .and_then(|n| n.get(&txid.to_string()))
+        .map(|n| n.text.clone());
+
+    let save = move |_: MouseEvent| {
+        let text = draft();
+        is_saving.set(true);
+        spawn(async move {
+            match api::set_tx_note(txid, text).await {
+                Ok(_) => {
+                    error.set(None);
+                    editing.set(false);
+                    notes.restart();
+                    if let Some(on_saved) = on_saved {
+                        on_saved.call(());
+                    }
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "tx-note",
+            if editing() {
+                label {
+                    {t!("tx-note-label")}
+                    textarea {
+                        rows: "2",
+                        maxlength: "500",
+                        placeholder: t!("tx-note-placeholder"),
+                        value: "{draft}",
+                        oninput: move |e| draft.set(e.value()),
+                    }
+                    small { {t!("tx-note-private")} }
+                }
+                div {
+                    style: "display: flex; gap: 0.5rem;",
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        on_click: move |_| editing.set(false),
+                        {t!("common-cancel")}
+                    }
+                    Button {
+                        disabled: is_saving(),
+                        on_click: save,
+                        {t!("common-save")}
+                    }
+                }
+                if let Some(err) = error() {
+                    small {
+                        style: "color: var(--pico-color-red-500); display: block;",
+                        "{err}"
+                    }
+                }
+            } else {
+                div {
+                    style: "display: flex; align-items: baseline; gap: 0.5rem;",
+                    if let Some(text) = &note {
+                        span {
+                            style: "white-space: pre-wrap;",
+                            "📝 {text}"
+                        }
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        style: "padding: 0.1rem 0.5rem; margin-bottom: 0;",
+                        on_click: {
+                            let note = note.clone();
+                            move |_| {
+                                draft.set(note.clone().unwrap_or_default());
+                                editing.set(true);
+                            }
+                        },
+                        if note.is_some() {
+                            {t!("tx-note-edit")}
+                        } else {
+                            {t!("tx-note-add")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A compact marker for table rows: 📝 with the note as its tooltip, or a
+/// faint one to add a note.
+#[component]
+pub fn TxNoteIcon(note: Option<String>, on_click: EventHandler<()>) -> Element {
+    let (title, style) = match &note {
+        Some(text) => (text.clone(), "cursor: pointer;"),
+        None => (t!("tx-note-add"), "cursor: pointer; opacity: 0.4;"),
+    };
+
+    rsx! {
+        span {
+            role: "button",
+            tabindex: "0",
+            title: "{title}",
+            "aria-label": if note.is_some() { t!("tx-note-edit") } else { t!("tx-note-add") },
+            style,
+            onclick: move |_| on_click.call(()),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Enter {
+                    on_click.call(());
+                }
+            },
+            "📝"
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Node data that several screens show, fetched by one background task so
+//! that neptune-core is asked once and every screen agrees.
+//!
+//! Screens whose own data only changes with the tip or the mempool, eg the
+//! history and mempool tables, watch [DataStore::dashboard] to know when to
+//! fetch it again instead of polling.
+
+use api::prefs::refresh_intervals::RefreshScreen;
+use api::sync_progress::SyncProgress;
+use api::ApiError;
+use dioxus::prelude::*;
+use neptune_types::dashboard_overview_data_from_client::DashBoardOverviewDataFromClient;
+use neptune_types::mempool_transaction_info::MempoolTransactionInfo;
+use neptune_types::peer_info::PeerInfo;
+
+use crate::app_state_mut::AppStateMut;
+use crate::hooks::use_rpc_checker::NeptuneRpcConnectionStatus;
+use crate::polling::PollWeight;
+
+/// A fetched value.  None until the first fetch completes.
+pub type Fetched<T> = Option<Result<T, ApiError>>;
+
+#[derive(Clone, Copy)]
+pub struct DataStore {
+    /// Balances, the tip, and mempool and peer summaries.
+    pub dashboard: Signal<Fetched<DashBoardOverviewDataFromClient>>,
+
+    /// Unconfirmed transactions that affect the wallet.  Fetched again only
+    /// when the tip or the mempool changes.
+    pub pending: Signal<Fetched<Vec<MempoolTransactionInfo>>>,
+
+    /// How far the node is through syncing.  Only fetched while the
+    /// dashboard says it is syncing, None otherwise.
+    pub sync: Signal<Option<SyncProgress>>,
+
+    /// The connected peers.  Only fetched while a screen shows them; see
+    /// [use_peers].
+    pub peers: Signal<Fetched<Vec<PeerInfo>>>,
+
+    /// The number of mounted components using [use_peers].
+    peer_watchers: Signal<u32>,
+}
+
+impl DataStore {
+    /// Fetches the dashboard, the sync progress while syncing, and the
+    /// pending transactions if the tip or the mempool changed since they were
+    /// last fetched.
+    pub async fn refresh(mut self) {
+        let dashboard = api::dashboard_overview_data().await;
+        let mempool_changed = match (&*self.dashboard.peek(), &dashboard) {
+            (Some(Ok(old)), Ok(new)) => {
+                old.tip_digest != new.tip_digest
+                    || old.mempool_total_tx_count != new.mempool_total_tx_count
+                    || old.mempool_size != new.mempool_size
+            }
+            _ => true,
+        };
+        let pending = if mempool_changed || !matches!(*self.pending.peek(), Some(Ok(_))) {
+            Some(api::pending_transactions().await)
+        } else {
+            None
+        };
+        let syncing = matches!(&dashboard, Ok(data) if data.syncing);
+        let sync = if syncing {
+            api::sync_progress().await.ok()
+        } else {
+            None
+        };
+
+        // all are set together, so that screens never see a tip without the
+        // pending transactions that go with it.
+        self.dashboard.set(Some(dashboard));
+        if let Some(pending) = pending {
+            self.pending.set(Some(pending));
+        }
+        if *self.sync.peek() != sync {
+            self.sync.set(sync);
+        }
+    }
+
+    /// Fetches the peers.
+    pub async fn refresh_peers(mut self) {
+        self.peers.set(Some(api::peer_info().await));
+    }
+}
+
+/// Provides the [DataStore], and starts the task that keeps it current while
+/// connected to neptune-core.
+pub fn use_data_store_provider(
+    app_state_mut: AppStateMut,
+    connection_status: Signal<NeptuneRpcConnectionStatus>,
+) -> DataStore {
+    let store = use_context_provider(|| DataStore {
+        dashboard: Signal::new(None),
+        pending: Signal::new(None),
+        sync: Signal::new(None),
+        peers: Signal::new(None),
+        peer_watchers: Signal::new(0),
+    });
+
+    // fetches everything at startup, and again once the connection is back.
+    use_effect(move || {
+        if connection_status.read().is_connected() {
+            spawn(store.refresh());
+            if *store.peer_watchers.peek() > 0 {
+                spawn(store.refresh_peers());
+            }
+        }
+    });
+
+    use_coroutine(move |_rx: UnboundedReceiver<()>| async move {
+        let overview = async {
+            loop {
+                crate::polling::wait_for_screen_refresh(
+                    app_state_mut,
+                    RefreshScreen::Balance,
+                    PollWeight::Light,
+                )
+                .await;
+                if connection_status.peek().is_connected() {
+                    store.refresh().await;
+                }
+            }
+        };
+        let peers = async {
+            loop {
+                crate::polling::wait_for_screen_refresh(
+                    app_state_mut,
+                    RefreshScreen::Peers,
+                    PollWeight::Light,
+                )
+                .await;
+                if connection_status.peek().is_connected() && *store.peer_watchers.peek() > 0 {
+                    store.refresh_peers().await;
+                }
+            }
+        };
+        futures::join!(overview, peers);
+    });
+
+    store
+}
+
+/// The connected peers, which the [DataStore] fetches while any component
+/// using this is mounted.
+pub fn use_peers() -> Signal<Fetched<Vec<PeerInfo>>> {
+    let store = use_context::<DataStore>();
+    let mut watchers = store.peer_watchers;
+    use_hook(move || {
+        watchers += 1;
+        spawn(store.refresh_peers());
+    });
+    use_drop(move || watchers -= 1);
+    store.peers
+}
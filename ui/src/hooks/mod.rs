@@ -1,2 +1,5 @@
+pub mod use_idle;
 pub mod use_is_touch_device;
 pub mod use_rpc_checker;
+pub mod use_selection;
+pub mod use_window_visibility;
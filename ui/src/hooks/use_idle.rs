@@ -0,0 +1,33 @@
+//=============================================================================
+// File: src/hooks/use_idle.rs
+//=============================================================================
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use web_time::Instant;
+
+/// Tracks when the user last interacted with the app, for locking or blurring
+/// it once left idle.
+#[derive(Clone, Copy)]
+pub struct IdleTracker {
+    last_activity: Signal<Instant>,
+}
+
+impl IdleTracker {
+    /// Records user activity now.
+    pub fn touch(&mut self) {
+        self.last_activity.set(Instant::now());
+    }
+
+    /// The time since the user last interacted with the app.
+    pub fn idle_for(&self) -> Duration {
+        self.last_activity.peek().elapsed()
+    }
+}
+
+/// Starts tracking activity from now.  Call [IdleTracker::touch] on input.
+pub fn use_idle() -> IdleTracker {
+    IdleTracker {
+        last_activity: use_signal(Instant::now),
+    }
+}
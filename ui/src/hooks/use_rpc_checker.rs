@@ -5,6 +5,11 @@ use dioxus::prelude::*;
 pub enum NeptuneRpcConnectionStatus {
     Connected,
     Disconnected(String),
+    /// neptune-core is reachable but a call timed out.
+    NotResponding(String),
+    /// neptune-core is reachable but its RPC cookie cannot be read, so only
+    /// unauthenticated calls work.
+    CookieUnavailable(String),
 }
 
 #[derive(Clone, Copy)]
@@ -16,14 +21,13 @@ impl RpcChecker {
     /// Inspects a Result from an API call.
     /// - If `Ok`: Updates status to Connected (if previously disconnected) and returns value.
     /// - If `Err`: Checks if it's a connection error. If so, updates status to Disconnected. Returns None.
+    ///
+    /// An auth failure is not a lost connection, however it is worded.
     pub fn check<T>(&mut self, result: Result<T, ApiError>) -> Option<T> {
         match result {
             Ok(val) => {
                 // If we were disconnected, we are back now.
-                if matches!(
-                    *self.status.peek(),
-                    NeptuneRpcConnectionStatus::Disconnected(_)
-                ) {
+                if !self.status.peek().is_connected() {
                     self.status.set(NeptuneRpcConnectionStatus::Connected);
                 }
                 Some(val)
@@ -32,14 +36,31 @@ impl RpcChecker {
                 let error_msg = e.to_string();
                 dioxus_logger::tracing::warn!("RPC Error: {}", error_msg);
 
-                // Heuristic: Check if this is a connection-related error.
-                if self.is_connection_error(&error_msg) {
+                let is_connection_error = match &e {
+                    ApiError::ConnectionLost(_) => true,
+                    ApiError::Timeout(_) => {
+                        self.status
+                            .set(NeptuneRpcConnectionStatus::NotResponding(error_msg.clone()));
+                        false
+                    }
+                    ApiError::CookieUnavailable(_) => {
+                        self.status
+                            .set(NeptuneRpcConnectionStatus::CookieUnavailable(error_msg.clone()));
+                        false
+                    }
+                    // failures between the UI and the server arrive as Internal,
+                    // so fall back to the wording.
+                    ApiError::Internal(_) => self.is_connection_error(&error_msg),
+                    ApiError::AuthFailed(_)
+                    | ApiError::InsufficientBalance(_)
+                    | ApiError::InvalidAddress(_)
+                    | ApiError::BroadcastUnknown(_) => false,
+                };
+                if is_connection_error {
                     self.status
                         .set(NeptuneRpcConnectionStatus::Disconnected(error_msg));
-                    None
-                } else {
-                    None
                 }
+                None
             }
         }
     }
@@ -47,18 +68,20 @@ impl RpcChecker {
     /// Checks a result by reference without consuming it.
     /// Returns `true` if the result is Ok.
     /// If Err, checks if it is a connection error and updates global status if so.
-    pub fn check_result_ref<T, E: std::fmt::Display>(&mut self, result: &Result<T, E>) -> bool {
+    pub fn check_result_ref<T>(&mut self, result: &Result<T, ApiError>) -> bool {
         match result {
             Ok(_) => {
                 // If we were disconnected, we are back now.
-                if matches!(
-                    *self.status.peek(),
-                    NeptuneRpcConnectionStatus::Disconnected(_)
-                ) {
+                if !self.status.peek().is_connected() {
                     self.status.set(NeptuneRpcConnectionStatus::Connected);
                 }
                 true
             }
+            Err(ApiError::CookieUnavailable(msg)) => {
+                self.status
+                    .set(NeptuneRpcConnectionStatus::CookieUnavailable(msg.clone()));
+                false
+            }
             Err(e) => {
                 let error_msg = e.to_string();
                 // Only log warnings if it looks like a connection drop, otherwise it might just be valid logic flow
@@ -0,0 +1,77 @@
+//! Row selection state for tables that offer bulk actions.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use dioxus::prelude::*;
+
+/// A set of selected row keys, held in a signal.
+///
+/// Keys should be stable across refreshes of the underlying data, eg an
+/// AOCL leaf index or a block digest, so that the selection survives polling.
+pub struct Selection<K: 'static> {
+    selected: Signal<HashSet<K>>,
+}
+
+impl<K: 'static> Clone for Selection<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: 'static> Copy for Selection<K> {}
+
+impl<K: Clone + Eq + Hash + 'static> Selection<K> {
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected.read().contains(key)
+    }
+
+    /// Selects `key` if unselected, and vice versa.
+    pub fn toggle(&mut self, key: K) {
+        let mut selected = self.selected.write();
+        if !selected.remove(&key) {
+            selected.insert(key);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.write().clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.selected.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.read().is_empty()
+    }
+
+    /// Returns the selected keys, in no particular order.
+    pub fn keys(&self) -> Vec<K> {
+        self.selected.read().iter().cloned().collect()
+    }
+
+    /// Replaces the selection with `keys`.
+    pub fn set(&mut self, keys: impl IntoIterator<Item = K>) {
+        self.selected.set(keys.into_iter().collect());
+    }
+
+    /// True if every key in `all` is selected and `all` is non-empty.
+    pub fn is_all_selected(&self, all: &HashSet<K>) -> bool {
+        !all.is_empty() && all.is_subset(&self.selected.read())
+    }
+
+    /// Selects all of `all`, or clears the selection if already all selected.
+    pub fn toggle_all(&mut self, all: HashSet<K>) {
+        if self.is_all_selected(&all) {
+            self.clear();
+        } else {
+            self.selected.set(all);
+        }
+    }
+}
+
+pub fn use_selection<K: Clone + Eq + Hash + 'static>() -> Selection<K> {
+    let selected = use_signal(HashSet::<K>::new);
+    Selection { selected }
+}
@@ -0,0 +1,37 @@
+//=============================================================================
+// File: src/hooks/use_window_visibility.rs
+//=============================================================================
+use dioxus::prelude::*;
+
+/// Reports whether the page is visible.  The desktop app also treats a window
+/// without focus as hidden, as its webview does not report being minimized on
+/// every platform.
+#[cfg(feature = "dioxus-desktop")]
+const VISIBILITY_SCRIPT: &str = r#"
+    const send = () => dioxus.send(!document.hidden && document.hasFocus());
+    document.addEventListener('visibilitychange', send);
+    window.addEventListener('focus', send);
+    window.addEventListener('blur', send);
+    send();
+"#;
+
+/// Reports whether the page is visible, ie its tab is shown and its window
+/// is not minimized.
+#[cfg(not(feature = "dioxus-desktop"))]
+const VISIBILITY_SCRIPT: &str = r#"
+    const send = () => dioxus.send(!document.hidden);
+    document.addEventListener('visibilitychange', send);
+    send();
+"#;
+
+/// Keeps `visible` up to date as the window is hidden and shown again.
+pub fn use_window_visibility(mut visible: Signal<bool>) {
+    use_future(move || async move {
+        let mut eval = document::eval(VISIBILITY_SCRIPT);
+        while let Ok(is_visible) = eval.recv::<bool>().await {
+            if *visible.peek() != is_visible {
+                visible.set(is_visible);
+            }
+        }
+    });
+}
@@ -0,0 +1,107 @@
+//! Translation of user-facing text, using [Fluent](https://projectfluent.org).
+//!
+//! Messages are kept in `assets/locales/<code>.ftl`, one file per language.
+//! English is complete.  A message missing from another language falls back
+//! to English, so screens may be translated a piece at a time.
+//!
+//! Use the [t] macro to look up a message in the current language:
+//!
+//! ```ignore
+//! t!("send-confirm")
+//! t!("common-selected", count = 3)
+//! ```
+
+use std::collections::HashMap;
+
+use api::prefs::language::Language;
+use dioxus::prelude::*;
+use fluent_bundle::FluentArgs;
+use fluent_bundle::FluentBundle;
+use fluent_bundle::FluentResource;
+use strum::IntoEnumIterator;
+use unic_langid::LanguageIdentifier;
+
+use crate::app_state_mut::AppStateMut;
+
+fn source(language: Language) -> &'static str {
+    match language {
+        Language::English => include_str!("../assets/locales/en.ftl"),
+        Language::Spanish => include_str!("../assets/locales/es.ftl"),
+        Language::German => include_str!("../assets/locales/de.ftl"),
+    }
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let langid: LanguageIdentifier = language
+        .code()
+        .parse()
+        .expect("language codes are valid identifiers");
+    let mut bundle = FluentBundle::new(vec![langid]);
+
+    // the unicode isolation marks Fluent puts around arguments show up as
+    // boxes in some fonts and end up in copied text.
+    bundle.set_use_isolating(false);
+
+    let resource = FluentResource::try_new(source(language).to_string())
+        .unwrap_or_else(|(_, errors)| panic!("invalid {}.ftl: {:?}", language.code(), errors));
+    bundle
+        .add_resource(resource)
+        .unwrap_or_else(|errors| panic!("invalid {}.ftl: {:?}", language.code(), errors));
+    bundle
+}
+
+thread_local! {
+    // bundles are not Sync, and are cheap enough to build once per thread.
+    static BUNDLES: HashMap<Language, FluentBundle<FluentResource>> =
+        Language::iter().map(|l| (l, bundle(l))).collect();
+}
+
+/// Formats the message `id` in `language`, falling back to English and then
+/// to the id itself.
+pub fn translate_in(language: Language, id: &str, args: Option<&FluentArgs>) -> String {
+    BUNDLES.with(|bundles| {
+        [language, Language::English]
+            .iter()
+            .find_map(|l| {
+                let bundle = &bundles[l];
+                let pattern = bundle.get_message(id)?.value()?;
+                let mut errors = vec![];
+                Some(bundle.format_pattern(pattern, args, &mut errors).into_owned())
+            })
+            .unwrap_or_else(|| id.to_string())
+    })
+}
+
+/// The current UI language.
+///
+/// Reading it subscribes the calling component, so it re-renders when the
+/// language changes.
+pub fn language() -> Language {
+    try_consume_context::<AppStateMut>()
+        .map(|state| *state.language.read())
+        .unwrap_or_default()
+}
+
+/// Formats the message `id` in the current language.  See [t].
+pub fn translate(id: &str, args: Option<&FluentArgs>) -> String {
+    translate_in(language(), id, args)
+}
+
+/// A chrono format string for dates in the current language.
+pub fn date_format() -> &'static str {
+    language().date_format()
+}
+
+/// Looks up a message in the current language, with optional named
+/// arguments.
+macro_rules! t {
+    ($id:literal) => {
+        $crate::i18n::translate($id, None)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::translate($id, Some(&args))
+    }};
+}
+pub(crate) use t;
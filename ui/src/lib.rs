@@ -7,63 +7,196 @@ mod app_state_mut;
 pub mod compat;
 mod components;
 mod currency;
+mod data_store;
 pub mod hooks;
+mod i18n;
+mod number_format;
+mod polling;
 mod screens;
+mod send_queue;
 
+use api::node_version::NodeCompatibility;
 use api::prefs::user_prefs::UserPrefs;
 use api::price_map::PriceMap;
+use api::sync_progress::TipEstimate;
+use api::wallet_capabilities::WalletCapabilities;
 use app_state::AppState;
 use app_state_mut::AppStateMut;
+use components::action_link::ActionLink;
+use components::lock_screen::IdleOverlay;
+use components::lock_screen::LockScreen;
+use components::notification_center::NotificationBell;
 use components::pico::Button;
 use components::pico::ButtonType;
+use components::pico::Card;
 use components::pico::Container;
+use components::search_box::SearchBox;
+use components::send_queue_status::SendQueueButton;
+use components::skeleton::SkeletonCardGrid;
+use data_store::DataStore;
+use hooks::use_idle::use_idle;
 use hooks::use_rpc_checker::NeptuneRpcConnectionStatus;
+use hooks::use_window_visibility::use_window_visibility;
 use neptune_types::block_selector::BlockSelector;
+use neptune_types::network::Network;
 use neptune_types::transaction_kernel_id::TransactionKernelId;
+use polling::PollWeight;
 use screens::addresses::AddressesScreen;
 use screens::balance::BalanceScreen;
 use screens::block::BlockScreen;
 use screens::blockchain::BlockChainScreen;
+use screens::broadcast::BroadcastScreen;
+use screens::health::HealthScreen;
 use screens::history::HistoryScreen;
+use screens::import_wallet::ImportWalletScreen;
+use screens::logs::LogsScreen;
 use screens::mempool::MempoolScreen;
 use screens::mempool_tx::MempoolTxScreen;
+use screens::node_logs::NodeLogsScreen;
+use screens::node_stats::NodeStatsScreen;
+use screens::onboarding::OnboardingScreen;
 use screens::peers::PeersScreen;
 use screens::receive::ReceiveScreen;
+use screens::reports::ReportsScreen;
 use screens::send::SendScreen;
+use screens::settings::SettingsScreen;
 use screens::utxos::UtxosScreen;
 
-/// Enum to represent the different screens in our application.
-#[derive(Clone, PartialEq, Default)]
-enum Screen {
-    #[default]
-    Balance,
-    Send,
-    Receive,
-    History,
-    Utxos,
-    Addresses,
-    Peers,
-    BlockChain,
-    Mempool,
-    MempoolTx(TransactionKernelId),
-    Block(BlockSelector),
+/// The screens of the app, by URL, so that screens can be bookmarked and
+/// reloaded, and the browser's back and forward buttons work.
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+enum Route {
+    #[layout(Shell)]
+        #[route("/", BalanceScreen)]
+        Balance {},
+        #[route("/send", SendScreen)]
+        Send {},
+        #[route("/send/drafts", SendDraftsScreen)]
+        SendDrafts {},
+        #[route("/receive", ReceiveScreen)]
+        Receive {},
+        #[route("/history", HistoryScreen)]
+        History {},
+        #[route("/reports", ReportsScreen)]
+        Reports {},
+        #[route("/utxos", UtxosScreen)]
+        Utxos {},
+        #[route("/addresses", AddressesScreen)]
+        Addresses {},
+        #[route("/peers", PeersScreen)]
+        Peers {},
+        #[route("/node-stats", NodeStatsScreen)]
+        NodeStats {},
+        #[route("/blockchain", BlockChainScreen)]
+        BlockChain {},
+        #[route("/mempool", MempoolScreen)]
+        Mempool {},
+        #[route("/broadcast", BroadcastScreen)]
+        Broadcast {},
+        #[route("/logs", LogsScreen)]
+        Logs {},
+        #[route("/node-logs", NodeLogsScreen)]
+        NodeLogs {},
+        #[route("/settings", SettingsScreen)]
+        Settings {},
+        #[route("/settings/import-wallet", ImportWalletScreen)]
+        ImportWallet {},
+        #[route("/settings/health", HealthScreen)]
+        Health {},
+        #[route("/tx/:tx_id", MempoolTxScreen)]
+        MempoolTx { tx_id: TransactionKernelId },
+        #[route("/block/:selector", BlockRoute)]
+        Block { selector: BlockSelector },
+        #[route("/:..segments")]
+        NotFound { segments: Vec<String> },
 }
 
-impl Screen {
+impl Route {
     /// Helper to get the display name for each screen.
     fn name(&self) -> &'static str {
         match self {
-            Screen::Balance => "Balance",
-            Screen::Send => "Send",
-            Screen::Receive => "Receive",
-            Screen::History => "History",
-            Screen::Utxos => "Utxos",
-            Screen::Addresses => "Addresses",
-            Screen::Peers => "Peers",
-            Screen::BlockChain => "BlockChain",
-            Screen::Mempool => "Mempool",
-            Screen::MempoolTx(_) => "Mempool Transaction",
-            Screen::Block(_) => "Block",
+            Route::Balance {} => "Balance",
+            Route::Send {} => "Send",
+            Route::Receive {} => "Receive",
+            Route::History {} => "History",
+            Route::Reports {} => "Reports",
+            Route::Utxos {} => "Utxos",
+            Route::Addresses {} => "Addresses",
+            Route::Peers {} => "Peers",
+            Route::NodeStats {} => "Node Stats",
+            Route::BlockChain {} => "BlockChain",
+            Route::Mempool {} => "Mempool",
+            Route::Broadcast {} => "Broadcast",
+            Route::Logs {} => "Logs",
+            Route::NodeLogs {} => "Node Logs",
+            Route::Settings {} => "Settings",
+            Route::ImportWallet {} => "Import Wallet",
+            Route::Health {} => "Health Check",
+            Route::SendDrafts {} => "Send",
+            Route::MempoolTx { .. } => "Mempool Transaction",
+            Route::Block { .. } => "Block",
+            Route::NotFound { .. } => "Not Found",
+        }
+    }
+
+    /// True for screens that need a wallet able to spend.
+    fn requires_spending(&self) -> bool {
+        matches!(self, Route::Send {} | Route::SendDrafts {})
+    }
+
+    /// True for screens only shown when developer tools are enabled.
+    fn requires_dev_tools(&self) -> bool {
+        matches!(self, Route::Logs {})
+    }
+
+    /// True if this is the screen of the tab `tab`, or one opened from it.
+    fn is_under(&self, tab: &Route) -> bool {
+        match (self, tab) {
+            (Route::MempoolTx { .. }, Route::Mempool {}) => true,
+            (Route::SendDrafts {}, Route::Send {}) => true,
+            (Route::Block { .. }, Route::BlockChain {}) => true,
+            (Route::NodeLogs {}, Route::Settings {}) => true,
+            (Route::ImportWallet {}, Route::Settings {}) => true,
+            (Route::Health {}, Route::Settings {}) => true,
+            (active, current) => active == current,
+        }
+    }
+}
+
+/// The Send screen, with the saved drafts open.
+#[component]
+fn SendDraftsScreen() -> Element {
+    rsx! {
+        SendScreen { open_drafts: true }
+    }
+}
+
+/// Shown for a URL that names no screen, eg an outdated bookmark.
+#[component]
+fn NotFound(segments: Vec<String>) -> Element {
+    let path = segments.join("/");
+    rsx! {
+        Card {
+            h3 { "Not Found" }
+            p { "There is no screen at /{path}." }
+            ActionLink {
+                to: Route::Balance {},
+                "Go to Balance"
+            }
+        }
+    }
+}
+
+/// Keyed by the selector, so that following a link from one block to another
+/// loads the new block.
+#[component]
+fn BlockRoute(selector: BlockSelector) -> Element {
+    let key = format!("{:?}", selector);
+    rsx! {
+        BlockScreen {
+            key: "{key}",
+            selector,
         }
     }
 }
@@ -77,48 +210,50 @@ enum ViewMode {
 }
 
 /// A list of all available screens for easy iteration.
-const ALL_SCREENS: [Screen; 9] = [
-    Screen::Balance,
-    Screen::Send,
-    Screen::Receive,
-    Screen::History,
-    Screen::Utxos,
-    Screen::Addresses,
-    Screen::Peers,
-    Screen::BlockChain,
-    Screen::Mempool,
+const ALL_SCREENS: [Route; 14] = [
+    Route::Balance {},
+    Route::Send {},
+    Route::Receive {},
+    Route::History {},
+    Route::Reports {},
+    Route::Utxos {},
+    Route::Addresses {},
+    Route::Peers {},
+    Route::NodeStats {},
+    Route::BlockChain {},
+    Route::Mempool {},
+    Route::Broadcast {},
+    Route::Logs {},
+    Route::Settings {},
 ];
+/// The screens available for navigation, given the wallet's capabilities.
+fn available_screens(capabilities: WalletCapabilities) -> impl Iterator<Item = Route> {
+    ALL_SCREENS.into_iter().filter(move |screen| {
+        (capabilities.can_spend || !screen.requires_spending())
+            && (capabilities.dev_tools || !screen.requires_dev_tools())
+    })
+}
+
 /// The desktop navigation tabs component.
 #[component]
-fn Tabs(active_screen: Signal<Screen>) -> Element {
+fn Tabs() -> Element {
+    let capabilities = use_context::<AppState>().capabilities;
+    let active_route = use_route::<Route>();
+
     rsx! {
         nav {
             class: "tab-menu",
             ul {
-                for screen in ALL_SCREENS {
+                for screen in available_screens(capabilities) {
                     li {
                         a {
-                            href: "#",
+                            href: "{screen}",
                             // LOGIC FIX: Determine active state including nested screens
-                            class: {
-                                let is_active = match (&*active_screen.read(), &screen) {
-                                    (Screen::MempoolTx(_), Screen::Mempool) => true,
-                                    (Screen::Block(_), Screen::BlockChain) => true,
-                                    (active, current) => active == current,
-                                };
-                                if is_active { "active-tab" } else { "" }
-                            },
-                            "aria-current": {
-                                let is_active = match (&*active_screen.read(), &screen) {
-                                    (Screen::MempoolTx(_), Screen::Mempool) => true,
-                                    (Screen::Block(_), Screen::BlockChain) => true,
-                                    (active, current) => active == current,
-                                };
-                                if is_active { "page" } else { "false" }
-                            },
+                            class: if active_route.is_under(&screen) { "active-tab" } else { "" },
+                            "aria-current": if active_route.is_under(&screen) { "page" } else { "false" },
                             onclick: move |event| {
                                 event.prevent_default();
-                                active_screen.set(screen.clone());
+                                navigator().push(screen.clone());
                             },
                             "{screen.name()}"
                         }
@@ -129,10 +264,108 @@ fn Tabs(active_screen: Signal<Screen>) -> Element {
     }
 }
 
+/// Toggles metered connection mode, which throttles background polling.
+#[component]
+fn MeteredToggle() -> Element {
+    let mut metered = use_context::<AppStateMut>().metered;
+    let title = if metered() {
+        "Metered connection: background updates are slowed or paused. Click to resume."
+    } else {
+        "Click if on a metered connection, to slow or pause background updates."
+    };
+
+    rsx! {
+        Button {
+            button_type: ButtonType::Secondary,
+            outline: !metered(),
+            style: "padding: 0.2rem 0.5rem; font-size: 0.8rem;",
+            title: "{title}",
+            aria_pressed: metered(),
+            on_click: move |_| metered.toggle(),
+            "Metered"
+        }
+    }
+}
+
+/// Pauses or resumes all background updates.
+#[component]
+fn RefreshToggle() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let enabled = (app_state_mut.refresh_enabled)();
+    let title = if enabled {
+        "Click to pause all background updates."
+    } else {
+        "Background updates are paused. Click to resume."
+    };
+
+    rsx! {
+        Button {
+            button_type: ButtonType::Secondary,
+            outline: enabled,
+            style: "padding: 0.2rem 0.5rem; font-size: 0.8rem;",
+            title: "{title}",
+            aria_pressed: !enabled,
+            on_click: move |_| app_state_mut.set_refresh_enabled(!enabled),
+            "Paused"
+        }
+    }
+}
+
+/// Masks or shows all amounts, for using the wallet where others can see the
+/// screen.
+#[component]
+fn HideAmountsToggle() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let hidden = (app_state_mut.hide_amounts)();
+    let title = if hidden {
+        "Amounts are hidden. Click to show them."
+    } else {
+        "Click to hide all amounts, eg while sharing your screen."
+    };
+
+    rsx! {
+        Button {
+            button_type: ButtonType::Secondary,
+            outline: !hidden,
+            style: "padding: 0.2rem 0.5rem; font-size: 0.8rem;",
+            title: "{title}",
+            aria_label: "Hide amounts".to_string(),
+            aria_pressed: hidden,
+            on_click: move |_| app_state_mut.set_hide_amounts(!hidden),
+            if hidden { "🙈" } else { "👁" }
+        }
+    }
+}
+
+/// Locks the app now.  Only shown once a PIN is set.
+#[component]
+fn LockButton() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    if !(app_state_mut.pin_set)() {
+        return rsx! {};
+    }
+
+    rsx! {
+        Button {
+            button_type: ButtonType::Secondary,
+            outline: true,
+            style: "padding: 0.2rem 0.5rem; font-size: 0.8rem;",
+            title: "Lock the wallet until the PIN is entered",
+            on_click: move |_| app_state_mut.lock(),
+            "Lock"
+        }
+    }
+}
+
 /// The mobile "hamburger" dropdown menu component.
 #[component]
-fn HamburgerMenu(active_screen: Signal<Screen>, view_mode: Signal<ViewMode>) -> Element {
+fn HamburgerMenu(view_mode: Signal<ViewMode>) -> Element {
     let mut is_open = use_signal(|| false);
+    let active_route = use_route::<Route>();
+    let capabilities = use_context::<AppState>().capabilities;
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut metered = app_state_mut.metered;
+    let refresh_enabled = (app_state_mut.refresh_enabled)();
 
     rsx! {
         div {
@@ -140,6 +373,8 @@ fn HamburgerMenu(active_screen: Signal<Screen>, view_mode: Signal<ViewMode>) ->
             Button {
                 button_type: ButtonType::Secondary,
                 outline: true,
+                aria_label: "Menu".to_string(),
+                aria_pressed: is_open(),
                 on_click: move |_| is_open.toggle(),
                 "≡"
             }
@@ -150,27 +385,52 @@ fn HamburgerMenu(active_screen: Signal<Screen>, view_mode: Signal<ViewMode>) ->
                 }
                 article {
                     class: "custom-dropdown-menu",
-                    for screen in ALL_SCREENS {
+                    for screen in available_screens(capabilities) {
                         a {
                             // LOGIC FIX: Apply active class to mobile items too using fuzzy match
-                            class: {
-                                let is_active = match (&*active_screen.read(), &screen) {
-                                    (Screen::MempoolTx(_), Screen::Mempool) => true,
-                                    (Screen::Block(_), Screen::BlockChain) => true,
-                                    (active, current) => active == current,
-                                };
-                                if is_active { "custom-dropdown-item active-tab" } else { "custom-dropdown-item" }
+                            class: if active_route.is_under(&screen) {
+                                "custom-dropdown-item active-tab"
+                            } else {
+                                "custom-dropdown-item"
                             },
-                            href: "#",
+                            href: "{screen}",
                             onclick: move |event| {
                                 event.prevent_default();
-                                active_screen.set(screen.clone());
+                                navigator().push(screen.clone());
                                 is_open.set(false);
                             },
                             "{screen.name()}"
                         }
                     }
                     hr {}
+                    a {
+                        class: "custom-dropdown-item",
+                        href: "#",
+                        onclick: move |event| {
+                            event.prevent_default();
+                            metered.toggle();
+                            is_open.set(false);
+                        },
+                        if metered() {
+                            "Metered Connection: On"
+                        } else {
+                            "Metered Connection: Off"
+                        }
+                    }
+                    a {
+                        class: "custom-dropdown-item",
+                        href: "#",
+                        onclick: move |event| {
+                            event.prevent_default();
+                            app_state_mut.set_refresh_enabled(!refresh_enabled);
+                            is_open.set(false);
+                        },
+                        if refresh_enabled {
+                            "Background Updates: On"
+                        } else {
+                            "Background Updates: Paused"
+                        }
+                    }
                     a {
                         class: "custom-dropdown-item",
                         href: "#",
@@ -340,7 +600,13 @@ pub fn App() -> Element {
         style {
             "{responsive_css}"
         }
-        AppBody {}
+        // render the shell at once, rather than waiting on neptune-core.
+        SuspenseBoundary {
+            fallback: |_| rsx! {
+                LoadingShell {}
+            },
+            AppBody {}
+        }
     }
 }
 
@@ -352,18 +618,28 @@ fn AppBody() -> Element {
     let mut initial_data_future = use_server_future(move || async move {
         dioxus_logger::tracing::info!("CALLING BACKEND APIs");
 
-        let (network, prefs) = tokio::join!(api::network(), api::get_user_prefs());
+        let (network, prefs, capabilities) = tokio::join!(
+            api::network(),
+            api::get_user_prefs(),
+            api::wallet_capabilities()
+        );
         (
             network.map_err(|e| e.to_string()),
             prefs.map_err(|e| e.to_string()),
+            capabilities.map_err(|e| e.to_string()),
         )
     })?;
 
+    // The first-run onboarding, which works without neptune-core.
+    let onboarding =
+        use_server_future(move || async move { api::onboarding_status().await.ok() })?;
+    let mut onboarding_finished = use_signal(|| false);
+
     // 2. Read current state
     let current_result = initial_data_future.read();
 
     let needs_retry = match &*current_result {
-        Some((Err(_), _)) | Some((_, Err(_))) => true,
+        Some((Err(_), _, _)) | Some((_, Err(_), _)) | Some((_, _, Err(_))) => true,
         _ => false,
     };
 
@@ -389,14 +665,30 @@ fn AppBody() -> Element {
         }
     });
 
+    if let Some(Some(status)) = &*onboarding.read() {
+        if status.should_show() && !onboarding_finished() {
+            if let Some(reason) = status.reason.clone() {
+                return rsx! {
+                    OnboardingScreen {
+                        reason,
+                        on_finish: move |_| {
+                            onboarding_finished.set(true);
+                            initial_data_future.restart();
+                        },
+                    }
+                };
+            }
+        }
+    }
+
     match &*current_result {
-        Some((Ok(network), Ok(user_prefs))) => rsx! {
+        Some((Ok(network), Ok(user_prefs), Ok(capabilities))) => rsx! {
             LoadedApp {
-                app_state: AppState::new(*network),
+                app_state: AppState::new(*network, *capabilities),
                 user_prefs: user_prefs.clone(),
             }
         },
-        Some((Err(e), _)) | Some((_, Err(e))) => {
+        Some((Err(e), _, _)) | Some((_, Err(e), _)) | Some((_, _, Err(e))) => {
             // SSR Failure or Client-side hydration of that failure
             rsx! {
                 ConnectionModal {
@@ -407,12 +699,202 @@ fn AppBody() -> Element {
         _ => {
             // Loading state (or initial_data_future.restart() was called)
             rsx! {
-                ConnectionModal {}
+                LoadingShell {}
+            }
+        }
+    }
+}
+
+/// Placeholder layout shown while the startup data loads.
+#[component]
+fn LoadingShell() -> Element {
+    rsx! {
+        div {
+            class: "app-main-container",
+            Container {
+                header {
+                    nav {
+                        ul {
+                            li {
+                                h1 {
+                                    style: "margin: 0; font-size: 1.5rem;",
+                                    "Neptune Wallet"
+                                }
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "content",
+                    SkeletonCardGrid { title: "Loading...".to_string() }
+                }
+            }
+        }
+    }
+}
+
+/// Tells the user the wallet can only observe, not spend.
+#[component]
+fn WatchOnlyBanner() -> Element {
+    rsx! {
+        div {
+            role: "status",
+            style: "margin-bottom: 1rem; padding: 0.5rem 1rem; border: 1px solid var(--pico-color-amber-500); border-radius: var(--pico-border-radius); color: var(--pico-color-amber-500);",
+            strong { "Observe only. " }
+            "This is a watch-only wallet. Balances and history are shown, but sending is disabled."
+        }
+    }
+}
+
+/// How often the node health banner asks for the latest tip check.
+const TIP_CHECK_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Warns the user when the node's tip diverges from public nodes, which may
+/// mean it is stalled or eclipsed.  Shows nothing otherwise.
+#[component]
+fn NodeHealthBanner() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut tip_check = use_signal(|| None::<api::tip_check::TipCheck>);
+    use_future(move || async move {
+        loop {
+            if let Ok(check) = api::tip_check().await {
+                tip_check.set(check);
+            }
+            polling::wait_for_next_poll(app_state_mut, TIP_CHECK_POLL_INTERVAL, PollWeight::Light)
+                .await;
+        }
+    });
+
+    let Some(check) = tip_check().filter(|c| c.is_diverged()) else {
+        return rsx! {};
+    };
+    let blocks = check.divergence().unwrap_or_default();
+    let reference_height = check.reference_height.unwrap_or_default();
+    let direction = if blocks < 0 { "behind" } else { "ahead of" };
+
+    rsx! {
+        div {
+            role: "alert",
+            style: "margin-bottom: 1rem; padding: 0.5rem 1rem; border: 1px solid var(--pico-del-color); border-radius: var(--pico-border-radius); color: var(--pico-del-color);",
+            strong { "Node out of step. " }
+            "Your node's tip is at height {check.local_height}, {blocks.unsigned_abs()} blocks {direction} public nodes at {reference_height}. "
+            "It may be stalled, or connected only to peers on another chain. Check its peers before relying on recent balances."
+        }
+    }
+}
+
+/// How often the node version banner checks the node again, eg after an
+/// upgrade.
+const NODE_VERSION_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
+const NEPTUNE_CORE_RELEASES_URL: &str = "https://github.com/Neptune-Crypto/neptune-core/releases";
+
+/// Warns the user when the connected neptune-core is too old or too new for
+/// this app, rather than leaving them with decoding errors mid-use.  Shows
+/// nothing otherwise.
+#[component]
+fn NodeVersionBanner() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut node_version = use_signal(|| None::<api::node_version::NodeVersion>);
+    use_future(move || async move {
+        loop {
+            if let Ok(version) = api::node_version().await {
+                node_version.set(Some(version));
+            }
+            polling::wait_for_next_poll(app_state_mut, NODE_VERSION_POLL_INTERVAL, PollWeight::Light)
+                .await;
+        }
+    });
+
+    let Some(version) = node_version().filter(|v| v.is_incompatible()) else {
+        return rsx! {};
+    };
+    let running = version.version.clone().unwrap_or_default();
+    let supported = api::node_version::supported_range();
+
+    rsx! {
+        div {
+            role: "alert",
+            style: "margin-bottom: 1rem; padding: 0.5rem 1rem; border: 1px solid var(--pico-del-color); border-radius: var(--pico-border-radius); color: var(--pico-del-color);",
+            match version.compatibility {
+                NodeCompatibility::TooOld => rsx! {
+                    strong { "neptune-core is too old. " }
+                    "You run {running}, and this wallet needs {supported}. Upgrade neptune-core, then restart it."
+                },
+                NodeCompatibility::TooNew => rsx! {
+                    strong { "neptune-core is too new. " }
+                    "You run {running}, and this wallet supports {supported}. Upgrade this wallet, or run a supported neptune-core."
+                },
+                NodeCompatibility::ApiMismatch { error } => rsx! {
+                    strong { "neptune-core's version does not match. " }
+                    "Its answers could not be read, which happens when it is older or newer than this wallet supports ({supported}). Run a supported neptune-core, or upgrade this wallet."
+                    details {
+                        summary { "Details" }
+                        small { style: "word-break: break-all;", "{error}" }
+                    }
+                },
+                NodeCompatibility::Supported | NodeCompatibility::Unknown => rsx! {},
             }
+            " "
+            a { href: NEPTUNE_CORE_RELEASES_URL, target: "_blank", "neptune-core releases" }
         }
     }
 }
 
+/// Shows how far the node is through syncing, and how long it may take.
+/// Shows nothing once synced.
+#[component]
+fn SyncProgressBar() -> Element {
+    let sync = use_context::<DataStore>().sync;
+    let Some(progress) = sync().filter(|p| p.syncing) else {
+        return rsx! {};
+    };
+    let percent = progress.fraction() * 100.0;
+    // a tip estimated from block times is rough.
+    let approx = match progress.estimate {
+        TipEstimate::PublicNodes => "",
+        TipEstimate::BlockTime => "~",
+    };
+    let rate = progress
+        .blocks_per_sec
+        .map(|rate| format!(", {rate:.1} blocks/s"))
+        .unwrap_or_default();
+    let eta = progress
+        .eta_secs
+        .map(|secs| format!(", about {} left", format_eta(secs)))
+        .unwrap_or_default();
+
+    rsx! {
+        div {
+            role: "status",
+            style: "padding-bottom: 0.5rem;",
+            small {
+                style: "color: var(--pico-muted-color);",
+                "Syncing: block {progress.height} of {approx}{progress.network_tip} ({percent:.1}%){rate}{eta}"
+            }
+            progress {
+                style: "margin: 0.25rem 0 0;",
+                value: "{progress.height}",
+                max: "{progress.network_tip}",
+            }
+        }
+    }
+}
+
+/// `secs` in the largest units that fit, eg "3h 20m".
+fn format_eta(secs: u64) -> String {
+    let (days, hours, minutes) = (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60);
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m")
+    } else {
+        "under a minute".to_string()
+    }
+}
+
 /// This component holds the main app logic and only runs when data is ready.
 #[component]
 fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
@@ -424,12 +906,17 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
     let mut connection_status = use_signal(|| NeptuneRpcConnectionStatus::Connected);
     use_context_provider(|| connection_status);
 
+    // Background work pauses while the window is hidden.
+    let visible_signal = use_signal(|| true);
+    use_window_visibility(visible_signal);
+
     // --- RECOVERY LOOP (POLLING) ---
-    // Runs only when disconnected during runtime.
+    // Runs only when disconnected, or the node stopped responding, during runtime.
     use_resource(move || async move {
-        if let NeptuneRpcConnectionStatus::Disconnected(_) = connection_status() {
+        if !connection_status().is_connected() {
             loop {
                 compat::sleep(std::time::Duration::from_secs(3)).await;
+                polling::wait_until_visible(visible_signal).await;
                 // We use block_height as a lightweight ping
                 if api::block_height().await.is_ok() {
                     connection_status.set(NeptuneRpcConnectionStatus::Connected);
@@ -442,15 +929,124 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
     // Create signals for mutable state at the top level of the component.
     let prices_signal = use_signal(|| None);
     let display_preference_signal = use_signal(|| user_prefs.display_preference().to_owned());
+    let decimal_separator_signal = use_signal(|| user_prefs.decimal_separator());
+    let thousands_separator_signal = use_signal(|| user_prefs.thousands_separator());
+    let amount_precision_signal = use_signal(|| user_prefs.amount_precision());
+    let metered_signal = use_signal(|| {
+        user_prefs.metered_connection() || compat::connection_is_metered().unwrap_or(false)
+    });
+    let block_explorer_signal = use_signal(|| user_prefs.block_explorer().cloned());
+    let suppressed_confirmations_signal =
+        use_signal(|| user_prefs.suppressed_confirmations().to_vec());
+    let language_signal = use_signal(|| user_prefs.language());
+    // the server only checks the PIN for callers whose role permits it, so
+    // the app is not locked for others.
+    let lock_enabled = user_prefs.pin_set() && app_state.capabilities.can_use_app_lock;
+    let pin_set_signal = use_signal(|| lock_enabled);
+    // start locked, so the PIN is asked for on startup.
+    let locked = use_signal(|| lock_enabled);
+    let idle_lock_minutes_signal = use_signal(|| user_prefs.idle_lock_minutes());
+    let blurred = use_signal(|| false);
+    let idle_blur_minutes_signal = use_signal(|| user_prefs.idle_blur_minutes());
+    let address_check_threshold_signal = use_signal(|| user_prefs.address_check_threshold());
+    let refresh_intervals_signal = use_signal(|| user_prefs.refresh_intervals().clone());
+    let refresh_enabled_signal = use_signal(|| user_prefs.refresh_enabled());
+    let hide_amounts_signal = use_signal(|| user_prefs.hide_amounts());
+    let disabled_startup_checks_signal =
+        use_signal(|| user_prefs.disabled_startup_checks().to_vec());
+    let mut attention_items_signal = use_signal(Vec::new);
+    let mut notifications_signal = use_signal(Vec::new);
 
     // Provide the mutable state by passing the already created signals.
     use_context_provider(|| AppStateMut {
         prices: prices_signal,
         display_preference: display_preference_signal,
+        decimal_separator: decimal_separator_signal,
+        thousands_separator: thousands_separator_signal,
+        amount_precision: amount_precision_signal,
+        metered: metered_signal,
+        visible: visible_signal,
+        block_explorer: block_explorer_signal,
+        suppressed_confirmations: suppressed_confirmations_signal,
+        language: language_signal,
+        pin_set: pin_set_signal,
+        locked,
+        idle_lock_minutes: idle_lock_minutes_signal,
+        blurred,
+        idle_blur_minutes: idle_blur_minutes_signal,
+        address_check_threshold: address_check_threshold_signal,
+        refresh_intervals: refresh_intervals_signal,
+        refresh_enabled: refresh_enabled_signal,
+        hide_amounts: hide_amounts_signal,
+        disabled_startup_checks: disabled_startup_checks_signal,
+        attention_items: attention_items_signal,
+        notifications: notifications_signal,
+    });
+
+    // Node data shared by the screens, kept current by one background task.
+    data_store::use_data_store_provider(use_context::<AppStateMut>(), connection_status);
+
+    // Sends outlive the Send screen.
+    send_queue::use_send_queue_provider();
+
+    // Look for unfinished business once, when the app starts.
+    use_future(move || async move {
+        if let Ok(items) = api::startup_checks().await {
+            attention_items_signal.set(items);
+        }
+    });
+
+    // Lock, and blur, after the configured minutes without user activity.
+    let mut idle = use_idle();
+    use_coroutine(move |_rx: UnboundedReceiver<()>| {
+        let mut locked = locked;
+        let mut blurred = blurred;
+        async move {
+            loop {
+                compat::sleep(std::time::Duration::from_secs(15)).await;
+                let idle_for = idle.idle_for();
+                let exceeds = |minutes: u32| {
+                    let limit = std::time::Duration::from_secs(u64::from(minutes) * 60);
+                    minutes > 0 && idle_for >= limit
+                };
+                if *pin_set_signal.peek()
+                    && !*locked.peek()
+                    && exceeds(*idle_lock_minutes_signal.peek())
+                {
+                    locked.set(true);
+                }
+                if !*blurred.peek() && exceeds(*idle_blur_minutes_signal.peek()) {
+                    blurred.set(true);
+                }
+            }
+        }
+    });
+
+    // Keep the server's throttling in step with the metered setting.
+    use_effect(move || {
+        let metered = metered_signal();
+        spawn(async move {
+            let _ = api::set_metered_connection(metered).await;
+        });
     });
     // Get a handle to the mutable state to populate it.
     let mut app_state_mut = use_context::<AppStateMut>();
 
+    // Keep notifications current, whichever screen is open.
+    use_coroutine(move |_rx: UnboundedReceiver<()>| async move {
+        loop {
+            if let Ok(notifications) = api::notifications().await {
+                notifications_signal.set(notifications);
+            }
+            polling::wait_for_next_poll(
+                app_state_mut,
+                std::time::Duration::from_secs(30),
+                PollWeight::Light,
+            )
+            .await;
+        }
+    });
+
     let fiat_enabled = app_state_mut.display_preference.read().is_fiat_enabled();
     let prices_resource = use_resource(move || async move {
         if fiat_enabled {
@@ -465,7 +1061,12 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
         let mut res = prices_resource;
         async move {
             loop {
-                compat::sleep(std::time::Duration::from_secs(60)).await;
+                polling::wait_for_next_poll(
+                    app_state_mut,
+                    std::time::Duration::from_secs(60),
+                    PollWeight::Light,
+                )
+                .await;
                 // The conditional logic is now INSIDE the hook's closure.
                 if display_preference_signal.read().is_fiat_enabled() {
                     res.restart();
@@ -491,11 +1092,30 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
         }
     });
 
-    let active_screen = use_signal(Screen::default);
+    rsx! {
+        // Any interaction counts as activity, for the idle lock.
+        div {
+            style: "display: contents;",
+            onpointerdown: move |_| idle.touch(),
+            onpointermove: move |_| idle.touch(),
+            onkeydown: move |_| idle.touch(),
+            // Modal reads from Context (no explicit_error passed)
+            ConnectionModal {}
+            Router::<Route> {}
+        }
+    }
+}
+
+/// The layout around every screen: the header with its navigation, the
+/// banners, and the screen of the current route.
+#[component]
+fn Shell() -> Element {
+    let is_watch_only = use_context::<AppState>().capabilities.is_watch_only();
+    let locked = use_context::<AppStateMut>().locked;
+    let blurred = use_context::<AppStateMut>().blurred;
+    let route = use_route::<Route>();
     let mut view_mode = use_signal(ViewMode::default);
 
-    // --- Provide the active_screen signal to the context ---
-    use_context_provider(|| active_screen);
     let wrapper_class = if view_mode() == ViewMode::Mobile {
         "mobile-view-wrapper"
     } else {
@@ -506,13 +1126,28 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
     } else {
         ""
     };
-    rsx! {
-        // Modal reads from Context (no explicit_error passed)
-        ConnectionModal {}
+    // the router stays mounted while locked, so the screen survives a lock.
+    if locked() {
+        return rsx! {
+            LockScreen {}
+        };
+    }
+    // blurred rather than replaced, so that the user sees where they left off.
+    let blur_style = if blurred() {
+        "filter: blur(12px); pointer-events: none; user-select: none;"
+    } else {
+        ""
+    };
 
+    rsx! {
+        if blurred() {
+            IdleOverlay {}
+        }
         if view_mode() == ViewMode::Desktop {
             div {
                 class: "app-main-container",
+                style: "{blur_style}",
+                "aria-hidden": if blurred() { "true" } else { "false" },
                 Container {
                     header {
                         nav {
@@ -529,57 +1164,44 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
                                     }
                                 }
                                 li {
-                                    Tabs {
-                                        active_screen,
-                                    }
+                                    Tabs {}
+                                }
+                                li {
+                                    SearchBox {}
+                                }
+                                li {
+                                    MeteredToggle {}
+                                }
+                                li {
+                                    RefreshToggle {}
+                                }
+                                li {
+                                    HideAmountsToggle {}
+                                }
+                                li {
+                                    SendQueueButton {}
+                                }
+                                li {
+                                    NotificationBell {}
+                                }
+                                li {
+                                    LockButton {}
                                 }
                             }
                         }
+                        SyncProgressBar {}
+                    }
+                    if is_watch_only {
+                        WatchOnlyBanner {}
                     }
+                    NodeHealthBanner {}
+                    NodeVersionBanner {}
                     div {
                         class: "content",
-                        match active_screen() {
-                            Screen::Balance => rsx! {
-                                BalanceScreen {}
-                            },
-                            Screen::Send => rsx! {
-                                SendScreen {}
-                            },
-                            Screen::Receive => rsx! {
-                                ReceiveScreen {}
-                            },
-                            Screen::History => rsx! {
-                                HistoryScreen {}
-                            },
-                            Screen::Utxos => rsx! {
-                                UtxosScreen {}
-                            },
-                            Screen::Addresses => rsx! {
-                                AddressesScreen {}
-                            },
-                            Screen::Peers => rsx! {
-                                PeersScreen {}
-                            },
-                            Screen::BlockChain => rsx! {
-                                BlockChainScreen {}
-                            },
-                            Screen::Mempool => rsx! {
-                                MempoolScreen {}
-                            },
-                            Screen::MempoolTx(tx_id) => rsx! {
-                                MempoolTxScreen {
-                                    tx_id,
-                                }
-                            },
-                            Screen::Block(selector) => {
-                                let key = std::fmt::format(format_args!("{:?}", selector));
-                                rsx! {
-                                    BlockScreen {
-                                        key: "{key}",
-                                        selector,
-                                    }
-                                }
-                            }
+                        if is_watch_only && route.requires_spending() {
+                            BalanceScreen {}
+                        } else {
+                            Outlet::<Route> {}
                         }
                     }
                 }
@@ -587,6 +1209,8 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
         } else {
             div {
                 class: "{wrapper_class}",
+                style: "{blur_style}",
+                "aria-hidden": if blurred() { "true" } else { "false" },
                 div {
                     class: "{content_class}",
                     header {
@@ -600,59 +1224,35 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
                                 }
                             }
                             ul {
+                                li {
+                                    HideAmountsToggle {}
+                                }
+                                li {
+                                    SendQueueButton {}
+                                }
+                                li {
+                                    NotificationBell {}
+                                }
                                 li {
                                     HamburgerMenu {
-                                        active_screen,
                                         view_mode,
                                     }
                                 }
                             }
                         }
+                        SyncProgressBar {}
+                    }
+                    if is_watch_only {
+                        WatchOnlyBanner {}
                     }
+                    NodeHealthBanner {}
+                    NodeVersionBanner {}
                     div {
                         class: "content",
-                        match active_screen() {
-                            Screen::Balance => rsx! {
-                                BalanceScreen {}
-                            },
-                            Screen::Send => rsx! {
-                                SendScreen {}
-                            },
-                            Screen::Receive => rsx! {
-                                ReceiveScreen {}
-                            },
-                            Screen::History => rsx! {
-                                HistoryScreen {}
-                            },
-                            Screen::Utxos => rsx! {
-                                UtxosScreen {}
-                            },
-                            Screen::Addresses => rsx! {
-                                AddressesScreen {}
-                            },
-                            Screen::Peers => rsx! {
-                                PeersScreen {}
-                            },
-                            Screen::BlockChain => rsx! {
-                                BlockChainScreen {}
-                            },
-                            Screen::Mempool => rsx! {
-                                MempoolScreen {}
-                            },
-                            Screen::MempoolTx(tx_id) => rsx! {
-                                MempoolTxScreen {
-                                    tx_id,
-                                }
-                            },
-                            Screen::Block(selector) => {
-                                let key = std::fmt::format(format_args!("{:?}", selector));
-                                rsx! {
-                                    BlockScreen {
-                                        key: "{key}",
-                                        selector,
-                                    }
-                                }
-                            }
+                        if is_watch_only && route.requires_spending() {
+                            BalanceScreen {}
+                        } else {
+                            Outlet::<Route> {}
                         }
                     }
                 }
@@ -661,23 +1261,82 @@ fn LoadedApp(app_state: AppState, user_prefs: UserPrefs) -> Element {
     }
 }
 
+/// Explains that neptune-core answers but its RPC cookie cannot be read, and
+/// how to fix that.  The recovery loop in [LoadedApp] closes it once the
+/// cookie can be read.
+#[component]
+fn CookieUnavailableModal(network: Option<Network>, msg: String) -> Element {
+    rsx! {
+        div {
+            style: "
+                position: fixed; top: 0; left: 0; width: 100%; height: 100%;
+                background: rgba(0, 0, 0, 0.7);
+                z-index: 9999;
+                display: flex; justify-content: center; align-items: center;
+                backdrop-filter: blur(5px);
+            ",
+            article {
+                style: "max-width: 500px; padding: 2rem; border-radius: 10px; box-shadow: 0 4px 20px rgba(0,0,0,0.5);",
+                h5 { "Cannot Authenticate with Neptune-Core" }
+                p {
+                    match network {
+                        Some(network) => rsx! { "neptune-core is running on {network}, " },
+                        None => rsx! { "neptune-core is running, " },
+                    }
+                    "but this app cannot read the RPC cookie that neptune-core writes to its data directory, so the wallet cannot be read."
+                }
+                ul {
+                    li { "Run this app as the same user as neptune-core, or give it read access to neptune-core's data directory." }
+                    li { "If this app sees neptune-core's data directory under another path, eg in a container, set the NEPTUNE_DATA_DIR env var to that path." }
+                }
+                div {
+                    class: "aria-busy",
+                    "Checking again every few seconds..."
+                }
+                progress {}
+                details {
+                    summary {
+                        style: "margin-top: 1rem; cursor: pointer; color: var(--pico-muted-color); font-size: 0.9rem;",
+                        "Details"
+                    }
+                    p {
+                        style: "margin-top: 0.5rem; word-break: break-all; color: var(--pico-del-color);",
+                        "{msg}"
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn ConnectionModal(explicit_error: Option<Option<String>>) -> Element {
     // Try to get context. It might not exist if called from AppBody.
     let status_signal = try_use_context::<Signal<NeptuneRpcConnectionStatus>>();
 
-    let (show, msg) = if let Some(Some(err)) = explicit_error {
+    // the network needs no authentication, so is known even without the cookie.
+    let network = try_use_context::<AppState>().map(|s| s.network);
+
+    let mut retrying = use_signal(|| false);
+
+    let (show, not_responding, msg) = if let Some(Some(err)) = explicit_error {
         // Case 1: AppBody passing an explicit error/loading string
-        (true, err)
+        (true, false, err)
     } else if let Some(signal) = status_signal {
         // Case 2: LoadedApp using context
         match *signal.read() {
-            NeptuneRpcConnectionStatus::Connected => (false, String::new()),
-            NeptuneRpcConnectionStatus::Disconnected(ref m) => (true, m.clone()),
+            NeptuneRpcConnectionStatus::Connected => (false, false, String::new()),
+            NeptuneRpcConnectionStatus::Disconnected(ref m) => (true, false, m.clone()),
+            NeptuneRpcConnectionStatus::NotResponding(ref m) => (true, true, m.clone()),
+            NeptuneRpcConnectionStatus::CookieUnavailable(ref m) => {
+                return rsx! {
+                    CookieUnavailableModal { network, msg: m.clone() }
+                };
+            }
         }
     } else {
         // Case 3: Fallback (shouldn't happen in logic above)
-        (true, String::new())
+        (true, false, String::new())
     };
 
     if !show {
@@ -695,14 +1354,43 @@ fn ConnectionModal(explicit_error: Option<Option<String>>) -> Element {
             ",
             article {
                 style: "max-width: 500px; padding: 2rem; border-radius: 10px; box-shadow: 0 4px 20px rgba(0,0,0,0.5);",
-                h5 { "No Neptune-Core Connection" }
-                p { "Please check if neptune-core is running" }
-                div {
-                    class: "aria-busy",
-                    style: "margin-top: 1rem;",
-                    "Attempting to connect..."
-                }
-                progress {
+                if not_responding {
+                    h5 { "Neptune-Core Not Responding" }
+                    p {
+                        "neptune-core is running but did not answer in time. It may be busy, eg syncing or proving, or it may be hung."
+                    }
+                    Button {
+                        disabled: retrying(),
+                        on_click: move |_| {
+                            let Some(mut signal) = status_signal else {
+                                return;
+                            };
+                            retrying.set(true);
+                            spawn(async move {
+                                match api::block_height().await {
+                                    Ok(_) => signal.set(NeptuneRpcConnectionStatus::Connected),
+                                    Err(e) if e.is_timeout() => {
+                                        signal.set(NeptuneRpcConnectionStatus::NotResponding(e.to_string()))
+                                    }
+                                    Err(e) => {
+                                        signal.set(NeptuneRpcConnectionStatus::Disconnected(e.to_string()))
+                                    }
+                                }
+                                retrying.set(false);
+                            });
+                        },
+                        if retrying() { "Retrying..." } else { "Retry" }
+                    }
+                } else {
+                    h5 { "No Neptune-Core Connection" }
+                    p { "Please check if neptune-core is running" }
+                    div {
+                        class: "aria-busy",
+                        style: "margin-top: 1rem;",
+                        "Attempting to connect..."
+                    }
+                    progress {
+                    }
                 }
 
                 if !msg.is_empty() {
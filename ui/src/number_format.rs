@@ -0,0 +1,20 @@
+//! The user's number format, from [AppStateMut].
+
+use api::number_format::NumberFormat;
+use dioxus::prelude::*;
+
+use crate::app_state_mut::AppStateMut;
+
+/// The user's current number format.
+///
+/// Reading it subscribes the calling component, so it re-renders when the
+/// format changes.
+pub fn current() -> NumberFormat {
+    let state = consume_context::<AppStateMut>();
+    NumberFormat {
+        language: *state.language.read(),
+        decimal_separator: *state.decimal_separator.read(),
+        thousands_separator: *state.thousands_separator.read(),
+        precision: *state.amount_precision.read(),
+    }
+}
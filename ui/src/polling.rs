@@ -0,0 +1,75 @@
+//! Pacing of background polling, with respect to metered connections,
+//! whether the window is visible, and whether the user paused it.
+
+use std::time::Duration;
+
+use api::prefs::refresh_intervals::RefreshScreen;
+use dioxus::prelude::*;
+
+use crate::app_state_mut::AppStateMut;
+use crate::compat;
+
+/// How long light polls are delayed while metered, relative to normal.
+const METERED_SLOWDOWN: u32 = 6;
+
+/// How often a hidden window is checked for being shown again.
+const HIDDEN_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often paused polling is checked for being resumed.
+const PAUSED_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How much bandwidth a poll uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PollWeight {
+    /// Small responses, eg the balance. Slowed down while metered.
+    Light,
+    /// Potentially large responses, eg the history or UTXO list. Paused while
+    /// metered.
+    Heavy,
+}
+
+/// Waits until the next background poll is due.
+///
+/// Normally this is `interval`. While metered, light polls wait longer and
+/// heavy polls wait until the connection is unmetered.  While the window is
+/// hidden, or polling is paused, all polls wait, and the one due is made as
+/// soon as it is shown and resumed.
+pub async fn wait_for_next_poll(state: AppStateMut, interval: Duration, weight: PollWeight) {
+    compat::sleep(interval).await;
+
+    match weight {
+        PollWeight::Light => {
+            if *state.metered.peek() {
+                compat::sleep(interval * (METERED_SLOWDOWN - 1)).await;
+            }
+        }
+        PollWeight::Heavy => {
+            while *state.metered.peek() {
+                compat::sleep(interval).await;
+            }
+        }
+    }
+
+    loop {
+        wait_until_visible(state.visible).await;
+        if *state.refresh_enabled.peek() {
+            break;
+        }
+        compat::sleep(PAUSED_CHECK_INTERVAL).await;
+    }
+}
+
+/// Waits until `screen` is due to refresh, at the interval the user chose
+/// for it.  The interval is read anew on every call, so a change takes
+/// effect from the next refresh.
+pub async fn wait_for_screen_refresh(state: AppStateMut, screen: RefreshScreen, weight: PollWeight) {
+    let interval = state.refresh_intervals.peek().interval(screen);
+    wait_for_next_poll(state, interval, weight).await;
+}
+
+/// Returns once the window is visible.
+pub async fn wait_until_visible(visible: Signal<bool>) {
+    while !*visible.peek() {
+        compat::sleep(HIDDEN_CHECK_INTERVAL).await;
+    }
+}
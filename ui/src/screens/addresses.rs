@@ -3,6 +3,7 @@
 //=============================================================================
 use std::rc::Rc;
 
+use api::address_label::AddressUsage;
 use dioxus::prelude::*;
 use neptune_types::address::KeyType;
 use neptune_types::address::ReceivingAddress;
@@ -10,6 +11,7 @@ use neptune_types::network::Network;
 
 use crate::app_state::AppState;
 use crate::components::address::Address;
+use crate::components::amount::Amount;
 use crate::components::empty_state::EmptyState;
 use crate::components::export_seed_phrase_modal::ExportSeedPhraseModal;
 use crate::components::pico::Button;
@@ -18,6 +20,7 @@ use crate::components::pico::Card;
 use crate::components::pico::CopyButton;
 use crate::components::pico::NoTitleModal;
 use crate::components::qr_code::QrCode;
+use crate::components::skeleton::SkeletonTable;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
 
 // Embed the SVG content as a static string at compile time.
@@ -27,15 +30,30 @@ const ADDRESSES_EMPTY_SVG: &str = include_str!("../../assets/svg/addresses-empty
 #[component]
 fn AddressRow(
     address: Rc<ReceivingAddress>,
+    /// Position among the derived addresses of the same key type.
+    index: usize,
+    label: Option<String>,
+    /// `None` until usage has been loaded.
+    usage: Option<AddressUsage>,
     on_qr_request: EventHandler<Rc<ReceivingAddress>>,
+    on_label_change: EventHandler<String>,
     network: Network,
 ) -> Element {
     // This component now manages its own hover and copied state locally.
     let mut is_hovered = use_signal(|| false);
+    let mut is_editing = use_signal(|| false);
+    let mut label_draft = use_signal(String::new);
 
     let key_type = KeyType::from(&*address);
     let key_type_str = key_type.to_string();
 
+    let current_label = label.clone().unwrap_or_default();
+    let start_editing = move |_| {
+        is_hovered.set(false);
+        label_draft.set(current_label.clone());
+        is_editing.set(true);
+    };
+
     rsx! {
         tr {
             // When the mouse leaves, we reset both hover and copied states.
@@ -48,7 +66,39 @@ fn AddressRow(
             td {
 
 
-                "{key_type_str}"
+                "{key_type_str} #{index}"
+            }
+
+            td {
+                if is_editing() {
+                    form {
+                        style: "margin: 0;",
+                        onsubmit: move |e: FormEvent| {
+                            e.prevent_default();
+                            is_editing.set(false);
+                            on_label_change.call(label_draft());
+                        },
+                        input {
+                            style: "margin: 0; padding: 0.2rem 0.5rem; height: auto;",
+                            autofocus: true,
+                            placeholder: "Label",
+                            value: "{label_draft}",
+                            oninput: move |e| label_draft.set(e.value()),
+                            onkeydown: move |e: KeyboardEvent| {
+                                if e.key() == Key::Escape {
+                                    is_editing.set(false);
+                                }
+                            },
+                        }
+                    }
+                } else if let Some(label) = &label {
+                    "{label}"
+                } else {
+                    span {
+                        style: "color: var(--pico-muted-color);",
+                        "—"
+                    }
+                }
             }
 
             td {
@@ -60,9 +110,32 @@ fn AddressRow(
                 }
             }
 
+            match usage {
+                None => rsx! {
+                    td { "…" }
+                    td { "…" }
+                },
+                Some(usage) => rsx! {
+                    td {
+                        style: "text-align: right;",
+                        "{usage.received_count}"
+                    }
+                    td {
+                        style: "text-align: right; white-space: nowrap;",
+                        title: if usage.total_is_partial {
+                            "Excludes funds that arrived in the same block as funds to other addresses of this wallet"
+                        },
+                        if usage.total_is_partial {
+                            "≥ "
+                        }
+                        Amount { amount: usage.total_received }
+                    }
+                },
+            }
+
             // Restore original style with min-width for the button group.
             td {
-                style: "min-width: 150px; display: flex; align-items: center; justify-content: flex-end;",
+                style: "min-width: 200px; display: flex; align-items: center; justify-content: flex-end;",
 
                 div {
                     style: {
@@ -84,27 +157,106 @@ fn AddressRow(
                         },
                         "QR"
                     }
+                    Button {
+                        button_type: ButtonType::Contrast,
+                        outline: true,
+                        on_click: start_editing,
+                        "Label"
+                    }
                 }
             }
         }
     }
 }
 
+/// Buttons that derive the next receiving address of each key type.
+#[component]
+fn DeriveAddressButtons(on_derived: EventHandler<ReceivingAddress>) -> Element {
+    let mut is_deriving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let derive = move |key_type: KeyType| {
+        is_deriving.set(true);
+        spawn(async move {
+            match api::next_receiving_address(key_type).await {
+                Ok(address) => {
+                    error.set(None);
+                    on_derived.call(address);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_deriving.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            style: "display: flex; align-items: center; gap: 0.5rem;",
+            if let Some(e) = error() {
+                small {
+                    style: "color: var(--pico-del-color);",
+                    "{e}"
+                }
+            }
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: true,
+                style: "height: 1.8rem; line-height: 1.8rem; font-size: 0.8em; padding: 0 1rem;",
+                disabled: is_deriving(),
+                on_click: move |_| derive(KeyType::Generation),
+                title: "Derive the next Generation address, for receiving from others",
+                "+ Generation"
+            }
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: true,
+                style: "height: 1.8rem; line-height: 1.8rem; font-size: 0.8em; padding: 0 1rem;",
+                disabled: is_deriving(),
+                on_click: move |_| derive(KeyType::Symmetric),
+                title: "Derive the next Symmetric Key address. Only for sending to yourself; do not share it.",
+                "+ Symmetric"
+            }
+        }
+    }
+}
+
 #[component]
 pub fn AddressesScreen() -> Element {
-    let network = use_context::<AppState>().network;
+    let app_state = use_context::<AppState>();
+    let network = app_state.network;
+    let can_export_secret = app_state.capabilities.can_export_secret;
     let mut rpc = use_rpc_checker(); // Initialize Hook
 
-    let mut known_keys = use_resource(move || async move { api::known_keys().await });
+    let mut known_addresses = use_resource(move || async move { api::known_addresses().await });
+    let mut labels = use_resource(move || async move { api::address_labels().await });
+    let mut usages = use_resource(move || async move { api::address_usage().await });
 
-    // Effect: Restarts the resource when connection is restored.
+    // Effect: Restarts the resources when connection is restored.
     let status_sig = rpc.status();
     use_effect(move || {
         if status_sig.read().is_connected() {
-            known_keys.restart();
+            known_addresses.restart();
+            labels.restart();
+            usages.restart();
         }
     });
 
+    let mut qr_code_content = use_signal::<Option<Rc<ReceivingAddress>>>(|| None);
+    let mut qr_modal_is_open = use_signal(|| false);
+    let mut label_error = use_signal(|| None::<String>);
+
+    let set_label = move |address: String, label: String| {
+        spawn(async move {
+            match api::set_address_label(address, label).await {
+                Ok(_) => {
+                    label_error.set(None);
+                    labels.restart();
+                }
+                Err(e) => label_error.set(Some(e.to_string())),
+            }
+        });
+    };
+
     // Signal for the Modal state
     let mut modal_is_open = use_signal(|| false);
 
@@ -114,22 +266,9 @@ pub fn AddressesScreen() -> Element {
             is_open: modal_is_open,
         }
 
-        match &*known_keys.read() {
+        match &*known_addresses.read() {
             None => rsx! {
-                Card {
-
-                    h3 {
-                        "My Addresses"
-                    }
-                    p {
-
-                        "Loading..."
-                    }
-                    progress {
-
-
-                    }
-                }
+                SkeletonTable { title: "My Addresses".to_string(), columns: 6 }
             },
             Some(result) if !rpc.check_result_ref(&result) => rsx! {
                 Card {
@@ -148,17 +287,23 @@ pub fn AddressesScreen() -> Element {
                         "Failed to load addresses: {e}"
                     }
                     button {
-                        onclick: move |_| known_keys.restart(),
+                        onclick: move |_| known_addresses.restart(),
                         "Retry"
                     }
                 }
             },
-            Some(Ok(keys)) if keys.is_empty() => {
+            Some(Ok(known)) if known.is_empty() => {
                 rsx! {
                     Card {
 
-                        h3 {
-                            "My Addresses"
+                        div {
+                            style: "display: flex; justify-content: space-between; align-items: center;",
+                            h3 {
+                                "My Addresses"
+                            }
+                            DeriveAddressButtons {
+                                on_derived: move |_| known_addresses.restart(),
+                            }
                         }
                         EmptyState {
                             title: "No Addresses Found".to_string(),
@@ -174,16 +319,12 @@ pub fn AddressesScreen() -> Element {
                     }
                 }
             }
-            Some(Ok(keys)) => {
-                let mut qr_code_content = use_signal::<
-                    Option<Rc<ReceivingAddress>>,
-                >(|| None);
-                let mut qr_modal_is_open = use_signal(|| false);
-                let addresses: Vec<_> = keys
-                    .iter()
-                    .map(|key| key.to_address())
-                    .map(Rc::new)
-                    .collect();
+            Some(Ok(known)) => {
+                // Grouped by key type, in derivation order.
+                let mut addresses: Vec<_> = known.iter().cloned().map(Rc::new).collect();
+                addresses.sort_by_key(|address| KeyType::from(&**address).is_symmetric());
+                let label_map = labels.read().as_ref().and_then(|r| r.as_ref().ok()).cloned();
+                let usage_map = usages.read().as_ref().and_then(|r| r.as_ref().ok()).cloned();
                 rsx! {
                     NoTitleModal {
                         is_open: qr_modal_is_open,
@@ -205,15 +346,29 @@ pub fn AddressesScreen() -> Element {
                             h3 {
                                 "My Addresses"
                             }
-                            // Button for Export Seed Phrase
-                            Button {
-                                button_type: ButtonType::Primary,
-                                outline: true,
-                                // Smaller height by modifying Pico variables
-                                style: "height: 1.8rem; line-height: 1.8rem; font-size: 0.8em; padding: 0 1rem;",
-                                on_click: move |_| modal_is_open.set(true),
-                                title: "Backup your wallet to offline storage", // Tooltip
-                                "Export Seed Phrase"
+                            div {
+                                style: "display: flex; align-items: center; gap: 0.5rem;",
+                                DeriveAddressButtons {
+                                    on_derived: move |_| known_addresses.restart(),
+                                }
+                                // Button for Export Seed Phrase
+                                if can_export_secret {
+                                    Button {
+                                        button_type: ButtonType::Primary,
+                                        outline: true,
+                                        // Smaller height by modifying Pico variables
+                                        style: "height: 1.8rem; line-height: 1.8rem; font-size: 0.8em; padding: 0 1rem;",
+                                        on_click: move |_| modal_is_open.set(true),
+                                        title: "Backup your wallet to offline storage", // Tooltip
+                                        "Export Seed Phrase"
+                                    }
+                                }
+                            }
+                        }
+                        if let Some(e) = label_error() {
+                            p {
+                                style: "color: var(--pico-del-color);",
+                                "Failed to save label: {e}"
                             }
                         }
                         // This div is the scrollable container for the table.
@@ -227,14 +382,33 @@ pub fn AddressesScreen() -> Element {
 
                                         // The 'th' elements are now sticky to the top of the scrollable container.
                                         th {
+                                            scope: "col",
                                             style: "position: sticky; top: 0; background: var(--pico-card-background-color);",
                                             "Type"
                                         }
                                         th {
+                                            scope: "col",
+                                            style: "position: sticky; top: 0; background: var(--pico-card-background-color);",
+                                            "Label"
+                                        }
+                                        th {
+                                            scope: "col",
                                             style: "position: sticky; top: 0; background: var(--pico-card-background-color);",
                                             "Address"
                                         }
                                         th {
+                                            scope: "col",
+                                            style: "position: sticky; top: 0; background: var(--pico-card-background-color); text-align: right;",
+                                            title: "Number of UTXOs received, including change",
+                                            "Received"
+                                        }
+                                        th {
+                                            scope: "col",
+                                            style: "position: sticky; top: 0; background: var(--pico-card-background-color); text-align: right;",
+                                            "Total Received"
+                                        }
+                                        th {
+                                            scope: "col",
                                             style: "position: sticky; top: 0; background: var(--pico-card-background-color); width: 1%;",
                                             ""
                                         }
@@ -244,18 +418,38 @@ pub fn AddressesScreen() -> Element {
 
                                     {
                                         addresses
-                                            .into_iter()
-                                            .map(|address| {
+                                            .iter()
+                                            .enumerate()
+                                            .map(|(i, address)| {
+                                                let key_type = KeyType::from(&**address);
+                                                let index = addresses[..i]
+                                                    .iter()
+                                                    .filter(|a| KeyType::from(&***a) == key_type)
+                                                    .count();
                                                 let full_address_for_key = address.to_bech32m(network).unwrap();
+                                                let label = label_map
+                                                    .as_ref()
+                                                    .and_then(|l| l.get(&full_address_for_key))
+                                                    .cloned();
+                                                let usage = usage_map.as_ref().map(|u| {
+                                                    u.get(&full_address_for_key).copied().unwrap_or_default()
+                                                });
+                                                let address_for_label = full_address_for_key.clone();
                                                 rsx! {
                                                     AddressRow {
                                                         key: "{full_address_for_key}",
-                                                        address: Rc::clone(&address),
+                                                        address: Rc::clone(address),
+                                                        index,
+                                                        label,
+                                                        usage,
                                                         network,
                                                         on_qr_request: move |address: Rc<ReceivingAddress>| {
                                                             qr_code_content.set(Some(address));
                                                             qr_modal_is_open.set(true);
                                                         },
+                                                        on_label_change: move |label: String| {
+                                                            set_label(address_for_label.clone(), label)
+                                                        },
                                                     }
                                                 }
                                             })
@@ -1,6 +1,7 @@
 // ui/src/screens/balance.rs
 use std::rc::Rc;
 
+use api::attention::AttentionItem;
 use api::fiat_amount::FiatAmount;
 use api::fiat_currency::FiatCurrency;
 use api::prefs::display_preference::DisplayPreference;
@@ -10,15 +11,22 @@ use num_traits::CheckedSub;
 use num_traits::Zero;
 use strum::IntoEnumIterator;
 
+use crate::components::action_link::ActionLink;
 use crate::components::amount::Amount;
 use crate::components::block::Block;
 use crate::components::currency_chooser::CurrencyChooser;
 use crate::components::currency_chooser::CurrencyInfo;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
 use crate::components::pico::Card;
+use crate::components::skeleton::SkeletonCardGrid;
 use crate::currency::npt_to_fiat;
+use crate::data_store::DataStore;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
+use crate::i18n::t;
 use crate::AppState;
 use crate::AppStateMut;
+use crate::Route;
 
 /// A responsive container for a section of the dashboard.
 #[component]
@@ -77,7 +85,7 @@ fn BalanceRow(
 
     rsx! {
         InfoItem {
-            label: "Available".to_string(),
+            label: t!("balance-available"),
             Amount {
                 amount: available,
                 fiat_equivalent: available_fiat,
@@ -85,14 +93,14 @@ fn BalanceRow(
         }
         if time_locked > NativeCurrencyAmount::zero() {
             InfoItem {
-                label: "Time-locked".to_string(),
+                label: t!("balance-time-locked"),
                 Amount {
                     amount: time_locked,
                     fiat_equivalent: time_locked_fiat,
                 }
             }
             InfoItem {
-                label: "Total".to_string(),
+                label: t!("balance-total"),
                 Amount {
                     amount: total,
                     fiat_equivalent: total_fiat,
@@ -102,67 +110,135 @@ fn BalanceRow(
     }
 }
 
+/// The wallet's balance of each asset, shown only if it holds more than the
+/// native coin.
 #[component]
-pub fn BalanceScreen() -> Element {
-    let mut rpc = use_rpc_checker(); // Initialize Hook
-    let app_state = use_context::<AppState>();
-    let app_state_mut = use_context::<AppStateMut>();
-    let network = app_state.network;
-    let mut dashboard_data =
-        use_resource(move || async move { api::dashboard_overview_data().await });
+fn AssetsCard() -> Element {
+    let assets = use_resource(move || async move { api::list_assets().await });
 
-    // Effect: Restarts the resource when connection is restored.
-    let status_sig = rpc.status();
-    use_effect(move || {
-        if status_sig.read().is_connected() {
-            dashboard_data.restart();
+    let Some(Ok(assets)) = &*assets.read() else {
+        return rsx! {};
+    };
+    if assets.len() < 2 {
+        return rsx! {};
+    }
+
+    rsx! {
+        InfoCard {
+            title: t!("balance-assets"),
+            for balance in assets.iter().cloned() {
+                InfoItem {
+                    key: "{balance.asset}",
+                    label: balance.asset.to_string(),
+                    span {
+                        if let Some(amount) = balance.amount {
+                            Amount { amount }
+                        } else {
+                            {t!("balance-asset-no-amount")}
+                        }
+                        small {
+                            style: "margin-left: 0.5rem; color: var(--pico-muted-color);",
+                            {t!("balance-asset-utxos", count = balance.num_utxos)}
+                        }
+                    }
+                }
+            }
         }
-    });
+    }
+}
 
-    // Coroutine: Polls every 5 seconds while connected.
-    // This ensures we detect if the connection dies while sitting on this screen.
-    use_coroutine(move |_rx: UnboundedReceiver<()>| {
-        let rpc_status = rpc.status(); // Use signal handle
-        let mut data_resource = dashboard_data;
+/// Summarizes what the startup checks found, with links to resolve each item.
+#[component]
+fn NeedsAttentionCard() -> Element {
+    let mut attention_items = use_context::<AppStateMut>().attention_items;
 
-        async move {
-            loop {
-                // Wait 5 seconds
-                crate::compat::sleep(std::time::Duration::from_millis(5000)).await;
+    if attention_items.read().is_empty() {
+        return rsx! {};
+    }
 
-                // Only restart (poll) if we believe we are connected.
-                // If disconnected, the global AppBody loop handles the "pinging".
-                if (*rpc_status.read()).is_connected() {
-                    data_resource.restart();
+    rsx! {
+        article {
+            style: "margin-bottom: 1rem; border: 1px solid var(--pico-color-amber-500); border-radius: var(--pico-border-radius); padding: 0.5rem; background-color: var(--pico-card-background-color);",
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; border-bottom: 1px solid var(--pico-secondary-border); margin-bottom: 0.5rem;",
+                h5 {
+                    style: "margin: 0;",
+                    {t!("balance-attention")}
+                }
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                    on_click: move |_| attention_items.set(vec![]),
+                    {t!("balance-attention-dismiss")}
+                }
+            }
+            ul {
+                style: "margin: 0;",
+                for item in attention_items.read().iter().cloned() {
+                    li {
+                        match item {
+                            AttentionItem::SendDrafts { count } => rsx! {
+                                {t!("balance-attention-drafts", count = count)}
+                                " — "
+                                ActionLink {
+                                    to: Route::SendDrafts {},
+                                    {t!("balance-attention-drafts-action")}
+                                }
+                            },
+                            AttentionItem::StuckTransaction { txid } => {
+                                let txid_str = txid.to_string();
+                                let abbreviated = format!(
+                                    "{}...{}",
+                                    &txid_str[0..6],
+                                    &txid_str[txid_str.len() - 4..]
+                                );
+                                rsx! {
+                                    {t!("balance-attention-stuck-tx", txid = abbreviated)}
+                                    " — "
+                                    ActionLink {
+                                        to: Route::MempoolTx { tx_id: txid },
+                                        {t!("balance-attention-stuck-tx-action")}
+                                    }
+                                }
+                            }
+                            AttentionItem::UnclaimedUtxos { count } => rsx! {
+                                {t!("balance-attention-unclaimed", count = count)}
+                                " — "
+                                ActionLink {
+                                    to: Route::Utxos {},
+                                    {t!("balance-attention-unclaimed-action")}
+                                }
+                            },
+                        }
+                    }
                 }
             }
         }
-    });
+    }
+}
+
+#[component]
+pub fn BalanceScreen() -> Element {
+    let mut rpc = use_rpc_checker(); // Initialize Hook
+    let app_state = use_context::<AppState>();
+    let app_state_mut = use_context::<AppStateMut>();
+    let network = app_state.network;
+    // kept current by the data store, which other screens share.
+    let store = use_context::<DataStore>();
+    let dashboard_data = store.dashboard;
 
     rsx! {
+        NeedsAttentionCard {}
         match &*dashboard_data.read() {
             None => rsx! {
-                Card {
-
-                    h3 {
-
-                        "Wallet Overview"
-                    }
-                    p {
-
-                        "Loading..."
-                    }
-                    progress {
-
-
-                    }
-                }
+                SkeletonCardGrid { title: t!("balance-title") }
             },
             Some(result) if !rpc.check_result_ref(&result) => rsx! {
                 // modal ConnectionLost is displayed by rpc.check_result_ref
                 Card {
                     h3 {
-                        "Wallet Overview"
+                        {t!("balance-title")}
                     }
                 }
             },
@@ -171,15 +247,17 @@ pub fn BalanceScreen() -> Element {
 
                     h3 {
 
-                        "Error"
+                        {t!("common-error")}
                     }
                     p {
 
-                        "Failed to load dashboard data: {e}"
+                        {t!("balance-load-failed", error = e.to_string())}
                     }
                     button {
-                        onclick: move |_| dashboard_data.restart(),
-                        "Retry"
+                        onclick: move |_| {
+                            spawn(store.refresh());
+                        },
+                        {t!("common-retry")}
                     }
                 }
             },
@@ -189,7 +267,7 @@ pub fn BalanceScreen() -> Element {
                 } else {
                     "var(--pico-color-amber-500)"
                 };
-                let sync_text = if data.syncing { "Syncing..." } else { "Synced" };
+                let sync_text = if data.syncing { t!("balance-syncing") } else { t!("balance-synced") };
                 let block_digest = Rc::new(data.tip_digest);
                 let height = Rc::new(data.tip_header.height);
                 let show_unconfirmed = data.unconfirmed_available_balance
@@ -276,7 +354,7 @@ pub fn BalanceScreen() -> Element {
                                 style: "display: flex; justify-content: space-between; align-items: center; border-bottom: 1px solid var(--pico-secondary-border); margin-top: 0; margin-bottom: 0.5rem;",
                                 h5 {
                                     style: "margin-top: 0; margin-bottom: 0;",
-                                    "Confirmed Balance"
+                                    {t!("balance-confirmed")}
                                 }
                                 {fiat_mode_active.then(|| rsx! {
                                     small {
@@ -304,7 +382,7 @@ pub fn BalanceScreen() -> Element {
                         }
                         if show_unconfirmed {
                             InfoCard {
-                                title: "Unconfirmed Balance".to_string(),
+                                title: t!("balance-unconfirmed"),
                                 div {
                                     style: "{balance_grid_style}",
                                     BalanceRow {
@@ -316,24 +394,25 @@ pub fn BalanceScreen() -> Element {
                                 }
                             }
                         }
+                        AssetsCard {}
                         InfoCard {
-                            title: "Blockchain".to_string(),
+                            title: t!("balance-blockchain"),
                             InfoItem {
-                                label: "Network".to_string(),
+                                label: t!("balance-network"),
                                 span {
 
                                     "{network}"
                                 }
                             }
                             InfoItem {
-                                label: "Status".to_string(),
+                                label: t!("balance-status"),
                                 span {
                                     style: "color: {status_color};",
                                     "{sync_text}"
                                 }
                             }
                             InfoItem {
-                                label: "Tip".to_string(),
+                                label: t!("balance-tip"),
                                 Block {
                                     block_digest,
                                     height,
@@ -341,16 +420,16 @@ pub fn BalanceScreen() -> Element {
                             }
                         }
                         InfoCard {
-                            title: "Mempool".to_string(),
+                            title: t!("balance-mempool"),
                             InfoItem {
-                                label: "Transactions".to_string(),
+                                label: t!("balance-transactions"),
                                 span {
 
                                     "{data.mempool_total_tx_count}"
                                 }
                             }
                             InfoItem {
-                                label: "Size (bytes)".to_string(),
+                                label: t!("balance-size-bytes"),
                                 span {
 
                                     "{data.mempool_size}"
@@ -358,16 +437,16 @@ pub fn BalanceScreen() -> Element {
                             }
                         }
                         InfoCard {
-                            title: "Network Peers".to_string(),
+                            title: t!("balance-network-peers"),
                             InfoItem {
-                                label: "Connected Peers".to_string(),
+                                label: t!("balance-connected-peers"),
                                 span {
 
                                     "{data.peer_count.unwrap_or_default()}"
                                 }
                             }
                             InfoItem {
-                                label: "Max Peers".to_string(),
+                                label: t!("balance-max-peers"),
                                 span {
 
                                     "{data.max_num_peers}"
@@ -375,16 +454,16 @@ pub fn BalanceScreen() -> Element {
                             }
                         }
                         InfoCard {
-                            title: "Node Info".to_string(),
+                            title: t!("balance-node-info"),
                             InfoItem {
-                                label: "Mining Status".to_string(),
+                                label: t!("balance-mining-status"),
                                 span {
 
                                     "{mining_status_str}"
                                 }
                             }
                             InfoItem {
-                                label: "Proving Capability".to_string(),
+                                label: t!("balance-proving-capability"),
                                 code {
 
                                     "{proving_capability_str}"
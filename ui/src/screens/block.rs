@@ -1,11 +1,14 @@
 // src/screens/block.rs
+use api::prefs::block_explorer::ExplorerItem;
 use dioxus::prelude::*;
 use neptune_types::block_info::BlockInfo;
 use neptune_types::block_selector::BlockSelector;
 use twenty_first::tip5::Digest;
 
+use crate::components::external_link::ExternalLink;
 use crate::components::pico::Card;
 use crate::components::pico::CopyButton;
+use crate::components::skeleton::SkeletonCard;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
 
 /// A small helper component to display a Digest with a label and copy button.
@@ -49,7 +52,11 @@ fn DigestDisplay(
                     }
                 }
                 CopyButton {
-                    text_to_copy: digest_str,
+                    text_to_copy: digest_str.clone(),
+                }
+                ExternalLink {
+                    item: ExplorerItem::Block,
+                    id: digest_str,
                 }
             }
         }
@@ -324,21 +331,7 @@ pub fn BlockScreen(selector: BlockSelector) -> Element {
         rsx! {
             match &*block_resource.read() {
                 None => rsx! {
-                    Card {
-
-                        h3 {
-
-                            "View Block"
-                        }
-                        p {
-
-                            "Loading block details..."
-                        }
-                        progress {
-
-
-                        }
-                    }
+                    SkeletonCard { title: "View Block".to_string(), lines: 6 }
                 },
                 // check if neptune-core rpc connection lost
                 Some(result) if !rpc.check_result_ref(&result) => rsx! {
@@ -1,6 +1,9 @@
 //=============================================================================
 // File: src/screens/blockchain.rs
 //=============================================================================
+use api::chain_reorg::ChainForks;
+use api::chain_reorg::ReorgEvent;
+use api::prefs::refresh_intervals::RefreshScreen;
 use dioxus::prelude::*;
 use neptune_types::block_selector::BlockSelector;
 use neptune_types::block_selector::BlockSelectorLiteral;
@@ -8,33 +11,43 @@ use twenty_first::prelude::Digest;
 
 use crate::components::action_link::ActionLink;
 use crate::components::pico::Card;
+use crate::components::skeleton::SkeletonCard;
+use crate::app_state_mut::AppStateMut;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
-use crate::Screen;
+use crate::polling::PollWeight;
+use crate::Route;
 
 #[component]
 pub fn BlockChainScreen() -> Element {
     let mut rpc = use_rpc_checker(); // Initialize Hook
-    let mut active_screen = use_context::<Signal<Screen>>();
 
     let mut height_resource = use_resource(move || async move { api::block_height().await });
+    let mut forks_resource = use_resource(move || async move { api::chain_forks().await });
 
     // Effect: Restarts the resource when connection is restored.
     let status_sig = rpc.status();
     use_effect(move || {
         if status_sig.read().is_connected() {
             height_resource.restart();
+            forks_resource.restart();
         }
     });
 
     // for refreshing from neptune-core every N secs
+    let app_state_mut = use_context::<AppStateMut>();
     use_coroutine(move |_rx: UnboundedReceiver<()>| {
         let rpc_status = rpc.status(); // Use signal handle
         let mut data_resource = height_resource;
+        let mut forks_resource = forks_resource;
 
         async move {
             loop {
-                // Wait 60 seconds
-                crate::compat::sleep(std::time::Duration::from_secs(60)).await;
+                crate::polling::wait_for_screen_refresh(
+                    app_state_mut,
+                    RefreshScreen::BlockChain,
+                    PollWeight::Light,
+                )
+                .await;
 
                 // Only restart the resource if we are currently connected.
                 // When connection is lost, rpc_status.read() will be Disconnected,
@@ -42,6 +55,7 @@ pub fn BlockChainScreen() -> Element {
                 // (in the resource closure) to trigger the restart when it comes back.
                 if (*rpc_status.read()).is_connected() {
                     data_resource.restart();
+                    forks_resource.restart();
                 }
             }
         }
@@ -54,21 +68,7 @@ pub fn BlockChainScreen() -> Element {
         match &*height_resource.read() {
             None => {
                 rsx! {
-                    Card {
-
-                        h3 {
-
-                            "Blockchain"
-                        }
-                        p {
-
-                            "Loading..."
-                        }
-                        progress {
-
-
-                        }
-                    }
+                    SkeletonCard { title: "Blockchain".to_string() }
                 }
             }
             // check if neptune-core rpc connection lost
@@ -83,7 +83,18 @@ pub fn BlockChainScreen() -> Element {
 
             Some(Ok(height)) => {
                 let owned_height = *height;
+                let forks = match &*forks_resource.read() {
+                    Some(Ok(forks)) => Some(forks.clone()),
+                    _ => None,
+                };
+                let now_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+                let wallet_reorg = forks
+                    .as_ref()
+                    .and_then(|f| f.recent_wallet_reorg(now_ms).cloned());
                 rsx! {
+                    if let Some(reorg) = wallet_reorg {
+                        ReorgBanner { reorg }
+                    }
                     Card {
 
                         h3 {
@@ -95,8 +106,7 @@ pub fn BlockChainScreen() -> Element {
                             "Current Block Height"
                         }
                         ActionLink {
-                             state: active_screen,
-                             to: Screen::Block(BlockSelector::Height(owned_height)),
+                             to: Route::Block { selector: BlockSelector::Height(owned_height) },
                              "{height}"
                         }
                     }
@@ -128,7 +138,7 @@ pub fn BlockChainScreen() -> Element {
                                     None
                                 };
                                 if let Some(s) = selector {
-                                    active_screen.set(Screen::Block(s));
+                                    navigator().push(Route::Block { selector: s });
                                 }
                             },
                             // Use Pico's group role for a compact input/button layout
@@ -149,18 +159,19 @@ pub fn BlockChainScreen() -> Element {
                             style: "margin-top: 1rem;",
                             "Quick Lookup: "
                             ActionLink {
-                                state: active_screen,
-                                to: Screen::Block(BlockSelector::Special(BlockSelectorLiteral::Genesis)),
+                                to: Route::Block { selector: BlockSelector::Special(BlockSelectorLiteral::Genesis) },
                                 "Genesis Block"
                             }
                             " | "
                             ActionLink {
-                                state: active_screen,
-                                to: Screen::Block(BlockSelector::Special(BlockSelectorLiteral::Tip)),
+                                to: Route::Block { selector: BlockSelector::Special(BlockSelectorLiteral::Tip) },
                                 "Tip Block"
                             }
                         }
                     }
+                    if let Some(forks) = forks {
+                        ForksCard { forks }
+                    }
                 }
             }
             Some(Err(e)) => {
@@ -185,3 +196,113 @@ pub fn BlockChainScreen() -> Element {
         }
     }
 }
+
+/// Warns that a recent reorg replaced blocks holding the wallet's history.
+#[component]
+fn ReorgBanner(reorg: ReorgEvent) -> Element {
+    rsx! {
+        div {
+            role: "alert",
+            style: "margin-bottom: 1rem; padding: 0.5rem 1rem; border: 1px solid var(--pico-del-color); border-radius: var(--pico-border-radius); color: var(--pico-del-color);",
+            strong { "Chain reorganised. " }
+            "{reorg.depth()} blocks above height {reorg.fork_height} were replaced, including blocks with your wallet's history. "
+            "Recent balances and confirmations may have changed; check the History screen."
+        }
+    }
+}
+
+fn short_digest(digest: &Digest) -> String {
+    let hex = digest.to_hex();
+    format!("{}…", &hex[..12])
+}
+
+fn format_time(at_ms: u64) -> String {
+    chrono::DateTime::from_timestamp_millis(at_ms as i64)
+        .map(|d| d.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Competing blocks near the tip, and the reorgs seen since the server
+/// started.
+#[component]
+fn ForksCard(forks: ChainForks) -> Element {
+    rsx! {
+        Card {
+            h4 { "Forks & Reorgs" }
+            if forks.forks.is_empty() {
+                p { "No competing blocks near the tip." }
+            } else {
+                table {
+                    thead {
+                        tr {
+                            th { scope: "col", "Height" }
+                            th { scope: "col", "On chain" }
+                            th { scope: "col", "Other branches" }
+                        }
+                    }
+                    tbody {
+                        for fork in forks.forks {
+                            tr {
+                                key: "{fork.height}",
+                                td { "{fork.height}" }
+                                td {
+                                    ActionLink {
+                                        to: Route::Block { selector: BlockSelector::Digest(fork.canonical) },
+                                        {short_digest(&fork.canonical)}
+                                    }
+                                }
+                                td {
+                                    for other in fork.others {
+                                        div {
+                                            key: "{other.to_hex()}",
+                                            ActionLink {
+                                                to: Route::Block { selector: BlockSelector::Digest(other) },
+                                                {short_digest(&other)}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if forks.reorgs.is_empty() {
+                p { "No reorgs seen since the server started." }
+            } else {
+                table {
+                    thead {
+                        tr {
+                            th { scope: "col", "Seen" }
+                            th { scope: "col", "Fork height" }
+                            th { scope: "col", "Depth" }
+                            th { scope: "col", "Old tip" }
+                            th { scope: "col", "New tip" }
+                            th { scope: "col", "Wallet" }
+                        }
+                    }
+                    tbody {
+                        for reorg in forks.reorgs {
+                            tr {
+                                key: "{reorg.at_ms}-{reorg.new_tip.to_hex()}",
+                                td { {format_time(reorg.at_ms)} }
+                                td { "{reorg.fork_height}" }
+                                td { "{reorg.depth()}" }
+                                td { "{short_digest(&reorg.old_tip)} ({reorg.old_height})" }
+                                td {
+                                    ActionLink {
+                                        to: Route::Block { selector: BlockSelector::Digest(reorg.new_tip) },
+                                        "{short_digest(&reorg.new_tip)} ({reorg.new_height})"
+                                    }
+                                }
+                                td {
+                                    if reorg.affects_wallet { "Affected" } else { "—" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//=============================================================================
+// File: src/screens/broadcast.rs
+//=============================================================================
+use api::signed_tx::signed_tx_bytes;
+use dioxus::prelude::*;
+
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::i18n::t;
+
+/// Broadcasts a transaction signed elsewhere, pasted or read from a file.
+#[component]
+pub fn BroadcastScreen() -> Element {
+    let mut input = use_signal(String::new);
+    let mut is_broadcasting = use_signal(|| false);
+    let mut result = use_signal(|| None::<Result<String, String>>);
+
+    let parsed = signed_tx_bytes(&input());
+    let can_broadcast = parsed.is_ok() && !is_broadcasting();
+
+    let open_file = move |_| {
+        spawn(async move {
+            match crate::compat::read_file(&["txt", "hex"]).await {
+                Ok(Some((_, content))) => {
+                    result.set(None);
+                    input.set(content);
+                }
+                Ok(None) => {}
+                Err(e) => result.set(Some(Err(e))),
+            }
+        });
+    };
+
+    let broadcast = move |_: MouseEvent| {
+        let Ok(bytes) = signed_tx_bytes(&input()) else {
+            return;
+        };
+        is_broadcasting.set(true);
+        result.set(None);
+        spawn(async move {
+            result.set(Some(
+                api::broadcast_transaction(bytes)
+                    .await
+                    .map(|txid| txid.to_string())
+                    .map_err(|e| e.to_string()),
+            ));
+            is_broadcasting.set(false);
+        });
+    };
+
+    rsx! {
+        Card {
+            h3 { {t!("broadcast-title")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("broadcast-intro")}
+            }
+            textarea {
+                rows: 8,
+                style: "font-family: monospace; font-size: 0.8rem;",
+                placeholder: t!("broadcast-placeholder"),
+                value: "{input}",
+                oninput: move |e| {
+                    result.set(None);
+                    input.set(e.value());
+                },
+            }
+            match &parsed {
+                Ok(bytes) => rsx! {
+                    small { {t!("broadcast-size", bytes = bytes.len())} }
+                },
+                Err(e) if !input().trim().is_empty() => rsx! {
+                    small { style: "color: var(--pico-del-color);", "{e}" }
+                },
+                Err(_) => rsx! {},
+            }
+            div {
+                role: "group",
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    on_click: open_file,
+                    {t!("broadcast-open-file")}
+                }
+                Button {
+                    button_type: ButtonType::Primary,
+                    disabled: !can_broadcast,
+                    on_click: broadcast,
+                    if is_broadcasting() {
+                        {t!("broadcast-broadcasting")}
+                    } else {
+                        {t!("broadcast-submit")}
+                    }
+                }
+            }
+            match result() {
+                Some(Ok(txid)) => rsx! {
+                    p {
+                        style: "word-break: break-all;",
+                        {t!("broadcast-done", txid = txid)}
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    article {
+                        style: "border-left: 4px solid var(--pico-del-color);",
+                        strong { {t!("broadcast-rejected")} }
+                        p { style: "margin: 0; word-break: break-word;", "{e}" }
+                    }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
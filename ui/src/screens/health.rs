@@ -0,0 +1,126 @@
+//=============================================================================
+// File: src/screens/health.rs
+//=============================================================================
+use api::health::HealthCheck;
+use api::health::HealthCheckKind;
+use api::health::HealthReport;
+use api::health::HealthStatus;
+use dioxus::prelude::*;
+
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::i18n::t;
+
+fn format_time(at_ms: u64) -> String {
+    chrono::DateTime::from_timestamp_millis(at_ms as i64)
+        .map(|d| d.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+fn check_label(kind: HealthCheckKind) -> String {
+    match kind {
+        HealthCheckKind::RpcConnection => t!("health-rpc-connection"),
+        HealthCheckKind::AuthToken => t!("health-auth-token"),
+        HealthCheckKind::DataDirectory => t!("health-data-directory"),
+        HealthCheckKind::PriceProviders => t!("health-price-providers"),
+    }
+}
+
+fn status_label(status: HealthStatus) -> (String, &'static str) {
+    match status {
+        HealthStatus::Ok => (t!("health-ok"), "var(--pico-ins-color)"),
+        HealthStatus::Warning => (t!("health-warning"), "var(--pico-color-amber-500)"),
+        HealthStatus::Failed => (t!("health-failed"), "var(--pico-del-color)"),
+        HealthStatus::Skipped => (t!("health-skipped"), "var(--pico-muted-color)"),
+    }
+}
+
+/// The outcome of each check, one per row.
+#[component]
+pub fn HealthCheckList(checks: Vec<HealthCheck>) -> Element {
+    rsx! {
+        div {
+            class: "overflow-auto",
+            table {
+                tbody {
+                    for check in checks {
+                        {
+                            let (status, color) = status_label(check.status);
+                            rsx! {
+                                tr {
+                                    key: "{check.kind:?}",
+                                    th { scope: "row", {check_label(check.kind)} }
+                                    td {
+                                        style: "white-space: nowrap;",
+                                        strong { style: "color: {color};", "{status}" }
+                                    }
+                                    td {
+                                        style: "font-size: 0.9rem; word-break: break-word;",
+                                        "{check.detail}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks everything the wallet depends on, for troubleshooting.
+#[component]
+pub fn HealthScreen() -> Element {
+    let mut report = use_resource(move || async move { api::health().await });
+    let checking = report.read().is_none();
+
+    rsx! {
+        Card {
+            h3 { {t!("health-title")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("health-intro")}
+            }
+            match &*report.read() {
+                None => rsx! {
+                    p { aria_busy: "true", {t!("health-checking")} }
+                },
+                Some(Err(e)) => rsx! {
+                    p { style: "color: var(--pico-del-color);", "{e}" }
+                },
+                Some(Ok(r)) => rsx! {
+                    HealthSummary { report: r.clone() }
+                    HealthCheckList { checks: r.checks.clone() }
+                },
+            }
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: true,
+                disabled: checking,
+                on_click: move |_| report.restart(),
+                {t!("health-check-again")}
+            }
+        }
+    }
+}
+
+#[component]
+fn HealthSummary(report: HealthReport) -> Element {
+    let (text, color) = if report.is_healthy() {
+        (t!("health-summary-ok"), "var(--pico-ins-color)")
+    } else {
+        (t!("health-summary-failed"), "var(--pico-del-color)")
+    };
+
+    rsx! {
+        p {
+            strong { style: "color: {color};", "{text}" }
+            small {
+                style: "color: var(--pico-muted-color);",
+                " "
+                {t!("health-checked-at", time = format_time(report.checked_at_ms))}
+            }
+        }
+    }
+}
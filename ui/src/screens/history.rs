@@ -1,33 +1,69 @@
 //=============================================================================
 // File: src/screens/history.rs
 //=============================================================================
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 
+use api::fiat_amount::FiatAmount;
+use api::history_page::HistoryRow;
+use api::history_page::HistorySort;
+use api::history_page::HistorySortColumn;
+use api::prefs::block_explorer::ExplorerItem;
+use api::prefs::display_preference::DisplayPreference;
+use api::price_map::PriceMap;
+use api::tx_annotation::TxAnnotation;
+use api::tx_annotation::TxAnnotations;
+use api::tx_note::note_for_block;
+use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
 use itertools::Itertools;
 use neptune_types::block_height::BlockHeight;
 use neptune_types::native_currency_amount::NativeCurrencyAmount;
 use neptune_types::timestamp::Timestamp;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
 use num_traits::Zero;
 use twenty_first::tip5::Digest;
 
+use crate::app_state_mut::AppStateMut;
+use crate::components::action_link::ActionLink;
 use crate::components::amount::Amount;
+use crate::components::amount::TABLE_MAX_DECIMALS;
 use crate::components::block::Block;
 use crate::components::empty_state::EmptyState;
+use crate::components::external_link::ExternalLink;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
 use crate::components::pico::Card;
+use crate::components::pico::NoTitleModal;
+use crate::components::selection_bar::SelectionBar;
+use crate::components::skeleton::SkeletonTable;
+use crate::components::tx_note::TxNoteEditor;
+use crate::components::tx_note::TxNoteIcon;
+use crate::currency::npt_to_fiat;
+use crate::data_store::DataStore;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
+use crate::hooks::use_selection::use_selection;
+use crate::i18n::t;
+use crate::number_format;
+use crate::Route;
 
 // Embed the SVG content as a static string at compile time.
 const HISTORY_EMPTY_SVG: &str = include_str!("../../assets/svg/history-empty.svg");
 
-// Enums to manage sorting state
-#[derive(Clone, Copy, PartialEq)]
-enum SortableColumn {
-    Date,
-    Type,
-    Amount,
-    Block,
-}
+/// Rows fetched per request.
+const PAGE_SIZE: usize = 100;
+
+/// The height of a row, in pixels.  Only the rows in view are rendered, with
+/// spacers of this height per row standing in for the rest.
+const ROW_HEIGHT: f64 = 57.0;
+
+/// Rows that fit in the scroll area, at its tallest.
+const VISIBLE_ROWS: usize = 30;
+
+/// Rows rendered beyond each edge of the view, so that scrolling shows no
+/// gaps.
+const OVERSCAN: usize = 10;
 
 #[derive(Clone, Copy, PartialEq)]
 enum SortDirection {
@@ -35,12 +71,37 @@ enum SortDirection {
     Descending,
 }
 
+/// Untranslated, for CSV export and sorting.
+fn tx_type(amount: NativeCurrencyAmount) -> &'static str {
+    if amount > NativeCurrencyAmount::zero() {
+        "Received"
+    } else {
+        "Sent"
+    }
+}
+
+/// Formats history rows as CSV, with a header line.
+fn to_csv(rows: &[HistoryRow]) -> String {
+    let mut csv = String::from("date,type,amount,block_height,block_digest\n");
+    for (digest, height, timestamp, amount) in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            timestamp.standard_format(),
+            tx_type(*amount),
+            amount,
+            height,
+            digest.to_hex(),
+        ));
+    }
+    csv
+}
+
 // A reusable component for sortable table headers
 #[component]
 fn SortableHeader(
-    title: &'static str,
-    column: SortableColumn,
-    sort_column: Signal<SortableColumn>,
+    title: String,
+    column: HistorySortColumn,
+    sort_column: Signal<HistorySortColumn>,
     sort_direction: Signal<SortDirection>,
     style: Option<&'static str>,
 ) -> Element {
@@ -56,56 +117,107 @@ fn SortableHeader(
         ("\u{00A0}", false)
     };
 
+    let aria_sort = match (is_active, *sort_direction.read()) {
+        (false, _) => "none",
+        (true, SortDirection::Ascending) => "ascending",
+        (true, SortDirection::Descending) => "descending",
+    };
+    let mut sort = move || {
+        if is_active {
+            sort_direction
+                .with_mut(|dir| {
+                    *dir = match dir {
+                        SortDirection::Ascending => SortDirection::Descending,
+                        SortDirection::Descending => SortDirection::Ascending,
+                    };
+                });
+        } else {
+            sort_column.set(column);
+            sort_direction.set(SortDirection::Ascending);
+        }
+    };
+
     rsx! {
         th {
             style: format!("{}{}", "position: sticky; top: 0; background: var(--pico-card-background-color); cursor: pointer; white-space: nowrap;", style.unwrap_or("")),
-            onclick: move |_| {
-                if is_active {
-                    sort_direction
-                        .with_mut(|dir| {
-                            *dir = match dir {
-                                SortDirection::Ascending => SortDirection::Descending,
-                                SortDirection::Descending => SortDirection::Ascending,
-                            };
-                        });
-                } else {
-                    sort_column.set(column);
-                    sort_direction.set(SortDirection::Ascending);
+            scope: "col",
+            tabindex: "0",
+            "aria-sort": aria_sort,
+            onclick: move |_| sort(),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Enter {
+                    sort();
                 }
             },
             "{title}"
             span {
                 style: "display: inline-block; width: 1.2em; text-align: right;",
+                "aria-hidden": "true",
                 "{arrow_char}"
             }
         }
     }
 }
 
+/// Values `amount` at the price on the day it was confirmed, if known.
+fn fiat_at_time(
+    amount: NativeCurrencyAmount,
+    timestamp: Timestamp,
+    prices: &HashMap<String, PriceMap>,
+    fiat: api::fiat_currency::FiatCurrency,
+) -> Option<FiatAmount> {
+    let rate = prices.get(&timestamp.format("%Y-%m-%d").to_string())?.get(fiat)?;
+    Some(npt_to_fiat(&amount, &rate))
+}
+
+/// The number of blocks confirming a transaction in the block at `height`,
+/// counting that block.
+fn confirmations(height: BlockHeight, tip: BlockHeight) -> u64 {
+    (u64::from(tip) + 1).saturating_sub(u64::from(height))
+}
+
 /// A self-contained component for rendering a single row in the history table.
 #[component]
+#[allow(clippy::too_many_arguments)]
 fn HistoryRow(
     digest: Digest,
     height: BlockHeight,
     timestamp: Timestamp,
     amount: NativeCurrencyAmount,
+    selected: bool,
+    on_toggle: EventHandler<()>,
+    show_fiat_at_time: bool,
+    fiat_at_time: Option<FiatAmount>,
+    confirmations: Option<u64>,
+    annotation: Option<TxAnnotation>,
+    note: Option<(TransactionKernelId, String)>,
+    on_edit_note: EventHandler<TransactionKernelId>,
 ) -> Element {
     let digest = Rc::new(digest);
     let height = Rc::new(height);
     let mut is_hovered = use_signal(|| false);
 
     let tx_type = if amount > NativeCurrencyAmount::zero() {
-        "Received"
+        t!("history-received")
     } else {
-        "Sent"
+        t!("history-sent")
     };
-    let date = timestamp.format("%Y-%m-%d");
+    let date = timestamp.format(crate::i18n::date_format());
+    let number_format = number_format::current();
 
     rsx! {
         tr {
+            style: "height: {ROW_HEIGHT}px;",
             onmouseenter: move |_| is_hovered.set(true),
             onmouseleave: move |_| is_hovered.set(false),
 
+            td {
+                input {
+                    r#type: "checkbox",
+                    checked: selected,
+                    onchange: move |_| on_toggle.call(()),
+                }
+            }
             td {
                 title: "{timestamp.standard_format()}",
                 "{date}"
@@ -114,20 +226,227 @@ fn HistoryRow(
 
 
                 "{tx_type}"
+                if let Some((txid, text)) = note {
+                    " "
+                    TxNoteIcon {
+                        note: Some(text),
+                        on_click: move |_| on_edit_note.call(txid),
+                    }
+                }
+                if let Some(annotation) = annotation {
+                    br {}
+                    small {
+                        style: "color: var(--pico-muted-color);",
+                        {
+                            [annotation.tag, annotation.contact]
+                                .into_iter()
+                                .flatten()
+                                .join(" · ")
+                        }
+                    }
+                }
             }
             td {
                 style: "min-width: 21ch; text-align: right; white-space: nowrap;",
 
                 Amount {
                     amount,
+                    max_decimals: TABLE_MAX_DECIMALS,
+                }
+            }
+            if show_fiat_at_time {
+                td {
+                    style: "text-align: right; white-space: nowrap;",
+                    match fiat_at_time {
+                        Some(value) => rsx! { "{value.to_string_localized(&number_format)}" },
+                        None => rsx! {
+                            span {
+                                style: "color: var(--pico-muted-color);",
+                                title: t!("history-price-unavailable"),
+                                "—"
+                            }
+                        },
+                    }
                 }
             }
             td {
+                div {
+                    style: "display: flex; align-items: center; gap: 0.5rem;",
+                    Block {
+                        block_digest: digest.clone(),
+                        height,
+                    }
+                    ExternalLink {
+                        item: ExplorerItem::Block,
+                        id: digest.to_hex(),
+                    }
+                }
+            }
+            td {
+                style: "text-align: right;",
+                if let Some(confirmations) = confirmations {
+                    "{confirmations}"
+                }
+            }
+        }
+    }
+}
+
+/// A row for a transaction still in the mempool, shown above the confirmed
+/// history.
+#[component]
+fn PendingRow(
+    txid: TransactionKernelId,
+    amount: NativeCurrencyAmount,
+    show_fiat_at_time: bool,
+    note: Option<String>,
+    on_edit_note: EventHandler<TransactionKernelId>,
+) -> Element {
 
+    let tx_type = if amount > NativeCurrencyAmount::zero() {
+        t!("history-received")
+    } else {
+        t!("history-sent")
+    };
+    let txid_str = txid.to_string();
+    let abbreviated_txid = format!(
+        "{}...{}",
+        &txid_str[0..6],
+        &txid_str[txid_str.len() - 4..]
+    );
 
-                Block {
-                    block_digest: digest.clone(),
-                    height,
+    rsx! {
+        tr {
+            td {}
+            td {
+                span {
+                    style: "padding: 0.1rem 0.4rem; border-radius: var(--pico-border-radius); font-size: 0.8rem; border: 1px solid var(--pico-color-amber-500); color: var(--pico-color-amber-500); white-space: nowrap;",
+                    title: t!("history-pending-tooltip"),
+                    {t!("history-pending")}
+                }
+            }
+            td {
+                "{tx_type} "
+                TxNoteIcon {
+                    note,
+                    on_click: move |_| on_edit_note.call(txid),
+                }
+            }
+            td {
+                style: "min-width: 21ch; text-align: right; white-space: nowrap;",
+                Amount {
+                    amount,
+                    max_decimals: TABLE_MAX_DECIMALS,
+                }
+            }
+            if show_fiat_at_time {
+                td {}
+            }
+            td {
+                ActionLink {
+                    to: Route::MempoolTx { tx_id: txid },
+                    "{abbreviated_txid}"
+                }
+            }
+            td {
+                style: "text-align: right;",
+                "0"
+            }
+        }
+    }
+}
+
+/// Sets the tag and contact of the selected history entries, for the
+/// spending reports.
+#[component]
+fn AnnotateHistoryModalContent(
+    block_digests: Vec<Digest>,
+    annotations: TxAnnotations,
+    show_modal: Signal<bool>,
+    on_done: EventHandler<()>,
+) -> Element {
+    // prefilled where all selected entries agree.
+    let common = |field: fn(&TxAnnotation) -> Option<String>| {
+        block_digests
+            .iter()
+            .map(|digest| annotations.get(&digest.to_hex()).and_then(field))
+            .all_equal_value()
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    };
+    let initial_tag = common(|a| a.tag.clone());
+    let initial_contact = common(|a| a.contact.clone());
+    let mut tag_input = use_signal(|| initial_tag);
+    let mut contact_input = use_signal(|| initial_contact);
+    let mut tag_edited = use_signal(|| false);
+    let mut contact_edited = use_signal(|| false);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut api_in_progress = use_signal(|| false);
+    let count = block_digests.len();
+
+    let handle_apply = move |_| {
+        let digests = block_digests.clone();
+        // fields left alone keep their values on each entry.
+        let tag = tag_edited().then(|| tag_input());
+        let contact = contact_edited().then(|| contact_input());
+        api_in_progress.set(true);
+        spawn(async move {
+            match api::annotate_history(digests, tag, contact).await {
+                Ok(_) => {
+                    show_modal.set(false);
+                    on_done.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            api_in_progress.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            header {
+                h3 { {t!("history-annotate-title", count = count)} }
+            }
+            label {
+                {t!("history-tag")}
+                input {
+                    r#type: "text",
+                    placeholder: t!("history-tag-placeholder"),
+                    value: "{tag_input}",
+                    oninput: move |e| {
+                        tag_input.set(e.value());
+                        tag_edited.set(true);
+                    },
+                }
+            }
+            label {
+                {t!("history-contact")}
+                input {
+                    r#type: "text",
+                    placeholder: t!("history-contact-placeholder"),
+                    value: "{contact_input}",
+                    oninput: move |e| {
+                        contact_input.set(e.value());
+                        contact_edited.set(true);
+                    },
+                }
+            }
+            small { {t!("history-annotate-hint")} }
+            if let Some(err) = error() {
+                p { style: "color: var(--pico-color-red-500);", "{err}" }
+            }
+            footer {
+                Button {
+                    button_type: ButtonType::Secondary,
+                    on_click: move |_| show_modal.set(false),
+                    style: "margin-right: 1rem;",
+                    {t!("common-cancel")}
+                }
+                Button {
+                    on_click: handle_apply,
+                    disabled: !(tag_edited() || contact_edited()) || api_in_progress(),
+                    {t!("common-save")}
                 }
             }
         }
@@ -139,66 +458,148 @@ fn HistoryRow(
 pub fn HistoryScreen() -> Element {
     let mut rpc = use_rpc_checker(); // Initialize Hook
 
-    let mut history = use_resource(move || async move { api::history().await });
+    // State for sorting
+    let sort_column = use_signal(|| HistorySortColumn::Date);
+    let sort_direction = use_signal(|| SortDirection::Descending);
+
+    // Only the pages in view are fetched, as they scroll into it.  Bumping
+    // `refresh` fetches them again.
+    let mut scroll_top = use_signal(|| 0.0);
+    let first_row =
+        use_memo(move || ((scroll_top() / ROW_HEIGHT) as usize).saturating_sub(OVERSCAN));
+    let mut pages = use_signal(HashMap::<usize, Vec<(usize, HistoryRow)>>::new);
+    let mut total = use_signal(|| 0usize);
+    let mut refresh = use_signal(|| 0u32);
+    // the sort and refresh the loaded pages are for.
+    let mut loaded_for = use_signal(|| None::<(HistorySort, u32)>);
+
+    let mut history = use_resource(move || async move {
+        let sort = HistorySort {
+            column: sort_column(),
+            descending: sort_direction() == SortDirection::Descending,
+        };
+        let key = (sort, refresh());
+        let first = first_row();
+        let last = first + VISIBLE_ROWS + 2 * OVERSCAN;
+
+        // pages loaded for another sort, or before a refresh, are replaced
+        // once the pages in view are fetched again.
+        let stale = *loaded_for.peek() != Some(key);
+        let mut fetched = vec![];
+        for index in first / PAGE_SIZE..=last / PAGE_SIZE {
+            if !stale && pages.peek().contains_key(&index) {
+                continue;
+            }
+            let page = api::history_page(index * PAGE_SIZE, PAGE_SIZE, sort).await?;
+            total.set(page.total);
+            if page.rows.is_empty() {
+                break;
+            }
+            fetched.push((index, page.rows));
+        }
+        pages.with_mut(|p| {
+            if stale {
+                p.clear();
+            }
+            p.extend(fetched);
+        });
+        loaded_for.set(Some(key));
+        Ok::<_, api::ApiError>(())
+    });
+    let mut annotations =
+        use_resource(move || async move { api::history_annotations().await.unwrap_or_default() });
+    let mut tx_notes =
+        use_resource(move || async move { api::tx_notes().await.unwrap_or_default() });
+    let mut editing_note = use_signal::<Option<TransactionKernelId>>(|| None);
+    let mut show_note_modal = use_signal(|| false);
+    let mut edit_note = move |txid: TransactionKernelId| {
+        editing_note.set(Some(txid));
+        show_note_modal.set(true);
+    };
+
+    // the tip and pending transactions come from the data store.
+    let store = use_context::<DataStore>();
 
-    // Effect: Restarts the resource when connection is restored.
+    // Effect: Fetches the pages in view again when connection is restored.
     let status_sig = rpc.status();
     use_effect(move || {
         if status_sig.read().is_connected() {
-            history.restart();
+            *refresh.write() += 1;
         }
     });
 
-    // for refreshing from neptune-core every N secs
-    use_coroutine(move |_rx: UnboundedReceiver<()>| {
-        let rpc_status = rpc.status(); // Use signal handle
-        let mut data_resource = history;
-
-        async move {
-            loop {
-                // Wait 60 seconds
-                crate::compat::sleep(std::time::Duration::from_secs(60)).await;
-
-                // Only restart the resource if we are currently connected.
-                // When connection is lost, rpc_status.read() will be Disconnected,
-                // and we rely on the resource's *dependency* on rpc.status().read()
-                // (in the resource closure) to trigger the restart when it comes back.
-                if (*rpc_status.read()).is_connected() {
-                    data_resource.restart();
-                }
+    // Effect: the history only changes with the tip, so the pages in view are
+    // fetched again when the data store sees a new one.
+    let mut seen_tip = use_signal(|| None::<Digest>);
+    use_effect(move || {
+        let tip = match &*store.dashboard.read() {
+            Some(Ok(data)) => Some(data.tip_digest),
+            _ => None,
+        };
+        if tip.is_some() && tip != *seen_tip.peek() {
+            if seen_tip.peek().is_some() {
+                *refresh.write() += 1;
             }
+            seen_tip.set(tip);
         }
     });
 
-    // State for sorting
-    let sort_column = use_signal(|| SortableColumn::Date);
-    let sort_direction = use_signal(|| SortDirection::Descending);
+    // Selection state, by block digest and position in the history, as a
+    // block may have several rows.
+    let mut selection = use_selection::<(Digest, usize)>();
+    let mut export_status = use_signal::<Option<String>>(|| None);
+    let mut show_annotate_modal = use_signal(|| false);
+
+    // Optional column: the fiat value on the day each transaction confirmed.
+    let display_preference = use_context::<AppStateMut>().display_preference;
+    let fiat = match *display_preference.read() {
+        DisplayPreference::FiatEnabled { fiat, .. } => Some(fiat),
+        DisplayPreference::NptOnly => None,
+    };
+    let mut show_fiat_at_time = use_signal(|| false);
+    let historical_prices = use_resource(move || async move {
+        if !show_fiat_at_time() {
+            return HashMap::new();
+        }
+        // re-runs as pages load, picking up prices not fetched before.
+        let dates: Vec<String> = pages
+            .read()
+            .values()
+            .flatten()
+            .map(|(_, _, timestamp, _)| timestamp.format("%Y-%m-%d").to_string())
+            .unique()
+            .collect();
+        if dates.is_empty() {
+            return HashMap::new();
+        }
+        api::historical_prices(dates).await.unwrap_or_default()
+    });
+
+    // note: the balance effect fields are reversed; see MempoolRow.
+    let pending_rows: Vec<(TransactionKernelId, NativeCurrencyAmount)> =
+        match &*store.pending.read() {
+            Some(Ok(txs)) => txs
+                .iter()
+                .map(|tx| (tx.id, tx.negative_balance_effect + -tx.positive_balance_effect))
+                .collect(),
+            _ => vec![],
+        };
+    let tip = match &*store.dashboard.read() {
+        Some(Ok(data)) => Some(data.tip_header.height),
+        _ => None,
+    };
 
     rsx! {
         match &*history.read() {
             None => rsx! {
-                Card {
-
-                    h3 {
-
-                        "History"
-                    }
-                    p {
-
-                        "Loading..."
-                    }
-                    progress {
-
-
-                    }
-                }
+                SkeletonTable { title: t!("history-title"), columns: 6 }
             },
             // check if neptune-core rpc connection lost
             Some(result) if !rpc.check_result_ref(&result) => rsx! {
                 // modal ConnectionLost is displayed by rpc.check_result_ref
                 Card {
                     h3 {
-                        "History"
+                        {t!("history-title")}
                     }
                 }
             },
@@ -207,28 +608,28 @@ pub fn HistoryScreen() -> Element {
 
                     h3 {
 
-                        "Error"
+                        {t!("common-error")}
                     }
                     p {
 
-                        "Failed to load history: {e}"
+                        {t!("history-load-failed", error = e.to_string())}
                     }
                     button {
                         onclick: move |_| history.restart(),
-                        "Retry"
+                        {t!("common-retry")}
                     }
                 }
             },
-            Some(Ok(utxos)) if utxos.is_empty() => rsx! {
+            Some(Ok(())) if total() == 0 && pending_rows.is_empty() => rsx! {
                 Card {
 
                     h3 {
-                        "History"
+                        {t!("history-title")}
                     }
 
                     EmptyState {
-                        title: "No Transactions".to_string(),
-                        description: Some("You have no transactions confirmed in a block yet.  You can also check the mempool for unconfirmed transctions.".to_string()),
+                        title: t!("history-empty-title"),
+                        description: Some(t!("history-empty-description")),
                         icon: rsx! {
                             // Inject the SVG string directly into the DOM
                             span {
@@ -239,104 +640,246 @@ pub fn HistoryScreen() -> Element {
                     }
                 }
             },
-            Some(Ok(utxos)) => {
-                let iter = utxos
+            Some(Ok(())) => {
+                let total_rows = total();
+                let first = first_row().min(total_rows);
+                let end = (first + VISIBLE_ROWS + 2 * OVERSCAN).min(total_rows);
+                let pages_read = pages.read();
+                let row_at = |i: usize| {
+                    pages_read
+                        .get(&(i / PAGE_SIZE))
+                        .and_then(|page| page.get(i % PAGE_SIZE))
+                        .copied()
+                };
+                let rows_in_view: Vec<(usize, Option<(usize, HistoryRow)>)> =
+                    (first..end).map(|i| (i, row_at(i))).collect();
+                let top_spacer = first as f64 * ROW_HEIGHT;
+                let bottom_spacer = (total_rows - end) as f64 * ROW_HEIGHT;
+
+                // selection and export cover the rows loaded so far, in the
+                // order displayed.
+                let loaded_rows: Vec<(usize, HistoryRow)> = pages_read
+                    .keys()
+                    .sorted()
+                    .flat_map(|index| pages_read[index].iter().copied())
+                    .collect();
+                drop(pages_read);
+
+                let all_keys: HashSet<(Digest, usize)> = loaded_rows
+                    .iter()
+                    .map(|(position, (digest, ..))| (*digest, *position))
+                    .collect();
+                let all_selected = selection.is_all_selected(&all_keys);
+
+                let selected_rows: Vec<HistoryRow> = loaded_rows
                     .iter()
-                    .rev()
-                    .chunk_by(|(digest, height, timestamp, _)| (digest, height, timestamp));
-                let mut block_summaries: Vec<_> = iter
-                    .into_iter()
-                    .map(|(key, group)| {
-                        let (digest, height, timestamp) = key;
-                        let amount_sum: NativeCurrencyAmount = group
-                            .map(|(.., amount)| *amount)
-                            .sum();
-                        (*digest, *height, *timestamp, amount_sum)
+                    .filter(|(position, (digest, ..))| {
+                        selection.is_selected(&(*digest, *position))
                     })
+                    .map(|(_, row)| *row)
                     .collect();
-                block_summaries
-                    .sort_by(|a, b| {
-                        let ordering = match sort_column() {
-                            SortableColumn::Date => a.2.cmp(&b.2),
-                            SortableColumn::Type => {
-                                let type_a = if a.3 > NativeCurrencyAmount::zero() {
-                                    "Received"
-                                } else {
-                                    "Sent"
-                                };
-                                let type_b = if b.3 > NativeCurrencyAmount::zero() {
-                                    "Received"
-                                } else {
-                                    "Sent"
-                                };
-                                type_a.cmp(type_b)
-                            }
-                            SortableColumn::Amount => a.3.cmp(&b.3),
-                            SortableColumn::Block => a.1.cmp(&b.1),
-                        };
-                        match sort_direction() {
-                            SortDirection::Ascending => ordering,
-                            SortDirection::Descending => ordering.reverse(),
-                        }
-                    });
+                let copy_csv = to_csv(&selected_rows);
+                let export_csv = copy_csv.clone();
+
+                let show_fiat_column = fiat.is_some() && show_fiat_at_time();
+                let num_columns = if show_fiat_column { "7" } else { "6" };
+                let prices = historical_prices.read().clone().unwrap_or_default();
+                let annotation_map = annotations.read().clone().unwrap_or_default();
+                let note_map = tx_notes.read().clone().unwrap_or_default();
+                let selected_digests: Vec<Digest> =
+                    selected_rows.iter().map(|(digest, ..)| *digest).unique().collect();
+
                 rsx! {
                     Card {
 
-                        h3 {
+                        div {
+                            style: "display: flex; justify-content: space-between; align-items: baseline; flex-wrap: wrap;",
+                            h3 {
 
-                            "History"
+                                {t!("history-title")}
+                            }
+                            if fiat.is_some() {
+                                label {
+                                    style: "font-size: 0.9rem;",
+                                    input {
+                                        r#type: "checkbox",
+                                        role: "switch",
+                                        checked: show_fiat_at_time(),
+                                        onchange: move |_| show_fiat_at_time.toggle(),
+                                    }
+                                    {t!("history-fiat-at-time")}
+                                }
+                            }
+                        }
+                        if !selection.is_empty() {
+                            SelectionBar {
+                                count: selection.len(),
+                                on_clear: move |_| {
+                                    selection.clear();
+                                    export_status.set(None);
+                                },
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    on_click: move |_| {
+                                        let csv = copy_csv.clone();
+                                        spawn(async move {
+                                            let status = if crate::compat::clipboard_set(csv).await {
+                                                t!("common-copied")
+                                            } else {
+                                                t!("common-copy-failed")
+                                            };
+                                            export_status.set(Some(status));
+                                        });
+                                    },
+                                    {t!("history-copy-csv")}
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    on_click: move |_| {
+                                        let csv = export_csv.clone();
+                                        spawn(async move {
+                                            let status = match crate::compat::save_file("history.csv", "CSV File", "csv", csv).await {
+                                                Ok(true) => Some(t!("common-exported")),
+                                                Ok(false) => None,
+                                                Err(e) => Some(t!("common-export-failed", error = e.to_string())),
+                                            };
+                                            export_status.set(status);
+                                        });
+                                    },
+                                    {t!("history-export-selected")}
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    on_click: move |_| show_annotate_modal.set(true),
+                                    {t!("history-annotate")}
+                                }
+                            }
+                            if let Some(status) = export_status() {
+                                p { style: "font-size: 0.9rem;", "{status}" }
+                            }
                         }
                         div {
                             style: "max-height: 70vh; overflow-y: auto;",
+                            onscroll: move |e| scroll_top.set(e.scroll_top()),
                             table {
 
                                 thead {
 
                                     tr {
 
+                                        th {
+                                            scope: "col",
+                                            style: "position: sticky; top: 0; background: var(--pico-card-background-color);",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: all_selected,
+                                                onchange: move |_| selection.toggle_all(all_keys.clone()),
+                                            }
+                                        }
                                         SortableHeader {
-                                            title: "Date",
-                                            column: SortableColumn::Date,
+                                            title: t!("history-date"),
+                                            column: HistorySortColumn::Date,
                                             sort_column,
                                             sort_direction,
                                         }
                                         SortableHeader {
-                                            title: "Type",
-                                            column: SortableColumn::Type,
+                                            title: t!("history-type"),
+                                            column: HistorySortColumn::Type,
                                             sort_column,
                                             sort_direction,
                                         }
                                         SortableHeader {
-                                            title: "Amount",
-                                            column: SortableColumn::Amount,
+                                            title: t!("history-amount"),
+                                            column: HistorySortColumn::Amount,
                                             sort_column,
                                             sort_direction,
                                             style: "text-align: right",
                                         }
+                                        if show_fiat_column {
+                                            th {
+                                                scope: "col",
+                                                style: "position: sticky; top: 0; background: var(--pico-card-background-color); text-align: right; white-space: nowrap;",
+                                                title: t!("history-value-at-time-tooltip"),
+                                                {t!("history-value-at-time")}
+                                            }
+                                        }
                                         SortableHeader {
-                                            title: "Block",
-                                            column: SortableColumn::Block,
+                                            title: t!("history-block"),
+                                            column: HistorySortColumn::Block,
                                             sort_column,
                                             sort_direction,
                                         }
+                                        th {
+                                            scope: "col",
+                                            style: "position: sticky; top: 0; background: var(--pico-card-background-color); text-align: right; white-space: nowrap;",
+                                            {t!("history-confirmations")}
+                                        }
                                     }
                                 }
                                 tbody {
 
+                                    for (txid, amount) in pending_rows.iter().copied() {
+                                        PendingRow {
+                                            key: "{txid}",
+                                            txid,
+                                            amount,
+                                            show_fiat_at_time: show_fiat_column,
+                                            note: note_map.get(&txid.to_string()).map(|n| n.text.clone()),
+                                            on_edit_note: move |txid| edit_note(txid),
+                                        }
+                                    }
+                                    if top_spacer > 0.0 {
+                                        tr { style: "height: {top_spacer}px;" }
+                                    }
                                     {
-                                        block_summaries
+                                        rows_in_view
                                             .into_iter()
-                                            .map(|(digest, height, timestamp, amount)| {
-                                                rsx! {
-                                                    HistoryRow {
-                                                        digest,
-                                                        height,
-                                                        timestamp,
-                                                        amount,
+                                            .map(|(index, row)| match row {
+                                                Some((position, (digest, height, timestamp, amount))) => {
+                                                    let value_at_time = fiat
+                                                        .filter(|_| show_fiat_column)
+                                                        .and_then(|fiat| fiat_at_time(amount, timestamp, &prices, fiat));
+                                                    let annotation = annotation_map.get(&digest.to_hex()).cloned();
+                                                    let note = note_for_block(&note_map, &digest.to_hex()).and_then(|(txid, note)| {
+                                                        Some((txid.parse::<TransactionKernelId>().ok()?, note.text.clone()))
+                                                    });
+                                                    rsx! {
+                                                        HistoryRow {
+                                                            key: "{index}",
+                                                            digest,
+                                                            height,
+                                                            timestamp,
+                                                            amount,
+                                                            selected: selection.is_selected(&(digest, position)),
+                                                            on_toggle: move |_| selection.toggle((digest, position)),
+                                                            show_fiat_at_time: show_fiat_column,
+                                                            fiat_at_time: value_at_time,
+                                                            confirmations: tip.map(|tip| confirmations(height, tip)),
+                                                            annotation,
+                                                            note,
+                                                            on_edit_note: move |txid| edit_note(txid),
+                                                        }
                                                     }
                                                 }
+                                                // not fetched yet.
+                                                None => rsx! {
+                                                    tr {
+                                                        key: "{index}",
+                                                        style: "height: {ROW_HEIGHT}px;",
+                                                        td { colspan: num_columns, "aria-busy": "true" }
+                                                    }
+                                                },
                                             })
                                     }
+                                    if bottom_spacer > 0.0 {
+                                        tr { style: "height: {bottom_spacer}px;" }
+                                    }
                                 }
                             }
                         }
@@ -345,7 +888,32 @@ pub fn HistoryScreen() -> Element {
 
                             em {
 
-                                "Note: Unconfirmed transactions will appear once confirmed by the network."
+                                {t!("history-unconfirmed-note")}
+                            }
+                        }
+                    }
+                    NoTitleModal {
+                        is_open: show_note_modal,
+                        if let Some(txid) = editing_note() {
+                            h4 { {t!("tx-note-title")} }
+                            TxNoteEditor {
+                                key: "{txid}",
+                                txid,
+                                on_saved: move |_| {
+                                    tx_notes.restart();
+                                    show_note_modal.set(false);
+                                },
+                            }
+                        }
+                    }
+                    NoTitleModal {
+                        is_open: show_annotate_modal,
+                        if show_annotate_modal() {
+                            AnnotateHistoryModalContent {
+                                block_digests: selected_digests,
+                                annotations: annotation_map.clone(),
+                                show_modal: show_annotate_modal,
+                                on_done: move |_| annotations.restart(),
                             }
                         }
                     }
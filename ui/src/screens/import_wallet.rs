@@ -0,0 +1,265 @@
+//=============================================================================
+// File: src/screens/import_wallet.rs
+//=============================================================================
+use api::wallet_import::WalletImport;
+use bip39::Language;
+use bip39::Mnemonic;
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::components::pico::NoTitleModal;
+use crate::components::qr_scanner::QrScanner;
+use crate::i18n::t;
+
+/// Seed phrase lengths accepted, in words.  Neptune wallets have 18.
+const WORD_COUNTS: [usize; 2] = [18, 24];
+
+/// Words suggested for the word being typed.
+const MAX_SUGGESTIONS: usize = 6;
+
+/// What is wrong with the phrase entered so far, if anything.
+#[derive(Clone, Debug, PartialEq)]
+enum PhraseCheck {
+    Empty,
+    UnknownWords(Vec<String>),
+    WrongCount(usize),
+    BadChecksum,
+    Valid,
+}
+
+fn word_list() -> &'static [&'static str] {
+    Language::English.word_list()
+}
+
+fn is_known(word: &str) -> bool {
+    word_list().binary_search(&word).is_ok()
+}
+
+/// The words of `phrase`, lowercased.
+fn words_of(phrase: &str) -> Vec<String> {
+    phrase.split_whitespace().map(str::to_lowercase).collect()
+}
+
+/// The word being typed: the last one, unless followed by a space.
+fn word_in_progress(phrase: &str) -> Option<String> {
+    if phrase.ends_with(char::is_whitespace) {
+        return None;
+    }
+    phrase.split_whitespace().last().map(str::to_lowercase)
+}
+
+fn check_phrase(phrase: &str) -> PhraseCheck {
+    let words = words_of(phrase);
+    if words.is_empty() {
+        return PhraseCheck::Empty;
+    }
+    // the word being typed is not unknown yet.
+    let in_progress = word_in_progress(phrase);
+    let unknown: Vec<String> = words
+        .iter()
+        .enumerate()
+        .filter(|(i, w)| !is_known(w) && !(*i == words.len() - 1 && in_progress.is_some()))
+        .map(|(_, w)| w.clone())
+        .collect();
+    if !unknown.is_empty() {
+        return PhraseCheck::UnknownWords(unknown);
+    }
+    if !WORD_COUNTS.contains(&words.len()) {
+        return PhraseCheck::WrongCount(words.len());
+    }
+    match Mnemonic::parse_in_normalized(Language::English, &words.join(" ")) {
+        Ok(_) => PhraseCheck::Valid,
+        Err(bip39::Error::UnknownWord(i)) => PhraseCheck::UnknownWords(vec![words[i].clone()]),
+        Err(_) => PhraseCheck::BadChecksum,
+    }
+}
+
+/// The phrase in a scanned QR code: either the words themselves, or a
+/// SeedQR, which lists the index of each word as 4 digits.
+fn phrase_from_qr(data: &str) -> String {
+    let data = data.trim();
+    let is_seed_qr = data.chars().all(|c| c.is_ascii_digit())
+        && data.len() % 4 == 0
+        && WORD_COUNTS.contains(&(data.len() / 4));
+    if !is_seed_qr {
+        return data.to_string();
+    }
+    data.as_bytes()
+        .chunks(4)
+        .filter_map(|digits| std::str::from_utf8(digits).ok()?.parse::<usize>().ok())
+        .filter_map(|i| word_list().get(i).copied())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Restores a wallet from its seed phrase, typed or scanned, for moving a
+/// wallet onto this machine.
+#[component]
+pub fn ImportWalletScreen() -> Element {
+    let network = use_context::<AppState>().network;
+    let mut phrase = use_signal(String::new);
+    let mut is_scanner_open = use_signal(|| false);
+    let mut is_importing = use_signal(|| false);
+    let mut outcome = use_signal(|| None::<Result<WalletImport, String>>);
+
+    let check = check_phrase(&phrase());
+    let suggestions: Vec<&'static str> = match word_in_progress(&phrase()) {
+        Some(prefix) if !is_known(&prefix) => word_list()
+            .iter()
+            .filter(|w| w.starts_with(prefix.as_str()))
+            .take(MAX_SUGGESTIONS)
+            .copied()
+            .collect(),
+        _ => vec![],
+    };
+
+    let mut complete_word = move |word: &str| {
+        let current = phrase();
+        let kept = current.trim_end_matches(|c: char| !c.is_whitespace());
+        phrase.set(format!("{kept}{word} "));
+    };
+
+    let import = move |_| {
+        let words = words_of(&phrase());
+        is_importing.set(true);
+        outcome.set(None);
+        spawn(async move {
+            let result = api::import_wallet(words, network)
+                .await
+                .map_err(|e| e.to_string());
+            // the phrase is not kept around once used.
+            if result.is_ok() {
+                phrase.set(String::new());
+            }
+            outcome.set(Some(result));
+            is_importing.set(false);
+        });
+    };
+
+    rsx! {
+        Card {
+            h3 { {t!("import-wallet-title")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("import-wallet-intro")}
+            }
+            label {
+                {t!("import-wallet-words")}
+                textarea {
+                    rows: "4",
+                    autocomplete: "off",
+                    autocapitalize: "none",
+                    spellcheck: "false",
+                    placeholder: t!("import-wallet-words-placeholder"),
+                    "aria-invalid": match &check {
+                        PhraseCheck::UnknownWords(_) | PhraseCheck::BadChecksum => "true",
+                        PhraseCheck::Valid => "false",
+                        _ => "",
+                    },
+                    value: "{phrase}",
+                    oninput: move |evt| {
+                        outcome.set(None);
+                        phrase.set(evt.value());
+                    },
+                }
+            }
+            if !suggestions.is_empty() {
+                div {
+                    style: "display: flex; flex-wrap: wrap; gap: 0.5rem; margin-bottom: 1rem;",
+                    for word in suggestions {
+                        Button {
+                            key: "{word}",
+                            button_type: ButtonType::Secondary,
+                            outline: true,
+                            style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                            on_click: move |_| complete_word(word),
+                            "{word}"
+                        }
+                    }
+                }
+            }
+            small {
+                style: match &check {
+                    PhraseCheck::Valid => "display: block; color: var(--pico-ins-color);",
+                    PhraseCheck::UnknownWords(_) | PhraseCheck::BadChecksum => "display: block; color: var(--pico-del-color);",
+                    _ => "display: block; color: var(--pico-muted-color);",
+                },
+                match &check {
+                    PhraseCheck::Empty => t!("import-wallet-word-count", count = 0),
+                    PhraseCheck::UnknownWords(words) => t!("import-wallet-unknown-words", words = words.join(", ")),
+                    PhraseCheck::WrongCount(count) => t!("import-wallet-word-count", count = *count),
+                    PhraseCheck::BadChecksum => t!("import-wallet-bad-checksum"),
+                    PhraseCheck::Valid => t!("import-wallet-valid"),
+                }
+            }
+            div {
+                role: "group",
+                style: "margin-top: 1rem;",
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    on_click: move |_| is_scanner_open.set(true),
+                    {t!("import-wallet-scan")}
+                }
+                Button {
+                    disabled: check != PhraseCheck::Valid || is_importing(),
+                    on_click: import,
+                    if is_importing() {
+                        {t!("import-wallet-importing")}
+                    } else {
+                        {t!("import-wallet-import")}
+                    }
+                }
+            }
+            match outcome() {
+                Some(Ok(WalletImport::Written { wallet_file, node_restarted })) => rsx! {
+                    article {
+                        role: "status",
+                        p { strong { {t!("import-wallet-written", file = wallet_file)} } }
+                        if node_restarted {
+                            p { {t!("import-wallet-node-restarted")} }
+                        } else {
+                            p { {t!("import-wallet-start-node")} }
+                        }
+                    }
+                },
+                Some(Ok(WalletImport::AlreadyInUse)) => rsx! {
+                    article {
+                        role: "status",
+                        p { {t!("import-wallet-already-in-use")} }
+                    }
+                },
+                Some(Ok(WalletImport::OtherWalletExists { wallet_dir })) => rsx! {
+                    article {
+                        role: "alert",
+                        p { strong { {t!("import-wallet-other-exists")} } }
+                        ol {
+                            li { {t!("import-wallet-step-backup")} }
+                            li { {t!("import-wallet-step-stop")} }
+                            li { {t!("import-wallet-step-move", dir = wallet_dir)} }
+                            li { {t!("import-wallet-step-retry")} }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { style: "color: var(--pico-del-color);", "{e}" }
+                },
+                None => rsx! {},
+            }
+        }
+
+        NoTitleModal {
+            is_open: is_scanner_open,
+            QrScanner {
+                on_scan: move |data: String| {
+                    outcome.set(None);
+                    phrase.set(phrase_from_qr(&data));
+                },
+                on_close: move |_| is_scanner_open.set(false),
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//=============================================================================
+// File: src/screens/logs.rs
+//=============================================================================
+use std::str::FromStr;
+
+use api::log_entry::LogEntry;
+use api::prefs::log_level::LogLevel;
+use api::prefs::refresh_intervals::RefreshScreen;
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+use crate::app_state_mut::AppStateMut;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::i18n::t;
+use crate::polling::PollWeight;
+
+/// The most entries shown.  Older entries are dropped.
+const MAX_SHOWN: usize = 1_000;
+
+fn level_name(level: LogLevel) -> String {
+    match level {
+        LogLevel::Error => t!("logs-level-error"),
+        LogLevel::Warn => t!("logs-level-warn"),
+        LogLevel::Info => t!("logs-level-info"),
+        LogLevel::Debug => t!("logs-level-debug"),
+        LogLevel::Trace => t!("logs-level-trace"),
+    }
+}
+
+#[component]
+fn LogRow(entry: LogEntry) -> Element {
+    let at = chrono::DateTime::from_timestamp_millis(entry.at_ms as i64)
+        .map(|d| d.with_timezone(&chrono::Local).format("%H:%M:%S%.3f").to_string())
+        .unwrap_or_default();
+    let color = match entry.level {
+        LogLevel::Error | LogLevel::Warn => "var(--pico-del-color)",
+        LogLevel::Info => "inherit",
+        LogLevel::Debug | LogLevel::Trace => "var(--pico-muted-color)",
+    };
+    let source = if entry.spans.is_empty() {
+        entry.target.clone()
+    } else {
+        format!("{} {}", entry.target, entry.spans)
+    };
+
+    rsx! {
+        tr {
+            style: "color: {color};",
+            td { style: "white-space: nowrap;", "{at}" }
+            td { {level_name(entry.level)} }
+            td { style: "word-break: break-all;", "{source}" }
+            td { style: "word-break: break-word;", "{entry.message}" }
+        }
+    }
+}
+
+/// Tails the server's recent log entries.
+#[component]
+pub fn LogsScreen() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut level = use_signal(LogLevel::default);
+    let mut entries = use_signal(Vec::<LogEntry>::new);
+    // the seq of the last entry fetched, which outlives clearing the view.
+    let mut last_seq = use_signal(|| None::<u64>);
+    let mut paused = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    use_future(move || async move {
+        if let Ok(prefs) = api::get_user_prefs().await {
+            level.set(prefs.log_level());
+        }
+    });
+
+    use_coroutine(move |_rx: UnboundedReceiver<()>| async move {
+        let mut fetched_level = None;
+        loop {
+            if !*paused.peek() {
+                let current = *level.peek();
+                if fetched_level != Some(current) {
+                    // entries at other levels are fetched again from the start.
+                    fetched_level = Some(current);
+                    entries.set(vec![]);
+                    last_seq.set(None);
+                }
+                match api::recent_logs(current, *last_seq.peek()).await {
+                    Ok(new) => {
+                        error.set(None);
+                        if let Some(last) = new.last() {
+                            last_seq.set(Some(last.seq));
+                        }
+                        entries.with_mut(|e| {
+                            e.extend(new);
+                            let excess = e.len().saturating_sub(MAX_SHOWN);
+                            e.drain(..excess);
+                        });
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            }
+            crate::polling::wait_for_screen_refresh(
+                app_state_mut,
+                RefreshScreen::Logs,
+                PollWeight::Light,
+            )
+                .await;
+        }
+    });
+
+    let change_level = move |e: FormEvent| {
+        let Ok(new_level) = LogLevel::from_str(&e.value()) else {
+            return;
+        };
+        level.set(new_level);
+        spawn(async move {
+            if let Err(e) = api::set_log_level(new_level).await {
+                error.set(Some(e.to_string()));
+            }
+        });
+    };
+
+    rsx! {
+        Card {
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: 0.5rem;",
+                h3 { style: "margin-bottom: 0;", {t!("logs-title")} }
+                div {
+                    style: "display: flex; align-items: center; gap: 0.5rem;",
+                    select {
+                        style: "margin-bottom: 0; width: auto;",
+                        "aria-label": t!("logs-level"),
+                        onchange: change_level,
+                        for choice in LogLevel::iter() {
+                            option {
+                                key: "{choice}",
+                                value: "{choice}",
+                                selected: choice == level(),
+                                {level_name(choice)}
+                            }
+                        }
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: !paused(),
+                        style: "padding: 0.2rem 0.75rem; font-size: 0.8rem;",
+                        on_click: move |_| paused.toggle(),
+                        if paused() { {t!("logs-resume")} } else { {t!("logs-pause")} }
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        style: "padding: 0.2rem 0.75rem; font-size: 0.8rem;",
+                        on_click: move |_| entries.set(vec![]),
+                        {t!("logs-clear")}
+                    }
+                }
+            }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("logs-intro")}
+            }
+            if let Some(e) = error() {
+                p { style: "color: var(--pico-del-color);", "{e}" }
+            }
+            if entries.read().is_empty() {
+                p {
+                    style: "color: var(--pico-muted-color);",
+                    {t!("logs-none")}
+                }
+            } else {
+                div {
+                    style: "max-height: 70vh; overflow-y: auto;",
+                    table {
+                        class: "striped",
+                        style: "font-family: monospace; font-size: 0.8rem;",
+                        thead {
+                            tr {
+                                th { scope: "col", {t!("logs-col-time")} }
+                                th { scope: "col", {t!("logs-col-level")} }
+                                th { scope: "col", {t!("logs-col-source")} }
+                                th { scope: "col", {t!("logs-col-message")} }
+                            }
+                        }
+                        tbody {
+                            // newest first, so that new entries need no scrolling.
+                            for entry in entries.read().iter().rev().cloned() {
+                                LogRow { key: "{entry.seq}", entry }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
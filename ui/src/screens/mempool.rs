@@ -4,33 +4,30 @@
 use std::ops::Deref;
 use std::rc::Rc;
 
+use api::mempool_page::MempoolSort;
+use api::mempool_page::MempoolSortColumn;
+use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
 use neptune_types::mempool_transaction_info::MempoolTransactionInfo;
-use neptune_types::native_currency_amount::NativeCurrencyAmount;
-use num_traits::CheckedSub;
 
 use crate::components::action_link::ActionLink;
 use crate::components::amount::Amount;
 use crate::components::amount::AmountType;
+use crate::components::amount::TABLE_MAX_DECIMALS;
 use crate::components::empty_state::EmptyState; // <--- Import Added
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
 use crate::components::pico::Card;
+use crate::components::skeleton::SkeletonTable;
+use crate::data_store::DataStore;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
-use crate::Screen;
+use crate::Route;
 
 // Embed the SVG content as a static string at compile time.
 const MEMPOOL_SVG: &str = include_str!("../../assets/svg/mempool-empty.svg");
 
-// Enums to manage sorting state
-#[derive(Clone, Copy, PartialEq)]
-enum SortableColumn {
-    Id,
-    ProofType,
-    Inputs,
-    Outputs,
-    BalanceEffect,
-    Fee,
-    Synced,
-}
+/// Transactions shown per page.
+const PAGE_SIZE: usize = 100;
 
 #[derive(Clone, Copy, PartialEq)]
 enum SortDirection {
@@ -38,14 +35,6 @@ enum SortDirection {
     Descending,
 }
 
-// A helper function to safely calculate balance effect as a signed integer for sorting.
-// We assume `NativeCurrencyAmount` is a tuple struct wrapping a u128, so we access with `.0`.
-fn calculate_balance_effect(tx: &MempoolTransactionInfo) -> NativeCurrencyAmount {
-    tx.positive_balance_effect
-        .checked_sub(&tx.negative_balance_effect)
-        .unwrap_or_default()
-}
-
 #[derive(Debug, Clone)]
 struct MempoolTransactionInfoReadOnly(Rc<MempoolTransactionInfo>);
 
@@ -64,13 +53,15 @@ impl Deref for MempoolTransactionInfoReadOnly {
     }
 }
 
-/// A reusable component for sortable table headers
+/// A reusable component for sortable table headers.  Sorting returns to the
+/// first page.
 #[component]
 fn SortableHeader(
     title: &'static str,
-    column: SortableColumn,
-    sort_column: Signal<SortableColumn>,
+    column: MempoolSortColumn,
+    sort_column: Signal<MempoolSortColumn>,
     sort_direction: Signal<SortDirection>,
+    page: Signal<usize>,
 ) -> Element {
     let (arrow_char, is_active) = if *sort_column.read() == column {
         (
@@ -84,36 +75,72 @@ fn SortableHeader(
         ("\u{00A0}", false)
     };
 
+    let aria_sort = match (is_active, *sort_direction.read()) {
+        (false, _) => "none",
+        (true, SortDirection::Ascending) => "ascending",
+        (true, SortDirection::Descending) => "descending",
+    };
+    let mut sort = move || {
+        page.set(0);
+        if is_active {
+            sort_direction
+                .with_mut(|dir| {
+                    *dir = match dir {
+                        SortDirection::Ascending => SortDirection::Descending,
+                        SortDirection::Descending => SortDirection::Ascending,
+                    };
+                });
+        } else {
+            sort_column.set(column);
+            sort_direction.set(SortDirection::Ascending);
+        }
+    };
+
     rsx! {
         th {
             style: "position: sticky; top: 0; background: var(--pico-card-background-color); cursor: pointer; white-space: nowrap; padding: 12px 4px;",
-            onclick: move |_| {
-                if is_active {
-                    sort_direction
-                        .with_mut(|dir| {
-                            *dir = match dir {
-                                SortDirection::Ascending => SortDirection::Descending,
-                                SortDirection::Descending => SortDirection::Ascending,
-                            };
-                        });
-                } else {
-                    sort_column.set(column);
-                    sort_direction.set(SortDirection::Ascending);
+            scope: "col",
+            tabindex: "0",
+            "aria-sort": aria_sort,
+            onclick: move |_| sort(),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Enter {
+                    sort();
                 }
             },
             "{title}"
             span {
                 style: "display: inline-block; width: 1.2em; text-align: right;",
+                "aria-hidden": "true",
                 "{arrow_char}"
             }
         }
     }
 }
 
+/// Checkbox limiting the table to transactions that affect this wallet.
+/// Changing it returns to the first page.
+#[component]
+fn OnlyMineToggle(only_mine: Signal<bool>, page: Signal<usize>) -> Element {
+    rsx! {
+        label {
+            style: "font-weight: normal; font-size: 1rem; margin-bottom: 0;",
+            input {
+                r#type: "checkbox",
+                checked: only_mine(),
+                onchange: move |e: FormEvent| {
+                    page.set(0);
+                    only_mine.set(e.checked());
+                },
+            }
+            "Only my transactions"
+        }
+    }
+}
+
 /// A self-contained component for rendering a single row in the mempool table.
 #[component]
 fn MempoolRow(tx: MempoolTransactionInfoReadOnly) -> Element {
-    let active_screen = use_context::<Signal<Screen>>();
     let mut is_hovered = use_signal(|| false);
 
     // note: as of neptune-core v0.3.0, the negative and positive balance
@@ -130,10 +157,12 @@ fn MempoolRow(tx: MempoolTransactionInfoReadOnly) -> Element {
     let balance_effect_display = rsx! {
         Amount {
             amount: delta,
-            fixed: Some(AmountType::Current)
+            fixed: Some(AmountType::Current),
+            max_decimals: TABLE_MAX_DECIMALS,
         }
     };
 
+    let is_mine = api::mempool_page::affects_wallet(&tx);
     let tx_id_str = tx.id.to_string();
     let abbreviated_tx_id = format!(
         "{}...{}",
@@ -149,10 +178,16 @@ fn MempoolRow(tx: MempoolTransactionInfoReadOnly) -> Element {
             td {
                 style: "padding: 8px 4px;",
                 ActionLink {
-                    state: active_screen,
-                    to: Screen::MempoolTx(tx.id),
+                    to: Route::MempoolTx { tx_id: tx.id },
                     "{abbreviated_tx_id}"
                 }
+                if is_mine {
+                    span {
+                        style: "margin-left: 0.5rem; padding: 0.1rem 0.4rem; border: 1px solid var(--pico-ins-color); border-radius: var(--pico-border-radius); font-size: 0.8rem;",
+                        title: "This transaction spends from or pays to this wallet",
+                        "Mine"
+                    }
+                }
             }
             td {
                 style: "padding: 8px 4px;",
@@ -174,7 +209,8 @@ fn MempoolRow(tx: MempoolTransactionInfoReadOnly) -> Element {
                 style: "padding: 8px 4px;",
                 Amount {
                     amount: tx.fee,
-                    fixed: Some(AmountType::Current)
+                    fixed: Some(AmountType::Current),
+                    max_decimals: TABLE_MAX_DECIMALS,
                 }
             }
             td {
@@ -193,8 +229,28 @@ fn MempoolRow(tx: MempoolTransactionInfoReadOnly) -> Element {
 pub fn MempoolScreen() -> Element {
     let mut rpc = use_rpc_checker(); // Initialize Hook
 
-    let mut mempool_overview =
-        use_resource(move || async move { api::mempool_overview(0, 1000).await });
+    // State for sorting and paging
+    let sort_column = use_signal(|| MempoolSortColumn::Fee);
+    let sort_direction = use_signal(|| SortDirection::Descending);
+    let mut page = use_signal(|| 0usize);
+    let only_mine = use_signal(|| false);
+
+    let sort = use_memo(move || MempoolSort {
+        column: sort_column(),
+        descending: sort_direction() == SortDirection::Descending,
+    });
+
+    let mut mempool_overview = use_resource(move || async move {
+        let result = api::mempool_overview(page() * PAGE_SIZE, PAGE_SIZE, sort(), only_mine())
+            .await;
+        // the mempool shrank past the page shown; go to its last page.
+        if let Ok(p) = &result {
+            if p.txs.is_empty() && p.total > 0 {
+                page.set((p.total - 1) / PAGE_SIZE);
+            }
+        }
+        result
+    });
 
     // Effect: Restarts the resource when connection is restored.
     let status_sig = rpc.status();
@@ -204,49 +260,29 @@ pub fn MempoolScreen() -> Element {
         }
     });
 
-    // for refreshing from neptune-core every N secs
-    use_coroutine(move |_rx: UnboundedReceiver<()>| {
-        let rpc_status = rpc.status(); // Use signal handle
-        let mut data_resource = mempool_overview;
-
-        async move {
-            loop {
-                // Wait 10 seconds
-                crate::compat::sleep(std::time::Duration::from_secs(10)).await;
-
-                // Only restart the resource if we are currently connected.
-                // When connection is lost, rpc_status.read() will be Disconnected,
-                // and we rely on the resource's *dependency* on rpc.status().read()
-                // (in the resource closure) to trigger the restart when it comes back.
-                if (*rpc_status.read()).is_connected() {
-                    data_resource.restart();
-                }
+    // Effect: the data store polls the tip and mempool summary; the page in
+    // view is fetched again when either changes.
+    let store = use_context::<DataStore>();
+    let mut seen_mempool = use_signal(|| None);
+    use_effect(move || {
+        let mempool = match &*store.dashboard.read() {
+            Some(Ok(data)) => {
+                Some((data.tip_digest, data.mempool_total_tx_count, data.mempool_size))
             }
+            _ => None,
+        };
+        if mempool.is_some() && mempool != *seen_mempool.peek() {
+            if seen_mempool.peek().is_some() {
+                mempool_overview.restart();
+            }
+            seen_mempool.set(mempool);
         }
     });
 
-    // State for sorting
-    let sort_column = use_signal(|| SortableColumn::Fee);
-    let sort_direction = use_signal(|| SortDirection::Descending);
-
     rsx! {
         match &*mempool_overview.read() {
             None => rsx! {
-                Card {
-
-                    h3 {
-
-                        "Mempool"
-                    }
-                    p {
-
-                        "Loading..."
-                    }
-                    progress {
-
-
-                    }
-                }
+                SkeletonTable { title: "Mempool".to_string(), columns: 7 }
             },
             // check if neptune-core rpc connection lost
             Some(result) if !rpc.check_result_ref(&result) => rsx! {
@@ -274,7 +310,26 @@ pub fn MempoolScreen() -> Element {
                     }
                 }
             },
-            Some(Ok(tx_list)) if tx_list.is_empty() => rsx! {
+            Some(Ok(mempool_page)) if mempool_page.total == 0 && only_mine() => rsx! {
+                Card {
+                    h3 {
+                        style: "display: flex; justify-content: space-between; align-items: baseline;",
+                        "Mempool"
+                        OnlyMineToggle { only_mine, page }
+                    }
+                    EmptyState {
+                        title: "No Transactions of Yours".to_string(),
+                        description: Some("None of the pending transactions spend from or pay to this wallet.".to_string()),
+                        icon: rsx! {
+                            span {
+                                dangerous_inner_html: MEMPOOL_SVG,
+                                style: "width: 100%; height: 100%; display: flex; align-items: center; justify-content: center;",
+                            }
+                        }
+                    }
+                }
+            },
+            Some(Ok(mempool_page)) if mempool_page.total == 0 => rsx! {
                 Card {
 
                     h3 {
@@ -293,30 +348,11 @@ pub fn MempoolScreen() -> Element {
                     }
                 }
             },
-            Some(Ok(tx_list)) => {
-                let mut sorted_txs = tx_list.clone();
-                sorted_txs
-                    .sort_by(|a, b| {
-                        let ordering = match sort_column() {
-                            SortableColumn::Id => a.id.cmp(&b.id),
-                            SortableColumn::ProofType => {
-                                a.proof_type.to_string().cmp(&b.proof_type.to_string())
-                            }
-                            SortableColumn::Inputs => a.num_inputs.cmp(&b.num_inputs),
-                            SortableColumn::Outputs => a.num_outputs.cmp(&b.num_outputs),
-                            SortableColumn::BalanceEffect => {
-                                let bal_a = calculate_balance_effect(a);
-                                let bal_b = calculate_balance_effect(b);
-                                bal_a.cmp(&bal_b)
-                            }
-                            SortableColumn::Fee => a.fee.cmp(&b.fee),
-                            SortableColumn::Synced => a.synced.cmp(&b.synced),
-                        };
-                        match sort_direction() {
-                            SortDirection::Ascending => ordering,
-                            SortDirection::Descending => ordering.reverse(),
-                        }
-                    });
+            Some(Ok(mempool_page)) => {
+                let total = mempool_page.total;
+                let first = (page() * PAGE_SIZE).min(total);
+                let last = (first + PAGE_SIZE).min(total);
+                let num_pages = total.div_ceil(PAGE_SIZE);
                 rsx! {
                     Card {
 
@@ -330,10 +366,15 @@ pub fn MempoolScreen() -> Element {
                                 // 2. Reset font styles to look like normal body text
                                 style: "font-weight: normal; font-size: 1rem; color: var(--pico-muted-color);",
 
-                                "{tx_list.len()} transactions"
+                                "{total} transactions"
                             }
                         }
 
+                        div {
+                            style: "margin-bottom: 1rem;",
+                            OnlyMineToggle { only_mine, page }
+                        }
+
                         div {
                             style: "max-height: 70vh; overflow-y: auto;",
                             table {
@@ -344,56 +385,66 @@ pub fn MempoolScreen() -> Element {
 
                                         SortableHeader {
                                             title: "Id",
-                                            column: SortableColumn::Id,
+                                            column: MempoolSortColumn::Id,
                                             sort_column,
                                             sort_direction,
+                                            page,
                                         }
                                         SortableHeader {
                                             title: "Proof",
-                                            column: SortableColumn::ProofType,
+                                            column: MempoolSortColumn::ProofType,
                                             sort_column,
                                             sort_direction,
+                                            page,
                                         }
                                         SortableHeader {
                                             title: "Inputs",
-                                            column: SortableColumn::Inputs,
+                                            column: MempoolSortColumn::Inputs,
                                             sort_column,
                                             sort_direction,
+                                            page,
                                         }
                                         SortableHeader {
                                             title: "Outputs",
-                                            column: SortableColumn::Outputs,
+                                            column: MempoolSortColumn::Outputs,
                                             sort_column,
                                             sort_direction,
+                                            page,
                                         }
                                         SortableHeader {
                                             title: "Δ Balance",
-                                            column: SortableColumn::BalanceEffect,
+                                            column: MempoolSortColumn::BalanceEffect,
                                             sort_column,
                                             sort_direction,
+                                            page,
                                         }
                                         SortableHeader {
                                             title: "Fee",
-                                            column: SortableColumn::Fee,
+                                            column: MempoolSortColumn::Fee,
                                             sort_column,
                                             sort_direction,
+                                            page,
                                         }
                                         SortableHeader {
                                             title: "Synced",
-                                            column: SortableColumn::Synced,
+                                            column: MempoolSortColumn::Synced,
                                             sort_column,
                                             sort_direction,
+                                            page,
                                         }
                                     }
                                 }
                                 tbody {
 
                                     {
-                                        sorted_txs
-                                            .into_iter()
+                                        mempool_page
+                                            .txs
+                                            .iter()
+                                            .cloned()
                                             .map(|tx| {
                                                 rsx! {
                                                     MempoolRow {
+                                                        key: "{tx.id}",
                                                         tx: MempoolTransactionInfoReadOnly(Rc::new(tx)),
                                                     }
                                                 }
@@ -402,6 +453,30 @@ pub fn MempoolScreen() -> Element {
                                 }
                             }
                         }
+
+                        if num_pages > 1 {
+                            div {
+                                style: "display: flex; justify-content: space-between; align-items: center; margin-top: 1rem;",
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    disabled: page() == 0,
+                                    on_click: move |_| page -= 1,
+                                    "Previous"
+                                }
+                                small {
+                                    style: "color: var(--pico-muted-color);",
+                                    "{first + 1}–{last} of {total} (page {page() + 1} of {num_pages})"
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    disabled: page() + 1 >= num_pages,
+                                    on_click: move |_| page += 1,
+                                    "Next"
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -1,4 +1,5 @@
 // ui/src/screens/mempool_tx.rs
+use api::prefs::block_explorer::ExplorerItem;
 use dioxus::prelude::*;
 use neptune_types::announcement::Announcement;
 use neptune_types::mutator_set::addition_record::AdditionRecord;
@@ -12,8 +13,11 @@ use num_traits::Zero;
 use twenty_first::tip5::Digest;
 use twenty_first::util_types::mmr::mmr_membership_proof::MmrMembershipProof;
 
+use crate::components::external_link::ExternalLink;
 use crate::components::pico::Card;
 use crate::components::pico::CopyButton;
+use crate::components::skeleton::SkeletonCard;
+use crate::components::tx_note::TxNoteEditor;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
 
 // --- Helper & Sub-Components ---
@@ -250,12 +254,7 @@ pub fn MempoolTxScreen(tx_id: TransactionKernelId) -> Element {
     rsx! {
         match &*mempool_tx.read() {
             None => rsx! {
-                div {
-                    style: "text-align: center; padding: 2rem;",
-                    h4 {
-                        "Loading transaction details..."
-                    }
-                }
+                SkeletonCard { title: "Mempool Transaction Details".to_string(), lines: 6 }
             },
             // check if neptune-core rpc connection lost
             Some(result) if !rpc.check_result_ref(&result) => rsx! {
@@ -327,8 +326,13 @@ pub fn MempoolTxScreen(tx_id: TransactionKernelId) -> Element {
                                 CopyButton {
                                     text_to_copy: tx_id.to_string(),
                                 }
+                                ExternalLink {
+                                    item: ExplorerItem::Transaction,
+                                    id: tx_id.to_string(),
+                                }
                             }
                         }
+                        TxNoteEditor { txid: tx_id }
                         hr {
                         }
 
@@ -4,10 +4,19 @@ pub mod addresses;
 pub mod balance;
 pub mod block;
 pub mod blockchain;
+pub mod broadcast;
+pub mod health;
 pub mod history;
+pub mod import_wallet;
+pub mod logs;
 pub mod mempool;
 pub mod mempool_tx;
+pub mod node_logs;
+pub mod node_stats;
+pub mod onboarding;
 pub mod peers;
 pub mod receive;
+pub mod reports;
 pub mod send;
+pub mod settings;
 pub mod utxos;
@@ -0,0 +1,185 @@
+//=============================================================================
+// File: src/screens/node_logs.rs
+//=============================================================================
+use api::managed_node::ManagedNodeState;
+use api::managed_node::ManagedNodeStatus;
+use api::managed_node::NodeLogLine;
+use api::prefs::refresh_intervals::RefreshScreen;
+use dioxus::prelude::*;
+
+use crate::app_state_mut::AppStateMut;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::i18n::t;
+use crate::polling::PollWeight;
+
+/// The most lines shown.  Older lines are dropped.
+const MAX_SHOWN: usize = 2_000;
+
+fn format_time(at_ms: u64) -> String {
+    chrono::DateTime::from_timestamp_millis(at_ms as i64)
+        .map(|d| d.with_timezone(&chrono::Local).format("%H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+/// One line summing up the managed neptune-core's state.
+#[component]
+pub fn ManagedNodeStatusLine(status: ManagedNodeStatus) -> Element {
+    let (text, color) = match status.state {
+        ManagedNodeState::Disabled => (t!("node-logs-state-disabled"), "var(--pico-muted-color)"),
+        ManagedNodeState::Running { pid, since_ms } if status.responding => (
+            t!("node-logs-state-running", pid = pid, since = format_time(since_ms)),
+            "var(--pico-ins-color)",
+        ),
+        ManagedNodeState::Running { pid, .. } => (
+            t!("node-logs-state-starting", pid = pid),
+            "var(--pico-color-amber-500)",
+        ),
+        ManagedNodeState::Restarting { retry_at_ms } => (
+            t!("node-logs-state-restarting", at = format_time(retry_at_ms)),
+            "var(--pico-del-color)",
+        ),
+        ManagedNodeState::Stopping => (t!("node-logs-state-stopping"), "var(--pico-muted-color)"),
+    };
+
+    rsx! {
+        p {
+            style: "margin-bottom: 0.5rem;",
+            strong { style: "color: {color};", "{text}" }
+            if status.restarts > 0 {
+                small {
+                    style: "color: var(--pico-muted-color);",
+                    " "
+                    {t!("node-logs-restarts", count = status.restarts)}
+                }
+            }
+        }
+        if let Some(exit) = status.last_exit {
+            p {
+                style: "font-size: 0.9rem; color: var(--pico-muted-color); word-break: break-word;",
+                {t!("node-logs-last-exit", reason = exit)}
+            }
+        }
+    }
+}
+
+/// Tails the output of the neptune-core that the wallet runs.
+#[component]
+pub fn NodeLogsScreen() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut lines = use_signal(Vec::<NodeLogLine>::new);
+    let mut status = use_signal(|| None::<ManagedNodeStatus>);
+    // the seq of the last line fetched, which outlives clearing the view.
+    let mut last_seq = use_signal(|| None::<u64>);
+    let mut paused = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    use_coroutine(move |_rx: UnboundedReceiver<()>| async move {
+        loop {
+            if let Ok(s) = api::managed_node_status().await {
+                status.set(Some(s));
+            }
+            if !*paused.peek() {
+                match api::node_logs(*last_seq.peek()).await {
+                    Ok(new) => {
+                        error.set(None);
+                        if let Some(last) = new.last() {
+                            last_seq.set(Some(last.seq));
+                        }
+                        lines.with_mut(|l| {
+                            l.extend(new);
+                            let excess = l.len().saturating_sub(MAX_SHOWN);
+                            l.drain(..excess);
+                        });
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            }
+            crate::polling::wait_for_screen_refresh(
+                app_state_mut,
+                RefreshScreen::NodeLogs,
+                PollWeight::Light,
+            )
+            .await;
+        }
+    });
+
+    let restart = move |_| {
+        spawn(async move {
+            if let Err(e) = api::restart_managed_node().await {
+                error.set(Some(e.to_string()));
+            }
+        });
+    };
+
+    let running = matches!(
+        status.read().as_ref().map(|s| &s.state),
+        Some(ManagedNodeState::Running { .. } | ManagedNodeState::Restarting { .. })
+    );
+
+    rsx! {
+        Card {
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: 0.5rem;",
+                h3 { style: "margin-bottom: 0;", {t!("node-logs-title")} }
+                div {
+                    style: "display: flex; align-items: center; gap: 0.5rem;",
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        disabled: !running,
+                        style: "padding: 0.2rem 0.75rem; font-size: 0.8rem;",
+                        on_click: restart,
+                        {t!("node-logs-restart")}
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: !paused(),
+                        style: "padding: 0.2rem 0.75rem; font-size: 0.8rem;",
+                        on_click: move |_| paused.toggle(),
+                        if paused() { {t!("logs-resume")} } else { {t!("logs-pause")} }
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        style: "padding: 0.2rem 0.75rem; font-size: 0.8rem;",
+                        on_click: move |_| lines.set(vec![]),
+                        {t!("logs-clear")}
+                    }
+                }
+            }
+            if let Some(s) = status() {
+                ManagedNodeStatusLine { status: s }
+            }
+            if let Some(e) = error() {
+                p { style: "color: var(--pico-del-color);", "{e}" }
+            }
+            if lines.read().is_empty() {
+                p {
+                    style: "color: var(--pico-muted-color);",
+                    {t!("node-logs-none")}
+                }
+            } else {
+                div {
+                    style: "max-height: 70vh; overflow-y: auto;",
+                    table {
+                        class: "striped",
+                        style: "font-family: monospace; font-size: 0.8rem;",
+                        tbody {
+                            // newest first, so that new lines need no scrolling.
+                            for line in lines.read().iter().rev().cloned() {
+                                tr {
+                                    key: "{line.seq}",
+                                    style: if line.stderr { "color: var(--pico-del-color);" } else { "" },
+                                    td { style: "white-space: nowrap;", {format_time(line.at_ms)} }
+                                    td { style: "word-break: break-word; white-space: pre-wrap;", "{line.text}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
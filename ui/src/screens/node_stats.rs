@@ -0,0 +1,163 @@
+//=============================================================================
+// File: src/screens/node_stats.rs
+//=============================================================================
+use api::node_stats::NodeMetric;
+use api::node_stats::StatsWindow;
+use api::prefs::refresh_intervals::RefreshScreen;
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+use crate::app_state_mut::AppStateMut;
+use crate::components::chart::LineChart;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::i18n::t;
+use crate::polling::PollWeight;
+
+fn metric_title(metric: NodeMetric) -> String {
+    match metric {
+        NodeMetric::BlockInterval => t!("node-stats-block-interval"),
+        NodeMetric::MempoolSize => t!("node-stats-mempool-size"),
+        NodeMetric::PeerCount => t!("node-stats-peer-count"),
+        NodeMetric::BlockHeight => t!("node-stats-sync-progress"),
+    }
+}
+
+fn metric_axis_label(metric: NodeMetric) -> String {
+    match metric {
+        NodeMetric::BlockInterval => t!("node-stats-axis-minutes"),
+        NodeMetric::MempoolSize => t!("node-stats-axis-kb"),
+        NodeMetric::PeerCount => t!("node-stats-axis-peers"),
+        NodeMetric::BlockHeight => t!("node-stats-axis-height"),
+    }
+}
+
+/// Converts a sample to the unit of its axis label.
+fn display_value(metric: NodeMetric, value: f64) -> f64 {
+    match metric {
+        NodeMetric::BlockInterval => value / 60.0,
+        NodeMetric::MempoolSize => value / 1000.0,
+        NodeMetric::PeerCount | NodeMetric::BlockHeight => value,
+    }
+}
+
+fn window_name(window: StatsWindow) -> String {
+    match window {
+        StatsWindow::Hour => t!("node-stats-window-hour"),
+        StatsWindow::Day => t!("node-stats-window-day"),
+        StatsWindow::Week => t!("node-stats-window-week"),
+    }
+}
+
+/// Formats a unix time in seconds as local time, with the date for long
+/// windows.
+fn format_time(secs: f64, window: StatsWindow) -> String {
+    let format = match window {
+        StatsWindow::Week => "%m-%d",
+        StatsWindow::Hour | StatsWindow::Day => "%H:%M",
+    };
+    chrono::DateTime::from_timestamp(secs as i64, 0)
+        .map(|t| t.with_timezone(&chrono::Local).format(format).to_string())
+        .unwrap_or_default()
+}
+
+#[component]
+fn MetricChart(metric: NodeMetric, window: Signal<StatsWindow>, refresh: Signal<u32>) -> Element {
+    let series = use_resource(move || async move {
+        // re-fetch on every refresh tick.
+        refresh();
+        api::node_stats_series(metric, window()).await
+    });
+    let window = window();
+
+    rsx! {
+        section {
+            h5 { {metric_title(metric)} }
+            match &*series.read() {
+                None => rsx! {
+                    progress {}
+                },
+                Some(Err(e)) => rsx! {
+                    p { style: "color: var(--pico-del-color);", "{e}" }
+                },
+                Some(Ok(samples)) if samples.len() < 2 => rsx! {
+                    p {
+                        style: "color: var(--pico-muted-color);",
+                        {t!("node-stats-no-data")}
+                    }
+                },
+                Some(Ok(samples)) => rsx! {
+                    LineChart {
+                        points: samples
+                            .iter()
+                            .map(|s| (s.time as f64, display_value(metric, s.value)))
+                            .collect::<Vec<_>>(),
+                        x_label: t!("node-stats-axis-time"),
+                        y_label: metric_axis_label(metric),
+                        format_x: move |secs: f64| format_time(secs, window),
+                        format_y: |v: f64| {
+                            if v.abs() >= 10.0 { format!("{:.0}", v) } else { format!("{:.1}", v) }
+                        },
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Charts node health over time, from samples the server collects in the
+/// background.
+#[component]
+pub fn NodeStatsScreen() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut window = use_signal(StatsWindow::default);
+    let mut refresh = use_signal(|| 0u32);
+
+    use_coroutine(move |_rx: UnboundedReceiver<()>| async move {
+        loop {
+            crate::polling::wait_for_screen_refresh(
+                app_state_mut,
+                RefreshScreen::NodeStats,
+                PollWeight::Light,
+            )
+            .await;
+            *refresh.write() += 1;
+        }
+    });
+
+    rsx! {
+        Card {
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: 0.5rem;",
+                h3 { style: "margin-bottom: 0;", {t!("node-stats-title")} }
+                div {
+                    role: "group",
+                    style: "width: auto; margin-bottom: 0;",
+                    for choice in StatsWindow::iter() {
+                        Button {
+                            key: "{choice:?}",
+                            button_type: ButtonType::Secondary,
+                            outline: window() != choice,
+                            style: "padding: 0.2rem 0.75rem; font-size: 0.8rem;",
+                            on_click: move |_| window.set(choice),
+                            {window_name(choice)}
+                        }
+                    }
+                }
+            }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("node-stats-intro")}
+            }
+            for metric in NodeMetric::iter() {
+                MetricChart {
+                    key: "{metric:?}",
+                    metric,
+                    window,
+                    refresh,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//=============================================================================
+// File: src/screens/onboarding.rs
+//=============================================================================
+//! The first-run onboarding.  Guides the user through starting neptune-core
+//! and connecting to it, and optionally choosing a fiat currency.
+//!
+//! Shown instead of the app, so it cannot use the router or the app state.
+
+use std::str::FromStr;
+
+use api::fiat_currency::FiatCurrency;
+use api::health::HealthCheckKind;
+use api::health::HealthReport;
+use api::onboarding::OnboardingReason;
+use api::onboarding::RpcConnectionTest;
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::components::pico::Container;
+use crate::i18n::t;
+use crate::screens::health::HealthCheckList;
+use crate::screens::settings::DataDirectorySettings;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, strum::EnumIter)]
+enum Step {
+    Network,
+    Node,
+    Connection,
+    Currency,
+}
+
+impl Step {
+    fn number(self) -> usize {
+        Step::iter().position(|s| s == self).unwrap_or_default() + 1
+    }
+
+    fn next(self) -> Option<Step> {
+        Step::iter().skip_while(|s| *s != self).nth(1)
+    }
+
+    fn previous(self) -> Option<Step> {
+        Step::iter().take_while(|s| *s != self).last()
+    }
+}
+
+/// The `--network` values offered to start neptune-core with.
+const NETWORKS: [&str; 3] = ["main", "testnet", "regtest"];
+
+const RELEASES_URL: &str = "https://github.com/Neptune-Crypto/neptune-core/releases";
+
+/// Walks the user through setting up, then calls `on_finish`.  Finishing or
+/// skipping is recorded, so that this is shown only once.
+#[component]
+pub fn OnboardingScreen(reason: OnboardingReason, on_finish: EventHandler<()>) -> Element {
+    // a new wallet is already connected to a running node.
+    let mut step = use_signal(|| match &reason {
+        OnboardingReason::NodeUnreachable(_) => Step::Network,
+        OnboardingReason::NewWallet => Step::Connection,
+    });
+    let network = use_signal(|| NETWORKS[0].to_string());
+    let connection = use_resource(move || async move { api::health().await });
+    let mut finishing = use_signal(|| false);
+
+    let mut finish = move || {
+        finishing.set(true);
+        spawn(async move {
+            if let Err(e) = api::complete_onboarding().await {
+                dioxus_logger::tracing::warn!("could not record that onboarding is complete: {e}");
+            }
+            on_finish.call(());
+        });
+    };
+
+    let connected = matches!(&*connection.read(), Some(Ok(report)) if report.is_connected());
+    let current = step();
+    let steps = Step::iter().count();
+
+    rsx! {
+        div {
+            class: "app-main-container",
+            Container {
+                header {
+                    nav {
+                        ul {
+                            li {
+                                h1 {
+                                    style: "margin: 0; font-size: 1.5rem;",
+                                    "Neptune Wallet"
+                                }
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "content",
+                    style: "overflow-y: auto;",
+                    Card {
+                        h3 { {t!("onboarding-title")} }
+                        small {
+                            style: "color: var(--pico-muted-color);",
+                            {t!("onboarding-step", step = current.number(), steps = steps)}
+                        }
+                        Intro { reason: reason.clone() }
+                        match current {
+                            Step::Network => rsx! {
+                                NetworkStep { network }
+                            },
+                            Step::Node => rsx! {
+                                NodeStep { network: network() }
+                            },
+                            Step::Connection => rsx! {
+                                ConnectionStep { network: network(), connection }
+                            },
+                            Step::Currency => rsx! {
+                                CurrencyStep {
+                                    disabled: finishing(),
+                                    on_done: move |_| finish(),
+                                }
+                            },
+                        }
+                        footer {
+                            style: "display: flex; gap: 1rem; flex-wrap: wrap; justify-content: space-between;",
+                            Button {
+                                button_type: ButtonType::Secondary,
+                                outline: true,
+                                disabled: finishing(),
+                                on_click: move |_| finish(),
+                                {t!("onboarding-skip")}
+                            }
+                            div {
+                                style: "display: flex; gap: 1rem;",
+                                if let Some(previous) = current.previous() {
+                                    Button {
+                                        button_type: ButtonType::Secondary,
+                                        disabled: finishing(),
+                                        on_click: move |_| step.set(previous),
+                                        {t!("common-back")}
+                                    }
+                                }
+                                if let Some(next) = current.next() {
+                                    Button {
+                                        disabled: current == Step::Connection && !connected,
+                                        on_click: move |_| step.set(next),
+                                        {t!("onboarding-next")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Says why onboarding is shown.
+#[component]
+fn Intro(reason: OnboardingReason) -> Element {
+    match reason {
+        OnboardingReason::NodeUnreachable(error) => rsx! {
+            p { {t!("onboarding-unreachable-intro")} }
+            details {
+                summary {
+                    style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                    {t!("onboarding-details")}
+                }
+                p {
+                    style: "word-break: break-all; color: var(--pico-del-color); font-size: 0.9rem;",
+                    "{error}"
+                }
+            }
+        },
+        OnboardingReason::NewWallet => rsx! {
+            p { {t!("onboarding-new-wallet-intro")} }
+        },
+    }
+}
+
+#[component]
+fn NetworkStep(network: Signal<String>) -> Element {
+    let mut network = network;
+
+    rsx! {
+        section {
+            h4 { {t!("onboarding-network")} }
+            p { {t!("onboarding-network-intro")} }
+            fieldset {
+                for name in NETWORKS {
+                    label {
+                        key: "{name}",
+                        input {
+                            r#type: "radio",
+                            name: "network",
+                            checked: network() == name,
+                            onchange: move |_| network.set(name.to_string()),
+                        }
+                        strong { "{name}" }
+                        " — "
+                        {network_description(name)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn network_description(name: &str) -> String {
+    match name {
+        "main" => t!("onboarding-network-main"),
+        "testnet" => t!("onboarding-network-testnet"),
+        _ => t!("onboarding-network-regtest"),
+    }
+}
+
+#[component]
+fn NodeStep(network: String) -> Element {
+    rsx! {
+        section {
+            h4 { {t!("onboarding-node")} }
+            ol {
+                li {
+                    {t!("onboarding-node-install")}
+                    " "
+                    a { href: RELEASES_URL, target: "_blank", "{RELEASES_URL}" }
+                }
+                li {
+                    {t!("onboarding-node-start")}
+                    pre {
+                        code { "neptune-core --network {network}" }
+                    }
+                }
+                li { {t!("onboarding-node-port")} }
+                li { {t!("onboarding-node-data-dir")} }
+            }
+        }
+    }
+}
+
+#[component]
+fn ConnectionStep(
+    network: String,
+    connection: Resource<Result<HealthReport, api::ApiError>>,
+) -> Element {
+    let mut connection = connection;
+    let testing = connection.read().is_none();
+    let connected = matches!(&*connection.read(), Some(Ok(report)) if report.is_connected());
+    // the price providers do not matter before a currency is chosen.
+    let checks: Vec<_> = match &*connection.read() {
+        Some(Ok(report)) => report
+            .checks
+            .iter()
+            .filter(|c| c.kind != HealthCheckKind::PriceProviders)
+            .cloned()
+            .collect(),
+        _ => vec![],
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("onboarding-connection")} }
+            match &*connection.read() {
+                None => rsx! {
+                    p { aria_busy: "true", {t!("onboarding-connection-testing")} }
+                },
+                Some(Err(e)) => rsx! {
+                    p {
+                        style: "color: var(--pico-del-color);",
+                        "{e}"
+                    }
+                },
+                Some(Ok(HealthReport { connection: RpcConnectionTest::Connected { network: actual }, .. })) => {
+                    let actual = actual.to_string();
+                    rsx! {
+                        p {
+                            style: "color: var(--pico-ins-color);",
+                            {t!("onboarding-connection-ok", network = actual.as_str())}
+                        }
+                        if !actual.starts_with(network.as_str()) {
+                            p {
+                                style: "color: var(--pico-color-amber-500);",
+                                {t!(
+                                    "onboarding-connection-network-mismatch",
+                                    network = actual.as_str(),
+                                    chosen = network.as_str()
+                                )}
+                            }
+                        }
+                    }
+                }
+                Some(Ok(HealthReport { connection: RpcConnectionTest::CookieUnavailable { error, .. }, .. })) => rsx! {
+                    p {
+                        style: "color: var(--pico-del-color);",
+                        {t!("onboarding-connection-cookie")}
+                    }
+                    p {
+                        style: "word-break: break-all; font-size: 0.9rem; color: var(--pico-muted-color);",
+                        "{error}"
+                    }
+                    DataDirectorySettings {}
+                },
+                Some(Ok(HealthReport { connection: RpcConnectionTest::Unreachable { port, error }, .. })) => rsx! {
+                    p {
+                        style: "color: var(--pico-del-color);",
+                        {t!("onboarding-connection-unreachable", port = *port)}
+                    }
+                    p {
+                        style: "word-break: break-all; font-size: 0.9rem; color: var(--pico-muted-color);",
+                        "{error}"
+                    }
+                },
+            }
+            if !checks.is_empty() {
+                details {
+                    open: !connected,
+                    summary {
+                        style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                        {t!("onboarding-connection-checks")}
+                    }
+                    HealthCheckList { checks }
+                }
+            }
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: true,
+                disabled: testing,
+                on_click: move |_| connection.restart(),
+                {t!("onboarding-connection-test")}
+            }
+        }
+    }
+}
+
+#[component]
+fn CurrencyStep(disabled: bool, on_done: EventHandler<()>) -> Element {
+    // the empty value stands for NPT only.
+    let mut choice = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let save = move |_| {
+        spawn(async move {
+            let fiat = FiatCurrency::from_str(&choice()).ok();
+            match api::set_fiat_currency(fiat).await {
+                Ok(_) => on_done.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("onboarding-currency")} }
+            p { {t!("onboarding-currency-intro")} }
+            select {
+                onchange: move |e| choice.set(e.value()),
+                option {
+                    value: "",
+                    selected: choice().is_empty(),
+                    {t!("onboarding-currency-npt-only")}
+                }
+                for fiat in FiatCurrency::iter() {
+                    option {
+                        key: "{fiat.code()}",
+                        value: "{fiat.code()}",
+                        selected: choice() == fiat.code(),
+                        "{fiat.code()} – {fiat.name()}"
+                    }
+                }
+            }
+            if let Some(e) = error() {
+                p {
+                    style: "color: var(--pico-del-color);",
+                    "{e}"
+                }
+            }
+            Button {
+                disabled,
+                on_click: save,
+                {t!("onboarding-finish")}
+            }
+        }
+    }
+}
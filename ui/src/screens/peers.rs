@@ -9,9 +9,16 @@ use std::time::SystemTime;
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::UNIX_EPOCH;
 
+use api::peer_geo::PeerGeoReport;
+use api::prefs::confirmation::Confirmation;
+use api::prefs::refresh_intervals::RefreshScreen;
+use api::reachability::Reachability;
+use api::reachability::ReachabilityMethod;
+use api::reachability::ReachabilityReport;
 use chrono::NaiveDateTime;
 use chrono::TimeZone;
 use chrono::Utc;
+use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
 use neptune_types::peer_info::PeerInfo;
 #[cfg(target_arch = "wasm32")]
@@ -24,7 +31,12 @@ use crate::components::pico::Button;
 use crate::components::pico::ButtonType;
 use crate::components::pico::Card;
 use crate::components::pico::NoTitleModal;
+use crate::components::skeleton::SkeletonTable;
+use crate::data_store::use_peers;
+use crate::data_store::DataStore;
+use crate::app_state_mut::AppStateMut;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
+use crate::polling::PollWeight;
 
 // Embed the SVG content as a static string at compile time.
 const PEERS_EMPTY_SVG: &str = include_str!("../../assets/svg/peers-empty.svg");
@@ -105,26 +117,42 @@ fn SortableHeader(
         ("\u{00A0}", false)
     };
 
+    let aria_sort = match (is_active, *sort_direction.read()) {
+        (false, _) => "none",
+        (true, SortDirection::Ascending) => "ascending",
+        (true, SortDirection::Descending) => "descending",
+    };
+    let mut sort = move || {
+        if is_active {
+            sort_direction
+                .with_mut(|dir| {
+                    *dir = match dir {
+                        SortDirection::Ascending => SortDirection::Descending,
+                        SortDirection::Descending => SortDirection::Ascending,
+                    };
+                });
+        } else {
+            sort_column.set(column);
+            sort_direction.set(SortDirection::Ascending);
+        }
+    };
+
     rsx! {
         th {
             style: "position: sticky; top: 0; background: var(--pico-card-background-color); cursor: pointer; white-space: nowrap;",
-            onclick: move |_| {
-                if is_active {
-                    sort_direction
-                        .with_mut(|dir| {
-                            *dir = match dir {
-                                SortDirection::Ascending => SortDirection::Descending,
-                                SortDirection::Descending => SortDirection::Ascending,
-                            };
-                        });
-                } else {
-                    sort_column.set(column);
-                    sort_direction.set(SortDirection::Ascending);
+            scope: "col",
+            tabindex: "0",
+            "aria-sort": aria_sort,
+            onclick: move |_| sort(),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Enter {
+                    sort();
                 }
             },
             "{title}"
             span {
                 style: "display: inline-block; width: 1.2em; text-align: right;",
+                "aria-hidden": "true",
                 "{arrow_char}"
             }
         }
@@ -146,6 +174,18 @@ impl PartialEq for ClearStandingModalContentProps {
     }
 }
 
+/// Clears the standing of the peer at `ip`, or of all peers if `None`.
+async fn clear_standing(ip: Option<IpAddr>) -> Result<(), String> {
+    match ip {
+        Some(ip) => api::clear_standing_by_ip(ip)
+            .await
+            .map_err(|e| format!("API Error: {}", e)),
+        None => api::clear_all_standings()
+            .await
+            .map_err(|e| format!("API Error: {}", e)),
+    }
+}
+
 // Component containing the modal's internal logic and buttons
 #[component]
 fn ClearStandingModalContent(props: ClearStandingModalContentProps) -> Element {
@@ -153,8 +193,10 @@ fn ClearStandingModalContent(props: ClearStandingModalContentProps) -> Element {
     let mut show_modal = props.show_modal;
     let on_success = props.on_success;
 
+    let app_state_mut = use_context::<AppStateMut>();
     let mut clear_status = use_signal::<Option<Result<(), String>>>(|| None);
     let mut api_in_progress = use_signal(|| false);
+    let mut dont_ask_again = use_signal(|| false);
 
     let action_title = match peer_ip {
         Some(ip) => format!("IP {}", ip),
@@ -176,14 +218,7 @@ fn ClearStandingModalContent(props: ClearStandingModalContentProps) -> Element {
         let ip_to_clear = ip_to_clear; // Capture the IP value
 
         spawn(async move {
-            let result = match ip_to_clear {
-                Some(ip) => api::clear_standing_by_ip(ip)
-                    .await
-                    .map_err(|e| format!("API Error: {}", e)),
-                None => api::clear_all_standings()
-                    .await
-                    .map_err(|e| format!("API Error: {}", e)),
-            };
+            let result = clear_standing(ip_to_clear).await;
 
             api_in_progress.set(false);
 
@@ -191,6 +226,9 @@ fn ClearStandingModalContent(props: ClearStandingModalContentProps) -> Element {
             clear_status.set(Some(result));
 
             if is_success {
+                if dont_ask_again() {
+                    app_state_mut.set_suppressed(Confirmation::ClearStanding, true);
+                }
                 show_modal.set(false);
                 on_success();
             }
@@ -231,6 +269,14 @@ fn ClearStandingModalContent(props: ClearStandingModalContentProps) -> Element {
                 ul {
                     li { b { "{action_title}" } }
                 }
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: dont_ask_again(),
+                        onchange: move |_| dont_ask_again.toggle(),
+                    }
+                    "Don't ask me again"
+                }
 
                 footer {
                     Button {
@@ -264,20 +310,15 @@ fn ClearStandingCell(
     display_content: Element,
     /// The SocketAddr of the peer.
     peer_addr: SocketAddr,
-    /// Signal to control modal visibility.
-    show_modal: Signal<bool>,
-    /// Signal to set the IP address for the modal.
-    modal_ip: Signal<Option<IpAddr>>,
+    /// Called with the peer's IP address when the cell is clicked.
+    on_clear: Callback<Option<IpAddr>>,
 ) -> Element {
     let canonical_ip = get_canonical_ip(&peer_addr);
 
     rsx! {
         td {
             style: "cursor: pointer;",
-            onclick: move |_| {
-                modal_ip.set(Some(canonical_ip));
-                show_modal.set(true);
-            },
+            onclick: move |_| on_clear.call(Some(canonical_ip)),
             {display_content}
         }
     }
@@ -318,46 +359,333 @@ fn EstablishedCell(time: SystemTime) -> Element {
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum GeoView {
+    Countries,
+    Map,
+}
+
+/// Shows where connected peers are, by country or on a map.  Hidden until
+/// the user picks a view, and resolved only with a local GeoIP database.
 #[component]
-pub fn PeersScreen() -> Element {
-    let mut rpc = use_rpc_checker(); // Initialize Hook
+fn PeerGeoCard() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut view = use_signal(|| None::<GeoView>);
+    let is_shown = use_memo(move || view().is_some());
+
+    let mut report = use_resource(move || async move {
+        if !is_shown() {
+            return None;
+        }
+        Some(api::peer_geo().await.map_err(|e| e.to_string()))
+    });
 
-    // Resource type explicitly targets Vec<PeerInfo> with a String error type,
-    // and maps the internal error to String for consistency.
-    let mut peer_info: Resource<Result<Vec<PeerInfo>, String>> =
-        use_resource(move || async move { api::peer_info().await.map_err(|e| e.to_string()) });
+    use_coroutine(move |_rx: UnboundedReceiver<()>| async move {
+        loop {
+            crate::polling::wait_for_screen_refresh(
+                app_state_mut,
+                RefreshScreen::Peers,
+                PollWeight::Light,
+            )
+            .await;
+            if *is_shown.peek() {
+                report.restart();
+            }
+        }
+    });
 
-    // Clone the resource handle for the immutable Fn() closure
-    let peer_info_handle = peer_info.clone();
+    let view_button = move |label: &'static str, choice: Option<GeoView>| {
+        rsx! {
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: view() != choice,
+                style: "padding: 0.2rem 0.5rem; font-size: 0.8rem;",
+                on_click: move |_| view.set(choice),
+                "{label}"
+            }
+        }
+    };
 
-    // Effect: Restarts the resource when connection is restored.
-    let status_sig = rpc.status();
-    use_effect(move || {
-        if status_sig.read().is_connected() {
-            peer_info.restart();
+    rsx! {
+        Card {
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center;",
+                h3 { style: "margin-bottom: 0;", "Peer Locations" }
+                div {
+                    role: "group",
+                    style: "width: auto; margin-bottom: 0;",
+                    {view_button("Hide", None)}
+                    {view_button("Countries", Some(GeoView::Countries))}
+                    {view_button("Map", Some(GeoView::Map))}
+                }
+            }
+            match (view(), &*report.read()) {
+                (None, _) => rsx! {},
+                (Some(_), None | Some(None)) => rsx! {
+                    progress {}
+                },
+                (Some(_), Some(Some(Err(e)))) => rsx! {
+                    p {
+                        style: "margin-top: 1rem; color: var(--pico-del-color);",
+                        "Could not locate peers: {e}"
+                    }
+                },
+                (Some(_), Some(Some(Ok(geo)))) if geo.database.is_none() => rsx! {
+                    p {
+                        style: "margin-top: 1rem; color: var(--pico-muted-color);",
+                        "No GeoIP database is configured. Set GEOIP_DATABASE to the path of a GeoLite2-Country or GeoLite2-City database. Lookups are made locally."
+                    }
+                },
+                (Some(GeoView::Countries), Some(Some(Ok(geo)))) => rsx! {
+                    CountryTable { report: geo.clone() }
+                },
+                (Some(GeoView::Map), Some(Some(Ok(geo)))) => rsx! {
+                    PeerMap { report: geo.clone() }
+                },
+            }
         }
-    });
+    }
+}
 
-    // for refreshing from neptune-core every N secs
-    use_coroutine(move |_rx: UnboundedReceiver<()>| {
-        let rpc_status = rpc.status(); // Use signal handle
-        let mut data_resource = peer_info;
-
-        async move {
-            loop {
-                // Wait 60 seconds
-                crate::compat::sleep(std::time::Duration::from_secs(60)).await;
-
-                // Only restart the resource if we are currently connected.
-                // When connection is lost, rpc_status.read() will be Disconnected,
-                // and we rely on the resource's *dependency* on rpc.status().read()
-                // (in the resource closure) to trigger the restart when it comes back.
-                if (*rpc_status.read()).is_connected() {
-                    data_resource.restart();
+#[component]
+fn CountryTable(report: PeerGeoReport) -> Element {
+    let total = report.peers.len().max(1);
+
+    rsx! {
+        table {
+            style: "margin-top: 1rem;",
+            thead {
+                tr {
+                    th { scope: "col", "Country" }
+                    th { scope: "col", style: "text-align: right;", "Peers" }
+                    th { scope: "col", style: "text-align: right;", "Share" }
+                }
+            }
+            tbody {
+                for country in report.countries() {
+                    tr {
+                        key: "{country.country_code.clone().unwrap_or_default()}",
+                        td {
+                            if let Some(flag) = country.country_code.as_deref().and_then(api::peer_geo::flag) {
+                                "{flag} "
+                            }
+                            {
+                                country
+                                    .country_name
+                                    .clone()
+                                    .or(country.country_code.clone())
+                                    .unwrap_or_else(|| "Unknown".to_string())
+                            }
+                        }
+                        td { style: "text-align: right;", "{country.num_peers}" }
+                        td {
+                            style: "text-align: right;",
+                            "{country.num_peers * 100 / total}%"
+                        }
+                    }
                 }
             }
         }
-    });
+        small {
+            style: "color: var(--pico-muted-color);",
+            "Source: {report.database.clone().unwrap_or_default()}"
+        }
+    }
+}
+
+/// Plots peers on an equirectangular grid of longitude and latitude.
+#[component]
+fn PeerMap(report: PeerGeoReport) -> Element {
+    if !report.has_coordinates() {
+        return rsx! {
+            p {
+                style: "margin-top: 1rem; color: var(--pico-muted-color);",
+                "The GeoIP database has no coordinates. Use a city-level database, eg GeoLite2-City, to see peers on a map."
+            }
+        };
+    }
+    let num_unplaced = report
+        .peers
+        .iter()
+        .filter(|p| p.coordinates().is_none())
+        .count();
+
+    rsx! {
+        svg {
+            view_box: "-180 -90 360 180",
+            width: "100%",
+            style: "margin-top: 1rem; max-height: 50vh; background: var(--pico-code-background-color); border-radius: var(--pico-border-radius);",
+            "aria-label": "Peer locations",
+
+            for lon in (-150..=150).step_by(30) {
+                line {
+                    key: "lon{lon}",
+                    x1: "{lon}",
+                    y1: "-90",
+                    x2: "{lon}",
+                    y2: "90",
+                    stroke: "var(--pico-muted-border-color)",
+                    stroke_width: if lon == 0 { "0.6" } else { "0.3" },
+                }
+            }
+            for lat in (-60..=60).step_by(30) {
+                line {
+                    key: "lat{lat}",
+                    x1: "-180",
+                    y1: "{-lat}",
+                    x2: "180",
+                    y2: "{-lat}",
+                    stroke: "var(--pico-muted-border-color)",
+                    stroke_width: if lat == 0 { "0.6" } else { "0.3" },
+                }
+            }
+            for (i , peer) in report.peers.iter().enumerate() {
+                if let Some((lat, lon)) = peer.coordinates() {
+                    circle {
+                        key: "{i}",
+                        cx: "{lon}",
+                        cy: "{-lat}",
+                        r: "2.5",
+                        fill: "var(--pico-primary)",
+                        fill_opacity: "0.7",
+                        title {
+                            "{format_socket_addr(peer.address)} {peer.country_name.clone().unwrap_or_default()}"
+                        }
+                    }
+                }
+            }
+        }
+        if num_unplaced > 0 {
+            small {
+                style: "color: var(--pico-muted-color);",
+                "{num_unplaced} peer(s) could not be placed."
+            }
+        }
+    }
+}
+
+/// Runs a check of whether the node's P2P port is reachable from the internet.
+#[component]
+fn ReachabilityCard() -> Element {
+    let mut is_checking = use_signal(|| false);
+    let mut report = use_signal::<Option<Result<ReachabilityReport, String>>>(|| None);
+
+    let run_check = move |_| {
+        is_checking.set(true);
+        spawn(async move {
+            let result = api::check_reachability()
+                .await
+                .map_err(|e| e.to_string());
+            report.set(Some(result));
+            is_checking.set(false);
+        });
+    };
+
+    rsx! {
+        Card {
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center;",
+                h3 {
+                    style: "margin-bottom: 0;",
+                    "Reachability"
+                }
+                Button {
+                    button_type: ButtonType::Primary,
+                    outline: true,
+                    style: "height: 1.8rem; line-height: 1.8rem; font-size: 0.8em; padding: 0 1rem;",
+                    disabled: is_checking(),
+                    title: "Test whether peers on the internet can connect to this node",
+                    on_click: run_check,
+                    if is_checking() {
+                        "Checking..."
+                    } else {
+                        "Check Reachability"
+                    }
+                }
+            }
+            match &*report.read() {
+                None => rsx! {
+                    p {
+                        style: "margin-top: 1rem; color: var(--pico-muted-color);",
+                        "A node that cannot accept incoming connections will usually have few peers."
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p {
+                        style: "margin-top: 1rem; color: var(--pico-del-color);",
+                        "Check failed: {e}"
+                    }
+                },
+                Some(Ok(r)) => {
+                    let (status_text, status_color) = match r.result {
+                        Reachability::Reachable => ("Reachable", "var(--pico-color-green-500)"),
+                        Reachability::Unreachable => ("Not reachable", "var(--pico-color-red-500)"),
+                        Reachability::Unknown => ("Unknown", "var(--pico-color-amber-500)"),
+                    };
+                    let method_text = match &r.method {
+                        ReachabilityMethod::ExternalChecker { url } => format!("External checker ({url})"),
+                        ReachabilityMethod::InboundPeers => "Inbound peer connections".to_string(),
+                    };
+                    let listen_text = r
+                        .listen_address
+                        .map(|a| a.to_string())
+                        .unwrap_or_else(|| "Not listening".to_string());
+                    rsx! {
+                        table {
+                            style: "margin-top: 1rem;",
+                            tbody {
+                                tr {
+                                    th { scope: "row", "Status" }
+                                    td {
+                                        strong { style: "color: {status_color};", "{status_text}" }
+                                    }
+                                }
+                                tr {
+                                    th { scope: "row", "Listen address" }
+                                    td { "{listen_text}" }
+                                }
+                                if let Some(ip) = r.public_ip {
+                                    tr {
+                                        th { scope: "row", "Public IP" }
+                                        td { "{ip}" }
+                                    }
+                                }
+                                tr {
+                                    th { scope: "row", "Peers (in / out)" }
+                                    td { "{r.num_inbound_peers} / {r.num_outbound_peers}" }
+                                }
+                                tr {
+                                    th { scope: "row", "Method" }
+                                    td { "{method_text}" }
+                                }
+                            }
+                        }
+                        if let Some(e) = &r.checker_error {
+                            p {
+                                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                                "External checker failed: {e}"
+                            }
+                        }
+                        ul {
+                            for hint in r.hints() {
+                                li { "{hint}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn PeersScreen() -> Element {
+    let mut rpc = use_rpc_checker(); // Initialize Hook
+
+    // fetched by the data store while this screen is shown.
+    let store = use_context::<DataStore>();
+    let peer_info = use_peers();
+    let app_state_mut = use_context::<AppStateMut>();
 
     let sort_column = use_signal(|| SortableColumn::Standing);
     let sort_direction = use_signal(|| SortDirection::Descending);
@@ -368,9 +696,32 @@ pub fn PeersScreen() -> Element {
 
     // ACTION/CONTROL LOGIC:
     let refresh_data_on_success = Rc::new(move || {
-        peer_info_handle.clone().restart();
+        spawn(store.refresh_peers());
     }) as Rc<dyn Fn()>;
 
+    // asks for confirmation first, unless the user chose not to be asked.
+    let request_clear = use_callback({
+        let on_success = refresh_data_on_success.clone();
+        move |ip: Option<IpAddr>| {
+            if app_state_mut.is_suppressed(Confirmation::ClearStanding) {
+                let on_success = on_success.clone();
+                spawn(async move {
+                    match clear_standing(ip).await {
+                        Ok(()) => on_success(),
+                        // show the modal, so the user can see the error on retry.
+                        Err(_) => {
+                            modal_peer_ip.set(ip);
+                            show_clear_standing_modal.set(true);
+                        }
+                    }
+                });
+            } else {
+                modal_peer_ip.set(ip);
+                show_clear_standing_modal.set(true);
+            }
+        }
+    });
+
     rsx! {
         // MODAL RENDER: Using the imported NoTitleModal component
         if *show_clear_standing_modal.read() {
@@ -390,21 +741,7 @@ pub fn PeersScreen() -> Element {
 
         match &*peer_info.read() {
             None => rsx! {
-                Card {
-
-                    h3 {
-
-                        "Connected Peers"
-                    }
-                    p {
-
-                        "Loading..."
-                    }
-                    progress {
-
-
-                    }
-                }
+                SkeletonTable { title: "Connected Peers".to_string(), columns: 6 }
             },
             // check if neptune-core rpc connection lost
             Some(result) if !rpc.check_result_ref(&result) => rsx! {
@@ -427,7 +764,9 @@ pub fn PeersScreen() -> Element {
                         "Failed to load peer data: {e}"
                     }
                     Button {
-                        on_click: move |_| peer_info.restart(),
+                        on_click: move |_| {
+                            spawn(store.refresh_peers());
+                        },
                         "Retry"
                     }
                 }
@@ -507,10 +846,7 @@ pub fn PeersScreen() -> Element {
                                 // RESTORED inline styles for small button size
                                 style: "margin-left: auto; margin-right: 0; padding: 0.2rem 0.5rem; font-size: 0.8rem;",
                                 title: "Resets standing scores for all connected peers back to zero",
-                                on_click: move |_| {
-                                    modal_peer_ip.set(None); // Set to None for "All Peers"
-                                    show_clear_standing_modal.set(true);
-                                },
+                                on_click: move |_| request_clear.call(None), // None for "All Peers"
                                 "Clear All Standings"
                             }
                         }
@@ -574,8 +910,7 @@ pub fn PeersScreen() -> Element {
                                                     }
                                                 },
                                                 peer_addr: peer.connected_address(),
-                                                show_modal: show_clear_standing_modal,
-                                                modal_ip: modal_peer_ip,
+                                                on_clear: request_clear,
                                             }
                                             td {
 
@@ -592,15 +927,13 @@ pub fn PeersScreen() -> Element {
                                             ClearStandingCell {
                                                 display_content: rsx! { "{format_sanction(peer.standing.latest_punishment)}" },
                                                 peer_addr: peer.connected_address(),
-                                                show_modal: show_clear_standing_modal,
-                                                modal_ip: modal_peer_ip,
+                                                on_clear: request_clear,
                                             }
                                             // Fixed: Use peer.connected_address() directly
                                             ClearStandingCell {
                                                 display_content: rsx! { "{format_sanction(peer.standing.latest_reward)}" },
                                                 peer_addr: peer.connected_address(),
-                                                show_modal: show_clear_standing_modal,
-                                                modal_ip: modal_peer_ip,
+                                                on_clear: request_clear,
                                             }
                                         }
                                     }
@@ -611,5 +944,9 @@ pub fn PeersScreen() -> Element {
                 }
             }
         }
+
+        PeerGeoCard {}
+
+        ReachabilityCard {}
     }
 }
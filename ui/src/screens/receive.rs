@@ -3,20 +3,31 @@
 //=============================================================================
 use std::rc::Rc;
 
+use api::expected_utxo::ExpectedUtxos;
+use api::payment_request::PaymentRequest;
+use api::prefs::refresh_intervals::RefreshScreen;
 use dioxus::prelude::*;
 use neptune_types::address::KeyType;
 use neptune_types::address::ReceivingAddress;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
 use serde::{Deserialize, Serialize}; // Needed for GenerationTask serialization
 
 use crate::app_state::AppState;
+use crate::app_state_mut::AppStateMut;
+use crate::components::action_link::ActionLink;
+use crate::components::amount::Amount;
 use crate::components::pico::Button;
 use crate::components::pico::ButtonType;
 use crate::components::pico::Card;
 use crate::components::pico::CopyButton;
 use crate::components::qr_code::QrCode;
+use crate::compat;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
 use crate::hooks::use_rpc_checker::NeptuneRpcConnectionStatus;
+use crate::i18n::t;
+use crate::polling::PollWeight;
 use crate::ConnectionModal;
+use crate::Route;
 
 /// Helper structure to hold the parameters needed to generate a receiving address.
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -25,8 +36,126 @@ struct GenerationTask {
 }
 
 // Consolidated function that performs the actual RPC call, reusable by the button click and the watchdog.
+// The type is remembered once an address of it was generated.
 async fn run_generation_task(task: GenerationTask) -> Result<ReceivingAddress, api::ApiError> {
-    api::next_receiving_address(task.key_type).await
+    let address = api::next_receiving_address(task.key_type).await?;
+    let _ = api::set_receive_key_type(task.key_type).await;
+    Ok(address)
+}
+
+/// The trade-offs of an address type: its size, who it may be shared with,
+/// and who can find the payments to it.
+#[component]
+fn KeyTypeExplanation(key_type: KeyType) -> Element {
+    let (size, sharing, scanning) = if key_type.is_symmetric() {
+        (
+            t!("receive-symmetric-size"),
+            t!("receive-symmetric-sharing"),
+            t!("receive-symmetric-scanning"),
+        )
+    } else {
+        (
+            t!("receive-generation-size"),
+            t!("receive-generation-sharing"),
+            t!("receive-generation-scanning"),
+        )
+    };
+
+    rsx! {
+        dl {
+            style: "max-width: 400px; margin: 0 auto 1.5rem; text-align: left; font-size: 0.9rem;",
+            dt { strong { {t!("receive-type-size")} } }
+            dd { "{size}" }
+            dt { strong { {t!("receive-type-sharing")} } }
+            dd { "{sharing}" }
+            dt { strong { {t!("receive-type-scanning")} } }
+            dd { "{scanning}" }
+        }
+    }
+}
+
+/// Lists incoming payments not yet in a block, so that a payer's transaction
+/// shows up as soon as it reaches the mempool.
+#[component]
+fn ExpectedUtxosCard() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let mut expected = use_signal(|| None::<ExpectedUtxos>);
+
+    use_future(move || async move {
+        loop {
+            if let Ok(found) = api::expected_utxos().await {
+                expected.set(Some(found));
+            }
+            crate::polling::wait_for_screen_refresh(
+                app_state_mut,
+                RefreshScreen::Receive,
+                PollWeight::Light,
+            )
+            .await;
+        }
+    });
+
+    let Some(expected) = expected() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        Card {
+            h4 { {t!("receive-expected-title")} }
+            if expected.in_mempool.is_empty() && expected.unclaimed == 0 {
+                p {
+                    style: "color: var(--pico-muted-color);",
+                    {t!("receive-expected-none")}
+                }
+            }
+            if !expected.in_mempool.is_empty() {
+                table {
+                    thead {
+                        tr {
+                            th { scope: "col", {t!("receive-expected-transaction")} }
+                            th { scope: "col", style: "text-align: right;", {t!("receive-expected-amount")} }
+                            th { scope: "col", {t!("receive-expected-status")} }
+                        }
+                    }
+                    tbody {
+                        for utxo in expected.in_mempool.iter().cloned() {
+                            tr {
+                                key: "{utxo.txid}",
+                                td {
+                                    ActionLink {
+                                        to: Route::MempoolTx { tx_id: utxo.txid },
+                                        code {
+                                            {
+                                                let txid = utxo.txid.to_string();
+                                                format!("{}...{}", &txid[0..6], &txid[txid.len() - 4..])
+                                            }
+                                        }
+                                    }
+                                }
+                                td {
+                                    style: "text-align: right; white-space: nowrap;",
+                                    Amount { amount: utxo.amount }
+                                }
+                                td {
+                                    if utxo.synced {
+                                        {t!("receive-expected-in-mempool")}
+                                    } else {
+                                        {t!("receive-expected-updating")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if expected.unclaimed > 0 {
+                p {
+                    style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                    {t!("receive-expected-unclaimed", count = expected.unclaimed)}
+                }
+            }
+        }
+    }
 }
 
 #[component]
@@ -39,6 +168,20 @@ pub fn ReceiveScreen() -> Element {
     let mut selected_key_type = use_signal(|| KeyType::Generation);
     let mut symmetric_warning_acknowledged = use_signal(|| false);
 
+    // offers the type last generated first.
+    use_future(move || async move {
+        if let Ok(prefs) = api::get_user_prefs().await {
+            selected_key_type.set(prefs.receive_key_type());
+        }
+    });
+
+    // Optional payment request details.
+    let mut request_amount = use_signal(String::new);
+    let mut request_label = use_signal(String::new);
+
+    // Set when sharing fell back to copying.
+    let mut share_note = use_signal(|| None::<String>);
+
     // 1. Signal to store the pending Task for retry.
     let mut pending_task = use_signal::<Option<GenerationTask>>(|| None);
 
@@ -101,16 +244,33 @@ pub fn ReceiveScreen() -> Element {
         || (selected_key_type() == KeyType::Symmetric && !symmetric_warning_acknowledged())
         || rpc.status().read().is_disconnected();
 
+    // The payment request for the generated address. The amount and label
+    // are optional; without them only the plain address is shared.
+    let amount_str = request_amount().trim().replace(',', ".");
+    let amount_result = if amount_str.is_empty() {
+        Ok(None)
+    } else {
+        NativeCurrencyAmount::coins_from_str(&amount_str)
+            .map(Some)
+            .map_err(|e| e.to_string())
+    };
+    let request = receiving_address().map(|address| PaymentRequest {
+        address: address.to_bech32m(network).unwrap(),
+        amount: amount_result.clone().ok().flatten(),
+        label: Some(request_label().trim().to_string()).filter(|l| !l.is_empty()),
+        message: None,
+    });
+
     rsx! {
         // Render the ConnectionModal based on global state
         ConnectionModal {}
 
         Card {
             h2 {
-                "Receive Funds"
+                {t!("receive-title")}
             }
 
-            if let Some(address) = receiving_address() {
+            if let (Some(address), Some(request)) = (receiving_address(), request) {
                 // View to display AFTER an address has been generated
                 div {
                     style: "text-align: center; padding-top: 1rem;",
@@ -118,42 +278,122 @@ pub fn ReceiveScreen() -> Element {
                         p {
                             strong {
                                 style: "color: var(--pico-color-red-500);",
-                                "Do not share with anyone."
+                                {t!("receive-symmetric-do-not-share")}
                             }
                         }
                         p {
-                            "This is a symmetric key/address."
+                            {t!("receive-symmetric-notice")}
                         }
                         p {
-                            "Anyone possessing it can spend associated funds."
+                            {t!("receive-symmetric-risk")}
                         }
                     } else {
                         p {
-                            "Share this address to receive funds."
+                            {t!("receive-share-address")}
                         }
                     }
 
                     QrCode {
-                        data: address.to_display_bech32m(network).unwrap().to_uppercase(),
-                        caption: "Scan the QR code to obtain the full address.".to_string(),
+                        data: if request.has_details() {
+                            request.to_string()
+                        } else {
+                            address.to_display_bech32m(network).unwrap().to_uppercase()
+                        },
+                        caption: if request.has_details() {
+                            t!("receive-scan-payment-request")
+                        } else {
+                            t!("receive-scan-address")
+                        },
+                        preserve_case: request.has_details(),
                     }
 
                     code {
                         style: "word-break: break-all; font-size: 0.9rem;",
                         "{address.to_bech32m_abbreviated(network).unwrap()}"
                     }
+
+                    // Payment request details. Not offered for symmetric
+                    // addresses, which must not be shared.
+                    if !KeyType::from(&*address).is_symmetric() {
+                        details {
+                            style: "max-width: 400px; margin: 1.5rem auto 0; text-align: left;",
+                            summary { {t!("receive-request-amount")} }
+                            label {
+                                {t!("receive-amount-npt")}
+                                input {
+                                    r#type: "text",
+                                    inputmode: "decimal",
+                                    placeholder: t!("receive-optional"),
+                                    value: "{request_amount}",
+                                    oninput: move |evt| request_amount.set(evt.value()),
+                                }
+                            }
+                            if let Err(e) = &amount_result {
+                                small { style: "color: var(--pico-del-color);", "{e}" }
+                            }
+                            label {
+                                {t!("receive-label")}
+                                input {
+                                    r#type: "text",
+                                    placeholder: t!("receive-label-placeholder"),
+                                    value: "{request_label}",
+                                    oninput: move |evt| request_label.set(evt.value()),
+                                }
+                            }
+                        }
+                    }
+
                     div {
                         style: "margin-top: 1.5rem; display: flex; justify-content: center; gap: 1rem;",
                         CopyButton {
-                            text_to_copy: address.to_bech32m(network).unwrap(),
+                            text_to_copy: if request.has_details() {
+                                request.to_string()
+                            } else {
+                                address.to_bech32m(network).unwrap()
+                            },
+                        }
+                        if compat::CAN_SHARE {
+                            Button {
+                                button_type: ButtonType::Secondary,
+                                on_click: {
+                                    let text = if request.has_details() {
+                                        request.to_string()
+                                    } else {
+                                        address.to_bech32m(network).unwrap()
+                                    };
+                                    move |_| {
+                                        let text = text.clone();
+                                        share_note.set(None);
+                                        spawn(async move {
+                                            // without a share sheet the address is copied instead.
+                                            if compat::share(&t!("receive-share-title"), &text).await.is_err()
+                                                && compat::clipboard_set(text).await
+                                            {
+                                                share_note.set(Some(t!("receive-share-copied")));
+                                            }
+                                        });
+                                    }
+                                },
+                                {t!("receive-share")}
+                            }
                         }
                         Button {
                             button_type: ButtonType::Secondary,
                             on_click: move |_| {
+                                share_note.set(None);
                                 receiving_address.set(None);
                                 symmetric_warning_acknowledged.set(false);
+                                request_amount.set(String::new());
+                                request_label.set(String::new());
                             },
-                            "Generate Another"
+                            {t!("receive-generate-another")}
+                        }
+                    }
+                    if let Some(note) = share_note() {
+                        p {
+                            role: "status",
+                            style: "margin-top: 0.5rem; color: var(--pico-muted-color);",
+                            small { "{note}" }
                         }
                     }
                 }
@@ -166,13 +406,13 @@ pub fn ReceiveScreen() -> Element {
                         p {
                             strong {
                                 style: "color: var(--pico-del-color);",
-                                "Connection Lost. Retrying when connection is restored..."
+                                {t!("receive-connection-lost")}
                             }
                         }
                     }
 
                     p {
-                        "Select Address Type:"
+                        {t!("receive-select-type")}
                     }
                     div {
                         style: "display: flex; justify-content: center; gap: 1rem; margin-bottom: 1.5rem;",
@@ -180,16 +420,18 @@ pub fn ReceiveScreen() -> Element {
                             button_type: ButtonType::Secondary,
                             outline: selected_key_type() != KeyType::Generation,
                             on_click: move |_| selected_key_type.set(KeyType::Generation),
-                            "Generation"
+                            {t!("receive-type-generation")}
                         }
                         Button {
                             button_type: ButtonType::Secondary,
                             outline: selected_key_type() != KeyType::Symmetric,
                             on_click: move |_| selected_key_type.set(KeyType::Symmetric),
-                            "Symmetric Key"
+                            {t!("receive-type-symmetric")}
                         }
                     }
 
+                    KeyTypeExplanation { key_type: selected_key_type() }
+
                     if selected_key_type() == KeyType::Symmetric {
                         div {
                             style: "max-width: 400px; margin: auto; margin-bottom: 1.5rem;",
@@ -200,7 +442,7 @@ pub fn ReceiveScreen() -> Element {
                                         checked: "{symmetric_warning_acknowledged()}",
                                         oninput: move |evt| symmetric_warning_acknowledged.set(evt.value() == "true"),
                                     }
-                                    "I understand that symmetric keys must only be used for sending between wallets I control. Sharing with others would enable them to spend my funds."
+                                    {t!("receive-symmetric-acknowledge")}
                                 }
                             }
                         }
@@ -239,15 +481,17 @@ pub fn ReceiveScreen() -> Element {
                             });
                         },
                         if is_generating() {
-                            "Generating..."
+                            {t!("receive-generating")}
                         } else if pending_task().is_some() {
-                             "Pending Retry..."
+                             {t!("receive-pending-retry")}
                         } else {
-                            "Generate New Receiving Address"
+                            {t!("receive-generate")}
                         }
                     }
                 }
             }
         }
+
+        ExpectedUtxosCard {}
     }
 }
@@ -0,0 +1,757 @@
+//=============================================================================
+// File: src/screens/reports.rs
+//=============================================================================
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use api::fiat_amount::FiatAmount;
+use api::fiat_currency::FiatCurrency;
+use api::prefs::display_preference::DisplayPreference;
+use api::price_map::PriceMap;
+use api::send_job::SendJob;
+use api::send_job::SendJobStatus;
+use api::tx_annotation::TxAnnotations;
+use dioxus::prelude::*;
+use itertools::Itertools;
+use neptune_types::block_height::BlockHeight;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::timestamp::Timestamp;
+use num_traits::Zero;
+use twenty_first::tip5::Digest;
+
+use crate::app_state_mut::AppStateMut;
+use crate::components::amount::Amount;
+use crate::components::amount::AmountType;
+use crate::components::empty_state::EmptyState;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::components::skeleton::SkeletonTable;
+use crate::currency::npt_to_fiat;
+use crate::i18n::t;
+use crate::number_format;
+
+/// Which field of the history annotations spending is grouped by.
+#[derive(Clone, Copy, PartialEq)]
+enum Grouping {
+    Tag,
+    Contact,
+}
+
+/// Spending in one category during one month.
+#[derive(Clone, PartialEq)]
+struct CategoryTotal {
+    /// None for spending without a tag or contact.
+    name: Option<String>,
+    npt: NativeCurrencyAmount,
+    fiat: Option<FiatAmount>,
+    num_txs: usize,
+    /// Some of the spending had no price on its day, and is left out of
+    /// `fiat`.
+    fiat_is_partial: bool,
+}
+
+/// A single outgoing history entry.
+struct Spend {
+    digest: Digest,
+    timestamp: Timestamp,
+    amount: NativeCurrencyAmount,
+}
+
+/// A history row: the block, its height and time, and the amount.
+type HistoryEntry = (Digest, BlockHeight, Timestamp, NativeCurrencyAmount);
+
+/// Nets the history per block.
+fn net_per_block(history: &[HistoryEntry]) -> Vec<HistoryEntry> {
+    history
+        .iter()
+        .chunk_by(|(digest, height, timestamp, _)| (*digest, *height, *timestamp))
+        .into_iter()
+        .map(|((digest, height, timestamp), group)| {
+            (digest, height, timestamp, group.map(|(.., amount)| *amount).sum())
+        })
+        .collect()
+}
+
+/// Nets the history per block, keeping the blocks in which funds left the
+/// wallet.
+fn spends(history: &[HistoryEntry]) -> Vec<Spend> {
+    net_per_block(history)
+        .into_iter()
+        .map(|(digest, _, timestamp, amount)| Spend {
+            digest,
+            timestamp,
+            amount,
+        })
+        .filter(|spend| spend.amount < NativeCurrencyAmount::zero())
+        .map(|spend| Spend {
+            amount: -spend.amount,
+            ..spend
+        })
+        .collect()
+}
+
+fn day(timestamp: Timestamp) -> String {
+    timestamp.format("%Y-%m-%d").to_string()
+}
+
+fn year(timestamp: Timestamp) -> String {
+    timestamp.format("%Y").to_string()
+}
+
+/// The value of `amount` in `fiat` at the price of `timestamp`'s day, if
+/// known.
+fn value_on_day(
+    amount: NativeCurrencyAmount,
+    timestamp: Timestamp,
+    prices: &HashMap<String, PriceMap>,
+    fiat: Option<FiatCurrency>,
+) -> Option<FiatAmount> {
+    let rate = prices.get(&day(timestamp))?.get(fiat?)?;
+    Some(npt_to_fiat(&amount, &rate))
+}
+
+/// What came in and went out during one calendar year, for tax purposes.
+#[derive(Clone, PartialEq)]
+struct YearTotals {
+    received: NativeCurrencyAmount,
+    received_fiat: Option<FiatAmount>,
+    num_received: usize,
+    /// Includes the fees.
+    sent: NativeCurrencyAmount,
+    sent_fiat: Option<FiatAmount>,
+    num_sent: usize,
+    /// The fees of the sends made with this app.  Sends made elsewhere, or
+    /// cleared from the list of sends, are not known.
+    fees: NativeCurrencyAmount,
+    num_fees: usize,
+    /// Some transactions had no price on their day, and are left out of the
+    /// fiat values.
+    fiat_is_partial: bool,
+}
+
+/// Totals the blocks of `year` ("YYYY"), as netted by [net_per_block].
+fn year_totals(
+    blocks: &[HistoryEntry],
+    year_: &str,
+    jobs: &[SendJob],
+    prices: &HashMap<String, PriceMap>,
+    fiat: Option<FiatCurrency>,
+) -> YearTotals {
+    let zero_fiat = fiat.map(|fiat| FiatAmount::new_from_minor(0, fiat));
+    let mut totals = YearTotals {
+        received: NativeCurrencyAmount::zero(),
+        received_fiat: zero_fiat,
+        num_received: 0,
+        sent: NativeCurrencyAmount::zero(),
+        sent_fiat: zero_fiat,
+        num_sent: 0,
+        fees: NativeCurrencyAmount::zero(),
+        num_fees: 0,
+        fiat_is_partial: false,
+    };
+    for (_, _, timestamp, amount) in blocks.iter().filter(|(.., ts, _)| year(*ts) == year_) {
+        let received = *amount > NativeCurrencyAmount::zero();
+        let magnitude = if received { *amount } else { -*amount };
+        let value = value_on_day(magnitude, *timestamp, prices, fiat);
+        let (total, total_fiat, count) = if received {
+            (&mut totals.received, &mut totals.received_fiat, &mut totals.num_received)
+        } else {
+            (&mut totals.sent, &mut totals.sent_fiat, &mut totals.num_sent)
+        };
+        *total = *total + magnitude;
+        *count += 1;
+        match (total_fiat, value) {
+            (Some(total_fiat), Some(value)) => *total_fiat += value,
+            (Some(_), None) => totals.fiat_is_partial = true,
+            _ => {}
+        }
+    }
+    for job in jobs
+        .iter()
+        .filter(|j| matches!(j.status, SendJobStatus::Sent { .. }))
+        .filter(|j| job_year(j).as_deref() == Some(year_))
+    {
+        totals.fees = totals.fees + job.fee;
+        totals.num_fees += 1;
+    }
+    totals
+}
+
+/// The year ("YYYY", UTC) a send finished in.
+fn job_year(job: &SendJob) -> Option<String> {
+    let ms = job.finished_ms?;
+    chrono::DateTime::from_timestamp_millis(ms as i64).map(|d| d.format("%Y").to_string())
+}
+
+/// The blocks of `year_` as CSV, one row each, after `#` comment lines with
+/// the year's totals.  Untranslated, for accounting software.
+fn year_csv(
+    blocks: &[HistoryEntry],
+    year_: &str,
+    totals: &YearTotals,
+    prices: &HashMap<String, PriceMap>,
+    fiat: Option<FiatCurrency>,
+) -> String {
+    let code = fiat.map(|f| f.code()).unwrap_or_default();
+    let fiat_or_empty = |value: Option<FiatAmount>| value.map(|v| v.to_string()).unwrap_or_default();
+    let mut csv = format!("# year: {year_}\n");
+    csv.push_str(&format!(
+        "# received: {} NPT in {} transactions{}\n",
+        totals.received,
+        totals.num_received,
+        totals
+            .received_fiat
+            .map(|v| format!(", {} {code}", v))
+            .unwrap_or_default()
+    ));
+    csv.push_str(&format!(
+        "# sent, including fees: {} NPT in {} transactions{}\n",
+        totals.sent,
+        totals.num_sent,
+        totals
+            .sent_fiat
+            .map(|v| format!(", {} {code}", v))
+            .unwrap_or_default()
+    ));
+    csv.push_str(&format!(
+        "# fees of the {} sends made with neptune-proton: {} NPT\n",
+        totals.num_fees, totals.fees
+    ));
+    if totals.fiat_is_partial {
+        csv.push_str("# some transactions have no price for their day, and no value\n");
+    }
+    csv.push_str(&format!(
+        "date,type,amount_npt,price_{code},value_{code},block_height,block_digest\n"
+    ));
+    for (digest, height, timestamp, amount) in blocks.iter().filter(|(.., ts, _)| year(*ts) == year_) {
+        let received = *amount > NativeCurrencyAmount::zero();
+        let magnitude = if received { *amount } else { -*amount };
+        let price = fiat.and_then(|fiat| prices.get(&day(*timestamp))?.get(fiat));
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            day(*timestamp),
+            if received { "Received" } else { "Sent" },
+            magnitude,
+            fiat_or_empty(price),
+            fiat_or_empty(value_on_day(magnitude, *timestamp, prices, fiat)),
+            height,
+            digest.to_hex(),
+        ));
+    }
+    csv
+}
+
+/// Totals spending per category, keyed by month ("YYYY-MM").  Categories are
+/// ordered by amount, largest first.
+fn monthly_spending(
+    spends: &[Spend],
+    annotations: &TxAnnotations,
+    grouping: Grouping,
+    prices: &HashMap<String, PriceMap>,
+    fiat: Option<FiatCurrency>,
+) -> BTreeMap<String, Vec<CategoryTotal>> {
+    let mut months: BTreeMap<String, Vec<CategoryTotal>> = BTreeMap::new();
+    for spend in spends {
+        let annotation = annotations.get(&spend.digest.to_hex());
+        let name = annotation.and_then(|a| match grouping {
+            Grouping::Tag => a.tag.clone(),
+            Grouping::Contact => a.contact.clone(),
+        });
+        let value = fiat.and_then(|fiat| {
+            let rate = prices.get(&day(spend.timestamp))?.get(fiat)?;
+            Some(npt_to_fiat(&spend.amount, &rate))
+        });
+
+        let categories = months
+            .entry(spend.timestamp.format("%Y-%m").to_string())
+            .or_default();
+        let index = match categories.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                categories.push(CategoryTotal {
+                    name,
+                    npt: NativeCurrencyAmount::zero(),
+                    fiat: fiat.map(|fiat| FiatAmount::new_from_minor(0, fiat)),
+                    num_txs: 0,
+                    fiat_is_partial: false,
+                });
+                categories.len() - 1
+            }
+        };
+        let category = &mut categories[index];
+        category.npt = category.npt + spend.amount;
+        category.num_txs += 1;
+        match (&mut category.fiat, value) {
+            (Some(total), Some(value)) => *total += value,
+            _ => category.fiat_is_partial = true,
+        }
+    }
+
+    for categories in months.values_mut() {
+        categories.sort_by(|a, b| b.npt.cmp(&a.npt));
+    }
+    months
+}
+
+/// `part` as a percentage of `whole`.
+fn share(part: NativeCurrencyAmount, whole: NativeCurrencyAmount) -> f64 {
+    if whole.is_zero() {
+        return 0.0;
+    }
+    part.to_nau() as f64 / whole.to_nau() as f64 * 100.0
+}
+
+/// A horizontal bar, `percent` of the available width.
+#[component]
+fn Bar(percent: f64, highlighted: bool) -> Element {
+    let color = if highlighted {
+        "var(--pico-primary)"
+    } else {
+        "var(--pico-muted-border-color)"
+    };
+    rsx! {
+        div {
+            style: "flex: 1; height: 0.8rem; background: var(--pico-card-sectioning-background-color); border-radius: 0.2rem;",
+            div {
+                style: "width: {percent:.1}%; height: 100%; background: {color}; border-radius: 0.2rem;",
+            }
+        }
+    }
+}
+
+/// Monthly spending, broken down by the tags or contacts set on the History
+/// screen, and valued at the price on the day of each transaction.
+#[component]
+pub fn ReportsScreen() -> Element {
+    let display_preference = use_context::<AppStateMut>().display_preference;
+    let fiat = match *display_preference.read() {
+        DisplayPreference::FiatEnabled { fiat, .. } => Some(fiat),
+        DisplayPreference::NptOnly => None,
+    };
+
+    let history = use_resource(move || async move { api::history().await });
+    let annotations =
+        use_resource(move || async move { api::history_annotations().await.unwrap_or_default() });
+    let prices = use_resource(move || async move {
+        if fiat.is_none() {
+            return HashMap::new();
+        }
+        let dates: Vec<String> = match &*history.read() {
+            Some(Ok(rows)) => spends(rows)
+                .iter()
+                .map(|spend| day(spend.timestamp))
+                .unique()
+                .collect(),
+            _ => return HashMap::new(),
+        };
+        api::historical_prices(dates).await.unwrap_or_default()
+    });
+
+    let mut grouping = use_signal(|| Grouping::Tag);
+    let mut selected_month = use_signal(|| None::<String>);
+    let number_format = number_format::current();
+
+    let rows = match &*history.read() {
+        None => {
+            return rsx! {
+                SkeletonTable { title: t!("reports-title"), columns: 4 }
+            }
+        }
+        Some(Err(e)) => {
+            return rsx! {
+                Card {
+                    h3 { {t!("common-error")} }
+                    p { {t!("history-load-failed", error = e.to_string())} }
+                }
+            }
+        }
+        Some(Ok(rows)) => rows.clone(),
+    };
+
+    let months = monthly_spending(
+        &spends(&rows),
+        &annotations.read().clone().unwrap_or_default(),
+        grouping(),
+        &prices.read().clone().unwrap_or_default(),
+        fiat,
+    );
+    let Some(latest_month) = months.keys().next_back().cloned() else {
+        return rsx! {
+            Card {
+                h3 { {t!("reports-title")} }
+                EmptyState {
+                    title: t!("reports-empty-title"),
+                    description: Some(t!("reports-empty-description")),
+                }
+            }
+            YearlySummary { rows, fiat }
+        };
+    };
+    let month = selected_month()
+        .filter(|m| months.contains_key(m))
+        .unwrap_or(latest_month);
+
+    let month_totals: Vec<(String, NativeCurrencyAmount)> = months
+        .iter()
+        .map(|(month, categories)| (month.clone(), categories.iter().map(|c| c.npt).sum()))
+        .collect();
+    let largest_month_total = month_totals
+        .iter()
+        .map(|(_, total)| *total)
+        .max()
+        .unwrap_or_else(NativeCurrencyAmount::zero);
+
+    let categories = months.get(&month).cloned().unwrap_or_default();
+    let month_total: NativeCurrencyAmount = categories.iter().map(|c| c.npt).sum();
+    let month_fiat_total = fiat.map(|fiat| {
+        categories
+            .iter()
+            .filter_map(|c| c.fiat)
+            .fold(FiatAmount::new_from_minor(0, fiat), |sum, value| sum + value)
+    });
+    let fiat_is_partial = categories.iter().any(|c| c.fiat_is_partial);
+    let untagged = match grouping() {
+        Grouping::Tag => t!("reports-untagged"),
+        Grouping::Contact => t!("reports-no-contact"),
+    };
+
+    rsx! {
+        Card {
+            div {
+                style: "display: flex; justify-content: space-between; align-items: baseline; flex-wrap: wrap; gap: 1rem;",
+                h3 { {t!("reports-title")} }
+                label {
+                    style: "font-size: 0.9rem;",
+                    {t!("reports-group-by")}
+                    select {
+                        onchange: move |e| {
+                            grouping.set(if e.value() == "contact" { Grouping::Contact } else { Grouping::Tag });
+                        },
+                        option {
+                            value: "tag",
+                            selected: grouping() == Grouping::Tag,
+                            {t!("reports-tag")}
+                        }
+                        option {
+                            value: "contact",
+                            selected: grouping() == Grouping::Contact,
+                            {t!("reports-contact")}
+                        }
+                    }
+                }
+            }
+
+            h4 { {t!("reports-monthly-totals")} }
+            div {
+                style: "display: flex; flex-direction: column; gap: 0.25rem; margin-bottom: 1.5rem;",
+                for (m, total) in month_totals.into_iter().rev() {
+                    {
+                        let weight = if m == month { "bold" } else { "normal" };
+                        rsx! {
+                            div {
+                                key: "{m}",
+                                style: "display: flex; align-items: center; gap: 0.5rem; cursor: pointer; font-size: 0.9rem;",
+                                onclick: {
+                                    let m = m.clone();
+                                    move |_| selected_month.set(Some(m.clone()))
+                                },
+                                span {
+                                    style: "width: 5rem; font-weight: {weight};",
+                                    "{m}"
+                                }
+                                Bar {
+                                    percent: share(total, largest_month_total),
+                                    highlighted: m == month,
+                                }
+                                span {
+                                    style: "min-width: 16ch; text-align: right; white-space: nowrap;",
+                                    Amount { amount: total, fixed: AmountType::Npt }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            h4 { {t!("reports-month-breakdown", month = month.clone())} }
+            table {
+                thead {
+                    tr {
+                        th { scope: "col", {t!("reports-category")} }
+                        th { scope: "col", style: "width: 30%;" }
+                        th { scope: "col", style: "text-align: right;", {t!("reports-transactions")} }
+                        th { scope: "col", style: "text-align: right;", {t!("history-amount")} }
+                        if fiat.is_some() {
+                            th {
+                                scope: "col",
+                                style: "text-align: right;",
+                                title: t!("history-value-at-time-tooltip"),
+                                {t!("history-value-at-time")}
+                            }
+                        }
+                        th { scope: "col", style: "text-align: right;", {t!("reports-share")} }
+                    }
+                }
+                tbody {
+                    for category in categories {
+                        {
+                            let percent = share(category.npt, month_total);
+                            let name = category.name.clone().unwrap_or_else(|| untagged.clone());
+                            rsx! {
+                                tr {
+                                    key: "{name}",
+                                    td {
+                                        if category.name.is_some() {
+                                            "{name}"
+                                        } else {
+                                            em { "{name}" }
+                                        }
+                                    }
+                                    td {
+                                        Bar { percent, highlighted: true }
+                                    }
+                                    td { style: "text-align: right;", "{category.num_txs}" }
+                                    td {
+                                        style: "text-align: right; white-space: nowrap;",
+                                        Amount { amount: category.npt, fixed: AmountType::Npt }
+                                    }
+                                    if fiat.is_some() {
+                                        td {
+                                            style: "text-align: right; white-space: nowrap;",
+                                            match category.fiat {
+                                                Some(value) => rsx! { "{value.to_string_localized(&number_format)}" },
+                                                None => rsx! { "—" },
+                                            }
+                                            if category.fiat_is_partial {
+                                                " *"
+                                            }
+                                        }
+                                    }
+                                    td { style: "text-align: right;", "{percent:.1}%" }
+                                }
+                            }
+                        }
+                    }
+                }
+                tfoot {
+                    tr {
+                        th { scope: "row", {t!("reports-total")} }
+                        th {}
+                        th {}
+                        th {
+                            style: "text-align: right; white-space: nowrap;",
+                            Amount { amount: month_total, fixed: AmountType::Npt }
+                        }
+                        if let Some(total) = month_fiat_total {
+                            th {
+                                style: "text-align: right; white-space: nowrap;",
+                                "{total.to_string_localized(&number_format)}"
+                                if fiat_is_partial {
+                                    " *"
+                                }
+                            }
+                        }
+                        th {}
+                    }
+                }
+            }
+            if fiat_is_partial {
+                p {
+                    style: "font-size: 0.9rem; color: var(--pico-muted-color);",
+                    {t!("reports-partial-prices")}
+                }
+            }
+            p {
+                style: "font-size: 0.9rem; color: var(--pico-muted-color);",
+                {t!("reports-hint")}
+            }
+        }
+        YearlySummary { rows, fiat }
+    }
+}
+
+/// Received, sent and fees per calendar year, valued at the price on the day
+/// of each transaction, for handing to an accountant as CSV or PDF.
+#[component]
+fn YearlySummary(rows: Vec<HistoryEntry>, fiat: Option<FiatCurrency>) -> Element {
+    let blocks = net_per_block(&rows);
+    let years: Vec<String> = blocks
+        .iter()
+        .map(|(.., timestamp, _)| year(*timestamp))
+        .unique()
+        .sorted()
+        .rev()
+        .collect();
+    let mut selected_year = use_signal(|| None::<String>);
+    let year_ = selected_year()
+        .filter(|y| years.contains(y))
+        .or_else(|| years.first().cloned());
+    let mut status = use_signal(|| None::<String>);
+
+    let jobs = use_resource(move || async move { api::send_jobs().await.unwrap_or_default() });
+    let prices = use_resource(use_reactive(
+        (&blocks, &year_, &fiat),
+        |(blocks, year_, fiat)| async move {
+            let (Some(year_), Some(_)) = (year_, fiat) else {
+                return HashMap::new();
+            };
+            let dates: Vec<String> = blocks
+                .iter()
+                .filter(|(.., timestamp, _)| year(*timestamp) == year_)
+                .map(|(.., timestamp, _)| day(*timestamp))
+                .unique()
+                .collect();
+            api::historical_prices(dates).await.unwrap_or_default()
+        },
+    ));
+
+    let Some(year_) = year_ else {
+        return rsx! {};
+    };
+    let number_format = number_format::current();
+    let prices_now = prices.read().clone().unwrap_or_default();
+    let totals = year_totals(
+        &blocks,
+        &year_,
+        &jobs.read().clone().unwrap_or_default(),
+        &prices_now,
+        fiat,
+    );
+    let fiat_cell = |value: Option<FiatAmount>| match value {
+        Some(value) => value.to_string_localized(&number_format),
+        None => "—".to_string(),
+    };
+    let partial = if totals.fiat_is_partial { " *" } else { "" };
+
+    let export_csv = {
+        let blocks = blocks.clone();
+        let year_ = year_.clone();
+        let totals = totals.clone();
+        move |_| {
+            let csv = year_csv(&blocks, &year_, &totals, &prices_now, fiat);
+            let file_name = format!("tax-report-{year_}.csv");
+            spawn(async move {
+                status.set(
+                    match crate::compat::save_file(&file_name, "CSV File", "csv", csv).await {
+                        Ok(true) => Some(t!("common-exported")),
+                        Ok(false) => None,
+                        Err(e) => Some(t!("common-export-failed", error = e)),
+                    },
+                );
+            });
+        }
+    };
+
+    rsx! {
+        Card {
+            div {
+                style: "display: flex; justify-content: space-between; align-items: baseline; flex-wrap: wrap; gap: 1rem;",
+                h3 { {t!("reports-year-title")} }
+                label {
+                    style: "font-size: 0.9rem;",
+                    {t!("reports-year")}
+                    select {
+                        onchange: move |e| selected_year.set(Some(e.value())),
+                        for y in years {
+                            option {
+                                key: "{y}",
+                                value: "{y}",
+                                selected: y == year_,
+                                "{y}"
+                            }
+                        }
+                    }
+                }
+            }
+            table {
+                thead {
+                    tr {
+                        th { scope: "col" }
+                        th { scope: "col", style: "text-align: right;", {t!("reports-transactions")} }
+                        th { scope: "col", style: "text-align: right;", {t!("history-amount")} }
+                        if fiat.is_some() {
+                            th {
+                                scope: "col",
+                                style: "text-align: right;",
+                                title: t!("history-value-at-time-tooltip"),
+                                {t!("history-value-at-time")}
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    tr {
+                        th { scope: "row", {t!("reports-year-received")} }
+                        td { style: "text-align: right;", "{totals.num_received}" }
+                        td {
+                            style: "text-align: right; white-space: nowrap;",
+                            Amount { amount: totals.received, fixed: AmountType::Npt }
+                        }
+                        if fiat.is_some() {
+                            td {
+                                style: "text-align: right; white-space: nowrap;",
+                                "{fiat_cell(totals.received_fiat)}{partial}"
+                            }
+                        }
+                    }
+                    tr {
+                        th { scope: "row", {t!("reports-year-sent")} }
+                        td { style: "text-align: right;", "{totals.num_sent}" }
+                        td {
+                            style: "text-align: right; white-space: nowrap;",
+                            Amount { amount: totals.sent, fixed: AmountType::Npt }
+                        }
+                        if fiat.is_some() {
+                            td {
+                                style: "text-align: right; white-space: nowrap;",
+                                "{fiat_cell(totals.sent_fiat)}{partial}"
+                            }
+                        }
+                    }
+                    tr {
+                        th { scope: "row", {t!("reports-year-fees")} }
+                        td { style: "text-align: right;", "{totals.num_fees}" }
+                        td {
+                            style: "text-align: right; white-space: nowrap;",
+                            Amount { amount: totals.fees, fixed: AmountType::Npt }
+                        }
+                        if fiat.is_some() {
+                            td {}
+                        }
+                    }
+                }
+            }
+            if totals.fiat_is_partial {
+                p {
+                    style: "font-size: 0.9rem; color: var(--pico-muted-color);",
+                    {t!("reports-partial-prices")}
+                }
+            }
+            p {
+                style: "font-size: 0.9rem; color: var(--pico-muted-color);",
+                {t!("reports-year-fees-hint")}
+            }
+            div {
+                role: "group",
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    on_click: export_csv,
+                    {t!("reports-year-export-csv")}
+                }
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    on_click: move |_| {
+                        let _ = document::eval("window.print()");
+                    },
+                    {t!("reports-year-print")}
+                }
+            }
+            if let Some(message) = status() {
+                p { style: "font-size: 0.9rem;", "{message}" }
+            }
+        }
+    }
+}
@@ -1,28 +1,53 @@
 //=============================================================================
 // File: src/screens/send.rs
 //=============================================================================
+use std::collections::BTreeMap;
 use std::rc::Rc;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
-
+use std::sync::Arc;
+use std::time::Duration;
+
+use api::address_validation::validate_address;
+use api::announcement_input;
+use api::announcement_input::AnnouncementEncoding;
+use api::announcement_input::MAX_ANNOUNCEMENT_ELEMENTS;
+use api::address_validation::AddressProblem;
 use api::fiat_amount::FiatAmount;
 use api::fiat_currency::FiatCurrency;
+use api::payment_request::ParsePaymentRequestError;
+use api::payment_request::PaymentRequest;
+use api::prefs::confirmation::Confirmation;
 use api::prefs::display_preference::DisplayPreference;
+use api::recent_recipient::RecentRecipient;
+use api::send_draft::DraftAmount;
+use api::send_draft::DraftRecipient;
+use api::send_draft::SendDraft;
+use api::send_job::SendJobStatus;
+use api::send_progress::SendProgress;
+use api::send_progress::SendStage;
+use api::spendable_balance::SpendableBalance;
+use api::utxo_breakdown;
+use api::ApiError;
+use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
+use neptune_types::address::KeyType;
 use neptune_types::address::ReceivingAddress;
+use neptune_types::address::SpendingKey;
 use neptune_types::change_policy::ChangePolicy;
 use neptune_types::native_currency_amount::NativeCurrencyAmount;
 use neptune_types::network::Network;
 use neptune_types::output_format::OutputFormat;
-use neptune_types::transaction_details::TransactionDetails;
-use neptune_types::transaction_kernel_id::TransactionKernelId;
+use neptune_types::utxo_notification_medium::UtxoNotificationMedium;
 use num_traits::Zero;
+use strum::IntoEnumIterator;
 
 use crate::components::address::Address;
 use crate::components::amount::Amount;
 use crate::components::amount::AmountType;
 use crate::components::currency_amount_input::CurrencyAmountInput;
 use crate::components::digest_display::DigestDisplay;
+use crate::components::lock_screen::ReauthModal;
 use crate::components::pico::Button;
 use crate::components::pico::ButtonType;
 use crate::components::pico::Card;
@@ -31,11 +56,17 @@ use crate::components::pico::Modal;
 use crate::components::pico::NoTitleModal;
 use crate::components::qr_scanner::QrScanner;
 use crate::components::qr_uploader::QrUploader;
+use crate::components::tx_note::TxNoteEditor;
+use crate::compat;
 use crate::currency::fiat_to_npt;
 use crate::currency::npt_to_fiat;
+use crate::data_store::DataStore;
+use crate::i18n::t;
+use crate::send_queue::SendQueue;
+use crate::send_queue::SendRequest;
 use crate::AppState;
 use crate::AppStateMut;
-use crate::Screen;
+use crate::Route;
 
 static NEXT_RECIPIENT_ID: AtomicU64 = AtomicU64::new(0);
 
@@ -43,6 +74,9 @@ const NPT_MAX_INTEGER_DIGITS: u8 = 8;
 const NPT_MAX_DECIMAL_DIGITS: u8 = 8;
 const FIAT_MAX_INTEGER_DIGITS: u8 = 12;
 
+/// How many trailing characters of an address are re-entered to verify it.
+const ADDRESS_CHECK_LEN: usize = 6;
+
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum InputKind {
     Npt,
@@ -117,6 +151,27 @@ impl SourcedAmount {
             self.as_npt_or_zero(rate).to_string()
         }
     }
+
+    fn to_draft(&self) -> DraftAmount {
+        DraftAmount {
+            value: self.source_value.clone(),
+            fiat: match self.source_kind {
+                InputKind::Npt => None,
+                InputKind::Fiat(fc) => Some(fc),
+            },
+        }
+    }
+
+    /// The caller must ensure that a fiat draft amount is in `rate`'s currency.
+    fn from_draft(draft: &DraftAmount, display_as_fiat: bool, rate: &FiatAmount) -> Self {
+        let mut amount = Self {
+            source_value: draft.value.clone(),
+            source_kind: draft.fiat.map_or(InputKind::Npt, InputKind::Fiat),
+            display_value: String::new(),
+        };
+        amount.display_value = amount.as_needed_or_zero(display_as_fiat, rate);
+        amount
+    }
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -124,6 +179,9 @@ struct EditableRecipient {
     id: u64,
     address_str: String,
     amount: SourcedAmount,
+    /// From a payment request: who is being paid, and what for.
+    label: Option<String>,
+    message: Option<String>,
     address_error: Option<String>,
     amount_error: Option<String>,
 }
@@ -133,6 +191,29 @@ impl EditableRecipient {
         ReceivingAddress::from_bech32m(&self.address_str, network).is_ok()
             && self.amount.as_npt_or_zero(rate) > NativeCurrencyAmount::zero()
     }
+
+    /// Fills in the address, label and message, and the amount if one was
+    /// requested.
+    fn apply_payment_request(
+        &mut self,
+        request: PaymentRequest,
+        display_as_fiat: bool,
+        rate: &FiatAmount,
+    ) {
+        self.address_str = request.address;
+        self.address_error = None;
+        self.label = request.label;
+        self.message = request.message;
+        if let Some(amount) = request.amount {
+            self.amount = SourcedAmount {
+                source_value: amount.to_string(),
+                source_kind: InputKind::Npt,
+                display_value: String::new(),
+            };
+            self.amount.display_value = self.amount.as_needed_or_zero(display_as_fiat, rate);
+            self.amount_error = None;
+        }
+    }
 }
 
 impl Default for EditableRecipient {
@@ -141,12 +222,149 @@ impl Default for EditableRecipient {
             id: NEXT_RECIPIENT_ID.fetch_add(1, Ordering::Relaxed),
             address_str: String::new(),
             amount: SourcedAmount::new(InputKind::Npt),
+            label: None,
+            message: None,
             address_error: None,
             amount_error: None,
         }
     }
 }
 
+/// How many recipients pay an address that an earlier recipient also pays.
+fn duplicate_recipient_count(recipients: &[EditableRecipient]) -> usize {
+    recipients
+        .iter()
+        .enumerate()
+        .filter(|(i, r)| {
+            recipients[..*i]
+                .iter()
+                .any(|e| e.address_str == r.address_str)
+        })
+        .count()
+}
+
+/// Merges the recipients that pay the same address into the first of them,
+/// summing their amounts, so that each address gets a single output.
+///
+/// The sum stays in fiat only if every merged amount was entered in the
+/// same fiat currency.
+fn merge_duplicate_recipients(
+    recipients: Vec<EditableRecipient>,
+    display_as_fiat: bool,
+    rate: &FiatAmount,
+) -> Vec<EditableRecipient> {
+    let mut merged: Vec<EditableRecipient> = vec![];
+    for recipient in recipients {
+        let Some(first) = merged
+            .iter_mut()
+            .find(|m| m.address_str == recipient.address_str)
+        else {
+            merged.push(recipient);
+            continue;
+        };
+
+        first.amount = match (first.amount.source_kind, recipient.amount.source_kind) {
+            (InputKind::Fiat(a), InputKind::Fiat(b)) if a == b => SourcedAmount {
+                source_value: (first.amount.as_fiat_or_zero(rate)
+                    + recipient.amount.as_fiat_or_zero(rate))
+                .to_string(),
+                source_kind: InputKind::Fiat(a),
+                display_value: String::new(),
+            },
+            _ => SourcedAmount {
+                source_value: (first.amount.as_npt_or_zero(rate)
+                    + recipient.amount.as_npt_or_zero(rate))
+                .to_string(),
+                source_kind: InputKind::Npt,
+                display_value: String::new(),
+            },
+        };
+        first.amount.display_value = first.amount.as_needed_or_zero(display_as_fiat, rate);
+        first.label = first.label.take().or(recipient.label);
+        first.message = match (first.message.take(), recipient.message) {
+            (Some(a), Some(b)) if a != b => Some(format!("{a}; {b}")),
+            (a, b) => a.or(b),
+        };
+    }
+    merged
+}
+
+/// True if `entered` matches the last `ADDRESS_CHECK_LEN` characters of
+/// `address`.
+fn address_tail_matches(address: &str, entered: &str) -> bool {
+    let address = address.trim();
+    address
+        .len()
+        .checked_sub(ADDRESS_CHECK_LEN)
+        .and_then(|start| address.get(start..))
+        .is_some_and(|tail| tail.eq_ignore_ascii_case(entered.trim()))
+}
+
+/// What the user can do about a failed send, if anything specific.
+fn send_error_hint(err: &ApiError) -> Option<String> {
+    match err {
+        ApiError::InsufficientBalance(_) => Some(t!("send-error-insufficient")),
+        ApiError::ConnectionLost(_) => Some(t!("send-error-connection")),
+        ApiError::Timeout(_) => Some(t!("send-error-timeout")),
+        ApiError::InvalidAddress(_) => Some(t!("send-error-invalid-address")),
+        ApiError::AuthFailed(_) => Some(t!("send-error-auth")),
+        ApiError::CookieUnavailable(_) => Some(t!("send-error-cookie")),
+        ApiError::BroadcastUnknown(_) => Some(t!("send-error-broadcast-unknown")),
+        ApiError::Internal(_) => None,
+    }
+}
+
+/// The heading of a failed send, by what went wrong.
+fn send_failure_title(err: &ApiError) -> String {
+    if err.is_broadcast_unknown() {
+        t!("send-failure-broadcast-unknown")
+    } else if err.is_transient() {
+        t!("send-failure-connection")
+    } else if err.is_insufficient_balance() {
+        t!("send-failure-funds")
+    } else if err.is_invalid_address() {
+        t!("send-failure-invalid")
+    } else {
+        t!("send-error-title")
+    }
+}
+
+/// Why an entered address cannot be paid, for showing under it.
+fn address_problem_message(problem: &AddressProblem) -> String {
+    match problem {
+        AddressProblem::Empty => t!("send-address-empty"),
+        AddressProblem::Truncated => t!("send-address-truncated"),
+        AddressProblem::Malformed => t!("send-address-malformed"),
+        AddressProblem::Checksum => t!("send-address-checksum"),
+        AddressProblem::WrongNetwork { prefix, expected } => t!(
+            "send-address-wrong-network",
+            prefix = prefix.as_str(),
+            network = expected.as_str()
+        ),
+        AddressProblem::UnsupportedKeyType { prefix } => {
+            t!("send-address-unsupported", prefix = prefix.as_str())
+        }
+    }
+}
+
+/// Shows the label and message of a payment request, if any.
+#[component]
+fn PaymentRequestNote(label: Option<String>, message: Option<String>) -> Element {
+    rsx! {
+        if label.is_some() || message.is_some() {
+            small {
+                style: "display: block; color: var(--pico-muted-color);",
+                if let Some(label) = &label {
+                    strong { "{label}" }
+                }
+                if let Some(message) = &message {
+                    " {message}"
+                }
+            }
+        }
+    }
+}
+
 #[component]
 #[allow(clippy::too_many_arguments)]
 fn EditableRecipientRow(
@@ -203,13 +421,13 @@ fn EditableRecipientRow(
 
     let (amount_label, max_integers, max_decimals) = if !display_as_fiat {
         (
-            "Amount (NPT)".to_string(),
+            t!("send-amount-npt"),
             NPT_MAX_INTEGER_DIGITS,
             NPT_MAX_DECIMAL_DIGITS,
         )
     } else {
         (
-            format!("Amount ({})", fiat_currency.code()),
+            t!("send-amount-fiat", currency = fiat_currency.code()),
             FIAT_MAX_INTEGER_DIGITS,
             fiat_currency.decimals(),
         )
@@ -226,7 +444,7 @@ fn EditableRecipientRow(
                         style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 0.5rem;",
                         label {
                             style: "margin-bottom: 0; align-self: flex-end;",
-                            "Recipient Address"
+                            {t!("send-recipient-address")}
                         }
                         div {
                             style: "display: flex; gap: 0.5rem;",
@@ -238,7 +456,7 @@ fn EditableRecipientRow(
                                 },
                                 disabled: !recipient.read().is_valid(network, &rate),
                                 style: "padding-top: 0.25rem; padding-bottom: 0.25rem;".to_string(),
-                                "Done"
+                                {t!("send-done")}
                             }
                             if can_delete {
                                 CloseButton {
@@ -255,13 +473,17 @@ fn EditableRecipientRow(
                             input {
                                 class: "pico-input",
                                 r#type: "text",
-                                placeholder: "Click to paste or scan an address...",
+                                placeholder: t!("send-address-placeholder"),
                                 value: "{display_address}",
                                 readonly: true,
                                 onclick: move |_| on_open_address_actions.call(index),
                                 style: "cursor: pointer;",
                             }
                         }
+                        PaymentRequestNote {
+                            label: recipient.read().label.clone(),
+                            message: recipient.read().message.clone(),
+                        }
                         if let Some(err) = &recipient.read().address_error {
                             small {
                                 style: "color: var(--pico-color-red-500);",
@@ -289,10 +511,10 @@ fn EditableRecipientRow(
                                 if show_fiat_toggle {
                                     Button {
                                         button_type: ButtonType::Secondary,
-                                        title: format!(
-                                            "Toggle between Neptune Cash (NPT) and {} ({})",
-                                            fiat_currency.name(),
-                                            fiat_currency.code().to_string(),
+                                        title: t!(
+                                            "send-toggle-currency",
+                                            name = fiat_currency.name(),
+                                            code = fiat_currency.code(),
                                         ),
                                         outline: true,
                                         style: "width: 5rem; margin-bottom: 0; flex-shrink: 0;",
@@ -333,6 +555,10 @@ fn EditableRecipientRow(
                                 "{display_address}"
                             }
                         }
+                        PaymentRequestNote {
+                            label: recipient.read().label.clone(),
+                            message: recipient.read().message.clone(),
+                        }
                     }
                     div {
                         style: "text-align: right; margin: 0 1rem; white-space: nowrap;",
@@ -354,7 +580,7 @@ fn EditableRecipientRow(
                             outline: true,
                             on_click: move |_| on_set_active.call(index),
                             disabled: is_any_other_row_active,
-                            "Edit"
+                            {t!("send-edit")}
                         }
                         if can_delete {
                             CloseButton {
@@ -368,8 +594,631 @@ fn EditableRecipientRow(
     }
 }
 
+/// Lists saved drafts, to resume or delete them.
+#[component]
+fn DraftsModal(is_open: Signal<bool>, on_resume: EventHandler<SendDraft>) -> Element {
+    let mut drafts = use_resource(move || async move {
+        // reload each time the modal is opened.
+        if is_open() {
+            Some(api::send_drafts().await)
+        } else {
+            None
+        }
+    });
+    let mut delete_error = use_signal::<Option<String>>(|| None);
+
+    rsx! {
+        Modal {
+            is_open,
+            title: t!("send-saved-drafts"),
+            match &*drafts.read() {
+                Some(Some(Ok(list))) if list.is_empty() => rsx! {
+                    p { {t!("send-no-drafts")} }
+                },
+                Some(Some(Ok(list))) => rsx! {
+                    table {
+                        role: "grid",
+                        tbody {
+                            for draft in list.iter().cloned() {
+                                {
+                                    let name = draft.name.clone();
+                                    let delete_name = draft.name.clone();
+                                    let date_time_format = format!("{} %H:%M", crate::i18n::date_format());
+                                    let saved_at = chrono::DateTime::from_timestamp_millis(draft.saved_at_ms as i64)
+                                        .map(|d| d.with_timezone(&chrono::Local).format(&date_time_format).to_string())
+                                        .unwrap_or_default();
+                                    let num_recipients = draft.recipients.len();
+                                    rsx! {
+                                        tr {
+                                            key: "{name}",
+                                            td {
+                                                strong { "{name}" }
+                                                small {
+                                                    style: "display: block; color: var(--pico-muted-color);",
+                                                    {t!("send-draft-summary", recipients = num_recipients, saved = saved_at.clone())}
+                                                }
+                                            }
+                                            td {
+                                                style: "text-align: right; white-space: nowrap;",
+                                                Button {
+                                                    style: "margin: 0 0.5rem 0 0;",
+                                                    on_click: move |_| on_resume.call(draft.clone()),
+                                                    {t!("send-resume")}
+                                                }
+                                                Button {
+                                                    button_type: ButtonType::Secondary,
+                                                    outline: true,
+                                                    style: "margin: 0;",
+                                                    on_click: move |_| {
+                                                        let name = delete_name.clone();
+                                                        spawn(async move {
+                                                            match api::delete_send_draft(name).await {
+                                                                Ok(()) => delete_error.set(None),
+                                                                Err(e) => delete_error.set(Some(e.to_string())),
+                                                            }
+                                                            drafts.restart();
+                                                        });
+                                                    },
+                                                    {t!("common-delete")}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Some(Err(e))) => rsx! {
+                    p { {t!("send-drafts-load-failed", error = e.to_string())} }
+                },
+                _ => rsx! {
+                    progress {}
+                },
+            }
+            if let Some(err) = delete_error() {
+                small {
+                    style: "color: var(--pico-color-red-500); display: block;",
+                    "{err}"
+                }
+            }
+            footer {
+                Button {
+                    on_click: move |_| is_open.set(false),
+                    {t!("common-close")}
+                }
+            }
+        }
+    }
+}
+
+/// Lists the recently paid addresses, for picking one as a recipient.
+/// Reloads whenever `is_open` becomes true.
+#[component]
+fn RecentRecipients(is_open: Signal<bool>, on_pick: EventHandler<RecentRecipient>) -> Element {
+    let network = use_context::<AppState>().network;
+    let mut recent = use_resource(move || async move {
+        if is_open() {
+            api::recent_recipients().await.ok()
+        } else {
+            None
+        }
+    });
+
+    let list = recent.read().clone().flatten().unwrap_or_default();
+    if list.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        details {
+            summary { {t!("send-recent-recipients")} }
+            table {
+                role: "grid",
+                tbody {
+                    for recipient in list {
+                        {
+                            let address = recipient.address.clone();
+                            let forget_address = recipient.address.clone();
+                            let label = recipient.label.clone();
+                            let abbreviated = ReceivingAddress::from_bech32m(&address, network)
+                                .ok()
+                                .and_then(|a| a.to_display_bech32m_abbreviated(network).ok())
+                                .unwrap_or_else(|| address.clone());
+                            let last_used = chrono::DateTime::from_timestamp_millis(recipient.last_used_ms as i64)
+                                .map(|d| d.with_timezone(&chrono::Local).format(crate::i18n::date_format()).to_string())
+                                .unwrap_or_default();
+                            rsx! {
+                                tr {
+                                    key: "{address}",
+                                    td {
+                                        style: "cursor: pointer;",
+                                        role: "button",
+                                        tabindex: "0",
+                                        onclick: {
+                                            let recipient = recipient.clone();
+                                            move |_| on_pick.call(recipient.clone())
+                                        },
+                                        onkeydown: move |evt| {
+                                            if evt.key() == Key::Enter {
+                                                on_pick.call(recipient.clone());
+                                            }
+                                        },
+                                        if let Some(label) = &label {
+                                            strong { "{label}" }
+                                            br {}
+                                        }
+                                        code { "{abbreviated}" }
+                                        small {
+                                            style: "display: block; color: var(--pico-muted-color);",
+                                            {t!("send-recent-last-used", date = last_used)}
+                                        }
+                                    }
+                                    td {
+                                        style: "text-align: right; width: 1%;",
+                                        CloseButton {
+                                            title: t!("send-recent-forget"),
+                                            on_click: move |_| {
+                                                let address = forget_address.clone();
+                                                spawn(async move {
+                                                    let _ = api::forget_recent_recipient(address).await;
+                                                    recent.restart();
+                                                });
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The `<select>` value of a change policy.  Provided keys are identified by
+/// their position in `keys`.
+fn change_choice(policy: &ChangePolicy, keys: &[SpendingKey]) -> String {
+    match policy {
+        ChangePolicy::RecoverToNextUnusedKey { key_type, .. } if key_type.is_symmetric() => {
+            "next-symmetric".to_string()
+        }
+        ChangePolicy::RecoverToNextUnusedKey { .. } => "next-generation".to_string(),
+        ChangePolicy::RecoverToProvidedKey { key, .. } => keys
+            .iter()
+            .position(|k| k.to_address() == key.to_address())
+            .map(|i| format!("key-{}", i))
+            .unwrap_or_default(),
+        ChangePolicy::ExactChange => "exact".to_string(),
+        // burning the change is not offered.
+        _ => String::new(),
+    }
+}
+
+/// Advanced options for where the change of a send goes.  Change is always
+/// announced on-chain, so that it can be recovered from the seed phrase.
+#[component]
+fn ChangePolicyOptions(change_policy: Signal<ChangePolicy>) -> Element {
+    let app_state = use_context::<AppState>();
+    let network = app_state.network;
+    // the keys hold secrets, so only those who may export them can pick one.
+    let can_export_secret = app_state.capabilities.can_export_secret;
+    let known_keys = use_resource(move || async move {
+        if can_export_secret {
+            api::known_keys().await
+        } else {
+            Ok(vec![])
+        }
+    });
+    let keys = known_keys
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok().cloned())
+        .unwrap_or_default();
+    let selected = change_choice(&change_policy.read(), &keys);
+
+    let choose = {
+        let keys = keys.clone();
+        move |e: FormEvent| {
+            let value = e.value();
+            let policy = match value.as_str() {
+                "next-symmetric" => ChangePolicy::RecoverToNextUnusedKey {
+                    key_type: KeyType::Symmetric,
+                    medium: UtxoNotificationMedium::OnChain,
+                },
+                "next-generation" => ChangePolicy::RecoverToNextUnusedKey {
+                    key_type: KeyType::Generation,
+                    medium: UtxoNotificationMedium::OnChain,
+                },
+                "exact" => ChangePolicy::ExactChange,
+                other => {
+                    let Some(key) = other
+                        .strip_prefix("key-")
+                        .and_then(|i| i.parse::<usize>().ok())
+                        .and_then(|i| keys.get(i))
+                    else {
+                        return;
+                    };
+                    ChangePolicy::RecoverToProvidedKey {
+                        key: Arc::new(key.clone()),
+                        medium: UtxoNotificationMedium::OnChain,
+                    }
+                }
+            };
+            change_policy.set(policy);
+        }
+    };
+
+    rsx! {
+        details {
+            style: "margin-top: 1.5rem;",
+            summary { {t!("send-advanced-options")} }
+            label {
+                {t!("send-change-title")}
+                select {
+                    onchange: choose,
+                    option {
+                        value: "next-symmetric",
+                        selected: selected == "next-symmetric",
+                        {t!("send-change-next-symmetric")}
+                    }
+                    option {
+                        value: "next-generation",
+                        selected: selected == "next-generation",
+                        {t!("send-change-next-generation")}
+                    }
+                    for (i, key) in keys.iter().enumerate() {
+                        {
+                            let value = format!("key-{}", i);
+                            let address = key
+                                .to_address()
+                                .to_bech32m_abbreviated(network)
+                                .unwrap_or_default();
+                            rsx! {
+                                option {
+                                    key: "{value}",
+                                    selected: selected == value,
+                                    value: "{value}",
+                                    {t!("send-change-existing", address = address)}
+                                }
+                            }
+                        }
+                    }
+                    option {
+                        value: "exact",
+                        selected: selected == "exact",
+                        {t!("send-change-exact")}
+                    }
+                }
+            }
+            small {
+                style: "color: var(--pico-muted-color);",
+                {t!("send-change-intro")}
+            }
+        }
+    }
+}
+
+/// Advanced option to attach a public announcement to the transaction.
+/// Leaving the message empty sends without one.
+#[component]
+fn AnnouncementOptions(
+    mut encoding: Signal<AnnouncementEncoding>,
+    mut message_input: Signal<String>,
+) -> Element {
+    let parsed = (!message_input.read().trim().is_empty())
+        .then(|| announcement_input::parse(&message_input.read(), encoding()));
+
+    rsx! {
+        details {
+            open: parsed.is_some(),
+            summary { {t!("send-announcement-title")} }
+            small {
+                style: "color: var(--pico-muted-color); display: block; margin-bottom: 0.5rem;",
+                {t!("send-announcement-intro")}
+            }
+            label {
+                {t!("send-announcement-encoding")}
+                select {
+                    onchange: move |e| {
+                        if let Some(enc) = AnnouncementEncoding::iter().find(|enc| enc.name() == e.value()) {
+                            encoding.set(enc);
+                        }
+                    },
+                    for enc in AnnouncementEncoding::iter() {
+                        option {
+                            key: "{enc.name()}",
+                            value: "{enc.name()}",
+                            selected: enc == encoding(),
+                            "{enc.name()}"
+                        }
+                    }
+                }
+            }
+            label {
+                {t!("send-announcement-message")}
+                textarea {
+                    rows: 3,
+                    style: "font-family: monospace;",
+                    placeholder: match encoding() {
+                        AnnouncementEncoding::Hex => "0x01 0x2a ff",
+                        AnnouncementEncoding::Utf8 => "hello",
+                    },
+                    "aria-invalid": match &parsed {
+                        Some(Err(_)) => "true",
+                        _ => "",
+                    },
+                    value: "{message_input}",
+                    oninput: move |e| message_input.set(e.value()),
+                }
+                match &parsed {
+                    Some(Ok(message)) => rsx! {
+                        small {
+                            {t!("send-announcement-elements", count = message.len(), max = MAX_ANNOUNCEMENT_ELEMENTS)}
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        small { "{e}" }
+                    },
+                    None => rsx! {},
+                }
+            }
+        }
+    }
+}
+
+/// How often the progress of a send is polled.
+const SEND_PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+fn send_stage_label(stage: SendStage) -> String {
+    match stage {
+        SendStage::SelectingInputs => t!("send-stage-selecting-inputs"),
+        SendStage::Building => t!("send-stage-building"),
+        SendStage::Proving => t!("send-stage-proving"),
+        SendStage::Broadcasting => t!("send-stage-broadcasting"),
+    }
+}
+
+/// Minutes and seconds, eg `2:05`.
+fn format_elapsed(secs: u64) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// The stages of the send in flight, polled from the server until the send
+/// returns and this is unmounted.
+#[component]
+fn SendProgressCard() -> Element {
+    let store = use_context::<DataStore>();
+    let mut progress = use_signal::<Option<SendProgress>>(|| None);
+
+    use_future(move || async move {
+        loop {
+            if let Ok(current) = api::send_progress().await {
+                progress.set(current);
+            }
+            compat::sleep(SEND_PROGRESS_INTERVAL).await;
+        }
+    });
+
+    let proving_capability = match &*store.dashboard.read() {
+        Some(Ok(data)) => Some(data.proving_capability.to_string()),
+        _ => None,
+    };
+    let current = progress();
+
+    rsx! {
+        Card {
+            h3 { {t!("send-sending")} }
+            p { {t!("send-please-wait")} }
+            ol {
+                "aria-live": "polite",
+                style: "list-style: none; padding-left: 0;",
+                for stage in SendStage::iter() {
+                    {
+                        let (marker, style) = match &current {
+                            Some(p) if stage < p.stage => ("✓", "color: var(--pico-muted-color);"),
+                            Some(p) if stage == p.stage => ("●", "font-weight: bold;"),
+                            _ => ("○", "color: var(--pico-muted-color);"),
+                        };
+                        let is_current = current.as_ref().is_some_and(|p| p.stage == stage);
+                        rsx! {
+                            li {
+                                key: "{stage:?}",
+                                style,
+                                "aria-current": if is_current { "step" } else { "false" },
+                                "{marker} {send_stage_label(stage)}"
+                                if let Some(p) = current.as_ref().filter(|_| is_current) {
+                                    span {
+                                        style: "font-weight: normal; color: var(--pico-muted-color); margin-left: 0.5rem;",
+                                        "{format_elapsed(p.stage_elapsed_secs)}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            progress {}
+            if let Some(p) = current.as_ref().filter(|p| p.attempt > 1) {
+                p {
+                    style: "color: var(--pico-muted-color);",
+                    {t!("send-stage-retrying", attempt = p.attempt)}
+                }
+            }
+            if current.as_ref().is_some_and(|p| p.stage == SendStage::Proving) {
+                small {
+                    style: "color: var(--pico-muted-color);",
+                    {t!("send-stage-proving-hint")}
+                    if let Some(capability) = proving_capability {
+                        " "
+                        {t!("send-stage-proving-capability", capability = capability)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What of the balance a send can spend now, and what is time-locked until
+/// when, so that a short balance is no surprise.
+#[component]
+fn SpendableSummary() -> Element {
+    let summary = use_resource(move || async move {
+        let (utxos, annotations) = futures::join!(api::list_utxos(), api::utxo_annotations());
+        let now_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+        Some(utxo_breakdown::breakdown(
+            &utxos.ok()?,
+            &annotations.unwrap_or_default(),
+            now_ms,
+        ))
+    });
+
+    let Some(Some(breakdown)) = summary() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            role: "status",
+            style: "margin: 0 0.5rem 0.5rem 0.5rem; font-size: 0.9rem;",
+            strong { {t!("send-spendable-now")} }
+            " "
+            Amount { amount: breakdown.available }
+            if let Some((release, amount)) = breakdown.next_release {
+                small {
+                    style: "display: block; color: var(--pico-muted-color);",
+                    {t!("send-spendable-time-locked")}
+                    " "
+                    Amount { amount: breakdown.time_locked }
+                    " · "
+                    {t!("send-spendable-next-release", date = release.format(crate::i18n::date_format()))}
+                    " "
+                    Amount { amount }
+                }
+            }
+            if !breakdown.do_not_spend.is_zero() {
+                small {
+                    style: "display: block; color: var(--pico-muted-color);",
+                    {t!("send-spendable-do-not-spend")}
+                    " "
+                    Amount { amount: breakdown.do_not_spend }
+                }
+            }
+        }
+    }
+}
+
+/// Advanced option to spend UTXOs chosen by hand, eg to keep some apart.
+/// With none chosen, the wallet picks them.  UTXOs marked do-not-spend cannot
+/// be chosen.
+///
+/// `chosen` holds the amount of each chosen UTXO, by AOCL leaf index.
+#[component]
+fn CoinControlOptions(
+    mut chosen: Signal<BTreeMap<u64, NativeCurrencyAmount>>,
+    needed: NativeCurrencyAmount,
+) -> Element {
+    let data = use_resource(move || async move {
+        let (utxos, annotations) = futures::join!(api::list_utxos(), api::utxo_annotations());
+        Some((utxos.ok()?, annotations.unwrap_or_default()))
+    });
+
+    let Some(Some((utxos, annotations))) = data() else {
+        return rsx! {};
+    };
+    let now_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+    let mut spendable: Vec<_> = utxos
+        .into_iter()
+        .filter(|u| u.aocl_leaf_index.is_some() && utxo_breakdown::is_spendable_now(u, now_ms))
+        .collect();
+    spendable.sort_by_key(|u| std::cmp::Reverse(u.amount));
+    let chosen_total = chosen
+        .read()
+        .values()
+        .fold(NativeCurrencyAmount::zero(), |acc, amount| acc + *amount);
+
+    rsx! {
+        details {
+            style: "margin-top: 1.5rem;",
+            open: !chosen.read().is_empty(),
+            summary { {t!("send-coin-control")} }
+            small {
+                style: "display: block; color: var(--pico-muted-color); margin-bottom: 0.5rem;",
+                {t!("send-coin-control-intro")}
+            }
+            if spendable.is_empty() {
+                p { {t!("send-coin-control-none")} }
+            }
+            div {
+                style: "max-height: 12rem; overflow-y: auto;",
+                for utxo in spendable {
+                    {
+                        let index = utxo.aocl_leaf_index.unwrap_or_default();
+                        let amount = utxo.amount;
+                        let annotation = annotations.get(&index).cloned().unwrap_or_default();
+                        rsx! {
+                            label {
+                                key: "{index}",
+                                style: if annotation.do_not_spend { "color: var(--pico-muted-color);" } else { "" },
+                                input {
+                                    r#type: "checkbox",
+                                    checked: chosen.read().contains_key(&index),
+                                    disabled: annotation.do_not_spend,
+                                    onchange: move |_| {
+                                        chosen.with_mut(|chosen| {
+                                            if chosen.remove(&index).is_none() {
+                                                chosen.insert(index, amount);
+                                            }
+                                        })
+                                    },
+                                }
+                                Amount { amount }
+                                small { " #{index}" }
+                                if annotation.do_not_spend {
+                                    span {
+                                        title: t!("send-coin-control-do-not-spend"),
+                                        " 🔒"
+                                    }
+                                }
+                                if let Some(label) = annotation.label {
+                                    small { " · {label}" }
+                                }
+                                for tag in annotation.tags {
+                                    small {
+                                        key: "{tag}",
+                                        style: "margin-left: 0.25rem; padding: 0 0.3rem; border: 1px solid var(--pico-muted-border-color); border-radius: var(--pico-border-radius); color: var(--pico-muted-color);",
+                                        "{tag}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if !chosen.read().is_empty() {
+                p {
+                    style: "margin: 0.5rem 0 0 0;",
+                    {t!("send-coin-control-chosen", count = chosen.read().len())}
+                    " "
+                    Amount { amount: chosen_total }
+                }
+                if chosen_total < needed {
+                    small {
+                        style: "display: block; color: var(--pico-del-color);",
+                        {t!("send-coin-control-short")}
+                        " "
+                        Amount { amount: needed - chosen_total }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `open_drafts` shows the saved drafts on arrival.
 #[component]
-pub fn SendScreen() -> Element {
+pub fn SendScreen(#[props(default)] open_drafts: bool) -> Element {
     let app_state = use_context::<AppState>();
     let mut app_state_mut = use_context::<AppStateMut>();
     let network = app_state.network;
@@ -405,9 +1254,31 @@ pub fn SendScreen() -> Element {
         Status,
     }
     let mut wizard_step = use_signal(|| WizardStep::AddRecipients);
-    let mut api_response = use_signal::<
-        Option<Result<(TransactionKernelId, TransactionDetails), api::ApiError>>,
-    >(|| None);
+    let send_queue = use_context::<SendQueue>();
+    // the background send shown on the Status step.
+    let mut send_job_id = use_signal::<Option<u64>>(|| None);
+    let current_job = send_job_id().and_then(|id| {
+        send_queue
+            .jobs
+            .read()
+            .iter()
+            .find(|j| j.id == id)
+            .cloned()
+    });
+    // an outcome shown here needs no pointing out in the header.
+    use_effect(move || {
+        let Some(id) = send_job_id() else {
+            return;
+        };
+        let is_unseen = send_queue
+            .jobs
+            .read()
+            .iter()
+            .any(|j| j.id == id && !j.seen && !j.status.is_in_flight());
+        if is_unseen {
+            send_queue.mark_seen(vec![id]);
+        }
+    });
     let mut recipients = use_signal(move || {
         let initial_kind = if display_as_fiat {
             InputKind::Fiat(fiat_currency)
@@ -435,10 +1306,33 @@ pub fn SendScreen() -> Element {
     let mut show_error_modal = use_signal(|| false);
     let mut error_modal_message = use_signal(String::new);
     let mut show_duplicate_warning_modal = use_signal(|| false);
-    let mut suppress_duplicate_warning = use_signal(|| false);
-    let mut pending_address = use_signal::<Option<String>>(|| None);
+    let mut dont_ask_duplicate_again = use_signal(|| false);
+    let mut show_large_send_modal = use_signal(|| false);
+    let mut dont_ask_large_send_again = use_signal(|| false);
+    let show_reauth_modal = use_signal(|| false);
+    // the address endings re-entered on the Review step, by recipient.
+    let mut address_checks = use_signal(Vec::<String>::new);
+    let mut pending_address = use_signal::<Option<PaymentRequest>>(|| None);
     let mut fee_error = use_signal::<Option<String>>(|| None);
+    // set when the total exceeds what can be spent, checked before Review.
+    let mut funds_shortfall =
+        use_signal::<Option<(NativeCurrencyAmount, SpendableBalance)>>(|| None);
+    let mut is_checking_funds = use_signal(|| false);
     let popup_slot = use_signal::<Option<Element>>(|| None);
+    let mut change_policy = use_signal(ChangePolicy::default);
+    // the UTXOs to spend, if chosen on the Review step.
+    let mut chosen_inputs = use_signal(BTreeMap::<u64, NativeCurrencyAmount>::new);
+    // an optional public announcement, entered on the Review step.
+    let mut announcement_encoding = use_signal(AnnouncementEncoding::default);
+    let mut announcement_message = use_signal(String::new);
+    let mut is_drafts_modal_open = use_signal(|| open_drafts);
+    let mut is_save_draft_modal_open = use_signal(|| false);
+    let mut draft_name = use_signal(String::new);
+    let mut save_draft_error = use_signal::<Option<String>>(|| None);
+    // the draft this send was resumed from, deleted once the send succeeds.
+    let mut resumed_draft = use_signal::<Option<String>>(|| None);
+    // used to warn before sending most of the balance.
+    let wallet_balance = use_resource(move || async move { api::wallet_balance().await });
 
     let is_any_row_active = use_memo(move || active_row_index().is_some());
     let are_recipients_valid = {
@@ -487,37 +1381,155 @@ pub fn SendScreen() -> Element {
         active_row_index.set(Some(0));
         fee_input.set(SourcedAmount::new(initial_kind));
         fee_error.set(None);
-        api_response.set(None);
-        suppress_duplicate_warning.set(false);
+        funds_shortfall.set(None);
+        send_job_id.set(None);
+        change_policy.set(ChangePolicy::default());
+        chosen_inputs.set(BTreeMap::new());
+        announcement_encoding.set(AnnouncementEncoding::default());
+        announcement_message.set(String::new());
+        draft_name.set(String::new());
+        resumed_draft.set(None);
+        address_checks.set(vec![]);
         wizard_step.set(WizardStep::AddRecipients);
     };
 
-    let mut active_screen = use_context::<Signal<Screen>>();
 
-    let mut handle_scanned_data = move |scanned_text: String| {
+    // read on demand, so that the handlers below remain Copy.
+    let current_rate = move || {
+        app_state_mut
+            .prices
+            .read()
+            .as_ref()
+            .and_then(|p| p.get(fiat_currency))
+            .unwrap_or_else(|| FiatAmount::new_from_minor(0, fiat_currency))
+    };
+
+    let mut handle_payment_request = move |request: PaymentRequest| {
         if let Some(index) = action_target_index() {
-            if ReceivingAddress::from_bech32m(&scanned_text, network).is_ok() {
+            if let Err(problem) = validate_address(&request.address, network) {
+                // keep what was entered, so the reason can be shown with it.
+                if let Some(mut target_recipient) = recipients.read().get(index).copied() {
+                    target_recipient.with_mut(|r| {
+                        r.address_str = request.address;
+                        r.address_error = Some(address_problem_message(&problem));
+                    });
+                }
+            } else {
                 let is_duplicate = recipients
                     .read()
                     .iter()
                     .enumerate()
-                    .any(|(i, r)| i != index && r.read().address_str == scanned_text);
-                if is_duplicate && !suppress_duplicate_warning() {
-                    pending_address.set(Some(scanned_text));
+                    .any(|(i, r)| i != index && r.read().address_str == request.address);
+                if is_duplicate && !app_state_mut.is_suppressed(Confirmation::DuplicateAddress) {
+                    pending_address.set(Some(request));
                     show_duplicate_warning_modal.set(true);
                 } else if let Ok(mut recs) = recipients.try_write() {
                     if let Some(target_recipient) = recs.get_mut(index) {
                         target_recipient.with_mut(|r| {
-                            r.address_str = scanned_text;
-                            r.address_error = None;
+                            r.apply_payment_request(request, display_as_fiat, &current_rate());
                         });
                     }
                 }
-            } else {
-                error_modal_message.set("Invalid Address from QR.".to_string());
+            }
+        }
+    };
+
+    let mut handle_scanned_data = move |scanned_text: String| {
+        // accept either a plain address or a payment request URI.
+        let request = match PaymentRequest::parse(&scanned_text) {
+            Ok(request) => request,
+            Err(ParsePaymentRequestError::NotAPaymentRequest) => {
+                PaymentRequest::new(scanned_text.trim().to_string())
+            }
+            Err(e) => {
+                error_modal_message.set(t!("send-invalid-payment-request", error = e.to_string()));
                 show_error_modal.set(true);
+                return;
             }
+        };
+        handle_payment_request(request);
+    };
+
+    let mut resume_draft = move |draft: SendDraft| {
+        // fiat amounts can only be converted at the rate of the selected currency.
+        let foreign_fiat = draft
+            .recipients
+            .iter()
+            .map(|r| &r.amount)
+            .chain([&draft.fee])
+            .find_map(|a| a.fiat)
+            .filter(|fc| !fiat_mode_active || *fc != fiat_currency);
+        if let Some(fc) = foreign_fiat {
+            error_modal_message.set(t!("send-draft-foreign-currency", currency = fc.code()));
+            show_error_modal.set(true);
+            return;
+        }
+
+        let rate = current_rate();
+        let mut recs: Vec<Signal<EditableRecipient>> = draft
+            .recipients
+            .iter()
+            .map(|r| {
+                Signal::new(EditableRecipient {
+                    address_str: r.address.clone(),
+                    address_error: validate_address(&r.address, network)
+                        .err()
+                        .map(|p| address_problem_message(&p)),
+                    amount: SourcedAmount::from_draft(&r.amount, display_as_fiat, &rate),
+                    label: r.label.clone(),
+                    message: r.message.clone(),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        if recs.is_empty() {
+            recs.push(Signal::new(EditableRecipient::default()));
         }
+        recipients.set(recs);
+        fee_input.set(SourcedAmount::from_draft(&draft.fee, display_as_fiat, &rate));
+        fee_error.set(None);
+        funds_shortfall.set(None);
+        change_policy.set(draft.change_policy);
+        chosen_inputs.set(BTreeMap::new());
+        active_row_index.set(None);
+        send_job_id.set(None);
+        draft_name.set(draft.name.clone());
+        resumed_draft.set(Some(draft.name));
+        is_drafts_modal_open.set(false);
+        wizard_step.set(WizardStep::AddRecipients);
+    };
+
+    let save_draft = move |_: MouseEvent| {
+        let draft = SendDraft {
+            name: draft_name(),
+            recipients: recipients
+                .read()
+                .iter()
+                .map(|rs| {
+                    let r = rs.read();
+                    DraftRecipient {
+                        address: r.address_str.clone(),
+                        amount: r.amount.to_draft(),
+                        label: r.label.clone(),
+                        message: r.message.clone(),
+                    }
+                })
+                .collect(),
+            fee: fee_input.read().to_draft(),
+            change_policy: change_policy(),
+            saved_at_ms: 0,
+        };
+        spawn(async move {
+            let name = draft.name.trim().to_string();
+            match api::save_send_draft(draft).await {
+                Ok(()) => {
+                    resumed_draft.set(Some(name));
+                    save_draft_error.set(None);
+                    is_save_draft_modal_open.set(false);
+                }
+                Err(e) => save_draft_error.set(Some(e.to_string())),
+            }
+        });
     };
 
     fn kinds_match(kind1: InputKind, kind2: InputKind) -> bool {
@@ -552,7 +1564,7 @@ pub fn SendScreen() -> Element {
 
                         match r.amount.as_npt(&rate) {
                             Ok(amt) if amt.is_zero() && !r.amount.source_value.is_empty() => {
-                                r.amount_error = Some("Amount must be > 0.".to_string())
+                                r.amount_error = Some(t!("send-amount-positive"))
                             }
                             Ok(_) => r.amount_error = None,
                             Err(e) if !r.amount.source_value.is_empty() => r.amount_error = Some(e),
@@ -590,6 +1602,48 @@ pub fn SendScreen() -> Element {
     rsx! {
         {popup_slot()}
 
+        DraftsModal {
+            is_open: is_drafts_modal_open,
+            on_resume: move |draft| resume_draft(draft),
+        }
+
+        Modal {
+            is_open: is_save_draft_modal_open,
+            title: t!("send-save-draft"),
+            label {
+                {t!("send-draft-name")}
+                input {
+                    r#type: "text",
+                    value: "{draft_name}",
+                    placeholder: t!("send-draft-name-placeholder"),
+                    oninput: move |e| draft_name.set(e.value()),
+                }
+            }
+            small {
+                style: "display: block; color: var(--pico-muted-color);",
+                {t!("send-draft-replaced")}
+            }
+            if let Some(err) = save_draft_error() {
+                small {
+                    style: "color: var(--pico-color-red-500); display: block;",
+                    "{err}"
+                }
+            }
+            footer {
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    on_click: move |_| is_save_draft_modal_open.set(false),
+                    {t!("common-cancel")}
+                }
+                Button {
+                    disabled: draft_name.read().trim().is_empty(),
+                    on_click: save_draft,
+                    {t!("common-save")}
+                }
+            }
+        }
+
         NoTitleModal {
             is_open: is_address_actions_modal_open,
             div {
@@ -597,16 +1651,16 @@ pub fn SendScreen() -> Element {
                 h3 {
 
 
-                    "Set Address"
+                    {t!("send-set-address")}
                 }
                 p {
 
 
                     {
                         if let Some(index) = action_target_index() {
-                            format!("Choose an action for recipient number {}.", index + 1)
+                            t!("send-choose-action-for", number = index + 1)
                         } else {
-                            "Choose an action.".to_string()
+                            t!("send-choose-action")
                         }
                     }
                 }
@@ -621,27 +1675,36 @@ pub fn SendScreen() -> Element {
                         }
                         is_address_actions_modal_open.set(false);
                     },
-                    "Paste Address"
+                    {t!("send-paste-address")}
                 }
                 Button {
                     on_click: move |_| {
                         is_address_actions_modal_open.set(false);
                         is_qr_scanner_modal_open.set(true);
                     },
-                    "Scan QR Code"
+                    {t!("send-scan-qr")}
                 }
                 Button {
                     on_click: move |_| {
                         is_address_actions_modal_open.set(false);
                         is_qr_upload_modal_open.set(true);
                     },
-                    "Upload QR Image"
+                    {t!("send-upload-qr")}
+                }
+                RecentRecipients {
+                    is_open: is_address_actions_modal_open,
+                    on_pick: move |recent: RecentRecipient| {
+                        let mut request = PaymentRequest::new(recent.address);
+                        request.label = recent.label;
+                        handle_payment_request(request);
+                        is_address_actions_modal_open.set(false);
+                    },
                 }
                 Button {
                     button_type: ButtonType::Secondary,
                     outline: true,
                     on_click: move |_| is_address_actions_modal_open.set(false),
-                    "Cancel"
+                    {t!("common-cancel")}
                 }
             }
         }
@@ -669,7 +1732,7 @@ pub fn SendScreen() -> Element {
 
         Modal {
             is_open: show_error_modal,
-            title: "Error".to_string(),
+            title: t!("common-error"),
             p {
 
 
@@ -680,17 +1743,17 @@ pub fn SendScreen() -> Element {
 
                 Button {
                     on_click: move |_| show_error_modal.set(false),
-                    "Close"
+                    {t!("common-close")}
                 }
             }
         }
         Modal {
             is_open: show_duplicate_warning_modal,
-            title: "Duplicate Address".to_string(),
+            title: t!("send-duplicate-title"),
             p {
 
 
-                "This address is already in the recipient list. Do you want to add it again?"
+                {t!("send-duplicate-message")}
             }
             div {
                 style: "margin-top: 1rem; margin-bottom: 1rem;",
@@ -699,10 +1762,10 @@ pub fn SendScreen() -> Element {
 
                     input {
                         r#type: "checkbox",
-                        checked: "{suppress_duplicate_warning}",
-                        oninput: move |e| suppress_duplicate_warning.set(e.value() == "true"),
+                        checked: dont_ask_duplicate_again(),
+                        onchange: move |_| dont_ask_duplicate_again.toggle(),
                     }
-                    "Don't ask me again"
+                    {t!("common-dont-ask-again")}
                 }
             }
             footer {
@@ -712,11 +1775,14 @@ pub fn SendScreen() -> Element {
                     button_type: ButtonType::Secondary,
                     outline: true,
                     on_click: move |_| show_duplicate_warning_modal.set(false),
-                    "Cancel"
+                    {t!("common-cancel")}
                 }
                 Button {
                     on_click: move |_| {
-                        if let (Some(addr), Some(index)) = (
+                        if dont_ask_duplicate_again() {
+                            app_state_mut.set_suppressed(Confirmation::DuplicateAddress, true);
+                        }
+                        if let (Some(request), Some(index)) = (
                             pending_address.take(),
                             action_target_index(),
                         ) {
@@ -724,15 +1790,14 @@ pub fn SendScreen() -> Element {
                                 if let Some(target) = recs.get_mut(index) {
                                     target
                                         .with_mut(|r| {
-                                            r.address_str = addr;
-                                            r.address_error = None;
+                                            r.apply_payment_request(request, display_as_fiat, &current_rate());
                                         });
                                 }
                             }
                         }
                         show_duplicate_warning_modal.set(false);
                     },
-                    "Proceed Anyway"
+                    {t!("send-proceed-anyway")}
                 }
             }
         }
@@ -744,10 +1809,35 @@ pub fn SendScreen() -> Element {
                 WizardStep::AddRecipients => rsx! {
                     div {
                         style: "display: flex; flex-direction: column; height: 75vh;",
-                        h3 {
-                            style: "margin: 0 0 0.5rem 0; padding: 0 0.5rem;",
-                            "Add Recipients"
+                        div {
+                            style: "display: flex; justify-content: space-between; align-items: center; margin: 0 0 0.5rem 0; padding: 0 0.5rem;",
+                            h3 {
+                                style: "margin: 0;",
+                                {t!("send-add-recipients")}
+                            }
+                            div {
+                                style: "display: flex; gap: 0.5rem;",
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    on_click: move |_| is_drafts_modal_open.set(true),
+                                    {t!("send-drafts")}
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    disabled: is_any_row_active(),
+                                    on_click: move |_| {
+                                        save_draft_error.set(None);
+                                        is_save_draft_modal_open.set(true);
+                                    },
+                                    {t!("send-save-draft")}
+                                }
+                            }
                         }
+                        SpendableSummary {}
                         div {
                             style: "flex-grow: 0; overflow-y: auto; padding: 0 0.5rem;",
                             Card {
@@ -808,7 +1898,7 @@ pub fn SendScreen() -> Element {
                                     active_row_index.set(Some(0));
                                 },
                                 disabled: is_any_row_active(),
-                                "Add Another Recipient"
+                                {t!("send-add-another-recipient")}
                             }
                             div {
                                 style: "text-align: right; line-height: 1.2;",
@@ -838,7 +1928,7 @@ pub fn SendScreen() -> Element {
                                     }
                                 },
                                 disabled: !are_recipients_valid() || is_any_row_active(),
-                                "Next: Set Fee"
+                                {t!("send-next-set-fee")}
                             }
                         }
                     }
@@ -860,18 +1950,39 @@ pub fn SendScreen() -> Element {
                         let subtotal_fiat = subtotals().1;
                         let total_spend_npt = subtotal_npt + fee_npt;
                         let total_spend_fiat = subtotal_fiat + fee_fiat;
+                        // the node would reject the send later, after the user reviewed it.
+                        let check_funds = move |_: MouseEvent| {
+                            is_checking_funds.set(true);
+                            spawn(async move {
+                                match api::spendable_balance().await {
+                                    Ok(balance) => match balance.shortfall(total_spend_npt) {
+                                        Some(short) => funds_shortfall.set(Some((short, balance))),
+                                        None => {
+                                            funds_shortfall.set(None);
+                                            wizard_step.set(WizardStep::Review);
+                                        }
+                                    },
+                                    // the node still checks when sending.
+                                    Err(_) => {
+                                        funds_shortfall.set(None);
+                                        wizard_step.set(WizardStep::Review);
+                                    }
+                                }
+                                is_checking_funds.set(false);
+                            });
+                        };
                         rsx! {
                             Card {
 
                                 h3 {
 
-                                    "Set Fee"
+                                    {t!("send-set-fee")}
                                 }
                                 p {
 
                                     span {
 
-                                        "Subtotal: "
+                                        {t!("send-subtotal")}
                                     }
                                     Amount {
                                         amount: subtotal_npt,
@@ -904,9 +2015,9 @@ pub fn SendScreen() -> Element {
                                 label {
 
                                     if !display_as_fiat {
-                                        "Fee (NPT)"
+                                        {t!("send-fee-npt")}
                                     } else {
-                                        "Fee ({fiat_currency.code()})"
+                                        {t!("send-fee-fiat", currency = fiat_currency.code())}
                                     }
                                 }
                                 div {
@@ -981,7 +2092,7 @@ pub fn SendScreen() -> Element {
                                     style: "margin-top: 1rem; text-align: right;",
                                     h4 {
 
-                                        "Total Spend"
+                                        {t!("send-total-spend")}
                                     }
                                     Amount {
                                         amount: total_spend_npt,
@@ -999,19 +2110,70 @@ pub fn SendScreen() -> Element {
                                         }
                                     }
                                 }
+                                if let Some((short, balance)) = funds_shortfall() {
+                                    article {
+                                        role: "alert",
+                                        style: "margin-top: 1rem; border-left: 4px solid var(--pico-del-color);",
+                                        strong { {t!("send-funds-short")} }
+                                        " "
+                                        Amount { amount: short }
+                                        p {
+                                            style: "margin: 0.5rem 0 0 0;",
+                                            {t!("send-funds-available")}
+                                            " "
+                                            Amount { amount: balance.available }
+                                        }
+                                        if !balance.time_locked.is_zero() {
+                                            small {
+                                                style: "display: block; color: var(--pico-muted-color);",
+                                                {t!("send-funds-time-locked")}
+                                                " "
+                                                Amount { amount: balance.time_locked }
+                                            }
+                                        }
+                                        if !balance.unconfirmed.is_zero() {
+                                            small {
+                                                style: "display: block; color: var(--pico-muted-color);",
+                                                {t!("send-funds-unconfirmed")}
+                                                " "
+                                                Amount { amount: balance.unconfirmed }
+                                            }
+                                        }
+                                        if !balance.pending_spent.is_zero() {
+                                            small {
+                                                style: "display: block; color: var(--pico-muted-color);",
+                                                {t!("send-funds-pending")}
+                                                " "
+                                                Amount { amount: balance.pending_spent }
+                                            }
+                                        }
+                                    }
+                                }
                                 footer {
                                     style: "flex-shrink: 1; display: flex; justify-content: space-between;",
 
                                     Button {
                                         button_type: ButtonType::Secondary,
                                         outline: true,
-                                        on_click: move |_| wizard_step.set(WizardStep::AddRecipients),
-                                        "Back"
+                                        on_click: move |_| {
+                                            funds_shortfall.set(None);
+                                            wizard_step.set(WizardStep::AddRecipients);
+                                        },
+                                        {t!("common-back")}
                                     }
                                     Button {
-                                        on_click: move |_| wizard_step.set(WizardStep::Review),
-                                        disabled: !is_fee_valid(),
-                                        "Next: Review"
+                                        button_type: ButtonType::Secondary,
+                                        outline: true,
+                                        on_click: move |_| {
+                                            save_draft_error.set(None);
+                                            is_save_draft_modal_open.set(true);
+                                        },
+                                        {t!("send-save-draft")}
+                                    }
+                                    Button {
+                                        on_click: check_funds,
+                                        disabled: !is_fee_valid() || is_checking_funds(),
+                                        {t!("send-next-review")}
                                     }
                                 }
                             }
@@ -1025,20 +2187,162 @@ pub fn SendScreen() -> Element {
                         let total_spend_npt = subtotals().0 + fee_npt;
                         let fiat_fee_display = fee_input.read().as_fiat_or_zero(&rate);
                         let fiat_total_display = subtotals().1 + fiat_fee_display;
+                        // guards against an address swapped in the clipboard.
+                        let address_check_threshold = (app_state_mut.address_check_threshold)();
+                        let needs_address_check = !address_check_threshold.is_zero()
+                            && total_spend_npt > address_check_threshold;
+                        let addresses_verified = !needs_address_check
+                            || recipients.read().iter().enumerate().all(|(i, r)| {
+                                address_checks
+                                    .read()
+                                    .get(i)
+                                    .is_some_and(|entered| address_tail_matches(&r.read().address_str, entered))
+                            });
+                        // an empty message sends without an announcement.
+                        let announcement = (!announcement_message.read().trim().is_empty())
+                            .then(|| announcement_input::parse(&announcement_message.read(), announcement_encoding()));
+                        let announcement_is_valid = !matches!(announcement, Some(Err(_)));
+                        let inputs_cover_spend = chosen_inputs.read().is_empty()
+                            || chosen_inputs
+                                .read()
+                                .values()
+                                .fold(NativeCurrencyAmount::zero(), |acc, amount| acc + *amount)
+                                >= total_spend_npt;
+                        // paying an address once is smaller, and so cheaper, than paying it twice.
+                        let duplicate_count = duplicate_recipient_count(
+                            &recipients.read().iter().map(|r| r.read().clone()).collect::<Vec<_>>(),
+                        );
+                        let merge_duplicates = {
+                            let rate = rate.clone();
+                            move |_: MouseEvent| {
+                                let merged = merge_duplicate_recipients(
+                                    recipients.peek().iter().map(|r| r.peek().clone()).collect(),
+                                    display_as_fiat,
+                                    &rate,
+                                );
+                                recipients.set(merged.into_iter().map(Signal::new).collect());
+                                // the checks were entered per recipient row.
+                                address_checks.set(vec![]);
+                            }
+                        };
+                        let send_now = {
+                            let rate = rate.clone();
+                            move || {
+                                let mut send_job_id = send_job_id;
+                                let mut wizard_step = wizard_step;
+                                let announcement = (!announcement_message.peek().trim().is_empty())
+                                    .then(|| announcement_input::parse(&announcement_message.peek(), *announcement_encoding.peek()))
+                                    .transpose();
+                                // the Confirm button is disabled meanwhile.
+                                let Ok(announcement) = announcement else {
+                                    return;
+                                };
+                                let outputs: Vec<OutputFormat> = recipients
+                                    .peek()
+                                    .iter()
+                                    .map(|rs| {
+                                        let r = rs.peek();
+                                        let addr = ReceivingAddress::from_bech32m(&r.address_str, network).unwrap();
+                                        OutputFormat::AddressAndAmount(addr, r.amount.as_npt_or_zero(&rate))
+                                    })
+                                    .collect();
+                                let paid: Vec<RecentRecipient> = recipients
+                                    .peek()
+                                    .iter()
+                                    .map(|rs| {
+                                        let r = rs.peek();
+                                        RecentRecipient {
+                                            address: r.address_str.clone(),
+                                            label: r.label.clone(),
+                                            last_used_ms: 0,
+                                        }
+                                    })
+                                    .collect();
+                                // sent in the background, so that leaving this screen does not lose it.
+                                let id = send_queue.submit(SendRequest {
+                                    outputs,
+                                    change_policy: change_policy.peek().clone(),
+                                    fee: fee_input.peek().as_npt_or_zero(&rate),
+                                    amount: subtotals.peek().0,
+                                    announcement,
+                                    inputs: (!chosen_inputs.peek().is_empty())
+                                        .then(|| chosen_inputs.peek().keys().copied().collect()),
+                                    recipients: paid,
+                                    draft: resumed_draft.peek().clone(),
+                                });
+                                send_job_id.set(Some(id));
+                                wizard_step.set(WizardStep::Status);
+                            }
+                        };
+                        // sending needs the PIN again, if one is set.
+                        let send_authorized = {
+                            let send_now = send_now.clone();
+                            move || {
+                                let mut show_reauth_modal = show_reauth_modal;
+                                if *app_state_mut.pin_set.peek() {
+                                    show_reauth_modal.set(true);
+                                } else {
+                                    send_now();
+                                }
+                            }
+                        };
                         rsx! {
+                            ReauthModal {
+                                is_open: show_reauth_modal,
+                                on_success: move |_| send_now(),
+                            }
+                            Modal {
+                                is_open: show_large_send_modal,
+                                title: t!("send-large-title"),
+                                p {
+                                    {t!("send-large-message")}
+                                }
+                                div {
+                                    style: "margin-top: 1rem; margin-bottom: 1rem;",
+                                    label {
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: dont_ask_large_send_again(),
+                                            onchange: move |_| dont_ask_large_send_again.toggle(),
+                                        }
+                                        {t!("common-dont-ask-again")}
+                                    }
+                                }
+                                footer {
+                                    Button {
+                                        button_type: ButtonType::Secondary,
+                                        outline: true,
+                                        on_click: move |_| show_large_send_modal.set(false),
+                                        {t!("common-cancel")}
+                                    }
+                                    Button {
+                                        on_click: {
+                                            let send_authorized = send_authorized.clone();
+                                            move |_| {
+                                                if dont_ask_large_send_again() {
+                                                    app_state_mut.set_suppressed(Confirmation::LargeSend, true);
+                                                }
+                                                show_large_send_modal.set(false);
+                                                send_authorized();
+                                            }
+                                        },
+                                        {t!("send-send")}
+                                    }
+                                }
+                            }
                             Card {
 
                                 h3 {
 
-                                    "Review Transaction"
+                                    {t!("send-review-title")}
                                 }
                                 p {
 
-                                    "Please review the details below. This action cannot be undone."
+                                    {t!("send-review-intro")}
                                 }
                                 h5 {
                                     style: "margin-top: 1rem;",
-                                    "Recipients:"
+                                    {t!("send-recipients")}
                                 }
                                 table {
                                     role: "grid",
@@ -1060,6 +2364,10 @@ pub fn SendScreen() -> Element {
                                                             Address {
                                                                 address: addr.clone(),
                                                             }
+                                                            PaymentRequestNote {
+                                                                label: recipient.label.clone(),
+                                                                message: recipient.message.clone(),
+                                                            }
                                                         }
                                                         td {
                                                             style: "text-align: right;",
@@ -1074,11 +2382,27 @@ pub fn SendScreen() -> Element {
                                         }
                                     }
                                 }
+                                if duplicate_count > 0 {
+                                    div {
+                                        role: "note",
+                                        style: "display: flex; align-items: center; justify-content: space-between; gap: 1rem; margin-top: 0.5rem;",
+                                        small {
+                                            {t!("send-merge-duplicates-message", count = duplicate_count)}
+                                        }
+                                        Button {
+                                            button_type: ButtonType::Secondary,
+                                            outline: true,
+                                            style: "margin-bottom: 0; flex-shrink: 0;",
+                                            on_click: merge_duplicates,
+                                            {t!("send-merge-duplicates")}
+                                        }
+                                    }
+                                }
                                 div {
                                     style: "text-align: right; margin-top: 1rem;",
                                     strong {
 
-                                        "Fee: "
+                                        {t!("send-fee")}
                                     }
                                     Amount {
                                         amount: fee_npt,
@@ -1102,7 +2426,7 @@ pub fn SendScreen() -> Element {
                                     style: "text-align: right; margin-top: 0.5rem; font-weight: bold; border-top: 1px solid var(--pico-secondary-border); padding-top: 0.5rem; display: grid; grid-template-columns: 1fr auto;",
                                     span {
                                         style: "justify-self: end; padding-right: 1rem;",
-                                        "Total Spend: "
+                                        {t!("send-total-spend-label")}
                                     }
                                     div {
 
@@ -1122,7 +2446,76 @@ pub fn SendScreen() -> Element {
                                                         fiat_equivalent: Some(fiat_total_display),
                                                         fixed: Some(AmountType::Fiat),
                                                     }
-                                                    " est.)"
+                                                    {t!("send-estimated-suffix")}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                if let Some(Ok(message)) = &announcement {
+                                    div {
+                                        style: "margin-top: 1rem;",
+                                        h5 { {t!("send-announcement-review")} }
+                                        pre {
+                                            style: "white-space: pre-wrap; word-break: break-all; max-height: 8rem; overflow-y: auto;",
+                                            "{announcement_message}"
+                                        }
+                                        small {
+                                            style: "color: var(--pico-muted-color);",
+                                            {t!("send-announcement-review-size", encoding = announcement_encoding().name(), count = message.len())}
+                                        }
+                                    }
+                                }
+                                ChangePolicyOptions { change_policy }
+                                CoinControlOptions {
+                                    chosen: chosen_inputs,
+                                    needed: total_spend_npt,
+                                }
+                                AnnouncementOptions {
+                                    encoding: announcement_encoding,
+                                    message_input: announcement_message,
+                                }
+                                if needs_address_check {
+                                    div {
+                                        style: "margin-top: 1.5rem;",
+                                        h5 { {t!("send-address-check-title")} }
+                                        p {
+                                            style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                                            {t!("send-address-check-intro", count = ADDRESS_CHECK_LEN)}
+                                        }
+                                        for (i, recipient_signal) in recipients.read().iter().enumerate() {
+                                            {
+                                                let address = recipient_signal.read().address_str.clone();
+                                                let entered = address_checks.read().get(i).cloned().unwrap_or_default();
+                                                let matches = address_tail_matches(&address, &entered);
+                                                rsx! {
+                                                    label {
+                                                        key: "{i}",
+                                                        {t!("send-address-check-label", number = i + 1)}
+                                                        input {
+                                                            r#type: "text",
+                                                            autocomplete: "off",
+                                                            spellcheck: "false",
+                                                            maxlength: "{ADDRESS_CHECK_LEN}",
+                                                            value: "{entered}",
+                                                            "aria-invalid": if matches {
+                                                                "false"
+                                                            } else if entered.trim().len() >= ADDRESS_CHECK_LEN {
+                                                                "true"
+                                                            } else {
+                                                                ""
+                                                            },
+                                                            oninput: move |e| {
+                                                                address_checks
+                                                                    .with_mut(|checks| {
+                                                                        if checks.len() <= i {
+                                                                            checks.resize(i + 1, String::new());
+                                                                        }
+                                                                        checks[i] = e.value();
+                                                                    })
+                                                            },
+                                                        }
+                                                    }
                                                 }
                                             }
                                         }
@@ -1134,42 +2527,30 @@ pub fn SendScreen() -> Element {
                                     Button {
                                         button_type: ButtonType::Secondary,
                                         outline: true,
-                                        on_click: move |_| wizard_step.set(WizardStep::EnterFee),
-                                        "Back"
+                                        on_click: move |_| {
+                                            address_checks.set(vec![]);
+                                            wizard_step.set(WizardStep::EnterFee);
+                                        },
+                                        {t!("common-back")}
                                     }
                                     Button {
+                                        disabled: !addresses_verified || !announcement_is_valid || !inputs_cover_spend,
                                         on_click: {
-                                            let rate = rate_rc.clone();
+                                            let send_authorized = send_authorized.clone();
                                             move |_| {
-                                                let network = network;
-                                                let recipients = recipients;
-                                                let fee_input = fee_input;
-                                                let mut api_response = api_response;
-                                                let mut wizard_step = wizard_step;
-                                                let rate = rate.clone();
-                                                spawn(async move {
-                                                    let outputs: Vec<OutputFormat> = recipients
-                                                        .read()
-                                                        .iter()
-                                                        .map(|rs| {
-                                                            let r = rs.read();
-                                                            let addr = ReceivingAddress::from_bech32m(
-                                                                    &r.address_str,
-                                                                    network,
-                                                                )
-                                                                .unwrap();
-                                                            let amount = r.amount.as_npt_or_zero(&rate);
-                                                            OutputFormat::AddressAndAmount(addr, amount)
-                                                        })
-                                                        .collect();
-                                                    let fee = fee_input.read().as_npt_or_zero(&rate);
-                                                    let result = api::send(outputs, ChangePolicy::default(), fee).await;
-                                                    api_response.set(Some(result));
-                                                    wizard_step.set(WizardStep::Status);
-                                                });
+                                                // ask before sending more than half the balance.
+                                                let is_large = match &*wallet_balance.read() {
+                                                    Some(Ok(balance)) => total_spend_npt + total_spend_npt > *balance,
+                                                    _ => false,
+                                                };
+                                                if is_large && !app_state_mut.is_suppressed(Confirmation::LargeSend) {
+                                                    show_large_send_modal.set(true);
+                                                } else {
+                                                    send_authorized();
+                                                }
                                             }
                                         },
-                                        "Confirm & Send"
+                                        {t!("send-confirm")}
                                     }
                                 }
                             }
@@ -1177,27 +2558,28 @@ pub fn SendScreen() -> Element {
                     }
                 },
                 WizardStep::Status => rsx! {
-                    if let Some(response_result) = api_response.read().as_ref() {
+                    if let Some(job) = current_job.as_ref().filter(|j| !j.status.is_in_flight()) {
                         Card {
-                            h3 { "Transaction Status" }
+                            h3 { {t!("send-status-title")} }
 
-                            match response_result {
-                                Ok((kernel_id, _details)) => {
-                                    let kernel_id_clone = kernel_id.clone();
+                            match &job.status {
+                                SendJobStatus::Sent { txid: kernel_id } => {
+                                    let kernel_id_clone = *kernel_id;
 
                                     rsx! {
                                         p {
                                             style: "color: var(--pico-color-green-500);",
-                                            "Transaction sent successfully!"
+                                            {t!("send-success")}
                                         }
                                         div {
                                             style: "display: flex; justify-content: space-between; align-items: center; margin-top: 1.5rem; margin-bottom: 1.5rem; padding: 0.75rem; border: 1px solid var(--pico-secondary-border); border-radius: var(--pico-border-radius);",
-                                            strong { "Transaction ID" }
+                                            strong { {t!("send-transaction-id")} }
                                             DigestDisplay {
                                                 digest: (*kernel_id).into(),
                                                 as_code: true,
                                             }
                                         }
+                                        TxNoteEditor { txid: *kernel_id }
                                         div {
                                             style: "display: flex; gap: 1rem; margin-top: 1.5rem; flex-wrap: wrap;",
                                             Button {
@@ -1205,46 +2587,92 @@ pub fn SendScreen() -> Element {
                                                 outline: true,
                                                 on_click: move |evt: Event<MouseData>| {
                                                     evt.prevent_default();
-                                                    active_screen.set(Screen::MempoolTx(kernel_id_clone));
+                                                    navigator().push(Route::MempoolTx { tx_id: kernel_id_clone });
                                                 },
-                                                "View in Mempool"
+                                                {t!("send-view-in-mempool")}
                                             }
                                             Button {
                                                 on_click: move |_| reset_screen(),
-                                                "Send Another Transaction"
+                                                {t!("send-another")}
                                             }
                                         }
                                     }
                                 },
-                                Err(err) => rsx! {
-                                    h4 {
-                                        style: "color: var(--pico-color-red-500);",
-                                        "Error Sending Transaction"
+                                SendJobStatus::Failed { error: err } => {
+                                    let job_id = job.id;
+                                    let can_retry = send_queue.can_retry(job_id);
+                                    rsx! {
+                                        h4 {
+                                            style: "color: var(--pico-color-red-500);",
+                                            {send_failure_title(err)}
+                                        }
+                                        p { "{err}" }
+                                        if let Some(hint) = send_error_hint(err) {
+                                            p {
+                                                style: "color: var(--pico-muted-color);",
+                                                "{hint}"
+                                            }
+                                        }
+                                        div {
+                                            style: "display: flex; gap: 1rem; margin-top: 1.5rem; flex-wrap: wrap;",
+                                            Button {
+                                                button_type: ButtonType::Secondary,
+                                                outline: true,
+                                                on_click: move |_| wizard_step.set(WizardStep::Review),
+                                                {t!("common-back")}
+                                            }
+                                            if err.is_insufficient_balance() || err.is_invalid_address() {
+                                                Button {
+                                                    button_type: ButtonType::Secondary,
+                                                    on_click: move |_| wizard_step.set(WizardStep::AddRecipients),
+                                                    {t!("send-edit-recipients")}
+                                                }
+                                            }
+                                            if err.is_insufficient_balance() {
+                                                Button {
+                                                    button_type: ButtonType::Secondary,
+                                                    on_click: move |_| wizard_step.set(WizardStep::EnterFee),
+                                                    {t!("send-edit-fee")}
+                                                }
+                                            }
+                                            if can_retry {
+                                                Button {
+                                                    on_click: move |_| send_queue.retry(job_id),
+                                                    {t!("send-retry")}
+                                                }
+                                            }
+                                            Button {
+                                                button_type: ButtonType::Secondary,
+                                                outline: true,
+                                                on_click: move |_| reset_screen(),
+                                                {t!("send-another")}
+                                            }
+                                        }
                                     }
-                                    p { "{err}" }
+                                },
+                                SendJobStatus::Interrupted | SendJobStatus::InFlight => rsx! {
+                                    p { {t!("send-queue-interrupted")} }
                                     div {
                                         style: "display: flex; gap: 1rem; margin-top: 1.5rem; flex-wrap: wrap;",
                                         Button {
                                             button_type: ButtonType::Secondary,
                                             outline: true,
-                                            on_click: move |_| wizard_step.set(WizardStep::Review),
-                                            "Back"
+                                            on_click: move |_| {
+                                                navigator().push(Route::History {});
+                                            },
+                                            {t!("send-queue-check-history")}
                                         }
                                         Button {
                                             on_click: move |_| reset_screen(),
-                                            "Send Another Transaction"
+                                            {t!("send-another")}
                                         }
                                     }
                                 },
                             }
                         }
                     } else {
-                        // The signal is still None (loading)
-                        Card {
-                            h3 { "Sending Transaction..." }
-                            p { "Please wait." }
-                            progress { }
-                        }
+                        // still in flight.
+                        SendProgressCard {}
                     }
                 },
             }
@@ -0,0 +1,1321 @@
+//=============================================================================
+// File: src/screens/settings.rs
+//=============================================================================
+use std::str::FromStr;
+
+use api::announcement_input::AnnouncementEncoding;
+use api::managed_node::ManagedNodeConfig;
+use api::prefs::amount_precision::AmountPrecision;
+use api::prefs::amount_precision::AmountRounding;
+use api::prefs::amount_precision::MAX_DECIMALS_CHOICES;
+use api::prefs::confirmation::Confirmation;
+use api::prefs::language::Language;
+use api::prefs::refresh_intervals::RefreshScreen;
+use api::prefs::startup_check::StartupCheck;
+use api::wallet_encryption::WalletFileState;
+use dioxus::prelude::*;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use strum::IntoEnumIterator;
+
+use crate::app_state::AppState;
+use crate::app_state_mut::AppStateMut;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
+use crate::components::pico::Card;
+use crate::i18n::t;
+use crate::screens::node_logs::ManagedNodeStatusLine;
+use crate::Route;
+
+#[component]
+pub fn SettingsScreen() -> Element {
+    let capabilities = use_context::<AppState>().capabilities;
+    let dev_tools = capabilities.dev_tools;
+
+    rsx! {
+        Card {
+            h3 { {t!("settings-title")} }
+            LanguageSettings {}
+            AmountSettings {}
+            if capabilities.can_use_app_lock {
+                SecuritySettings {}
+            }
+            WalletEncryptionSettings {}
+            ConfirmationSettings {}
+            StartupCheckSettings {}
+            RefreshSettings {}
+            DataDirectorySettings {}
+            if cfg!(feature = "dioxus-desktop") {
+                ManagedNodeSettings {}
+            }
+            SessionSettings {}
+            MetadataSettings {}
+            WalletImportSettings {}
+            DiagnosticsSettings {}
+            details {
+                summary { {t!("settings-advanced")} }
+                RpcMetricsSettings {}
+            }
+            if dev_tools {
+                DeveloperSettings {}
+            }
+        }
+    }
+}
+
+/// Lets the user choose the UI language.
+#[component]
+fn LanguageSettings() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let current = *app_state_mut.language.read();
+
+    rsx! {
+        section {
+            h4 { {t!("settings-language")} }
+            select {
+                onchange: move |e| {
+                    if let Ok(language) = Language::from_str(&e.value()) {
+                        app_state_mut.set_language(language);
+                    }
+                },
+                for language in Language::iter() {
+                    option {
+                        key: "{language.code()}",
+                        value: "{language.code()}",
+                        selected: language == current,
+                        "{language.native_name()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Lets the user choose how many decimals of NPT amounts are shown.
+#[component]
+fn AmountSettings() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let precision = *app_state_mut.amount_precision.read();
+
+    rsx! {
+        section {
+            h4 { {t!("settings-amounts")} }
+            div {
+                class: "grid",
+                label {
+                    {t!("settings-amount-decimals")}
+                    select {
+                        onchange: move |e| {
+                            // "all" does not parse, and shows every decimal.
+                            let max_decimals = e.value().parse().ok();
+                            app_state_mut.set_amount_precision(AmountPrecision {
+                                max_decimals,
+                                ..precision
+                            });
+                        },
+                        for max_decimals in MAX_DECIMALS_CHOICES {
+                            option {
+                                key: "{max_decimals:?}",
+                                value: max_decimals.map_or("all".to_string(), |m| m.to_string()),
+                                selected: max_decimals == precision.max_decimals,
+                                {decimals_label(max_decimals)}
+                            }
+                        }
+                    }
+                }
+                label {
+                    {t!("settings-amount-rounding")}
+                    select {
+                        onchange: move |e| {
+                            if let Ok(rounding) = AmountRounding::from_str(&e.value()) {
+                                app_state_mut.set_amount_precision(AmountPrecision {
+                                    rounding,
+                                    ..precision
+                                });
+                            }
+                        },
+                        for rounding in AmountRounding::iter() {
+                            option {
+                                key: "{rounding}",
+                                value: "{rounding}",
+                                selected: rounding == precision.rounding,
+                                {rounding_label(rounding)}
+                            }
+                        }
+                    }
+                }
+            }
+            small { {t!("settings-amount-help")} }
+        }
+    }
+}
+
+fn decimals_label(max_decimals: Option<u8>) -> String {
+    match max_decimals {
+        Some(count) => t!("settings-amount-decimals-count", count = count),
+        None => t!("settings-amount-decimals-all"),
+    }
+}
+
+fn rounding_label(rounding: AmountRounding) -> String {
+    match rounding {
+        AmountRounding::Round => t!("settings-amount-rounding-round"),
+        AmountRounding::Truncate => t!("settings-amount-rounding-truncate"),
+    }
+}
+
+/// The idle timeouts offered, in minutes.  0 never locks.
+const IDLE_LOCK_CHOICES: [u32; 6] = [1, 5, 15, 30, 60, 0];
+
+/// Lets the user set, change or remove the app lock PIN, and choose when the
+/// app locks itself.
+#[component]
+fn SecuritySettings() -> Element {
+    let mut app_state_mut = use_context::<AppStateMut>();
+    let pin_set = (app_state_mut.pin_set)();
+    let idle_lock_minutes = (app_state_mut.idle_lock_minutes)();
+    let idle_blur_minutes = (app_state_mut.idle_blur_minutes)();
+
+    let mut current_pin = use_signal(String::new);
+    let mut new_pin = use_signal(String::new);
+    let mut confirm_pin = use_signal(String::new);
+    let mut message = use_signal(|| None::<Result<String, String>>);
+
+    // `new` of None removes the PIN.
+    let mut save_pin = move |new: Option<String>| {
+        if new.is_some() && new_pin() != confirm_pin() {
+            message.set(Some(Err(t!("settings-pin-mismatch"))));
+            return;
+        }
+        let current = pin_set.then(|| current_pin());
+        spawn(async move {
+            let removing = new.is_none();
+            match api::set_lock_pin(current, new).await {
+                Ok(prefs) => {
+                    app_state_mut.pin_set.set(prefs.pin_set());
+                    current_pin.set(String::new());
+                    new_pin.set(String::new());
+                    confirm_pin.set(String::new());
+                    let done = if removing {
+                        t!("settings-pin-removed")
+                    } else {
+                        t!("settings-pin-saved")
+                    };
+                    message.set(Some(Ok(done)));
+                }
+                Err(e) => message.set(Some(Err(e.to_string()))),
+            }
+        });
+    };
+
+    let mut set_idle_lock_minutes = move |minutes: u32| {
+        app_state_mut.idle_lock_minutes.set(minutes);
+        spawn(async move {
+            let _ = api::set_idle_lock_minutes(minutes).await;
+        });
+    };
+
+    let mut set_idle_blur_minutes = move |minutes: u32| {
+        app_state_mut.idle_blur_minutes.set(minutes);
+        spawn(async move {
+            let _ = api::set_idle_blur_minutes(minutes).await;
+        });
+    };
+
+    // empty when off.
+    let mut threshold_input = use_signal(move || {
+        let threshold = *app_state_mut.address_check_threshold.peek();
+        if threshold.is_zero() {
+            String::new()
+        } else {
+            threshold.to_string()
+        }
+    });
+    let parse_threshold = move || match threshold_input().trim() {
+        "" => Ok(NativeCurrencyAmount::zero()),
+        input => NativeCurrencyAmount::coins_from_str(&input.replace(',', ".")),
+    };
+    let threshold_invalid = parse_threshold().is_err();
+
+    let save_threshold = move |_| {
+        let Ok(threshold) = parse_threshold() else {
+            return;
+        };
+        app_state_mut.address_check_threshold.set(threshold);
+        spawn(async move {
+            let _ = api::set_address_check_threshold(threshold).await;
+        });
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("settings-security")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-security-intro")}
+            }
+            if pin_set {
+                label {
+                    {t!("settings-current-pin")}
+                    input {
+                        r#type: "password",
+                        autocomplete: "current-password",
+                        value: "{current_pin}",
+                        oninput: move |e| current_pin.set(e.value()),
+                    }
+                }
+            }
+            div {
+                class: "grid",
+                label {
+                    {t!("settings-new-pin")}
+                    input {
+                        r#type: "password",
+                        autocomplete: "new-password",
+                        value: "{new_pin}",
+                        oninput: move |e| new_pin.set(e.value()),
+                    }
+                }
+                label {
+                    {t!("settings-confirm-pin")}
+                    input {
+                        r#type: "password",
+                        autocomplete: "new-password",
+                        value: "{confirm_pin}",
+                        oninput: move |e| confirm_pin.set(e.value()),
+                    }
+                }
+            }
+            match message() {
+                Some(Ok(m)) => rsx! {
+                    p { style: "color: var(--pico-ins-color);", "{m}" }
+                },
+                Some(Err(m)) => rsx! {
+                    p { style: "color: var(--pico-del-color);", "{m}" }
+                },
+                None => rsx! {},
+            }
+            div {
+                style: "display: flex; gap: 1rem; flex-wrap: wrap;",
+                Button {
+                    disabled: new_pin().is_empty() || (pin_set && current_pin().is_empty()),
+                    on_click: move |_| save_pin(Some(new_pin())),
+                    if pin_set {
+                        {t!("settings-change-pin")}
+                    } else {
+                        {t!("settings-set-pin")}
+                    }
+                }
+                if pin_set {
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        disabled: current_pin().is_empty(),
+                        on_click: move |_| save_pin(None),
+                        {t!("settings-remove-pin")}
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        on_click: move |_| app_state_mut.lock(),
+                        {t!("settings-lock-now")}
+                    }
+                }
+            }
+            if pin_set {
+                label {
+                    {t!("settings-idle-lock")}
+                    select {
+                        onchange: move |e| {
+                            if let Ok(minutes) = e.value().parse() {
+                                set_idle_lock_minutes(minutes);
+                            }
+                        },
+                        for minutes in IDLE_LOCK_CHOICES {
+                            option {
+                                key: "{minutes}",
+                                value: "{minutes}",
+                                selected: minutes == idle_lock_minutes,
+                                if minutes == 0 {
+                                    {t!("settings-idle-lock-never")}
+                                } else {
+                                    {t!("settings-idle-lock-minutes", minutes = minutes)}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            label {
+                {t!("settings-idle-blur")}
+                select {
+                    onchange: move |e| {
+                        if let Ok(minutes) = e.value().parse() {
+                            set_idle_blur_minutes(minutes);
+                        }
+                    },
+                    for minutes in IDLE_LOCK_CHOICES {
+                        option {
+                            key: "{minutes}",
+                            value: "{minutes}",
+                            selected: minutes == idle_blur_minutes,
+                            if minutes == 0 {
+                                {t!("settings-idle-lock-never")}
+                            } else {
+                                {t!("settings-idle-lock-minutes", minutes = minutes)}
+                            }
+                        }
+                    }
+                }
+                small { {t!("settings-idle-blur-help")} }
+            }
+            label {
+                {t!("settings-address-check")}
+                input {
+                    r#type: "text",
+                    inputmode: "decimal",
+                    placeholder: t!("settings-address-check-off"),
+                    value: "{threshold_input}",
+                    "aria-invalid": if threshold_invalid { "true" } else { "" },
+                    oninput: move |e| threshold_input.set(e.value()),
+                    onchange: save_threshold,
+                }
+                small { {t!("settings-address-check-help")} }
+            }
+        }
+    }
+}
+
+/// Lets the user re-enable confirmation dialogs they asked not to be shown.
+#[component]
+fn ConfirmationSettings() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let suppressed = app_state_mut.suppressed_confirmations.read().clone();
+
+    rsx! {
+        section {
+            h4 { {t!("settings-confirmations")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-confirmations-intro")}
+            }
+            for confirmation in Confirmation::iter() {
+                label {
+                    key: "{confirmation:?}",
+                    input {
+                        r#type: "checkbox",
+                        role: "switch",
+                        checked: !suppressed.contains(&confirmation),
+                        onchange: move |_| {
+                            app_state_mut.set_suppressed(confirmation, !app_state_mut.is_suppressed(confirmation))
+                        },
+                    }
+                    {confirmation_label(confirmation)}
+                }
+            }
+        }
+    }
+}
+
+fn confirmation_label(confirmation: Confirmation) -> String {
+    match confirmation {
+        Confirmation::DuplicateAddress => t!("settings-confirm-duplicate-address"),
+        Confirmation::ClearStanding => t!("settings-confirm-clear-standing"),
+        Confirmation::LargeSend => t!("settings-confirm-large-send"),
+    }
+}
+
+/// Lets the user choose what is checked for when the app starts.
+#[component]
+fn StartupCheckSettings() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let disabled = app_state_mut.disabled_startup_checks.read().clone();
+
+    rsx! {
+        section {
+            h4 { {t!("settings-startup-checks")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-startup-checks-intro")}
+            }
+            for check in StartupCheck::iter() {
+                label {
+                    key: "{check:?}",
+                    input {
+                        r#type: "checkbox",
+                        role: "switch",
+                        checked: !disabled.contains(&check),
+                        onchange: move |_| {
+                            let was_disabled =
+                                app_state_mut.disabled_startup_checks.peek().contains(&check);
+                            app_state_mut.set_startup_check_enabled(check, was_disabled)
+                        },
+                    }
+                    {startup_check_label(check)}
+                }
+            }
+        }
+    }
+}
+
+fn startup_check_label(check: StartupCheck) -> String {
+    match check {
+        StartupCheck::SendDrafts => t!("settings-startup-check-drafts"),
+        StartupCheck::StuckTransactions => t!("settings-startup-check-stuck"),
+        StartupCheck::UnclaimedUtxos => t!("settings-startup-check-unclaimed"),
+    }
+}
+
+/// The refresh intervals offered, in seconds.
+const REFRESH_CHOICES: [u32; 7] = [2, 5, 10, 30, 60, 120, 300];
+
+/// Lets the user choose how often each screen polls while shown.
+#[component]
+fn RefreshSettings() -> Element {
+    let app_state_mut = use_context::<AppStateMut>();
+    let intervals = app_state_mut.refresh_intervals.read().clone();
+
+    rsx! {
+        section {
+            h4 { {t!("settings-refresh")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-refresh-intro")}
+            }
+            div {
+                class: "grid",
+                for screen in RefreshScreen::iter() {
+                    label {
+                        key: "{screen:?}",
+                        {refresh_screen_route(screen).name()}
+                        select {
+                            onchange: move |e| {
+                                if let Ok(secs) = e.value().parse() {
+                                    app_state_mut.set_refresh_interval(screen, secs);
+                                }
+                            },
+                            for secs in REFRESH_CHOICES {
+                                option {
+                                    key: "{secs}",
+                                    value: "{secs}",
+                                    selected: secs == intervals.secs(screen),
+                                    if secs == screen.default_secs() {
+                                        {t!("settings-refresh-default", seconds = secs)}
+                                    } else {
+                                        {t!("settings-refresh-seconds", seconds = secs)}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn refresh_screen_route(screen: RefreshScreen) -> Route {
+    match screen {
+        RefreshScreen::Balance => Route::Balance {},
+        RefreshScreen::Receive => Route::Receive {},
+        RefreshScreen::Utxos => Route::Utxos {},
+        RefreshScreen::Peers => Route::Peers {},
+        RefreshScreen::BlockChain => Route::BlockChain {},
+        RefreshScreen::NodeStats => Route::NodeStats {},
+        RefreshScreen::Logs => Route::Logs {},
+        RefreshScreen::NodeLogs => Route::NodeLogs {},
+    }
+}
+
+/// Lets the user choose neptune-core's data directory, for when this app sees
+/// it under another path than neptune-core reports.
+#[component]
+pub(crate) fn DataDirectorySettings() -> Element {
+    let mut choice = use_resource(move || async move { api::data_directory_choice().await });
+    let mut root_input = use_signal(String::new);
+    let mut is_busy = use_signal(|| false);
+    let mut message = use_signal(|| None::<Result<String, String>>);
+
+    // shows the stored choice once loaded.
+    use_effect(move || {
+        if let Some(Ok(c)) = &*choice.read() {
+            root_input.set(c.chosen.clone().unwrap_or_default());
+        }
+    });
+
+    let mut save = move |root: Option<String>| {
+        is_busy.set(true);
+        message.set(None);
+        spawn(async move {
+            match api::set_data_directory(root).await {
+                Ok(_) => {
+                    message.set(Some(Ok(t!("settings-data-dir-saved"))));
+                    choice.restart();
+                }
+                Err(e) => message.set(Some(Err(t!(
+                    "settings-data-dir-failed",
+                    error = e.to_string()
+                )))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let browse = move |_: MouseEvent| {
+        spawn(async move {
+            if let Some(root) = crate::compat::pick_folder().await {
+                root_input.set(root);
+            }
+        });
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("settings-data-dir")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-data-dir-intro")}
+            }
+            match &*choice.read() {
+                None => rsx! {
+                    p { aria_busy: "true" }
+                },
+                Some(Err(e)) => rsx! {
+                    p {
+                        style: "color: var(--pico-del-color);",
+                        {t!("settings-data-dir-load-failed", error = e.to_string())}
+                    }
+                },
+                Some(Ok(c)) => {
+                    let from_env = c.from_env;
+                    let chosen = c.chosen.is_some();
+                    rsx! {
+                        p {
+                            style: "font-size: 0.9rem; word-break: break-all;",
+                            {t!("settings-data-dir-in-use", path = c.in_use.as_str())}
+                        }
+                        if from_env {
+                            p {
+                                style: "font-size: 0.9rem;",
+                                {t!("settings-data-dir-from-env")}
+                            }
+                        } else {
+                            div {
+                                role: "group",
+                                input {
+                                    r#type: "text",
+                                    placeholder: t!("settings-data-dir-placeholder"),
+                                    value: "{root_input}",
+                                    oninput: move |e| root_input.set(e.value()),
+                                }
+                                if crate::compat::CAN_PICK_PATHS {
+                                    Button {
+                                        button_type: ButtonType::Secondary,
+                                        outline: true,
+                                        disabled: is_busy(),
+                                        on_click: browse,
+                                        {t!("settings-data-dir-browse")}
+                                    }
+                                }
+                            }
+                            div {
+                                style: "display: flex; gap: 1rem; flex-wrap: wrap;",
+                                Button {
+                                    disabled: is_busy(),
+                                    on_click: move |_| save(Some(root_input())),
+                                    {t!("common-save")}
+                                }
+                                if chosen {
+                                    Button {
+                                        button_type: ButtonType::Secondary,
+                                        outline: true,
+                                        disabled: is_busy(),
+                                        on_click: move |_| save(None),
+                                        {t!("settings-data-dir-reset")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            match message() {
+                Some(Ok(m)) => rsx! {
+                    p { style: "color: var(--pico-ins-color);", "{m}" }
+                },
+                Some(Err(m)) => rsx! {
+                    p { style: "color: var(--pico-del-color);", "{m}" }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
+
+/// Lets the desktop app run neptune-core itself, restarting it if it stops.
+#[component]
+fn ManagedNodeSettings() -> Element {
+    let mut config = use_resource(move || async move { api::managed_node_config().await });
+    let mut status = use_resource(move || async move { api::managed_node_status().await });
+    let mut enabled = use_signal(|| false);
+    let mut binary_input = use_signal(String::new);
+    let mut args_input = use_signal(String::new);
+    let mut is_busy = use_signal(|| false);
+    let mut message = use_signal(|| None::<Result<String, String>>);
+
+    // shows the stored config once loaded.
+    use_effect(move || {
+        if let Some(Ok(c)) = &*config.read() {
+            enabled.set(c.enabled);
+            binary_input.set(c.binary.clone());
+            args_input.set(c.args.join(" "));
+        }
+    });
+
+    let save = move |_: MouseEvent| {
+        is_busy.set(true);
+        message.set(None);
+        let new = ManagedNodeConfig {
+            enabled: enabled(),
+            binary: binary_input().trim().to_string(),
+            args: args_input().split_whitespace().map(String::from).collect(),
+        };
+        spawn(async move {
+            match api::set_managed_node_config(new).await {
+                Ok(_) => {
+                    message.set(Some(Ok(t!("settings-managed-node-saved"))));
+                    config.restart();
+                    status.restart();
+                }
+                Err(e) => message.set(Some(Err(t!(
+                    "settings-managed-node-failed",
+                    error = e.to_string()
+                )))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let browse = move |_: MouseEvent| {
+        spawn(async move {
+            if let Some(path) = crate::compat::pick_file_path().await {
+                binary_input.set(path);
+            }
+        });
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("settings-managed-node")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-managed-node-intro")}
+            }
+            match &*config.read() {
+                None => rsx! {
+                    p { aria_busy: "true" }
+                },
+                Some(Err(e)) => rsx! {
+                    p {
+                        style: "color: var(--pico-del-color);",
+                        {t!("settings-managed-node-load-failed", error = e.to_string())}
+                    }
+                },
+                Some(Ok(_)) => rsx! {
+                    label {
+                        input {
+                            r#type: "checkbox",
+                            role: "switch",
+                            checked: enabled(),
+                            onchange: move |_| enabled.toggle(),
+                        }
+                        {t!("settings-managed-node-enable")}
+                    }
+                    label {
+                        {t!("settings-managed-node-binary")}
+                        div {
+                            role: "group",
+                            input {
+                                r#type: "text",
+                                value: "{binary_input}",
+                                oninput: move |e| binary_input.set(e.value()),
+                            }
+                            if crate::compat::CAN_PICK_PATHS {
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    disabled: is_busy(),
+                                    on_click: browse,
+                                    {t!("settings-data-dir-browse")}
+                                }
+                            }
+                        }
+                    }
+                    label {
+                        {t!("settings-managed-node-args")}
+                        input {
+                            r#type: "text",
+                            placeholder: "--network main",
+                            value: "{args_input}",
+                            oninput: move |e| args_input.set(e.value()),
+                        }
+                        small { {t!("settings-managed-node-args-hint")} }
+                    }
+                    Button {
+                        disabled: is_busy(),
+                        on_click: save,
+                        {t!("common-save")}
+                    }
+                },
+            }
+            match message() {
+                Some(Ok(m)) => rsx! {
+                    p { style: "color: var(--pico-ins-color);", "{m}" }
+                },
+                Some(Err(m)) => rsx! {
+                    p { style: "color: var(--pico-del-color);", "{m}" }
+                },
+                None => rsx! {},
+            }
+            if let Some(Ok(s)) = &*status.read() {
+                ManagedNodeStatusLine { status: s.clone() }
+            }
+            Link {
+                to: Route::NodeLogs {},
+                {t!("settings-managed-node-logs")}
+            }
+        }
+    }
+}
+
+/// Lists the devices using the wallet, and lets the user revoke any they do
+/// not recognise.
+#[component]
+fn SessionSettings() -> Element {
+    let mut sessions =
+        use_resource(move || async move { api::sessions().await.map_err(|e| e.to_string()) });
+    let mut revoke_error = use_signal(|| None::<String>);
+
+    let revoke = move |id: String| {
+        spawn(async move {
+            match api::revoke_session(id).await {
+                Ok(_) => {
+                    revoke_error.set(None);
+                    sessions.restart();
+                }
+                Err(e) => revoke_error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let date_time_format = format!("{} %H:%M", crate::i18n::date_format());
+
+    rsx! {
+        section {
+            h4 { {t!("settings-sessions")} }
+            if let Some(e) = revoke_error() {
+                p {
+                    style: "color: var(--pico-del-color);",
+                    {t!("settings-session-revoke-failed", error = e)}
+                }
+            }
+            match &*sessions.read() {
+                None => rsx! {
+                    p { aria_busy: "true" }
+                },
+                Some(Err(e)) => rsx! {
+                    p {
+                        style: "color: var(--pico-del-color);",
+                        {t!("settings-sessions-load-failed", error = e.as_str())}
+                    }
+                },
+                Some(Ok(list)) => rsx! {
+                    div {
+                        class: "overflow-auto",
+                        table {
+                            thead {
+                                tr {
+                                    th { scope: "col", {t!("settings-session-device")} }
+                                    th { scope: "col", {t!("settings-session-ip")} }
+                                    th { scope: "col", {t!("settings-session-last-active")} }
+                                    th {}
+                                }
+                            }
+                            tbody {
+                                for session in list.iter().cloned() {
+                                    {
+                                        let last_active = chrono::DateTime::from_timestamp_millis(session.last_active_ms as i64)
+                                            .map(|d| d.with_timezone(&chrono::Local).format(&date_time_format).to_string())
+                                            .unwrap_or_default();
+                                        let ip = session.ip.map(|ip| ip.to_string()).unwrap_or_else(|| "-".to_string());
+                                        let id = session.id.clone();
+                                        rsx! {
+                                            tr {
+                                                key: "{session.id}",
+                                                td {
+                                                    "{session.device}"
+                                                    small {
+                                                        style: "display: block; color: var(--pico-muted-color);",
+                                                        "{session.user}"
+                                                    }
+                                                }
+                                                td { "{ip}" }
+                                                td { "{last_active}" }
+                                                td {
+                                                    if session.current {
+                                                        small { {t!("settings-session-current")} }
+                                                    } else {
+                                                        Button {
+                                                            button_type: ButtonType::Secondary,
+                                                            outline: true,
+                                                            style: "margin-bottom: 0;",
+                                                            on_click: move |_| revoke(id.clone()),
+                                                            {t!("settings-session-revoke")}
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Exports and imports labels, tags and contacts, for sharing them with
+/// other Neptune tools.
+#[component]
+fn MetadataSettings() -> Element {
+    let mut is_busy = use_signal(|| false);
+    let mut status = use_signal(|| None::<String>);
+
+    let export = move |_: MouseEvent| {
+        is_busy.set(true);
+        status.set(None);
+        spawn(async move {
+            let result = match api::export_wallet_metadata().await {
+                Ok(json) => {
+                    crate::compat::save_file(
+                        "neptune-wallet-metadata.json",
+                        "JSON File",
+                        "json",
+                        json,
+                    )
+                    .await
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            status.set(match result {
+                Ok(true) => Some(t!("common-exported")),
+                Ok(false) => None,
+                Err(e) => Some(t!("common-export-failed", error = e)),
+            });
+            is_busy.set(false);
+        });
+    };
+
+    let import = move |_: MouseEvent| {
+        is_busy.set(true);
+        status.set(None);
+        spawn(async move {
+            let result = match crate::compat::read_file(&["json"]).await {
+                Ok(Some((_, json))) => api::import_wallet_metadata(json)
+                    .await
+                    .map(Some)
+                    .map_err(|e| e.to_string()),
+                Ok(None) => Ok(None),
+                Err(e) => Err(e),
+            };
+            status.set(match result {
+                Ok(Some(summary)) => Some(t!(
+                    "settings-metadata-imported",
+                    addresses = summary.addresses,
+                    transactions = summary.transactions,
+                    utxos = summary.utxos,
+                    skipped = summary.skipped
+                )),
+                Ok(None) => None,
+                Err(e) => Some(t!("settings-metadata-import-failed", error = e)),
+            });
+            is_busy.set(false);
+        });
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("settings-metadata")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-metadata-intro")}
+            }
+            div {
+                role: "group",
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    disabled: is_busy(),
+                    on_click: export,
+                    {t!("settings-metadata-export")}
+                }
+                Button {
+                    button_type: ButtonType::Secondary,
+                    outline: true,
+                    disabled: is_busy(),
+                    on_click: import,
+                    {t!("settings-metadata-import")}
+                }
+            }
+            if let Some(status) = status() {
+                p { style: "font-size: 0.9rem;", "{status}" }
+            }
+        }
+    }
+}
+
+/// Shows whether neptune-core's wallet file is encrypted, and how to protect
+/// it where neptune-core cannot encrypt it.
+#[component]
+fn WalletEncryptionSettings() -> Element {
+    let status = use_resource(move || async move {
+        api::wallet_encryption_status()
+            .await
+            .map_err(|e| e.to_string())
+    });
+
+    let Some(Ok(s)) = status() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("settings-wallet-encryption")} }
+            p {
+                match &s.state {
+                    WalletFileState::Missing => rsx! {
+                        {t!("settings-wallet-missing", file = s.wallet_file.clone())}
+                    },
+                    WalletFileState::PlainText => rsx! {
+                        "⚠️ "
+                        {t!("settings-wallet-not-encrypted", file = s.wallet_file.clone())}
+                    },
+                    WalletFileState::Unreadable(error) => rsx! {
+                        "❌ "
+                        {t!("settings-wallet-unreadable", file = s.wallet_file.clone(), error = error.clone())}
+                    },
+                }
+            }
+            if !s.passphrase_supported {
+                p {
+                    style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                    {t!("settings-wallet-encryption-unsupported")}
+                }
+                ul {
+                    style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                    li { {t!("settings-wallet-protect-disk")} }
+                    li { {t!("settings-wallet-protect-permissions")} }
+                    li { {t!("settings-wallet-protect-backup")} }
+                }
+            }
+        }
+    }
+}
+
+/// Links to restoring a wallet from its seed phrase.
+#[component]
+fn WalletImportSettings() -> Element {
+    rsx! {
+        section {
+            h4 { {t!("settings-import-wallet")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-import-wallet-intro")}
+            }
+            Link {
+                to: Route::ImportWallet {},
+                {t!("settings-import-wallet-open")}
+            }
+        }
+    }
+}
+
+/// Generates a support bundle for the user to attach to a support request.
+#[component]
+fn DiagnosticsSettings() -> Element {
+    let mut include_addresses = use_signal(|| false);
+    let mut is_generating = use_signal(|| false);
+    let mut status = use_signal(|| None::<String>);
+
+    let generate = move |_: MouseEvent| {
+        is_generating.set(true);
+        status.set(None);
+        spawn(async move {
+            let result = match api::support_bundle(include_addresses()).await {
+                Ok(bundle) => {
+                    crate::compat::save_file(
+                        "neptune-proton-support.json",
+                        "JSON File",
+                        "json",
+                        bundle,
+                    )
+                    .await
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            status.set(match result {
+                Ok(true) => Some(t!("common-exported")),
+                Ok(false) => None,
+                Err(e) => Some(t!("common-export-failed", error = e)),
+            });
+            is_generating.set(false);
+        });
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("settings-diagnostics")} }
+            p {
+                Link {
+                    to: Route::Health {},
+                    {t!("settings-health-open")}
+                }
+            }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-support-bundle-intro")}
+            }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: include_addresses(),
+                    onchange: move |_| include_addresses.toggle(),
+                }
+                {t!("settings-support-bundle-include-addresses")}
+            }
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: true,
+                disabled: is_generating(),
+                on_click: generate,
+                {t!("settings-support-bundle-generate")}
+            }
+            if let Some(status) = status() {
+                p { style: "font-size: 0.9rem;", "{status}" }
+            }
+        }
+    }
+}
+
+/// Endpoints shown in the table of slowest calls.
+const MAX_SLOWEST_METHODS: usize = 10;
+
+/// Failures shown, newest first.
+const MAX_SHOWN_FAILURES: usize = 10;
+
+fn format_time(at_ms: u64) -> String {
+    chrono::DateTime::from_timestamp_millis(at_ms as i64)
+        .map(|d| d.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// The slowest neptune-core RPC methods and the latest failed calls since
+/// the server started, for troubleshooting a slow or flaky node.
+#[component]
+fn RpcMetricsSettings() -> Element {
+    let mut metrics = use_resource(move || async move { api::rpc_metrics().await });
+
+    rsx! {
+        section {
+            h4 { {t!("settings-rpc-metrics")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-rpc-metrics-intro")}
+            }
+            match &*metrics.read() {
+                None => rsx! {
+                    p { aria_busy: "true" }
+                },
+                Some(Err(e)) => rsx! {
+                    p { style: "color: var(--pico-del-color);", "{e}" }
+                },
+                Some(Ok(m)) if m.methods.is_empty() => rsx! {
+                    p { {t!("settings-rpc-metrics-none")} }
+                },
+                Some(Ok(m)) => rsx! {
+                    small { {t!("settings-rpc-metrics-since", time = format_time(m.since_ms))} }
+                    div {
+                        class: "overflow-auto",
+                        table {
+                            thead {
+                                tr {
+                                    th { scope: "col", {t!("settings-rpc-metrics-method")} }
+                                    th { scope: "col", style: "text-align: right;", {t!("settings-rpc-metrics-calls")} }
+                                    th { scope: "col", style: "text-align: right;", {t!("settings-rpc-metrics-errors")} }
+                                    th { scope: "col", style: "text-align: right;", {t!("settings-rpc-metrics-mean")} }
+                                    th { scope: "col", style: "text-align: right;", {t!("settings-rpc-metrics-max")} }
+                                }
+                            }
+                            tbody {
+                                for method in m.methods.iter().take(MAX_SLOWEST_METHODS) {
+                                    tr {
+                                        key: "{method.method}",
+                                        td { code { "{method.method}" } }
+                                        td { style: "text-align: right;", "{method.calls}" }
+                                        td {
+                                            style: if method.errors > 0 { "text-align: right; color: var(--pico-del-color);" } else { "text-align: right;" },
+                                            "{method.errors} ({method.error_rate():.1}%)"
+                                        }
+                                        td { style: "text-align: right;", "{method.mean_ms} ms" }
+                                        td { style: "text-align: right;", "{method.max_ms} ms" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    h5 { {t!("settings-rpc-metrics-failures")} }
+                    if m.recent_failures.is_empty() {
+                        p { style: "font-size: 0.9rem;", {t!("settings-rpc-metrics-no-failures")} }
+                    } else {
+                        ul {
+                            style: "font-size: 0.9rem;",
+                            for (i, failure) in m.recent_failures.iter().take(MAX_SHOWN_FAILURES).enumerate() {
+                                li {
+                                    key: "{i}-{failure.at_ms}",
+                                    "{format_time(failure.at_ms)} "
+                                    code { "{failure.method}" }
+                                    " ({failure.elapsed_ms} ms): {failure.error}"
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: true,
+                on_click: move |_| metrics.restart(),
+                {t!("settings-rpc-metrics-refresh")}
+            }
+        }
+    }
+}
+
+/// Tools for protocol developers, shown only when the `DEV_MODE` env var is
+/// set.  Broadcasts a transaction carrying an arbitrary announcement.
+#[component]
+fn DeveloperSettings() -> Element {
+    let mut encoding = use_signal(AnnouncementEncoding::default);
+    let mut message_input = use_signal(String::new);
+    let mut fee_input = use_signal(String::new);
+    let mut is_broadcasting = use_signal(|| false);
+    let mut result = use_signal(|| None::<Result<String, String>>);
+
+    let parsed = api::announcement_input::parse(&message_input(), encoding());
+    let parsed_fee = NativeCurrencyAmount::coins_from_str(&fee_input().replace(',', "."));
+    let can_broadcast = parsed.is_ok() && parsed_fee.is_ok() && !is_broadcasting();
+
+    let broadcast = move |_: MouseEvent| {
+        let Ok(message) = api::announcement_input::parse(&message_input(), encoding()) else {
+            return;
+        };
+        let Ok(fee) = NativeCurrencyAmount::coins_from_str(&fee_input().replace(',', "."))
+        else {
+            return;
+        };
+        is_broadcasting.set(true);
+        result.set(None);
+        spawn(async move {
+            result.set(Some(
+                api::broadcast_announcement(message, fee)
+                    .await
+                    .map(|txid| txid.to_string())
+                    .map_err(|e| e.to_string()),
+            ));
+            is_broadcasting.set(false);
+        });
+    };
+
+    rsx! {
+        section {
+            h4 { {t!("settings-developer")} }
+            p {
+                style: "color: var(--pico-muted-color); font-size: 0.9rem;",
+                {t!("settings-announcement-intro")}
+            }
+            label {
+                {t!("settings-announcement-encoding")}
+                select {
+                    onchange: move |e| {
+                        if let Some(enc) = AnnouncementEncoding::iter()
+                            .find(|enc| enc.name() == e.value())
+                        {
+                            encoding.set(enc);
+                        }
+                    },
+                    for enc in AnnouncementEncoding::iter() {
+                        option {
+                            key: "{enc.name()}",
+                            value: "{enc.name()}",
+                            selected: enc == encoding(),
+                            "{enc.name()}"
+                        }
+                    }
+                }
+            }
+            label {
+                {t!("settings-announcement-message")}
+                textarea {
+                    rows: 4,
+                    style: "font-family: monospace;",
+                    placeholder: match encoding() {
+                        AnnouncementEncoding::Hex => "0x01 0x2a ff",
+                        AnnouncementEncoding::Utf8 => "hello",
+                    },
+                    value: "{message_input}",
+                    oninput: move |e| message_input.set(e.value()),
+                }
+            }
+            match &parsed {
+                Ok(message) => rsx! {
+                    small { {t!("settings-announcement-elements", count = message.len())} }
+                },
+                Err(e) if !message_input().is_empty() => rsx! {
+                    small { style: "color: var(--pico-del-color);", "{e}" }
+                },
+                Err(_) => rsx! {},
+            }
+            label {
+                {t!("settings-announcement-fee")}
+                input {
+                    r#type: "text",
+                    inputmode: "decimal",
+                    placeholder: "0.01",
+                    value: "{fee_input}",
+                    oninput: move |e| fee_input.set(e.value()),
+                }
+            }
+            Button {
+                button_type: ButtonType::Secondary,
+                outline: true,
+                disabled: !can_broadcast,
+                on_click: broadcast,
+                {t!("settings-announcement-broadcast")}
+            }
+            match result() {
+                Some(Ok(txid)) => rsx! {
+                    p {
+                        style: "font-size: 0.9rem; word-break: break-all;",
+                        {t!("settings-announcement-sent", txid = txid)}
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { style: "font-size: 0.9rem; color: var(--pico-del-color);", "{e}" }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
@@ -1,21 +1,44 @@
 //=============================================================================
 // File: src/screens/utxos.rs
 //=============================================================================
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use api::asset::asset_of;
+use api::prefs::refresh_intervals::RefreshScreen;
+use api::utxo_annotation::UtxoAnnotation;
+use api::utxo_annotation::UtxoAnnotations;
+use api::utxo_snapshot::SnapshotFormat;
+use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
 use neptune_types::block_height::BlockHeight;
 use neptune_types::block_selector::BlockSelector;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::transaction_kernel_id::TransactionKernelId;
 use neptune_types::ui_utxo::UiUtxo;
 use neptune_types::ui_utxo::UtxoStatusEvent;
+use num_traits::Zero;
+use strum::IntoEnumIterator;
 
+use crate::app_state::AppState;
 use crate::components::action_link::ActionLink;
+use crate::app_state_mut::AppStateMut;
 use crate::components::amount::Amount;
+use crate::components::amount::TABLE_MAX_DECIMALS;
+use crate::components::chart::ChartPoint;
+use crate::components::chart::ScatterChart;
 use crate::components::empty_state::EmptyState;
+use crate::components::pico::Button;
+use crate::components::pico::ButtonType;
 use crate::components::pico::Card;
+use crate::components::pico::NoTitleModal;
+use crate::components::selection_bar::SelectionBar;
+use crate::components::skeleton::SkeletonTable;
 use crate::hooks::use_rpc_checker::use_rpc_checker;
-use crate::Screen;
+use crate::hooks::use_selection::use_selection;
+use crate::polling::PollWeight;
+use crate::Route;
 
 const UTXOS_EMPTY_SVG: &str = include_str!("../../assets/svg/utxos-empty.svg");
 
@@ -41,6 +64,20 @@ enum DisplayMode {
     BlockHeight,
 }
 
+/// Bulk actions that need a modal for further input.
+#[derive(Clone, Copy, PartialEq)]
+enum BulkAction {
+    Tag,
+    /// Labels the one UTXO selected.
+    Label,
+    Consolidate,
+    /// Picks UTXOs to consolidate, then continues as [BulkAction::Consolidate].
+    ConsolidateSmallest,
+}
+
+/// How many UTXOs "Consolidate Smallest" picks unless told otherwise.
+const DEFAULT_CONSOLIDATE_COUNT: usize = 10;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct UiUtxoReadOnly(Rc<UiUtxo>);
 
@@ -61,14 +98,97 @@ fn get_event_sort_key(event: &UtxoStatusEvent) -> u64 {
     }
 }
 
+const MS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+fn npt_as_f64(amount: NativeCurrencyAmount) -> f64 {
+    amount.to_nau() as f64 / NativeCurrencyAmount::coins(1).to_nau() as f64
+}
+
+/// The AOCL indices of the `n` smallest UTXOs that can be spent now: confirmed,
+/// unspent, not timelocked and not marked do-not-spend.
+fn smallest_spendable(
+    utxos: &[UiUtxoReadOnly],
+    annotations: &UtxoAnnotations,
+    n: usize,
+) -> Vec<u64> {
+    let now_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+    let mut spendable: Vec<&UiUtxoReadOnly> = utxos
+        .iter()
+        .filter(|u| {
+            matches!(u.spent, UtxoStatusEvent::None)
+                && matches!(u.received, UtxoStatusEvent::Confirmed { .. })
+                && !u.release_date.is_some_and(|ts| ts.to_millis() > now_ms)
+                && u
+                    .aocl_leaf_index
+                    .is_some_and(|i| !annotations.get(&i).is_some_and(|a| a.do_not_spend))
+        })
+        .collect();
+    spendable.sort_by_key(|u| u.amount);
+    spendable
+        .into_iter()
+        .take(n)
+        .filter_map(|u| u.aocl_leaf_index)
+        .collect()
+}
+
+/// Plots unspent, confirmed UTXOs by age and value, to help pick UTXOs to
+/// consolidate. Clicking a point toggles its selection.
+#[component]
+fn UtxoAgeChart(utxos: Vec<UiUtxoReadOnly>, selected: Vec<u64>, on_toggle: EventHandler<u64>) -> Element {
+    let now_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+
+    let (indices, points): (Vec<u64>, Vec<ChartPoint>) = utxos
+        .iter()
+        .filter(|u| matches!(u.spent, UtxoStatusEvent::None))
+        .filter_map(|u| {
+            let index = u.aocl_leaf_index?;
+            let UtxoStatusEvent::Confirmed { timestamp, .. } = &u.received else {
+                return None;
+            };
+            let age_days = now_ms.saturating_sub(timestamp.to_millis()) as f64 / MS_PER_DAY;
+            let npt = npt_as_f64(u.amount);
+            let point = ChartPoint {
+                x: age_days,
+                y: npt,
+                size: npt,
+                label: format!("#{}: {} NPT, {:.0} days old", index, u.amount, age_days),
+                highlighted: selected.contains(&index),
+            };
+            Some((index, point))
+        })
+        .unzip();
+
+    if points.is_empty() {
+        return rsx! {
+            p { style: "color: var(--pico-muted-color);", "No confirmed, unspent UTXOs to chart." }
+        };
+    }
+
+    rsx! {
+        ScatterChart {
+            points,
+            x_label: "Age (days)".to_string(),
+            y_label: "Amount (NPT)".to_string(),
+            format_x: |days: f64| format!("{:.0}", days),
+            format_y: |npt: f64| {
+                if npt >= 1.0 { format!("{:.0}", npt) } else { format!("{:.4}", npt) }
+            },
+            log_y: true,
+            on_point_click: move |i: usize| on_toggle.call(indices[i]),
+        }
+        small {
+            style: "color: var(--pico-muted-color);",
+            "Bubble size shows value. Click a bubble to select that UTXO; many small, old UTXOs are good candidates for consolidation."
+        }
+    }
+}
+
 #[component]
 fn BlockHeightDisplay(height: BlockHeight) -> Element {
-    let active_screen = use_context::<Signal<Screen>>();
 
     rsx! {
         ActionLink {
-            state: active_screen,
-            to: Screen::Block(BlockSelector::Height(height)),
+            to: Route::Block { selector: BlockSelector::Height(height) },
             "{height}"
         }
     }
@@ -143,25 +263,41 @@ fn SortableHeader(
         ("\u{00A0}", false)
     };
 
+    let aria_sort = match (is_active, *sort_direction.read()) {
+        (false, _) => "none",
+        (true, SortDirection::Ascending) => "ascending",
+        (true, SortDirection::Descending) => "descending",
+    };
+    let mut sort = move || {
+        if is_active {
+            sort_direction.with_mut(|dir| {
+                *dir = match dir {
+                    SortDirection::Ascending => SortDirection::Descending,
+                    SortDirection::Descending => SortDirection::Ascending,
+                };
+            });
+        } else {
+            sort_column.set(column);
+            sort_direction.set(SortDirection::Ascending);
+        }
+    };
+
     rsx! {
         th {
             style: format!("{}{}", "position: sticky; top: 0; background: var(--pico-card-background-color); z-index: 20; cursor: pointer; white-space: nowrap; ", style.unwrap_or("")),
-            onclick: move |_| {
-                if is_active {
-                    sort_direction.with_mut(|dir| {
-                        *dir = match dir {
-                            SortDirection::Ascending => SortDirection::Descending,
-                            SortDirection::Descending => SortDirection::Ascending,
-                        };
-                    });
-                } else {
-                    sort_column.set(column);
-                    sort_direction.set(SortDirection::Ascending);
+            scope: "col",
+            tabindex: "0",
+            "aria-sort": aria_sort,
+            onclick: move |_| sort(),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Enter {
+                    sort();
                 }
             },
             "{title}"
             span {
                 style: "display: inline-block; width: 1.2em; text-align: right;",
+                "aria-hidden": "true",
                 "{arrow_char}"
             }
         }
@@ -169,8 +305,435 @@ fn SortableHeader(
 }
 
 #[component]
-fn UtxoRow(utxo: UiUtxoReadOnly, display_mode: Signal<DisplayMode>) -> Element {
+fn TagUtxosModalContent(
+    aocl_indices: Vec<u64>,
+    show_modal: Signal<bool>,
+    on_done: EventHandler<()>,
+) -> Element {
+    let mut tag_input = use_signal(String::new);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut api_in_progress = use_signal(|| false);
+    let count = aocl_indices.len();
+
+    let handle_apply = move |_| {
+        let indices = aocl_indices.clone();
+        let tag = tag_input();
+        api_in_progress.set(true);
+        spawn(async move {
+            match api::tag_utxos(indices, tag).await {
+                Ok(_) => {
+                    show_modal.set(false);
+                    on_done.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            api_in_progress.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            header {
+                h3 { "Tag {count} UTXOs" }
+            }
+            label {
+                "Tag"
+                input {
+                    r#type: "text",
+                    placeholder: "eg. savings",
+                    value: "{tag_input}",
+                    oninput: move |e| tag_input.set(e.value()),
+                }
+            }
+            if let Some(err) = error() {
+                p { style: "color: var(--pico-color-red-500);", "{err}" }
+            }
+            footer {
+                Button {
+                    button_type: ButtonType::Secondary,
+                    on_click: move |_| show_modal.set(false),
+                    style: "margin-right: 1rem;",
+                    "Cancel"
+                }
+                Button {
+                    on_click: handle_apply,
+                    disabled: tag_input().trim().is_empty() || api_in_progress(),
+                    "Apply Tag"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn LabelUtxoModalContent(
+    aocl_index: u64,
+    label: Option<String>,
+    show_modal: Signal<bool>,
+    on_done: EventHandler<()>,
+) -> Element {
+    let mut label_input = use_signal(|| label.unwrap_or_default());
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut api_in_progress = use_signal(|| false);
+
+    let handle_save = move |_| {
+        let label = label_input();
+        api_in_progress.set(true);
+        spawn(async move {
+            match api::set_utxo_label(aocl_index, label).await {
+                Ok(_) => {
+                    show_modal.set(false);
+                    on_done.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            api_in_progress.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            header {
+                h3 { "Label UTXO {aocl_index}" }
+            }
+            label {
+                "Label"
+                input {
+                    r#type: "text",
+                    placeholder: "eg. payment from Alice",
+                    value: "{label_input}",
+                    oninput: move |e| label_input.set(e.value()),
+                }
+                small { "Leave empty to remove the label." }
+            }
+            if let Some(err) = error() {
+                p { style: "color: var(--pico-color-red-500);", "{err}" }
+            }
+            footer {
+                Button {
+                    button_type: ButtonType::Secondary,
+                    on_click: move |_| show_modal.set(false),
+                    style: "margin-right: 1rem;",
+                    "Cancel"
+                }
+                Button {
+                    on_click: handle_save,
+                    disabled: api_in_progress(),
+                    "Save Label"
+                }
+            }
+        }
+    }
+}
+
+/// Asks how many UTXOs to consolidate, and picks the smallest spendable ones.
+#[component]
+fn ConsolidateSmallestModalContent(
+    utxos: Vec<UiUtxoReadOnly>,
+    annotations: UtxoAnnotations,
+    show_modal: Signal<bool>,
+    on_pick: EventHandler<Vec<u64>>,
+) -> Element {
+    let mut count_input = use_signal(|| DEFAULT_CONSOLIDATE_COUNT.to_string());
+
+    let picked = count_input()
+        .trim()
+        .parse::<usize>()
+        .map(|n| smallest_spendable(&utxos, &annotations, n))
+        .unwrap_or_default();
+    let total = utxos
+        .iter()
+        .filter(|u| u.aocl_leaf_index.is_some_and(|i| picked.contains(&i)))
+        .fold(NativeCurrencyAmount::zero(), |acc, u| acc + u.amount);
+    let num_picked = picked.len();
+
+    rsx! {
+        div {
+            header {
+                h3 { "Consolidate Smallest UTXOs" }
+            }
+            p {
+                "Picks the smallest UTXOs that can be spent now, ie confirmed, unspent, not timelocked and not marked do-not-spend. "
+                "Merging them keeps future sends from needing many inputs, and the larger fees that come with them."
+            }
+            label {
+                "Number of UTXOs"
+                input {
+                    r#type: "number",
+                    min: "2",
+                    value: "{count_input}",
+                    oninput: move |e| count_input.set(e.value()),
+                }
+            }
+            if num_picked >= 2 {
+                p {
+                    "{num_picked} UTXOs, totalling "
+                    Amount { amount: total }
+                    "."
+                }
+            } else {
+                p {
+                    style: "color: var(--pico-muted-color);",
+                    "Fewer than two UTXOs can be spent now."
+                }
+            }
+            footer {
+                Button {
+                    button_type: ButtonType::Secondary,
+                    on_click: move |_| show_modal.set(false),
+                    style: "margin-right: 1rem;",
+                    "Cancel"
+                }
+                Button {
+                    disabled: num_picked < 2,
+                    on_click: move |_| on_pick.call(picked.clone()),
+                    "Next"
+                }
+            }
+        }
+    }
+}
+
+/// Exports all UTXOs as of the current tip, for an auditor or accountant,
+/// and shows the file's checksum to hand over with it.
+#[component]
+fn ExportSnapshotModalContent(show_modal: Signal<bool>) -> Element {
+    let mut snapshot_format = use_signal(SnapshotFormat::default);
+    let mut checksum = use_signal::<Option<String>>(|| None);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut api_in_progress = use_signal(|| false);
+
+    let handle_export = move |_| {
+        api_in_progress.set(true);
+        error.set(None);
+        checksum.set(None);
+        spawn(async move {
+            match api::export_utxo_snapshot(snapshot_format()).await {
+                Ok(export) => {
+                    let name = snapshot_format().to_string();
+                    match crate::compat::save_file(
+                        &export.file_name,
+                        &format!("{name} File"),
+                        snapshot_format().extension(),
+                        export.contents,
+                    )
+                    .await
+                    {
+                        Ok(true) => checksum.set(Some(export.sha256)),
+                        Ok(false) => {}
+                        Err(e) => error.set(Some(format!("Export failed: {e}"))),
+                    }
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            api_in_progress.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            header {
+                h3 { "Export UTXO Snapshot" }
+            }
+            p {
+                "Writes every UTXO of this wallet, with its amount, status and block, as of the current tip. "
+                "The file names the tip's height and digest, so that it can be checked against the blockchain."
+            }
+            label {
+                "Format"
+                select {
+                    onchange: move |e| {
+                        if let Some(f) = SnapshotFormat::iter().find(|f| f.to_string() == e.value()) {
+                            snapshot_format.set(f);
+                        }
+                    },
+                    for f in SnapshotFormat::iter() {
+                        option {
+                            key: "{f}",
+                            value: "{f}",
+                            selected: f == snapshot_format(),
+                            "{f}"
+                        }
+                    }
+                }
+            }
+            if let Some(sha256) = checksum() {
+                article {
+                    role: "status",
+                    p { "Exported. Its SHA-256 checksum is:" }
+                    code {
+                        style: "display: block; word-break: break-all; margin-bottom: 0.5rem;",
+                        "{sha256}"
+                    }
+                    small {
+                        style: "display: block; color: var(--pico-muted-color); margin-bottom: 0.5rem;",
+                        "Send the checksum separately from the file, so that the recipient can tell it was not altered, eg with sha256sum."
+                    }
+                    Button {
+                        button_type: ButtonType::Secondary,
+                        outline: true,
+                        style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                        on_click: move |_| {
+                            let sha256 = sha256.clone();
+                            spawn(async move {
+                                crate::compat::clipboard_set(sha256).await;
+                            });
+                        },
+                        "Copy Checksum"
+                    }
+                }
+            }
+            if let Some(err) = error() {
+                p { style: "color: var(--pico-color-red-500);", "{err}" }
+            }
+            footer {
+                Button {
+                    button_type: ButtonType::Secondary,
+                    on_click: move |_| show_modal.set(false),
+                    style: "margin-right: 1rem;",
+                    "Close"
+                }
+                Button {
+                    on_click: handle_export,
+                    disabled: api_in_progress(),
+                    if api_in_progress() {
+                        "Exporting..."
+                    } else {
+                        "Export"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ConsolidateModalContent(
+    aocl_indices: Vec<u64>,
+    total: NativeCurrencyAmount,
+    show_modal: Signal<bool>,
+    on_done: EventHandler<()>,
+) -> Element {
+    let mut fee_input = use_signal(String::new);
+    let mut status = use_signal::<Option<Result<TransactionKernelId, String>>>(|| None);
+    let mut api_in_progress = use_signal(|| false);
+    let count = aocl_indices.len();
+
+    let parsed_fee = NativeCurrencyAmount::coins_from_str(&fee_input().replace(',', "."));
+    let fee_ok = matches!(&parsed_fee, Ok(fee) if *fee < total);
+    // what the fee costs, against the inputs it saves later sends.
+    let fee_impact = parsed_fee
+        .ok()
+        .filter(|_| fee_ok)
+        .map(|fee| (total - fee, 100.0 * npt_as_f64(fee) / npt_as_f64(total)));
+
+    let handle_consolidate = move |_| {
+        let Ok(fee) = NativeCurrencyAmount::coins_from_str(&fee_input().replace(',', ".")) else {
+            return;
+        };
+        let indices = aocl_indices.clone();
+        api_in_progress.set(true);
+        spawn(async move {
+            let result = api::consolidate_utxos(indices, fee)
+                .await
+                .map(|(txid, _)| txid)
+                .map_err(|e| format!("{:#}", e));
+            if result.is_ok() {
+                on_done.call(());
+            }
+            status.set(Some(result));
+            api_in_progress.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            header {
+                h3 { "Consolidate {count} UTXOs" }
+            }
+            match status() {
+                Some(Ok(txid)) => rsx! {
+                    p { "Consolidation transaction created." }
+                    footer {
+                        Button {
+                            on_click: move |_| {
+                                show_modal.set(false);
+                                navigator().push(Route::MempoolTx { tx_id: txid });
+                            },
+                            "View in Mempool"
+                        }
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    p { "Consolidation failed." }
+                    p { "Details: {err}" }
+                    footer {
+                        Button {
+                            button_type: ButtonType::Secondary,
+                            on_click: move |_| show_modal.set(false),
+                            "Close"
+                        }
+                    }
+                },
+                None => rsx! {
+                    p {
+                        "The selected UTXOs, totalling "
+                        Amount { amount: total }
+                        ", will be spent to a new address of this wallet, leaving a single UTXO."
+                    }
+                    label {
+                        "Fee (NPT)"
+                        input {
+                            r#type: "text",
+                            inputmode: "decimal",
+                            value: "{fee_input}",
+                            oninput: move |e| fee_input.set(e.value()),
+                        }
+                    }
+                    if let Some((remaining, fee_percent)) = fee_impact {
+                        p {
+                            style: "font-size: 0.9rem;",
+                            "The new UTXO will hold "
+                            Amount { amount: remaining }
+                            ". The fee is {fee_percent:.2}% of the amount consolidated; in return, spending these funds later takes one input instead of {count}."
+                        }
+                    }
+                    footer {
+                        Button {
+                            button_type: ButtonType::Secondary,
+                            on_click: move |_| show_modal.set(false),
+                            disabled: api_in_progress(),
+                            style: "margin-right: 1rem;",
+                            "Cancel"
+                        }
+                        Button {
+                            on_click: handle_consolidate,
+                            disabled: !fee_ok || api_in_progress(),
+                            if api_in_progress() {
+                                "Building transaction..."
+                            } else {
+                                "Consolidate"
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn UtxoRow(
+    utxo: UiUtxoReadOnly,
+    display_mode: Signal<DisplayMode>,
+    annotation: Option<UtxoAnnotation>,
+    selected: bool,
+    on_toggle: EventHandler<()>,
+    /// Adds the asset column, for wallets holding more than one asset.
+    show_asset: bool,
+) -> Element {
     let mut is_hovered = use_signal(|| false);
+    let annotation = annotation.unwrap_or_default();
 
     let index_display = match utxo.aocl_leaf_index {
         Some(idx) => idx.to_string(),
@@ -193,6 +756,14 @@ fn UtxoRow(utxo: UiUtxoReadOnly, display_mode: Signal<DisplayMode>) -> Element {
             onmouseenter: move |_| is_hovered.set(true),
             onmouseleave: move |_| is_hovered.set(false),
 
+            td {
+                input {
+                    r#type: "checkbox",
+                    checked: selected,
+                    disabled: utxo.aocl_leaf_index.is_none(),
+                    onchange: move |_| on_toggle.call(()),
+                }
+            }
             td {
                 UtxoEventDisplay {
                     event: utxo.received,
@@ -201,11 +772,35 @@ fn UtxoRow(utxo: UiUtxoReadOnly, display_mode: Signal<DisplayMode>) -> Element {
             }
             td {
                 "{index_display}"
+                if annotation.do_not_spend {
+                    span {
+                        title: "Marked do-not-spend",
+                        style: "margin-left: 0.25rem;",
+                        "🔒"
+                    }
+                }
+                if let Some(label) = annotation.label.as_ref() {
+                    small {
+                        style: "margin-left: 0.25rem; color: var(--pico-muted-color); font-style: italic;",
+                        "{label}"
+                    }
+                }
+                for tag in annotation.tags.iter() {
+                    small {
+                        key: "{tag}",
+                        style: "margin-left: 0.25rem; padding: 0 0.3rem; border: 1px solid var(--pico-muted-border-color); border-radius: var(--pico-border-radius); color: var(--pico-muted-color);",
+                        "{tag}"
+                    }
+                }
+            }
+            if show_asset {
+                td { "{asset_of(&utxo)}" }
             }
             td {
                 style: "text-align: right; white-space: nowrap; min-width: 21ch;",
                 Amount {
                     amount: utxo.amount,
+                    max_decimals: TABLE_MAX_DECIMALS,
                 }
             }
             td {
@@ -226,7 +821,17 @@ fn UtxoRow(utxo: UiUtxoReadOnly, display_mode: Signal<DisplayMode>) -> Element {
 #[component]
 pub fn UtxosScreen() -> Element {
     let mut rpc = use_rpc_checker();
+    let can_spend = use_context::<AppState>().capabilities.can_spend;
     let mut utxos_resource = use_resource(move || async move { api::list_utxos().await });
+    let mut annotations_resource =
+        use_resource(move || async move { api::utxo_annotations().await });
+
+    // Selection state, by AOCL leaf index.
+    let mut selection = use_selection::<u64>();
+    let mut bulk_action = use_signal::<Option<BulkAction>>(|| None);
+    let mut show_bulk_modal = use_signal(|| false);
+    let mut bulk_error = use_signal::<Option<String>>(|| None);
+    let mut show_export_modal = use_signal(|| false);
 
     // State for display mode
     let mut display_mode = use_signal(|| DisplayMode::Date);
@@ -242,12 +847,18 @@ pub fn UtxosScreen() -> Element {
         }
     });
 
+    let app_state_mut = use_context::<AppStateMut>();
     use_coroutine(move |_rx: UnboundedReceiver<()>| {
         let rpc_status = rpc.status();
         let mut data_resource = utxos_resource;
         async move {
             loop {
-                crate::compat::sleep(std::time::Duration::from_secs(10)).await;
+                crate::polling::wait_for_screen_refresh(
+                    app_state_mut,
+                    RefreshScreen::Utxos,
+                    PollWeight::Heavy,
+                )
+                .await;
                 if (*rpc_status.read()).is_connected() {
                     data_resource.restart();
                 }
@@ -255,10 +866,116 @@ pub fn UtxosScreen() -> Element {
         }
     });
 
+    let annotations = annotations_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok().cloned())
+        .unwrap_or_default();
+
+    let mut set_do_not_spend = move |do_not_spend: bool| {
+        let indices = selection.keys();
+        spawn(async move {
+            match api::set_utxos_do_not_spend(indices, do_not_spend).await {
+                Ok(_) => bulk_error.set(None),
+                Err(e) => bulk_error.set(Some(e.to_string())),
+            }
+            annotations_resource.restart();
+        });
+    };
+
+    let copy_indices = move |_| {
+        let mut indices = selection.keys();
+        indices.sort_unstable();
+        let text = indices
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        spawn(async move {
+            crate::compat::clipboard_set(text).await;
+        });
+    };
+
+    let selected_indices = {
+        let mut v = selection.keys();
+        v.sort_unstable();
+        v
+    };
+
     rsx! {
+        NoTitleModal {
+            is_open: show_export_modal,
+            ExportSnapshotModalContent { show_modal: show_export_modal }
+        }
+
+        if let Some(action) = bulk_action() {
+            NoTitleModal {
+                is_open: show_bulk_modal,
+                match action {
+                    BulkAction::Tag => rsx! {
+                        TagUtxosModalContent {
+                            aocl_indices: selected_indices.clone(),
+                            show_modal: show_bulk_modal,
+                            on_done: move |_| annotations_resource.restart(),
+                        }
+                    },
+                    BulkAction::Label => rsx! {
+                        if let Some(&index) = selected_indices.first() {
+                            LabelUtxoModalContent {
+                                key: "{index}",
+                                aocl_index: index,
+                                label: annotations.get(&index).and_then(|a| a.label.clone()),
+                                show_modal: show_bulk_modal,
+                                on_done: move |_| annotations_resource.restart(),
+                            }
+                        }
+                    },
+                    BulkAction::ConsolidateSmallest => rsx! {
+                        ConsolidateSmallestModalContent {
+                            utxos: utxos_resource
+                                .read()
+                                .as_ref()
+                                .and_then(|r| r.as_ref().ok())
+                                .map(|list| list.iter().cloned().map(|u| UiUtxoReadOnly(Rc::new(u))).collect())
+                                .unwrap_or_default(),
+                            annotations: annotations.clone(),
+                            show_modal: show_bulk_modal,
+                            on_pick: move |picked: Vec<u64>| {
+                                selection.set(picked);
+                                bulk_action.set(Some(BulkAction::Consolidate));
+                            },
+                        }
+                    },
+                    BulkAction::Consolidate => {
+                        let total = utxos_resource
+                            .read()
+                            .as_ref()
+                            .and_then(|r| r.as_ref().ok())
+                            .map(|list| {
+                                list.iter()
+                                    .filter(|u| u.aocl_leaf_index.is_some_and(|i| selection.is_selected(&i)))
+                                    .fold(NativeCurrencyAmount::zero(), |acc, u| acc + u.amount)
+                            })
+                            .unwrap_or_else(NativeCurrencyAmount::zero);
+                        rsx! {
+                            ConsolidateModalContent {
+                                aocl_indices: selected_indices.clone(),
+                                total,
+                                show_modal: show_bulk_modal,
+                                on_done: move |_| {
+                                    selection.clear();
+                                    utxos_resource.restart();
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
         match &*utxos_resource.read() {
             None => rsx! {
-                Card { h3 { "UTXOs" }, p { "Loading..." }, progress {} }
+                SkeletonTable { title: "UTXOs".to_string(), columns: 6 }
             },
             Some(result) if !rpc.check_result_ref(&result) => rsx! {
                 Card { h3 { "UTXOs" } }
@@ -286,6 +1003,13 @@ pub fn UtxosScreen() -> Element {
                 }
             },
             Some(Ok(utxo_list)) => {
+                // wallets holding several assets see each asset's UTXOs together.
+                let show_asset = utxo_list
+                    .iter()
+                    .map(asset_of)
+                    .collect::<HashSet<_>>()
+                    .len()
+                    > 1;
                 let mut sorted_utxos = utxo_list.clone();
                 sorted_utxos.sort_by(|a, b| {
                     let ordering = match sort_column() {
@@ -299,12 +1023,17 @@ pub fn UtxosScreen() -> Element {
                             get_event_sort_key(&a.spent).cmp(&get_event_sort_key(&b.spent))
                         },
                     };
-                    match sort_direction() {
+                    let ordering = match sort_direction() {
                         SortDirection::Ascending => ordering,
                         SortDirection::Descending => ordering.reverse(),
-                    }
+                    };
+                    asset_of(a).cmp(&asset_of(b)).then(ordering)
                 });
 
+                let selectable: HashSet<u64> =
+                    utxo_list.iter().filter_map(|u| u.aocl_leaf_index).collect();
+                let all_selected = selection.is_all_selected(&selectable);
+
                 rsx! {
                     Card {
                         div {
@@ -319,19 +1048,120 @@ pub fn UtxosScreen() -> Element {
                                 }
                             }
 
-                            select {
-                                style: "width: auto; margin-bottom: 0; padding: 4px 8px; font-size: 0.9rem;",
-                                onchange: move |evt| {
-                                    match evt.value().as_str() {
-                                        "date" => display_mode.set(DisplayMode::Date),
-                                        "datetime" => display_mode.set(DisplayMode::DateTime),
-                                        "height" => display_mode.set(DisplayMode::BlockHeight),
-                                        _ => {}
+                            div {
+                                style: "display: flex; align-items: center; gap: 0.5rem;",
+                                if can_spend {
+                                    Button {
+                                        button_type: ButtonType::Secondary,
+                                        outline: true,
+                                        style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                        title: "Merge the smallest spendable UTXOs into one",
+                                        on_click: move |_| {
+                                            bulk_action.set(Some(BulkAction::ConsolidateSmallest));
+                                            show_bulk_modal.set(true);
+                                        },
+                                        "Consolidate Smallest..."
                                     }
-                                },
-                                option { value: "date", selected: *display_mode.read() == DisplayMode::Date, "Date" }
-                                option { value: "datetime", selected: *display_mode.read() == DisplayMode::DateTime, "Date & Time" }
-                                option { value: "height", selected: *display_mode.read() == DisplayMode::BlockHeight, "Height" }
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    title: "Export all UTXOs as of the current tip, for audit",
+                                    on_click: move |_| show_export_modal.set(true),
+                                    "Export..."
+                                }
+                                select {
+                                    style: "width: auto; margin-bottom: 0; padding: 4px 8px; font-size: 0.9rem;",
+                                    onchange: move |evt| {
+                                        match evt.value().as_str() {
+                                            "date" => display_mode.set(DisplayMode::Date),
+                                            "datetime" => display_mode.set(DisplayMode::DateTime),
+                                            "height" => display_mode.set(DisplayMode::BlockHeight),
+                                            _ => {}
+                                        }
+                                    },
+                                    option { value: "date", selected: *display_mode.read() == DisplayMode::Date, "Date" }
+                                    option { value: "datetime", selected: *display_mode.read() == DisplayMode::DateTime, "Date & Time" }
+                                    option { value: "height", selected: *display_mode.read() == DisplayMode::BlockHeight, "Height" }
+                                }
+                            }
+                        }
+
+                        if !selection.is_empty() {
+                            SelectionBar {
+                                count: selection.len(),
+                                on_clear: move |_| selection.clear(),
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    on_click: move |_| {
+                                        bulk_action.set(Some(BulkAction::Tag));
+                                        show_bulk_modal.set(true);
+                                    },
+                                    "Tag..."
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    disabled: selection.len() != 1,
+                                    title: "Label the selected UTXO",
+                                    on_click: move |_| {
+                                        bulk_action.set(Some(BulkAction::Label));
+                                        show_bulk_modal.set(true);
+                                    },
+                                    "Label..."
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    title: "Exclude these UTXOs from all sends, including coin-control ones",
+                                    on_click: move |_| set_do_not_spend(true),
+                                    "Do Not Spend"
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    on_click: move |_| set_do_not_spend(false),
+                                    "Allow Spending"
+                                }
+                                Button {
+                                    button_type: ButtonType::Secondary,
+                                    outline: true,
+                                    style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                    on_click: copy_indices,
+                                    "Copy Indices"
+                                }
+                                if can_spend {
+                                    Button {
+                                        button_type: ButtonType::Secondary,
+                                        outline: true,
+                                        style: "padding: 0.2rem 0.5rem; font-size: 0.8rem; margin: 0;",
+                                        disabled: selection.len() < 2,
+                                        title: "Merge the selected UTXOs into one",
+                                        on_click: move |_| {
+                                            bulk_action.set(Some(BulkAction::Consolidate));
+                                            show_bulk_modal.set(true);
+                                        },
+                                        "Consolidate..."
+                                    }
+                                }
+                            }
+                            if let Some(err) = bulk_error() {
+                                p { style: "color: var(--pico-color-red-500);", "{err}" }
+                            }
+                        }
+
+                        details {
+                            summary { "Age Distribution" }
+                            UtxoAgeChart {
+                                utxos: utxo_list.iter().cloned().map(|u| UiUtxoReadOnly(Rc::new(u))).collect::<Vec<_>>(),
+                                selected: selected_indices.clone(),
+                                on_toggle: move |i| selection.toggle(i),
                             }
                         }
 
@@ -340,8 +1170,25 @@ pub fn UtxosScreen() -> Element {
                             table {
                                 thead {
                                     tr {
+                                        th {
+                                            scope: "col",
+                                            style: "position: sticky; top: 0; background: var(--pico-card-background-color); z-index: 20;",
+                                            input {
+                                                r#type: "checkbox",
+                                                "aria-label": "Select all",
+                                                checked: all_selected,
+                                                onchange: move |_| selection.toggle_all(selectable.clone()),
+                                            }
+                                        }
                                         SortableHeader { title: "Received", column: SortableColumn::Received, sort_column, sort_direction }
                                         SortableHeader { title: "Index", column: SortableColumn::Index, sort_column, sort_direction }
+                                        if show_asset {
+                                            th {
+                                                scope: "col",
+                                                style: "position: sticky; top: 0; background: var(--pico-card-background-color); z-index: 20;",
+                                                "Asset"
+                                            }
+                                        }
                                         SortableHeader { title: "Amount", column: SortableColumn::Amount, sort_column, sort_direction, style: "text-align: right; padding-right: 0" }
                                         SortableHeader { title: "Releases", column: SortableColumn::Releases, sort_column, sort_direction }
                                         SortableHeader { title: "Spent", column: SortableColumn::Spent, sort_column, sort_direction }
@@ -349,9 +1196,22 @@ pub fn UtxosScreen() -> Element {
                                 }
                                 tbody {
                                     for utxo in sorted_utxos {
-                                        UtxoRow {
-                                            utxo: UiUtxoReadOnly(Rc::new(utxo)),
-                                            display_mode: display_mode
+                                        {
+                                            let index = utxo.aocl_leaf_index;
+                                            rsx! {
+                                                UtxoRow {
+                                                    annotation: index.and_then(|i| annotations.get(&i).cloned()),
+                                                    selected: index.is_some_and(|i| selection.is_selected(&i)),
+                                                    on_toggle: move |_| {
+                                                        if let Some(i) = index {
+                                                            selection.toggle(i);
+                                                        }
+                                                    },
+                                                    utxo: UiUtxoReadOnly(Rc::new(utxo)),
+                                                    display_mode: display_mode,
+                                                    show_asset,
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -0,0 +1,248 @@
+//! Sends in the background, so that leaving the Send screen does not lose
+//! track of a transaction being built and proved.
+//!
+//! Each send is a [SendJob], saved through the api when it starts and when
+//! it returns, so that its outcome can also be seen after a restart.  Jobs
+//! run in the root scope, which outlives every screen.
+//!
+//! What a failed job sent is kept in memory, so that it can be retried as
+//! is until the app closes.
+
+use std::collections::HashMap;
+
+use api::recent_recipient::RecentRecipient;
+use api::send_job::SendJob;
+use api::send_job::SendJobStatus;
+use dioxus::prelude::*;
+use neptune_types::change_policy::ChangePolicy;
+use neptune_types::native_currency_amount::NativeCurrencyAmount;
+use neptune_types::output_format::OutputFormat;
+use twenty_first::prelude::BFieldElement;
+
+/// What to send, and what to do once it was sent.
+#[derive(Clone)]
+pub struct SendRequest {
+    pub outputs: Vec<OutputFormat>,
+    pub change_policy: ChangePolicy,
+    pub fee: NativeCurrencyAmount,
+
+    /// The total of `outputs`, shown with the job.
+    pub amount: NativeCurrencyAmount,
+
+    /// The message of a public announcement to attach, if any.
+    pub announcement: Option<Vec<BFieldElement>>,
+
+    /// The AOCL leaf indices of the UTXOs to spend, if chosen by the user
+    /// rather than left to the wallet.
+    pub inputs: Option<Vec<u64>>,
+
+    /// Remembered as recently paid once sent.
+    pub recipients: Vec<RecentRecipient>,
+
+    /// The Send draft this was resumed from, deleted once sent.
+    pub draft: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub struct SendQueue {
+    /// The tracked sends, most recently started first.
+    pub jobs: Signal<Vec<SendJob>>,
+
+    /// What the failed jobs of this session sent, by job id.
+    failed_requests: Signal<HashMap<u64, SendRequest>>,
+}
+
+impl SendQueue {
+    /// Starts sending `request` in the background, returning the id of its
+    /// job.
+    pub fn submit(self, request: SendRequest) -> u64 {
+        let mut jobs = self.jobs;
+        let started_ms = now_ms();
+        // ids are start times, bumped past any job started in the same
+        // millisecond.
+        let id = jobs
+            .peek()
+            .iter()
+            .map(|j| j.id + 1)
+            .max()
+            .unwrap_or_default()
+            .max(started_ms);
+        let job = SendJob {
+            id,
+            started_ms,
+            finished_ms: None,
+            num_recipients: request.outputs.len(),
+            amount: request.amount,
+            fee: request.fee,
+            status: SendJobStatus::InFlight,
+            seen: false,
+        };
+        jobs.write().insert(0, job.clone());
+        self.run(job, request);
+        id
+    }
+
+    /// True if the job with `id` failed in this session, so that what it
+    /// sent is known, and its error allows sending it again as is.
+    pub fn can_retry(&self, id: u64) -> bool {
+        let retryable = self.jobs.read().iter().any(|job| {
+            job.id == id
+                && matches!(&job.status, SendJobStatus::Failed { error } if error.can_retry_send())
+        });
+        retryable && self.failed_requests.read().contains_key(&id)
+    }
+
+    /// Sends what the failed job with `id` sent again, as the same job.
+    pub fn retry(self, id: u64) {
+        let mut failed_requests = self.failed_requests;
+        let Some(request) = failed_requests.write().remove(&id) else {
+            return;
+        };
+        let mut jobs = self.jobs;
+        let mut jobs_write = jobs.write();
+        let Some(job) = jobs_write.iter_mut().find(|j| j.id == id) else {
+            return;
+        };
+        job.started_ms = now_ms();
+        job.finished_ms = None;
+        job.status = SendJobStatus::InFlight;
+        job.seen = false;
+        let job = job.clone();
+        drop(jobs_write);
+        self.run(job, request);
+    }
+
+    /// Sends `request` in the root scope, saving `job` before and after.
+    fn run(self, mut job: SendJob, request: SendRequest) {
+        let mut jobs = self.jobs;
+        let mut failed_requests = self.failed_requests;
+        let id = job.id;
+
+        spawn_forever(async move {
+            let _ = api::save_send_job(job.clone()).await;
+
+            let result = match (request.inputs.clone(), request.announcement.clone()) {
+                (Some(inputs), announcement) => {
+                    api::send_from_utxos(
+                        request.outputs.clone(),
+                        request.change_policy.clone(),
+                        request.fee,
+                        inputs,
+                        announcement,
+                    )
+                    .await
+                }
+                (None, None) => {
+                    api::send(
+                        request.outputs.clone(),
+                        request.change_policy.clone(),
+                        request.fee,
+                    )
+                    .await
+                }
+                (None, Some(message)) => {
+                    api::send_with_announcement(
+                        request.outputs.clone(),
+                        request.change_policy.clone(),
+                        request.fee,
+                        message,
+                    )
+                    .await
+                }
+            };
+            job.finished_ms = Some(now_ms());
+            job.status = match result {
+                Ok((txid, _)) => {
+                    let _ = api::record_recent_recipients(request.recipients).await;
+                    if let Some(name) = request.draft {
+                        let _ = api::delete_send_draft(name).await;
+                    }
+                    SendJobStatus::Sent { txid }
+                }
+                Err(error) => {
+                    failed_requests.write().insert(id, request);
+                    SendJobStatus::Failed { error }
+                }
+            };
+
+            // saved before it is shown, so that marking it seen comes after.
+            let _ = api::save_send_job(job.clone()).await;
+            if let Some(existing) = jobs.write().iter_mut().find(|j| j.id == id) {
+                *existing = job;
+            }
+        });
+    }
+
+    /// Marks the finished jobs with the given ids as seen.
+    pub fn mark_seen(self, ids: Vec<u64>) {
+        let mut jobs = self.jobs;
+        let mut changed = false;
+        for job in jobs.write().iter_mut().filter(|j| ids.contains(&j.id)) {
+            changed |= !job.seen;
+            job.seen = true;
+        }
+        if changed {
+            spawn(async move {
+                let _ = api::mark_send_jobs_seen(ids).await;
+            });
+        }
+    }
+
+    /// Forgets the finished jobs.
+    pub fn clear_finished(self) {
+        let mut jobs = self.jobs;
+        let mut failed_requests = self.failed_requests;
+        jobs.write().retain(|j| j.status.is_in_flight());
+        failed_requests.write().clear();
+        spawn(async move {
+            if let Ok(remaining) = api::clear_send_jobs().await {
+                jobs.set(remaining);
+            }
+        });
+    }
+
+    pub fn num_in_flight(&self) -> usize {
+        self.jobs
+            .read()
+            .iter()
+            .filter(|j| j.status.is_in_flight())
+            .count()
+    }
+
+    /// Finished jobs whose outcome the user has not seen.
+    pub fn num_unseen(&self) -> usize {
+        self.jobs
+            .read()
+            .iter()
+            .filter(|j| !j.seen && !j.status.is_in_flight())
+            .count()
+    }
+}
+
+/// Provides the [SendQueue], with the jobs saved by earlier sessions.
+pub fn use_send_queue_provider() -> SendQueue {
+    let queue = use_context_provider(|| SendQueue {
+        jobs: Signal::new(vec![]),
+        failed_requests: Signal::new(HashMap::new()),
+    });
+
+    use_future(move || async move {
+        let mut jobs = queue.jobs;
+        if let Ok(saved) = api::send_jobs().await {
+            // keeps any job submitted before the saved ones arrived.
+            let submitted: Vec<SendJob> = jobs
+                .peek()
+                .iter()
+                .filter(|j| !saved.iter().any(|s| s.id == j.id))
+                .cloned()
+                .collect();
+            jobs.set(submitted.into_iter().chain(saved).collect());
+        }
+    });
+
+    queue
+}
+
+fn now_ms() -> u64 {
+    chrono::Utc::now().timestamp_millis().max(0) as u64
+}
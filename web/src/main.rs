@@ -1,8 +1,14 @@
 use dioxus::prelude::*;
 
 fn main() {
-    dioxus_logger::init(dioxus_logger::tracing::Level::INFO).expect("failed to init logger");
+    api::logging::init();
+
+    #[cfg(not(feature = "server"))]
     dioxus::launch(App);
+
+    // every API request is identified before it reaches an endpoint.
+    #[cfg(feature = "server")]
+    dioxus::serve(|| async move { Ok(api::protect(dioxus::server::router(App))) });
 }
 
 #[component]